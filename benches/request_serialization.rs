@@ -0,0 +1,98 @@
+//! Benchmarks for building the provider request payload (no network).
+//!
+//! Run with: `cargo bench --bench request_serialization`
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use genai::adapter::{AdapterKind, ServiceType};
+use genai::chat::{ChatMessage, ChatRequest, ContentPart, Tool};
+use genai::resolver::{AuthData, Endpoint};
+use genai::{Client, ModelIden, ServiceTarget};
+use serde_json::json;
+use std::hint::black_box;
+
+// region:    --- Fixtures
+
+fn openai_target() -> ServiceTarget {
+	ServiceTarget {
+		endpoint: Endpoint::from_static("https://api.openai.com/v1/"),
+		auth: AuthData::from_single("bench-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	}
+}
+
+fn text_only_req(message_count: usize) -> ChatRequest {
+	let mut chat_req = ChatRequest::from_system("You are a helpful assistant.");
+	for idx in 0..message_count {
+		let msg = if idx % 2 == 0 {
+			ChatMessage::user(format!(
+				"Question {idx}: what is the capital of France? {}",
+				"lorem ".repeat(20)
+			))
+		} else {
+			ChatMessage::assistant(format!("Answer {idx}: Paris. {}", "ipsum ".repeat(20)))
+		};
+		chat_req = chat_req.append_message(msg);
+	}
+	chat_req
+}
+
+fn multi_image_req(image_count: usize) -> ChatRequest {
+	// ~64KB of base64 per image
+	let image_b64 = "iVBORw0KGgo".repeat(6_000);
+	let mut parts = vec![ContentPart::from_text("Describe these images.")];
+	for idx in 0..image_count {
+		parts.push(ContentPart::from_binary_base64(
+			"image/png",
+			image_b64.as_str(),
+			Some(format!("image-{idx}.png")),
+		));
+	}
+	ChatRequest::from_messages(vec![ChatMessage::user(parts)])
+}
+
+fn large_tool_schema_req(tool_count: usize) -> ChatRequest {
+	let tools = (0..tool_count).map(|idx| {
+		let properties: serde_json::Map<String, serde_json::Value> = (0..30)
+			.map(|prop_idx| {
+				(
+					format!("prop_{prop_idx}"),
+					json!({
+						"type": "string",
+						"description": format!("Property {prop_idx} of tool {idx}"),
+						"enum": ["a", "b", "c", "d"],
+					}),
+				)
+			})
+			.collect();
+		Tool::new(format!("tool_{idx}"))
+			.with_description(format!("Tool number {idx}"))
+			.with_schema(json!({"type": "object", "properties": properties, "required": ["prop_0"]}))
+	});
+	ChatRequest::from_user("Which tool should be used?").append_tools(tools)
+}
+
+// endregion: --- Fixtures
+
+fn bench_request_serialization(c: &mut Criterion) {
+	let client = Client::default();
+
+	let cases = [
+		("openai_text_only_20_messages", text_only_req(20)),
+		("openai_multi_image_8_images", multi_image_req(8)),
+		("openai_large_tool_schema_20_tools", large_tool_schema_req(20)),
+	];
+
+	for (name, chat_req) in cases {
+		c.bench_function(name, |b| {
+			b.iter(|| {
+				let payload = client
+					.build_chat_payload(openai_target(), ServiceType::Chat, chat_req.clone(), None)
+					.expect("payload should build");
+				black_box(payload)
+			})
+		});
+	}
+}
+
+criterion_group!(benches, bench_request_serialization);
+criterion_main!(benches);
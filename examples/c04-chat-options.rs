@@ -26,8 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	// -- Global ChatOptions
 	// Note: The properties of ChatOptions set at the client config level will be
 	//       the fallback values if not provided at the chat execution level.
-	let client_config =
-		ClientConfig::default().with_chat_options(ChatOptions::default().with_temperature(0.0).with_top_p(0.99));
+	let client_config = ClientConfig::default().with_chat_options(ChatOptions::deterministic());
 
 	// -- Build the new client with this client_config
 	let client = Client::builder().with_config(client_config).build();
@@ -36,7 +35,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let chat_req = ChatRequest::new(vec![ChatMessage::user(question)]);
 
 	// -- Build the chat request options (used per execution chat)
-	let options = ChatOptions::default().with_max_tokens(1000);
+	// Note: Presets can be layered with `merge`, the fields set in the argument take precedence.
+	let options = ChatOptions::creative().merge(&ChatOptions::default().with_max_tokens(1000));
 
 	// -- Execute and print
 	println!("\n--- Question:\n{question}");
@@ -0,0 +1,97 @@
+//! Streaming latency benchmark - p50/p95 time to first token and tokens per second.
+//!
+//! Uses the client-side timing of the stream (`StreamEnd::metrics`), so no external wall-clocking.
+//!
+//! Run with: `cargo run --example c12-latency-bench -- [model] [runs]`
+
+use futures::StreamExt;
+use genai::Client;
+use genai::chat::{ChatMessage, ChatMetrics, ChatOptions, ChatRequest, ChatStreamEvent};
+use tracing_subscriber::EnvFilter;
+
+const DEFAULT_MODEL: &str = "gpt-5.4-mini";
+const DEFAULT_RUNS: usize = 10;
+
+const PROMPT: &str = "Write a short paragraph (about 60 words) about the sky.";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	tracing_subscriber::fmt().with_env_filter(EnvFilter::new("genai=info")).init();
+
+	let mut args = std::env::args().skip(1);
+	let model = args.next().unwrap_or_else(|| DEFAULT_MODEL.to_string());
+	let runs = args.next().and_then(|runs| runs.parse().ok()).unwrap_or(DEFAULT_RUNS);
+
+	// capture_usage is needed for the tokens per second
+	let client = Client::builder()
+		.with_chat_options(ChatOptions::default().with_capture_usage(true))
+		.build();
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(PROMPT)]);
+
+	println!("--- Model: {model} ({runs} runs)");
+
+	let mut all_metrics: Vec<ChatMetrics> = Vec::with_capacity(runs);
+	for run in 1..=runs {
+		let mut stream = client.exec_chat_stream(&model, chat_req.clone(), None).await?.stream;
+
+		while let Some(event) = stream.next().await {
+			if let ChatStreamEvent::End(stream_end) = event?
+				&& let Some(metrics) = stream_end.metrics
+			{
+				println!(
+					"run {run:>3}: ttft {:>6} ms | latency {:>6} ms | {}",
+					fmt_opt(metrics.time_to_first_token_ms),
+					metrics.latency_ms,
+					fmt_tps(metrics.tokens_per_second)
+				);
+				all_metrics.push(metrics);
+			}
+		}
+	}
+
+	let mut ttfts: Vec<f64> = all_metrics
+		.iter()
+		.filter_map(|m| m.time_to_first_token_ms.map(|ttft| ttft as f64))
+		.collect();
+	let mut tps: Vec<f64> = all_metrics.iter().filter_map(|m| m.tokens_per_second).collect();
+
+	println!("\n--- Summary");
+	println!(
+		"time to first token (ms)  p50: {:>8} | p95: {:>8}",
+		fmt_f64(percentile(&mut ttfts, 50.)),
+		fmt_f64(percentile(&mut ttfts, 95.))
+	);
+	println!(
+		"tokens per second         p50: {:>8} | p95: {:>8}",
+		fmt_f64(percentile(&mut tps, 50.)),
+		fmt_f64(percentile(&mut tps, 95.))
+	);
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// Nearest-rank percentile (`None` when no values).
+fn percentile(values: &mut [f64], pct: f64) -> Option<f64> {
+	if values.is_empty() {
+		return None;
+	}
+	values.sort_by(f64::total_cmp);
+	let rank = ((pct / 100.) * values.len() as f64).ceil() as usize;
+	Some(values[rank.clamp(1, values.len()) - 1])
+}
+
+fn fmt_opt(value: Option<u64>) -> String {
+	value.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+fn fmt_f64(value: Option<f64>) -> String {
+	value.map(|v| format!("{v:.1}")).unwrap_or_else(|| "-".to_string())
+}
+
+fn fmt_tps(value: Option<f64>) -> String {
+	value.map(|v| format!("{v:.1} tok/s")).unwrap_or_else(|| "- tok/s".to_string())
+}
+
+// endregion: --- Support
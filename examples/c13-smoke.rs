@@ -0,0 +1,188 @@
+//! Smoke test - one tiny prompt to every configured provider (e.g., after rotating the API keys).
+//!
+//! A provider is configured when the `AdapterKind::default_key_env_name()` environment variable is set,
+//! and is called with its `smoke_model` below (small, non-reasoning when possible, to edit as the providers change).
+//!
+//! Run with: `cargo run --example c13-smoke -- [--timeout <secs>] [--json]`
+//!
+//! Exits with a non-zero code if any configured provider fails (error or timeout).
+
+use futures::future::join_all;
+use genai::Client;
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+const PROMPT: &str = "Reply with: ok";
+const MAX_TOKENS: u32 = 10;
+/// The OpenAI Responses API minimum `max_output_tokens`.
+const MAX_TOKENS_RESPONSES_API: u32 = 16;
+
+#[derive(Debug, Serialize)]
+struct SmokeResult {
+	adapter_kind: AdapterKind,
+	model: &'static str,
+	passed: bool,
+	latency_ms: u64,
+	prompt_tokens: Option<i32>,
+	completion_tokens: Option<i32>,
+	error: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	let mut json = false;
+	let mut timeout = Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+	let mut args = std::env::args().skip(1);
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--json" => json = true,
+			"--timeout" => {
+				let secs = args
+					.next()
+					.and_then(|secs| secs.parse().ok())
+					.ok_or("--timeout expects seconds")?;
+				timeout = Duration::from_secs(secs);
+			}
+			_ => return Err(format!("Unknown argument '{arg}' (usage: [--timeout <secs>] [--json])").into()),
+		}
+	}
+
+	// -- The configured providers (API key environment variable set, and a smoke model)
+	let kinds: Vec<AdapterKind> = AdapterKind::ALL
+		.iter()
+		.filter(|kind| {
+			kind.default_key_env_name()
+				.is_some_and(|env_name| std::env::var(env_name).is_ok_and(|key| !key.is_empty()))
+				&& smoke_model(**kind).is_some()
+		})
+		.copied()
+		.collect();
+
+	// -- Exec, all the providers concurrently, each one within its timeout
+	let client = Client::default();
+	let results = join_all(kinds.into_iter().map(|kind| smoke(&client, kind, timeout))).await;
+
+	// -- Report
+	if json {
+		println!("{}", serde_json::to_string_pretty(&results)?);
+	} else {
+		print_table(&results);
+	}
+
+	if results.iter().any(|res| !res.passed) {
+		std::process::exit(1);
+	}
+
+	Ok(())
+}
+
+async fn smoke(client: &Client, adapter_kind: AdapterKind, timeout: Duration) -> SmokeResult {
+	let model = smoke_model(adapter_kind).unwrap_or_default();
+	let max_tokens = match adapter_kind {
+		AdapterKind::OpenAIResp | AdapterKind::CopilotResp => MAX_TOKENS_RESPONSES_API,
+		_ => MAX_TOKENS,
+	};
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(PROMPT)]);
+	let options = ChatOptions::default().with_max_tokens(max_tokens).with_capture_usage(true);
+
+	let start = Instant::now();
+	let res = tokio::time::timeout(timeout, client.exec_chat(model, chat_req, Some(&options))).await;
+	let latency_ms = start.elapsed().as_millis() as u64;
+
+	let mut result = SmokeResult {
+		adapter_kind,
+		model,
+		passed: false,
+		latency_ms,
+		prompt_tokens: None,
+		completion_tokens: None,
+		error: None,
+	};
+	match res {
+		Ok(Ok(chat_res)) => {
+			result.passed = true;
+			result.prompt_tokens = chat_res.usage.prompt_tokens;
+			result.completion_tokens = chat_res.usage.completion_tokens;
+		}
+		Ok(Err(err)) => result.error = Some(err.to_string()),
+		Err(_) => result.error = Some(format!("Timed out after {}s", timeout.as_secs())),
+	}
+	result
+}
+
+// region:    --- Support
+
+/// A small chat model of the adapter kind, namespaced so it resolves to this adapter kind.
+fn smoke_model(adapter_kind: AdapterKind) -> Option<&'static str> {
+	let model = match adapter_kind {
+		AdapterKind::OpenAI => "openai::gpt-4.1-mini",
+		AdapterKind::OpenAIResp => "openai_resp::gpt-4.1-mini",
+		AdapterKind::Gemini => "gemini::gemini-2.5-flash-lite",
+		AdapterKind::Anthropic => "anthropic::claude-haiku-4-5",
+		AdapterKind::Fireworks => "fireworks::gpt-oss-20b",
+		AdapterKind::Together => "together::openai/gpt-oss-20b",
+		AdapterKind::AI21 => "ai21::jamba-mini",
+		AdapterKind::DeepInfra => "deepinfra::meta-llama/Meta-Llama-3.1-8B-Instruct",
+		AdapterKind::Hunyuan => "hunyuan::hunyuan-turbo",
+		AdapterKind::Qianfan => "qianfan::ernie-4.0-turbo-8k",
+		AdapterKind::StepFun => "stepfun::step-2-16k",
+		AdapterKind::Lingyiwanwu => "lingyiwanwu::yi-lightning",
+		AdapterKind::Groq => "groq::llama-3.1-8b-instant",
+		AdapterKind::Mimo => "mimo::mimo-v2-flash",
+		AdapterKind::Nebius => "nebius::meta-llama/Meta-Llama-3.1-8B-Instruct",
+		AdapterKind::Xai => "xai::grok-3-mini",
+		AdapterKind::DeepSeek => "deepseek::deepseek-chat",
+		AdapterKind::Zai => "zai::glm-4-plus",
+		AdapterKind::BigModel => "bigmodel::glm-4-flash",
+		AdapterKind::Aliyun => "aliyun::qwen-plus",
+		AdapterKind::Cohere => "cohere::command-r7b-12-2024",
+		AdapterKind::Copilot => "copilot::gpt-4o",
+		// the model of the `copilot_resp` live tests (tests/tests_p_copilot_resp.rs)
+		AdapterKind::CopilotResp => "copilot_resp::gpt-5.4",
+		AdapterKind::Ollama => "ollama::gemma3:4b",
+		AdapterKind::OllamaCloud => "ollama_cloud::gpt-oss:120b",
+		AdapterKind::Vertex => "vertex::gemini-2.5-flash-lite",
+		AdapterKind::GithubCopilot => "github_copilot::openai/gpt-4.1-mini",
+		_ => return None,
+	};
+	Some(model)
+}
+
+fn print_table(results: &[SmokeResult]) {
+	if results.is_empty() {
+		println!("No provider configured (no API key environment variable set).");
+		return;
+	}
+
+	println!(
+		"{:<14} {:<50} {:<6} {:>9} {:>7} {:>7}",
+		"provider", "model", "result", "latency", "input", "output"
+	);
+	for res in results {
+		println!(
+			"{:<14} {:<50} {:<6} {:>6} ms {:>7} {:>7}",
+			res.adapter_kind.to_string(),
+			res.model,
+			if res.passed { "pass" } else { "FAIL" },
+			res.latency_ms,
+			fmt_opt(res.prompt_tokens),
+			fmt_opt(res.completion_tokens)
+		);
+		if let Some(error) = &res.error {
+			println!("    {error}");
+		}
+	}
+
+	let failed = results.iter().filter(|res| !res.passed).count();
+	println!("\n--- {} passed, {failed} failed", results.len() - failed);
+}
+
+fn fmt_opt(value: Option<i32>) -> String {
+	value.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+// endregion: --- Support
@@ -0,0 +1,520 @@
+//! OpenAI compatible server - exposes genai as an OpenAI `/v1/chat/completions` endpoint (axum).
+//!
+//! The OpenAI request is translated to a genai `ChatRequest` and `ChatOptions`, and the genai response back
+//! with `ChatResponse::to_openai_json` (or as `chat.completion.chunk` SSE events when `"stream": true`).
+//! The `model` is any genai model name (e.g., `gpt-4o-mini`, `claude-sonnet-4-5`, `groq::llama-3.1-8b-instant`).
+//!
+//! Run with: `cargo run --example c14-openai-server -- [addr]` (default `127.0.0.1:3000`), then e.g.:
+//!
+//! ```sh
+//! curl http://127.0.0.1:3000/v1/chat/completions -H 'content-type: application/json' \
+//!   -d '{"model": "gpt-4o-mini", "messages": [{"role": "user", "content": "Why is the sky blue?"}]}'
+//! ```
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use futures::{Stream, StreamExt, future, stream};
+use genai::Client;
+use genai::chat::{
+	ChatMessage, ChatOptions, ChatRequest, ChatStream, ChatStreamEvent, ContentPart, MessageContent, Tool, ToolCall,
+	ToolResponse,
+};
+use serde_json::{Value, json};
+use std::convert::Infallible;
+use tracing_subscriber::EnvFilter;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:3000";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	tracing_subscriber::fmt().with_env_filter(EnvFilter::new("genai=info")).init();
+
+	let addr = std::env::args().nth(1).unwrap_or_else(|| DEFAULT_ADDR.to_string());
+	let listener = tokio::net::TcpListener::bind(&addr).await?;
+
+	println!("--- OpenAI compatible server: http://{addr}/v1/chat/completions");
+	axum::serve(listener, router(Client::default())).await?;
+
+	Ok(())
+}
+
+fn router(client: Client) -> Router {
+	Router::new()
+		.route("/v1/chat/completions", post(chat_completions))
+		.with_state(client)
+}
+
+async fn chat_completions(State(client): State<Client>, Json(body): Json<Value>) -> Response {
+	let OpenAIChatRequest {
+		model,
+		stream,
+		chat_req,
+		options,
+	} = match OpenAIChatRequest::from_json(&body) {
+		Ok(openai_req) => openai_req,
+		Err(err) => return error_response(StatusCode::BAD_REQUEST, "invalid_request_error", err),
+	};
+
+	if stream {
+		match client.exec_chat_stream(&model, chat_req, Some(&options)).await {
+			Ok(chat_stream_res) => Sse::new(sse_events(chat_stream_res.stream, model))
+				.keep_alive(KeepAlive::default())
+				.into_response(),
+			Err(err) => error_response(StatusCode::BAD_GATEWAY, "api_error", err.to_string()),
+		}
+	} else {
+		match client.exec_chat(&model, chat_req, Some(&options)).await {
+			Ok(chat_res) => Json(chat_res.to_openai_json()).into_response(),
+			Err(err) => error_response(StatusCode::BAD_GATEWAY, "api_error", err.to_string()),
+		}
+	}
+}
+
+/// An OpenAI error body (`{"error": {"message", "type"}}`).
+fn error_response(status: StatusCode, error_type: &str, message: String) -> Response {
+	let body = json!({"error": {"message": message, "type": error_type}});
+	(status, Json(body)).into_response()
+}
+
+// region:    --- OpenAI Request
+
+/// The genai request of an OpenAI chat completions request.
+struct OpenAIChatRequest {
+	model: String,
+	stream: bool,
+	chat_req: ChatRequest,
+	options: ChatOptions,
+}
+
+impl OpenAIChatRequest {
+	/// Translates the messages (`system`/`developer`, `user`, `assistant`, `tool`), the `tools`,
+	/// and the generation options (`temperature`, `max_tokens`, `top_p`, `stop`).
+	fn from_json(body: &Value) -> Result<Self, String> {
+		let model = body["model"].as_str().ok_or("Missing 'model'")?.to_string();
+		let messages = body["messages"]
+			.as_array()
+			.ok_or("Missing 'messages'")?
+			.iter()
+			.map(to_chat_message)
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let mut chat_req = ChatRequest::from_messages(messages);
+		if let Some(tools) = body["tools"].as_array() {
+			chat_req = chat_req.with_tools(tools.iter().filter_map(to_tool));
+		}
+
+		// The usage and the tool calls are captured for the last stream chunk
+		let mut options = ChatOptions::default().with_capture_usage(true).with_capture_tool_calls(true);
+		if let Some(temperature) = body["temperature"].as_f64() {
+			options = options.with_temperature(temperature);
+		}
+		if let Some(max_tokens) = body["max_completion_tokens"].as_u64().or_else(|| body["max_tokens"].as_u64()) {
+			options = options.with_max_tokens(max_tokens as u32);
+		}
+		if let Some(top_p) = body["top_p"].as_f64() {
+			options = options.with_top_p(top_p);
+		}
+		let stop_sequences: Vec<String> = match &body["stop"] {
+			Value::String(stop) => vec![stop.clone()],
+			Value::Array(stops) => stops.iter().filter_map(|stop| stop.as_str().map(String::from)).collect(),
+			_ => Vec::new(),
+		};
+		if !stop_sequences.is_empty() {
+			options = options.with_stop_sequences(stop_sequences);
+		}
+
+		Ok(Self {
+			model,
+			stream: body["stream"].as_bool().unwrap_or(false),
+			chat_req,
+			options,
+		})
+	}
+}
+
+fn to_chat_message(message: &Value) -> Result<ChatMessage, String> {
+	let content = &message["content"];
+	match message["role"].as_str().ok_or("Missing message 'role'")? {
+		"system" | "developer" => Ok(ChatMessage::system(text_content(content))),
+		"user" => Ok(ChatMessage::user(user_content(content))),
+		"assistant" => {
+			let mut parts: Vec<ContentPart> = Vec::new();
+			let text = text_content(content);
+			if !text.is_empty() {
+				parts.push(ContentPart::from_text(text));
+			}
+			for tool_call in message["tool_calls"].as_array().into_iter().flatten() {
+				let function = &tool_call["function"];
+				// The arguments are a JSON string (kept as a string when not valid JSON)
+				let arguments = function["arguments"].as_str().unwrap_or("{}");
+				parts.push(ContentPart::ToolCall(ToolCall {
+					call_id: tool_call["id"].as_str().unwrap_or_default().to_string(),
+					fn_name: function["name"].as_str().unwrap_or_default().to_string(),
+					fn_arguments: serde_json::from_str(arguments).unwrap_or_else(|_| arguments.into()),
+					thought_signatures: None,
+				}));
+			}
+			Ok(ChatMessage::assistant(MessageContent::from_parts(parts)))
+		}
+		"tool" => {
+			let tool_call_id = message["tool_call_id"].as_str().ok_or("Missing tool message 'tool_call_id'")?;
+			Ok(ChatMessage::from(ToolResponse::new(
+				tool_call_id,
+				text_content(content),
+			)))
+		}
+		role => Err(format!("Unsupported message role '{role}'")),
+	}
+}
+
+/// The text of a content (a string, or the `text` parts joined).
+fn text_content(content: &Value) -> String {
+	match content {
+		Value::String(text) => text.clone(),
+		Value::Array(parts) => parts.iter().filter_map(|part| part["text"].as_str()).collect(),
+		_ => String::new(),
+	}
+}
+
+/// The user content, with the `image_url` parts (data URLs or URLs).
+fn user_content(content: &Value) -> MessageContent {
+	let Value::Array(parts) = content else {
+		return MessageContent::from_text(text_content(content));
+	};
+
+	let parts: Vec<ContentPart> = parts
+		.iter()
+		.filter_map(|part| match part["type"].as_str() {
+			Some("text") => part["text"].as_str().map(ContentPart::from_text),
+			Some("image_url") => part["image_url"]["url"].as_str().map(to_image_part),
+			_ => None,
+		})
+		.collect();
+	MessageContent::from_parts(parts)
+}
+
+fn to_image_part(url: &str) -> ContentPart {
+	// e.g., `data:image/png;base64,iVBORw0KGgo...`
+	if let Some((content_type, data)) = url.strip_prefix("data:").and_then(|data_url| data_url.split_once(";base64,")) {
+		return ContentPart::from_binary_base64(content_type, data, None);
+	}
+
+	let content_type = match url.rsplit('.').next().map(str::to_lowercase).as_deref() {
+		Some("png") => "image/png",
+		Some("gif") => "image/gif",
+		Some("webp") => "image/webp",
+		_ => "image/jpeg",
+	};
+	ContentPart::from_binary_url(content_type, url, None)
+}
+
+fn to_tool(tool: &Value) -> Option<Tool> {
+	let function = &tool["function"];
+	let mut genai_tool = Tool::new(function["name"].as_str()?);
+	if let Some(description) = function["description"].as_str() {
+		genai_tool = genai_tool.with_description(description);
+	}
+	if function["parameters"].is_object() {
+		genai_tool = genai_tool.with_schema(function["parameters"].clone());
+	}
+	Some(genai_tool)
+}
+
+// endregion: --- OpenAI Request
+
+// region:    --- OpenAI Stream
+
+/// The genai stream as OpenAI `chat.completion.chunk` SSE events, ending with `[DONE]`.
+///
+/// The text and reasoning chunks are sent as they come. The tool calls, the finish reason, and the usage
+/// are sent in the last chunk (from the captured `StreamEnd`).
+fn sse_events(chat_stream: ChatStream, model: String) -> impl Stream<Item = Result<Event, Infallible>> {
+	let created = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|duration| duration.as_secs())
+		.unwrap_or_default();
+	let chunk = move |delta: Value, finish_reason: Option<&str>| {
+		json!({
+			"id": "chatcmpl-genai",
+			"object": "chat.completion.chunk",
+			"created": created,
+			"model": model,
+			"choices": [{"index": 0, "delta": delta, "finish_reason": finish_reason}],
+		})
+	};
+
+	let chunks = chat_stream.filter_map(move |event| {
+		let data = match event {
+			Ok(ChatStreamEvent::Start) => Some(chunk(json!({"role": "assistant", "content": ""}), None)),
+			Ok(ChatStreamEvent::Chunk(text)) => Some(chunk(json!({"content": text.content}), None)),
+			Ok(ChatStreamEvent::ReasoningChunk(reasoning)) => {
+				Some(chunk(json!({"reasoning_content": reasoning.content}), None))
+			}
+			Ok(ChatStreamEvent::End(end)) => {
+				let mut delta = json!({});
+				let tool_calls: Vec<Value> = end
+					.captured_tool_calls()
+					.unwrap_or_default()
+					.into_iter()
+					.enumerate()
+					.map(|(index, tool_call)| {
+						let arguments = match &tool_call.fn_arguments {
+							Value::String(arguments) => arguments.clone(),
+							fn_arguments => fn_arguments.to_string(),
+						};
+						json!({
+							"index": index,
+							"id": tool_call.call_id,
+							"type": "function",
+							"function": {"name": tool_call.fn_name, "arguments": arguments},
+						})
+					})
+					.collect();
+				if !tool_calls.is_empty() {
+					delta["tool_calls"] = tool_calls.into();
+				}
+
+				// An interrupted stream has no finish reason (its error follows)
+				let finish_reason = match end.captured_stop_reason.as_ref() {
+					_ if end.incomplete => None,
+					Some(stop_reason) => Some(stop_reason.openai_finish_reason()),
+					None => Some("stop"),
+				};
+				let mut last_chunk = chunk(delta, finish_reason);
+				if let Some(usage) = end.captured_usage.as_ref() {
+					last_chunk["usage"] = serde_json::to_value(usage).unwrap_or_default();
+				}
+				Some(last_chunk)
+			}
+			Ok(_) => None,
+			// The error is sent as an OpenAI error body (the stream then ends)
+			Err(err) => Some(json!({"error": {"message": err.to_string(), "type": "api_error"}})),
+		};
+		future::ready(data)
+	});
+
+	chunks
+		.map(|data| Ok(Event::default().data(data.to_string())))
+		.chain(stream::once(future::ready(Ok(Event::default().data("[DONE]")))))
+}
+
+// endregion: --- OpenAI Stream
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use genai::adapter::AdapterKind;
+	use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+	use genai::{ModelIden, ServiceTarget};
+
+	/// The mocked OpenAI backend: answers `echo: <last user text>`, as a completion or as SSE chunks
+	/// (for `interrupt`, a tool call chunk then a stream error).
+	async fn mock_backend(Json(body): Json<Value>) -> Response {
+		let last_text = body["messages"]
+			.as_array()
+			.and_then(|messages| messages.last())
+			.map(|message| text_content(&message["content"]))
+			.unwrap_or_default();
+		let usage = json!({"prompt_tokens": 12, "completion_tokens": 3, "total_tokens": 15});
+
+		if body["stream"] == true && last_text == "interrupt" {
+			let tool_call = json!({"index": 0, "id": "call_1", "type": "function",
+				"function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}});
+			let chunks = [
+				json!({"choices": [{"index": 0, "delta": {"role": "assistant", "tool_calls": [tool_call]}}]}),
+				json!({"error": {"message": "Overloaded", "type": "server_error"}}),
+			];
+			let sse: String = chunks.iter().map(|chunk| format!("data: {chunk}\n\n")).collect();
+			([("content-type", "text/event-stream")], sse).into_response()
+		} else if body["stream"] == true {
+			let chunks = [
+				json!({"choices": [{"index": 0, "delta": {"role": "assistant", "content": "echo: "}}]}),
+				json!({"choices": [{"index": 0, "delta": {"content": last_text}}]}),
+				json!({"choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]}),
+				json!({"choices": [], "usage": usage}),
+			];
+			let mut sse: String = chunks.iter().map(|chunk| format!("data: {chunk}\n\n")).collect();
+			sse.push_str("data: [DONE]\n\n");
+			([("content-type", "text/event-stream")], sse).into_response()
+		} else {
+			Json(json!({
+				"id": "chatcmpl-mock",
+				"choices": [{
+					"index": 0,
+					"message": {"role": "assistant", "content": format!("echo: {last_text}")},
+					"finish_reason": "stop",
+				}],
+				"usage": usage,
+			}))
+			.into_response()
+		}
+	}
+
+	/// Serves the router on a local port, and returns its base url.
+	async fn serve(router: Router) -> TestResult<String> {
+		let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://127.0.0.1:{}", listener.local_addr()?.port());
+		tokio::spawn(async move { axum::serve(listener, router).await });
+		Ok(base_url)
+	}
+
+	/// Starts the mocked backend and the example server (with a client targeting the backend),
+	/// and returns the example server base url.
+	async fn start_servers() -> TestResult<String> {
+		let backend_url = serve(Router::new().route("/v1/chat/completions", post(mock_backend))).await?;
+
+		let target_resolver = ServiceTargetResolver::from_resolver_fn(
+			move |service_target: ServiceTarget| -> Result<ServiceTarget, genai::resolver::Error> {
+				Ok(ServiceTarget {
+					endpoint: Endpoint::from_owned(format!("{backend_url}/v1/")),
+					auth: AuthData::from_single("test-key"),
+					model: ModelIden::new(AdapterKind::OpenAI, service_target.model.model_name),
+				})
+			},
+		);
+		let client = Client::builder().with_service_target_resolver(target_resolver).build();
+
+		serve(router(client)).await
+	}
+
+	fn openai_request(stream: bool) -> Value {
+		openai_request_with_text(stream, "Hello")
+	}
+
+	fn openai_request_with_text(stream: bool, text: &str) -> Value {
+		json!({
+			"model": "gpt-4o-mini",
+			"stream": stream,
+			"max_tokens": 64,
+			"messages": [
+				{"role": "system", "content": "Answer briefly."},
+				{"role": "user", "content": [{"type": "text", "text": text}]},
+			],
+		})
+	}
+
+	#[tokio::test]
+	async fn test_openai_server_chat_completion() -> TestResult<()> {
+		// -- Setup & Fixtures
+		let server_url = start_servers().await?;
+
+		// -- Exec
+		let res = reqwest::Client::new()
+			.post(format!("{server_url}/v1/chat/completions"))
+			.json(&openai_request(false))
+			.send()
+			.await?;
+
+		// -- Check
+		assert_eq!(res.status(), 200);
+		let body: Value = res.json().await?;
+		assert_eq!(body["object"], "chat.completion");
+		assert_eq!(body["id"], "chatcmpl-mock");
+		assert_eq!(body["choices"][0]["message"]["content"], "echo: Hello");
+		assert_eq!(body["choices"][0]["finish_reason"], "stop");
+		assert_eq!(body["usage"]["total_tokens"], 15);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_openai_server_chat_completion_stream() -> TestResult<()> {
+		// -- Setup & Fixtures
+		let server_url = start_servers().await?;
+
+		// -- Exec
+		let res = reqwest::Client::new()
+			.post(format!("{server_url}/v1/chat/completions"))
+			.json(&openai_request(true))
+			.send()
+			.await?;
+
+		// -- Check
+		assert_eq!(res.status(), 200);
+		let sse = res.text().await?;
+		let data: Vec<&str> = sse.lines().filter_map(|line| line.strip_prefix("data: ")).collect();
+		assert_eq!(data.last(), Some(&"[DONE]"));
+
+		let chunks = data[..data.len() - 1]
+			.iter()
+			.map(|data| serde_json::from_str::<Value>(data))
+			.collect::<Result<Vec<_>, _>>()?;
+		assert!(chunks.iter().all(|chunk| chunk["object"] == "chat.completion.chunk"));
+		let content: String = chunks
+			.iter()
+			.filter_map(|chunk| chunk["choices"][0]["delta"]["content"].as_str())
+			.collect();
+		assert_eq!(content, "echo: Hello");
+
+		let last_chunk = chunks.last().ok_or("should have chunks")?;
+		assert_eq!(last_chunk["choices"][0]["finish_reason"], "stop");
+		assert_eq!(last_chunk["usage"]["total_tokens"], 15);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_openai_server_chat_completion_stream_interrupted() -> TestResult<()> {
+		// -- Setup & Fixtures
+		let server_url = start_servers().await?;
+
+		// -- Exec
+		let res = reqwest::Client::new()
+			.post(format!("{server_url}/v1/chat/completions"))
+			.json(&openai_request_with_text(true, "interrupt"))
+			.send()
+			.await?;
+
+		// -- Check
+		let sse = res.text().await?;
+		let data: Vec<&str> = sse.lines().filter_map(|line| line.strip_prefix("data: ")).collect();
+		assert_eq!(data.last(), Some(&"[DONE]"));
+		let chunks = data[..data.len() - 1]
+			.iter()
+			.map(|data| serde_json::from_str::<Value>(data))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		// the partial tool call, without finish reason, then the error
+		let [.., end_chunk, error_chunk] = chunks.as_slice() else {
+			return Err("should have the end and error chunks".into());
+		};
+		assert_eq!(
+			end_chunk["choices"][0]["delta"]["tool_calls"][0]["function"]["name"],
+			"get_weather"
+		);
+		assert_eq!(end_chunk["choices"][0]["finish_reason"], Value::Null);
+		assert!(error_chunk["error"]["message"].is_string());
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_openai_server_invalid_request() -> TestResult<()> {
+		// -- Setup & Fixtures
+		let server_url = start_servers().await?;
+
+		// -- Exec
+		let res = reqwest::Client::new()
+			.post(format!("{server_url}/v1/chat/completions"))
+			.json(&json!({"messages": []}))
+			.send()
+			.await?;
+
+		// -- Check
+		assert_eq!(res.status(), 400);
+		let body: Value = res.json().await?;
+		assert_eq!(body["error"]["type"], "invalid_request_error");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
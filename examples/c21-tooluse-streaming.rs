@@ -66,12 +66,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 			ChatStreamEvent::Start => {
 				println!("Stream started");
 			}
+			ChatStreamEvent::FirstToken(first_token) => {
+				println!("First token after {:?}", first_token.elapsed);
+			}
 			ChatStreamEvent::Chunk(chunk) => {
 				print!("{}", chunk.content);
 			}
 			ChatStreamEvent::ToolCallChunk(chunk) => {
 				println!("  ToolCallChunk: {:?}", chunk.tool_call);
 			}
+			ChatStreamEvent::ToolCallArgsChunk(chunk) => {
+				println!("  ToolCallArgsChunk #{}: {}", chunk.tool_index, chunk.fragment);
+			}
 			ChatStreamEvent::ReasoningChunk(chunk) => {
 				println!("  ReasoningChunk: {:?}", chunk.content);
 			}
@@ -1,5 +1,5 @@
 use genai::Client;
-use genai::chat::{ChatMessage, ChatRequest, Tool, ToolCall, ToolResponse};
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest, Tool, ToolCall, ToolResponse};
 use serde_json::json;
 
 const MODEL: &str = "gemini-3-flash-preview";
@@ -44,7 +44,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	println!("--- Model: {MODEL}");
 	println!("--- Sending deterministic history (synthetic tool call)...");
 
-	match client.exec_chat(MODEL, chat_req, None).await {
+	match client.exec_chat(MODEL, chat_req, Some(&ChatOptions::deterministic())).await {
 		Ok(chat_res) => {
 			println!("\n--- Response received successfully:");
 			if let Some(text) = chat_res.first_text() {
@@ -2,7 +2,7 @@
 //!
 //! Demonstrates how to use ZAI models with automatic endpoint routing:
 //! - `glm-4.6` → Regular credit-based API
-//! - `zai::glm-4.6` → Coding subscription API (automatically routed)
+//! - `zai-coding::glm-4.6` → Coding subscription API (automatically routed, see `ZAI_CODING_NAMESPACE`)
 
 use genai::Client;
 use genai::chat::{ChatMessage, ChatRequest};
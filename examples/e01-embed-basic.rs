@@ -7,7 +7,7 @@
 //! - Handle different providers
 
 use genai::Client;
-use genai::embed::{EmbedOptions, EmbedRequest};
+use genai::embed::{EmbedOptions, EmbedRequest, EmbedTruncate};
 use tracing_subscriber::EnvFilter;
 
 // OpenAI embedding models
@@ -130,7 +130,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let cohere_options = EmbedOptions::new()
 		.with_dimensions(512)
 		.with_embedding_type("search_query") // Cohere: specify embedding type
-		.with_truncate("START") // Cohere: truncate from start instead of end
+		.with_truncate(EmbedTruncate::Start) // Cohere: truncate from start instead of end
 		.with_capture_usage(true);
 
 	println!("   Cohere options: embedding_type='search_query', truncate='START'");
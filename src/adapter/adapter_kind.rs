@@ -1,8 +1,14 @@
+use crate::adapter::adapters::ai21::AI21Adapter;
 use crate::adapter::adapters::copilot_resp::CopilotRespAdapter;
+use crate::adapter::adapters::deepinfra::DeepInfraAdapter;
 use crate::adapter::adapters::github_copilot::GithubCopilotAdapter;
+use crate::adapter::adapters::hunyuan::HunyuanAdapter;
+use crate::adapter::adapters::lingyiwanwu::LingyiwanwuAdapter;
 use crate::adapter::adapters::ollama::OllamaAdapter;
 use crate::adapter::adapters::ollama_cloud::OllamaCloudAdapter;
 use crate::adapter::adapters::openai_resp::OpenAIRespAdapter;
+use crate::adapter::adapters::qianfan::QianfanAdapter;
+use crate::adapter::adapters::stepfun::StepFunAdapter;
 use crate::adapter::adapters::together::TogetherAdapter;
 use crate::adapter::adapters::zai::ZaiAdapter;
 use crate::adapter::aliyun::AliyunAdapter;
@@ -18,14 +24,42 @@ use crate::adapter::nebius::NebiusAdapter;
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::vertex::VertexAdapter;
 use crate::adapter::xai::XaiAdapter;
-use crate::adapter::{Adapter as _, zai};
+use crate::adapter::{Adapter as _, ServiceType, zai};
 use crate::{ModelName, Result};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+/// The API family (wire protocol) of an adapter, to branch on the protocol rather than on each adapter kind
+/// (see `AdapterKind::api_family`).
+///
+/// NOTE: Non exhaustive, as new families (and the feature-gated `Mock`) can be added without a major version.
+#[derive(Debug, Clone, Copy, Display, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ApiFamily {
+	/// OpenAI Chat Completions (`chat/completions`), and the providers compatible with it.
+	OpenAICompat,
+	/// OpenAI Responses API (`responses`).
+	OpenAIResponses,
+	/// Anthropic Messages API.
+	Anthropic,
+	/// Gemini `generateContent` API.
+	Gemini,
+	/// Cohere v2 Chat API.
+	CohereV2,
+	/// Ollama native API (`api/chat`).
+	Ollama,
+	/// Vertex AI, Gemini or Anthropic protocol depending on the model publisher.
+	Vertex,
+	/// The test-only in-memory adapter (no wire protocol, see `AdapterKind::Mock`).
+	#[cfg(feature = "mock")]
+	Mock,
+}
+
 /// AdapterKind is an enum that represents the different types of adapters that can be used to interact with the API.
 ///
+/// NOTE: Non exhaustive, as new adapters (and the feature-gated `Mock`) can be added without a major version.
 #[derive(Debug, Clone, Copy, Display, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum AdapterKind {
 	/// For OpenAI Chat Completions and also can be used for OpenAI compatible APIs
 	/// NOTE: This adapter share some behavior that other adapters can use while still providing some variant
@@ -40,6 +74,18 @@ pub enum AdapterKind {
 	Fireworks,
 	/// Together AI (Mostly uses OpenAI-compatible protocol)
 	Together,
+	/// AI21 Studio for the Jamba models (Mostly use OpenAI)
+	AI21,
+	/// DeepInfra (Mostly use OpenAI, only accessible via namespace deepinfra::)
+	DeepInfra,
+	/// Tencent Hunyuan (Mostly use OpenAI)
+	Hunyuan,
+	/// Baidu Qianfan v2 for the ERNIE models (Mostly use OpenAI, API key or AK/SK bearer token)
+	Qianfan,
+	/// StepFun for the Step models (Mostly use OpenAI)
+	StepFun,
+	/// 01.AI / Lingyiwanwu for the Yi models (Mostly use OpenAI)
+	Lingyiwanwu,
 	/// Reuse some of the OpenAI adapter behavior, customize some (e.g., normalize thinking budget)
 	Groq,
 	/// For Mimo (Mostly use OpenAI)
@@ -72,6 +118,10 @@ pub enum AdapterKind {
 	/// GitHub Models inference API (multi-publisher gateway for OpenAI, Anthropic, and Google models).
 	/// Uses namespace routing: `github_copilot::openai/gpt-4.1-mini`, `github_copilot::anthropic/claude-sonnet-4-6`, `github_copilot::google/gemini-2.5-pro`
 	GithubCopilot,
+	/// Test-only in-memory adapter (`mock` feature), answered by the client `MockChatHandler` without network.
+	/// Uses namespace routing: `mock::any-model-name`
+	#[cfg(feature = "mock")]
+	Mock,
 }
 
 /// Serialization/Parse implementations
@@ -85,6 +135,12 @@ impl AdapterKind {
 			AdapterKind::Anthropic => "Anthropic",
 			AdapterKind::Fireworks => "Fireworks",
 			AdapterKind::Together => "Together",
+			AdapterKind::AI21 => "AI21",
+			AdapterKind::DeepInfra => "DeepInfra",
+			AdapterKind::Hunyuan => "Hunyuan",
+			AdapterKind::Qianfan => "Qianfan",
+			AdapterKind::StepFun => "StepFun",
+			AdapterKind::Lingyiwanwu => "Lingyiwanwu",
 			AdapterKind::Groq => "Groq",
 			AdapterKind::Mimo => "Mimo",
 			AdapterKind::Nebius => "Nebius",
@@ -100,6 +156,8 @@ impl AdapterKind {
 			AdapterKind::OllamaCloud => "OllamaCloud",
 			AdapterKind::Vertex => "Vertex",
 			AdapterKind::GithubCopilot => "GithubCopilot",
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => "Mock",
 		}
 	}
 
@@ -112,6 +170,12 @@ impl AdapterKind {
 			AdapterKind::Anthropic => "anthropic",
 			AdapterKind::Fireworks => "fireworks",
 			AdapterKind::Together => "together",
+			AdapterKind::AI21 => "ai21",
+			AdapterKind::DeepInfra => "deepinfra",
+			AdapterKind::Hunyuan => "hunyuan",
+			AdapterKind::Qianfan => "qianfan",
+			AdapterKind::StepFun => "stepfun",
+			AdapterKind::Lingyiwanwu => "lingyiwanwu",
 			AdapterKind::Groq => "groq",
 			AdapterKind::Mimo => "mimo",
 			AdapterKind::Nebius => "nebius",
@@ -127,6 +191,8 @@ impl AdapterKind {
 			AdapterKind::OllamaCloud => "ollama_cloud",
 			AdapterKind::Vertex => "vertex",
 			AdapterKind::GithubCopilot => "github_copilot",
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => "mock",
 		}
 	}
 
@@ -138,6 +204,12 @@ impl AdapterKind {
 			"anthropic" => Some(AdapterKind::Anthropic),
 			"fireworks" => Some(AdapterKind::Fireworks),
 			"together" => Some(AdapterKind::Together),
+			"ai21" => Some(AdapterKind::AI21),
+			"deepinfra" => Some(AdapterKind::DeepInfra),
+			"hunyuan" => Some(AdapterKind::Hunyuan),
+			"qianfan" => Some(AdapterKind::Qianfan),
+			"stepfun" => Some(AdapterKind::StepFun),
+			"lingyiwanwu" => Some(AdapterKind::Lingyiwanwu),
 			"groq" => Some(AdapterKind::Groq),
 			"mimo" => Some(AdapterKind::Mimo),
 			"nebius" => Some(AdapterKind::Nebius),
@@ -153,11 +225,72 @@ impl AdapterKind {
 			"ollama_cloud" => Some(AdapterKind::OllamaCloud),
 			"vertex" => Some(AdapterKind::Vertex),
 			"github_copilot" => Some(AdapterKind::GithubCopilot),
+			#[cfg(feature = "mock")]
+			"mock" => Some(AdapterKind::Mock),
 			_ => None,
 		}
 	}
+
+	/// All the namespaces accepted in `namespace::model_name`, as `(lowercase alias, AdapterKind)`.
+	///
+	/// - Every `as_lower_str()` name (e.g., `openai`, `github_copilot`).
+	/// - The `-` spelling of the `_` names (e.g., `openai-resp`, `github-copilot`).
+	/// - The provider spellings `x-ai` (xAI) and `z-ai` (ZAI).
+	/// - `zai-coding`, which routes to the ZAI coding plan endpoint.
+	///
+	/// Namespaces are matched case-insensitively (see `from_namespace`).
+	pub fn namespace_aliases() -> &'static [(&'static str, AdapterKind)] {
+		NAMESPACE_ALIASES
+	}
+
+	/// Returns the adapter kind for a model namespace (case-insensitive), e.g., `X-AI` -> `Xai`.
+	pub fn from_namespace(namespace: &str) -> Option<Self> {
+		NAMESPACE_ALIASES
+			.iter()
+			.find(|(alias, _)| alias.eq_ignore_ascii_case(namespace))
+			.map(|(_, kind)| *kind)
+	}
 }
 
+const NAMESPACE_ALIASES: &[(&str, AdapterKind)] = &[
+	("openai", AdapterKind::OpenAI),
+	("openai_resp", AdapterKind::OpenAIResp),
+	("openai-resp", AdapterKind::OpenAIResp),
+	("gemini", AdapterKind::Gemini),
+	("anthropic", AdapterKind::Anthropic),
+	("fireworks", AdapterKind::Fireworks),
+	("together", AdapterKind::Together),
+	("ai21", AdapterKind::AI21),
+	("deepinfra", AdapterKind::DeepInfra),
+	("hunyuan", AdapterKind::Hunyuan),
+	("qianfan", AdapterKind::Qianfan),
+	("stepfun", AdapterKind::StepFun),
+	("lingyiwanwu", AdapterKind::Lingyiwanwu),
+	("groq", AdapterKind::Groq),
+	("mimo", AdapterKind::Mimo),
+	("nebius", AdapterKind::Nebius),
+	("xai", AdapterKind::Xai),
+	("x-ai", AdapterKind::Xai),
+	("deepseek", AdapterKind::DeepSeek),
+	("zai", AdapterKind::Zai),
+	("z-ai", AdapterKind::Zai),
+	(zai::ZAI_CODING_NAMESPACE, AdapterKind::Zai),
+	("bigmodel", AdapterKind::BigModel),
+	("aliyun", AdapterKind::Aliyun),
+	("cohere", AdapterKind::Cohere),
+	("copilot", AdapterKind::Copilot),
+	("copilot_resp", AdapterKind::CopilotResp),
+	("copilot-resp", AdapterKind::CopilotResp),
+	("ollama", AdapterKind::Ollama),
+	("ollama_cloud", AdapterKind::OllamaCloud),
+	("ollama-cloud", AdapterKind::OllamaCloud),
+	("vertex", AdapterKind::Vertex),
+	("github_copilot", AdapterKind::GithubCopilot),
+	("github-copilot", AdapterKind::GithubCopilot),
+	#[cfg(feature = "mock")]
+	("mock", AdapterKind::Mock),
+];
+
 /// Utilities
 impl AdapterKind {
 	/// Get the default key environment variable name for the adapter kind.
@@ -169,6 +302,12 @@ impl AdapterKind {
 			AdapterKind::Anthropic => AnthropicAdapter::DEFAULT_API_KEY_ENV_NAME,
 			AdapterKind::Fireworks => FireworksAdapter::DEFAULT_API_KEY_ENV_NAME,
 			AdapterKind::Together => TogetherAdapter::DEFAULT_API_KEY_ENV_NAME,
+			AdapterKind::AI21 => AI21Adapter::DEFAULT_API_KEY_ENV_NAME,
+			AdapterKind::DeepInfra => DeepInfraAdapter::DEFAULT_API_KEY_ENV_NAME,
+			AdapterKind::Hunyuan => HunyuanAdapter::DEFAULT_API_KEY_ENV_NAME,
+			AdapterKind::Qianfan => QianfanAdapter::DEFAULT_API_KEY_ENV_NAME,
+			AdapterKind::StepFun => StepFunAdapter::DEFAULT_API_KEY_ENV_NAME,
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::DEFAULT_API_KEY_ENV_NAME,
 			AdapterKind::Groq => GroqAdapter::DEFAULT_API_KEY_ENV_NAME,
 			AdapterKind::Mimo => MimoAdapter::DEFAULT_API_KEY_ENV_NAME,
 			AdapterKind::Nebius => NebiusAdapter::DEFAULT_API_KEY_ENV_NAME,
@@ -184,10 +323,136 @@ impl AdapterKind {
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::DEFAULT_API_KEY_ENV_NAME,
 			AdapterKind::Vertex => VertexAdapter::DEFAULT_API_KEY_ENV_NAME,
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::DEFAULT_API_KEY_ENV_NAME,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => None,
 		}
 	}
 }
 
+/// API Family
+impl AdapterKind {
+	/// Returns the API family (wire protocol) of the adapter.
+	pub fn api_family(&self) -> ApiFamily {
+		match self {
+			AdapterKind::OpenAI
+			| AdapterKind::Fireworks
+			| AdapterKind::Together
+			| AdapterKind::AI21
+			| AdapterKind::DeepInfra
+			| AdapterKind::Hunyuan
+			| AdapterKind::Qianfan
+			| AdapterKind::StepFun
+			| AdapterKind::Lingyiwanwu
+			| AdapterKind::Groq
+			| AdapterKind::Mimo
+			| AdapterKind::Nebius
+			| AdapterKind::Xai
+			| AdapterKind::DeepSeek
+			| AdapterKind::Zai
+			| AdapterKind::BigModel
+			| AdapterKind::Aliyun
+			| AdapterKind::Copilot
+			| AdapterKind::GithubCopilot => ApiFamily::OpenAICompat,
+			AdapterKind::OpenAIResp | AdapterKind::CopilotResp => ApiFamily::OpenAIResponses,
+			AdapterKind::Anthropic => ApiFamily::Anthropic,
+			AdapterKind::Gemini => ApiFamily::Gemini,
+			AdapterKind::Cohere => ApiFamily::CohereV2,
+			AdapterKind::Ollama | AdapterKind::OllamaCloud => ApiFamily::Ollama,
+			AdapterKind::Vertex => ApiFamily::Vertex,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => ApiFamily::Mock,
+		}
+	}
+
+	/// Returns true if the adapter speaks the OpenAI Chat Completions protocol.
+	pub fn is_openai_compat(&self) -> bool {
+		self.api_family() == ApiFamily::OpenAICompat
+	}
+}
+
+/// Service Support
+impl AdapterKind {
+	/// All the adapter kinds, in declaration order.
+	pub const ALL: &'static [AdapterKind] = &[
+		AdapterKind::OpenAI,
+		AdapterKind::OpenAIResp,
+		AdapterKind::Gemini,
+		AdapterKind::Anthropic,
+		AdapterKind::Fireworks,
+		AdapterKind::Together,
+		AdapterKind::AI21,
+		AdapterKind::DeepInfra,
+		AdapterKind::Hunyuan,
+		AdapterKind::Qianfan,
+		AdapterKind::StepFun,
+		AdapterKind::Lingyiwanwu,
+		AdapterKind::Groq,
+		AdapterKind::Mimo,
+		AdapterKind::Nebius,
+		AdapterKind::Xai,
+		AdapterKind::DeepSeek,
+		AdapterKind::Zai,
+		AdapterKind::BigModel,
+		AdapterKind::Aliyun,
+		AdapterKind::Cohere,
+		AdapterKind::Copilot,
+		AdapterKind::CopilotResp,
+		AdapterKind::Ollama,
+		AdapterKind::OllamaCloud,
+		AdapterKind::Vertex,
+		AdapterKind::GithubCopilot,
+		#[cfg(feature = "mock")]
+		AdapterKind::Mock,
+	];
+
+	/// Returns true if the adapter implements the service type.
+	///
+	/// NOTE: This is the service-support registry, and must stay in sync with the adapters
+	///       returning `Error::AdapterNotSupported` for a service.
+	pub fn supports_service(&self, service_type: ServiceType) -> bool {
+		#[cfg(feature = "mock")]
+		if *self == AdapterKind::Mock {
+			return matches!(service_type, ServiceType::Chat | ServiceType::ChatStream);
+		}
+
+		match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => true,
+			ServiceType::Embed => !matches!(
+				self,
+				AdapterKind::OpenAIResp
+					| AdapterKind::Anthropic
+					| AdapterKind::AI21
+					| AdapterKind::StepFun
+					| AdapterKind::Lingyiwanwu
+					| AdapterKind::Groq
+					| AdapterKind::Copilot
+					| AdapterKind::CopilotResp
+					| AdapterKind::Vertex
+			),
+			ServiceType::Models => !matches!(self, AdapterKind::Vertex | AdapterKind::Zai),
+		}
+	}
+
+	/// Returns the other adapter kinds supporting the service type, in declaration order
+	/// (e.g., to suggest an alternative when this one does not support it).
+	pub fn alternatives_for(&self, service_type: ServiceType) -> Vec<AdapterKind> {
+		Self::ALL
+			.iter()
+			.filter(|kind| *kind != self && kind.supports_service(service_type))
+			.copied()
+			.collect()
+	}
+
+	/// Returns true if the adapter accepts a completion-style raw prompt (see `ChatRequest::with_raw_prompt`),
+	/// i.e., the Ollama native `api/generate`, and the Together and Fireworks `completions` endpoints.
+	pub fn supports_raw_prompt(&self) -> bool {
+		matches!(
+			self,
+			AdapterKind::Ollama | AdapterKind::OllamaCloud | AdapterKind::Together | AdapterKind::Fireworks
+		)
+	}
+}
+
 /// From Model implementations
 impl AdapterKind {
 	/// This is a default static mapping from model names to AdapterKind.
@@ -203,11 +468,17 @@ impl AdapterKind {
 	///  - DeepSeek   - model in DeepSeek models (deepseek.com)
 	///  - Zhipu      - starts_with "glm"
 	///  - Cohere     - starts_with "command"
+	///  - AI21       - starts_with "jamba"
+	///  - Hunyuan    - starts_with "hunyuan"
+	///  - Qianfan    - starts_with "ernie"
+	///  - StepFun    - starts_with "step-"
+	///  - Lingyiwanwu - starts_with "yi-lightning", "yi-large", "yi-vision"
 	///  - Ollama     - For anything else
 	///
 	/// Other Some adapters have to have model name namespaced to be used,
 	/// - e.g., for together.ai `together::meta-llama/Llama-3-8b-chat-hf`
 	/// - e.g., for nebius with `nebius::Qwen/Qwen3-235B-A22B`
+	/// - e.g., for deepinfra with `deepinfra::meta-llama/Meta-Llama-3.1-8B-Instruct`
 	/// - e.g., for ZAI coding plan with `coding::glm-4.6`
 	/// - e.g., for vertex with `vertex::gemini-2.5-flash` or `vertex::claude-sonnet-4-6`
 	///
@@ -255,6 +526,16 @@ impl AdapterKind {
 			Ok(Self::Xai)
 		} else if model.starts_with("glm") {
 			Ok(Self::Zai)
+		} else if model.starts_with("jamba") {
+			Ok(Self::AI21)
+		} else if model.starts_with("hunyuan") {
+			Ok(Self::Hunyuan)
+		} else if model.starts_with("ernie") {
+			Ok(Self::Qianfan)
+		} else if model.starts_with("step-") {
+			Ok(Self::StepFun)
+		} else if model.starts_with("yi-lightning") || model.starts_with("yi-large") || model.starts_with("yi-vision") {
+			Ok(Self::Lingyiwanwu)
 		} else if model.starts_with("deepseek-chat") || model.starts_with("deepseek-reasoner") {
 			Ok(Self::DeepSeek)
 		}
@@ -271,23 +552,7 @@ impl AdapterKind {
 impl AdapterKind {
 	fn from_model_namespace(model: &str) -> Option<Self> {
 		let (namespace, _) = ModelName::split_as_namespace_and_name(model);
-		let namespace = namespace?;
-
-		// -- First, check if simple adapter lower string match
-		if let Some(adapter) = Self::from_lower_str(namespace) {
-			Some(adapter)
-		}
-		// -- Second, custom, for now, we harcode this exceptin here (might become more generic later)
-		else if namespace == "github_copilot" {
-			Some(Self::Copilot)
-		} else if namespace == zai::ZAI_CODING_NAMESPACE {
-			Some(Self::Zai)
-		}
-		//
-		// -- Otherwise, no adapter from namespace, because no matching namespace
-		else {
-			None
-		}
+		Self::from_namespace(namespace?)
 	}
 }
 
@@ -302,4 +567,205 @@ mod tests {
 		let adapter = AdapterKind::from_model("copilot_resp::gpt-4o").expect("namespaced model should resolve");
 		assert_eq!(adapter, AdapterKind::CopilotResp);
 	}
+
+	#[test]
+	fn test_jamba_model_maps_to_ai21_adapter() {
+		assert_eq!(AdapterKind::from_model("jamba-1.5-large").ok(), Some(AdapterKind::AI21));
+		assert_eq!(
+			AdapterKind::from_model("ai21::jamba-mini").ok(),
+			Some(AdapterKind::AI21)
+		);
+	}
+
+	#[test]
+	fn test_hunyuan_and_ernie_models_map_to_their_adapters() {
+		assert_eq!(
+			AdapterKind::from_model("hunyuan-turbo").ok(),
+			Some(AdapterKind::Hunyuan)
+		);
+		assert_eq!(AdapterKind::from_model("ernie-4.0-8k").ok(), Some(AdapterKind::Qianfan));
+		assert_eq!(
+			AdapterKind::from_model("qianfan::ernie-4.5-turbo-128k").ok(),
+			Some(AdapterKind::Qianfan)
+		);
+	}
+
+	#[test]
+	fn test_step_and_yi_models_map_to_their_adapters() {
+		assert_eq!(AdapterKind::from_model("step-2-16k").ok(), Some(AdapterKind::StepFun));
+		assert_eq!(
+			AdapterKind::from_model("yi-lightning").ok(),
+			Some(AdapterKind::Lingyiwanwu)
+		);
+		assert_eq!(
+			AdapterKind::from_model("lingyiwanwu::yi-large").ok(),
+			Some(AdapterKind::Lingyiwanwu)
+		);
+		// Ollama tags for the open Yi models stay on Ollama
+		assert_eq!(AdapterKind::from_model("yi-coder:9b").ok(), Some(AdapterKind::Ollama));
+	}
+
+	#[test]
+	fn test_namespace_aliases_all_resolve_case_insensitive() {
+		for (alias, kind) in AdapterKind::namespace_aliases() {
+			let model = format!("{alias}::some-model");
+			assert_eq!(AdapterKind::from_model(&model).ok(), Some(*kind), "alias '{alias}'");
+
+			let model = format!("{}::some-model", alias.to_uppercase());
+			assert_eq!(
+				AdapterKind::from_model(&model).ok(),
+				Some(*kind),
+				"alias '{alias}' uppercase"
+			);
+
+			assert_eq!(alias.to_lowercase(), *alias, "aliases are published lowercase");
+		}
+	}
+
+	#[test]
+	fn test_namespace_aliases_cover_every_lower_str() {
+		// Exhaustive match, so a new AdapterKind fails to compile until added below.
+		let all_kinds = |kind: AdapterKind| match kind {
+			AdapterKind::OpenAI
+			| AdapterKind::OpenAIResp
+			| AdapterKind::Gemini
+			| AdapterKind::Anthropic
+			| AdapterKind::Fireworks
+			| AdapterKind::Together
+			| AdapterKind::AI21
+			| AdapterKind::DeepInfra
+			| AdapterKind::Hunyuan
+			| AdapterKind::Qianfan
+			| AdapterKind::StepFun
+			| AdapterKind::Lingyiwanwu
+			| AdapterKind::Groq
+			| AdapterKind::Mimo
+			| AdapterKind::Nebius
+			| AdapterKind::Xai
+			| AdapterKind::DeepSeek
+			| AdapterKind::Zai
+			| AdapterKind::BigModel
+			| AdapterKind::Aliyun
+			| AdapterKind::Cohere
+			| AdapterKind::Copilot
+			| AdapterKind::CopilotResp
+			| AdapterKind::Ollama
+			| AdapterKind::OllamaCloud
+			| AdapterKind::Vertex
+			| AdapterKind::GithubCopilot => kind,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => kind,
+		};
+		let kinds = [
+			AdapterKind::OpenAI,
+			AdapterKind::OpenAIResp,
+			AdapterKind::Gemini,
+			AdapterKind::Anthropic,
+			AdapterKind::Fireworks,
+			AdapterKind::Together,
+			AdapterKind::AI21,
+			AdapterKind::DeepInfra,
+			AdapterKind::Hunyuan,
+			AdapterKind::Qianfan,
+			AdapterKind::StepFun,
+			AdapterKind::Lingyiwanwu,
+			AdapterKind::Groq,
+			AdapterKind::Mimo,
+			AdapterKind::Nebius,
+			AdapterKind::Xai,
+			AdapterKind::DeepSeek,
+			AdapterKind::Zai,
+			AdapterKind::BigModel,
+			AdapterKind::Aliyun,
+			AdapterKind::Cohere,
+			AdapterKind::Copilot,
+			AdapterKind::CopilotResp,
+			AdapterKind::Ollama,
+			AdapterKind::OllamaCloud,
+			AdapterKind::Vertex,
+			AdapterKind::GithubCopilot,
+		]
+		.map(all_kinds);
+
+		for kind in kinds {
+			assert_eq!(AdapterKind::from_namespace(kind.as_lower_str()), Some(kind));
+		}
+	}
+
+	#[test]
+	fn test_adapter_kind_api_family() {
+		let cases = [
+			(AdapterKind::OpenAI, ApiFamily::OpenAICompat),
+			(AdapterKind::OpenAIResp, ApiFamily::OpenAIResponses),
+			(AdapterKind::Gemini, ApiFamily::Gemini),
+			(AdapterKind::Anthropic, ApiFamily::Anthropic),
+			(AdapterKind::Fireworks, ApiFamily::OpenAICompat),
+			(AdapterKind::Together, ApiFamily::OpenAICompat),
+			(AdapterKind::AI21, ApiFamily::OpenAICompat),
+			(AdapterKind::DeepInfra, ApiFamily::OpenAICompat),
+			(AdapterKind::Hunyuan, ApiFamily::OpenAICompat),
+			(AdapterKind::Qianfan, ApiFamily::OpenAICompat),
+			(AdapterKind::StepFun, ApiFamily::OpenAICompat),
+			(AdapterKind::Lingyiwanwu, ApiFamily::OpenAICompat),
+			(AdapterKind::Groq, ApiFamily::OpenAICompat),
+			(AdapterKind::Mimo, ApiFamily::OpenAICompat),
+			(AdapterKind::Nebius, ApiFamily::OpenAICompat),
+			(AdapterKind::Xai, ApiFamily::OpenAICompat),
+			(AdapterKind::DeepSeek, ApiFamily::OpenAICompat),
+			(AdapterKind::Zai, ApiFamily::OpenAICompat),
+			(AdapterKind::BigModel, ApiFamily::OpenAICompat),
+			(AdapterKind::Aliyun, ApiFamily::OpenAICompat),
+			(AdapterKind::Cohere, ApiFamily::CohereV2),
+			(AdapterKind::Copilot, ApiFamily::OpenAICompat),
+			(AdapterKind::CopilotResp, ApiFamily::OpenAIResponses),
+			(AdapterKind::Ollama, ApiFamily::Ollama),
+			(AdapterKind::OllamaCloud, ApiFamily::Ollama),
+			(AdapterKind::Vertex, ApiFamily::Vertex),
+			(AdapterKind::GithubCopilot, ApiFamily::OpenAICompat),
+			#[cfg(feature = "mock")]
+			(AdapterKind::Mock, ApiFamily::Mock),
+		];
+
+		assert_eq!(cases.len(), AdapterKind::ALL.len(), "every adapter kind has a case");
+		for (kind, family) in cases {
+			assert_eq!(kind.api_family(), family, "{kind}");
+			assert_eq!(kind.is_openai_compat(), family == ApiFamily::OpenAICompat, "{kind}");
+		}
+	}
+
+	#[test]
+	fn test_adapter_kind_alternatives_for_service() {
+		assert_eq!(
+			AdapterKind::ALL.len(),
+			NAMESPACE_ALIASES
+				.iter()
+				.filter(|(alias, kind)| *alias == kind.as_lower_str())
+				.count()
+		);
+		assert!(!AdapterKind::Groq.supports_service(ServiceType::Embed));
+		assert!(AdapterKind::Groq.supports_service(ServiceType::Chat));
+
+		let alternatives = AdapterKind::Groq.alternatives_for(ServiceType::Embed);
+		assert_eq!(alternatives.first(), Some(&AdapterKind::OpenAI));
+		assert!(alternatives.contains(&AdapterKind::Cohere));
+		assert!(alternatives.iter().all(|kind| kind.supports_service(ServiceType::Embed)));
+		assert!(!alternatives.contains(&AdapterKind::Groq));
+	}
+
+	#[test]
+	fn test_namespace_copilot_vs_github_copilot() {
+		assert_eq!(
+			AdapterKind::from_model("copilot::gpt-4o").ok(),
+			Some(AdapterKind::Copilot)
+		);
+		assert_eq!(
+			AdapterKind::from_model("github_copilot::openai/gpt-4.1-mini").ok(),
+			Some(AdapterKind::GithubCopilot)
+		);
+		assert_eq!(
+			AdapterKind::from_model("GitHub-Copilot::openai/gpt-4.1-mini").ok(),
+			Some(AdapterKind::GithubCopilot)
+		);
+		assert_eq!(AdapterKind::from_namespace("unknown"), None);
+	}
 }
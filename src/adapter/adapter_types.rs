@@ -2,7 +2,7 @@ use crate::Headers;
 use crate::Model;
 use crate::ModelIden;
 use crate::adapter::AdapterKind;
-use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse, IgnoredOption};
 use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{WebClient, WebResponse};
@@ -64,7 +64,8 @@ pub trait Adapter {
 
 // region:    --- ServiceType
 
-#[derive(Debug, Clone, Copy)]
+/// The kind of service call made to a provider (used to select the service url path).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ServiceType {
 	Chat,
 	ChatStream,
@@ -82,6 +83,8 @@ pub struct WebRequestData {
 	pub url: String,
 	pub headers: Headers,
 	pub payload: Value,
+	/// The requested chat options the adapter could not honor (see `ChatResponse::ignored_options`).
+	pub ignored_options: Vec<IgnoredOption>,
 }
 
 // endregion: --- WebRequestData
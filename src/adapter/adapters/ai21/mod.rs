@@ -0,0 +1,11 @@
+//! API Documentation:     <https://docs.ai21.com/reference/jamba-1-6-api-ref>
+//! Model Names:           <https://docs.ai21.com/docs/jamba-foundation-models>
+//! Pricing:               <https://www.ai21.com/pricing/>
+
+// region:    --- Modules
+
+mod adapter_impl;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
@@ -1,5 +1,6 @@
 // region:    --- AliyunAdapter
 
+use crate::adapter::adapters::support::join_service_url;
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
@@ -78,14 +79,12 @@ impl Adapter for AliyunAdapter {
 	///
 	/// Since Aliyun Dashscope API is OpenAI-compatible, we use the OpenAI URL pattern.
 	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
-		let base_url = endpoint.base_url();
-
-		let url = match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}chat/completions"),
-			ServiceType::Embed => format!("{base_url}embeddings"),
-			ServiceType::Models => format!("{base_url}models"),
+		let suffix = match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => "chat/completions",
+			ServiceType::Embed => "embeddings",
+			ServiceType::Models => "models",
 		};
-		Ok(url)
+		join_service_url(endpoint.base_url(), suffix)
 	}
 
 	async fn all_models(
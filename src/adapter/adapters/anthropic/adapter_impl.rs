@@ -1,25 +1,27 @@
 use crate::Model;
 use crate::adapter::ModelCapabilities;
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, ignored_options, join_service_url, with_model_deprecation};
 use crate::adapter::anthropic::AnthropicStreamer;
-use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::adapter::{Adapter, AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
 	Binary, BinarySource, CacheControl, CacheCreationDetails, ChatOptionsSet, ChatRequest, ChatResponse,
-	ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse, ContentPart, MessageContent, PromptTokensDetails,
-	ReasoningEffort, StopReason, Tool, ToolCall, ToolConfig, ToolName, Usage,
+	ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse, ContentPart, IgnoredOption, MessageContent,
+	PromptTokensDetails, ReasoningEffort, StopReason, Tool, ToolCall, ToolChoice, ToolConfig, ToolName, ToolResponse,
+	Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{EventSourceStream, WebResponse};
 use crate::{Error, Headers, ModelIden};
 use crate::{Result, ServiceTarget};
 use reqwest::RequestBuilder;
+use reqwest::header::HeaderMap;
 use serde_json::{Map, Value, json};
 use tracing::info;
 use tracing::warn;
 use value_ext::JsonValueExt;
 
 pub struct AnthropicAdapter;
-const MODELS: &[&str] = &[
+pub(in crate::adapter) const MODELS: &[&str] = &[
 	"claude-sonnet-4-20250514",
 	"claude-opus-4-20250514",
 	"claude-3-7-sonnet-20250219",
@@ -27,6 +29,22 @@ const MODELS: &[&str] = &[
 	"claude-3-5-haiku-20241022",
 ];
 
+/// The `ChatOptions` payload options sent by the Anthropic adapter (the others are reported as ignored).
+const SUPPORTED_OPTIONS: &[&str] = &[
+	"temperature",
+	"max_tokens",
+	"top_p",
+	"top_k",
+	"stop_sequences",
+	"response_format",
+	"reasoning_effort",
+	"tool_choice",
+	"parallel_tool_calls",
+	"cache_control",
+	"anthropic_version",
+	"anthropic_betas",
+];
+
 const REASONING_LOW: u32 = 1024;
 const REASONING_MEDIUM: u32 = 8000;
 const REASONING_HIGH: u32 = 24000;
@@ -136,30 +154,56 @@ pub(in crate::adapter) const MAX_TOKENS_32K: u32 = 32000; // claude-opus-4
 pub(in crate::adapter) const MAX_TOKENS_8K: u32 = 8192; // claude-3-5-sonnet, claude-3-5-haiku
 pub(in crate::adapter) const MAX_TOKENS_4K: u32 = 4096; // claude-3-opus, claude-3-haiku
 
-const ANTHROPIC_VERSION: &str = "2023-06-01";
+/// Default `anthropic-version` header value (override with `ChatOptions::with_anthropic_version(..)`).
+pub const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Default `anthropic-beta` values, always sent before the `ChatOptions::anthropic_betas` ones.
+pub const ANTHROPIC_BETAS: &[&str] = &[];
 
 impl AnthropicAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "ANTHROPIC_API_KEY";
 	pub const BASE_URL_DEFAULT_ENV_NAME: &str = "ANTHROPIC_BASE_URL";
 
+	/// Builds the `x-api-key`, `anthropic-version`, and `anthropic-beta` headers.
+	///
+	/// The betas are the `ANTHROPIC_BETAS` defaults followed by the given ones (comma-separated values
+	/// are split), deduped in order. The `anthropic-beta` header is omitted when the list is empty.
+	pub(in crate::adapter) fn anthropic_headers(api_key: String, version: Option<&str>, betas: &[&str]) -> Headers {
+		let mut merged_betas: Vec<&str> = Vec::new();
+		let custom_betas = betas.iter().flat_map(|beta| beta.split(','));
+		for beta in ANTHROPIC_BETAS.iter().copied().chain(custom_betas) {
+			let beta = beta.trim();
+			if !beta.is_empty() && !merged_betas.contains(&beta) {
+				merged_betas.push(beta);
+			}
+		}
+
+		let mut headers = vec![
+			("x-api-key".to_string(), api_key),
+			(
+				"anthropic-version".to_string(),
+				version.unwrap_or(ANTHROPIC_VERSION).to_string(),
+			),
+		];
+		if !merged_betas.is_empty() {
+			headers.push(("anthropic-beta".to_string(), merged_betas.join(",")));
+		}
+
+		Headers::from(headers)
+	}
+
 	pub(in crate::adapter::adapters) async fn list_model_names_for_end_target(
 		kind: AdapterKind,
 		endpoint: Endpoint,
 		auth: AuthData,
 	) -> Result<Vec<String>> {
 		// -- url
-		let base_url = endpoint.base_url();
-		let url = format!("{base_url}models");
+		let url = AdapterDispatcher::get_service_url(&ModelIden::new(kind, ""), ServiceType::Models, endpoint)?;
 
 		// -- auth / headers
 		let api_key = auth.single_key_value().ok();
 		let headers = api_key
-			.map(|api_key| {
-				Headers::from(vec![
-					("x-api-key".to_string(), api_key),
-					("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
-				])
-			})
+			.map(|api_key| Self::anthropic_headers(api_key, None, &[]))
 			.unwrap_or_default();
 
 		// -- Exec request
@@ -231,10 +275,7 @@ impl Adapter for AnthropicAdapter {
 		let api_key = get_api_key(auth, &model_iden)?;
 
 		// 构建请求头 - Anthropic 使用不同的认证头格式
-		let headers = Headers::from(vec![
-			("x-api-key".to_string(), api_key),
-			("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
-		]);
+		let headers = Self::anthropic_headers(api_key, None, &[]);
 
 		// 使用传入的 WebClient 发送请求
 		let web_response = web_client
@@ -307,14 +348,13 @@ impl Adapter for AnthropicAdapter {
 	}
 
 	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
-		let base_url = endpoint.base_url();
-		let url = match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}messages"),
-			ServiceType::Embed => format!("{base_url}embeddings"), // Anthropic doesn't support embeddings yet
-			ServiceType::Models => format!("{base_url}models"),
+		let suffix = match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => "messages",
+			ServiceType::Embed => "embeddings", // Anthropic doesn't support embeddings yet
+			ServiceType::Models => "models",
 		};
 
-		Ok(url)
+		join_service_url(endpoint.base_url(), suffix)
 	}
 
 	fn to_web_request_data(
@@ -332,10 +372,8 @@ impl Adapter for AnthropicAdapter {
 		let url = Self::get_service_url(&model, service_type, endpoint)?;
 
 		// -- headers
-		let headers = Headers::from(vec![
-			("x-api-key".to_string(), api_key),
-			("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
-		]);
+		let betas = options_set.anthropic_betas().unwrap_or_default();
+		let headers = Self::anthropic_headers(api_key, options_set.anthropic_version(), &betas);
 
 		// -- Parts
 		let AnthropicRequestParts {
@@ -390,6 +428,10 @@ impl Adapter for AnthropicAdapter {
 
 		if let Some(tools) = tools {
 			payload.x_insert("/tools", tools)?;
+			if let Some(tool_choice) = tool_choice_to_json(options_set.tool_choice(), options_set.parallel_tool_calls())
+			{
+				payload.x_insert("tool_choice", tool_choice)?;
+			}
 		}
 
 		// -- Set the reasoning effort
@@ -401,10 +443,22 @@ impl Adapter for AnthropicAdapter {
 			insert_anthropic_reasoning(&mut payload, &mut output_config, model_name, &computed_reasoning_effort)?;
 		}
 
+		// -- Ignored options
+		let mut ignored_options = ignored_options(&options_set, SUPPORTED_OPTIONS, AdapterKind::Anthropic);
 		if let Some(cache_control) = options_set.cache_control() {
 			info!(
 				"Anthropic request-level cache_control '{cache_control:?}' is currently ignored. Use message-level cache_control instead."
 			);
+			ignored_options.push(IgnoredOption::new(
+				"cache_control",
+				"request-level cache_control is not supported by Anthropic (use message-level cache_control)",
+			));
+		}
+		if let Some(ChatResponseFormat::JsonMode) = options_set.response_format() {
+			ignored_options.push(IgnoredOption::new(
+				"response_format",
+				"JsonMode is not supported by Anthropic (use JsonSpec)",
+			));
 		}
 
 		// -- Add supported ChatOptions
@@ -440,7 +494,16 @@ impl Adapter for AnthropicAdapter {
 			payload.x_insert("top_p", top_p)?;
 		}
 
-		Ok(WebRequestData { url, headers, payload })
+		if let Some(top_k) = options_set.top_k() {
+			payload.x_insert("top_k", top_k)?;
+		}
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -448,15 +511,24 @@ impl Adapter for AnthropicAdapter {
 		web_response: WebResponse,
 		_options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatResponse> {
-		let WebResponse { mut body, .. } = web_response;
+		let WebResponse { mut body, headers, .. } = web_response;
+
+		// -- Capture the provider timing (the rate limit and overload headers)
+		let provider_timing = Self::provider_timing(&headers);
 
 		// -- Capture the provider_model_iden
 		// TODO: Need to be implemented (if available), for now, just clone model_iden
 		let provider_model_name: Option<String> = body.x_remove("model").ok();
 		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
 
-		// -- Capture the usage
+		// -- Capture the usage (and the service tier used, reported in the usage)
 		let usage = body.x_take::<Value>("usage");
+		let service_tier = usage
+			.as_ref()
+			.ok()
+			.and_then(|usage| usage.get("service_tier"))
+			.and_then(Value::as_str)
+			.map(String::from);
 
 		let usage = usage.map(Self::into_usage).unwrap_or_default();
 		let stop_reason = body
@@ -512,6 +584,8 @@ impl Adapter for AnthropicAdapter {
 			None
 		};
 
+		let usage_reported = !usage.is_empty();
+
 		Ok(ChatResponse {
 			content,
 			reasoning_content,
@@ -519,8 +593,15 @@ impl Adapter for AnthropicAdapter {
 			provider_model_iden,
 			stop_reason,
 			usage,
+			usage_reported,
 			captured_raw_body: None, // Set by the client exec_chat
 			response_id: None,
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier,
+			provider_timing,
 		})
 	}
 
@@ -529,12 +610,15 @@ impl Adapter for AnthropicAdapter {
 		reqwest_builder: RequestBuilder,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let event_source = EventSourceStream::new(reqwest_builder);
+		let event_source =
+			EventSourceStream::new(reqwest_builder).with_lossy_utf8(options_set.lossy_utf8().unwrap_or(true));
 		let anthropic_stream = AnthropicStreamer::new(event_source, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(anthropic_stream);
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -543,10 +627,7 @@ impl Adapter for AnthropicAdapter {
 		_embed_req: crate::embed::EmbedRequest,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::adapter::WebRequestData> {
-		Err(crate::Error::AdapterNotSupported {
-			adapter_kind: crate::adapter::AdapterKind::Anthropic,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Anthropic, ServiceType::Embed))
 	}
 
 	fn to_embed_response(
@@ -554,10 +635,7 @@ impl Adapter for AnthropicAdapter {
 		_web_response: crate::webc::WebResponse,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::embed::EmbedResponse> {
-		Err(crate::Error::AdapterNotSupported {
-			adapter_kind: crate::adapter::AdapterKind::Anthropic,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Anthropic, ServiceType::Embed))
 	}
 }
 
@@ -604,6 +682,9 @@ impl AnthropicAdapter {
 			.with_input_modalities(input_modalities)
 			.with_output_modalities(output_modalities);
 
+		// 弃用信息（如果 API 返回）
+		let model = with_model_deprecation(model, &model_data);
+
 		Ok(model)
 	}
 
@@ -632,6 +713,21 @@ impl AnthropicAdapter {
 		})
 	}
 
+	/// Returns the `anthropic-ratelimit-*`, `retry-after`, and `x-should-retry` (overload hint) response headers,
+	/// as a JSON object of the header values, when present.
+	pub(in crate::adapter) fn provider_timing(headers: &HeaderMap) -> Option<Value> {
+		let timing: Map<String, Value> = headers
+			.iter()
+			.filter(|(name, _)| {
+				let name = name.as_str();
+				name.starts_with("anthropic-ratelimit-") || name == "retry-after" || name == "x-should-retry"
+			})
+			.filter_map(|(name, value)| Some((name.to_string(), Value::from(value.to_str().ok()?))))
+			.collect();
+
+		(!timing.is_empty()).then_some(Value::Object(timing))
+	}
+
 	pub(in crate::adapter) fn into_usage(mut usage_value: Value) -> Usage {
 		// IMPORTANT: For Anthropic, the `input_tokens` does not include `cache_creation_input_tokens` or `cache_read_input_tokens`.
 		// Therefore, it must be normalized in the OpenAI style, where it includes both cached and written tokens (for symmetry).
@@ -715,7 +811,7 @@ impl AnthropicAdapter {
 
 			match msg.role {
 				// Collect only text for system; other content parts are ignored by Anthropic here.
-				ChatRole::System => {
+				ChatRole::System | ChatRole::Developer => {
 					if let Some(system_text) = msg.content.joined_texts() {
 						systems.push((system_text, cache_control));
 					}
@@ -786,11 +882,7 @@ impl AnthropicAdapter {
 								// ToolCall is not valid in user content for Anthropic; skip gracefully.
 								ContentPart::ToolCall(_tc) => {}
 								ContentPart::ToolResponse(tool_response) => {
-									values.push(json!({
-										"type": "tool_result",
-										"content": tool_response.content,
-										"tool_use_id": tool_response.call_id,
-									}));
+									values.push(tool_result_value(tool_response));
 								}
 								ContentPart::ThoughtSignature(_) => {}
 								ContentPart::ReasoningContent(_) => {}
@@ -864,11 +956,7 @@ impl AnthropicAdapter {
 					let mut values: Vec<Value> = Vec::new();
 					for part in msg.content {
 						if let ContentPart::ToolResponse(tool_response) = part {
-							values.push(json!({
-								"type": "tool_result",
-								"content": tool_response.content,
-								"tool_use_id": tool_response.call_id,
-							}));
+							values.push(tool_result_value(tool_response));
 						}
 					}
 					if !values.is_empty() {
@@ -985,6 +1073,27 @@ impl AnthropicAdapter {
 	}
 }
 
+/// Converts the tool choice and parallel tool calls options to the Anthropic `tool_choice`
+/// (`None` when both are unset, to keep the provider default).
+///
+/// See: https://docs.anthropic.com/en/docs/agents-and-tools/tool-use/implement-tool-use#forcing-tool-use
+fn tool_choice_to_json(tool_choice: Option<&ToolChoice>, parallel_tool_calls: Option<bool>) -> Option<Value> {
+	let disable_parallel_tool_use = parallel_tool_calls == Some(false);
+	let mut tool_choice = match tool_choice {
+		// `none` does not accept `disable_parallel_tool_use`
+		Some(ToolChoice::None) => return Some(json!({"type": "none"})),
+		Some(ToolChoice::Auto) => json!({"type": "auto"}),
+		Some(ToolChoice::Required) => json!({"type": "any"}),
+		Some(ToolChoice::Tool(name)) => json!({"type": "tool", "name": name}),
+		None if disable_parallel_tool_use => json!({"type": "auto"}),
+		None => return None,
+	};
+	if disable_parallel_tool_use {
+		tool_choice["disable_parallel_tool_use"] = true.into();
+	}
+	Some(tool_choice)
+}
+
 /// Convert CacheControl to Anthropic JSON format.
 ///
 /// See: https://docs.anthropic.com/en/docs/build-with-claude/prompt-caching#1-hour-cache-duration
@@ -1066,6 +1175,19 @@ fn apply_cache_control_to_parts(cache_control: Option<&CacheControl>, parts: Vec
 	parts
 }
 
+/// Builds the `tool_result` block, with `is_error: true` for a failed tool execution.
+fn tool_result_value(tool_response: ToolResponse) -> Value {
+	let mut value = json!({
+		"type": "tool_result",
+		"content": tool_response.content,
+		"tool_use_id": tool_response.call_id,
+	});
+	if tool_response.is_error {
+		value["is_error"] = true.into();
+	}
+	value
+}
+
 pub(in crate::adapter) struct AnthropicRequestParts {
 	pub system: Option<Value>,
 	pub messages: Vec<Value>,
@@ -1081,7 +1203,7 @@ mod tests {
 	use super::*;
 	use crate::ServiceTarget;
 	use crate::adapter::{Adapter, ServiceType};
-	use crate::chat::{ChatOptions, ChatRequest, JsonSpec};
+	use crate::chat::{ChatMessage, ChatOptions, ChatRequest, JsonSpec};
 	use crate::resolver::AuthData;
 
 	/// Regression guard: when both `reasoning_effort` and `JsonSpec` response format are set
@@ -1128,6 +1250,157 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_tool_result_is_error() -> Result<()> {
+		let chat_req = ChatRequest::from_user("What is the weather?").append_message(ChatMessage::from(vec![
+			ToolResponse::new("call_ok", "sunny"),
+			ToolResponse::error("call_err", "city not found"),
+		]));
+
+		let parts = AnthropicAdapter::into_anthropic_request_parts(chat_req)?;
+
+		let tool_results = &parts.messages[1]["content"];
+		assert_eq!(tool_results[0]["tool_use_id"], "call_ok");
+		assert!(tool_results[0].get("is_error").is_none());
+		assert_eq!(tool_results[1]["tool_use_id"], "call_err");
+		assert_eq!(tool_results[1]["content"], "city not found");
+		assert_eq!(tool_results[1]["is_error"], true);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_choice_to_json() {
+		let weather = ToolChoice::tool("get_weather");
+		let cases: &[(Option<&ToolChoice>, Option<bool>, Option<Value>)] = &[
+			(None, None, None),
+			(None, Some(true), None),
+			(
+				None,
+				Some(false),
+				Some(json!({"type": "auto", "disable_parallel_tool_use": true})),
+			),
+			(Some(&ToolChoice::Auto), None, Some(json!({"type": "auto"}))),
+			(Some(&ToolChoice::Required), None, Some(json!({"type": "any"}))),
+			(Some(&ToolChoice::None), Some(false), Some(json!({"type": "none"}))),
+			(
+				Some(&weather),
+				None,
+				Some(json!({"type": "tool", "name": "get_weather"})),
+			),
+			(
+				Some(&weather),
+				Some(false),
+				Some(json!({"type": "tool", "name": "get_weather", "disable_parallel_tool_use": true})),
+			),
+		];
+
+		for (tool_choice, parallel_tool_calls, expected) in cases {
+			assert_eq!(
+				&tool_choice_to_json(*tool_choice, *parallel_tool_calls),
+				expected,
+				"for {tool_choice:?} / {parallel_tool_calls:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_tool_choice_sent_with_tools_only() -> Result<()> {
+		let target = ServiceTarget {
+			endpoint: AnthropicAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+		};
+		let options = ChatOptions::default()
+			.with_tool_choice(ToolChoice::tool("get_weather"))
+			.with_parallel_tool_calls(false);
+		let chat_req = ChatRequest::from_user("What is the weather in Paris?");
+
+		// -- Without tools
+		let data = AnthropicAdapter::to_web_request_data(
+			target.clone(),
+			ServiceType::Chat,
+			chat_req.clone(),
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		)?;
+		assert!(data.payload.get("tool_choice").is_none());
+
+		// -- With tools
+		let data = AnthropicAdapter::to_web_request_data(
+			target,
+			ServiceType::Chat,
+			chat_req.with_tools(vec![Tool::new("get_weather")]),
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		)?;
+		assert_eq!(
+			data.payload["tool_choice"],
+			json!({"type": "tool", "name": "get_weather", "disable_parallel_tool_use": true})
+		);
+
+		Ok(())
+	}
+
+	fn header_value<'a>(headers: &'a Headers, name: &str) -> Option<&'a str> {
+		headers.iter().find(|(k, _)| k.as_str() == name).map(|(_, v)| v.as_str())
+	}
+
+	#[test]
+	fn test_anthropic_headers_defaults() {
+		let headers = AnthropicAdapter::anthropic_headers("test-key".to_string(), None, &[]);
+
+		assert_eq!(header_value(&headers, "x-api-key"), Some("test-key"));
+		assert_eq!(header_value(&headers, "anthropic-version"), Some(ANTHROPIC_VERSION));
+		assert_eq!(header_value(&headers, "anthropic-beta"), None);
+	}
+
+	#[test]
+	fn test_anthropic_headers_per_service_type() -> Result<()> {
+		let target = ServiceTarget {
+			endpoint: AnthropicAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+		};
+		let client_options = ChatOptions::default()
+			.with_anthropic_version("2099-01-01")
+			.with_anthropic_betas(vec!["output-128k-2025-02-19".to_string()]);
+		let chat_options = ChatOptions::default().with_anthropic_betas(vec![
+			"token-counting-2024-11-01, computer-use-2025-01-24".to_string(),
+			"token-counting-2024-11-01".to_string(),
+		]);
+
+		for service_type in [ServiceType::Chat, ServiceType::ChatStream] {
+			// -- Client options only
+			let data = AnthropicAdapter::to_web_request_data(
+				target.clone(),
+				service_type,
+				ChatRequest::from_user("Hello"),
+				ChatOptionsSet::default().with_client_options(Some(&client_options)),
+			)?;
+			assert_eq!(header_value(&data.headers, "anthropic-version"), Some("2099-01-01"));
+			assert_eq!(
+				header_value(&data.headers, "anthropic-beta"),
+				Some("output-128k-2025-02-19")
+			);
+
+			// -- Chat betas added to the client ones, split and deduped
+			let data = AnthropicAdapter::to_web_request_data(
+				target.clone(),
+				service_type,
+				ChatRequest::from_user("Hello"),
+				ChatOptionsSet::default()
+					.with_client_options(Some(&client_options))
+					.with_chat_options(Some(&chat_options)),
+			)?;
+			assert_eq!(header_value(&data.headers, "anthropic-version"), Some("2099-01-01"));
+			assert_eq!(
+				header_value(&data.headers, "anthropic-beta"),
+				Some("output-128k-2025-02-19,token-counting-2024-11-01,computer-use-2025-01-24")
+			);
+		}
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_cache_control_to_json_ephemeral() {
 		let result = cache_control_to_json(&CacheControl::Ephemeral);
@@ -1201,6 +1474,46 @@ mod tests {
 		let result = parse_cache_creation_details(&cache_creation);
 		assert!(result.is_none());
 	}
+
+	#[test]
+	fn test_to_chat_response_service_tier_and_provider_timing() -> Result<()> {
+		let mut headers = HeaderMap::new();
+		for (name, value) in [
+			("anthropic-ratelimit-requests-remaining", "49"),
+			("anthropic-ratelimit-tokens-reset", "2026-10-16T12:00:30Z"),
+			("retry-after", "2"),
+			("request-id", "req_01abc"),
+		] {
+			headers.insert(name, value.parse().expect("valid header value"));
+		}
+		let web_response = WebResponse {
+			status: reqwest::StatusCode::OK,
+			headers,
+			body: json!({
+				"id": "msg_01abc",
+				"model": "claude-sonnet-4-20250514",
+				"stop_reason": "end_turn",
+				"content": [{"type": "text", "text": "hello"}],
+				"usage": {"input_tokens": 12, "output_tokens": 3, "service_tier": "priority"}
+			}),
+		};
+		let model = ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-20250514");
+
+		let response = AnthropicAdapter::to_chat_response(model, web_response, ChatOptionsSet::default())?;
+
+		assert_eq!(response.service_tier.as_deref(), Some("priority"));
+		assert_eq!(
+			response.provider_timing,
+			Some(json!({
+				"anthropic-ratelimit-requests-remaining": "49",
+				"anthropic-ratelimit-tokens-reset": "2026-10-16T12:00:30Z",
+				"retry-after": "2"
+			}))
+		);
+		assert_eq!(response.usage.total_tokens, Some(15));
+
+		Ok(())
+	}
 }
 
 // endregion: --- Tests
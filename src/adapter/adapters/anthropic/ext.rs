@@ -0,0 +1,321 @@
+//! Anthropic provider extension - Message Batches listing and Admin API usage report.
+//!
+//! Usage: `client.provider_ext::<AnthropicExt>()?`
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::{get_api_key, join_service_url};
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::resolver::{AuthData, Endpoint};
+use crate::{Client, Error, ModelIden, ProviderExt, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// region:    --- AnthropicExt
+
+/// Anthropic-specific APIs, created with `client.provider_ext::<AnthropicExt>()`.
+///
+/// - `list_batches` uses the regular Anthropic auth (AuthResolver or `ANTHROPIC_API_KEY`).
+/// - `get_usage_report` requires an Admin API key (`sk-ant-admin...`), read from `ANTHROPIC_ADMIN_KEY`
+///   by default, or set with `with_admin_auth(..)`.
+///
+/// The endpoint is resolved like for chat requests, so a `ServiceTargetResolver` endpoint applies to both.
+#[derive(Debug, Clone)]
+pub struct AnthropicExt<'a> {
+	client: &'a Client,
+	admin_auth: AuthData,
+}
+
+impl<'a> ProviderExt<'a> for AnthropicExt<'a> {
+	const ADAPTER_KIND: AdapterKind = AdapterKind::Anthropic;
+
+	fn from_client(client: &'a Client) -> Result<Self> {
+		Ok(Self {
+			client,
+			admin_auth: AuthData::from_env(Self::ADMIN_KEY_DEFAULT_ENV_NAME),
+		})
+	}
+}
+
+/// Builders
+impl AnthropicExt<'_> {
+	pub const ADMIN_KEY_DEFAULT_ENV_NAME: &'static str = "ANTHROPIC_ADMIN_KEY";
+
+	/// Sets the auth used for the Admin API calls (e.g., `get_usage_report`).
+	pub fn with_admin_auth(mut self, admin_auth: AuthData) -> Self {
+		self.admin_auth = admin_auth;
+		self
+	}
+}
+
+/// Calls
+impl AnthropicExt<'_> {
+	/// Lists the Message Batches of the workspace, most recent first.
+	///
+	/// - `limit` - Page size (Anthropic default is 20, max 1000).
+	/// - `after_id` - Cursor for the next page (`MessageBatchPage::last_id` of the previous page).
+	pub async fn list_batches(&self, limit: Option<u32>, after_id: Option<&str>) -> Result<MessageBatchPage> {
+		let (auth, endpoint) = self.client.config().resolve_adapter_config(Self::ADAPTER_KIND).await?;
+
+		let mut query: Vec<(&str, String)> = Vec::new();
+		if let Some(limit) = limit {
+			query.push(("limit", limit.to_string()));
+		}
+		if let Some(after_id) = after_id {
+			query.push(("after_id", after_id.to_string()));
+		}
+
+		let body = self.get_json(auth, &endpoint, "messages/batches", &query).await?;
+		let page = serde_json::from_value(body)?;
+
+		Ok(page)
+	}
+
+	/// Gets the Admin API messages usage report (token usage per time bucket).
+	///
+	/// Requires an Admin API key (see `with_admin_auth`).
+	pub async fn get_usage_report(&self, request: &UsageReportRequest) -> Result<UsageReport> {
+		let (_, endpoint) = self.client.config().resolve_adapter_config(Self::ADAPTER_KIND).await?;
+
+		let query = request.to_query();
+		let body = self
+			.get_json(
+				self.admin_auth.clone(),
+				&endpoint,
+				"organizations/usage_report/messages",
+				&query,
+			)
+			.await?;
+		let report = serde_json::from_value(body)?;
+
+		Ok(report)
+	}
+}
+
+/// Support
+impl AnthropicExt<'_> {
+	async fn get_json(
+		&self,
+		auth: AuthData,
+		endpoint: &Endpoint,
+		suffix: &str,
+		query: &[(&str, String)],
+	) -> Result<Value> {
+		let model_iden = ModelIden::new(Self::ADAPTER_KIND, "");
+		let api_key = get_api_key(auth, &model_iden)?;
+
+		let url = join_service_url(endpoint.base_url(), suffix)?;
+		let mut url = reqwest::Url::parse(&url)
+			.map_err(|err| Error::Internal(format!("Cannot parse url: {url}. Cause:\n{err}")))?;
+		if !query.is_empty() {
+			url.query_pairs_mut().extend_pairs(query);
+		}
+
+		let headers = AnthropicAdapter::anthropic_headers(api_key, None, &[]);
+
+		let web_response = self
+			.client
+			.web_client()
+			.do_get(url.as_str(), &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: Self::ADAPTER_KIND,
+				webc_error,
+			})?;
+
+		Ok(web_response.body)
+	}
+}
+
+// endregion: --- AnthropicExt
+
+// region:    --- Message Batches Types
+
+/// A page of Message Batches, as returned by `AnthropicExt::list_batches`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageBatchPage {
+	pub data: Vec<MessageBatch>,
+	#[serde(default)]
+	pub has_more: bool,
+	pub first_id: Option<String>,
+	pub last_id: Option<String>,
+}
+
+/// A Message Batch (metadata only, results are fetched from `results_url`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageBatch {
+	pub id: String,
+	/// `in_progress`, `canceling`, or `ended`.
+	pub processing_status: String,
+	pub request_counts: MessageBatchRequestCounts,
+	/// RFC 3339 datetime strings.
+	pub created_at: String,
+	pub ended_at: Option<String>,
+	pub expires_at: Option<String>,
+	pub cancel_initiated_at: Option<String>,
+	pub archived_at: Option<String>,
+	pub results_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MessageBatchRequestCounts {
+	#[serde(default)]
+	pub processing: u32,
+	#[serde(default)]
+	pub succeeded: u32,
+	#[serde(default)]
+	pub errored: u32,
+	#[serde(default)]
+	pub canceled: u32,
+	#[serde(default)]
+	pub expired: u32,
+}
+
+// endregion: --- Message Batches Types
+
+// region:    --- Usage Report Types
+
+/// Request for `AnthropicExt::get_usage_report`.
+#[derive(Debug, Clone)]
+pub struct UsageReportRequest {
+	/// RFC 3339 datetime, inclusive.
+	pub starting_at: String,
+	/// RFC 3339 datetime, exclusive.
+	pub ending_at: Option<String>,
+	/// `1m`, `1h`, or `1d`.
+	pub bucket_width: Option<String>,
+	/// e.g., `model`, `workspace_id`, `api_key_id`, `service_tier`.
+	pub group_by: Vec<String>,
+	pub limit: Option<u32>,
+	/// Cursor for the next page (`UsageReport::next_page` of the previous page).
+	pub page: Option<String>,
+}
+
+impl UsageReportRequest {
+	pub fn new(starting_at: impl Into<String>) -> Self {
+		Self {
+			starting_at: starting_at.into(),
+			ending_at: None,
+			bucket_width: None,
+			group_by: Vec::new(),
+			limit: None,
+			page: None,
+		}
+	}
+
+	pub fn with_ending_at(mut self, ending_at: impl Into<String>) -> Self {
+		self.ending_at = Some(ending_at.into());
+		self
+	}
+
+	pub fn with_bucket_width(mut self, bucket_width: impl Into<String>) -> Self {
+		self.bucket_width = Some(bucket_width.into());
+		self
+	}
+
+	pub fn append_group_by(mut self, group_by: impl Into<String>) -> Self {
+		self.group_by.push(group_by.into());
+		self
+	}
+
+	pub fn with_limit(mut self, limit: u32) -> Self {
+		self.limit = Some(limit);
+		self
+	}
+
+	pub fn with_page(mut self, page: impl Into<String>) -> Self {
+		self.page = Some(page.into());
+		self
+	}
+
+	fn to_query(&self) -> Vec<(&'static str, String)> {
+		let mut query = vec![("starting_at", self.starting_at.clone())];
+		if let Some(ending_at) = &self.ending_at {
+			query.push(("ending_at", ending_at.clone()));
+		}
+		if let Some(bucket_width) = &self.bucket_width {
+			query.push(("bucket_width", bucket_width.clone()));
+		}
+		for group_by in &self.group_by {
+			query.push(("group_by[]", group_by.clone()));
+		}
+		if let Some(limit) = self.limit {
+			query.push(("limit", limit.to_string()));
+		}
+		if let Some(page) = &self.page {
+			query.push(("page", page.clone()));
+		}
+		query
+	}
+}
+
+/// Admin API messages usage report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReport {
+	pub data: Vec<UsageBucket>,
+	#[serde(default)]
+	pub has_more: bool,
+	pub next_page: Option<String>,
+}
+
+/// Usage for one time bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageBucket {
+	pub starting_at: String,
+	pub ending_at: String,
+	#[serde(default)]
+	pub results: Vec<UsageResult>,
+}
+
+/// Usage for one group of a bucket (the group fields are set only when grouped by them).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageResult {
+	#[serde(default)]
+	pub uncached_input_tokens: u64,
+	#[serde(default)]
+	pub cache_read_input_tokens: u64,
+	/// e.g., `{"ephemeral_5m_input_tokens": 0, "ephemeral_1h_input_tokens": 0}`
+	pub cache_creation: Option<Value>,
+	#[serde(default)]
+	pub output_tokens: u64,
+	/// e.g., `{"web_search_requests": 0}`
+	pub server_tool_use: Option<Value>,
+
+	pub model: Option<String>,
+	pub workspace_id: Option<String>,
+	pub api_key_id: Option<String>,
+	pub service_tier: Option<String>,
+	pub context_window: Option<String>,
+}
+
+// endregion: --- Usage Report Types
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_anthropic_ext_usage_report_request_query() {
+		let request = UsageReportRequest::new("2025-01-01T00:00:00Z")
+			.with_bucket_width("1d")
+			.append_group_by("model")
+			.append_group_by("workspace_id")
+			.with_limit(7);
+
+		let query = request.to_query();
+
+		assert_eq!(
+			query,
+			vec![
+				("starting_at", "2025-01-01T00:00:00Z".to_string()),
+				("bucket_width", "1d".to_string()),
+				("group_by[]", "model".to_string()),
+				("group_by[]", "workspace_id".to_string()),
+				("limit", "7".to_string()),
+			]
+		);
+	}
+}
+
+// endregion: --- Tests
@@ -2,14 +2,18 @@
 //! Tool Documentation:    <https://docs.anthropic.com/en/docs/build-with-claude/tool-use>
 //! Effort Documentation:  <https://platform.claude.com/docs/en/build-with-claude/effort>
 //! Model Names:           <https://docs.anthropic.com/en/docs/models-overview>
+//! Batches Documentation: <https://docs.anthropic.com/en/api/listing-message-batches>
+//! Usage Documentation:   <https://docs.anthropic.com/en/api/admin-api/usage-cost/get-messages-usage-report>
 //! Pricing:               <https://www.anthropic.com/pricing#anthropic-api>
 
 // region:    --- Modules
 
 mod adapter_impl;
+mod ext;
 mod streamer;
 
 pub use adapter_impl::*;
+pub use ext::*;
 pub use streamer::*;
 
 // endregion: --- Modules
@@ -1,10 +1,10 @@
-use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions, parse_tool_arguments, sse_event_type};
 use crate::adapter::anthropic::parse_cache_creation_details;
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{ChatOptionsSet, PromptTokensDetails, StopReason, ToolCall, Usage};
+use crate::chat::{ChatOptionsSet, PromptTokensDetails, StopReason, ToolArgsChunk, ToolCall, Usage};
 use crate::webc::{Event, EventSourceStream};
 use crate::{Error, ModelIden, Result};
-use serde_json::{Map, Value};
+use serde_json::Value;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use value_ext::JsonValueExt;
@@ -19,11 +19,20 @@ pub struct AnthropicStreamer {
 
 	captured_data: StreamerCapturedData,
 	in_progress_block: InProgressBlock,
+	/// Number of tool_use blocks started so far (the next tool index).
+	tool_use_count: usize,
+	/// The ToolCallChunk held back while its ToolCallArgsChunk is returned.
+	pending_event: Option<InterStreamEvent>,
 }
 
 enum InProgressBlock {
 	Text,
-	ToolUse { id: String, name: String, input: String },
+	ToolUse {
+		index: usize,
+		id: String,
+		name: String,
+		input: String,
+	},
 	Thinking,
 }
 
@@ -35,6 +44,8 @@ impl AnthropicStreamer {
 			options: StreamerOptions::new(model_iden, options_set),
 			captured_data: Default::default(),
 			in_progress_block: InProgressBlock::Text,
+			tool_use_count: 0,
+			pending_event: None,
 		}
 	}
 }
@@ -43,6 +54,9 @@ impl futures::Stream for AnthropicStreamer {
 	type Item = Result<InterStreamEvent>;
 
 	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		if let Some(pending_event) = self.pending_event.take() {
+			return Poll::Ready(Some(Ok(pending_event)));
+		}
 		if self.done {
 			return Poll::Ready(None);
 		}
@@ -52,7 +66,9 @@ impl futures::Stream for AnthropicStreamer {
 			match event {
 				Some(Ok(Event::Open)) => return Poll::Ready(Some(Ok(InterStreamEvent::Start))),
 				Some(Ok(Event::Message(message))) => {
-					let message_type = message.event.as_str();
+					// The named event, or the data `type` when the frames are `data:` only (some proxies)
+					let message_type = sse_event_type(&message);
+					let message_type = message_type.as_ref();
 
 					match message_type {
 						"message_start" => {
@@ -93,11 +109,14 @@ impl futures::Stream for AnthropicStreamer {
 									};
 
 									self.in_progress_block = InProgressBlock::ToolUse {
+										index: self.tool_use_count,
 										id,
 										name,
 										input: String::new(),
 									};
 
+									self.tool_use_count += 1;
+
 									return Poll::Ready(Some(Ok(InterStreamEvent::ToolCallChunk(tc))));
 								}
 								Ok(txt) => {
@@ -131,7 +150,7 @@ impl futures::Stream for AnthropicStreamer {
 
 									return Poll::Ready(Some(Ok(InterStreamEvent::Chunk(content))));
 								}
-								InProgressBlock::ToolUse { id, name, input } => {
+								InProgressBlock::ToolUse { index, id, name, input } => {
 									let partial = data.x_get_str("/delta/partial_json")?;
 									input.push_str(partial);
 
@@ -144,6 +163,17 @@ impl futures::Stream for AnthropicStreamer {
 										thought_signatures: None,
 									};
 
+									// Emit the raw fragment first (the first delta is usually empty, so skipped)
+									if !partial.is_empty() {
+										let args_chunk = ToolArgsChunk {
+											tool_index: *index,
+											call_id: id.clone(),
+											fragment: partial.to_string(),
+										};
+										self.pending_event = Some(InterStreamEvent::ToolCallChunk(tc));
+										return Poll::Ready(Some(Ok(InterStreamEvent::ToolCallArgsChunk(args_chunk))));
+									}
+
 									return Poll::Ready(Some(Ok(InterStreamEvent::ToolCallChunk(tc))));
 								}
 								InProgressBlock::Thinking => {
@@ -173,16 +203,16 @@ impl futures::Stream for AnthropicStreamer {
 						}
 						"content_block_stop" => {
 							match std::mem::replace(&mut self.in_progress_block, InProgressBlock::Text) {
-								InProgressBlock::ToolUse { id, name, input } => {
+								InProgressBlock::ToolUse { id, name, input, .. } => {
 									// ToolCallChunks were already emitted incrementally
 									// during content_block_start and content_block_delta.
 									// Here we only finalize capture with parsed arguments.
 									if self.options.capture_tool_calls {
-										let fn_arguments = if input.is_empty() {
-											Value::Object(Map::new())
-										} else {
-											serde_json::from_str(&input)?
-										};
+										let fn_arguments = parse_tool_arguments(
+											&name,
+											Value::String(input),
+											self.options.lenient_tool_arguments,
+										)?;
 
 										let tc = ToolCall {
 											call_id: id,
@@ -235,6 +265,7 @@ impl futures::Stream for AnthropicStreamer {
 								captured_tool_calls: self.captured_data.tool_calls.take(),
 								captured_thought_signatures: None,
 								captured_response_id: None,
+								interrupted_by: None,
 							};
 
 							// TODO: Need to capture the data as needed
@@ -242,16 +273,34 @@ impl futures::Stream for AnthropicStreamer {
 						}
 
 						"ping" => continue, // Loop to the next event
+						// e.g., `overloaded_error` under load, end with the partial data (see `Error::provider_error_kind`)
+						"error" => {
+							let body = serde_json::from_str::<Value>(&message.data)
+								.unwrap_or_else(|_| Value::String(message.data.clone()));
+							let error = Error::ChatResponse {
+								model_iden: self.options.model_iden.clone(),
+								body,
+							};
+							self.done = true;
+							return Poll::Ready(Some(
+								self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+							));
+						}
 						other => tracing::warn!("UNKNOWN MESSAGE TYPE: {other}"),
 					}
 				}
 				Some(Err(err)) => {
 					tracing::error!("Error: {}", err);
-					return Poll::Ready(Some(Err(Error::WebStream {
+					let error = Error::WebStream {
 						model_iden: self.options.model_iden.clone(),
 						cause: err.to_string(),
 						error: err,
-					})));
+					};
+					// End with the partial data captured so far, if any (the error follows, see `ChatStream`)
+					self.done = true;
+					return Poll::Ready(Some(
+						self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+					));
 				}
 				None => return Poll::Ready(None),
 			}
@@ -347,3 +396,136 @@ impl AnthropicStreamer {
 		})
 	}
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ProviderErrorKind;
+	use crate::adapter::AdapterKind;
+	use crate::chat::ChatOptions;
+	use futures::StreamExt;
+
+	/// The SSE frames of a short text response, as `(event name, data)`.
+	const FRAMES: &[(&str, &str)] = &[
+		(
+			"message_start",
+			r#"{"type":"message_start","message":{"usage":{"input_tokens":10,"output_tokens":1}}}"#,
+		),
+		(
+			"content_block_start",
+			r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+		),
+		("ping", r#"{"type":"ping"}"#),
+		(
+			"content_block_delta",
+			r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hello"}}"#,
+		),
+		(
+			"content_block_delta",
+			r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":" world"}}"#,
+		),
+		("content_block_stop", r#"{"type":"content_block_stop","index":0}"#),
+		(
+			"message_delta",
+			r#"{"type":"message_delta","delta":{"stop_reason":"end_turn"},"usage":{"output_tokens":3}}"#,
+		),
+		("message_stop", r#"{"type":"message_stop"}"#),
+	];
+
+	async fn collect_events(body: String) -> Vec<InterStreamEvent> {
+		let options = ChatOptions::default().with_capture_content(true).with_capture_usage(true);
+		let streamer = AnthropicStreamer::new(
+			EventSourceStream::from_body_chunks(vec![body.into_bytes()]),
+			ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		);
+		streamer.map(|event| event.expect("event should parse")).collect().await
+	}
+
+	fn assert_text_stream(events: &[InterStreamEvent]) {
+		let chunks: Vec<&str> = events
+			.iter()
+			.filter_map(|event| match event {
+				InterStreamEvent::Chunk(content) => Some(content.as_str()),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(chunks, vec!["Hello", " world"]);
+
+		let Some(InterStreamEvent::End(end)) = events.last() else {
+			panic!("should end with InterStreamEvent::End");
+		};
+		assert_eq!(end.captured_text_content.as_deref(), Some("Hello world"));
+		assert_eq!(end.captured_stop_reason, Some(StopReason::from("end_turn".to_string())));
+		let usage = end.captured_usage.as_ref().expect("should have the usage");
+		assert_eq!(usage.prompt_tokens, Some(10));
+		assert_eq!(usage.completion_tokens, Some(4));
+	}
+
+	#[tokio::test]
+	async fn test_anthropic_streamer_named_event_frames() {
+		let body: String = FRAMES
+			.iter()
+			.map(|(event, data)| format!("event: {event}\ndata: {data}\n\n"))
+			.collect();
+
+		assert_text_stream(&collect_events(body).await);
+	}
+
+	/// Without the `event:` lines (e.g., some proxies), the events are dispatched on the data `type`.
+	#[tokio::test]
+	async fn test_anthropic_streamer_data_only_frames() {
+		let body: String = FRAMES.iter().map(|(_, data)| format!("data: {data}\n\n")).collect();
+
+		assert_text_stream(&collect_events(body).await);
+	}
+
+	#[tokio::test]
+	async fn test_anthropic_streamer_overloaded_error_event() {
+		let error_data = r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#;
+		let body: String = FRAMES[..4]
+			.iter()
+			.chain(std::iter::once(&("error", error_data)))
+			.map(|(event, data)| format!("event: {event}\ndata: {data}\n\n"))
+			.collect();
+
+		let events = collect_events(body).await;
+
+		let Some(InterStreamEvent::End(end)) = events.last() else {
+			panic!("should end with InterStreamEvent::End");
+		};
+		assert_eq!(end.captured_text_content.as_deref(), Some("Hello"));
+		let error = end.interrupted_by.as_deref().expect("should be interrupted");
+		assert!(matches!(error, Error::ChatResponse { .. }));
+		assert_eq!(error.provider_error_kind(), Some(ProviderErrorKind::Overloaded));
+	}
+
+	/// An error before any captured content is the error itself (no incomplete end).
+	#[tokio::test]
+	async fn test_anthropic_streamer_error_event_before_content() {
+		let error_data = r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#;
+		let body: String = FRAMES[..1]
+			.iter()
+			.chain(std::iter::once(&("error", error_data)))
+			.map(|(event, data)| format!("event: {event}\ndata: {data}\n\n"))
+			.collect();
+		let options = ChatOptions::default().with_capture_content(true);
+		let streamer = AnthropicStreamer::new(
+			EventSourceStream::from_body_chunks(vec![body.into_bytes()]),
+			ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		);
+
+		let events: Vec<Result<InterStreamEvent>> = streamer.collect().await;
+
+		assert!(!events.iter().any(|event| matches!(event, Ok(InterStreamEvent::End(_)))));
+		let Some(Err(error)) = events.last() else {
+			panic!("should end with the error");
+		};
+		assert_eq!(error.provider_error_kind(), Some(ProviderErrorKind::Overloaded));
+	}
+}
+
+// endregion: --- Tests
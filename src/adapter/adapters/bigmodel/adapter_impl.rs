@@ -1,4 +1,5 @@
 use crate::ModelIden;
+use crate::adapter::adapters::support::join_service_url;
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
@@ -38,14 +39,12 @@ impl Adapter for BigModelAdapter {
 	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		// For ZAI, we need to handle model-specific routing at this level
 		// because get_service_url is called with the modified endpoint from to_web_request_data
-		let base_url = endpoint.base_url();
-
-		let url = match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}chat/completions"),
-			ServiceType::Embed => format!("{base_url}embeddings"),
-			ServiceType::Models => format!("{base_url}models"),
+		let suffix = match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => "chat/completions",
+			ServiceType::Embed => "embeddings",
+			ServiceType::Models => "models",
 		};
-		Ok(url)
+		join_service_url(endpoint.base_url(), suffix)
 	}
 
 	async fn all_models(
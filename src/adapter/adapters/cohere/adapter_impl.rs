@@ -1,11 +1,13 @@
 use crate::Model;
 use crate::adapter::ModelCapabilities;
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{
+	get_api_key, ignored_options, join_service_url, synthesize_call_id, tool_calls_by_call_id, tool_response_fn_name,
+};
 use crate::adapter::cohere::CohereStreamer;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, MessageContent, StopReason,
-	Usage,
+	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, ContentPart, MessageContent,
+	StopReason, Tool, ToolCall, ToolName, ToolResponse, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{WebResponse, WebStream};
@@ -13,11 +15,15 @@ use crate::{Error, Headers, Result};
 use crate::{ModelIden, ServiceTarget};
 use reqwest::RequestBuilder;
 use serde_json::{Value, json};
+use std::collections::HashMap;
 use value_ext::JsonValueExt;
 
 pub struct CohereAdapter;
 
-const MODELS: &[&str] = &[
+/// The `ChatOptions` payload options sent by the Cohere adapter (the others are reported as ignored).
+const SUPPORTED_OPTIONS: &[&str] = &["temperature", "max_tokens", "top_p", "top_k", "stop_sequences"];
+
+pub(in crate::adapter) const MODELS: &[&str] = &[
 	// Aya series - Multilingual models
 	"aya-vision-8b",
 	"aya-vision-32b",
@@ -136,13 +142,12 @@ impl Adapter for CohereAdapter {
 	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		let base_url = endpoint.base_url();
 		let url = match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}chat"),
+			ServiceType::Chat | ServiceType::ChatStream => join_service_url(base_url, "chat")?,
 			ServiceType::Embed => {
 				//HACK: Cohere embeddings use v2 API, but base_url is v1, so we need to replace it
-				let base_without_version = base_url.trim_end_matches("v1/");
-				format!("{base_without_version}v2/embed")
+				join_service_url(base_url, "v2/embed")?.replacen("/v1/v2/embed", "/v2/embed", 1)
 			}
-			ServiceType::Models => format!("{base_url}models"),
+			ServiceType::Models => join_service_url(base_url, "models")?,
 		};
 		Ok(url)
 	}
@@ -169,6 +174,8 @@ impl Adapter for CohereAdapter {
 			preamble,
 			message,
 			chat_history,
+			tool_results,
+			tools,
 		} = Self::into_cohere_request_parts(model.clone(), chat_req)?;
 
 		// -- Build the basic payload
@@ -186,6 +193,12 @@ impl Adapter for CohereAdapter {
 		if let Some(preamble) = preamble {
 			payload.x_insert("preamble", preamble)?;
 		}
+		if !tool_results.is_empty() {
+			payload.x_insert("tool_results", tool_results)?;
+		}
+		if let Some(tools) = tools {
+			payload.x_insert("tools", tools)?;
+		}
 
 		// -- Add supported ChatOptions
 		if let Some(temperature) = options_set.temperature() {
@@ -204,7 +217,18 @@ impl Adapter for CohereAdapter {
 			payload.x_insert("p", top_p)?;
 		}
 
-		Ok(WebRequestData { url, headers, payload })
+		if let Some(top_k) = options_set.top_k() {
+			payload.x_insert("k", top_k)?;
+		}
+
+		let ignored_options = ignored_options(&options_set, SUPPORTED_OPTIONS, model.adapter_kind);
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -235,11 +259,30 @@ impl Adapter for CohereAdapter {
 			return Err(Error::NoChatResponse { model_iden });
 		};
 
-		let content: MessageContent = last_chat_history_item
+		let mut content: MessageContent = last_chat_history_item
 			.x_take::<Option<String>>("message")?
+			.filter(|message| !message.is_empty())
 			.map(MessageContent::from)
 			.unwrap_or_default();
 
+		// -- Get the eventual tool calls (Cohere v1 has no call id)
+		let tool_calls = body
+			.x_take::<Option<Vec<Value>>>("tool_calls")
+			.ok()
+			.flatten()
+			.unwrap_or_default();
+		for (idx, mut tool_call) in tool_calls.into_iter().enumerate() {
+			let fn_name: String = tool_call.x_take("name")?;
+			content.push(ContentPart::ToolCall(ToolCall {
+				call_id: synthesize_call_id(&fn_name, idx),
+				fn_name,
+				fn_arguments: tool_call.x_take("parameters").unwrap_or_else(|_| json!({})),
+				thought_signatures: None,
+			}));
+		}
+
+		let usage_reported = !usage.is_empty();
+
 		Ok(ChatResponse {
 			content,
 			reasoning_content: None,
@@ -247,8 +290,15 @@ impl Adapter for CohereAdapter {
 			provider_model_iden,
 			stop_reason,
 			usage,
+			usage_reported,
 			captured_raw_body: None, // Set by the client exec_chat
 			response_id: None,
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		})
 	}
 
@@ -257,13 +307,16 @@ impl Adapter for CohereAdapter {
 		reqwest_builder: RequestBuilder,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let web_stream = WebStream::new_with_delimiter(reqwest_builder, "\n");
+		let web_stream = WebStream::new_with_delimiter(reqwest_builder, "\n")
+			.with_lossy_utf8(options_set.lossy_utf8().unwrap_or(true));
 		let cohere_stream = CohereStreamer::new(web_stream, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(cohere_stream);
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -347,64 +400,87 @@ impl CohereAdapter {
 	}
 
 	/// Takes the GenAI ChatMessages and builds the system string and JSON messages for Cohere.
-	/// - Pops the last chat user message and sets it as the message
+	/// - Pops the last chat message (user, or tool responses) and sets it as the message (or the `tool_results`)
 	/// - Sets any eventual `system` as the first `preamble`
 	/// - Adds all of the system messages into the 'preamble' (this might change when ChatReq has a `.system`)
 	/// - Builds the chat history with the remaining messages
+	///   - Assistant tool calls as `CHATBOT` `tool_calls`, and tool responses as `TOOL` `tool_results`
+	///     (Cohere v1 references the tool call name and parameters, resolved from the call id)
 	fn into_cohere_request_parts(
 		model_iden: ModelIden, // for error only
 		mut chat_req: ChatRequest,
 	) -> Result<CohereChatRequestParts> {
 		let mut chat_history: Vec<Value> = Vec::new();
 		let mut systems: Vec<String> = Vec::new();
+		let tool_calls = tool_calls_by_call_id(&chat_req.messages);
 
 		// -- Add the eventual system as preamble
 		if let Some(system) = chat_req.system {
 			systems.push(system);
 		}
 
-		// -- Build and extract the last user message
+		// -- Build and extract the last user message (or the tool results)
 		let last_chat_msg = chat_req.messages.pop().ok_or_else(|| Error::ChatReqHasNoMessages {
 			model_iden: model_iden.clone(),
 		})?;
-		if !matches!(last_chat_msg.role, ChatRole::User) {
+		if !matches!(last_chat_msg.role, ChatRole::User | ChatRole::Tool) {
 			return Err(Error::LastChatMessageIsNotUser {
 				model_iden,
 				actual_role: last_chat_msg.role,
 			});
 		}
 
-		// TODO: Needs to implement tool_calls
-		let Some(message) = last_chat_msg.content.into_joined_texts() else {
-			return Err(Error::MessageContentTypeNotSupported {
-				model_iden,
-				cause: "Only MessageContent::Text supported for this model (for now)",
-			});
-		};
-
-		// -- Build
-		for msg in chat_req.messages {
-			let Some(content) = msg.content.into_joined_texts() else {
+		let tool_results = cohere_tool_results(last_chat_msg.content.tool_responses(), &tool_calls);
+		let message = match last_chat_msg.content.into_joined_texts() {
+			Some(message) => message,
+			// Note: the tool results turn has an empty message
+			None if !tool_results.is_empty() => String::new(),
+			None => {
 				return Err(Error::MessageContentTypeNotSupported {
 					model_iden,
-					cause: "Only MessageContent::Text supported for this model (for now)",
+					cause: "Only MessageContent::Text and ToolResponse supported for this model (for now)",
 				});
-			};
+			}
+		};
 
+		// -- Build
+		for msg in chat_req.messages {
 			match msg.role {
-				// For now, system and tool messages go to the system
-				ChatRole::System => systems.push(content),
-				ChatRole::User => chat_history.push(json! ({"role": "USER", "content": content})),
-				ChatRole::Assistant => chat_history.push(json! ({"role": "CHATBOT", "content": content})),
-				ChatRole::Tool => {
-					return Err(Error::MessageRoleNotSupported {
-						model_iden,
-						role: ChatRole::Tool,
-					});
+				// For now, system messages go to the preamble
+				ChatRole::System | ChatRole::Developer | ChatRole::User if !msg.content.contains_tool_response() => {
+					let Some(content) = msg.content.into_joined_texts() else {
+						return Err(Error::MessageContentTypeNotSupported {
+							model_iden,
+							cause: "Only MessageContent::Text supported for this model (for now)",
+						});
+					};
+					match msg.role {
+						ChatRole::User => chat_history.push(json!({"role": "USER", "message": content})),
+						_ => systems.push(content),
+					}
+				}
+				ChatRole::Assistant => {
+					let tool_calls: Vec<Value> = msg.content.tool_calls().into_iter().map(cohere_tool_call).collect();
+					let content = msg.content.into_joined_texts().unwrap_or_default();
+					let mut entry = json!({"role": "CHATBOT", "message": content});
+					if !tool_calls.is_empty() {
+						entry.x_insert("tool_calls", tool_calls)?;
+					}
+					chat_history.push(entry);
+				}
+				// Tool responses (ChatRole::Tool, or ChatRole::User with tool responses)
+				_ => {
+					let tool_results = cohere_tool_results(msg.content.tool_responses(), &tool_calls);
+					chat_history.push(json!({"role": "TOOL", "tool_results": tool_results}));
 				}
 			}
 		}
 
+		// -- Build the tools
+		let tools = chat_req
+			.tools
+			.map(|tools| tools.into_iter().map(cohere_tool).collect::<Vec<_>>());
+
 		// -- Build the preamble
 		// Note: For now, we just concatenate the system messages into the preamble as recommended by Cohere
 		//       Later, the ChatRequest should have a `.system` property
@@ -418,6 +494,8 @@ impl CohereAdapter {
 			preamble,
 			message,
 			chat_history,
+			tool_results,
+			tools,
 		})
 	}
 }
@@ -425,10 +503,188 @@ impl CohereAdapter {
 struct CohereChatRequestParts {
 	/// The "system" in the Cohere context
 	preamble: Option<String>,
-	/// The last user message
+	/// The last user message (empty for a tool results turn)
 	message: String,
 	/// The chat history (user and assistant, except the last user message which is the message)
 	chat_history: Vec<Value>,
+	/// The tool results of the last message, if any
+	tool_results: Vec<Value>,
+	tools: Option<Vec<Value>>,
+}
+
+/// Cohere v1 tool call (no call id, see `synthesize_call_id` for the response side).
+fn cohere_tool_call(tool_call: &ToolCall) -> Value {
+	json!({
+		"name": tool_call.fn_name,
+		"parameters": tool_call.fn_arguments,
+	})
+}
+
+/// Cohere v1 `tool_results`, each with its originating tool call (name and parameters).
+/// - A JSON object content is sent as is as the output, otherwise as `{"result": content}`
+///   (or `{"error": content}` for a failed tool execution).
+fn cohere_tool_results(tool_responses: Vec<&ToolResponse>, tool_calls: &HashMap<String, ToolCall>) -> Vec<Value> {
+	tool_responses
+		.into_iter()
+		.map(|tool_response| {
+			let call = match tool_calls.get(&tool_response.call_id) {
+				Some(tool_call) => cohere_tool_call(tool_call),
+				None => json!({
+					"name": tool_response_fn_name(&tool_response.call_id, tool_calls),
+					"parameters": {},
+				}),
+			};
+			let output = match serde_json::from_str::<Value>(&tool_response.content) {
+				Ok(output @ Value::Object(_)) if !tool_response.is_error => output,
+				_ if tool_response.is_error => json!({"error": tool_response.content}),
+				_ => json!({"result": tool_response.content}),
+			};
+			json!({"call": call, "outputs": [output]})
+		})
+		.collect()
+}
+
+/// Cohere v1 tool definition, with the `parameter_definitions` from the JSON schema top level properties.
+fn cohere_tool(tool: Tool) -> Value {
+	let Tool {
+		name,
+		description,
+		schema,
+		..
+	} = tool;
+
+	let name = match name {
+		ToolName::WebSearch => "web_search".to_string(),
+		ToolName::Custom(name) => name,
+	};
+
+	let required: Vec<&str> = schema
+		.as_ref()
+		.and_then(|schema| schema.get("required"))
+		.and_then(Value::as_array)
+		.map(|required| required.iter().filter_map(Value::as_str).collect())
+		.unwrap_or_default();
+
+	let mut parameter_definitions = serde_json::Map::new();
+	if let Some(properties) = schema
+		.as_ref()
+		.and_then(|schema| schema.get("properties"))
+		.and_then(Value::as_object)
+	{
+		for (param_name, property) in properties {
+			// Cohere v1 uses the python type names
+			let param_type = match property.get("type").and_then(Value::as_str) {
+				Some("integer") => "int",
+				Some("number") => "float",
+				Some("boolean") => "bool",
+				Some("array") => "list",
+				Some("object") => "dict",
+				_ => "str",
+			};
+			let mut definition = json!({
+				"type": param_type,
+				"required": required.contains(&param_name.as_str()),
+			});
+			if let Some(description) = property.get("description") {
+				definition["description"] = description.clone();
+			}
+			parameter_definitions.insert(param_name.clone(), definition);
+		}
+	}
+
+	json!({
+		"name": name,
+		"description": description.unwrap_or_default(),
+		"parameter_definitions": parameter_definitions,
+	})
 }
 
 // endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chat::ChatMessage;
+
+	#[test]
+	fn test_into_cohere_request_parts_tool_replay() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Cohere, "command-r-plus");
+		// -- A transcript from an OpenAI-shaped provider, ending with the tool responses
+		let chat_req = ChatRequest::from_user("What is the weather in Paris?")
+			.append_message(ChatMessage::assistant(vec![ToolCall {
+				call_id: "call_Xa1".to_string(),
+				fn_name: "get_weather".to_string(),
+				fn_arguments: json!({"city": "Paris"}),
+				thought_signatures: None,
+			}]))
+			.append_message(ChatMessage::from(ToolResponse::new(
+				"call_Xa1",
+				r#"{"weather": "sunny"}"#,
+			)))
+			.with_tools(vec![
+				Tool::new("get_weather").with_description("Get the weather").with_schema(json!({
+					"type": "object",
+					"properties": {"city": {"type": "string", "description": "The city name"}},
+					"required": ["city"]
+				})),
+			]);
+
+		let parts = CohereAdapter::into_cohere_request_parts(model_iden, chat_req)?;
+
+		assert_eq!(parts.message, "");
+		assert_eq!(
+			parts.tool_results,
+			vec![json!({
+				"call": {"name": "get_weather", "parameters": {"city": "Paris"}},
+				"outputs": [{"weather": "sunny"}]
+			})]
+		);
+		assert_eq!(
+			parts.chat_history,
+			vec![
+				json!({"role": "USER", "message": "What is the weather in Paris?"}),
+				json!({"role": "CHATBOT", "message": "", "tool_calls": [{"name": "get_weather", "parameters": {"city": "Paris"}}]}),
+			]
+		);
+		let tools = parts.tools.expect("should have tools");
+		assert_eq!(
+			tools[0]["parameter_definitions"]["city"],
+			json!({"type": "str", "required": true, "description": "The city name"})
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_chat_response_tool_calls() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Cohere, "command-r-plus");
+		let body = json!({
+			"text": "",
+			"chat_history": [
+				{"role": "USER", "message": "What is the weather in Paris?"},
+				{"role": "CHATBOT", "message": "", "tool_calls": [{"name": "get_weather", "parameters": {"city": "Paris"}}]}
+			],
+			"tool_calls": [{"name": "get_weather", "parameters": {"city": "Paris"}}],
+			"finish_reason": "COMPLETE"
+		});
+		let web_response = WebResponse {
+			status: reqwest::StatusCode::OK,
+			headers: Default::default(),
+			body,
+		};
+
+		let chat_res = CohereAdapter::to_chat_response(model_iden, web_response, ChatOptionsSet::default())?;
+
+		assert_eq!(chat_res.first_text(), None);
+		let tool_calls = chat_res.tool_calls();
+		assert_eq!(tool_calls.len(), 1);
+		assert_eq!(tool_calls[0].call_id, synthesize_call_id("get_weather", 0));
+		assert_eq!(tool_calls[0].fn_arguments, json!({"city": "Paris"}));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
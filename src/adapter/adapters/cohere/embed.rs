@@ -4,7 +4,7 @@
 use crate::adapter::adapters::support::get_api_key;
 use crate::adapter::{Adapter, ServiceType, WebRequestData};
 use crate::chat::Usage;
-use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, EmbedTruncate, Embedding};
 use crate::webc::WebResponse;
 use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
 use serde::{Deserialize, Serialize};
@@ -97,15 +97,10 @@ pub fn to_embed_request_data(
 	}
 
 	// Convert EmbedRequest to Cohere format
+	// NOTE: Cohere only accepts the `texts` array, so a single text is sent as a one item array.
 	let (texts, inputs) = match embed_req.input {
-		crate::embed::EmbedInput::Single(text) => {
-			// For single text, use the simpler texts array format
-			(Some(vec![text]), None)
-		}
-		crate::embed::EmbedInput::Batch(texts) => {
-			// For batch, use the texts array format
-			(Some(texts), None)
-		}
+		crate::embed::EmbedInput::Single(text) => (Some(vec![text]), None),
+		crate::embed::EmbedInput::Batch(texts) => (Some(texts), None),
 	};
 
 	// Determine embedding types - default to float
@@ -124,10 +119,7 @@ pub fn to_embed_request_data(
 		model: model_name.to_string(),
 		input_type: options_set.embedding_type().unwrap_or("search_document").to_string(),
 		embedding_types,
-		truncate: options_set
-			.truncate()
-			.map(|s| s.to_string())
-			.or_else(|| Some("END".to_string())),
+		truncate: Some(options_set.truncate().unwrap_or(EmbedTruncate::End).as_str().to_string()),
 		output_dimension: options_set.dimensions(),
 	};
 
@@ -143,7 +135,12 @@ pub fn to_embed_request_data(
 		service_target.endpoint,
 	)?;
 
-	Ok(WebRequestData { url, headers, payload })
+	Ok(WebRequestData {
+		url,
+		headers,
+		payload,
+		ignored_options: Vec::new(),
+	})
 }
 
 pub fn to_embed_response(
@@ -194,7 +191,7 @@ pub fn to_embed_response(
 		}
 	};
 
-	// Convert to our format
+	// Convert to our format (Cohere returns the embeddings in the input order)
 	let embeddings: Vec<Embedding> = embedding_vectors
 		.into_iter()
 		.enumerate()
@@ -246,3 +243,83 @@ pub fn to_embed_response(
 }
 
 // endregion: --- Public Functions
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterKind;
+	use crate::embed::EmbedOptions;
+	use crate::resolver::AuthData;
+	use serde_json::{Value, json};
+
+	fn test_target() -> ServiceTarget {
+		ServiceTarget {
+			endpoint: crate::adapter::cohere::CohereAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Cohere, "embed-english-v3.0"),
+		}
+	}
+
+	#[test]
+	fn test_embed_request_data_texts_array_single_and_batch() -> Result<()> {
+		let single = to_embed_request_data(test_target(), EmbedRequest::new("hello"), EmbedOptionsSet::default())?;
+		let batch = to_embed_request_data(
+			test_target(),
+			EmbedRequest::new_batch(vec!["hello".to_string(), "world".to_string()]),
+			EmbedOptionsSet::default(),
+		)?;
+
+		assert_eq!(single.payload["texts"], json!(["hello"]));
+		assert_eq!(batch.payload["texts"], json!(["hello", "world"]));
+		assert!(batch.payload.get("inputs").is_none());
+		assert!(single.url.ends_with("v2/embed"));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_embed_request_data_truncate_mapping() -> Result<()> {
+		let truncate_value = |truncate: Option<EmbedTruncate>| -> Result<Value> {
+			let options = truncate.map(|truncate| EmbedOptions::new().with_truncate(truncate));
+			let options_set = EmbedOptionsSet::new().with_request_options(options.as_ref());
+			let data = to_embed_request_data(test_target(), EmbedRequest::new("hello"), options_set)?;
+			Ok(data.payload["truncate"].clone())
+		};
+
+		assert_eq!(truncate_value(None)?, "END");
+		assert_eq!(truncate_value(Some(EmbedTruncate::None))?, "NONE");
+		assert_eq!(truncate_value(Some(EmbedTruncate::Start))?, "START");
+		assert_eq!(truncate_value(Some(EmbedTruncate::End))?, "END");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_embed_response_input_order_and_index() -> Result<()> {
+		let web_response = WebResponse {
+			status: reqwest::StatusCode::OK,
+			headers: Default::default(),
+			body: json!({
+				"id": "emb-test",
+				"embeddings": {"float": [[0.1, 0.2], [0.3, 0.4]]},
+				"texts": ["hello", "world"],
+				"meta": {"billed_units": {"input_tokens": 2}}
+			}),
+		};
+
+		let res = to_embed_response(test_target().model, web_response, EmbedOptionsSet::default())?;
+
+		assert_eq!(res.embedding_count(), 2);
+		assert_eq!(res.embeddings[0].index, 0);
+		assert_eq!(res.embeddings[0].vector, vec![0.1, 0.2]);
+		assert_eq!(res.embeddings[1].index, 1);
+		assert_eq!(res.embeddings[1].vector, vec![0.3, 0.4]);
+		assert_eq!(res.usage.prompt_tokens, Some(2));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,7 +1,7 @@
 use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
 use crate::adapter::cohere::CohereAdapter;
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{ChatOptionsSet, StopReason};
+use crate::chat::{ChatOptionsSet, StopReason, Usage};
 use crate::webc::WebStream;
 use crate::{Error, ModelIden, Result};
 use serde::Deserialize;
@@ -18,6 +18,9 @@ pub struct CohereStreamer {
 	/// Flag to prevent polling the EventSource after a MessageStop event
 	done: bool,
 	captured_data: StreamerCapturedData,
+	/// The parse error of the last message, held until the next one
+	/// (Cohere occasionally sends a malformed last message, which is then ignored).
+	malformed_error: Option<Error>,
 }
 
 impl CohereStreamer {
@@ -27,6 +30,22 @@ impl CohereStreamer {
 			done: false,
 			options: StreamerOptions::new(model_iden, options_set),
 			captured_data: Default::default(),
+			malformed_error: None,
+		}
+	}
+
+	/// Takes the data captured so far into the `InterStreamEnd` of a completed stream.
+	fn take_captured_end(&mut self, captured_usage: Option<Usage>) -> InterStreamEnd {
+		InterStreamEnd {
+			captured_usage,
+			captured_stop_reason: self.captured_data.stop_reason.take().map(StopReason::from),
+			captured_text_content: self.captured_data.content.take(),
+			captured_content_parts: None,
+			captured_reasoning_content: self.captured_data.reasoning_content.take(),
+			captured_tool_calls: self.captured_data.tool_calls.take(),
+			captured_thought_signatures: None,
+			captured_response_id: None,
+			interrupted_by: None,
 		}
 	}
 }
@@ -58,15 +77,16 @@ impl futures::Stream for CohereStreamer {
 		while let Poll::Ready(item) = Pin::new(&mut self.inner).poll_next(cx) {
 			match item {
 				Some(Ok(raw_string)) => {
-					let cohere_message =
-						serde_json::from_str::<CohereStreamMessage>(&raw_string).map_err(|serde_error| {
-							Error::StreamParse {
-								model_iden: self.options.model_iden.clone(),
-								serde_error,
-							}
-						});
+					// -- A malformed message followed by another one is not the last one, so it interrupts the stream
+					if let Some(error) = self.malformed_error.take() {
+						tracing::error!("Cohere Adapter Stream Error: {}", error);
+						self.done = true;
+						return Poll::Ready(Some(
+							self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+						));
+					}
 
-					match cohere_message {
+					match serde_json::from_str::<CohereStreamMessage>(&raw_string) {
 						Ok(cohere_message) => {
 							let inter_event = match cohere_message.event_type.as_str() {
 								"stream-start" => InterStreamEvent::Start,
@@ -107,44 +127,47 @@ impl futures::Stream for CohereStreamer {
 										None
 									};
 
-									let inter_stream_end = InterStreamEnd {
-										captured_usage,
-										captured_stop_reason: self
-											.captured_data
-											.stop_reason
-											.take()
-											.map(StopReason::from),
-										captured_text_content: self.captured_data.content.take(),
-										captured_content_parts: None,
-										captured_reasoning_content: self.captured_data.reasoning_content.take(),
-										captured_tool_calls: self.captured_data.tool_calls.take(),
-										captured_thought_signatures: None,
-										captured_response_id: None,
-									};
-
-									InterStreamEvent::End(inter_stream_end)
+									self.done = true;
+									InterStreamEvent::End(self.take_captured_end(captured_usage))
 								}
 								_ => continue, // Skip the "other" event
 							};
 
 							return Poll::Ready(Some(Ok(inter_event)));
 						}
-						Err(err) => {
-							tracing::error!("Cohere Adapter Stream Error: {}", err);
-							return Poll::Ready(Some(Err(err)));
+						Err(serde_error) => {
+							tracing::warn!(
+								"Cohere Adapter malformed stream message (error if not the last one): {serde_error}"
+							);
+							self.malformed_error = Some(Error::StreamParse {
+								model_iden: self.options.model_iden.clone(),
+								serde_error,
+							});
+							continue;
 						}
 					}
 				}
 				Some(Err(err)) => {
 					tracing::error!("Cohere Adapter Stream Error: {}", err);
-					return Poll::Ready(Some(Err(Error::WebStream {
+					let error = Error::WebStream {
 						model_iden: self.options.model_iden.clone(),
 						cause: err.to_string(),
 						error: err,
-					})));
+					};
+					// End with the partial data captured so far, if any (the error follows, see `ChatStream`)
+					self.done = true;
+					return Poll::Ready(Some(
+						self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+					));
 				}
 				None => {
 					self.done = true;
+					// -- A malformed last message is ignored, and the stream ends with the data captured so far
+					if self.malformed_error.take().is_some() {
+						tracing::warn!("Cohere Adapter malformed last stream message ignored");
+						let inter_stream_end = self.take_captured_end(None);
+						return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
+					}
 					return Poll::Ready(None);
 				}
 			}
@@ -152,3 +175,59 @@ impl futures::Stream for CohereStreamer {
 		Poll::Pending
 	}
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterKind;
+	use crate::chat::ChatOptions;
+	use crate::webc::StreamMode;
+	use futures::StreamExt;
+
+	const STREAM_START: &str = r#"{"is_finished":false,"event_type":"stream-start","generation_id":"gen-1"}"#;
+	const TEXT_HELLO: &str = r#"{"is_finished":false,"event_type":"text-generation","text":"Hello"}"#;
+	const TEXT_WORLD: &str = r#"{"is_finished":false,"event_type":"text-generation","text":" world"}"#;
+
+	async fn collect_events(lines: &[&str]) -> Vec<Result<InterStreamEvent>> {
+		let body = lines.iter().map(|line| format!("{line}\n")).collect::<String>();
+		let options = ChatOptions::default().with_capture_content(true);
+		let streamer = CohereStreamer::new(
+			WebStream::from_body_chunks(StreamMode::Delimiter("\n"), vec![body.into_bytes()]),
+			ModelIden::new(AdapterKind::Cohere, "command-r7b-12-2024"),
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		);
+		streamer.collect().await
+	}
+
+	/// A garbage last message (instead of the `stream-end`) ends the stream with the captured content.
+	#[tokio::test]
+	async fn test_cohere_streamer_malformed_last_message_ends_with_captured_data() {
+		let events = collect_events(&[STREAM_START, TEXT_HELLO, TEXT_WORLD, r#"{"is_finished":true,"event_"#]).await;
+
+		let events: Vec<InterStreamEvent> = events.into_iter().map(|e| e.expect("should not error")).collect();
+		assert_eq!(events.len(), 4);
+		let Some(InterStreamEvent::End(end)) = events.last() else {
+			panic!("should end with InterStreamEvent::End");
+		};
+		assert_eq!(end.captured_text_content.as_deref(), Some("Hello world"));
+		assert!(end.interrupted_by.is_none());
+	}
+
+	/// A malformed message followed by another one interrupts the stream (the partial content is kept).
+	#[tokio::test]
+	async fn test_cohere_streamer_malformed_middle_message_interrupts() {
+		let events = collect_events(&[STREAM_START, TEXT_HELLO, "not json", TEXT_WORLD]).await;
+
+		let events: Vec<InterStreamEvent> = events.into_iter().map(|e| e.expect("should not error")).collect();
+		assert_eq!(events.len(), 3);
+		let Some(InterStreamEvent::End(end)) = events.last() else {
+			panic!("should end with InterStreamEvent::End");
+		};
+		assert_eq!(end.captured_text_content.as_deref(), Some("Hello"));
+		assert!(matches!(end.interrupted_by.as_deref(), Some(Error::StreamParse { .. })));
+	}
+}
+
+// endregion: --- Tests
@@ -5,6 +5,9 @@ use super::types::*;
 use crate::adapter::adapters::copilot_headers::{
 	COPILOT_INTEGRATION_ID, EDITOR_VERSION, X_GITHUB_API_VERSION, build_copilot_headers,
 };
+use crate::adapter::adapters::support::{
+	ignored_options, join_service_url, parse_tool_arguments, with_model_deprecation,
+};
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
 	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStreamResponse, ContentPart, MessageContent, ToolCall,
@@ -12,14 +15,18 @@ use crate::chat::{
 };
 use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
 use crate::resolver::{AuthData, Endpoint};
+use crate::support::truncate_for_log;
 use crate::webc::{EventSourceStream, WebResponse};
 use crate::{Error, Headers, Model, ModelIden, Result, ServiceTarget};
 use reqwest::RequestBuilder;
 
 pub struct CopilotAdapter;
 
+/// The `ChatOptions` payload options sent by the Copilot adapter (the others are reported as ignored).
+const SUPPORTED_OPTIONS: &[&str] = &["temperature", "max_tokens", "top_p"];
+
 // Models supported by GitHub Copilot
-const MODELS: &[&str] = &[
+pub(in crate::adapter) const MODELS: &[&str] = &[
 	"gpt-4o",
 	"gpt-4o-mini",
 	"gpt-5-mini",
@@ -93,6 +100,9 @@ impl CopilotAdapter {
 			}
 		}
 
+		// Parse deprecation info if present
+		let model = with_model_deprecation(model, &model_data);
+
 		Ok(model)
 	}
 
@@ -115,7 +125,7 @@ impl CopilotAdapter {
 			let role = match msg.role {
 				ChatRole::User => "user",
 				ChatRole::Assistant => "assistant",
-				ChatRole::System => "system",
+				ChatRole::System | ChatRole::Developer => "system",
 				ChatRole::Tool => "tool",
 			};
 
@@ -298,7 +308,10 @@ impl Adapter for CopilotAdapter {
 				webc_error,
 			})?;
 
-		println!("Copilot models API response body: {}", web_response.body);
+		tracing::debug!(
+			"Copilot models API response body: {}",
+			truncate_for_log(&web_response.body.to_string())
+		);
 
 		// Parse response - similar to Zed's model parsing
 		let mut models: Vec<Model> = Vec::new();
@@ -312,7 +325,7 @@ impl Adapter for CopilotAdapter {
 					Ok(model) => models.push(model),
 					Err(e) => {
 						// Log error but continue parsing other models (resilient like Zed)
-						eprintln!("Failed to parse Copilot model: {}", e);
+						tracing::warn!("Failed to parse Copilot model: {}", e);
 					}
 				}
 			}
@@ -323,14 +336,11 @@ impl Adapter for CopilotAdapter {
 
 	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		let suffix = match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => "/chat/completions",
+			ServiceType::Chat | ServiceType::ChatStream => "chat/completions",
 			ServiceType::Embed => {
-				return Err(Error::AdapterNotSupported {
-					adapter_kind: AdapterKind::Copilot,
-					feature: "embed".to_string(),
-				});
+				return Err(Error::service_not_supported(AdapterKind::Copilot, ServiceType::Embed));
 			}
-			ServiceType::Models => "/models",
+			ServiceType::Models => "models",
 		};
 
 		let base_url = if matches!(service_type, ServiceType::Models)
@@ -344,7 +354,7 @@ impl Adapter for CopilotAdapter {
 			endpoint.base_url()
 		};
 
-		Ok(format!("{}{}", base_url, suffix))
+		join_service_url(base_url, suffix)
 	}
 
 	fn to_web_request_data(
@@ -381,7 +391,14 @@ impl Adapter for CopilotAdapter {
 		let api_key = get_api_key(auth, &model)?;
 		let headers = build_copilot_headers(&api_key, &payload, true);
 
-		Ok(WebRequestData { url, headers, payload })
+		let ignored_options = ignored_options(&options_set, SUPPORTED_OPTIONS, model.adapter_kind);
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -391,10 +408,9 @@ impl Adapter for CopilotAdapter {
 	) -> Result<ChatResponse> {
 		let WebResponse { body, .. } = web_response;
 
-		// Debug: print raw response body
-		eprintln!(
-			"[DEBUG Copilot] Raw response body: {}",
-			serde_json::to_string_pretty(&body).unwrap_or_default()
+		tracing::debug!(
+			"Copilot raw response body: {}",
+			truncate_for_log(&serde_json::to_string_pretty(&body).unwrap_or_default())
 		);
 
 		let captured_raw_body = options_set.capture_raw_body().unwrap_or_default().then(|| body.clone());
@@ -404,7 +420,7 @@ impl Adapter for CopilotAdapter {
 			Error::Internal(format!(
 				"Failed to parse Copilot response: {}. Body: {}",
 				e,
-				serde_json::to_string_pretty(&body).unwrap_or_default()
+				truncate_for_log(&serde_json::to_string_pretty(&body).unwrap_or_default())
 			))
 		})?;
 
@@ -431,8 +447,13 @@ impl Adapter for CopilotAdapter {
 
 			// Extract tool calls if present
 			if let Some(tool_calls) = &choice.message.tool_calls {
+				let lenient_tool_arguments = options_set.lenient_tool_arguments().unwrap_or(false);
 				for tc in tool_calls {
-					let fn_arguments = serde_json::from_str(&tc.function.arguments).unwrap_or_default();
+					let fn_arguments = parse_tool_arguments(
+						&tc.function.name,
+						serde_json::Value::String(tc.function.arguments.clone()),
+						lenient_tool_arguments,
+					)?;
 
 					content.push(ContentPart::ToolCall(ToolCall {
 						call_id: tc.id.clone(),
@@ -450,6 +471,8 @@ impl Adapter for CopilotAdapter {
 			.and_then(|choice| choice.finish_reason.clone())
 			.map(crate::chat::StopReason::from);
 
+		let usage_reported = usage.is_some();
+
 		Ok(ChatResponse {
 			content,
 			reasoning_content: None,
@@ -457,8 +480,15 @@ impl Adapter for CopilotAdapter {
 			provider_model_iden,
 			stop_reason,
 			usage: usage.unwrap_or_default(),
+			usage_reported,
 			captured_raw_body,
 			response_id: None,
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		})
 	}
 
@@ -469,13 +499,16 @@ impl Adapter for CopilotAdapter {
 	) -> Result<ChatStreamResponse> {
 		use crate::chat::ChatStream;
 
-		let event_source = EventSourceStream::new(reqwest_builder);
+		let event_source =
+			EventSourceStream::new(reqwest_builder).with_lossy_utf8(options_set.lossy_utf8().unwrap_or(true));
 		let streamer = CopilotStreamer::new(event_source, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(streamer);
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -484,10 +517,7 @@ impl Adapter for CopilotAdapter {
 		_embed_req: EmbedRequest,
 		_options_set: EmbedOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
-		Err(Error::AdapterNotSupported {
-			adapter_kind: AdapterKind::Copilot,
-			feature: "embed".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Copilot, ServiceType::Embed))
 	}
 
 	fn to_embed_response(
@@ -495,10 +525,7 @@ impl Adapter for CopilotAdapter {
 		_web_response: WebResponse,
 		_options_set: EmbedOptionsSet<'_, '_>,
 	) -> Result<EmbedResponse> {
-		Err(Error::AdapterNotSupported {
-			adapter_kind: AdapterKind::Copilot,
-			feature: "embed".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Copilot, ServiceType::Embed))
 	}
 }
 
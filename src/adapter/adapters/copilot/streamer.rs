@@ -1,13 +1,13 @@
 //! Stream handling for GitHub Copilot Chat API
 
 use super::types::CopilotStreamResponse;
-use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions, parse_tool_arguments};
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{ChatOptionsSet, ToolCall, Usage};
+use crate::chat::{ChatOptionsSet, StopReason, ToolCall, Usage};
 use crate::webc::{Event, EventSourceStream};
 use crate::{Error, ModelIden, Result};
 use futures::stream::Stream;
-use serde_json::from_str;
+use serde_json::{Value, from_str};
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
@@ -27,6 +27,88 @@ impl CopilotStreamer {
 			captured_data: Default::default(),
 		}
 	}
+
+	/// Captures a tool call delta into `captured_data.tool_calls`, accumulating its arguments string
+	/// (parsed at the end, see `take_stream_end`). Returns the (possibly merged) tool call for the event.
+	fn capture_tool_call(
+		&mut self,
+		index: usize,
+		call_id: Option<String>,
+		fn_name: Option<String>,
+		arguments: String,
+	) -> ToolCall {
+		let tool_call = ToolCall {
+			call_id: call_id.clone().unwrap_or_else(|| format!("call_{index}")),
+			fn_name: fn_name.clone().unwrap_or_default(),
+			fn_arguments: Value::String(arguments.clone()),
+			thought_signatures: None,
+		};
+
+		if !self.options.capture_tool_calls {
+			return tool_call;
+		}
+
+		let calls = self.captured_data.tool_calls.get_or_insert_with(Vec::new);
+		if let Some(existing_call) = calls.get_mut(index) {
+			if let Some(existing_args) = existing_call.fn_arguments.as_str() {
+				existing_call.fn_arguments = Value::String(format!("{existing_args}{arguments}"));
+			}
+			// Update call_id and fn_name on the first chunk that has them
+			if let Some(fn_name) = fn_name.filter(|fn_name| !fn_name.is_empty()) {
+				existing_call.fn_name = fn_name;
+			}
+			if let Some(call_id) = call_id {
+				existing_call.call_id = call_id;
+			}
+			existing_call.clone()
+		} else {
+			calls.resize(index + 1, tool_call.clone());
+			tool_call
+		}
+	}
+
+	/// Takes the captured data into the `InterStreamEnd`, with the accumulated tool call arguments parsed.
+	///
+	/// Errors when a captured tool call has malformed arguments (unless `lenient_tool_arguments`).
+	fn take_stream_end(&mut self) -> Result<InterStreamEnd> {
+		let captured_usage = if self.options.capture_usage {
+			self.captured_data.usage.take()
+		} else {
+			None
+		};
+
+		let lenient_tool_arguments = self.options.lenient_tool_arguments;
+		let captured_tool_calls = self
+			.captured_data
+			.tool_calls
+			.take()
+			.map(|tool_calls| {
+				tool_calls
+					.into_iter()
+					.map(|tool_call| {
+						let fn_arguments =
+							parse_tool_arguments(&tool_call.fn_name, tool_call.fn_arguments, lenient_tool_arguments)?;
+						Ok(ToolCall {
+							fn_arguments,
+							..tool_call
+						})
+					})
+					.collect::<Result<Vec<ToolCall>>>()
+			})
+			.transpose()?;
+
+		Ok(InterStreamEnd {
+			captured_usage,
+			captured_stop_reason: self.captured_data.stop_reason.take().map(StopReason::from),
+			captured_text_content: self.captured_data.content.take(),
+			captured_content_parts: None,
+			captured_reasoning_content: self.captured_data.reasoning_content.take(),
+			captured_tool_calls,
+			captured_thought_signatures: None,
+			captured_response_id: None,
+			interrupted_by: None,
+		})
+	}
 }
 
 impl Stream for CopilotStreamer {
@@ -48,26 +130,7 @@ impl Stream for CopilotStreamer {
 					// Check for [DONE] marker
 					if data.trim() == "[DONE]" {
 						self.done = true;
-						let captured_usage = if self.options.capture_usage {
-							self.captured_data.usage.take()
-						} else {
-							None
-						};
-						let inter_stream_end = InterStreamEnd {
-							captured_usage,
-							captured_stop_reason: self
-								.captured_data
-								.stop_reason
-								.take()
-								.map(crate::chat::StopReason::from),
-							captured_text_content: self.captured_data.content.take(),
-							captured_content_parts: None,
-							captured_reasoning_content: self.captured_data.reasoning_content.take(),
-							captured_tool_calls: self.captured_data.tool_calls.take(),
-							captured_thought_signatures: None,
-							captured_response_id: None,
-						};
-						return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
+						return Poll::Ready(Some(self.take_stream_end().map(InterStreamEvent::End)));
 					}
 
 					// Parse the stream response
@@ -111,62 +174,32 @@ impl Stream for CopilotStreamer {
 							}
 						}
 
-						// Handle tool calls delta
-						if let Some(tool_calls) = &delta.tool_calls {
-							for delta_tool_call in tool_calls {
-								if let Some(function) = &delta_tool_call.function {
-									if let (Some(name), Some(id)) = (&function.name, &delta_tool_call.id) {
-										let fn_arguments = function
-											.arguments
-											.as_ref()
-											.and_then(|args| serde_json::from_str(args).ok())
-											.unwrap_or_default();
-
-										let tool_call = ToolCall {
-											call_id: id.clone(),
-											fn_name: name.clone(),
-											fn_arguments,
-											thought_signatures: None,
-										};
-
-										// Capture tool calls if enabled
-										if self.options.capture_tool_calls {
-											match &mut self.captured_data.tool_calls {
-												Some(calls) => calls.push(tool_call.clone()),
-												None => self.captured_data.tool_calls = Some(vec![tool_call.clone()]),
-											}
-										}
-
-										return Poll::Ready(Some(Ok(InterStreamEvent::ToolCallChunk(tool_call))));
-									}
-								}
-							}
+						// Handle tool calls delta (the arguments arrive in fragments, accumulated until the end)
+						let mut tool_call_event: Option<ToolCall> = None;
+						for delta_tool_call in delta.tool_calls.iter().flatten() {
+							let Some(function) = &delta_tool_call.function else {
+								continue;
+							};
+							let tool_call = self.capture_tool_call(
+								delta_tool_call.index as usize,
+								delta_tool_call.id.clone(),
+								function.name.clone(),
+								function.arguments.clone().unwrap_or_default(),
+							);
+							tool_call_event.get_or_insert(tool_call);
 						}
 
-						// If finish_reason is present, send end event
+						// The finish_reason is captured for the end event (on `[DONE]`, or now if no tool call to send)
 						if let Some(finish_reason) = &choice.finish_reason {
 							self.captured_data.stop_reason = Some(finish_reason.clone());
-							self.done = true;
-							let captured_usage = if self.options.capture_usage {
-								self.captured_data.usage.take()
-							} else {
-								None
-							};
-							let inter_stream_end = InterStreamEnd {
-								captured_usage,
-								captured_stop_reason: self
-									.captured_data
-									.stop_reason
-									.take()
-									.map(crate::chat::StopReason::from),
-								captured_text_content: self.captured_data.content.take(),
-								captured_content_parts: None,
-								captured_reasoning_content: self.captured_data.reasoning_content.take(),
-								captured_tool_calls: self.captured_data.tool_calls.take(),
-								captured_thought_signatures: None,
-								captured_response_id: None,
-							};
-							return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
+							if tool_call_event.is_none() {
+								self.done = true;
+								return Poll::Ready(Some(self.take_stream_end().map(InterStreamEvent::End)));
+							}
+						}
+
+						if let Some(tool_call) = tool_call_event {
+							return Poll::Ready(Some(Ok(InterStreamEvent::ToolCallChunk(tool_call))));
 						}
 					}
 
@@ -174,7 +207,12 @@ impl Stream for CopilotStreamer {
 					continue;
 				}
 				Some(Err(e)) => {
-					return Poll::Ready(Some(Err(Error::Internal(format!("Stream error: {}", e)))));
+					let error = Error::Internal(format!("Stream error: {}", e));
+					// End with the partial data captured so far, if any (the error follows, see `ChatStream`)
+					self.done = true;
+					return Poll::Ready(Some(
+						self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+					));
 				}
 				None => {
 					return Poll::Ready(None);
@@ -185,3 +223,63 @@ impl Stream for CopilotStreamer {
 		Poll::Pending
 	}
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterKind;
+	use crate::chat::ChatOptions;
+	use futures::StreamExt;
+	use serde_json::json;
+
+	async fn collect_events(chunks: &[serde_json::Value]) -> Vec<Result<InterStreamEvent>> {
+		let mut body: String = chunks.iter().map(|chunk| format!("data: {chunk}\n\n")).collect();
+		body.push_str("data: [DONE]\n\n");
+		let options = ChatOptions::default().with_capture_tool_calls(true);
+		let streamer = CopilotStreamer::new(
+			EventSourceStream::from_body_chunks(vec![body.into_bytes()]),
+			ModelIden::new(AdapterKind::Copilot, "gpt-4o"),
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		);
+		streamer.collect().await
+	}
+
+	#[tokio::test]
+	async fn test_copilot_streamer_tool_call_split_arguments() -> Result<()> {
+		let tool_call_delta =
+			|tool_call: serde_json::Value| json!({"choices": [{"index": 0, "delta": {"tool_calls": [tool_call]}}]});
+		let chunks = [
+			tool_call_delta(json!({"index": 0, "id": "call_1", "type": "function",
+				"function": {"name": "get_weather", "arguments": ""}})),
+			tool_call_delta(json!({"index": 0, "function": {"arguments": "{\"city\": "}})),
+			tool_call_delta(json!({"index": 0, "function": {"arguments": "\"Paris\"}"}})),
+			json!({"choices": [{"index": 0, "delta": {}, "finish_reason": "tool_calls"}]}),
+		];
+
+		let events = collect_events(&chunks).await.into_iter().collect::<Result<Vec<_>>>()?;
+
+		let tool_call_chunks = events
+			.iter()
+			.filter(|event| matches!(event, InterStreamEvent::ToolCallChunk(_)))
+			.count();
+		assert_eq!(tool_call_chunks, 3);
+		let Some(InterStreamEvent::End(end)) = events.last() else {
+			panic!("should end with InterStreamEvent::End");
+		};
+		let tool_calls = end.captured_tool_calls.as_ref().expect("should have the tool calls");
+		assert_eq!(tool_calls.len(), 1);
+		assert_eq!(tool_calls[0].call_id, "call_1");
+		assert_eq!(tool_calls[0].fn_name, "get_weather");
+		assert_eq!(tool_calls[0].fn_arguments, json!({"city": "Paris"}));
+		assert_eq!(
+			end.captured_stop_reason,
+			Some(StopReason::from("tool_calls".to_string()))
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
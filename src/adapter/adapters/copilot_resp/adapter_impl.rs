@@ -3,7 +3,7 @@ use crate::Headers;
 use crate::adapter::ModelCapabilities;
 use crate::adapter::adapters::copilot::CopilotAdapter;
 use crate::adapter::adapters::copilot_headers::build_copilot_headers;
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, ignored_options};
 use crate::adapter::openai_resp::OpenAIRespAdapter;
 use crate::adapter::openai_resp::OpenAIRespStreamer;
 use crate::adapter::openai_resp::resp_types::RespResponse;
@@ -28,6 +28,18 @@ use value_ext::JsonValueExt;
 /// while reusing the existing OpenAI Responses wire format inside genai.
 pub struct CopilotRespAdapter;
 
+/// The `ChatOptions` payload options sent by the Copilot Responses adapter (the others are reported as ignored).
+const SUPPORTED_OPTIONS: &[&str] = &[
+	"temperature",
+	"max_tokens",
+	"top_p",
+	"stop_sequences",
+	"seed",
+	"response_format",
+	"reasoning_effort",
+	"verbosity",
+];
+
 impl CopilotRespAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = CopilotAdapter::API_KEY_DEFAULT_ENV_NAME;
 
@@ -45,7 +57,7 @@ impl CopilotRespAdapter {
 
 		for msg in chat_req.messages {
 			match msg.role {
-				ChatRole::System => {
+				ChatRole::System | ChatRole::Developer => {
 					if let Some(content) = msg.content.into_joined_texts() {
 						input_items.push(json!({"role": "system", "content": content}));
 					}
@@ -434,7 +446,14 @@ impl Adapter for CopilotRespAdapter {
 		let mut headers = build_copilot_headers(&api_key, &payload, true);
 		headers.merge(("x-vscode-user-agent-library-version", "electron-fetch"));
 
-		Ok(WebRequestData { url, headers, payload })
+		let ignored_options = ignored_options(&chat_options, SUPPORTED_OPTIONS, model.adapter_kind);
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -449,6 +468,8 @@ impl Adapter for CopilotRespAdapter {
 		let usage = resp.usage.map(Usage::from).unwrap_or_default();
 		let (content, reasoning_content) = Self::response_output_to_chat_parts(resp.output, &provider_model_iden)?;
 
+		let usage_reported = !usage.is_empty();
+
 		Ok(ChatResponse {
 			content,
 			reasoning_content,
@@ -456,8 +477,15 @@ impl Adapter for CopilotRespAdapter {
 			provider_model_iden,
 			stop_reason: Some(crate::chat::StopReason::from(resp.status.clone())),
 			usage,
+			usage_reported,
 			captured_raw_body,
 			response_id: Some(resp.id),
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		})
 	}
 
@@ -466,13 +494,16 @@ impl Adapter for CopilotRespAdapter {
 		reqwest_builder: RequestBuilder,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let event_source = EventSourceStream::new(reqwest_builder);
+		let event_source =
+			EventSourceStream::new(reqwest_builder).with_lossy_utf8(options_set.lossy_utf8().unwrap_or(true));
 		let stream = OpenAIRespStreamer::new(event_source, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(stream);
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -481,10 +512,10 @@ impl Adapter for CopilotRespAdapter {
 		_embed_req: EmbedRequest,
 		_options_set: EmbedOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
-		Err(Error::AdapterNotSupported {
-			adapter_kind: AdapterKind::CopilotResp,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(
+			AdapterKind::CopilotResp,
+			ServiceType::Embed,
+		))
 	}
 
 	fn to_embed_response(
@@ -492,10 +523,10 @@ impl Adapter for CopilotRespAdapter {
 		_web_response: WebResponse,
 		_options_set: EmbedOptionsSet<'_, '_>,
 	) -> Result<EmbedResponse> {
-		Err(Error::AdapterNotSupported {
-			adapter_kind: AdapterKind::CopilotResp,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(
+			AdapterKind::CopilotResp,
+			ServiceType::Embed,
+		))
 	}
 }
 
@@ -628,6 +659,7 @@ mod tests {
 		let model_iden = ModelIden::new(AdapterKind::CopilotResp, "gpt-5.4");
 		let web_response = WebResponse {
 			status: StatusCode::OK,
+			headers: reqwest::header::HeaderMap::new(),
 			body: json!({
 				"id": "resp_123",
 				"status": "completed",
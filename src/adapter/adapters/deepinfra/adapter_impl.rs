@@ -0,0 +1,217 @@
+use crate::adapter::ModelCapabilities;
+use crate::adapter::adapters::support::{get_api_key, with_model_deprecation};
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::resolver::{AuthData, Endpoint};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Error, Headers, Result, ServiceTarget};
+use crate::{Model, ModelIden, ModelName};
+use reqwest::RequestBuilder;
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+/// The DeepInfra API is compatible with the OpenAI API (under `/v1/openai/`).
+/// NOTE: This adapter is activated for namespaced model names (e.g., `deepinfra::meta-llama/Meta-Llama-3.1-8B-Instruct`)
+pub struct DeepInfraAdapter;
+
+impl DeepInfraAdapter {
+	pub const API_KEY_DEFAULT_ENV_NAME: &str = "DEEPINFRA_API_KEY";
+}
+
+impl Adapter for DeepInfraAdapter {
+	const DEFAULT_API_KEY_ENV_NAME: Option<&'static str> = Some(Self::API_KEY_DEFAULT_ENV_NAME);
+
+	fn default_endpoint() -> Endpoint {
+		const BASE_URL: &str = "https://api.deepinfra.com/v1/openai/";
+		Endpoint::from_static(BASE_URL)
+	}
+
+	fn default_auth() -> AuthData {
+		match Self::DEFAULT_API_KEY_ENV_NAME {
+			Some(env_name) => AuthData::from_env(env_name),
+			None => AuthData::None,
+		}
+	}
+
+	async fn all_model_names(kind: AdapterKind, endpoint: Endpoint, auth: AuthData) -> Result<Vec<String>> {
+		OpenAIAdapter::list_model_names_for_end_target(kind, endpoint, auth).await
+	}
+
+	/// DeepInfra's `/models` listing has per model `metadata` (context length, max tokens, pricing),
+	/// which is used instead of the name based heuristics when present.
+	async fn all_models(kind: AdapterKind, target: ServiceTarget, web_client: &WebClient) -> Result<Vec<Model>> {
+		let model_iden = ModelIden::new(kind, "");
+		let url = OpenAIAdapter::util_get_service_url(&model_iden, ServiceType::Models, target.endpoint)?;
+		let api_key = get_api_key(target.auth, &model_iden)?;
+		let headers = Headers::from(vec![("Authorization".to_string(), format!("Bearer {api_key}"))]);
+
+		let mut web_response = web_client
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: kind,
+				webc_error,
+			})?;
+
+		let models_data: Vec<Value> = web_response.body.x_take("data")?;
+		models_data.into_iter().map(Self::parse_deepinfra_model_to_model).collect()
+	}
+
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		OpenAIAdapter::util_get_service_url(model, service_type, endpoint)
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		chat_options: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, None)
+	}
+
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		OpenAIAdapter::to_chat_response(model_iden, web_response, options_set)
+	}
+
+	fn to_chat_stream(
+		model_iden: ModelIden,
+		reqwest_builder: RequestBuilder,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		OpenAIAdapter::to_chat_stream(model_iden, reqwest_builder, options_set)
+	}
+
+	fn to_embed_request_data(
+		service_target: ServiceTarget,
+		embed_req: EmbedRequest,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		OpenAIAdapter::to_embed_request_data(service_target, embed_req, options_set)
+	}
+
+	fn to_embed_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		OpenAIAdapter::to_embed_response(model_iden, web_response, options_set)
+	}
+}
+
+// region:    --- Support
+
+impl DeepInfraAdapter {
+	/// Parse one entry of the DeepInfra models listing, e.g.:
+	/// `{"id": "...", "object": "model", "metadata": {"context_length": 131072, "max_tokens": 131072, "pricing": {...}}}`
+	///
+	/// - `metadata.context_length` (or `metadata.max_tokens`) becomes `max_input_tokens`
+	/// - `metadata.pricing` becomes `additional_properties.pricing`
+	/// - Record deprecation fields (e.g., `deprecated`, `deprecation_date`) set the `Model` deprecation
+	/// - Missing metadata falls back on the `ModelCapabilities` heuristics
+	fn parse_deepinfra_model_to_model(mut model_data: Value) -> Result<Model> {
+		let kind = AdapterKind::DeepInfra;
+		let model_id: String = model_data.x_take("id")?;
+		let mut metadata: Value = model_data.x_take("metadata").unwrap_or_default();
+
+		let (inferred_max_input, inferred_max_output) = ModelCapabilities::infer_token_limits(kind, &model_id);
+		let max_input_tokens = metadata
+			.x_get::<u32>("context_length")
+			.or_else(|_| metadata.x_get::<u32>("max_tokens"))
+			.ok()
+			.or(inferred_max_input);
+
+		let model_name: ModelName = model_id.clone().into();
+		let mut model = Model::new(model_name, model_id.clone())
+			.with_max_input_tokens(max_input_tokens)
+			.with_max_output_tokens(inferred_max_output)
+			.with_streaming(ModelCapabilities::supports_streaming(kind, &model_id))
+			.with_tool_calls(ModelCapabilities::supports_tool_calls(kind, &model_id))
+			.with_json_mode(ModelCapabilities::supports_json_mode(kind, &model_id))
+			.with_reasoning(ModelCapabilities::supports_reasoning(kind, &model_id))
+			.with_input_modalities(ModelCapabilities::infer_input_modalities(kind, &model_id))
+			.with_output_modalities(ModelCapabilities::infer_output_modalities(kind, &model_id));
+
+		if let Ok(pricing) = metadata.x_take::<Value>("pricing") {
+			model = model.with_additional_properties(json!({ "pricing": pricing }));
+		}
+
+		Ok(with_model_deprecation(model, &model_data))
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_deepinfra_model_with_metadata() -> Result<()> {
+		let model_data = json!({
+			"id": "meta-llama/Meta-Llama-3.1-8B-Instruct",
+			"object": "model",
+			"owned_by": "deepinfra",
+			"metadata": {
+				"description": "Llama 3.1 8B",
+				"context_length": 131072,
+				"max_tokens": 131072,
+				"pricing": {"input_tokens": 0.03, "output_tokens": 0.05}
+			}
+		});
+
+		let model = DeepInfraAdapter::parse_deepinfra_model_to_model(model_data)?;
+
+		assert_eq!(model.id, "meta-llama/Meta-Llama-3.1-8B-Instruct");
+		assert_eq!(model.max_input_tokens, Some(131_072));
+		assert_eq!(
+			model.additional_properties,
+			Some(json!({"pricing": {"input_tokens": 0.03, "output_tokens": 0.05}}))
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_parse_deepinfra_model_deprecated() -> Result<()> {
+		let model_data = json!({
+			"id": "meta-llama/Llama-2-70b-chat-hf",
+			"object": "model",
+			"deprecated": 1_700_000_000,
+			"metadata": {"context_length": 4096}
+		});
+
+		let model = DeepInfraAdapter::parse_deepinfra_model_to_model(model_data)?;
+
+		assert!(model.is_deprecated());
+		assert_eq!(model.deprecation_date.as_deref(), Some("2023-11-14T22:13:20Z"));
+		assert_eq!(model.max_input_tokens, Some(4_096));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_parse_deepinfra_model_without_metadata() -> Result<()> {
+		let model_data = json!({"id": "BAAI/bge-large-en-v1.5", "object": "model", "metadata": null});
+
+		let model = DeepInfraAdapter::parse_deepinfra_model_to_model(model_data)?;
+
+		let (inferred_max_input, _) =
+			ModelCapabilities::infer_token_limits(AdapterKind::DeepInfra, "BAAI/bge-large-en-v1.5");
+		assert_eq!(model.max_input_tokens, inferred_max_input);
+		assert_eq!(model.additional_properties, None);
+		assert!(!model.is_deprecated());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
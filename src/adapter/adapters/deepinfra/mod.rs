@@ -0,0 +1,11 @@
+//! API Documentation:     <https://deepinfra.com/docs/openai_api>
+//! Model Names:           <https://deepinfra.com/models>
+//! Pricing:               <https://deepinfra.com/pricing>
+
+// region:    --- Modules
+
+mod adapter_impl;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
@@ -13,7 +13,7 @@ use serde_json::Value;
 use value_ext::JsonValueExt;
 
 pub struct DeepSeekAdapter;
-const MODELS: &[&str] = &["deepseek-chat", "deepseek-reasoner"];
+pub(in crate::adapter) const MODELS: &[&str] = &["deepseek-chat", "deepseek-reasoner"];
 
 impl DeepSeekAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "DEEPSEEK_API_KEY";
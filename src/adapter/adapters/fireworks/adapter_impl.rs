@@ -86,6 +86,7 @@ impl Adapter for FireworksAdapter {
 				ServiceType::ChatStream => Some(256_000),
 				_ => Some(5_000),
 			},
+			..Default::default()
 		};
 
 		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, Some(custom))
@@ -123,3 +124,81 @@ impl Adapter for FireworksAdapter {
 		OpenAIAdapter::to_embed_response(model_iden, web_response, options_set)
 	}
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::{AdapterDispatcher, ModelCapabilities};
+	use crate::common::Modality;
+
+	fn target(model_name: &str) -> ServiceTarget {
+		ServiceTarget {
+			endpoint: FireworksAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Fireworks, model_name),
+		}
+	}
+
+	/// Fireworks is activated by the namespace, or by `fireworks` in the model name.
+	#[test]
+	fn test_fireworks_routing() {
+		for model in [
+			"fireworks::qwen3-30b-a3b",
+			"fireworks::accounts/fireworks/models/qwen3-30b-a3b",
+			"accounts/fireworks/models/qwen3-30b-a3b",
+		] {
+			assert_eq!(
+				AdapterKind::from_model(model).ok(),
+				Some(AdapterKind::Fireworks),
+				"{model}"
+			);
+		}
+		assert_eq!(
+			AdapterKind::Fireworks.default_key_env_name(),
+			Some(FireworksAdapter::API_KEY_DEFAULT_ENV_NAME)
+		);
+	}
+
+	#[test]
+	fn test_fireworks_request_data_model_and_max_tokens() -> Result<()> {
+		let chat = |model_name: &str, service_type: ServiceType| {
+			AdapterDispatcher::to_web_request_data(
+				target(model_name),
+				service_type,
+				ChatRequest::from_user("hello"),
+				ChatOptionsSet::default(),
+			)
+		};
+
+		// -- Short name, with the accounts prefix added
+		let data = chat("fireworks::qwen3-30b-a3b", ServiceType::Chat)?;
+		assert_eq!(data.url, "https://api.fireworks.ai/inference/v1/chat/completions");
+		assert_eq!(data.payload["model"], "accounts/fireworks/models/qwen3-30b-a3b");
+		assert_eq!(data.payload["max_tokens"], 5_000);
+
+		// -- Full name, kept as is
+		let data = chat(
+			"fireworks::accounts/fireworks/models/qwen3-30b-a3b",
+			ServiceType::ChatStream,
+		)?;
+		assert_eq!(data.payload["model"], "accounts/fireworks/models/qwen3-30b-a3b");
+		assert_eq!(data.payload["max_tokens"], 256_000);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_fireworks_capabilities() {
+		let kind = AdapterKind::Fireworks;
+		let model_name = "accounts/fireworks/models/qwen3-30b-a3b";
+		assert!(ModelCapabilities::supports_streaming(kind, model_name));
+		assert!(ModelCapabilities::supports_tool_calls(kind, model_name));
+		assert!(ModelCapabilities::supports_json_mode(kind, model_name));
+		assert!(ModelCapabilities::infer_input_modalities(kind, model_name).contains(&Modality::Text));
+		assert_eq!(ModelCapabilities::known_max_output_tokens(kind, model_name), None);
+	}
+}
+
+// endregion: --- Tests
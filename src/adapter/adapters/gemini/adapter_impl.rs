@@ -1,17 +1,20 @@
 use crate::adapter::ModelCapabilities;
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{
+	get_api_key, ignored_options, join_service_url, synthesize_call_id, tool_calls_by_call_id, tool_response_fn_name,
+};
 use crate::adapter::gemini::GeminiStreamer;
-use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::adapter::{Adapter, AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
 	Binary, BinarySource, ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream,
-	ChatStreamResponse, CompletionTokensDetails, ContentPart, MessageContent, PromptTokensDetails, ReasoningEffort,
-	StopReason, Tool, ToolCall, ToolConfig, ToolName, Usage,
+	ChatStreamResponse, CompletionTokensDetails, ContentPart, IgnoredOption, MessageContent, PromptTokensDetails,
+	ReasoningEffort, StopReason, Tool, ToolCall, ToolChoice, ToolConfig, ToolName, ToolResponse, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{WebResponse, WebStream};
 use crate::{Error, Headers, Model, ModelIden, Result, ServiceTarget};
 use reqwest::RequestBuilder;
 use serde_json::{Value, json};
+use std::collections::HashMap;
 use value_ext::JsonValueExt;
 
 pub struct GeminiAdapter;
@@ -31,6 +34,20 @@ pub(in crate::adapter) const MODELS: &[&str] = &[
 	"text-embedding-004",
 ];
 // Per gemini doc (https://x.com/jeremychone/status/1916501987371438372)
+/// The `ChatOptions` payload options sent by the Gemini adapter (the others are reported as ignored).
+const SUPPORTED_OPTIONS: &[&str] = &[
+	"temperature",
+	"max_tokens",
+	"top_p",
+	"top_k",
+	"stop_sequences",
+	"response_format",
+	"reasoning_effort",
+	"include_thoughts",
+	"cached_content",
+	"tool_choice",
+];
+
 pub(in crate::adapter) const REASONING_ZERO: u32 = 0;
 pub(in crate::adapter) const REASONING_LOW: u32 = 1000;
 pub(in crate::adapter) const REASONING_MEDIUM: u32 = 8000;
@@ -55,13 +72,36 @@ fn insert_gemini_thinking_budget_value(payload: &mut Value, effort: &ReasoningEf
 	Ok(())
 }
 
+/// Converts the tool choice to the Gemini `toolConfig` (`Tool` is `ANY` restricted to the tool name).
+///
+/// See: https://ai.google.dev/gemini-api/docs/function-calling#function_calling_modes
+fn tool_choice_to_gemini_tool_config(tool_choice: &ToolChoice) -> Value {
+	let function_calling_config = match tool_choice {
+		ToolChoice::Auto => json!({"mode": "AUTO"}),
+		ToolChoice::Required => json!({"mode": "ANY"}),
+		ToolChoice::None => json!({"mode": "NONE"}),
+		ToolChoice::Tool(name) => json!({"mode": "ANY", "allowedFunctionNames": [name]}),
+	};
+	json!({"functionCallingConfig": function_calling_config})
+}
+
 // curl \
 //   -H 'Content-Type: application/json' \
 //   -d '{"contents":[{"parts":[{"text":"Explain how AI works"}]}]}' \
 //   -X POST 'https://generativelanguage.googleapis.com/v1beta/models/gemini-1.5-flash-latest:generateContent?key=YOUR_API_KEY'
 
+/// Gemini auth is either:
+/// - An API key (`GEMINI_API_KEY` or `AuthData::Key`), sent in the `x-goog-api-key` header (never in the url).
+/// - An OAuth2 access token (`AuthData::MultiKeys` with the `oauth_token` key), sent as `Authorization: Bearer`.
+/// - An API key sent as the `key` query parameter (`AuthData::MultiKeys` with the `query_api_key` key),
+///   only for the old proxies requiring it (the key is then in the url).
 impl GeminiAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "GEMINI_API_KEY";
+
+	/// `AuthData::MultiKeys` key name for an OAuth2 access token (sent as `Authorization: Bearer`).
+	pub const OAUTH_TOKEN_KEY_NAME: &str = "oauth_token";
+	/// `AuthData::MultiKeys` key name for an API key sent as the `key` query parameter (opt-out of the header).
+	pub const QUERY_API_KEY_NAME: &str = "query_api_key";
 }
 
 impl Adapter for GeminiAdapter {
@@ -81,14 +121,13 @@ impl Adapter for GeminiAdapter {
 
 	async fn all_model_names(kind: AdapterKind, endpoint: Endpoint, auth: AuthData) -> Result<Vec<String>> {
 		// -- url
-		let base_url = endpoint.base_url();
-		let url = format!("{base_url}models");
+		let url = AdapterDispatcher::get_service_url(&ModelIden::new(kind, ""), ServiceType::Models, endpoint)?;
 
 		// -- auth / headers
-		let api_key = auth.single_key_value().ok();
-		let headers = api_key
-			.map(|api_key| Headers::from(("x-goog-api-key".to_string(), api_key)))
-			.unwrap_or_default();
+		let (headers, url) = match Self::apply_auth(auth, &ModelIden::new(kind, ""), url.clone()) {
+			Ok(headers_and_url) => headers_and_url,
+			Err(_) => (Headers::default(), url),
+		};
 
 		// -- Exec request
 		let web_c = crate::webc::WebClient::default();
@@ -127,11 +166,8 @@ impl Adapter for GeminiAdapter {
 		// 获取 models API 的 URL
 		let url = Self::get_service_url(&model_iden, ServiceType::Models, endpoint)?;
 
-		// 获取 API key
-		let api_key = get_api_key(auth, &model_iden)?;
-
-		// 构建请求头 - Gemini 使用 x-goog-api-key 头部
-		let headers = Headers::from(vec![(String::from("x-goog-api-key"), api_key)]);
+		// 构建请求头 - Gemini 使用 x-goog-api-key 头部 (或 OAuth Bearer)
+		let (headers, url) = Self::apply_auth(auth, &model_iden, url)?;
 
 		// 使用传入的 WebClient 发送请求
 		let web_response = web_client
@@ -196,18 +232,16 @@ impl Adapter for GeminiAdapter {
 		Ok(models)
 	}
 
-	/// NOTE: As Google Gemini has decided to put their API_KEY in the URL,
-	///       this will return the URL without the API_KEY in it. The API_KEY will need to be added by the caller.
+	/// NOTE: The url never has the API key (see `GeminiAdapter::apply_auth`).
 	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
-		let base_url = endpoint.base_url();
 		let (_, model_name) = model.model_name.namespace_and_name();
-		let url = match service_type {
-			ServiceType::Chat => format!("{base_url}models/{model_name}:generateContent"),
-			ServiceType::ChatStream => format!("{base_url}models/{model_name}:streamGenerateContent"),
-			ServiceType::Embed => format!("{base_url}models/{model_name}:embedContent"), // Gemini embeddings API
-			ServiceType::Models => format!("{base_url}models"),
+		let suffix = match service_type {
+			ServiceType::Chat => format!("models/{model_name}:generateContent"),
+			ServiceType::ChatStream => format!("models/{model_name}:streamGenerateContent"),
+			ServiceType::Embed => format!("models/{model_name}:embedContent"), // Gemini embeddings API
+			ServiceType::Models => "models".to_string(),
 		};
-		Ok(url)
+		join_service_url(endpoint.base_url(), &suffix)
 	}
 
 	fn to_web_request_data(
@@ -219,18 +253,21 @@ impl Adapter for GeminiAdapter {
 		let ServiceTarget { endpoint, auth, model } = target;
 		let (_, model_name) = model.model_name.namespace_and_name();
 
-		// -- api_key
-		let api_key = get_api_key(auth, &model)?;
-		let headers = Headers::from(("x-goog-api-key".to_string(), api_key.to_string()));
-
+		let ignored_options = Self::gemini_ignored_options(&options_set, model.adapter_kind);
 		let (payload, provider_model_name) =
 			Self::build_gemini_request_payload(&model, model_name, chat_req, options_set)?;
 
-		// -- url
+		// -- url & auth
 		let provider_model = model.from_name(&provider_model_name);
 		let url = Self::get_service_url(&provider_model, service_type, endpoint)?;
+		let (headers, url) = Self::apply_auth(auth, &model, url)?;
 
-		Ok(WebRequestData { url, headers, payload })
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -287,12 +324,7 @@ impl Adapter for GeminiAdapter {
 				parts.push(ContentPart::Text(combined_text));
 			}
 		}
-		let mut reasoning_text = String::new();
-		if !reasonings.is_empty() {
-			for reasoning in &reasonings {
-				reasoning_text.push_str(reasoning);
-			}
-		}
+		let reasoning_content = (!reasonings.is_empty()).then(|| reasonings.concat());
 
 		if !binary_parts.is_empty() {
 			for binary in binary_parts {
@@ -303,15 +335,24 @@ impl Adapter for GeminiAdapter {
 		parts.extend(tool_calls.into_iter().map(ContentPart::ToolCall));
 		let content = MessageContent::from_parts(parts);
 
+		let usage_reported = !usage.is_empty();
+
 		Ok(ChatResponse {
 			content,
-			reasoning_content: Some(reasoning_text),
+			reasoning_content,
 			model_iden,
 			provider_model_iden,
 			stop_reason,
 			usage,
+			usage_reported,
 			captured_raw_body: None, // Set by the client exec_chat
 			response_id: None,
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		})
 	}
 
@@ -320,7 +361,8 @@ impl Adapter for GeminiAdapter {
 		reqwest_builder: RequestBuilder,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let web_stream = WebStream::new_with_pretty_json_array(reqwest_builder);
+		let web_stream = WebStream::new_with_pretty_json_array(reqwest_builder)
+			.with_lossy_utf8(options_set.lossy_utf8().unwrap_or(true));
 
 		let gemini_stream = GeminiStreamer::new(web_stream, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(gemini_stream);
@@ -328,6 +370,8 @@ impl Adapter for GeminiAdapter {
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -407,22 +451,27 @@ impl GeminiAdapter {
 			// thoughtSignature, thought+text (reasoning), functionCall, text.
 			// We extract them in priority order.
 
-			// -- Thought signature (Gemini 3+) or legacy thought boolean
+			// -- Thought signature (Gemini 3+)
 			if let Some(sig) = take_string(&mut part, "thoughtSignature") {
 				content.push(GeminiChatContent::ThoughtSignature(sig));
-			} else if take_bool(&mut part, "thought") {
-				// Legacy: `thought: true` + `text` = reasoning content
-				if let Some(reasoning) = take_string(&mut part, "text") {
-					content.push(GeminiChatContent::Reasoning(reasoning));
-				}
+			}
+
+			// -- Thought summary (`thought: true` + `text`, with `includeThoughts`), kept out of the text
+			// NOTE: Can be on the same part as a thought signature.
+			if take_bool(&mut part, "thought")
+				&& let Some(reasoning) = take_string(&mut part, "text")
+			{
+				content.push(GeminiChatContent::Reasoning(reasoning));
 			}
 
 			// -- Function call
 			if let Ok(fc) = part.x_take::<Value>("functionCall") {
 				let fn_name: String = fc.x_get("name").unwrap_or_default();
-				// Gemini omits call_id; synthesize a unique one to avoid
-				// collisions when the same tool is called multiple times.
-				let call_id = format!("call#{}#{}", fn_name, tool_call_counter);
+				// Gemini usually omits the call id; synthesize a deterministic one (unique per response,
+				// even when the same tool is called multiple times), mapped back to the name on replay.
+				let call_id = fc
+					.x_get::<String>("id")
+					.unwrap_or_else(|_| synthesize_call_id(&fn_name, tool_call_counter));
 				tool_call_counter += 1;
 				content.push(GeminiChatContent::ToolCall(ToolCall {
 					call_id,
@@ -457,6 +506,21 @@ impl GeminiAdapter {
 		})
 	}
 
+	/// Returns the options not sent by `build_gemini_request_payload` (see `support::ignored_options`).
+	pub(in crate::adapter) fn gemini_ignored_options(
+		options_set: &ChatOptionsSet,
+		adapter_kind: AdapterKind,
+	) -> Vec<IgnoredOption> {
+		let mut ignored_options = ignored_options(options_set, SUPPORTED_OPTIONS, adapter_kind);
+		if let Some(ChatResponseFormat::JsonMode) = options_set.response_format() {
+			ignored_options.push(IgnoredOption::new(
+				"response_format",
+				"JsonMode is not supported by Gemini (use JsonSpec)",
+			));
+		}
+		ignored_options
+	}
+
 	/// Builds the Gemini JSON payload from a ChatRequest, including reasoning budget
 	/// resolution, system instruction, tools, response format, and chat options.
 	/// Returns (payload, provider_model_name) where provider_model_name may differ
@@ -527,9 +591,12 @@ impl GeminiAdapter {
 		}
 
 		// -- Opt-in for includeThoughts: only request thought content when
-		// the caller explicitly asks for reasoning content capture.
+		// the caller explicitly asks for it (or for reasoning content capture).
 		// Thought *signatures* are always returned by the API regardless of this flag.
-		if options_set.capture_reasoning_content() == Some(true) {
+		let include_thoughts = options_set
+			.include_thoughts()
+			.or_else(|| options_set.capture_reasoning_content());
+		if include_thoughts == Some(true) {
 			payload.x_insert("/generationConfig/thinkingConfig/includeThoughts", true)?;
 		}
 
@@ -540,9 +607,17 @@ impl GeminiAdapter {
 			payload.x_insert("systemInstruction", json!({ "parts": [{ "text": system }] }))?;
 		}
 
-		// -- Tools
+		// -- Tools (the tool choice is only sent with tools)
 		if let Some(tools) = tools {
 			payload.x_insert("tools", tools)?;
+			if let Some(tool_choice) = options_set.tool_choice() {
+				payload.x_insert("toolConfig", tool_choice_to_gemini_tool_config(tool_choice))?;
+			}
+		}
+
+		// -- Explicit cache (the cached system and tools must not be repeated in the request)
+		if let Some(cached_content) = options_set.cached_content() {
+			payload.x_insert("cachedContent", cached_content)?;
 		}
 
 		// -- Response Format
@@ -567,6 +642,10 @@ impl GeminiAdapter {
 			payload.x_insert("/generationConfig/topP", top_p)?;
 		}
 
+		if let Some(top_k) = options_set.top_k() {
+			payload.x_insert("/generationConfig/topK", top_k)?;
+		}
+
 		Ok((payload, provider_model_name.to_string()))
 	}
 
@@ -644,7 +723,7 @@ impl GeminiAdapter {
 
 	/// Takes the genai ChatMessages and builds the System string and JSON Messages for Gemini.
 	/// - Role mapping `ChatRole:User -> role: "user"`, `ChatRole::Assistant -> role: "model"`
-	/// - `ChatRole::System` is concatenated (with an empty line) into a single `system` for the system instruction.
+	/// - `ChatRole::System` (and `ChatRole::Developer`) is concatenated (with an empty line) into a single `system` for the system instruction.
 	///   - This adapter uses version v1beta, which supports `systemInstruction`
 	/// - The eventual `chat_req.system` is pushed first into the "systemInstruction"
 	pub(in crate::adapter) fn into_gemini_request_parts(
@@ -654,6 +733,9 @@ impl GeminiAdapter {
 		let mut contents: Vec<Value> = Vec::new();
 		let mut systems: Vec<String> = Vec::new();
 
+		// Gemini references the function name (not the call id) in the `functionResponse`
+		let tool_calls = tool_calls_by_call_id(&chat_req.messages);
+
 		if let Some(system) = chat_req.system {
 			systems.push(system);
 		}
@@ -662,7 +744,7 @@ impl GeminiAdapter {
 		for msg in chat_req.messages {
 			match msg.role {
 				// For now, system goes as "user" (later, we might have adapter_config.system_to_user_impl)
-				ChatRole::System => {
+				ChatRole::System | ChatRole::Developer => {
 					if let Some(content) = msg.content.into_joined_texts() {
 						systems.push(content);
 					}
@@ -700,15 +782,7 @@ impl GeminiAdapter {
 								}));
 							}
 							ContentPart::ToolResponse(tool_response) => {
-								parts_values.push(json!({
-									"functionResponse": {
-										"name": tool_response.call_id,
-										"response": {
-											"name": tool_response.call_id,
-											"content": tool_response.content,
-										}
-									}
-								}));
+								parts_values.push(function_response_value(tool_response, &tool_calls));
 							}
 							ContentPart::ThoughtSignature(thought) => {
 								parts_values.push(json!({
@@ -811,15 +885,7 @@ impl GeminiAdapter {
 								}));
 							}
 							ContentPart::ToolResponse(tool_response) => {
-								parts_values.push(json!({
-									"functionResponse": {
-										"name": tool_response.call_id,
-										"response": {
-											"name": tool_response.call_id,
-											"content": tool_response.content,
-										}
-									}
-								}));
+								parts_values.push(function_response_value(tool_response, &tool_calls));
 							}
 							ContentPart::ThoughtSignature(thought) => {
 								parts_values.push(json!({
@@ -1016,6 +1082,32 @@ pub(in crate::adapter) struct GeminiChatRequestParts {
 	pub tools: Option<Vec<Value>>,
 }
 
+// region:    --- Auth
+
+impl GeminiAdapter {
+	/// Returns the auth headers and the `url` for the `auth` (see the `GeminiAdapter` auth kinds).
+	/// The `url` is returned as is, except for the `query_api_key` opt-out.
+	pub(in crate::adapter) fn apply_auth(auth: AuthData, model: &ModelIden, url: String) -> Result<(Headers, String)> {
+		if let AuthData::MultiKeys(keys) = &auth {
+			if let Some(token) = keys.get(Self::OAUTH_TOKEN_KEY_NAME) {
+				return Ok((
+					Headers::from(("Authorization".to_string(), format!("Bearer {token}"))),
+					url,
+				));
+			}
+			if let Some(api_key) = keys.get(Self::QUERY_API_KEY_NAME) {
+				let url = crate::support::url_with_extra_query_params(&url, &[("key".to_string(), api_key.clone())])?;
+				return Ok((Headers::default(), url));
+			}
+		}
+
+		let api_key = get_api_key(auth, model)?;
+		Ok((Headers::from(("x-goog-api-key".to_string(), api_key)), url))
+	}
+}
+
+// endregion: --- Auth
+
 // region:    --- Helpers
 
 /// Extract and remove a string field from a JSON Value.
@@ -1033,11 +1125,171 @@ fn take_bool(v: &mut Value, key: &str) -> bool {
 		.unwrap_or(false)
 }
 
+/// Builds the `functionResponse` part, named after the function of the answered tool call
+/// (see `tool_response_fn_name`, e.g., for a transcript with OpenAI call ids).
+/// A failed tool execution uses the `error` key of the response
+/// (Gemini convention is `output` or `content` for results, `error` for failures).
+fn function_response_value(tool_response: ToolResponse, tool_calls: &HashMap<String, ToolCall>) -> Value {
+	let fn_name = tool_response_fn_name(&tool_response.call_id, tool_calls);
+	let content_key = if tool_response.is_error { "error" } else { "content" };
+	let mut response = json!({"name": fn_name});
+	response[content_key] = tool_response.content.into();
+	json!({
+		"functionResponse": {
+			"name": fn_name,
+			"response": response,
+		}
+	})
+}
+
 // endregion: --- Helpers
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::chat::{ChatMessage, ChatOptions, SystemHandling};
+
+	#[test]
+	fn into_gemini_request_parts_tool_response_error() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		let chat_req = ChatRequest::from_user("What is the weather?").append_message(ChatMessage::from(vec![
+			ToolResponse::new("call_ok", "sunny"),
+			ToolResponse::error("call_err", "city not found"),
+		]));
+
+		let parts = GeminiAdapter::into_gemini_request_parts(&model_iden, chat_req)?;
+
+		let tool_entry = parts.contents.last().expect("should have the tool response entry");
+		let ok_response = &tool_entry["parts"][0]["functionResponse"]["response"];
+		let err_response = &tool_entry["parts"][1]["functionResponse"]["response"];
+		assert_eq!(ok_response, &json!({"name": "call_ok", "content": "sunny"}));
+		assert_eq!(err_response, &json!({"name": "call_err", "error": "city not found"}));
+
+		Ok(())
+	}
+
+	#[test]
+	fn into_gemini_request_parts_replays_openai_tool_calls() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		// -- A transcript from an OpenAI-shaped provider (opaque call ids)
+		let chat_req = ChatRequest::from_user("What is the weather in Paris and Lyon?")
+			.append_message(ChatMessage::assistant(vec![
+				ToolCall {
+					call_id: "call_Xa1".to_string(),
+					fn_name: "get_weather".to_string(),
+					fn_arguments: json!({"city": "Paris"}),
+					thought_signatures: None,
+				},
+				ToolCall {
+					call_id: "call_Xa2".to_string(),
+					fn_name: "get_time".to_string(),
+					fn_arguments: json!({"city": "Lyon"}),
+					thought_signatures: None,
+				},
+			]))
+			.append_message(ChatMessage::from(ToolResponse::new("call_Xa1", "sunny")))
+			.append_message(ChatMessage::from(ToolResponse::new("call_Xa2", "10:00")));
+
+		let parts = GeminiAdapter::into_gemini_request_parts(&model_iden, chat_req)?;
+
+		let model_entry = &parts.contents[1];
+		assert_eq!(model_entry["role"], "model");
+		assert_eq!(model_entry["parts"][0]["functionCall"]["name"], "get_weather");
+		assert_eq!(model_entry["parts"][1]["functionCall"]["args"], json!({"city": "Lyon"}));
+
+		// both responses in one entry, named after their function
+		let tool_entry = &parts.contents[2];
+		assert_eq!(parts.contents.len(), 3);
+		assert_eq!(tool_entry["parts"][0]["functionResponse"]["name"], "get_weather");
+		assert_eq!(tool_entry["parts"][1]["functionResponse"]["name"], "get_time");
+		assert_eq!(
+			tool_entry["parts"][1]["functionResponse"]["response"],
+			json!({"name": "get_time", "content": "10:00"})
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn build_gemini_request_payload_tool_choice() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		let chat_req =
+			ChatRequest::from_user("What is the weather in Paris?").with_tools(vec![Tool::new("get_weather")]);
+		let cases = [
+			(ToolChoice::Auto, json!({"mode": "AUTO"})),
+			(ToolChoice::Required, json!({"mode": "ANY"})),
+			(ToolChoice::None, json!({"mode": "NONE"})),
+			(
+				ToolChoice::tool("get_weather"),
+				json!({"mode": "ANY", "allowedFunctionNames": ["get_weather"]}),
+			),
+		];
+
+		for (tool_choice, expected) in cases {
+			let options = ChatOptions::default().with_tool_choice(tool_choice);
+			let (payload, _) = GeminiAdapter::build_gemini_request_payload(
+				&model_iden,
+				"gemini-2.5-flash",
+				chat_req.clone(),
+				ChatOptionsSet::default().with_chat_options(Some(&options)),
+			)?;
+			assert_eq!(payload["toolConfig"]["functionCallingConfig"], expected);
+		}
+
+		// -- Not sent without tools, and no Gemini parallel tool calls option
+		let options = ChatOptions::default()
+			.with_tool_choice(ToolChoice::Required)
+			.with_parallel_tool_calls(false);
+		let options_set = ChatOptionsSet::default().with_chat_options(Some(&options));
+		let (payload, _) = GeminiAdapter::build_gemini_request_payload(
+			&model_iden,
+			"gemini-2.5-flash",
+			ChatRequest::from_user("Hi"),
+			options_set.clone(),
+		)?;
+		assert!(payload.get("toolConfig").is_none());
+		let ignored_options = GeminiAdapter::gemini_ignored_options(&options_set, AdapterKind::Gemini);
+		let ignored_options: Vec<&str> = ignored_options.iter().map(|ignored| ignored.option.as_str()).collect();
+		assert_eq!(ignored_options, vec!["parallel_tool_calls"]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn into_gemini_request_parts_system_handling() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Gemini, "gemma-3-27b-it");
+		let chat_req = ChatRequest::from_system("Be concise.").append_message(ChatMessage::user("Hi"));
+
+		// -- Native: the system instruction
+		let parts = GeminiAdapter::into_gemini_request_parts(&model_iden, chat_req.clone())?;
+		assert_eq!(parts.system.as_deref(), Some("Be concise."));
+		assert_eq!(parts.contents, vec![json!({"role": "user", "parts": [{"text": "Hi"}]})]);
+
+		// -- PrependToFirstUser
+		let mut prepended = chat_req.clone();
+		prepended.apply_system_handling(SystemHandling::PrependToFirstUser);
+		let parts = GeminiAdapter::into_gemini_request_parts(&model_iden, prepended)?;
+		assert_eq!(parts.system, None);
+		assert_eq!(
+			parts.contents,
+			vec![json!({"role": "user", "parts": [{"text": "Be concise.\n\nHi"}]})]
+		);
+
+		// -- AsUserMessage
+		let mut as_user = chat_req;
+		as_user.apply_system_handling(SystemHandling::AsUserMessage);
+		let parts = GeminiAdapter::into_gemini_request_parts(&model_iden, as_user)?;
+		assert_eq!(parts.system, None);
+		assert_eq!(
+			parts.contents,
+			vec![
+				json!({"role": "user", "parts": [{"text": "Be concise."}]}),
+				json!({"role": "user", "parts": [{"text": "Hi"}]}),
+			]
+		);
+
+		Ok(())
+	}
 
 	#[test]
 	fn merge_consecutive_tool_responses() {
@@ -1180,4 +1432,161 @@ mod tests {
 		};
 		assert!(matches!(err, Error::ChatResponse { .. }));
 	}
+
+	#[test]
+	fn to_chat_response_thought_summary_as_reasoning_content() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		let body = json!({
+			"candidates": [{
+				"content": {
+					"role": "model",
+					"parts": [
+						{"text": "**Recalling Rayleigh scattering**", "thought": true, "thoughtSignature": "sig-1"},
+						{"text": " Short wavelengths scatter more.", "thought": true},
+						{"text": "Because of Rayleigh scattering."}
+					]
+				},
+				"finishReason": "STOP"
+			}],
+			"usageMetadata": {"promptTokenCount": 8, "candidatesTokenCount": 6, "thoughtsTokenCount": 40, "totalTokenCount": 54}
+		});
+		let web_response = WebResponse {
+			status: reqwest::StatusCode::OK,
+			headers: Default::default(),
+			body,
+		};
+
+		let chat_res = GeminiAdapter::to_chat_response(model_iden, web_response, ChatOptionsSet::default())?;
+
+		assert_eq!(chat_res.first_text(), Some("Because of Rayleigh scattering."));
+		assert_eq!(
+			chat_res.reasoning_content.as_deref(),
+			Some("**Recalling Rayleigh scattering** Short wavelengths scatter more.")
+		);
+		assert_eq!(chat_res.content.texts().len(), 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn to_web_request_data_include_thoughts() -> Result<()> {
+		let build_payload = |chat_options: ChatOptions| -> Result<Value> {
+			let model = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+			let target = ServiceTarget {
+				endpoint: GeminiAdapter::default_endpoint(),
+				auth: AuthData::from_single("test-api-key"),
+				model,
+			};
+			let options_set = ChatOptionsSet::default().with_chat_options(Some(&chat_options));
+			let web_req = GeminiAdapter::to_web_request_data(
+				target,
+				ServiceType::Chat,
+				ChatRequest::from_user("Why is the sky blue?"),
+				options_set,
+			)?;
+			Ok(web_req.payload)
+		};
+		let include_thoughts =
+			|payload: &Value| payload.pointer("/generationConfig/thinkingConfig/includeThoughts").cloned();
+
+		let payload = build_payload(ChatOptions::default().with_include_thoughts(true))?;
+		assert_eq!(include_thoughts(&payload), Some(json!(true)));
+
+		// -- Follows capture_reasoning_content when not set
+		let payload = build_payload(ChatOptions::default().with_capture_reasoning_content(true))?;
+		assert_eq!(include_thoughts(&payload), Some(json!(true)));
+		let payload = build_payload(
+			ChatOptions::default()
+				.with_capture_reasoning_content(true)
+				.with_include_thoughts(false),
+		)?;
+		assert_eq!(include_thoughts(&payload), None);
+
+		let payload = build_payload(ChatOptions::default())?;
+		assert_eq!(include_thoughts(&payload), None);
+
+		Ok(())
+	}
+
+	/// Builds the chat request data of `service_type` for `auth`, returning the url and the headers.
+	fn chat_request_url_and_headers(auth: AuthData, service_type: ServiceType) -> Result<(String, Headers)> {
+		let target = ServiceTarget {
+			endpoint: GeminiAdapter::default_endpoint(),
+			auth,
+			model: ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash"),
+		};
+		let data = GeminiAdapter::to_web_request_data(
+			target,
+			service_type,
+			ChatRequest::from_user("Why is the sky blue?"),
+			ChatOptionsSet::default(),
+		)?;
+		Ok((data.url, data.headers))
+	}
+
+	fn header_value<'a>(headers: &'a Headers, name: &str) -> Option<&'a str> {
+		headers.iter().find(|(k, _)| k.as_str() == name).map(|(_, v)| v.as_str())
+	}
+
+	#[test]
+	fn test_service_urls_have_no_api_key() -> Result<()> {
+		const API_KEY: &str = "test-secret-api-key";
+
+		let model = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		for service_type in [
+			ServiceType::Chat,
+			ServiceType::ChatStream,
+			ServiceType::Embed,
+			ServiceType::Models,
+		] {
+			let url = GeminiAdapter::get_service_url(&model, service_type, GeminiAdapter::default_endpoint())?;
+			assert!(!url.contains("key="), "url should have no key: {url}");
+		}
+
+		for service_type in [ServiceType::Chat, ServiceType::ChatStream] {
+			let (url, headers) = chat_request_url_and_headers(AuthData::from_single(API_KEY), service_type)?;
+			assert!(
+				!url.contains(API_KEY) && !url.contains("key="),
+				"url should have no key: {url}"
+			);
+			assert_eq!(header_value(&headers, "x-goog-api-key"), Some(API_KEY));
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_oauth_token_as_bearer() -> Result<()> {
+		let auth = AuthData::from_multi(
+			[(
+				GeminiAdapter::OAUTH_TOKEN_KEY_NAME.to_string(),
+				"ya29.token".to_string(),
+			)]
+			.into(),
+		);
+
+		let (url, headers) = chat_request_url_and_headers(auth, ServiceType::Chat)?;
+
+		assert!(!url.contains("ya29.token"), "url should have no token: {url}");
+		assert_eq!(header_value(&headers, "Authorization"), Some("Bearer ya29.token"));
+		assert_eq!(header_value(&headers, "x-goog-api-key"), None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_query_api_key_opt_out() -> Result<()> {
+		let auth =
+			AuthData::from_multi([(GeminiAdapter::QUERY_API_KEY_NAME.to_string(), "proxy-key".to_string())].into());
+
+		let (url, headers) = chat_request_url_and_headers(auth, ServiceType::Chat)?;
+
+		assert_eq!(
+			url,
+			"https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:generateContent?key=proxy-key"
+		);
+		assert_eq!(header_value(&headers, "x-goog-api-key"), None);
+
+		Ok(())
+	}
 }
@@ -1,12 +1,12 @@
 //! Gemini Embeddings API implementation
 //! API Documentation: <https://ai.google.dev/gemini-api/docs/embeddings>
 
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::gemini::GeminiAdapter;
 use crate::adapter::{Adapter, ServiceType, WebRequestData};
 use crate::chat::Usage;
 use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
 use crate::webc::WebResponse;
-use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use crate::{Error, ModelIden, Result, ServiceTarget};
 use serde::{Deserialize, Serialize};
 
 // region:    --- Gemini Embed Request
@@ -81,18 +81,6 @@ pub fn to_embed_request_data(
 	options_set: EmbedOptionsSet<'_, '_>,
 ) -> Result<WebRequestData> {
 	let ServiceTarget { model, auth, .. } = service_target;
-	let api_key = get_api_key(auth, &model)?;
-
-	// Build headers - Gemini uses x-goog-api-key header
-	let mut headers = Headers::from(vec![
-		("x-goog-api-key".to_string(), api_key),
-		("Content-Type".to_string(), "application/json".to_string()),
-	]);
-
-	// Add custom headers from options
-	if let Some(custom_headers) = options_set.headers() {
-		headers.merge_with(custom_headers);
-	}
 
 	// Extract the actual model name (without namespace) - not needed for Gemini request body
 	// Get the model name for the request
@@ -167,18 +155,28 @@ pub fn to_embed_request_data(
 	};
 
 	// Get the service URL and modify it for batch requests
-	let mut url = <crate::adapter::gemini::GeminiAdapter as Adapter>::get_service_url(
-		&model,
-		ServiceType::Embed,
-		service_target.endpoint,
-	)?;
+	let mut url = <GeminiAdapter as Adapter>::get_service_url(&model, ServiceType::Embed, service_target.endpoint)?;
 
 	// For batch requests, change :embedContent to :batchEmbedContents
 	if is_batch {
 		url = url.replace(":embedContent", ":batchEmbedContents");
 	}
 
-	Ok(WebRequestData { url, headers, payload })
+	// Build headers - Gemini uses x-goog-api-key header (or the OAuth Bearer)
+	let (mut headers, url) = GeminiAdapter::apply_auth(auth, &model, url)?;
+	headers.merge(("Content-Type".to_string(), "application/json".to_string()));
+
+	// Add custom headers from options
+	if let Some(custom_headers) = options_set.headers() {
+		headers.merge_with(custom_headers);
+	}
+
+	Ok(WebRequestData {
+		url,
+		headers,
+		payload,
+		ignored_options: Vec::new(),
+	})
 }
 
 pub fn to_embed_response(
@@ -0,0 +1,225 @@
+//! Gemini provider extension - explicit context caching (`cachedContents`).
+//!
+//! A cached content holds a large request prefix (system instruction, contents, and tools) on the Gemini side
+//! for a TTL, and is referenced by name in the chat requests with `ChatOptions::with_cached_content(..)`,
+//! so the repeated prefix is billed at the cached token rate.
+//!
+//! Usage: `client.create_gemini_cache(model, content, ttl)` (or `client.provider_ext::<GeminiExt>()?`)
+
+use super::adapter_impl::{GeminiAdapter, GeminiChatRequestParts};
+use crate::adapter::adapters::support::join_service_url;
+use crate::adapter::{AdapterKind, WebRequestData};
+use crate::chat::ChatRequest;
+use crate::resolver::{AuthData, Endpoint};
+use crate::{Client, Error, ModelIden, ProviderExt, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+use value_ext::JsonValueExt;
+
+// region:    --- GeminiExt
+
+/// Gemini-specific APIs, created with `client.provider_ext::<GeminiExt>()`.
+///
+/// Auth and endpoint are resolved like for chat requests (AuthResolver or `GEMINI_API_KEY`,
+/// and the `ServiceTargetResolver` endpoint).
+#[derive(Debug, Clone)]
+pub struct GeminiExt<'a> {
+	client: &'a Client,
+}
+
+impl<'a> ProviderExt<'a> for GeminiExt<'a> {
+	const ADAPTER_KIND: AdapterKind = AdapterKind::Gemini;
+
+	fn from_client(client: &'a Client) -> Result<Self> {
+		Ok(Self { client })
+	}
+}
+
+/// Cached Contents API
+impl GeminiExt<'_> {
+	/// Creates a cached content (`POST /cachedContents`) with the system, messages, and tools of `content`,
+	/// expiring after `ttl`.
+	///
+	/// NOTE: The chat requests referencing it must target the same model, and must not repeat
+	///       the cached system or tools. Gemini also requires a minimum cached token count (e.g., 1024).
+	pub async fn create_cache(&self, model: &str, content: ChatRequest, ttl: Duration) -> Result<CacheHandle> {
+		let (auth, endpoint) = self.client.config().resolve_adapter_config(Self::ADAPTER_KIND).await?;
+		let model_iden = ModelIden::new(Self::ADAPTER_KIND, model);
+
+		let WebRequestData {
+			url, headers, payload, ..
+		} = to_create_cache_request_data(&model_iden, auth, &endpoint, content, ttl)?;
+
+		let web_res = self
+			.client
+			.web_client()
+			.do_post(&url, &headers, &payload)
+			.await
+			.map_err(|webc_error| Error::WebModelCall { model_iden, webc_error })?;
+
+		Ok(serde_json::from_value(web_res.body)?)
+	}
+}
+
+impl Client {
+	/// Creates a Gemini cached content (see `GeminiExt::create_cache`),
+	/// to reference in chat requests with `ChatOptions::with_cached_content(handle)`.
+	pub async fn create_gemini_cache(&self, model: &str, content: ChatRequest, ttl: Duration) -> Result<CacheHandle> {
+		self.provider_ext::<GeminiExt>()?.create_cache(model, content, ttl).await
+	}
+}
+
+/// Builds the `cachedContents` creation request (the same contents, system, and tools as a chat request).
+fn to_create_cache_request_data(
+	model: &ModelIden,
+	auth: AuthData,
+	endpoint: &Endpoint,
+	content: ChatRequest,
+	ttl: Duration,
+) -> Result<WebRequestData> {
+	let (_, model_name) = model.model_name.namespace_and_name();
+	let GeminiChatRequestParts {
+		system,
+		contents,
+		tools,
+	} = GeminiAdapter::into_gemini_request_parts(model, content)?;
+
+	let mut payload = json!({
+		"model": format!("models/{model_name}"),
+		"contents": contents,
+		"ttl": format!("{}s", ttl.as_secs()),
+	});
+	if let Some(system) = system {
+		payload.x_insert("systemInstruction", json!({ "parts": [{ "text": system }] }))?;
+	}
+	if let Some(tools) = tools {
+		payload.x_insert("tools", tools)?;
+	}
+
+	let url = join_service_url(endpoint.base_url(), "cachedContents")?;
+	let (headers, url) = GeminiAdapter::apply_auth(auth, model, url)?;
+
+	Ok(WebRequestData {
+		url,
+		headers,
+		payload,
+		ignored_options: Vec::new(),
+	})
+}
+
+// endregion: --- GeminiExt
+
+// region:    --- Cached Contents Types
+
+/// A created Gemini cached content, as returned by `Client::create_gemini_cache`.
+///
+/// Referenced in the chat requests with `ChatOptions::with_cached_content(handle)` (by its `name`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheHandle {
+	/// The resource name (e.g., `cachedContents/abc123`).
+	pub name: String,
+	/// The model of the cache (e.g., `models/gemini-2.5-flash`).
+	#[serde(default)]
+	pub model: Option<String>,
+	/// The expiration time (RFC 3339).
+	#[serde(default)]
+	pub expire_time: Option<String>,
+	#[serde(default)]
+	pub usage_metadata: Option<CacheUsageMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheUsageMetadata {
+	/// The number of cached tokens.
+	#[serde(default)]
+	pub total_token_count: Option<u32>,
+}
+
+impl From<CacheHandle> for String {
+	fn from(handle: CacheHandle) -> Self {
+		handle.name
+	}
+}
+
+impl From<&CacheHandle> for String {
+	fn from(handle: &CacheHandle) -> Self {
+		handle.name.clone()
+	}
+}
+
+// endregion: --- Cached Contents Types
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ServiceTarget;
+	use crate::adapter::{Adapter, ServiceType};
+	use crate::chat::{ChatMessage, ChatOptions, ChatOptionsSet};
+
+	#[test]
+	fn test_create_cache_request_data() -> Result<()> {
+		let model = ModelIden::new(AdapterKind::Gemini, "gemini::gemini-2.5-flash");
+		let content = ChatRequest::from_system("You answer questions about the attached manual.")
+			.append_message(ChatMessage::user("<the long manual>"));
+
+		let data = to_create_cache_request_data(
+			&model,
+			AuthData::from_single("test-key"),
+			&GeminiAdapter::default_endpoint(),
+			content,
+			Duration::from_secs(300),
+		)?;
+
+		assert_eq!(
+			data.url,
+			"https://generativelanguage.googleapis.com/v1beta/cachedContents"
+		);
+		assert_eq!(data.payload["model"], "models/gemini-2.5-flash");
+		assert_eq!(data.payload["ttl"], "300s");
+		assert_eq!(
+			data.payload["systemInstruction"]["parts"][0]["text"],
+			"You answer questions about the attached manual."
+		);
+		assert_eq!(data.payload["contents"][0]["parts"][0]["text"], "<the long manual>");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_cached_content_referenced_in_chat_request() -> Result<()> {
+		let handle: CacheHandle = serde_json::from_value(json!({
+			"name": "cachedContents/abc123",
+			"model": "models/gemini-2.5-flash",
+			"expireTime": "2025-06-01T10:05:00Z",
+			"usageMetadata": {"totalTokenCount": 4096}
+		}))?;
+		assert_eq!(
+			handle.usage_metadata.as_ref().and_then(|u| u.total_token_count),
+			Some(4096)
+		);
+
+		let target = ServiceTarget {
+			endpoint: GeminiAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash"),
+		};
+		let options = ChatOptions::default().with_cached_content(&handle);
+		let data = GeminiAdapter::to_web_request_data(
+			target,
+			ServiceType::Chat,
+			ChatRequest::from_user("What does chapter 2 say?"),
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		)?;
+
+		assert_eq!(data.payload["cachedContent"], "cachedContents/abc123");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -68,6 +68,7 @@ impl futures::Stream for GeminiStreamer {
 								captured_tool_calls: self.captured_data.tool_calls.take(),
 								captured_thought_signatures: self.captured_data.thought_signatures.take(),
 								captured_response_id: None,
+								interrupted_by: None,
 							};
 
 							return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
@@ -121,7 +122,7 @@ impl futures::Stream for GeminiStreamer {
 							for g_content_item in content {
 								match g_content_item {
 									GeminiChatContent::Reasoning(reasoning) => {
-										stream_reasoning_content = Some(reasoning)
+										stream_reasoning_content.get_or_insert_default().push_str(&reasoning)
 									}
 									GeminiChatContent::Text(text) => stream_text_content.push_str(&text),
 									GeminiChatContent::Binary(_) => {
@@ -151,7 +152,7 @@ impl futures::Stream for GeminiStreamer {
 							}
 							if let Some(reasoning_content) = stream_reasoning_content {
 								// Capture reasoning content
-								if self.options.capture_content {
+								if self.options.capture_reasoning_content {
 									match self.captured_data.reasoning_content {
 										Some(ref mut rc) => rc.push_str(&reasoning_content),
 										None => self.captured_data.reasoning_content = Some(reasoning_content.clone()),
@@ -204,11 +205,16 @@ impl futures::Stream for GeminiStreamer {
 				}
 				Some(Err(err)) => {
 					tracing::error!("Gemini Adapter Stream Error: {}", err);
-					return Poll::Ready(Some(Err(Error::WebStream {
+					let error = Error::WebStream {
 						model_iden: self.options.model_iden.clone(),
 						cause: err.to_string(),
 						error: err,
-					})));
+					};
+					// End with the partial data captured so far, if any (the error follows, see `ChatStream`)
+					self.done = true;
+					return Poll::Ready(Some(
+						self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+					));
 				}
 				None => {
 					self.done = true;
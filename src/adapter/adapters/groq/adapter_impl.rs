@@ -155,10 +155,7 @@ impl Adapter for GroqAdapter {
 		_embed_req: crate::embed::EmbedRequest,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::adapter::WebRequestData> {
-		Err(crate::Error::AdapterNotSupported {
-			adapter_kind: crate::adapter::AdapterKind::Groq,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Groq, ServiceType::Embed))
 	}
 
 	fn to_embed_response(
@@ -166,10 +163,7 @@ impl Adapter for GroqAdapter {
 		_web_response: crate::webc::WebResponse,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::embed::EmbedResponse> {
-		Err(crate::Error::AdapterNotSupported {
-			adapter_kind: crate::adapter::AdapterKind::Groq,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Groq, ServiceType::Embed))
 	}
 }
 
@@ -0,0 +1,119 @@
+use crate::adapter::ModelCapabilities;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::resolver::{AuthData, Endpoint};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Model, ModelIden, ModelName};
+use crate::{Result, ServiceTarget};
+use reqwest::RequestBuilder;
+
+pub struct HunyuanAdapter;
+
+// Hunyuan does not expose a `/models` endpoint on its OpenAI compatible API - newer on top
+pub(in crate::adapter) const MODELS: &[&str] = &[
+	"hunyuan-turbos-latest", // Alias to the latest Hunyuan TurboS
+	"hunyuan-t1-latest",     // Reasoning model
+	"hunyuan-turbo",
+	"hunyuan-large",
+	"hunyuan-standard",
+	"hunyuan-standard-256K",
+	"hunyuan-lite", // Free tier, 256K context
+	"hunyuan-vision",
+];
+
+impl HunyuanAdapter {
+	pub const API_KEY_DEFAULT_ENV_NAME: &str = "HUNYUAN_API_KEY";
+}
+
+// The Hunyuan API is OpenAI compatible, so chat, stream, and embed are delegated to the OpenAI adapter.
+impl Adapter for HunyuanAdapter {
+	const DEFAULT_API_KEY_ENV_NAME: Option<&'static str> = Some(Self::API_KEY_DEFAULT_ENV_NAME);
+
+	fn default_endpoint() -> Endpoint {
+		const BASE_URL: &str = "https://api.hunyuan.cloud.tencent.com/v1/";
+		Endpoint::from_static(BASE_URL)
+	}
+
+	fn default_auth() -> AuthData {
+		match Self::DEFAULT_API_KEY_ENV_NAME {
+			Some(env_name) => AuthData::from_env(env_name),
+			None => AuthData::None,
+		}
+	}
+
+	async fn all_model_names(_kind: AdapterKind, _endpoint: Endpoint, _auth: AuthData) -> Result<Vec<String>> {
+		Ok(MODELS.iter().map(|s| s.to_string()).collect())
+	}
+
+	async fn all_models(kind: AdapterKind, _target: ServiceTarget, _web_client: &WebClient) -> Result<Vec<Model>> {
+		Ok(MODELS.iter().map(|model_id| Self::to_model(kind, model_id)).collect())
+	}
+
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		OpenAIAdapter::util_get_service_url(model, service_type, endpoint)
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		chat_options: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, None)
+	}
+
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		OpenAIAdapter::to_chat_response(model_iden, web_response, options_set)
+	}
+
+	fn to_chat_stream(
+		model_iden: ModelIden,
+		reqwest_builder: RequestBuilder,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		OpenAIAdapter::to_chat_stream(model_iden, reqwest_builder, options_set)
+	}
+
+	fn to_embed_request_data(
+		service_target: ServiceTarget,
+		embed_req: EmbedRequest,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		OpenAIAdapter::to_embed_request_data(service_target, embed_req, options_set)
+	}
+
+	fn to_embed_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		OpenAIAdapter::to_embed_response(model_iden, web_response, options_set)
+	}
+}
+
+// region:    --- Support Functions
+
+impl HunyuanAdapter {
+	fn to_model(kind: AdapterKind, model_id: &str) -> Model {
+		let model_name: ModelName = model_id.to_string().into();
+		let (max_input_tokens, max_output_tokens) = ModelCapabilities::infer_token_limits(kind, model_id);
+
+		Model::new(model_name, model_id)
+			.with_max_input_tokens(max_input_tokens)
+			.with_max_output_tokens(max_output_tokens)
+			.with_streaming(ModelCapabilities::supports_streaming(kind, model_id))
+			.with_tool_calls(ModelCapabilities::supports_tool_calls(kind, model_id))
+			.with_json_mode(ModelCapabilities::supports_json_mode(kind, model_id))
+			.with_reasoning(ModelCapabilities::supports_reasoning(kind, model_id))
+			.with_input_modalities(ModelCapabilities::infer_input_modalities(kind, model_id))
+			.with_output_modalities(ModelCapabilities::infer_output_modalities(kind, model_id))
+	}
+}
+
+// endregion: --- Support Functions
@@ -0,0 +1,11 @@
+//! API Documentation:     <https://cloud.tencent.com/document/product/1729/111007>
+//! Model Names:           <https://cloud.tencent.com/document/product/1729/104753>
+//! Pricing:               <https://cloud.tencent.com/document/product/1729/97731>
+
+// region:    --- Modules
+
+mod adapter_impl;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
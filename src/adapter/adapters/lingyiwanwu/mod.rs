@@ -0,0 +1,11 @@
+//! API Documentation:     <https://platform.lingyiwanwu.com/docs/api-reference>
+//! Model Names:           <https://platform.lingyiwanwu.com/docs#模型与计费>
+//! Pricing:               <https://platform.lingyiwanwu.com/docs#模型与计费>
+
+// region:    --- Modules
+
+mod adapter_impl;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
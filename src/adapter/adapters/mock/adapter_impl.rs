@@ -0,0 +1,160 @@
+use crate::adapter::adapters::support::StreamerOptions;
+use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStream, ChatStreamResponse};
+use crate::resolver::{AuthData, Endpoint};
+use crate::webc::WebResponse;
+use crate::{Error, Headers, Model, ModelIden, Result, ServiceTarget};
+use reqwest::RequestBuilder;
+use serde_json::json;
+
+/// The test-only in-memory adapter (see `MockChatHandler`).
+///
+/// NOTE: The client answers the `AdapterKind::Mock` chat requests with its `MockChatHandler` (no web call).
+///       The `Adapter` implementation only builds the request data (e.g., for `Client::build_chat_payload`),
+///       with the `ChatRequest` as payload.
+pub struct MockAdapter;
+
+impl Adapter for MockAdapter {
+	const DEFAULT_API_KEY_ENV_NAME: Option<&'static str> = None;
+
+	fn default_endpoint() -> Endpoint {
+		const BASE_URL: &str = "http://mock.invalid/";
+		Endpoint::from_static(BASE_URL)
+	}
+
+	fn default_auth() -> AuthData {
+		AuthData::from_single("mock")
+	}
+
+	async fn all_model_names(_kind: AdapterKind, _endpoint: Endpoint, _auth: AuthData) -> Result<Vec<String>> {
+		Ok(Vec::new())
+	}
+
+	async fn all_models(
+		_kind: AdapterKind,
+		_target: ServiceTarget,
+		_web_client: &crate::webc::WebClient,
+	) -> Result<Vec<Model>> {
+		Ok(Vec::new())
+	}
+
+	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => Ok(format!("{}chat", endpoint.base_url())),
+			ServiceType::Embed | ServiceType::Models => {
+				Err(Error::service_not_supported(AdapterKind::Mock, service_type))
+			}
+		}
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		_chat_options: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		let ServiceTarget { model, endpoint, .. } = target;
+		let url = Self::get_service_url(&model, service_type, endpoint)?;
+		let (_, model_name) = model.model_name.namespace_and_name();
+
+		let payload = json!({
+			"model": model_name,
+			"stream": matches!(service_type, ServiceType::ChatStream),
+			"request": serde_json::to_value(chat_req)?,
+		});
+
+		Ok(WebRequestData {
+			url,
+			headers: Headers::default(),
+			payload,
+			ignored_options: Vec::new(),
+		})
+	}
+
+	/// The mock responses are not sent over the wire, but a serialized `ChatResponse` body is accepted as is.
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		_options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		let mut chat_res: ChatResponse = serde_json::from_value(web_response.body)?;
+		chat_res.model_iden = model_iden;
+		Ok(chat_res)
+	}
+
+	fn to_chat_stream(
+		_model_iden: ModelIden,
+		_reqwest_builder: RequestBuilder,
+		_options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		Err(Error::Internal(
+			"The Mock adapter streams are replayed by the client from the MockChatHandler responses".to_string(),
+		))
+	}
+
+	fn to_embed_request_data(
+		_service_target: ServiceTarget,
+		_embed_req: crate::embed::EmbedRequest,
+		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		Err(Error::service_not_supported(AdapterKind::Mock, ServiceType::Embed))
+	}
+
+	fn to_embed_response(
+		_model_iden: ModelIden,
+		_web_response: WebResponse,
+		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
+	) -> Result<crate::embed::EmbedResponse> {
+		Err(Error::service_not_supported(AdapterKind::Mock, ServiceType::Embed))
+	}
+}
+
+/// Client support
+impl MockAdapter {
+	/// Replays a `MockChatHandler` response as a chat stream: the reasoning, the text chunks, the tool calls,
+	/// and the end (with the captures asked by the options).
+	pub(crate) fn into_chat_stream_response(
+		chat_res: ChatResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> ChatStreamResponse {
+		let options = StreamerOptions::new(chat_res.model_iden.clone(), options_set);
+		let ChatResponse {
+			content,
+			reasoning_content,
+			model_iden,
+			stop_reason,
+			usage,
+			response_id,
+			..
+		} = chat_res;
+
+		let texts: Vec<String> = content.texts().into_iter().map(String::from).collect();
+		let tool_calls = content.into_tool_calls();
+
+		let mut events = vec![InterStreamEvent::Start];
+		if let Some(reasoning_content) = reasoning_content.as_ref() {
+			events.push(InterStreamEvent::ReasoningChunk(reasoning_content.clone()));
+		}
+		events.extend(texts.iter().cloned().map(InterStreamEvent::Chunk));
+		events.extend(tool_calls.iter().cloned().map(InterStreamEvent::ToolCallChunk));
+
+		let end = InterStreamEnd {
+			captured_usage: options.capture_usage.then_some(usage),
+			captured_stop_reason: stop_reason,
+			captured_text_content: (options.capture_content && !texts.is_empty()).then(|| texts.concat()),
+			captured_reasoning_content: reasoning_content.filter(|_| options.capture_reasoning_content),
+			captured_tool_calls: (options.capture_tool_calls && !tool_calls.is_empty()).then_some(tool_calls),
+			captured_response_id: response_id,
+			..Default::default()
+		};
+		events.push(InterStreamEvent::End(end));
+
+		ChatStreamResponse {
+			stream: ChatStream::from_inter_stream(futures::stream::iter(events.into_iter().map(Ok))),
+			model_iden,
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+		}
+	}
+}
@@ -0,0 +1,68 @@
+use crate::ModelIden;
+use crate::adapter::AdapterKind;
+use crate::chat::{ChatRequest, ChatResponse, MessageContent, StopReason, Usage};
+
+// region:    --- MockChatHandler
+
+/// Produces the response of a chat request for the `AdapterKind::Mock` models
+/// (implemented for any `Fn(ChatRequest) -> ChatResponse + Send + Sync`).
+///
+/// ```ignore
+/// let client = Client::builder()
+///     .with_mock_chat_handler(|chat_req: ChatRequest| {
+///         let question = chat_req.messages.last().and_then(|msg| msg.content.first_text()).unwrap_or_default();
+///         mock_chat_response(format!("You said: {question}"))
+///     })
+///     .build();
+/// let chat_res = client.exec_chat("mock::test-model", chat_req, None).await?;
+/// ```
+pub trait MockChatHandler: Send + Sync {
+	/// Returns the response of the chat request.
+	fn handle(&self, chat_req: ChatRequest) -> ChatResponse;
+}
+
+impl<F> MockChatHandler for F
+where
+	F: Fn(ChatRequest) -> ChatResponse + Send + Sync,
+{
+	fn handle(&self, chat_req: ChatRequest) -> ChatResponse {
+		self(chat_req)
+	}
+}
+
+impl std::fmt::Debug for dyn MockChatHandler {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "MockChatHandler")
+	}
+}
+
+// endregion: --- MockChatHandler
+
+// region:    --- Response Builder
+
+/// Returns a `ChatResponse` with the content (text, tool calls, ...), a `stop` stop reason, and no usage,
+/// for the `MockChatHandler` implementations.
+///
+/// NOTE: The client sets the `model_iden` of the request model (the `provider_model_iden` is kept).
+pub fn mock_chat_response(content: impl Into<MessageContent>) -> ChatResponse {
+	let model_iden = ModelIden::new(AdapterKind::Mock, "mock");
+	ChatResponse {
+		content: content.into(),
+		reasoning_content: None,
+		model_iden: model_iden.clone(),
+		provider_model_iden: model_iden,
+		stop_reason: Some(StopReason::from("stop".to_string())),
+		usage: Usage::default(),
+		usage_reported: false,
+		captured_raw_body: None,
+		response_id: None,
+		metrics: None,
+		extra_body_overrides: Vec::new(),
+		ignored_options: Vec::new(),
+		choices: Vec::new(),
+		service_tier: None,
+		provider_timing: None,
+	}
+}
+
+// endregion: --- Response Builder
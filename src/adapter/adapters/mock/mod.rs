@@ -0,0 +1,14 @@
+//! Test-only in-memory adapter (`AdapterKind::Mock`, with the `mock` feature).
+//!
+//! No network call: the chat responses are produced by the `MockChatHandler` of the client
+//! (`ClientBuilder::with_mock_chat_handler(..)`), and the chat streams are replayed from them.
+
+// region:    --- Modules
+
+mod adapter_impl;
+mod mock_handler;
+
+pub use adapter_impl::*;
+pub use mock_handler::*;
+
+// endregion: --- Modules
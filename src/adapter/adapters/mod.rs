@@ -1,5 +1,6 @@
 mod support;
 
+pub(super) mod ai21;
 pub(super) mod aliyun;
 pub(super) mod anthropic;
 pub(super) mod bigmodel;
@@ -7,17 +8,24 @@ pub(super) mod cohere;
 pub(super) mod copilot;
 pub(super) mod copilot_headers;
 pub(super) mod copilot_resp;
+pub(super) mod deepinfra;
 pub(super) mod deepseek;
 pub(super) mod fireworks;
 pub(super) mod gemini;
 pub(super) mod github_copilot;
 pub(super) mod groq;
+pub(super) mod hunyuan;
+pub(super) mod lingyiwanwu;
 pub(super) mod mimo;
+#[cfg(feature = "mock")]
+pub(super) mod mock;
 pub(super) mod nebius;
 pub(super) mod ollama;
 pub(super) mod ollama_cloud;
 pub(super) mod openai;
 pub(super) mod openai_resp;
+pub(super) mod qianfan;
+pub(super) mod stepfun;
 pub(super) mod together;
 pub(super) mod vertex;
 pub(super) mod xai;
@@ -1,6 +1,6 @@
 use crate::ModelIden;
 use crate::adapter::ModelCapabilities;
-use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::openai::{OpenAIAdapter, ToWebRequestCustom};
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::resolver::{AuthData, Endpoint};
@@ -9,7 +9,7 @@ use crate::{Model, Result, ServiceTarget};
 use reqwest::RequestBuilder;
 
 pub struct NebiusAdapter;
-const MODELS: &[&str] = &["Qwen/Qwen3-235B-A22B", "meta-llama/Llama-3.1-70B-Instruct"];
+pub(in crate::adapter) const MODELS: &[&str] = &["Qwen/Qwen3-235B-A22B", "meta-llama/Llama-3.1-70B-Instruct"];
 
 impl NebiusAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "NEBIUS_API_KEY";
@@ -82,7 +82,12 @@ impl Adapter for NebiusAdapter {
 		chat_req: ChatRequest,
 		chat_options: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
-		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, None)
+		// Nebius serves vLLM-based models, which accept the extra sampling parameters
+		let custom = ToWebRequestCustom {
+			sampling_extras: true,
+			..Default::default()
+		};
+		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, Some(custom))
 	}
 
 	fn to_chat_response(
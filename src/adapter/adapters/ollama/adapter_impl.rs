@@ -3,18 +3,22 @@
 use super::adapter_shared::OllamaRequestParts;
 use crate::Headers;
 use crate::adapter::ModelCapabilities;
+use crate::adapter::adapters::support::join_service_url;
 use crate::adapter::ollama::OllamaStreamer;
-use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::adapter::{Adapter, AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
 	ChatOptionsSet, ChatRequest, ChatResponse, ChatStream, ChatStreamResponse, MessageContent, StopReason, ToolCall,
 };
-use crate::embed::{EmbedResponse, Embedding};
+use crate::embed::{EmbedResponse, EmbedTruncate, Embedding};
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
-use crate::{Error, Model, Result};
+use crate::{Error, Modality, Model, Result};
 use crate::{ModelIden, ServiceTarget};
+use futures::{StreamExt, stream};
 use reqwest::RequestBuilder;
 use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
 use value_ext::JsonValueExt;
 
 pub struct OllamaAdapter;
@@ -45,9 +49,12 @@ impl Adapter for OllamaAdapter {
 		target: ServiceTarget,
 		web_client: &crate::webc::WebClient,
 	) -> Result<Vec<Model>> {
-		let endpoint = target.endpoint;
-		let base_url = endpoint.base_url();
-		let url = format!("{base_url}api/tags");
+		let show_url = join_service_url(target.endpoint.base_url(), "api/show")?;
+		let url = AdapterDispatcher::get_service_url(
+			&ModelIden::new(adapter_kind, ""),
+			ServiceType::Models,
+			target.endpoint,
+		)?;
 
 		let mut res =
 			web_client
@@ -61,7 +68,17 @@ impl Adapter for OllamaAdapter {
 		let mut models: Vec<Model> = Vec::new();
 
 		if let Value::Array(models_value) = res.body.x_take("models")? {
-			for mut model_data in models_value {
+			// -- Fetch the `/api/show` capabilities (bounded concurrency, in the models order)
+			let show_capabilities: Vec<Option<Vec<String>>> = stream::iter(models_value.iter().map(|model_data| {
+				let model_id = model_data.x_get_as::<&str>("name").unwrap_or_default();
+				let digest = model_data.x_get_as::<&str>("digest").unwrap_or_default();
+				Self::show_capabilities(web_client, &show_url, model_id, digest)
+			}))
+			.buffered(SHOW_CONCURRENCY)
+			.collect()
+			.await;
+
+			for (mut model_data, show_capabilities) in models_value.into_iter().zip(show_capabilities) {
 				let model_id: String = model_data.x_take("name")?;
 
 				let model_name: crate::ModelName = model_id.clone().into();
@@ -90,6 +107,10 @@ impl Adapter for OllamaAdapter {
 					.with_json_mode(ModelCapabilities::supports_json_mode(AdapterKind::Ollama, &model_id))
 					.with_additional_properties(model_data);
 
+				if let Some(capabilities) = show_capabilities {
+					model = Self::with_show_capabilities(model, &capabilities);
+				}
+
 				models.push(model);
 			}
 		} else {
@@ -100,24 +121,30 @@ impl Adapter for OllamaAdapter {
 	}
 
 	fn get_service_url(_model_iden: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
-		let base_url = endpoint.base_url();
-		match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => Ok(format!("{base_url}api/chat")),
-			ServiceType::Embed => Ok(format!("{base_url}api/embed")),
-			ServiceType::Models => Ok(format!("{base_url}api/tags")),
-		}
+		let suffix = endpoint.service_path(service_type).unwrap_or(match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => "api/chat",
+			ServiceType::Embed => "api/embed",
+			ServiceType::Models => "api/tags",
+		});
+		join_service_url(endpoint.base_url(), suffix)
 	}
 
 	fn to_web_request_data(
 		target: ServiceTarget,
 		service_type: ServiceType,
-		chat_req: ChatRequest,
+		mut chat_req: ChatRequest,
 		chat_options: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
 		let ServiceTarget { model, endpoint, .. } = target;
 
 		// -- Service URL
-		let url = Self::get_service_url(&model, service_type, endpoint)?;
+		// Note: The raw prompt requests (see `ChatRequest::with_raw_prompt`) go to `api/generate`.
+		let raw_prompt = chat_req.raw_prompt.take();
+		let url = if raw_prompt.is_some() {
+			Self::util_get_generate_url(&endpoint)?
+		} else {
+			Self::get_service_url(&model, service_type, endpoint)?
+		};
 
 		// -- Ollama Request Parts
 		let OllamaRequestParts { messages, tools } = Self::into_ollama_request_parts(chat_req)?;
@@ -130,6 +157,9 @@ impl Adapter for OllamaAdapter {
 		if let Some(top_p) = chat_options.top_p() {
 			options.x_insert("top_p", top_p)?;
 		}
+		if let Some(top_k) = chat_options.top_k() {
+			options.x_insert("top_k", top_k)?;
+		}
 		if let Some(max_tokens) = chat_options.max_tokens() {
 			options.x_insert("num_predict", max_tokens)?;
 		}
@@ -139,6 +169,9 @@ impl Adapter for OllamaAdapter {
 		if !chat_options.stop_sequences().is_empty() {
 			options.x_insert("stop", chat_options.stop_sequences())?;
 		}
+		if let Some(sampling_extras) = chat_options.sampling_extras() {
+			Self::util_insert_sampling_extras(&mut options, sampling_extras)?;
+		}
 
 		// -- Build Payload
 		let stream = matches!(service_type, ServiceType::ChatStream);
@@ -146,9 +179,16 @@ impl Adapter for OllamaAdapter {
 
 		let mut payload = json!({
 			"model": model_name,
-			"messages": messages,
 			"stream": stream,
 		});
+		match raw_prompt {
+			// `raw` so that Ollama does not apply the model template to the prompt
+			Some(raw_prompt) => {
+				payload.x_insert("prompt", raw_prompt)?;
+				payload.x_insert("raw", true)?;
+			}
+			None => payload.x_insert("messages", messages)?,
+		}
 
 		if !options.as_object().unwrap().is_empty() {
 			payload.x_insert("options", options)?;
@@ -171,7 +211,14 @@ impl Adapter for OllamaAdapter {
 			headers.merge_with(extra_headers);
 		}
 
-		Ok(WebRequestData { url, headers, payload })
+		let ignored_options = Self::util_ignored_options(&chat_options, model.adapter_kind);
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -188,7 +235,11 @@ impl Adapter for OllamaAdapter {
 		};
 
 		// -- Content and Tool Calls
-		let mut message: Value = body.x_take("message")?;
+		// Note: The raw prompt (`api/generate`) responses have a top-level `response` instead of the `message`.
+		let mut message: Value = match body.x_take::<Value>("message") {
+			Ok(message) => message,
+			Err(_) => json!({ "content": body.x_take::<String>("response")? }),
+		};
 		let content_text: Option<String> = message.x_take("content").ok();
 		let mut content = content_text.map(MessageContent::from_text).unwrap_or_default();
 
@@ -222,6 +273,8 @@ impl Adapter for OllamaAdapter {
 		// -- Usage
 		let usage = Self::into_usage(&mut body);
 
+		let usage_reported = !usage.is_empty();
+
 		Ok(ChatResponse {
 			content,
 			reasoning_content,
@@ -233,8 +286,15 @@ impl Adapter for OllamaAdapter {
 				.flatten()
 				.map(StopReason::from),
 			usage,
+			usage_reported,
 			captured_raw_body,
 			response_id: None,
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		})
 	}
 
@@ -243,14 +303,14 @@ impl Adapter for OllamaAdapter {
 		reqwest_builder: RequestBuilder,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let streamer = OllamaStreamer::new(
-			crate::webc::WebStream::new_with_delimiter(reqwest_builder, "\n"),
-			model_iden.clone(),
-			options_set,
-		);
+		let web_stream = crate::webc::WebStream::new_with_delimiter(reqwest_builder, "\n")
+			.with_lossy_utf8(options_set.lossy_utf8().unwrap_or(true));
+		let streamer = OllamaStreamer::new(web_stream, model_iden.clone(), options_set);
 		Ok(ChatStreamResponse {
 			stream: ChatStream::from_inter_stream(streamer),
 			model_iden,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -272,8 +332,9 @@ impl Adapter for OllamaAdapter {
 		if let Some(dimensions) = options_set.dimensions() {
 			payload.x_insert("dimensions", dimensions)?;
 		}
+		// Note: Ollama truncates the end (`EmbedTruncate::Start` is applied client-side, when the limit is known)
 		if let Some(truncate) = options_set.truncate() {
-			payload.x_insert("truncate", truncate)?;
+			payload.x_insert("truncate", !matches!(truncate, EmbedTruncate::None))?;
 		}
 
 		// -- Headers
@@ -282,7 +343,12 @@ impl Adapter for OllamaAdapter {
 			headers.merge_with(extra_headers);
 		}
 
-		Ok(WebRequestData { url, headers, payload })
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options: Vec::new(),
+		})
 	}
 
 	fn to_embed_response(
@@ -318,3 +384,79 @@ impl Adapter for OllamaAdapter {
 }
 
 // endregion: --- Adapter Impl
+
+// region:    --- Show Capabilities
+
+/// Max concurrent `/api/show` calls of `all_models`.
+const SHOW_CONCURRENCY: usize = 4;
+
+/// (show url, model name, digest), so a pulled model update is refetched.
+type ShowCapabilitiesKey = (String, String, String);
+
+/// The `/api/show` capabilities, by `ShowCapabilitiesKey`.
+static SHOW_CAPABILITIES_CACHE: LazyLock<Mutex<HashMap<ShowCapabilitiesKey, Vec<String>>>> =
+	LazyLock::new(|| Mutex::new(HashMap::new()));
+
+impl OllamaAdapter {
+	/// Returns the model `capabilities` from `/api/show` (e.g., `["completion", "tools", "vision"]`), cached.
+	///
+	/// None when not available (older Ollama, or the call failed), in which case the name heuristics are used.
+	async fn show_capabilities(
+		web_client: &crate::webc::WebClient,
+		show_url: &str,
+		model_id: &str,
+		digest: &str,
+	) -> Option<Vec<String>> {
+		let key = (show_url.to_string(), model_id.to_string(), digest.to_string());
+		if let Some(capabilities) = SHOW_CAPABILITIES_CACHE.lock().ok()?.get(&key) {
+			return Some(capabilities.clone());
+		}
+
+		let res = match web_client
+			.do_post(show_url, &Headers::default(), &json!({"model": model_id}))
+			.await
+		{
+			Ok(res) => res,
+			Err(err) => {
+				tracing::debug!("OllamaAdapter /api/show failed for '{model_id}', using the heuristics. Cause: {err}");
+				return None;
+			}
+		};
+		let capabilities = Self::parse_show_capabilities(res.body)?;
+
+		if let Ok(mut cache) = SHOW_CAPABILITIES_CACHE.lock() {
+			cache.insert(key, capabilities.clone());
+		}
+		Some(capabilities)
+	}
+
+	fn parse_show_capabilities(mut body: Value) -> Option<Vec<String>> {
+		body.x_take::<Vec<String>>("capabilities").ok()
+	}
+
+	/// Sets the tool calls, vision, and thinking support from the `/api/show` capabilities.
+	/// Note: A capability override (e.g., from `Client::probe_capabilities`) still wins for the tool calls.
+	fn with_show_capabilities(model: Model, capabilities: &[String]) -> Model {
+		let has = |capability: &str| capabilities.iter().any(|c| c == capability);
+
+		let tool_calls = ModelCapabilities::get_override(AdapterKind::Ollama, &model.id)
+			.and_then(|cap_override| cap_override.tool_calls)
+			.unwrap_or_else(|| has("tools"));
+
+		let mut input_modalities = model.supported_input_modalities.clone();
+		if has("vision") {
+			input_modalities.insert(Modality::Image);
+		} else {
+			input_modalities.remove(&Modality::Image);
+		}
+
+		let model = model.with_tool_calls(tool_calls).with_input_modalities(input_modalities);
+		if has("thinking") {
+			model.with_reasoning(true)
+		} else {
+			model
+		}
+	}
+}
+
+// endregion: --- Show Capabilities
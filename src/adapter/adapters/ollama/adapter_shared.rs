@@ -1,23 +1,43 @@
 //! This is support implementation of the Ollama Adapter which can also be called by other Ollama Adapter Variants
 
 use crate::Headers;
-use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::{ignored_options, join_service_url};
 use crate::adapter::ollama::OllamaAdapter;
-use crate::chat::{Binary, BinarySource, ChatRequest, ContentPart, Tool, ToolName, Usage};
+use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType};
+use crate::chat::{
+	Binary, BinarySource, ChatOptionsSet, ChatRequest, ChatResponseFormat, ContentPart, IgnoredOption, SamplingExtras,
+	Tool, ToolName, Usage,
+};
 use crate::resolver::Endpoint;
-use crate::{Error, Result};
+use crate::{Error, ModelIden, Result};
 use serde_json::{Value, json};
 use value_ext::JsonValueExt;
 
+/// The `ChatOptions` payload options sent by the Ollama adapters (the others are reported as ignored).
+const SUPPORTED_OPTIONS: &[&str] = &[
+	"temperature",
+	"max_tokens",
+	"top_p",
+	"top_k",
+	"stop_sequences",
+	"seed",
+	"response_format",
+	"sampling_extras",
+];
+
 /// Support functions for other adapters that share Ollama APIs
 impl OllamaAdapter {
+	/// Returns the `api/generate` url, for the raw prompt requests (see `ChatRequest::with_raw_prompt`).
+	pub(in crate::adapter::adapters) fn util_get_generate_url(endpoint: &Endpoint) -> Result<String> {
+		join_service_url(endpoint.base_url(), "api/generate")
+	}
+
 	pub(in crate::adapter::adapters) async fn list_model_names(
 		adapter_kind: AdapterKind,
 		endpoint: Endpoint,
 		headers: Headers,
 	) -> Result<Vec<String>> {
-		let base_url = endpoint.base_url();
-		let url = format!("{base_url}api/tags");
+		let url = AdapterDispatcher::get_service_url(&ModelIden::new(adapter_kind, ""), ServiceType::Models, endpoint)?;
 
 		let web_c = crate::webc::WebClient::default();
 		let mut res = web_c.do_get(&url, &headers).await.map_err(|webc_error| Error::WebAdapterCall {
@@ -40,6 +60,44 @@ impl OllamaAdapter {
 		Ok(models)
 	}
 
+	/// Inserts the `SamplingExtras` into the Ollama request `options` object.
+	pub(in crate::adapter::adapters) fn util_insert_sampling_extras(
+		options: &mut Value,
+		extras: &SamplingExtras,
+	) -> Result<()> {
+		if let Some(min_p) = extras.min_p {
+			options.x_insert("min_p", min_p)?;
+		}
+		if let Some(repetition_penalty) = extras.repetition_penalty {
+			options.x_insert("repeat_penalty", repetition_penalty)?;
+		}
+		if let Some(mirostat) = extras.mirostat {
+			options.x_insert("mirostat", mirostat)?;
+		}
+		if let Some(mirostat_tau) = extras.mirostat_tau {
+			options.x_insert("mirostat_tau", mirostat_tau)?;
+		}
+		if let Some(mirostat_eta) = extras.mirostat_eta {
+			options.x_insert("mirostat_eta", mirostat_eta)?;
+		}
+		Ok(())
+	}
+
+	/// Returns the chat options not sent in the Ollama chat payload (see `support::ignored_options`).
+	pub(in crate::adapter::adapters) fn util_ignored_options(
+		options_set: &ChatOptionsSet,
+		adapter_kind: AdapterKind,
+	) -> Vec<IgnoredOption> {
+		let mut ignored_options = ignored_options(options_set, SUPPORTED_OPTIONS, adapter_kind);
+		if let Some(ChatResponseFormat::JsonSpec(_)) = options_set.response_format() {
+			ignored_options.push(IgnoredOption::new(
+				"response_format",
+				"JsonSpec is not supported by Ollama (only JsonMode)",
+			));
+		}
+		ignored_options
+	}
+
 	pub(in crate::adapter::adapters) fn into_usage(body: &mut Value) -> Usage {
 		let prompt_tokens = body.x_take::<i32>("prompt_eval_count").ok();
 		let completion_tokens = body.x_take::<i32>("eval_count").ok();
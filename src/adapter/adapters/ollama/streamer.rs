@@ -82,7 +82,11 @@ impl futures::Stream for OllamaStreamer {
 						}
 
 						// -- Handle Text Chunk
-						if let Ok(content) = data.x_take::<String>("/message/content") {
+						// Note: The raw prompt (`api/generate`) chunks have a top-level `response` instead of the `message`.
+						if let Ok(content) = data
+							.x_take::<String>("/message/content")
+							.or_else(|_| data.x_take::<String>("response"))
+						{
 							// Note: Ollama may return content in chunks, so we check if it's non-empty and return it as a content chunk.
 							if !content.is_empty() {
 								// Add to the captured_content if chat options say so
@@ -165,6 +169,7 @@ impl futures::Stream for OllamaStreamer {
 								captured_tool_calls: self.captured_data.tool_calls.take(),
 								captured_thought_signatures: None,
 								captured_response_id: None,
+								interrupted_by: None,
 							};
 
 							return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
@@ -172,11 +177,16 @@ impl futures::Stream for OllamaStreamer {
 					}
 				}
 				Some(Err(err)) => {
-					return Poll::Ready(Some(Err(Error::WebStream {
+					let error = Error::WebStream {
 						model_iden: self.options.model_iden.clone(),
 						cause: err.to_string(),
 						error: err,
-					})));
+					};
+					// End with the partial data captured so far, if any (the error follows, see `ChatStream`)
+					self.done = true;
+					return Poll::Ready(Some(
+						self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+					));
 				}
 				None => {
 					if !self.done {
@@ -190,6 +200,7 @@ impl futures::Stream for OllamaStreamer {
 							captured_tool_calls: self.captured_data.tool_calls.take(),
 							captured_thought_signatures: None,
 							captured_response_id: None,
+							interrupted_by: None,
 						};
 						return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
 					}
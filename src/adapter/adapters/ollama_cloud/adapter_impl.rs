@@ -5,7 +5,7 @@ use crate::adapter::adapters::ollama::OllamaRequestParts;
 use crate::adapter::adapters::support::get_api_key;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
-use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, EmbedTruncate};
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
 use crate::{Model, ModelIden, Result, ServiceTarget};
@@ -78,14 +78,19 @@ impl Adapter for OllamaCloudAdapter {
 	fn to_web_request_data(
 		target: ServiceTarget,
 		service_type: ServiceType,
-		chat_req: ChatRequest,
+		mut chat_req: ChatRequest,
 		chat_options: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
 		let ServiceTarget {
 			model, endpoint, auth, ..
 		} = target;
 		let api_key = get_api_key(auth, &model)?;
-		let url = OllamaAdapter::get_service_url(&model, service_type, endpoint)?;
+		let raw_prompt = chat_req.raw_prompt.take();
+		let url = if raw_prompt.is_some() {
+			OllamaAdapter::util_get_generate_url(&endpoint)?
+		} else {
+			OllamaAdapter::get_service_url(&model, service_type, endpoint)?
+		};
 		let OllamaRequestParts { messages, tools } = OllamaAdapter::into_ollama_request_parts(chat_req)?;
 
 		let mut options = json!({});
@@ -95,6 +100,9 @@ impl Adapter for OllamaCloudAdapter {
 		if let Some(top_p) = chat_options.top_p() {
 			options.x_insert("top_p", top_p)?;
 		}
+		if let Some(top_k) = chat_options.top_k() {
+			options.x_insert("top_k", top_k)?;
+		}
 		if let Some(max_tokens) = chat_options.max_tokens() {
 			options.x_insert("num_predict", max_tokens)?;
 		}
@@ -104,15 +112,25 @@ impl Adapter for OllamaCloudAdapter {
 		if !chat_options.stop_sequences().is_empty() {
 			options.x_insert("stop", chat_options.stop_sequences())?;
 		}
+		if let Some(sampling_extras) = chat_options.sampling_extras() {
+			OllamaAdapter::util_insert_sampling_extras(&mut options, sampling_extras)?;
+		}
 
 		let stream = matches!(service_type, ServiceType::ChatStream);
 		let (_, model_name) = model.model_name.namespace_and_name();
 
 		let mut payload = json!({
 			"model": model_name,
-			"messages": messages,
 			"stream": stream,
 		});
+		match raw_prompt {
+			// `raw` so that Ollama does not apply the model template to the prompt
+			Some(raw_prompt) => {
+				payload.x_insert("prompt", raw_prompt)?;
+				payload.x_insert("raw", true)?;
+			}
+			None => payload.x_insert("messages", messages)?,
+		}
 
 		if !options.as_object().unwrap().is_empty() {
 			payload.x_insert("options", options)?;
@@ -134,7 +152,14 @@ impl Adapter for OllamaCloudAdapter {
 		}
 		headers.merge(Headers::from(("Authorization", format!("Bearer {api_key}"))));
 
-		Ok(WebRequestData { url, headers, payload })
+		let ignored_options = OllamaAdapter::util_ignored_options(&chat_options, model.adapter_kind);
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -173,8 +198,9 @@ impl Adapter for OllamaCloudAdapter {
 		if let Some(dimensions) = options_set.dimensions() {
 			payload.x_insert("dimensions", dimensions)?;
 		}
+		// Note: Ollama truncates the end (`EmbedTruncate::Start` is applied client-side, when the limit is known)
 		if let Some(truncate) = options_set.truncate() {
-			payload.x_insert("truncate", truncate)?;
+			payload.x_insert("truncate", !matches!(truncate, EmbedTruncate::None))?;
 		}
 
 		let mut headers = Headers::default();
@@ -183,7 +209,12 @@ impl Adapter for OllamaCloudAdapter {
 		}
 		headers.merge(Headers::from(("Authorization", format!("Bearer {api_key}"))));
 
-		Ok(WebRequestData { url, headers, payload })
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options: Vec::new(),
+		})
 	}
 
 	fn to_embed_response(
@@ -1,12 +1,13 @@
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, parse_tool_arguments, with_model_deprecation};
 use crate::adapter::model_capabilities::ModelCapabilities;
 use crate::adapter::openai::OpenAIStreamer;
-use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::adapter::{Adapter, AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatStream, ChatStreamResponse, MessageContent, StopReason, ToolCall,
+	ChatChoice, ChatOptionsSet, ChatRequest, ChatResponse, ChatStream, ChatStreamResponse, MessageContent, StopReason,
+	ToolCall,
 };
 use crate::common::{Modality, ReasoningEffortType};
-use crate::resolver::{AuthData, Endpoint};
+use crate::resolver::{AuthData, Endpoint, StreamTransport};
 use crate::webc::{EventSourceStream, WebResponse};
 use crate::{Error, Headers, Model, Result};
 use crate::{ModelIden, ServiceTarget};
@@ -18,6 +19,19 @@ use value_ext::JsonValueExt;
 
 pub struct OpenAIAdapter;
 
+// Fallback list when the `/models` API is not reachable - newer on top
+pub(in crate::adapter) const MODELS: &[&str] = &[
+	"gpt-4.1",
+	"gpt-4.1-mini",
+	"gpt-4.1-nano",
+	"gpt-4o",
+	"gpt-4o-mini",
+	"o4-mini",
+	"o3",
+	"o3-mini",
+	"o1",
+];
+
 impl OpenAIAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "OPENAI_API_KEY";
 	pub const BASE_URL_DEFAULT_ENV_NAME: &str = "OPENAI_BASE_URL";
@@ -65,6 +79,9 @@ impl OpenAIAdapter {
 			model = model.with_reasoning_efforts(reasoning_efforts);
 		}
 
+		// 弃用信息（如果 API 返回）
+		let model = with_model_deprecation(model, &model_data);
+
 		Ok(model)
 	}
 
@@ -131,9 +148,12 @@ impl Adapter for OpenAIAdapter {
 		Endpoint::from_owned(base_url)
 	}
 
-	/// Note: Currently returns the common models (see above)
+	/// Note: Queries the live `/models` endpoint, and falls back to the common models (see `MODELS`) if it fails.
 	async fn all_model_names(kind: AdapterKind, endpoint: Endpoint, auth: AuthData) -> Result<Vec<String>> {
-		OpenAIAdapter::list_model_names_for_end_target(kind, endpoint, auth).await
+		match OpenAIAdapter::list_model_names_for_end_target(kind, endpoint, auth).await {
+			Ok(model_names) if !model_names.is_empty() => Ok(model_names),
+			_ => Ok(MODELS.iter().map(|s| s.to_string()).collect()),
+		}
 	}
 
 	async fn all_models(
@@ -149,7 +169,7 @@ impl Adapter for OpenAIAdapter {
 		let model_iden = ModelIden::new(kind, "temp");
 
 		// 获取 models API 的 URL
-		let url = Self::util_get_service_url(&model_iden, ServiceType::Models, endpoint)?;
+		let url = AdapterDispatcher::get_service_url(&model_iden, ServiceType::Models, endpoint)?;
 
 		// 获取 API key
 		let api_key = get_api_key(auth, &model_iden)?;
@@ -157,26 +177,18 @@ impl Adapter for OpenAIAdapter {
 		// 构建请求头
 		let headers = Headers::from(vec![("Authorization".to_string(), format!("Bearer {api_key}"))]);
 
-		// 使用传入的 WebClient 发送请求
-		let mut web_response = web_client
-			.do_get(&url, &headers)
-			.await
-			.map_err(|webc_error| Error::WebAdapterCall {
-				adapter_kind: kind,
-				webc_error,
-			})?;
+		// 使用传入的 WebClient 发送请求（跟随分页）
+		let models_data = Self::fetch_all_models_data(kind, web_client, &url, &headers).await?;
 
 		// 解析响应
 		let mut models: Vec<Model> = Vec::new();
 
-		if let Value::Array(models_data) = web_response.body.x_take("data")? {
-			for mut model_data in models_data {
-				let model_id: String = model_data.x_take("id")?;
+		for mut model_data in models_data {
+			let model_id: String = model_data.x_take("id")?;
 
-				// 解析模型的基本信息
-				let model = Self::parse_openai_model_to_model(model_id, model_data)?;
-				models.push(model);
-			}
+			// 解析模型的基本信息
+			let model = Self::parse_openai_model_to_model(model_id, model_data)?;
+			models.push(model);
 		}
 
 		Ok(models)
@@ -202,9 +214,14 @@ impl Adapter for OpenAIAdapter {
 	) -> Result<ChatResponse> {
 		let WebResponse { mut body, .. } = web_response;
 
-		// -- Capture the provider_model_iden
+		// -- Capture the provider_model_iden and the response id (e.g., `chatcmpl-...`)
 		let provider_model_name: Option<String> = body.x_remove("model").ok();
 		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
+		let response_id: Option<String> = body.x_take::<Option<String>>("id").ok().flatten();
+
+		// -- Capture the service tier and the provider timing (before the usage, which holds the Groq timings)
+		let service_tier: Option<String> = body.x_take::<Option<String>>("service_tier").ok().flatten();
+		let provider_timing = OpenAIAdapter::provider_timing(&body);
 
 		// -- Capture the usage
 		let usage = body
@@ -212,61 +229,28 @@ impl Adapter for OpenAIAdapter {
 			.map(|value| OpenAIAdapter::into_usage(model_iden.adapter_kind, value))
 			.unwrap_or_default();
 
-		// -- Capture the content
-		let mut content: MessageContent = MessageContent::default();
-		let mut reasoning_content: Option<String> = None;
-		let mut stop_reason: Option<StopReason> = None;
-
-		if let Ok(Some(mut first_choice)) = body.x_take::<Option<Value>>("/choices/0") {
-			stop_reason = first_choice
-				.x_take::<Option<String>>("finish_reason")
-				.ok()
-				.flatten()
-				.map(StopReason::from);
-
-			// Check if reasoning is present
-			// Can be in two places:
-			// - /message/reasoning
-			// - /message/reasoning_content
-			// Extracted before content as some model can return reasoning without content
-			reasoning_content = first_choice
-				.x_take::<Option<String>>("/message/reasoning")
-				.ok()
-				.unwrap_or_else(|| {
-					first_choice
-						.x_take::<Option<String>>("/message/reasoning_content")
-						.ok()
-						.flatten()
-				})
-				.map(|s| s.trim().to_string());
-
-			// -- Push eventual text message
-			if let Ok(Some(mut text_content)) = first_choice.x_take::<Option<String>>("/message/content") {
-				text_content = text_content.trim().to_string();
-				// If not reasoning_content, but
-				if reasoning_content.is_none() && options_set.normalize_reasoning_content().unwrap_or_default() {
-					let (content_tmp, reasoning_content_tmp) = extract_think(text_content);
-					reasoning_content = reasoning_content_tmp;
-					text_content = content_tmp;
-				}
-
-				// After extracting reasoning_content, sometimes the content is empty.
-				if !text_content.is_empty() {
-					content.push(text_content);
-				}
-			}
-
-			// -- Push eventual ToolCalls
-			if let Some(tool_calls) = first_choice
-				.x_take("/message/tool_calls")
-				.ok()
-				.map(parse_tool_calls)
-				.transpose()?
-				.map(MessageContent::from_tool_calls)
-			{
-				content.extend(tool_calls);
-			}
-		}
+		// -- Capture the choices (the first one is the response content)
+		let lenient_tool_arguments = options_set.lenient_tool_arguments().unwrap_or(false);
+		let normalize_reasoning_content = options_set.normalize_reasoning_content().unwrap_or_default();
+
+		let raw_choices = body.x_take::<Option<Vec<Value>>>("choices").ok().flatten().unwrap_or_default();
+		let mut choices = raw_choices
+			.into_iter()
+			.enumerate()
+			.map(|(idx, choice)| parse_choice(idx, choice, lenient_tool_arguments, normalize_reasoning_content))
+			.collect::<Result<Vec<_>>>()?;
+
+		// Note: The choices are only kept for the multi-choice responses (otherwise, all in the response itself)
+		let first_choice = if choices.len() > 1 {
+			choices.first().cloned()
+		} else {
+			choices.pop()
+		};
+		let (content, reasoning_content, stop_reason) = first_choice
+			.map(|choice| (choice.content, choice.reasoning_content, choice.stop_reason))
+			.unwrap_or_default();
+
+		let usage_reported = !usage.is_empty();
 
 		Ok(ChatResponse {
 			content,
@@ -275,8 +259,15 @@ impl Adapter for OpenAIAdapter {
 			provider_model_iden,
 			stop_reason,
 			usage,
+			usage_reported,
 			captured_raw_body: None, // Set by the client exec_chat
-			response_id: None,
+			response_id,
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices,
+			service_tier,
+			provider_timing,
 		})
 	}
 
@@ -285,13 +276,19 @@ impl Adapter for OpenAIAdapter {
 		reqwest_builder: RequestBuilder,
 		options_sets: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let event_source = EventSourceStream::new(reqwest_builder);
+		let event_source = match options_sets.stream_transport() {
+			StreamTransport::Sse => EventSourceStream::new(reqwest_builder),
+			StreamTransport::JsonLines => EventSourceStream::new_json_lines(reqwest_builder),
+		};
+		let event_source = event_source.with_lossy_utf8(options_sets.lossy_utf8().unwrap_or(true));
 		let openai_stream = OpenAIStreamer::new(event_source, model_iden.clone(), options_sets);
 		let chat_stream = ChatStream::from_inter_stream(openai_stream);
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -343,7 +340,87 @@ fn extract_think(content: String) -> (String, Option<String>) {
 	(content, None)
 }
 
-fn parse_tool_calls(raw_tool_calls: Value) -> Result<Vec<ToolCall>> {
+/// Parses a `choices` item (content, reasoning, tool calls, finish reason, and eventual logprobs).
+fn parse_choice(
+	idx: usize,
+	mut choice: Value,
+	lenient_tool_arguments: bool,
+	normalize_reasoning_content: bool,
+) -> Result<ChatChoice> {
+	let index = choice
+		.x_take::<Option<u32>>("index")
+		.ok()
+		.flatten()
+		.unwrap_or_else(|| u32::try_from(idx).unwrap_or(u32::MAX));
+
+	let stop_reason = choice
+		.x_take::<Option<String>>("finish_reason")
+		.ok()
+		.flatten()
+		.map(StopReason::from);
+
+	let logprobs = choice.x_take::<Option<Value>>("logprobs").ok().flatten();
+
+	// Check if reasoning is present
+	// Can be in two places:
+	// - /message/reasoning
+	// - /message/reasoning_content
+	// Extracted before content as some model can return reasoning without content
+	let mut reasoning_content = choice
+		.x_take::<Option<String>>("/message/reasoning")
+		.ok()
+		.unwrap_or_else(|| choice.x_take::<Option<String>>("/message/reasoning_content").ok().flatten())
+		.map(|s| s.trim().to_string());
+
+	let mut content = MessageContent::default();
+
+	// -- Push eventual text message
+	// Note: The completions (raw prompt) choices have a `text` instead of the `message`.
+	let text_content = choice
+		.x_take::<Option<String>>("/message/content")
+		.or_else(|_| choice.x_take::<Option<String>>("text"));
+	if let Ok(Some(mut text_content)) = text_content {
+		// NOTE: A text truncated by the output token limit keeps its trailing whitespace,
+		//       so that the continuation can be stitched to it (see `ChatOptions::with_auto_continue`).
+		text_content = if stop_reason.as_ref().is_some_and(StopReason::is_max_tokens) {
+			text_content.trim_start().to_string()
+		} else {
+			text_content.trim().to_string()
+		};
+		// If not reasoning_content, but
+		if reasoning_content.is_none() && normalize_reasoning_content {
+			let (content_tmp, reasoning_content_tmp) = extract_think(text_content);
+			reasoning_content = reasoning_content_tmp;
+			text_content = content_tmp;
+		}
+
+		// After extracting reasoning_content, sometimes the content is empty.
+		if !text_content.is_empty() {
+			content.push(text_content);
+		}
+	}
+
+	// -- Push eventual ToolCalls
+	if let Some(tool_calls) = choice
+		.x_take("/message/tool_calls")
+		.ok()
+		.map(|raw_tool_calls| parse_tool_calls(raw_tool_calls, lenient_tool_arguments))
+		.transpose()?
+		.map(MessageContent::from_tool_calls)
+	{
+		content.extend(tool_calls);
+	}
+
+	Ok(ChatChoice {
+		index,
+		content,
+		reasoning_content,
+		stop_reason,
+		logprobs,
+	})
+}
+
+fn parse_tool_calls(raw_tool_calls: Value, lenient_tool_arguments: bool) -> Result<Vec<ToolCall>> {
 	// Some backends (like sglang) return null if no tool calls are present.
 	if raw_tool_calls.is_null() {
 		return Ok(vec![]);
@@ -355,12 +432,15 @@ fn parse_tool_calls(raw_tool_calls: Value) -> Result<Vec<ToolCall>> {
 		});
 	};
 
-	let tool_calls = raw_tool_calls.into_iter().map(parse_tool_call).collect::<Result<Vec<_>>>()?;
+	let tool_calls = raw_tool_calls
+		.into_iter()
+		.map(|raw_tool_call| parse_tool_call(raw_tool_call, lenient_tool_arguments))
+		.collect::<Result<Vec<_>>>()?;
 
 	Ok(tool_calls)
 }
 
-fn parse_tool_call(raw_tool_call: Value) -> Result<ToolCall> {
+fn parse_tool_call(raw_tool_call: Value, lenient_tool_arguments: bool) -> Result<ToolCall> {
 	// Define a helper struct to match the original JSON structure.
 	#[derive(Deserialize)]
 	struct IterimToolFnCall {
@@ -381,17 +461,8 @@ fn parse_tool_call(raw_tool_call: Value) -> Result<ToolCall> {
 
 	let fn_name = iterim.function.name;
 
-	// For now, support Object only, and parse the eventual string as a json value.
-	// Eventually, we might check pricing
-	let fn_arguments = match iterim.function.arguments {
-		Value::Object(obj) => Value::Object(obj),
-		Value::String(txt) => serde_json::from_str(&txt)?,
-		_ => {
-			return Err(Error::InvalidJsonResponseElement {
-				info: "tool call arguments is not an object",
-			});
-		}
-	};
+	// Object, or stringified JSON object (error on malformed, unless lenient)
+	let fn_arguments = parse_tool_arguments(&fn_name, iterim.function.arguments, lenient_tool_arguments)?;
 
 	// Then, map the fields of the helper struct to the flat structure.
 	Ok(ToolCall {
@@ -408,6 +479,7 @@ fn parse_tool_call(raw_tool_call: Value) -> Result<ToolCall> {
 mod tests {
 	use super::*;
 	use crate::adapter::AdapterKind;
+	use crate::chat::{ContentPart, Usage};
 	use reqwest::StatusCode;
 
 	fn test_model() -> ModelIden {
@@ -418,6 +490,7 @@ mod tests {
 	fn test_to_chat_response_captures_finish_reason_as_stop_reason() {
 		let web_response = WebResponse {
 			status: StatusCode::OK,
+			headers: Default::default(),
 			body: serde_json::json!({
 				"id": "chatcmpl-test",
 				"model": "gpt-4o-mini-2024-07-18",
@@ -440,6 +513,7 @@ mod tests {
 	fn test_to_chat_response_stop_reason_none_when_missing() {
 		let web_response = WebResponse {
 			status: StatusCode::OK,
+			headers: Default::default(),
 			body: serde_json::json!({
 				"id": "chatcmpl-test",
 				"model": "gpt-4o-mini-2024-07-18",
@@ -455,4 +529,303 @@ mod tests {
 
 		assert_eq!(response.stop_reason, None);
 	}
+
+	#[test]
+	fn test_to_chat_response_usage_not_reported() -> Result<()> {
+		// -- Without usage (e.g., some OpenAI compatible servers)
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: serde_json::json!({
+				"id": "chatcmpl-test",
+				"model": "local-model",
+				"choices": [{
+					"finish_reason": "stop",
+					"message": {"role": "assistant", "content": "hello"}
+				}]
+			}),
+		};
+		let response = OpenAIAdapter::to_chat_response(test_model(), web_response, ChatOptionsSet::default())?;
+		assert!(!response.usage_reported);
+		assert!(response.usage.is_empty());
+
+		// -- With a zero usage (reported, not empty)
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: serde_json::json!({
+				"id": "chatcmpl-test",
+				"model": "local-model",
+				"usage": {"prompt_tokens": 0, "completion_tokens": 0, "total_tokens": 0},
+				"choices": [{
+					"finish_reason": "stop",
+					"message": {"role": "assistant", "content": ""}
+				}]
+			}),
+		};
+		let response = OpenAIAdapter::to_chat_response(test_model(), web_response, ChatOptionsSet::default())?;
+		assert!(response.usage_reported);
+		assert_eq!(response.usage.completion_tokens, Some(0));
+
+		Ok(())
+	}
+
+	/// The completions (raw prompt) responses have the choice `text` instead of the `message`.
+	#[test]
+	fn test_to_chat_response_completions_text() -> Result<()> {
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: serde_json::json!({
+				"id": "cmpl-test",
+				"object": "text_completion",
+				"model": "acme/custom-finetune",
+				"usage": {"prompt_tokens": 12, "completion_tokens": 2, "total_tokens": 14},
+				"choices": [{"index": 0, "text": "Hello!", "finish_reason": "stop"}]
+			}),
+		};
+
+		let response = OpenAIAdapter::to_chat_response(test_model(), web_response, ChatOptionsSet::default())?;
+
+		assert_eq!(response.first_text(), Some("Hello!"));
+		assert_eq!(response.stop_reason, Some(StopReason::Completed("stop".to_string())));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_chat_response_multi_choice() -> Result<()> {
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: serde_json::json!({
+				"id": "chatcmpl-test",
+				"model": "gpt-4o-mini-2024-07-18",
+				"usage": {"prompt_tokens": 10, "completion_tokens": 12, "total_tokens": 22},
+				"choices": [
+					{
+						"index": 0,
+						"finish_reason": "stop",
+						"message": {"role": "assistant", "content": "Blue."},
+						"logprobs": {"content": [{"token": "Blue", "logprob": -0.1}]}
+					},
+					{
+						"index": 1,
+						"finish_reason": "length",
+						"message": {"role": "assistant", "content": "The sky is usually"},
+						"logprobs": null
+					}
+				]
+			}),
+		};
+
+		let response = OpenAIAdapter::to_chat_response(test_model(), web_response, ChatOptionsSet::default())?;
+
+		// -- The first choice is the response
+		assert_eq!(response.first_text(), Some("Blue."));
+		assert_eq!(response.stop_reason, Some(StopReason::Completed("stop".to_string())));
+
+		// -- All the choices
+		assert_eq!(response.choice_count(), 2);
+		let [first, second] = response.choices.as_slice() else {
+			panic!("should have two choices");
+		};
+		assert_eq!(first.index, 0);
+		assert!(first.logprobs.is_some());
+		assert_eq!(second.index, 1);
+		assert_eq!(second.content.first_text(), Some("The sky is usually"));
+		assert!(second.stop_reason.as_ref().is_some_and(StopReason::is_max_tokens));
+		assert!(second.logprobs.is_none());
+
+		// -- The usage covers both choices
+		assert_eq!(response.usage.completion_tokens, Some(12));
+		assert_eq!(response.completion_tokens_per_choice(), Some(6));
+
+		Ok(())
+	}
+
+	/// `ChatResponse::to_openai_json` is the inverse of `to_chat_response`.
+	#[test]
+	fn test_to_openai_json_round_trip() -> Result<()> {
+		// -- Setup & Fixtures
+		let chat_res = ChatResponse {
+			content: MessageContent::from_parts(vec![
+				ContentPart::Text("Let me check the weather.".to_string()),
+				ContentPart::ToolCall(ToolCall {
+					call_id: "call_1".to_string(),
+					fn_name: "get_weather".to_string(),
+					fn_arguments: serde_json::json!({"city": "Paris"}),
+					thought_signatures: None,
+				}),
+			]),
+			reasoning_content: Some("The user wants the weather.".to_string()),
+			model_iden: test_model(),
+			provider_model_iden: test_model().from_name("gpt-4o-mini-2024-07-18"),
+			stop_reason: Some(StopReason::from("tool_calls".to_string())),
+			usage: Usage {
+				prompt_tokens: Some(10),
+				completion_tokens: Some(5),
+				total_tokens: Some(15),
+				..Default::default()
+			},
+			usage_reported: true,
+			captured_raw_body: None,
+			response_id: None,
+			metrics: None,
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: Some("default".to_string()),
+			provider_timing: None,
+		};
+
+		// -- Exec
+		let body = chat_res.to_openai_json();
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: body.clone(),
+		};
+		let parsed = OpenAIAdapter::to_chat_response(test_model(), web_response, ChatOptionsSet::default())?;
+
+		// -- Check
+		assert_eq!(body["object"], "chat.completion");
+		assert_eq!(body["choices"][0]["finish_reason"], "tool_calls");
+		assert_eq!(
+			body["choices"][0]["message"]["tool_calls"][0]["function"]["arguments"],
+			r#"{"city":"Paris"}"#
+		);
+
+		assert_eq!(
+			serde_json::to_value(&parsed.content)?,
+			serde_json::to_value(&chat_res.content)?
+		);
+		assert_eq!(parsed.reasoning_content, chat_res.reasoning_content);
+		assert_eq!(parsed.model_iden, chat_res.model_iden);
+		assert_eq!(parsed.provider_model_iden, chat_res.provider_model_iden);
+		assert_eq!(parsed.stop_reason, chat_res.stop_reason);
+		assert_eq!(
+			serde_json::to_value(&parsed.usage)?,
+			serde_json::to_value(&chat_res.usage)?
+		);
+		assert_eq!(parsed.usage_reported, chat_res.usage_reported);
+		assert_eq!(parsed.service_tier, chat_res.service_tier);
+		assert!(parsed.choices.is_empty());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_openai_json_round_trip_multi_choice() -> Result<()> {
+		// -- Setup & Fixtures
+		let web_response = |body| WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body,
+		};
+		let chat_res = OpenAIAdapter::to_chat_response(
+			test_model(),
+			web_response(serde_json::json!({
+				"model": "gpt-4o-mini-2024-07-18",
+				"choices": [
+					{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": "Blue."}},
+					{"index": 1, "finish_reason": "length", "message": {"role": "assistant", "content": "The sky"}}
+				]
+			})),
+			ChatOptionsSet::default(),
+		)?;
+
+		// -- Exec
+		let body = chat_res.to_openai_json();
+		let parsed =
+			OpenAIAdapter::to_chat_response(test_model(), web_response(body.clone()), ChatOptionsSet::default())?;
+
+		// -- Check
+		assert!(body.get("usage").is_none());
+		assert!(!parsed.usage_reported);
+		assert_eq!(parsed.first_text(), Some("Blue."));
+		assert_eq!(parsed.choice_count(), 2);
+		assert_eq!(parsed.choices[1].content.first_text(), Some("The sky"));
+		assert_eq!(parsed.choices[1].stop_reason, chat_res.choices[1].stop_reason);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_chat_response_service_tier_openai_flex() -> Result<()> {
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: serde_json::json!({
+				"id": "chatcmpl-test",
+				"model": "o3-2025-04-16",
+				"service_tier": "flex",
+				"usage": {"prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15},
+				"choices": [{"finish_reason": "stop", "message": {"role": "assistant", "content": "hello"}}]
+			}),
+		};
+
+		let response = OpenAIAdapter::to_chat_response(test_model(), web_response, ChatOptionsSet::default())?;
+
+		assert_eq!(response.service_tier.as_deref(), Some("flex"));
+		assert_eq!(response.provider_timing, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_chat_response_provider_timing_groq() -> Result<()> {
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: serde_json::json!({
+				"id": "chatcmpl-test",
+				"model": "llama-3.3-70b-versatile",
+				"service_tier": "on_demand",
+				"usage": {
+					"queue_time": 0.0421,
+					"prompt_tokens": 18,
+					"prompt_time": 0.0031,
+					"completion_tokens": 7,
+					"completion_time": 0.0254,
+					"total_tokens": 25,
+					"total_time": 0.0285
+				},
+				"x_groq": {"id": "req_01abc"},
+				"choices": [{"finish_reason": "stop", "message": {"role": "assistant", "content": "hello"}}]
+			}),
+		};
+		let model = ModelIden::new(AdapterKind::Groq, "llama-3.3-70b-versatile");
+
+		let response = OpenAIAdapter::to_chat_response(model, web_response, ChatOptionsSet::default())?;
+
+		assert_eq!(response.service_tier.as_deref(), Some("on_demand"));
+		assert_eq!(
+			response.provider_timing,
+			Some(serde_json::json!({
+				"queue_time": 0.0421,
+				"prompt_time": 0.0031,
+				"completion_time": 0.0254,
+				"total_time": 0.0285,
+				"x_groq": {"id": "req_01abc"}
+			}))
+		);
+		// the timings do not change the usage
+		assert_eq!(response.usage.total_tokens, Some(25));
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_all_model_names_fallback_when_unreachable() -> Result<()> {
+		// Nothing listens on this port, so the live call fails and the static list is returned
+		let endpoint = Endpoint::from_static("http://127.0.0.1:1/v1/");
+		let auth = AuthData::from_single("test-key");
+
+		let model_names = OpenAIAdapter::all_model_names(AdapterKind::OpenAI, endpoint, auth).await?;
+
+		assert_eq!(model_names, MODELS.iter().map(|s| s.to_string()).collect::<Vec<_>>());
+
+		Ok(())
+	}
 }
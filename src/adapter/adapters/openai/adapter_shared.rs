@@ -1,13 +1,14 @@
 //! This is support implementation of the OpenAI Adapter which can also be called by other OpenAI Adapter Variants
 
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, ignored_options, join_service_url, validate_openai_tool_names};
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	BinarySource, CacheControl, ChatOptionsSet, ChatRequest, ChatResponseFormat, ChatRole, ContentPart,
-	ReasoningEffort, Usage,
+	Binary, BinarySource, CacheControl, ChatOptionsSet, ChatRequest, ChatResponseFormat, ChatRole, ContentPart,
+	IgnoredOption, ReasoningEffort, SamplingExtras, ToolChoice, ToolName, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
+use crate::webc::WebClient;
 use crate::{Error, Headers, Result};
 use crate::{ModelIden, ServiceTarget};
 use serde_json::{Value, json};
@@ -15,6 +16,44 @@ use tracing::error;
 use tracing::warn;
 use value_ext::JsonValueExt;
 
+/// The `ChatOptions` payload options sent by the OpenAI-compatible adapters (the others are reported as ignored).
+/// `reasoning_effort` is added for OpenAI, and `top_k`, `min_tokens`, and `sampling_extras` for the
+/// `ToWebRequestCustom::sampling_extras` providers.
+const OPENAI_SUPPORTED_OPTIONS: &[&str] = &[
+	"temperature",
+	"max_tokens",
+	"top_p",
+	"stop_sequences",
+	"seed",
+	"n",
+	"response_format",
+	"verbosity",
+	"service_tier",
+	"tool_choice",
+	"parallel_tool_calls",
+	"cache_control",
+	"prompt_cache_key",
+];
+
+/// Returns the payload key of the max tokens for the model.
+///
+/// GPT-5.x and o-series models require `max_completion_tokens` (they reject `max_tokens`), the others
+/// (older OpenAI models, and the OpenAI-compatible providers) take `max_tokens`.
+/// Note: A provider prefix (e.g., `openai/o3-mini` for the GitHub models) is ignored.
+fn max_tokens_key(model_name: &str) -> &'static str {
+	let model_name = model_name.rsplit('/').next().unwrap_or(model_name);
+	let is_o_series = ["o1", "o3", "o4"].iter().any(|prefix| {
+		model_name
+			.strip_prefix(prefix)
+			.is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+	});
+	if is_o_series || model_name.starts_with("gpt-5") {
+		"max_completion_tokens"
+	} else {
+		"max_tokens"
+	}
+}
+
 fn insert_openai_reasoning_effort(payload: &mut Value, effort: &ReasoningEffort) -> Result<()> {
 	let keyword = match effort {
 		ReasoningEffort::None => "none",
@@ -31,6 +70,27 @@ fn insert_openai_reasoning_effort(payload: &mut Value, effort: &ReasoningEffort)
 	Ok(())
 }
 
+/// Converts the tool choice to the OpenAI Chat Completions `tool_choice`.
+fn tool_choice_to_openai_json(tool_choice: &ToolChoice) -> Value {
+	match tool_choice {
+		ToolChoice::Auto => json!("auto"),
+		ToolChoice::Required => json!("required"),
+		ToolChoice::None => json!("none"),
+		ToolChoice::Tool(name) => json!({"type": "function", "function": {"name": name}}),
+	}
+}
+
+/// Inserts the `SamplingExtras` supported by the vLLM OpenAI-compatible API (mirostat is not).
+fn insert_openai_sampling_extras(payload: &mut Value, extras: &SamplingExtras) -> Result<()> {
+	if let Some(min_p) = extras.min_p {
+		payload.x_insert("min_p", min_p)?;
+	}
+	if let Some(repetition_penalty) = extras.repetition_penalty {
+		payload.x_insert("repetition_penalty", repetition_penalty)?;
+	}
+	Ok(())
+}
+
 /// Support functions for other adapters that share OpenAI APIs
 impl OpenAIAdapter {
 	pub(in crate::adapter::adapters) fn util_get_service_url(
@@ -39,31 +99,19 @@ impl OpenAIAdapter {
 		// -- utility arguments
 		default_endpoint: Endpoint,
 	) -> Result<String> {
-		let base_url = default_endpoint.base_url();
-		// Parse into URL and query-params
-		let base_url = reqwest::Url::parse(base_url)
-			.map_err(|err| Error::Internal(format!("Cannot parse url: {base_url}. Cause:\n{err}")))?;
-		let original_query_params = base_url.query().to_owned();
-
-		let suffix = match service_type {
+		let suffix = default_endpoint.service_path(service_type).unwrap_or(match service_type {
 			ServiceType::Chat | ServiceType::ChatStream => "chat/completions",
 			ServiceType::Embed => "embeddings",
 			ServiceType::Models => "models",
-		};
-		let mut full_url = base_url.join(suffix).map_err(|err| {
-			Error::Internal(format!(
-				"Cannot join suffix '{suffix}' for url: {base_url}. Cause:\n{err}"
-			))
-		})?;
-		full_url.set_query(original_query_params);
-		Ok(full_url.to_string())
+		});
+		join_service_url(default_endpoint.base_url(), suffix)
 	}
 
 	/// Shared OpenAI to_web_request_data for various OpenAI compatible adapters
 	pub(in crate::adapter::adapters) fn util_to_web_request_data(
 		target: ServiceTarget,
 		service_type: ServiceType,
-		chat_req: ChatRequest,
+		mut chat_req: ChatRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 		custom: Option<ToWebRequestCustom>,
 	) -> Result<WebRequestData> {
@@ -72,7 +120,13 @@ impl OpenAIAdapter {
 		let adapter_kind = model.adapter_kind;
 
 		// -- url
-		let url = AdapterDispatcher::get_service_url(&model, service_type, endpoint)?;
+		// Note: The raw prompt requests (see `ChatRequest::with_raw_prompt`) go to the `completions` endpoint.
+		let raw_prompt = chat_req.raw_prompt.take();
+		let url = if raw_prompt.is_some() {
+			join_service_url(endpoint.base_url(), "completions")?
+		} else {
+			AdapterDispatcher::get_service_url(&model, service_type, endpoint)?
+		};
 
 		// -- api_key / headers
 		let api_key = get_api_key(auth, &model)?;
@@ -97,12 +151,20 @@ impl OpenAIAdapter {
 
 		// -- Build the basic payload
 
-		let OpenAIRequestParts { messages, tools } = Self::into_openai_request_parts(&model, chat_req)?;
+		validate_openai_tool_names(&model, chat_req.tools.as_deref())?;
+		let OpenAIRequestParts { mut messages, tools } = Self::into_openai_request_parts(&model, chat_req)?;
+		if options_set.normalize_system_role().unwrap_or(true) && Self::util_is_o_series(&model) {
+			Self::util_system_to_developer_role(&mut messages);
+		}
 		let mut payload = json!({
 			"model": model_name,
-			"messages": messages,
 			"stream": stream
 		});
+		// Moved in (`json!` would deep clone the messages)
+		match raw_prompt {
+			Some(raw_prompt) => payload["prompt"] = Value::String(raw_prompt),
+			None => payload["messages"] = Value::Array(messages),
+		}
 
 		// -- Set reasoning effort
 		if let Some(reasoning_effort) = reasoning_effort {
@@ -116,9 +178,15 @@ impl OpenAIAdapter {
 			payload.x_insert("verbosity", keyword)?;
 		}
 
-		// -- Tools
+		// -- Tools (the tool choice and parallel tool calls are only sent with tools)
 		if let Some(tools) = tools {
-			payload.x_insert("/tools", tools)?;
+			payload["tools"] = Value::Array(tools);
+			if let Some(tool_choice) = options_set.tool_choice() {
+				payload.x_insert("tool_choice", tool_choice_to_openai_json(tool_choice))?;
+			}
+			if let Some(parallel_tool_calls) = options_set.parallel_tool_calls() {
+				payload.x_insert("parallel_tool_calls", parallel_tool_calls)?;
+			}
 		}
 
 		// -- Add options
@@ -159,16 +227,7 @@ impl OpenAIAdapter {
 			payload.x_insert("stop", options_set.stop_sequences())?;
 		}
 
-		// GPT-5.x and o-series models require "max_completion_tokens" instead of "max_tokens"
-		let max_tokens_key = if model_name.starts_with("gpt-5")
-			|| model_name.starts_with("o1")
-			|| model_name.starts_with("o3")
-			|| model_name.starts_with("o4")
-		{
-			"max_completion_tokens"
-		} else {
-			"max_tokens"
-		};
+		let max_tokens_key = max_tokens_key(model_name);
 		if let Some(max_tokens) = options_set.max_tokens() {
 			payload.x_insert(max_tokens_key, max_tokens)?;
 		} else if let Some(custom) = custom.as_ref()
@@ -182,16 +241,35 @@ impl OpenAIAdapter {
 		if let Some(seed) = options_set.seed() {
 			payload.x_insert("seed", seed)?;
 		}
+		if let Some(n) = options_set.n() {
+			payload.x_insert("n", n)?;
+		}
 		if let Some(service_tier) = options_set.service_tier()
 			&& let Some(keyword) = service_tier.as_keyword()
 		{
 			payload.x_insert("service_tier", keyword)?;
 		}
 
+		// -- Sampling extras, top_k, and min_tokens (only for the providers accepting them)
+		if let Some(custom) = custom.as_ref()
+			&& custom.sampling_extras
+		{
+			if let Some(top_k) = options_set.top_k() {
+				payload.x_insert("top_k", top_k)?;
+			}
+			if let Some(min_tokens) = options_set.min_tokens() {
+				payload.x_insert("min_tokens", min_tokens)?;
+			}
+			if let Some(sampling_extras) = options_set.sampling_extras() {
+				insert_openai_sampling_extras(&mut payload, sampling_extras)?;
+			}
+		}
+
 		// -- OpenAI prompt cache options
 		if let Some(prompt_cache_key) = options_set.prompt_cache_key() {
 			payload.x_insert("prompt_cache_key", prompt_cache_key)?;
 		}
+		let mut cache_control_ignored = false;
 		if let Some(cache_control) = options_set.cache_control() {
 			let prompt_cache_retention = match cache_control {
 				CacheControl::Memory | CacheControl::Ephemeral => Some("in_memory"),
@@ -200,10 +278,50 @@ impl OpenAIAdapter {
 			};
 			if let Some(prompt_cache_retention) = prompt_cache_retention {
 				payload.x_insert("prompt_cache_retention", prompt_cache_retention)?;
+			} else {
+				cache_control_ignored = true;
 			}
 		}
 
-		Ok(WebRequestData { url, headers, payload })
+		// -- Ignored options
+		let mut supported = OPENAI_SUPPORTED_OPTIONS.to_vec();
+		if matches!(adapter_kind, AdapterKind::OpenAI) {
+			supported.push("reasoning_effort");
+		}
+		if custom.as_ref().is_some_and(|custom| custom.sampling_extras) {
+			supported.extend(["top_k", "min_tokens", "sampling_extras"]);
+		}
+		let mut ignored_options = ignored_options(&options_set, &supported, adapter_kind);
+		if cache_control_ignored {
+			ignored_options.push(IgnoredOption::new(
+				"cache_control",
+				"only the in-memory and 24h prompt cache retentions are supported by OpenAI",
+			));
+		}
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
+	}
+
+	/// Returns the provider timing of a response body, when reported:
+	/// the `queue_time`, `prompt_time`, `completion_time`, and `total_time` (seconds) of the usage,
+	/// and the `x_groq` metadata (e.g., Groq).
+	pub(super) fn provider_timing(body: &Value) -> Option<Value> {
+		let mut timing = serde_json::Map::new();
+		for name in ["queue_time", "prompt_time", "completion_time", "total_time"] {
+			if let Some(time) = body.pointer(&format!("/usage/{name}")).filter(|v| v.is_number()) {
+				timing.insert(name.to_string(), time.clone());
+			}
+		}
+		if let Some(x_groq) = body.get("x_groq").filter(|v| !v.is_null()) {
+			timing.insert("x_groq".to_string(), x_groq.clone());
+		}
+
+		(!timing.is_empty()).then_some(Value::Object(timing))
 	}
 
 	/// Note: Needs to be called from super::streamer as well
@@ -236,12 +354,15 @@ impl OpenAIAdapter {
 	/// Takes the genai ChatMessages and builds the OpenAIChatRequestParts
 	/// - `genai::ChatRequest.system`, if present, is added as the first message with role 'system'.
 	/// - All messages get added with the corresponding roles (tools are not supported for now)
-	fn into_openai_request_parts(_model_iden: &ModelIden, chat_req: ChatRequest) -> Result<OpenAIRequestParts> {
-		let mut messages: Vec<Value> = Vec::new();
+	///
+	/// NOTE: The owned strings and schemas of the `chat_req` are moved into the JSON values (see `json_object`),
+	///       since `json!` serializes its expressions by reference, which clones them.
+	fn into_openai_request_parts(model_iden: &ModelIden, chat_req: ChatRequest) -> Result<OpenAIRequestParts> {
+		let mut messages: Vec<Value> = Vec::with_capacity(chat_req.messages.len() + 1);
 
 		// -- Process the system
 		if let Some(system_msg) = chat_req.system {
-			messages.push(json!({"role": "system", "content": system_msg}));
+			messages.push(json_object([("role", "system".into()), ("content", system_msg.into())]));
 		}
 
 		// -- Process the messages
@@ -251,66 +372,79 @@ impl OpenAIAdapter {
 				// For now, system and tool messages go to the system
 				ChatRole::System => {
 					if let Some(content) = msg.content.into_joined_texts() {
-						messages.push(json!({"role": "system", "content": content}))
+						messages.push(json_object([("role", "system".into()), ("content", content.into())]))
 					}
 					// TODO: Probably need to warn if it is a ToolCalls type of content
 				}
 
+				ChatRole::Developer => {
+					if let Some(content) = msg.content.into_joined_texts() {
+						messages.push(json_object([
+							("role", Self::util_developer_role(model_iden).into()),
+							("content", content.into()),
+						]))
+					}
+				}
+
 				// User - For now support Text and Binary
 				ChatRole::User => {
 					// -- If we have only text, then, we jjust returned the joined_texts
 					if msg.content.is_text_only() {
 						// NOTE: for now, if no content, just return empty string (respect current logic)
-						let content = json!(msg.content.joined_texts().unwrap_or_else(String::new));
-						messages.push(json! ({"role": "user", "content": content}));
+						let content = msg.content.into_joined_texts().unwrap_or_default();
+						messages.push(json_object([("role", "user".into()), ("content", content.into())]));
 					} else {
 						let mut values: Vec<Value> = Vec::new();
 						for part in msg.content {
 							match part {
-								ContentPart::Text(content) => values.push(json!({"type": "text", "text": content})),
+								ContentPart::Text(content) => {
+									values.push(json_object([("type", "text".into()), ("text", content.into())]))
+								}
 								ContentPart::Binary(binary) => {
 									let is_audio = binary.is_audio();
 									let is_image = binary.is_image();
 
-									// let Binary {
-									// 	content_type, source, ..
-									// } = binary;
-
 									if is_audio {
-										match &binary.source {
+										let Binary {
+											content_type, source, ..
+										} = binary;
+										match source {
 											BinarySource::Url(_url) => {
 												warn!(
 													"OpenAI doesn't support audio from URL, need to handle it gracefully"
 												);
 											}
 											BinarySource::Base64(content) => {
-												let mut format =
-													binary.content_type.split('/').next_back().unwrap_or("");
+												let mut format = content_type.split('/').next_back().unwrap_or("");
 												if format == "mpeg" {
 													format = "mp3";
 												}
-												values.push(json!({
-													"type": "input_audio",
-													"input_audio": {
-														"data": content,
-														"format": format
-													}
-												}));
+												let input_audio = json_object([
+													("data", content.to_string().into()),
+													("format", format.into()),
+												]);
+												values.push(json_object([
+													("type", "input_audio".into()),
+													("input_audio", input_audio),
+												]));
 											}
 										}
 									} else if is_image {
-										let image_url = binary.into_url();
-										values.push(json!({"type": "image_url", "image_url": {"url": image_url}}));
+										let image_url = json_object([("url", binary.into_url().into())]);
+										values.push(json_object([
+											("type", "image_url".into()),
+											("image_url", image_url),
+										]));
 									} else if matches!(&binary.source, BinarySource::Url(_)) {
 										// TODO: Need to return error
 										warn!("OpenAI doesn't support file from URL, need to handle it gracefully");
 									} else {
 										let filename = binary.name.clone();
-										let file_base64_url = binary.into_url();
-										values.push(json!({"type": "file", "file": {
-											"filename": filename,
-											"file_data": file_base64_url
-										}}))
+										let file = json_object([
+											("filename", filename.into()),
+											("file_data", binary.into_url().into()),
+										]);
+										values.push(json_object([("type", "file".into()), ("file", file)]))
 									}
 								}
 
@@ -327,7 +461,7 @@ impl OpenAIAdapter {
 								ContentPart::Custom(_) => {}
 							}
 						}
-						messages.push(json! ({"role": "user", "content": values}));
+						messages.push(json_object([("role", "user".into()), ("content", values.into())]));
 					}
 				}
 
@@ -340,15 +474,15 @@ impl OpenAIAdapter {
 						match part {
 							ContentPart::Text(text) => texts.push(text),
 							ContentPart::ToolCall(tool_call) => {
-								//
-								tool_calls.push(json!({
-									"type": "function",
-									"id": tool_call.call_id,
-									"function": {
-										"name": tool_call.fn_name,
-										"arguments": tool_call.fn_arguments.to_string(),
-									}
-								}))
+								let function = json_object([
+									("name", tool_call.fn_name.into()),
+									("arguments", tool_call.fn_arguments.to_string().into()),
+								]);
+								tool_calls.push(json_object([
+									("type", "function".into()),
+									("id", tool_call.call_id.into()),
+									("function", function),
+								]))
 							}
 							// Extract reasoning content parts to hoist into sibling field
 							ContentPart::ReasoningContent(reasoning) => reasoning_parts.push(reasoning),
@@ -361,16 +495,21 @@ impl OpenAIAdapter {
 							ContentPart::Custom(_) => {}
 						}
 					}
-					let content = texts.join("\n\n");
-					let mut message = json!({"role": "assistant", "content": content});
+					// A single text is moved as is (no join allocation)
+					let content = if texts.len() == 1 {
+						texts.pop().unwrap_or_default()
+					} else {
+						texts.join("\n\n")
+					};
+					let mut message = json_object([("role", "assistant".into()), ("content", content.into())]);
 					if !tool_calls.is_empty() {
-						message.x_insert("tool_calls", tool_calls)?;
+						message["tool_calls"] = tool_calls.into();
 					}
 					// Echo reasoning_content back for providers that require it (Kimi, DeepSeek)
 					// Note: In practice there is at most one ReasoningContent part per message,
 					//       but we join defensively in case multiple parts are present.
 					if !reasoning_parts.is_empty() {
-						message.x_insert("reasoning_content", reasoning_parts.join("\n"))?;
+						message["reasoning_content"] = reasoning_parts.join("\n").into();
 					}
 					messages.push(message);
 				}
@@ -379,11 +518,11 @@ impl OpenAIAdapter {
 				ChatRole::Tool => {
 					for part in msg.content {
 						if let ContentPart::ToolResponse(tool_response) = part {
-							messages.push(json!({
-								"role": "tool",
-								"content": tool_response.content,
-								"tool_call_id": tool_response.call_id,
-							}))
+							messages.push(json_object([
+								("role", "tool".into()),
+								("content", tool_response.content.into()),
+								("tool_call_id", tool_response.call_id.into()),
+							]))
 						}
 					}
 
@@ -414,15 +553,18 @@ impl OpenAIAdapter {
 						});
 					}
 
-					json!({
-						"type": "function",
-						"function": {
-							"name": tool.name,
-							"description": tool.description,
-							"parameters": parameters,
-							"strict": strict,
-						}
-					})
+					// Built-in tool names keep their serde form (e.g., `{"WebSearch": null}`)
+					let name = match tool.name {
+						ToolName::Custom(name) => name.into(),
+						other => json!(other),
+					};
+					let function = json_object([
+						("name", name),
+						("description", tool.description.into()),
+						("parameters", parameters.into()),
+						("strict", strict.into()),
+					]);
+					json_object([("type", "function".into()), ("function", function)])
 				})
 				.collect::<Vec<Value>>()
 		});
@@ -436,8 +578,7 @@ impl OpenAIAdapter {
 		auth: AuthData,
 	) -> Result<Vec<String>> {
 		// -- url
-		let base_url = endpoint.base_url();
-		let url = format!("{base_url}models");
+		let url = AdapterDispatcher::get_service_url(&ModelIden::new(kind, ""), ServiceType::Models, endpoint)?;
 
 		// -- auth / headers
 		let api_key = auth.single_key_value().ok();
@@ -445,34 +586,157 @@ impl OpenAIAdapter {
 			.map(|api_key| Headers::from(("Authorization".to_string(), format!("Bearer {api_key}"))))
 			.unwrap_or_default();
 
-		// -- Exec request
+		// -- Exec request(s)
 		let web_c = crate::webc::WebClient::default();
-		let mut res = web_c.do_get(&url, &headers).await.map_err(|webc_error| Error::WebAdapterCall {
-			adapter_kind: kind,
-			webc_error,
-		})?;
+		let models_data = Self::fetch_all_models_data(kind, &web_c, &url, &headers).await?;
 
 		// -- Format result
 		let mut models: Vec<String> = Vec::new();
+		for mut model in models_data {
+			let model_name: String = model.x_take("id")?;
+			models.push(model_name);
+		}
+
+		Ok(models)
+	}
+
+	/// Fetches all the `data` items of an OpenAI-compatible `/models` listing, following the pagination cursors.
+	///
+	/// See [`next_models_page_url`] for the supported cursor styles.
+	pub(in crate::adapter::adapters) async fn fetch_all_models_data(
+		kind: AdapterKind,
+		web_client: &WebClient,
+		url: &str,
+		headers: &Headers,
+	) -> Result<Vec<Value>> {
+		let mut models_data: Vec<Value> = Vec::new();
+		let mut page_url = url.to_string();
+
+		for _ in 0..MAX_MODELS_PAGES {
+			let mut res = web_client
+				.do_get(&page_url, headers)
+				.await
+				.map_err(|webc_error| Error::WebAdapterCall {
+					adapter_kind: kind,
+					webc_error,
+				})?;
+
+			let next_url = next_models_page_url(url, &res.body)?;
+
+			let page_data = match res.body.x_take("data")? {
+				Value::Array(page_data) => page_data,
+				_ => Vec::new(),
+			};
+			let page_is_empty = page_data.is_empty();
+			models_data.extend(page_data);
 
-		if let Value::Array(models_value) = res.body.x_take("data")? {
-			for mut model in models_value {
-				let model_name: String = model.x_take("id")?;
-				models.push(model_name);
+			match next_url {
+				// Guard against a provider returning the same cursor, or more pages without data
+				Some(next_url) if next_url != page_url && !page_is_empty => page_url = next_url,
+				_ => break,
 			}
+		}
+
+		Ok(models_data)
+	}
+}
+
+/// Safety limit for the number of `/models` pages fetched for one listing.
+const MAX_MODELS_PAGES: usize = 50;
+
+/// Returns the url of the next `/models` page (if any) from a page response body.
+///
+/// Supported cursor styles:
+/// - `"next": "<url or path>"` (resolved against the `/models` url when relative)
+/// - `"has_more": true` with `"last_id"` (or the last `data[].id`), sent as the `after` query param
+fn next_models_page_url(models_url: &str, body: &Value) -> Result<Option<String>> {
+	let parse_url = |url: &str| {
+		reqwest::Url::parse(url).map_err(|err| Error::Internal(format!("Cannot parse url: {url}. Cause:\n{err}")))
+	};
+
+	// -- `next` link
+	if let Some(next) = body.get("next").and_then(Value::as_str).filter(|next| !next.is_empty()) {
+		let next_url = parse_url(models_url)?
+			.join(next)
+			.map_err(|err| Error::Internal(format!("Cannot join next page '{next}' to {models_url}. Cause:\n{err}")))?;
+		return Ok(Some(next_url.to_string()));
+	}
+
+	// -- `has_more` cursor
+	if body.get("has_more").and_then(Value::as_bool) != Some(true) {
+		return Ok(None);
+	}
+	let last_id = body.get("last_id").and_then(Value::as_str).or_else(|| {
+		body.get("data")
+			.and_then(Value::as_array)
+			.and_then(|data| data.last())
+			.and_then(|model| model.get("id"))
+			.and_then(Value::as_str)
+	});
+	let Some(last_id) = last_id else {
+		return Ok(None);
+	};
+
+	let mut next_url = parse_url(models_url)?;
+	let query_pairs: Vec<(String, String)> = next_url
+		.query_pairs()
+		.filter(|(name, _)| name != "after")
+		.map(|(name, value)| (name.into_owned(), value.into_owned()))
+		.collect();
+	next_url
+		.query_pairs_mut()
+		.clear()
+		.extend_pairs(query_pairs)
+		.append_pair("after", last_id);
+
+	Ok(Some(next_url.to_string()))
+}
+
+impl OpenAIAdapter {
+	/// The role name for a `ChatRole::Developer` message.
+	/// - `developer` for the OpenAI models expecting it (o-series reasoning models and gpt-5 family)
+	/// - `system` otherwise (older models, OpenAI compatible providers)
+	pub(in crate::adapter::adapters) fn util_developer_role(model_iden: &ModelIden) -> &'static str {
+		let (_, name) = model_iden.model_name.namespace_and_name();
+		let is_gpt_5 = Self::is_openai_kind(model_iden) && (name.starts_with("gpt-5") || name.starts_with("codex"));
+
+		if Self::util_is_o_series(model_iden) || is_gpt_5 {
+			"developer"
 		} else {
-			// TODO: Need to add tracing
-			// error!("OllamaAdapter::list_models did not have any models {res:?}");
+			"system"
 		}
+	}
 
-		Ok(models)
+	/// Whether the model is an OpenAI o-series reasoning model (o1, o3, o4) on the OpenAI adapters.
+	/// NOTE: o1-mini and o1-preview predate the developer role, so they are not included.
+	pub(in crate::adapter::adapters) fn util_is_o_series(model_iden: &ModelIden) -> bool {
+		let (_, name) = model_iden.model_name.namespace_and_name();
+		Self::is_openai_kind(model_iden)
+			&& ((name.starts_with("o1") && !name.starts_with("o1-mini") && !name.starts_with("o1-preview"))
+				|| name.starts_with("o3")
+				|| name.starts_with("o4"))
+	}
+
+	/// Switches the `system` role messages (or input items) to the `developer` role.
+	pub(in crate::adapter::adapters) fn util_system_to_developer_role(messages: &mut [Value]) {
+		for message in messages.iter_mut().filter(|message| message["role"] == "system") {
+			message["role"] = json!("developer");
+		}
+	}
+
+	fn is_openai_kind(model_iden: &ModelIden) -> bool {
+		matches!(model_iden.adapter_kind, AdapterKind::OpenAI | AdapterKind::OpenAIResp)
 	}
 }
 
 /// Custom OpenAI structure for Adapters to use to customize
 /// the default [`OpenAIAdapter::util_to_web_request_data`]
+#[derive(Default)]
 pub struct ToWebRequestCustom {
 	pub default_max_tokens: Option<u32>,
+	/// When true, the `ChatOptions::sampling_extras`, `top_k`, and `min_tokens` are sent
+	/// (for the vLLM-based providers accepting them).
+	pub sampling_extras: bool,
 }
 
 // region:    --- Support
@@ -482,6 +746,11 @@ struct OpenAIRequestParts {
 	tools: Option<Vec<Value>>,
 }
 
+/// Builds a JSON object by moving the values in (unlike `json!`, which serializes its expressions by reference).
+fn json_object<const N: usize>(entries: [(&str, Value); N]) -> Value {
+	Value::Object(entries.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+}
+
 // endregion: --- Support
 
 // region:    --- Tests
@@ -490,12 +759,32 @@ struct OpenAIRequestParts {
 mod tests {
 	use super::*;
 	use crate::adapter::AdapterKind;
-	use crate::chat::{ChatMessage, ContentPart, MessageContent, ToolCall};
+	use crate::chat::{
+		ChatMessage, ChatOptions, ContentPart, MessageContent, MessageMetadata, SystemHandling, Tool, ToolCall,
+		ToolResponse,
+	};
 
 	fn test_model() -> ModelIden {
 		ModelIden::new(AdapterKind::OpenAI, "test-model")
 	}
 
+	/// Builds the chat payload of a "Hello" user request for the adapter kind default endpoint.
+	fn build_payload(adapter_kind: AdapterKind, model_name: &str, chat_options: &ChatOptions) -> Result<Value> {
+		let target = ServiceTarget {
+			endpoint: AdapterDispatcher::default_endpoint(adapter_kind),
+			auth: AuthData::from_single("test-api-key"),
+			model: ModelIden::new(adapter_kind, model_name),
+		};
+		let options_set = ChatOptionsSet::default().with_chat_options(Some(chat_options));
+		let web_req = AdapterDispatcher::to_web_request_data(
+			target,
+			ServiceType::Chat,
+			ChatRequest::from_user("Hello"),
+			options_set,
+		)?;
+		Ok(web_req.payload)
+	}
+
 	/// When an assistant message carries reasoning_content, it must appear
 	/// in the serialized JSON so providers that require it (Kimi, DeepSeek)
 	/// don't reject the request.
@@ -527,6 +816,328 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_message_metadata_round_trips_but_not_sent() -> Result<()> {
+		let metadata = MessageMetadata::default()
+			.with_id("msg-8f2c")
+			.with_timestamp_ms(1_700_000_000_123);
+		let chat_req = ChatRequest::new(vec![
+			ChatMessage::user("Hello").with_metadata(metadata.clone()),
+			ChatMessage::assistant("Hi!"),
+		]);
+
+		// -- Kept by the serde serialization (for persistence)
+		let persisted = serde_json::to_string(&chat_req)?;
+		let restored: ChatRequest = serde_json::from_str(&persisted)?;
+		assert_eq!(restored.messages[0].metadata.as_ref(), Some(&metadata));
+		assert!(restored.messages[1].metadata.is_none());
+
+		// -- Not in the API payload
+		let parts = OpenAIAdapter::into_openai_request_parts(&test_model(), restored)?;
+		let payload = serde_json::to_string(&parts.messages)?;
+		assert!(!payload.contains("metadata"), "{payload}");
+		assert!(!payload.contains("msg-8f2c"), "{payload}");
+		assert!(!payload.contains("1700000000123"), "{payload}");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_merge_adjacent_texts_option_in_payload() -> Result<()> {
+		let client = crate::Client::default();
+		let target = ServiceTarget {
+			endpoint: Endpoint::from_static("https://api.openai.com/v1/"),
+			auth: AuthData::from_single("test-key"),
+			model: test_model(),
+		};
+		let chat_req = ChatRequest::new(vec![ChatMessage::user(MessageContent::from_parts(vec![
+			ContentPart::Text("One".to_string()),
+			ContentPart::Text("Two".to_string()),
+			ContentPart::Text("Three".to_string()),
+			ContentPart::from_binary_url("image/png", "https://example.com/duck.png", None),
+		]))]);
+		let user_content = |options: ChatOptions| -> Result<Value> {
+			let payload =
+				client.build_chat_payload(target.clone(), ServiceType::Chat, chat_req.clone(), Some(&options))?;
+			Ok(payload["messages"][0]["content"].clone())
+		};
+
+		// -- Default, the parts are sent as is
+		let content = user_content(ChatOptions::default())?;
+		assert_eq!(content.as_array().map(Vec::len), Some(4));
+
+		// -- Merged, the three texts become one text part
+		let content = user_content(ChatOptions::default().with_merge_adjacent_texts(true))?;
+		assert_eq!(content.as_array().map(Vec::len), Some(2));
+		assert_eq!(content[0]["text"], "One\n\nTwo\n\nThree");
+		assert_eq!(content[1]["type"], "image_url");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_developer_role_mapping_per_model_family() -> Result<()> {
+		let cases = [
+			(AdapterKind::OpenAI, "o1", "developer"),
+			(AdapterKind::OpenAI, "o3-mini", "developer"),
+			(AdapterKind::OpenAI, "o4-mini", "developer"),
+			(AdapterKind::OpenAIResp, "gpt-5-codex", "developer"),
+			(AdapterKind::OpenAI, "openai::o3", "developer"),
+			(AdapterKind::OpenAI, "o1-mini", "system"),
+			(AdapterKind::OpenAI, "o1-preview", "system"),
+			(AdapterKind::OpenAI, "gpt-4o-mini", "system"),
+			// OpenAI compatible providers do not get the developer role
+			(AdapterKind::DeepSeek, "deepseek-reasoner", "system"),
+			(AdapterKind::Together, "o3-lookalike", "system"),
+		];
+
+		for (kind, model_name, expected_role) in cases {
+			let model_iden = ModelIden::new(kind, model_name);
+			let chat_req = ChatRequest::new(vec![
+				ChatMessage::developer("Answer in French."),
+				ChatMessage::user("Hello"),
+			]);
+
+			let parts = OpenAIAdapter::into_openai_request_parts(&model_iden, chat_req)?;
+
+			assert_eq!(parts.messages[0]["role"], expected_role, "model: {kind} {model_name}");
+			assert_eq!(parts.messages[0]["content"], "Answer in French.");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_system_handling_modes_openai_messages() -> Result<()> {
+		let chat_req = ChatRequest::from_system("Be concise.")
+			.append_message(ChatMessage::user("Hi"))
+			.append_message(ChatMessage::assistant("Hello!"))
+			.append_message(ChatMessage::system("Answer in French."))
+			.append_message(ChatMessage::user("Bye"));
+		let cases = [
+			(
+				SystemHandling::Native,
+				json!([
+					{"role": "system", "content": "Be concise."},
+					{"role": "user", "content": "Hi"},
+					{"role": "assistant", "content": "Hello!"},
+					{"role": "system", "content": "Answer in French."},
+					{"role": "user", "content": "Bye"}
+				]),
+			),
+			(
+				SystemHandling::PrependToFirstUser,
+				json!([
+					{"role": "user", "content": "Be concise.\n\nHi"},
+					{"role": "assistant", "content": "Hello!"},
+					{"role": "user", "content": "Answer in French."},
+					{"role": "user", "content": "Bye"}
+				]),
+			),
+			(
+				SystemHandling::AsUserMessage,
+				json!([
+					{"role": "user", "content": "Be concise."},
+					{"role": "user", "content": "Hi"},
+					{"role": "assistant", "content": "Hello!"},
+					{"role": "user", "content": "Answer in French."},
+					{"role": "user", "content": "Bye"}
+				]),
+			),
+		];
+
+		for (handling, expected) in cases {
+			let mut chat_req = chat_req.clone();
+			chat_req.apply_system_handling(handling);
+
+			let parts = OpenAIAdapter::into_openai_request_parts(&test_model(), chat_req)?;
+
+			assert_eq!(Value::Array(parts.messages), expected, "handling: {handling:?}");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_system_handling_option_in_payload() -> Result<()> {
+		let client = crate::Client::default();
+		let target = ServiceTarget {
+			endpoint: Endpoint::from_static("https://api.openai.com/v1/"),
+			auth: AuthData::from_single("test-key"),
+			model: test_model(),
+		};
+		let chat_req = ChatRequest::from_system("Be concise.").append_message(ChatMessage::user("Hi"));
+		let options = ChatOptions::default().with_system_handling(SystemHandling::PrependToFirstUser);
+
+		let payload = client.build_chat_payload(target, ServiceType::Chat, chat_req, Some(&options))?;
+
+		assert_eq!(
+			payload["messages"],
+			json!([{"role": "user", "content": "Be concise.\n\nHi"}])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_choice_and_parallel_tool_calls_in_payload() -> Result<()> {
+		let client = crate::Client::default();
+		let target = ServiceTarget {
+			endpoint: Endpoint::from_static("https://api.openai.com/v1/"),
+			auth: AuthData::from_single("test-key"),
+			model: test_model(),
+		};
+		let chat_req = ChatRequest::from_user("What is the weather in Paris?");
+		let options = ChatOptions::default()
+			.with_tool_choice(ToolChoice::tool("get_weather"))
+			.with_parallel_tool_calls(false);
+
+		// -- Without tools, not sent
+		let payload = client.build_chat_payload(target.clone(), ServiceType::Chat, chat_req.clone(), Some(&options))?;
+		assert!(payload.get("tool_choice").is_none());
+		assert!(payload.get("parallel_tool_calls").is_none());
+
+		// -- With tools
+		let chat_req = chat_req.with_tools(vec![Tool::new("get_weather")]);
+		let payload = client.build_chat_payload(target.clone(), ServiceType::Chat, chat_req.clone(), Some(&options))?;
+		assert_eq!(
+			payload["tool_choice"],
+			json!({"type": "function", "function": {"name": "get_weather"}})
+		);
+		assert_eq!(payload["parallel_tool_calls"], json!(false));
+
+		for (tool_choice, expected) in [
+			(ToolChoice::Auto, "auto"),
+			(ToolChoice::Required, "required"),
+			(ToolChoice::None, "none"),
+		] {
+			let options = ChatOptions::default().with_tool_choice(tool_choice);
+			let payload =
+				client.build_chat_payload(target.clone(), ServiceType::Chat, chat_req.clone(), Some(&options))?;
+			assert_eq!(payload["tool_choice"], json!(expected));
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_system_handling_prepend_leading_system_messages() -> Result<()> {
+		let mut chat_req = ChatRequest::from_system("Be concise.")
+			.append_message(ChatMessage::system("Use metric units."))
+			.append_message(ChatMessage::user("Hi"))
+			.append_message(ChatMessage::system("Answer in French."));
+		chat_req.apply_system_handling(SystemHandling::PrependToFirstUser);
+
+		let parts = OpenAIAdapter::into_openai_request_parts(&test_model(), chat_req)?;
+
+		assert_eq!(
+			Value::Array(parts.messages),
+			json!([
+				{"role": "user", "content": "Be concise.\n\nUse metric units.\n\nHi"},
+				{"role": "user", "content": "Answer in French."}
+			])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_system_handling_prepend_without_user_message() -> Result<()> {
+		let mut chat_req = ChatRequest::from_system("Be concise.");
+		chat_req.apply_system_handling(SystemHandling::PrependToFirstUser);
+
+		let parts = OpenAIAdapter::into_openai_request_parts(&test_model(), chat_req)?;
+
+		assert_eq!(
+			Value::Array(parts.messages),
+			json!([{"role": "user", "content": "Be concise."}])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_util_get_service_url_all_service_types() -> Result<()> {
+		let bases = [
+			"https://api.openai.com/v1/",
+			"https://api.openai.com/v1",
+			"https://proxy.corp/llm/openai/v1",
+			"https://proxy.corp/llm/openai/v1/?api-version=2024-10-21",
+		];
+		let service_types = [
+			(ServiceType::Chat, "chat/completions"),
+			(ServiceType::ChatStream, "chat/completions"),
+			(ServiceType::Embed, "embeddings"),
+			(ServiceType::Models, "models"),
+		];
+
+		for base in bases {
+			let (base_path, query) = match base.split_once('?') {
+				Some((base_path, query)) => (base_path, Some(query)),
+				None => (base, None),
+			};
+			let base_path = base_path.trim_end_matches('/');
+
+			for (service_type, suffix) in service_types {
+				let url =
+					OpenAIAdapter::util_get_service_url(&test_model(), service_type, Endpoint::from_static(base))?;
+				let expected = match query {
+					Some(query) => format!("{base_path}/{suffix}?{query}"),
+					None => format!("{base_path}/{suffix}"),
+				};
+				assert_eq!(url, expected, "base: {base}, service_type: {service_type:?}");
+			}
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_util_get_service_url_custom_service_path() -> Result<()> {
+		let endpoint =
+			Endpoint::from_static("https://proxy.corp/llm/v1/").with_service_path(ServiceType::Chat, "/v2/chat");
+
+		let chat_url = OpenAIAdapter::util_get_service_url(&test_model(), ServiceType::Chat, endpoint.clone())?;
+		let stream_url = OpenAIAdapter::util_get_service_url(&test_model(), ServiceType::ChatStream, endpoint.clone())?;
+		let models_url = OpenAIAdapter::util_get_service_url(&test_model(), ServiceType::Models, endpoint)?;
+
+		assert_eq!(chat_url, "https://proxy.corp/llm/v1/v2/chat");
+		assert_eq!(stream_url, "https://proxy.corp/llm/v1/v2/chat");
+		assert_eq!(models_url, "https://proxy.corp/llm/v1/models");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_next_models_page_url_cursor_styles() -> Result<()> {
+		let models_url = "https://proxy.corp/v1/models?api-version=1";
+
+		// -- has_more + last_id
+		let body = json!({"data": [{"id": "a"}, {"id": "b"}], "has_more": true, "last_id": "b"});
+		let next = next_models_page_url(models_url, &body)?;
+		assert_eq!(
+			next.as_deref(),
+			Some("https://proxy.corp/v1/models?api-version=1&after=b")
+		);
+
+		// -- has_more without last_id (last data id), replacing a previous `after`
+		let body = json!({"data": [{"id": "c"}, {"id": "d"}], "has_more": true});
+		let next = next_models_page_url("https://proxy.corp/v1/models?after=b", &body)?;
+		assert_eq!(next.as_deref(), Some("https://proxy.corp/v1/models?after=d"));
+
+		// -- relative next link
+		let body = json!({"data": [{"id": "a"}], "next": "/v1/models?page=2"});
+		let next = next_models_page_url(models_url, &body)?;
+		assert_eq!(next.as_deref(), Some("https://proxy.corp/v1/models?page=2"));
+
+		// -- last page
+		let body = json!({"data": [{"id": "a"}], "has_more": false, "next": null});
+		assert_eq!(next_models_page_url(models_url, &body)?, None);
+		assert_eq!(next_models_page_url(models_url, &json!({"data": []}))?, None);
+
+		Ok(())
+	}
+
 	/// When reasoning_content is None, the field should not appear in the JSON.
 	#[test]
 	fn test_no_reasoning_content_when_absent() {
@@ -541,6 +1152,474 @@ mod tests {
 			"reasoning_content should be absent when not set"
 		);
 	}
+
+	/// The parts are built by moving the request values in, and must be the same JSON as the `json!` form.
+	#[test]
+	fn test_into_openai_request_parts_output_equivalence() -> Result<()> {
+		let tool_call = ToolCall {
+			call_id: "call_1".to_string(),
+			fn_name: "get_weather".to_string(),
+			fn_arguments: json!({"city": "Paris"}),
+			thought_signatures: None,
+		};
+		let chat_req = ChatRequest::from_system("Be brief.")
+			.append_message(ChatMessage::system("Answer in French."))
+			.append_message(ChatMessage::user("What is in the picture?"))
+			.append_message(ChatMessage::user(vec![
+				ContentPart::from_text("Look:"),
+				ContentPart::from_binary_url("image/png", "https://example.com/a.png", None),
+				ContentPart::from_binary_base64("audio/mpeg", "QUJD", None),
+				ContentPart::from_binary_base64("application/pdf", "UERG", Some("doc.pdf".to_string())),
+			]))
+			.append_message(
+				ChatMessage::assistant(MessageContent::from_parts(vec![
+					ContentPart::Text("Let me check.".to_string()),
+					ContentPart::ToolCall(tool_call),
+				]))
+				.with_reasoning_content(Some("Need the weather.".to_string())),
+			)
+			.append_message(ChatMessage::from(ToolResponse::new("call_1", r#"{"temp": 21}"#)))
+			.append_tool(
+				Tool::new("get_weather")
+					.with_description("Get the weather")
+					.with_schema(json!({"type": "object", "properties": {"city": {"type": "string"}}})),
+			);
+
+		let parts = OpenAIAdapter::into_openai_request_parts(&test_model(), chat_req)?;
+
+		let expected_messages = json!([
+			{"role": "system", "content": "Be brief."},
+			{"role": "system", "content": "Answer in French."},
+			{"role": "user", "content": "What is in the picture?"},
+			{"role": "user", "content": [
+				{"type": "text", "text": "Look:"},
+				{"type": "image_url", "image_url": {"url": "https://example.com/a.png"}},
+				{"type": "input_audio", "input_audio": {"data": "QUJD", "format": "mp3"}},
+				{"type": "file", "file": {"filename": "doc.pdf", "file_data": "data:application/pdf;base64,UERG"}},
+			]},
+			{
+				"role": "assistant",
+				"content": "Let me check.",
+				"tool_calls": [{
+					"type": "function",
+					"id": "call_1",
+					"function": {"name": "get_weather", "arguments": r#"{"city":"Paris"}"#}
+				}],
+				"reasoning_content": "Need the weather."
+			},
+			{"role": "tool", "content": r#"{"temp": 21}"#, "tool_call_id": "call_1"},
+		]);
+		let expected_tools = json!([{
+			"type": "function",
+			"function": {
+				"name": "get_weather",
+				"description": "Get the weather",
+				"parameters": {"type": "object", "properties": {"city": {"type": "string"}}},
+				"strict": false,
+			}
+		}]);
+
+		assert_eq!(Value::Array(parts.messages), expected_messages);
+		assert_eq!(parts.tools.map(Value::Array), Some(expected_tools));
+
+		Ok(())
+	}
+
+	/// The full payload of a mixed conversation (text, images, audio, file, tool call) must be the same
+	/// as the one of the `json!` form (recorded before the values were moved in).
+	#[test]
+	fn test_to_web_request_data_payload_unchanged_by_move_in() -> Result<()> {
+		let tool_call = ToolCall {
+			call_id: "call_1".to_string(),
+			fn_name: "get_weather".to_string(),
+			fn_arguments: json!({"city": "Paris"}),
+			thought_signatures: None,
+		};
+		let chat_req = ChatRequest::from_system("Be brief.")
+			.append_message(ChatMessage::user("What is in the picture?"))
+			.append_message(ChatMessage::user(vec![
+				ContentPart::from_text("Look:"),
+				ContentPart::from_binary_url("image/png", "https://example.com/a.png", None),
+				ContentPart::from_binary_base64("image/jpeg", "SU1H", None),
+				ContentPart::from_binary_base64("audio/wav", "V0FW", None),
+				ContentPart::from_binary_base64("application/pdf", "UERG", Some("doc.pdf".to_string())),
+			]))
+			.append_message(ChatMessage::assistant(MessageContent::from_parts(vec![
+				ContentPart::Text("Let me check.".to_string()),
+				ContentPart::ToolCall(tool_call),
+			])))
+			.append_message(ChatMessage::from(ToolResponse::new("call_1", r#"{"temp": 21}"#)))
+			.append_message(ChatMessage::assistant("It is 21°C in Paris."))
+			.append_tool(
+				Tool::new("get_weather").with_description("Get the weather").with_schema(json!({
+					"type": "object",
+					"properties": {"city": {"type": "string"}},
+					"required": ["city"]
+				})),
+			);
+		let chat_options = ChatOptions::default().with_temperature(0.2).with_max_tokens(100);
+		let target = ServiceTarget {
+			endpoint: AdapterDispatcher::default_endpoint(AdapterKind::OpenAI),
+			auth: AuthData::from_single("test-api-key"),
+			model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+		};
+
+		let web_req = AdapterDispatcher::to_web_request_data(
+			target,
+			ServiceType::ChatStream,
+			chat_req,
+			ChatOptionsSet::default().with_chat_options(Some(&chat_options)),
+		)?;
+
+		let expected_payload = json!({
+			"model": "gpt-4o-mini",
+			"stream": true,
+			"temperature": 0.2,
+			"max_tokens": 100,
+			"messages": [
+				{"role": "system", "content": "Be brief."},
+				{"role": "user", "content": "What is in the picture?"},
+				{"role": "user", "content": [
+					{"type": "text", "text": "Look:"},
+					{"type": "image_url", "image_url": {"url": "https://example.com/a.png"}},
+					{"type": "image_url", "image_url": {"url": "data:image/jpeg;base64,SU1H"}},
+					{"type": "input_audio", "input_audio": {"data": "V0FW", "format": "wav"}},
+					{"type": "file", "file": {"filename": "doc.pdf", "file_data": "data:application/pdf;base64,UERG"}},
+				]},
+				{
+					"role": "assistant",
+					"content": "Let me check.",
+					"tool_calls": [{
+						"type": "function",
+						"id": "call_1",
+						"function": {"name": "get_weather", "arguments": r#"{"city":"Paris"}"#}
+					}]
+				},
+				{"role": "tool", "content": r#"{"temp": 21}"#, "tool_call_id": "call_1"},
+				{"role": "assistant", "content": "It is 21°C in Paris."},
+			],
+			"tools": [{
+				"type": "function",
+				"function": {
+					"name": "get_weather",
+					"description": "Get the weather",
+					"parameters": {
+						"type": "object",
+						"properties": {"city": {"type": "string"}},
+						"required": ["city"]
+					},
+					"strict": false,
+				}
+			}],
+		});
+		assert_eq!(web_req.payload, expected_payload);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_web_request_data_sampling_extras_per_provider() -> Result<()> {
+		let extras = crate::chat::SamplingExtras::default()
+			.with_min_p(0.05)
+			.with_repetition_penalty(1.1)
+			.with_mirostat(2);
+		let chat_options = ChatOptions::default().with_top_k(40).with_sampling_extras(extras);
+
+		// -- Ollama (native options, with mirostat)
+		for adapter_kind in [AdapterKind::Ollama, AdapterKind::OllamaCloud] {
+			let payload = build_payload(adapter_kind, "gemma3:4b", &chat_options)?;
+			assert_eq!(
+				payload["options"],
+				json!({"top_k": 40, "min_p": 0.05, "repeat_penalty": 1.1, "mirostat": 2}),
+				"adapter: {adapter_kind}"
+			);
+		}
+
+		// -- Nebius (vLLM, at the payload root, without mirostat)
+		let payload = build_payload(AdapterKind::Nebius, "Qwen/Qwen3-235B-A22B", &chat_options)?;
+		assert_eq!(payload["top_k"], 40);
+		assert_eq!(payload["min_p"], 0.05);
+		assert_eq!(payload["repetition_penalty"], 1.1);
+		assert!(payload.get("mirostat").is_none());
+
+		// -- OpenAI (strict, omitted)
+		let payload = build_payload(AdapterKind::OpenAI, "gpt-4o-mini", &chat_options)?;
+		for key in ["top_k", "min_p", "repetition_penalty", "mirostat", "options"] {
+			assert!(payload.get(key).is_none(), "OpenAI payload should not have '{key}'");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_web_request_data_top_k_per_provider() -> Result<()> {
+		let chat_options = ChatOptions::default().with_top_k(20);
+
+		let cases: &[(AdapterKind, &str, &str)] = &[
+			(AdapterKind::Anthropic, "claude-sonnet-4-5", "/top_k"),
+			(AdapterKind::Gemini, "gemini-2.5-flash", "/generationConfig/topK"),
+			(AdapterKind::Cohere, "command-r7b-12-2024", "/k"),
+			(AdapterKind::Ollama, "gemma3:4b", "/options/top_k"),
+			(AdapterKind::OllamaCloud, "gpt-oss:20b", "/options/top_k"),
+			(AdapterKind::Nebius, "Qwen/Qwen3-235B-A22B", "/top_k"),
+		];
+		for (adapter_kind, model_name, pointer) in cases {
+			let payload = build_payload(*adapter_kind, model_name, &chat_options)?;
+			assert_eq!(payload.pointer(pointer), Some(&json!(20)), "adapter: {adapter_kind}");
+		}
+
+		// -- OpenAI (not supported, omitted)
+		let payload = build_payload(AdapterKind::OpenAI, "gpt-4o-mini", &chat_options)?;
+		assert!(payload.get("top_k").is_none());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_web_request_data_min_tokens_per_provider() -> Result<()> {
+		let chat_options = ChatOptions::default().with_min_tokens(16);
+
+		// -- Nebius (vLLM)
+		let payload = build_payload(AdapterKind::Nebius, "Qwen/Qwen3-235B-A22B", &chat_options)?;
+		assert_eq!(payload["min_tokens"], 16);
+
+		// -- Not supported, omitted
+		let cases: &[(AdapterKind, &str)] = &[
+			(AdapterKind::OpenAI, "gpt-4o-mini"),
+			(AdapterKind::Groq, "llama-3.1-8b-instant"),
+			(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+			(AdapterKind::Gemini, "gemini-2.5-flash"),
+			(AdapterKind::Ollama, "gemma3:4b"),
+		];
+		for (adapter_kind, model_name) in cases {
+			let payload = build_payload(*adapter_kind, model_name, &chat_options)?;
+			assert!(
+				!payload.to_string().contains("min_tokens"),
+				"adapter {adapter_kind} should not send min_tokens"
+			);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_util_to_web_request_data_invalid_tool_name() {
+		let cases = [
+			(AdapterKind::OpenAI, "gpt-4o-mini"),
+			(AdapterKind::Groq, "llama-3.1-8b-instant"),
+			(AdapterKind::DeepSeek, "deepseek-chat"),
+		];
+		let long_name = "a".repeat(65);
+		let tool_names = ["get weather", "get.weather", "", &long_name];
+
+		for (adapter_kind, model_name) in cases {
+			for tool_name in tool_names {
+				let target = ServiceTarget {
+					endpoint: AdapterDispatcher::default_endpoint(adapter_kind),
+					auth: AuthData::from_single("test-api-key"),
+					model: ModelIden::new(adapter_kind, model_name),
+				};
+				let chat_req = ChatRequest::from_user("Hello").append_tool(crate::chat::Tool::new(tool_name));
+
+				let res = AdapterDispatcher::to_web_request_data(
+					target,
+					ServiceType::Chat,
+					chat_req,
+					ChatOptionsSet::default(),
+				);
+
+				assert!(
+					matches!(res, Err(Error::InvalidToolName { tool_name: ref name, .. }) if name.as_str() == tool_name),
+					"adapter: {adapter_kind}, tool_name: '{tool_name}'"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_util_to_web_request_data_duplicate_tool_name() {
+		let target = ServiceTarget {
+			endpoint: AdapterDispatcher::default_endpoint(AdapterKind::OpenAI),
+			auth: AuthData::from_single("test-api-key"),
+			model: test_model(),
+		};
+		let chat_req = ChatRequest::from_user("Hello").with_tools(vec![
+			crate::chat::Tool::new("get_weather"),
+			crate::chat::Tool::new("get_weather"),
+		]);
+
+		let res =
+			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, ChatOptionsSet::default());
+
+		assert!(matches!(
+			res,
+			Err(Error::InvalidToolName {
+				cause: "duplicate tool name",
+				..
+			})
+		));
+	}
+
+	#[test]
+	fn test_util_to_web_request_data_system_to_developer_for_o_series() -> Result<()> {
+		let cases = [
+			(AdapterKind::OpenAI, "o1", "developer"),
+			(AdapterKind::OpenAI, "o3-mini", "developer"),
+			(AdapterKind::OpenAI, "o4-mini", "developer"),
+			(AdapterKind::OpenAI, "o1-mini", "system"),
+			(AdapterKind::OpenAI, "gpt-4o", "system"),
+			(AdapterKind::OpenAI, "gpt-4.1-mini", "system"),
+			(AdapterKind::DeepSeek, "deepseek-reasoner", "system"),
+		];
+
+		for (adapter_kind, model_name, expected_role) in cases {
+			let target = ServiceTarget {
+				endpoint: AdapterDispatcher::default_endpoint(adapter_kind),
+				auth: AuthData::from_single("test-api-key"),
+				model: ModelIden::new(adapter_kind, model_name),
+			};
+			let chat_req = ChatRequest::from_system("Be brief.")
+				.append_message(ChatMessage::system("Answer in French."))
+				.append_message(ChatMessage::user("Hello"));
+
+			let data =
+				AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, ChatOptionsSet::default())?;
+
+			let messages = &data.payload["messages"];
+			assert_eq!(messages[0]["role"], expected_role, "model: {model_name}");
+			assert_eq!(messages[1]["role"], expected_role, "model: {model_name}");
+			assert_eq!(messages[2]["role"], "user");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_util_to_web_request_data_system_role_normalization_disabled() -> Result<()> {
+		let target = ServiceTarget {
+			endpoint: AdapterDispatcher::default_endpoint(AdapterKind::OpenAI),
+			auth: AuthData::from_single("test-api-key"),
+			model: ModelIden::new(AdapterKind::OpenAI, "o3"),
+		};
+		let chat_req = ChatRequest::from_system("Be brief.").append_message(ChatMessage::user("Hello"));
+		let chat_options = ChatOptions::default().with_normalize_system_role(false);
+		let options_set = ChatOptionsSet::default().with_chat_options(Some(&chat_options));
+
+		let data = AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set)?;
+
+		assert_eq!(data.payload["messages"][0]["role"], "system");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_service_url_from_endpoint_builder() -> core::result::Result<(), Box<dyn std::error::Error>> {
+		let endpoint = Endpoint::builder()
+			.base("https://proxy.corp/")
+			.path_prefix("/llm/openai/v1/")
+			.query_param("api-version", "2024-06-01")
+			.query_param("tag", "a b")
+			.build()?;
+		assert_eq!(
+			endpoint.base_url(),
+			"https://proxy.corp/llm/openai/v1/?api-version=2024-06-01&tag=a+b"
+		);
+
+		let url = OpenAIAdapter::util_get_service_url(&test_model(), ServiceType::Chat, endpoint.clone())?;
+		assert_eq!(
+			url,
+			"https://proxy.corp/llm/openai/v1/chat/completions?api-version=2024-06-01&tag=a+b"
+		);
+		let url = OpenAIAdapter::util_get_service_url(&test_model(), ServiceType::Models, endpoint)?;
+		assert_eq!(
+			url,
+			"https://proxy.corp/llm/openai/v1/models?api-version=2024-06-01&tag=a+b"
+		);
+
+		// -- Base url path and query params are kept, no prefix
+		let endpoint = Endpoint::builder()
+			.base("https://proxy.corp/gateway?team=core")
+			.query_param("api-version", "2024-06-01")
+			.build()?;
+		let url = OpenAIAdapter::util_get_service_url(&test_model(), ServiceType::Embed, endpoint)?;
+		assert_eq!(
+			url,
+			"https://proxy.corp/gateway/embeddings?team=core&api-version=2024-06-01"
+		);
+
+		// -- Invalid or missing base
+		assert!(Endpoint::builder().base("not a url").build().is_err());
+		assert!(Endpoint::builder().path_prefix("v1").build().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_max_tokens_key_per_model_family() {
+		let cases = [
+			// -- o-series and GPT-5.x
+			("o1", "max_completion_tokens"),
+			("o1-mini", "max_completion_tokens"),
+			("o3-mini", "max_completion_tokens"),
+			("o4-mini-2025-04-16", "max_completion_tokens"),
+			("openai/o3", "max_completion_tokens"),
+			("gpt-5", "max_completion_tokens"),
+			("gpt-5.4-mini", "max_completion_tokens"),
+			// -- others
+			("gpt-4o-mini", "max_tokens"),
+			("gpt-4.1", "max_tokens"),
+			("gpt-3.5-turbo", "max_tokens"),
+			("o1x-custom", "max_tokens"),
+			("deepseek-chat", "max_tokens"),
+		];
+
+		for (model_name, expected) in cases {
+			assert_eq!(max_tokens_key(model_name), expected, "model: {model_name}");
+		}
+
+		// -- In the payload
+		let chat_req = ChatRequest::from_user("Hello");
+		let options = ChatOptions::default().with_max_tokens(100);
+		for (model_name, expected, unexpected) in [
+			("o3-mini", "max_completion_tokens", "max_tokens"),
+			("gpt-4o-mini", "max_tokens", "max_completion_tokens"),
+		] {
+			let target = ServiceTarget {
+				endpoint: AdapterDispatcher::default_endpoint(AdapterKind::OpenAI),
+				auth: AuthData::from_single("test-key"),
+				model: ModelIden::new(AdapterKind::OpenAI, model_name),
+			};
+			let options_set = ChatOptionsSet::default().with_chat_options(Some(&options));
+			let data =
+				OpenAIAdapter::util_to_web_request_data(target, ServiceType::Chat, chat_req.clone(), options_set, None)
+					.expect("should build the request data");
+			assert_eq!(data.payload[expected], 100, "model: {model_name}");
+			assert!(data.payload.get(unexpected).is_none(), "model: {model_name}");
+		}
+	}
+
+	#[test]
+	fn test_invalid_endpoint_is_an_error() {
+		// -- At construction
+		for url in ["not a url", "localhost:11434", "ftp://proxy.corp/v1/", "file:///tmp/v1/"] {
+			assert!(Endpoint::try_from_owned(url).is_err(), "url: {url}");
+		}
+		assert!(Endpoint::try_from_owned("http://localhost:11434/v1/").is_ok());
+
+		// -- At request time (unchecked constructor), an error rather than a panic
+		let target = ServiceTarget {
+			endpoint: Endpoint::from_owned("not a url"),
+			auth: AuthData::from_single("test-key"),
+			model: test_model(),
+		};
+		let res = AdapterDispatcher::to_web_request_data(
+			target,
+			ServiceType::Chat,
+			ChatRequest::from_user("Hello"),
+			ChatOptionsSet::default(),
+		);
+		assert!(matches!(res, Err(Error::InvalidEndpoint { .. })));
+	}
 }
 
 // endregion: --- Tests
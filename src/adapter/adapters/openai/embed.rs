@@ -4,7 +4,7 @@
 use crate::adapter::adapters::support::get_api_key;
 use crate::adapter::{Adapter, ServiceType, WebRequestData};
 use crate::chat::Usage;
-use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
+use crate::embed::{EmbedInput, EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
 use crate::webc::WebResponse;
 use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
 use serde::{Deserialize, Serialize};
@@ -13,7 +13,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
 struct OpenAIEmbedRequest {
-	input: OpenAIEmbedInput,
+	/// A string for a single input, an array for a batch (see `EmbedInput`).
+	input: EmbedInput,
 	model: String,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	encoding_format: Option<String>,
@@ -23,13 +24,6 @@ struct OpenAIEmbedRequest {
 	user: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-#[serde(untagged)]
-enum OpenAIEmbedInput {
-	Single(String),
-	Batch(Vec<String>),
-}
-
 // endregion: --- OpenAI Embed Request
 
 // region:    --- OpenAI Embed Response
@@ -76,17 +70,11 @@ pub fn to_embed_request_data(
 		headers.merge_with(custom_headers);
 	}
 
-	// Convert EmbedRequest to OpenAI format
-	let input = match embed_req.input {
-		crate::embed::EmbedInput::Single(text) => OpenAIEmbedInput::Single(text),
-		crate::embed::EmbedInput::Batch(texts) => OpenAIEmbedInput::Batch(texts),
-	};
-
 	// Extract the actual model name (without namespace)
 	let (_, model_name) = model.model_name.namespace_and_name();
 
 	let openai_req = OpenAIEmbedRequest {
-		input,
+		input: embed_req.input,
 		model: model_name.to_string(),
 		encoding_format: options_set.encoding_format().map(|s| s.to_string()),
 		dimensions: options_set.dimensions(),
@@ -105,7 +93,12 @@ pub fn to_embed_request_data(
 		service_target.endpoint,
 	)?;
 
-	Ok(WebRequestData { url, headers, payload })
+	Ok(WebRequestData {
+		url,
+		headers,
+		payload,
+		ignored_options: Vec::new(),
+	})
 }
 
 pub fn to_embed_response(
@@ -122,9 +115,10 @@ pub fn to_embed_response(
 			serde_error,
 		})?;
 
-	// Convert to our format
-	let embeddings: Vec<Embedding> = openai_res
-		.data
+	// Convert to our format, in the input order (the data items have the input index)
+	let mut data = openai_res.data;
+	data.sort_by_key(|data| data.index);
+	let embeddings: Vec<Embedding> = data
 		.into_iter()
 		.map(|data| Embedding::new(data.embedding, data.index))
 		.collect();
@@ -155,3 +149,67 @@ pub fn to_embed_response(
 }
 
 // endregion: --- Public Functions
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterKind;
+	use crate::resolver::AuthData;
+	use reqwest::StatusCode;
+	use serde_json::json;
+
+	fn test_target() -> ServiceTarget {
+		ServiceTarget {
+			endpoint: crate::adapter::openai::OpenAIAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::OpenAI, "text-embedding-3-small"),
+		}
+	}
+
+	#[test]
+	fn test_embed_request_data_input_single_string_batch_array() -> Result<()> {
+		let single = to_embed_request_data(test_target(), EmbedRequest::new("hello"), EmbedOptionsSet::default())?;
+		let batch = to_embed_request_data(
+			test_target(),
+			EmbedRequest::new_batch(vec!["hello".to_string(), "world".to_string()]),
+			EmbedOptionsSet::default(),
+		)?;
+
+		assert_eq!(single.payload["input"], json!("hello"));
+		assert_eq!(batch.payload["input"], json!(["hello", "world"]));
+		assert_eq!(batch.payload["model"], "text-embedding-3-small");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_embed_response_input_order_and_index() -> Result<()> {
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			headers: Default::default(),
+			body: json!({
+				"object": "list",
+				"model": "text-embedding-3-small",
+				"data": [
+					{"object": "embedding", "index": 1, "embedding": [0.3, 0.4]},
+					{"object": "embedding", "index": 0, "embedding": [0.1, 0.2]}
+				],
+				"usage": {"prompt_tokens": 4, "total_tokens": 4}
+			}),
+		};
+
+		let res = to_embed_response(test_target().model, web_response, EmbedOptionsSet::default())?;
+
+		assert_eq!(res.embedding_count(), 2);
+		assert_eq!(res.embeddings[0].index, 0);
+		assert_eq!(res.embeddings[0].vector, vec![0.1, 0.2]);
+		assert_eq!(res.embeddings[1].index, 1);
+		assert_eq!(res.embeddings[1].vector, vec![0.3, 0.4]);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
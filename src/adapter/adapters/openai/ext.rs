@@ -0,0 +1,208 @@
+//! OpenAI provider extension - Files API (upload, list, content, delete).
+//!
+//! Files are the inputs of the Batch API (`purpose=batch`) and can be referenced by `file_id` in requests.
+//!
+//! Usage: `client.provider_ext::<OpenAIExt>()?`
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::{get_api_key, join_service_url};
+use crate::webc::WebClient;
+use crate::{Client, Error, Headers, ModelIden, ProviderExt, Result};
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+
+// region:    --- OpenAIExt
+
+/// OpenAI-specific APIs, created with `client.provider_ext::<OpenAIExt>()`.
+///
+/// Auth and endpoint are resolved like for chat requests (AuthResolver or `OPENAI_API_KEY`,
+/// and the `ServiceTargetResolver` endpoint).
+#[derive(Debug, Clone)]
+pub struct OpenAIExt<'a> {
+	client: &'a Client,
+}
+
+impl<'a> ProviderExt<'a> for OpenAIExt<'a> {
+	const ADAPTER_KIND: AdapterKind = AdapterKind::OpenAI;
+
+	fn from_client(client: &'a Client) -> Result<Self> {
+		Ok(Self { client })
+	}
+}
+
+/// Files API
+impl OpenAIExt<'_> {
+	/// Uploads a file (`POST /files`, multipart with the `purpose` and `file` fields).
+	pub async fn upload_file(&self, upload: FileUpload) -> Result<FileObject> {
+		let (url, headers) = self.url_and_headers("files").await?;
+
+		let FileUpload {
+			file_name,
+			content,
+			purpose,
+		} = upload;
+		let mime = mime_guess::from_path(&file_name).first_or_octet_stream();
+		let part = Part::bytes(content)
+			.file_name(file_name)
+			.mime_str(mime.essence_str())
+			.map_err(|err| self.web_error(err.into()))?;
+		let form = Form::new().text("purpose", purpose.as_str().to_string()).part("file", part);
+
+		let web_res = self
+			.web_client()
+			.do_post_multipart(&url, &headers, form)
+			.await
+			.map_err(|webc_error| self.web_error(webc_error))?;
+
+		Ok(serde_json::from_value(web_res.body)?)
+	}
+
+	/// Lists the files of the organization (`GET /files`), optionally filtered by purpose.
+	pub async fn list_files(&self, purpose: Option<FilePurpose>) -> Result<FileList> {
+		let (mut url, headers) = self.url_and_headers("files").await?;
+		if let Some(purpose) = purpose {
+			url = format!("{url}?purpose={}", purpose.as_str());
+		}
+
+		let web_res = self
+			.web_client()
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| self.web_error(webc_error))?;
+
+		Ok(serde_json::from_value(web_res.body)?)
+	}
+
+	/// Returns the raw content of a file (`GET /files/{file_id}/content`).
+	pub async fn get_file_content(&self, file_id: &str) -> Result<Vec<u8>> {
+		let (url, headers) = self.url_and_headers(&format!("files/{file_id}/content")).await?;
+
+		let content = self
+			.web_client()
+			.do_get_bytes(&url, &headers)
+			.await
+			.map_err(|webc_error| self.web_error(webc_error))?;
+
+		Ok(content)
+	}
+
+	/// Deletes a file (`DELETE /files/{file_id}`).
+	pub async fn delete_file(&self, file_id: &str) -> Result<FileDeleted> {
+		let (url, headers) = self.url_and_headers(&format!("files/{file_id}")).await?;
+
+		let web_res = self
+			.web_client()
+			.do_delete(&url, &headers)
+			.await
+			.map_err(|webc_error| self.web_error(webc_error))?;
+
+		Ok(serde_json::from_value(web_res.body)?)
+	}
+}
+
+/// Support
+impl OpenAIExt<'_> {
+	fn web_client(&self) -> &WebClient {
+		self.client.web_client()
+	}
+
+	async fn url_and_headers(&self, suffix: &str) -> Result<(String, Headers)> {
+		let (auth, endpoint) = self.client.config().resolve_adapter_config(Self::ADAPTER_KIND).await?;
+
+		let api_key = get_api_key(auth, &ModelIden::new(Self::ADAPTER_KIND, ""))?;
+		let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+		let url = join_service_url(endpoint.base_url(), suffix)?;
+
+		Ok((url, headers))
+	}
+
+	fn web_error(&self, webc_error: crate::webc::Error) -> Error {
+		Error::WebAdapterCall {
+			adapter_kind: Self::ADAPTER_KIND,
+			webc_error,
+		}
+	}
+}
+
+// endregion: --- OpenAIExt
+
+// region:    --- Files Types
+
+/// The intended use of an uploaded file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilePurpose {
+	/// Batch API input (`.jsonl`).
+	Batch,
+	/// Images for vision inputs.
+	Vision,
+	/// Assistants and file inputs.
+	Assistants,
+	/// Flexible file type for any purpose.
+	UserData,
+	/// Fine-tuning data.
+	FineTune,
+}
+
+impl FilePurpose {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			FilePurpose::Batch => "batch",
+			FilePurpose::Vision => "vision",
+			FilePurpose::Assistants => "assistants",
+			FilePurpose::UserData => "user_data",
+			FilePurpose::FineTune => "fine-tune",
+		}
+	}
+}
+
+/// A file to upload with `OpenAIExt::upload_file`.
+#[derive(Debug, Clone)]
+pub struct FileUpload {
+	/// File name sent in the multipart part (its extension drives the content type, e.g., `.jsonl`).
+	pub file_name: String,
+	pub content: Vec<u8>,
+	pub purpose: FilePurpose,
+}
+
+impl FileUpload {
+	pub fn new(file_name: impl Into<String>, content: impl Into<Vec<u8>>, purpose: FilePurpose) -> Self {
+		Self {
+			file_name: file_name.into(),
+			content: content.into(),
+			purpose,
+		}
+	}
+}
+
+/// A file object, as returned by the Files API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileObject {
+	pub id: String,
+	/// Size in bytes.
+	pub bytes: u64,
+	/// Unix timestamp (seconds).
+	pub created_at: i64,
+	pub filename: String,
+	/// As reported by the API (e.g., `batch`, `batch_output`, `vision`).
+	pub purpose: String,
+	pub status: Option<String>,
+	/// Unix timestamp (seconds).
+	pub expires_at: Option<i64>,
+}
+
+/// Result of `OpenAIExt::list_files`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileList {
+	pub data: Vec<FileObject>,
+	#[serde(default)]
+	pub has_more: bool,
+}
+
+/// Result of `OpenAIExt::delete_file`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileDeleted {
+	pub id: String,
+	pub deleted: bool,
+}
+
+// endregion: --- Files Types
@@ -1,4 +1,5 @@
 //! API Documentation:     <https://platform.openai.com/docs/api-reference/chat>
+//! Files Documentation:   <https://platform.openai.com/docs/api-reference/files>
 //! Model Names:           <https://platform.openai.com/docs/models>
 //! Pricing:               <https://platform.openai.com/docs/pricing/> (user: <https://openai.com/api/pricing/>)
 
@@ -7,10 +8,12 @@
 mod adapter_impl;
 mod adapter_shared;
 mod embed;
+mod ext;
 mod streamer;
 
 pub use adapter_impl::*;
 pub use adapter_shared::*;
+pub use ext::*;
 pub use streamer::*;
 
 // endregion: --- Modules
@@ -1,5 +1,5 @@
 use crate::adapter::AdapterKind;
-use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions, parse_tool_arguments};
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
 use crate::adapter::openai::OpenAIAdapter;
 use crate::chat::{ChatOptionsSet, StopReason, ToolCall};
@@ -10,14 +10,28 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 use value_ext::JsonValueExt;
 
+/// Takes the provider error of an error-shaped stream message, either an `{"error": {..}}` payload,
+/// or a Groq `{"x_groq": {"error": ..}}` envelope.
 fn take_stream_error(message_data: &mut Value, model_iden: &ModelIden) -> Option<Error> {
-	let error_body = message_data.x_take::<Value>("error").ok()?;
+	let error_body = ["error", "/x_groq/error"]
+		.into_iter()
+		.find_map(|path| message_data.x_take::<Value>(path).ok().filter(|body| !body.is_null()))?;
 	Some(Error::ChatResponse {
 		model_iden: model_iden.clone(),
 		body: error_body,
 	})
 }
 
+/// Takes the text delta of a choice, `delta.content` for the chat completions,
+/// or `text` for the completions (raw prompt) chunks.
+fn delta_text(choice: &mut Value) -> Option<String> {
+	choice
+		.x_take::<Option<String>>("/delta/content")
+		.ok()
+		.flatten()
+		.or_else(|| choice.x_take::<Option<String>>("text").ok().flatten())
+}
+
 fn take_finish_reason_usage(
 	message_data: &mut Value,
 	adapter_kind: AdapterKind,
@@ -101,6 +115,62 @@ impl OpenAIStreamer {
 			tool_call
 		}
 	}
+
+	/// Takes the captured data into the `InterStreamEnd` (on the `[DONE]` message).
+	///
+	/// Errors when a captured tool call has malformed arguments (unless `lenient_tool_arguments`).
+	fn take_stream_end(&mut self) -> Result<InterStreamEnd> {
+		// -- Build the usage and captured_content
+		// TODO: Needs to clarify wh for usage we do not adopt the same strategy from captured content below
+		let captured_usage = if self.options.capture_usage {
+			self.captured_data.usage.take()
+		} else {
+			None
+		};
+
+		// -- Process the captured_tool_calls
+		// NOTE: here we attempt to parse the `fn_arguments` if it is string, because it means that it was accumulated
+		let captured_tool_calls = if let Some(tools_calls) = self.captured_data.tool_calls.take() {
+			let lenient_tool_arguments = self.options.lenient_tool_arguments;
+			let tools_calls = tools_calls
+				.into_iter()
+				.map(|tool_call| {
+					// extrat
+					let ToolCall {
+						call_id,
+						fn_name,
+						fn_arguments,
+						..
+					} = tool_call;
+					// parse fn_arguments (error on malformed, unless lenient)
+					let fn_arguments = parse_tool_arguments(&fn_name, fn_arguments, lenient_tool_arguments)?;
+
+					Ok(ToolCall {
+						call_id,
+						fn_name,
+						fn_arguments,
+						thought_signatures: None,
+					})
+				})
+				.collect::<Result<Vec<ToolCall>>>()?;
+			Some(tools_calls)
+		} else {
+			None
+		};
+
+		// Return the internal stream end
+		Ok(InterStreamEnd {
+			captured_usage,
+			captured_stop_reason: self.captured_data.stop_reason.take().map(StopReason::from),
+			captured_text_content: self.captured_data.content.take(),
+			captured_content_parts: None,
+			captured_reasoning_content: self.captured_data.reasoning_content.take(),
+			captured_tool_calls,
+			captured_thought_signatures: None,
+			captured_response_id: None,
+			interrupted_by: None,
+		})
+	}
 }
 
 impl futures::Stream for OpenAIStreamer {
@@ -120,78 +190,38 @@ impl futures::Stream for OpenAIStreamer {
 					// According to OpenAI Spec, this is the end message
 					if message.data == "[DONE]" {
 						self.done = true;
-
-						// -- Build the usage and captured_content
-						// TODO: Needs to clarify wh for usage we do not adopt the same strategy from captured content below
-						let captured_usage = if self.options.capture_usage {
-							self.captured_data.usage.take()
-						} else {
-							None
-						};
-
-						// -- Process the captured_tool_calls
-						// NOTE: here we attempt to parse the `fn_arguments` if it is string, because it means that it was accumulated
-						let captured_tool_calls = if let Some(tools_calls) = self.captured_data.tool_calls.take() {
-							let tools_calls: Vec<ToolCall> = tools_calls
-								.into_iter()
-								.map(|tool_call| {
-									// extrat
-									let ToolCall {
-										call_id,
-										fn_name,
-										fn_arguments,
-										..
-									} = tool_call;
-									// parse fn_arguments if needed
-									let fn_arguments = match fn_arguments {
-										Value::String(fn_arguments_string) => {
-											// NOTE: Here we are resilient for now, if we cannot parse, just return the original String
-											match serde_json::from_str::<Value>(&fn_arguments_string) {
-												Ok(fn_arguments) => fn_arguments,
-												Err(_) => Value::String(fn_arguments_string),
-											}
-										}
-										_ => fn_arguments,
-									};
-
-									ToolCall {
-										call_id,
-										fn_name,
-										fn_arguments,
-										thought_signatures: None,
-									}
-								})
-								.collect();
-							Some(tools_calls)
-						} else {
-							None
-						};
-
-						// Return the internal stream end
-						let inter_stream_end = InterStreamEnd {
-							captured_usage,
-							captured_stop_reason: self.captured_data.stop_reason.take().map(StopReason::from),
-							captured_text_content: self.captured_data.content.take(),
-							captured_content_parts: None,
-							captured_reasoning_content: self.captured_data.reasoning_content.take(),
-							captured_tool_calls,
-							captured_thought_signatures: None,
-							captured_response_id: None,
-						};
-
-						return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
+						return Poll::Ready(Some(self.take_stream_end().map(InterStreamEvent::End)));
 					}
 
 					// -- Other Content Messages
 					// Parse to get the choice
-					let mut message_data: Value =
-						serde_json::from_str(&message.data).map_err(|serde_error| Error::StreamParse {
-							model_iden: self.options.model_iden.clone(),
-							serde_error,
-						})?;
+					let mut message_data: Value = match serde_json::from_str(&message.data) {
+						Ok(message_data) => message_data,
+						// An `error` event with a non-JSON data (e.g., `Internal Server Error`), end with the partial data
+						Err(_) if message.event == "error" => {
+							let error = Error::ChatResponse {
+								model_iden: self.options.model_iden.clone(),
+								body: Value::String(message.data),
+							};
+							self.done = true;
+							return Poll::Ready(Some(
+								self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+							));
+						}
+						Err(serde_error) => {
+							return Poll::Ready(Some(Err(Error::StreamParse {
+								model_iden: self.options.model_iden.clone(),
+								serde_error,
+							})));
+						}
+					};
 
+					// -- Provider error mid-stream, end with the partial data captured so far
 					if let Some(error) = take_stream_error(&mut message_data, &self.options.model_iden) {
-						return Poll::Ready(Some(Err(error)));
+						self.done = true;
+						return Poll::Ready(Some(
+							self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+						));
 					}
 
 					let first_choice: Option<Value> = message_data.x_take("/choices/0").ok();
@@ -246,7 +276,7 @@ impl futures::Stream for OpenAIStreamer {
 							// NOTE: Some providers (e.g., mistral) send delta/content AND finish_reason
 							// in the same SSE message. We must capture and emit that final content chunk
 							// before continuing to the next message, otherwise it is silently lost.
-							let content = first_choice.x_take::<Option<String>>("/delta/content").ok().flatten();
+							let content = delta_text(&mut first_choice);
 							let reasoning_content = first_choice
 								.x_take::<Option<String>>("/delta/reasoning_content")
 								.ok()
@@ -317,7 +347,7 @@ impl futures::Stream for OpenAIStreamer {
 						// -- Content / Reasoning Content
 						// Some providers (e.g., Ollama) emit reasoning in `delta.reasoning` and send empty content.
 						else {
-							let content = first_choice.x_take::<Option<String>>("/delta/content").ok().flatten();
+							let content = delta_text(&mut first_choice);
 							let reasoning_content = first_choice
 								.x_take::<Option<String>>("/delta/reasoning_content")
 								.ok()
@@ -376,11 +406,21 @@ impl futures::Stream for OpenAIStreamer {
 				}
 				Some(Err(err)) => {
 					tracing::error!("Error: {}", err);
-					return Poll::Ready(Some(Err(Error::WebStream {
+					let error = Error::WebStream {
 						model_iden: self.options.model_iden.clone(),
 						cause: err.to_string(),
 						error: err,
-					})));
+					};
+					// End with the partial data captured so far, if any (the error follows, see `ChatStream`)
+					self.done = true;
+					return Poll::Ready(Some(
+						self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+					));
+				}
+				// A body ending without the `[DONE]` message after the finish reason (e.g., a JSON lines gateway)
+				None if self.captured_data.stop_reason.is_some() => {
+					self.done = true;
+					return Poll::Ready(Some(self.take_stream_end().map(InterStreamEvent::End)));
 				}
 				None => {
 					return Poll::Ready(None);
@@ -420,6 +460,35 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_take_stream_error_reads_groq_envelope() {
+		let mut message_data = serde_json::json!({
+			"id": "chatcmpl-1",
+			"object": "chat.completion.chunk",
+			"choices": [],
+			"x_groq": {
+				"id": "req_01",
+				"error": {"message": "Service Unavailable", "type": "internal_server_error"}
+			}
+		});
+
+		let err = take_stream_error(&mut message_data, &test_model()).expect("expected stream error");
+		let Error::ChatResponse { body, .. } = err else {
+			panic!("unexpected error variant: {err:?}");
+		};
+		assert_eq!(body["message"], "Service Unavailable");
+	}
+
+	#[test]
+	fn test_take_stream_error_none_when_error_null() {
+		let mut message_data = serde_json::json!({
+			"error": null,
+			"x_groq": {"id": "req_01", "usage": {"total_tokens": 3}},
+			"choices": [{"delta": {"content": "hi"}}]
+		});
+		assert!(take_stream_error(&mut message_data, &test_model()).is_none());
+	}
+
 	#[test]
 	fn test_take_stream_error_none_when_error_key_missing() {
 		let mut message_data = serde_json::json!({
@@ -428,6 +497,47 @@ mod tests {
 		assert!(take_stream_error(&mut message_data, &test_model()).is_none());
 	}
 
+	/// The emoji and CJK characters split across the body chunks (and SSE frames) are reassembled,
+	/// in the chunks and in the captured content.
+	#[tokio::test]
+	async fn test_split_multibyte_chunks_captured_content_intact() {
+		use crate::chat::ChatOptions;
+		use futures::StreamExt;
+
+		let deltas = ["你好", " 😀", "世界 🎉", "!"];
+		let mut body = String::new();
+		for delta in deltas {
+			let data = serde_json::json!({"choices": [{"delta": {"content": delta}}]});
+			body.push_str(&format!("data: {data}\n\n"));
+		}
+		body.push_str("data: {\"choices\": [{\"delta\": {}, \"finish_reason\": \"stop\"}]}\n\ndata: [DONE]\n\n");
+
+		let options = ChatOptions::default().with_capture_content(true);
+		for size in [1, 2, 3, 5, 7] {
+			let chunks = body.as_bytes().chunks(size).map(<[u8]>::to_vec).collect();
+			let streamer = OpenAIStreamer::new(
+				EventSourceStream::from_body_chunks(chunks),
+				test_model(),
+				ChatOptionsSet::default().with_chat_options(Some(&options)),
+			);
+			let events: Vec<InterStreamEvent> =
+				streamer.map(|event| event.expect("event should parse")).collect().await;
+
+			let chunks: Vec<&str> = events
+				.iter()
+				.filter_map(|event| match event {
+					InterStreamEvent::Chunk(content) => Some(content.as_str()),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(chunks, deltas, "for chunk size {size}");
+			let Some(InterStreamEvent::End(end)) = events.last() else {
+				panic!("should end with InterStreamEvent::End (chunk size {size})");
+			};
+			assert_eq!(end.captured_text_content.as_deref(), Some("你好 😀世界 🎉!"));
+		}
+	}
+
 	fn new_streamer_with_tool_capture() -> OpenAIStreamer {
 		let client = reqwest::Client::new();
 		let reqwest_builder = client.get("http://127.0.0.1/");
@@ -1,12 +1,13 @@
 use crate::adapter::ModelCapabilities;
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, ignored_options, join_service_url, validate_openai_tool_names};
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::openai_resp::OpenAIRespStreamer;
 use crate::adapter::openai_resp::resp_types::{RespResponse, parse_resp_output};
 use crate::adapter::{Adapter, AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
 	CacheControl, ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream,
-	ChatStreamResponse, ContentPart, MessageContent, ReasoningEffort, StopReason, Tool, ToolConfig, ToolName, Usage,
+	ChatStreamResponse, ContentPart, IgnoredOption, MessageContent, ReasoningEffort, StopReason, Tool, ToolChoice,
+	ToolConfig, ToolName, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{EventSourceStream, WebResponse};
@@ -18,6 +19,22 @@ use value_ext::JsonValueExt;
 
 pub struct OpenAIRespAdapter;
 
+/// The `ChatOptions` payload options sent by the OpenAI Responses adapter (the others are reported as ignored).
+/// `reasoning_effort` is added for OpenAIResp.
+const SUPPORTED_OPTIONS: &[&str] = &[
+	"temperature",
+	"max_tokens",
+	"top_p",
+	"stop_sequences",
+	"seed",
+	"response_format",
+	"verbosity",
+	"tool_choice",
+	"parallel_tool_calls",
+	"cache_control",
+	"prompt_cache_key",
+];
+
 impl OpenAIRespAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "OPENAI_API_KEY";
 }
@@ -139,10 +156,14 @@ impl Adapter for OpenAIRespAdapter {
 		let explicit_store = chat_req.store;
 
 		// -- Build the basic payload
+		validate_openai_tool_names(&model, chat_req.tools.as_deref())?;
 		let OpenAIRespRequestParts {
-			input_items: messages,
+			input_items: mut messages,
 			tools,
 		} = Self::into_openai_request_parts(&model, chat_req)?;
+		if chat_options.normalize_system_role().unwrap_or(true) && OpenAIAdapter::util_is_o_series(&model) {
+			OpenAIAdapter::util_system_to_developer_role(&mut messages);
+		}
 
 		// Store: always opt-in. If not explicitly set, default is false.
 		// Privacy first: we never implicitly set store=true, even when previous_response_id is set.
@@ -195,9 +216,21 @@ impl Adapter for OpenAIRespAdapter {
 			payload.x_insert("include", json!(["reasoning.encrypted_content"]))?;
 		}
 
-		// -- Tools
+		// -- Tools (the tool choice and parallel tool calls are only sent with tools)
 		if let Some(tools) = tools {
 			payload.x_insert("/tools", tools)?;
+			if let Some(tool_choice) = chat_options.tool_choice() {
+				let tool_choice = match tool_choice {
+					ToolChoice::Auto => json!("auto"),
+					ToolChoice::Required => json!("required"),
+					ToolChoice::None => json!("none"),
+					ToolChoice::Tool(name) => json!({"type": "function", "name": name}),
+				};
+				payload.x_insert("tool_choice", tool_choice)?;
+			}
+			if let Some(parallel_tool_calls) = chat_options.parallel_tool_calls() {
+				payload.x_insert("parallel_tool_calls", parallel_tool_calls)?;
+			}
 		}
 
 		// -- Compute response format
@@ -257,6 +290,7 @@ impl Adapter for OpenAIRespAdapter {
 		if let Some(prompt_cache_key) = chat_options.prompt_cache_key() {
 			payload.x_insert("prompt_cache_key", prompt_cache_key)?;
 		}
+		let mut cache_control_ignored = false;
 		if let Some(cache_control) = chat_options.cache_control() {
 			let prompt_cache_retention = match cache_control {
 				CacheControl::Memory | CacheControl::Ephemeral => Some("in_memory"),
@@ -265,10 +299,30 @@ impl Adapter for OpenAIRespAdapter {
 			};
 			if let Some(prompt_cache_retention) = prompt_cache_retention {
 				payload.x_insert("prompt_cache_retention", prompt_cache_retention)?;
+			} else {
+				cache_control_ignored = true;
 			}
 		}
 
-		Ok(WebRequestData { url, headers, payload })
+		// -- Ignored options
+		let mut supported = SUPPORTED_OPTIONS.to_vec();
+		if matches!(adapter_kind, AdapterKind::OpenAIResp) {
+			supported.push("reasoning_effort");
+		}
+		let mut ignored_options = ignored_options(&chat_options, &supported, adapter_kind);
+		if cache_control_ignored {
+			ignored_options.push(IgnoredOption::new(
+				"cache_control",
+				"only the in-memory and 24h prompt cache retentions are supported by OpenAI",
+			));
+		}
+
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 
 	fn to_chat_response(
@@ -292,6 +346,8 @@ impl Adapter for OpenAIRespAdapter {
 		let content = MessageContent::from_parts(parsed_output.content);
 		let reasoning_content = parsed_output.reasoning_content;
 
+		let usage_reported = !usage.is_empty();
+
 		Ok(ChatResponse {
 			content,
 			reasoning_content,
@@ -299,8 +355,15 @@ impl Adapter for OpenAIRespAdapter {
 			provider_model_iden,
 			stop_reason: Some(StopReason::from(resp.status)),
 			usage,
+			usage_reported,
 			captured_raw_body,
 			response_id: Some(resp.id),
+			metrics: None, // Set by the client exec_chat
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: resp.service_tier,
+			provider_timing: None,
 		})
 	}
 
@@ -309,13 +372,16 @@ impl Adapter for OpenAIRespAdapter {
 		reqwest_builder: RequestBuilder,
 		options_sets: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let event_source = EventSourceStream::new(reqwest_builder);
+		let event_source =
+			EventSourceStream::new(reqwest_builder).with_lossy_utf8(options_sets.lossy_utf8().unwrap_or(true));
 		let openai_stream = OpenAIRespStreamer::new(event_source, model_iden.clone(), options_sets);
 		let chat_stream = ChatStream::from_inter_stream(openai_stream);
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			extra_body_overrides: Vec::new(), // Set by the client exec_chat_stream
+			ignored_options: Vec::new(),      // Set by the client exec_chat_stream
 		})
 	}
 
@@ -324,10 +390,10 @@ impl Adapter for OpenAIRespAdapter {
 		_embed_req: crate::embed::EmbedRequest,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
-		Err(crate::Error::AdapterNotSupported {
-			adapter_kind: crate::adapter::AdapterKind::OpenAIResp,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(
+			AdapterKind::OpenAIResp,
+			ServiceType::Embed,
+		))
 	}
 
 	fn to_embed_response(
@@ -335,10 +401,10 @@ impl Adapter for OpenAIRespAdapter {
 		_web_response: WebResponse,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::embed::EmbedResponse> {
-		Err(crate::Error::AdapterNotSupported {
-			adapter_kind: crate::adapter::AdapterKind::OpenAIResp,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(
+			AdapterKind::OpenAIResp,
+			ServiceType::Embed,
+		))
 	}
 }
 
@@ -350,31 +416,19 @@ impl OpenAIRespAdapter {
 		// -- utility arguments
 		default_endpoint: Endpoint,
 	) -> Result<String> {
-		let base_url = default_endpoint.base_url();
-		// Parse into URL and query-params
-		let base_url = reqwest::Url::parse(base_url)
-			.map_err(|err| Error::Internal(format!("Cannot parse url: {base_url}. Cause:\n{err}")))?;
-		let original_query_params = base_url.query().to_owned();
-
-		let suffix = match service_type {
+		let suffix = default_endpoint.service_path(service_type).unwrap_or(match service_type {
 			ServiceType::Chat | ServiceType::ChatStream => "responses",
 			ServiceType::Embed => "embeddings", // Not really supported; kept for completeness
 			ServiceType::Models => "models",
-		};
-		let mut full_url = base_url.join(suffix).map_err(|err| {
-			Error::Internal(format!(
-				"Cannot joing url suffix '{suffix}' for base_url '{base_url}'. Cause:\n{err}"
-			))
-		})?;
-		full_url.set_query(original_query_params);
-		Ok(full_url.to_string())
+		});
+		join_service_url(default_endpoint.base_url(), suffix)
 	}
 
 	/// Takes the genai ChatMessages and builds the OpenAIChatRequestParts
 	/// - `genai::ChatRequest.system`, if present, is added as the first message with role 'system'.
 	/// - All messages get added with the corresponding roles (tools are not supported for now)
 	///
-	fn into_openai_request_parts(_model_iden: &ModelIden, chat_req: ChatRequest) -> Result<OpenAIRespRequestParts> {
+	fn into_openai_request_parts(model_iden: &ModelIden, chat_req: ChatRequest) -> Result<OpenAIRespRequestParts> {
 		let mut input_items: Vec<Value> = Vec::new();
 
 		// -- Process the system
@@ -396,6 +450,13 @@ impl OpenAIRespAdapter {
 					// TODO: Probably need to warn if it is a ToolCalls type of content
 				}
 
+				ChatRole::Developer => {
+					if let Some(content) = msg.content.into_joined_texts() {
+						let role = OpenAIAdapter::util_developer_role(model_iden);
+						input_items.push(json!({"role": role, "content": content}))
+					}
+				}
+
 				// User - For now support Text and Binary
 				ChatRole::User => {
 					// -- If we have only text, then, we jjust returned the joined_texts
@@ -626,7 +687,51 @@ struct OpenAIRespRequestParts {
 mod tests {
 	use super::*;
 	use crate::adapter::AdapterKind;
-	use crate::chat::ChatMessage;
+	use crate::chat::{ChatMessage, ChatOptions};
+
+	#[test]
+	fn test_tool_choice_and_parallel_tool_calls_in_payload() -> Result<()> {
+		let target = ServiceTarget {
+			endpoint: OpenAIRespAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::OpenAIResp, "gpt-5-mini"),
+		};
+		let options = ChatOptions::default()
+			.with_tool_choice(ToolChoice::tool("get_weather"))
+			.with_parallel_tool_calls(false);
+		let chat_req =
+			ChatRequest::from_user("What is the weather in Paris?").with_tools(vec![Tool::new("get_weather")]);
+
+		let data = OpenAIRespAdapter::to_web_request_data(
+			target,
+			ServiceType::Chat,
+			chat_req,
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		)?;
+
+		assert_eq!(
+			data.payload["tool_choice"],
+			json!({"type": "function", "name": "get_weather"})
+		);
+		assert_eq!(data.payload["parallel_tool_calls"], json!(false));
+		assert!(data.ignored_options.is_empty());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_developer_message_role_per_model_family() {
+		for (model_name, expected_role) in [("gpt-5", "developer"), ("o3-pro", "developer"), ("gpt-4.1", "system")] {
+			let model_iden = ModelIden::new(AdapterKind::OpenAIResp, model_name);
+			let chat_req =
+				ChatRequest::from_messages(vec![ChatMessage::developer("Be concise."), ChatMessage::user("Hi")]);
+
+			let parts = OpenAIRespAdapter::into_openai_request_parts(&model_iden, chat_req)
+				.expect("Should serialize successfully");
+
+			assert_eq!(parts.input_items[0]["role"], expected_role, "model: {model_name}");
+		}
+	}
 
 	/// Test that assistant message text content uses "output_text" type (not "input_text").
 	///
@@ -676,6 +781,68 @@ mod tests {
 			"Assistant message content should use 'output_text' type, not 'input_text'"
 		);
 	}
+
+	#[test]
+	fn test_to_web_request_data_invalid_tool_name() {
+		let target = ServiceTarget {
+			endpoint: OpenAIRespAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-api-key"),
+			model: ModelIden::new(AdapterKind::OpenAIResp, "gpt-5-codex"),
+		};
+		let chat_req = ChatRequest::from_user("Hello").append_tool(crate::chat::Tool::new("get/weather"));
+
+		let res =
+			OpenAIRespAdapter::to_web_request_data(target, ServiceType::Chat, chat_req, ChatOptionsSet::default());
+
+		assert!(matches!(res, Err(Error::InvalidToolName { .. })));
+	}
+
+	#[test]
+	fn test_to_web_request_data_inline_system_to_developer_for_o_series() -> Result<()> {
+		for (model_name, expected_role) in [("o3", "developer"), ("o4-mini", "developer"), ("gpt-4.1", "system")] {
+			let target = ServiceTarget {
+				endpoint: OpenAIRespAdapter::default_endpoint(),
+				auth: AuthData::from_single("test-api-key"),
+				model: ModelIden::new(AdapterKind::OpenAIResp, model_name),
+			};
+			let chat_req =
+				ChatRequest::from_messages(vec![ChatMessage::system("Be concise."), ChatMessage::user("Hi")]);
+
+			let data =
+				OpenAIRespAdapter::to_web_request_data(target, ServiceType::Chat, chat_req, ChatOptionsSet::default())?;
+
+			assert_eq!(data.payload["input"][0]["role"], expected_role, "model: {model_name}");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_chat_response_service_tier() -> Result<()> {
+		let web_response = WebResponse {
+			status: reqwest::StatusCode::OK,
+			headers: Default::default(),
+			body: json!({
+				"id": "resp_test",
+				"status": "completed",
+				"model": "o3-2025-04-16",
+				"service_tier": "flex",
+				"output": [{
+					"type": "message",
+					"role": "assistant",
+					"content": [{"type": "output_text", "text": "hello"}]
+				}]
+			}),
+		};
+		let model = ModelIden::new(AdapterKind::OpenAIResp, "o3");
+
+		let response = OpenAIRespAdapter::to_chat_response(model, web_response, ChatOptionsSet::default())?;
+
+		assert_eq!(response.first_text(), Some("hello"));
+		assert_eq!(response.service_tier.as_deref(), Some("flex"));
+
+		Ok(())
+	}
 }
 
 // endregion: --- Tests
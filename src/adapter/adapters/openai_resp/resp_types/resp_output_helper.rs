@@ -1,5 +1,6 @@
+use crate::Result;
+use crate::adapter::adapters::support::parse_tool_arguments;
 use crate::chat::{Binary, ContentPart, ToolCall};
-use crate::{Error, Result};
 use serde_json::Value;
 use value_ext::JsonValueExt;
 
@@ -95,10 +96,8 @@ impl ContentPart {
 				let fn_name = item_value.x_remove::<String>("name")?;
 				let call_id = item_value.x_remove::<String>("call_id")?;
 				let arguments = item_value.x_remove::<String>("arguments")?;
-				let fn_arguments: Value =
-					serde_json::from_str(&arguments).map_err(|_| Error::InvalidJsonResponseElement {
-						info: "tool call arguments is not an object.\nCause",
-					})?;
+				// NOTE: Strict for now, as the lenient option is not available at this level
+				let fn_arguments = parse_tool_arguments(&fn_name, Value::String(arguments), false)?;
 
 				let tool_call = ToolCall {
 					call_id,
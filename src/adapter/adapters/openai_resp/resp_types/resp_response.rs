@@ -30,4 +30,8 @@ pub struct RespResponse {
 	pub output: Vec<Value>,
 
 	pub usage: Option<RespUsage>,
+
+	/// The processing tier used for the request (e.g., default, flex, priority).
+	#[serde(default)]
+	pub service_tier: Option<String>,
 }
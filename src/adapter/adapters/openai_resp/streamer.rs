@@ -1,4 +1,4 @@
-use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions, parse_tool_arguments};
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
 use crate::adapter::openai_resp::resp_types::{RespResponse, parse_resp_output};
 use crate::chat::{ChatOptionsSet, ContentPart, StopReason, ToolCall};
@@ -123,17 +123,14 @@ impl OpenAIRespStreamer {
 		}
 	}
 
-	fn take_tool_calls(&mut self) -> Vec<ToolCall> {
+	fn take_tool_calls(&mut self) -> Result<Vec<ToolCall>> {
 		let mut tool_calls = Vec::new();
 		for (_, mut tc) in std::mem::take(&mut self.in_progress_tool_calls) {
-			if let Some(args_str) = tc.fn_arguments.as_str()
-				&& let Ok(args_val) = serde_json::from_str(args_str)
-			{
-				tc.fn_arguments = args_val;
-			}
+			let fn_arguments = std::mem::take(&mut tc.fn_arguments);
+			tc.fn_arguments = parse_tool_arguments(&tc.fn_name, fn_arguments, self.options.lenient_tool_arguments)?;
 			tool_calls.push(tc);
 		}
-		tool_calls
+		Ok(tool_calls)
 	}
 
 	fn finalize_output_capture(&mut self, response_output: Option<Vec<Value>>) -> Result<FinalOutputCapture> {
@@ -146,7 +143,7 @@ impl OpenAIRespStreamer {
 		});
 
 		let mut parsed_output = parse_resp_output(output_items)?;
-		let fallback_tool_calls = self.take_tool_calls();
+		let fallback_tool_calls = self.take_tool_calls()?;
 		let existing_tool_call_ids = parsed_output
 			.content
 			.iter()
@@ -378,6 +375,7 @@ impl futures::Stream for OpenAIRespStreamer {
 								captured_tool_calls: None,
 								captured_thought_signatures: final_output.thought_signatures,
 								captured_response_id: Some(response.id),
+								interrupted_by: None,
 							};
 
 							return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
@@ -411,6 +409,7 @@ impl futures::Stream for OpenAIRespStreamer {
 								captured_tool_calls: None,
 								captured_thought_signatures: final_output.thought_signatures,
 								captured_response_id: Some(resp_id),
+								interrupted_by: None,
 							};
 
 							return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
@@ -423,11 +422,16 @@ impl futures::Stream for OpenAIRespStreamer {
 				}
 				Some(Err(err)) => {
 					tracing::error!("Error: {}", err);
-					return Poll::Ready(Some(Err(Error::WebStream {
+					let error = Error::WebStream {
 						model_iden: self.options.model_iden.clone(),
 						cause: err.to_string(),
 						error: err,
-					})));
+					};
+					// End with the partial data captured so far, if any (the error follows, see `ChatStream`)
+					self.done = true;
+					return Poll::Ready(Some(
+						self.captured_data.take_interrupted_end(error).map(InterStreamEvent::End),
+					));
 				}
 				None => {
 					if !self.done {
@@ -445,6 +449,7 @@ impl futures::Stream for OpenAIRespStreamer {
 							captured_tool_calls: None,
 							captured_thought_signatures: final_output.thought_signatures,
 							captured_response_id: None,
+							interrupted_by: None,
 						};
 						return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
 					}
@@ -0,0 +1,197 @@
+use super::bce_auth::get_bearer_token;
+use crate::adapter::ModelCapabilities;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::resolver::{self, AuthData, Endpoint};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Error, Model, ModelIden, ModelName};
+use crate::{Result, ServiceTarget};
+use reqwest::RequestBuilder;
+use std::collections::HashMap;
+
+/// Baidu Qianfan (ERNIE) through its OpenAI compatible v2 API.
+///
+/// Auth is either:
+/// - An API key (`QIANFAN_API_KEY`), sent as is as the bearer token.
+/// - An Access Key / Secret Key pair (`QIANFAN_ACCESS_KEY` / `QIANFAN_SECRET_KEY`, or `AuthData::MultiKeys`
+///   with the `access_key` and `secret_key` keys), exchanged for a cached bearer token before each call.
+pub struct QianfanAdapter;
+
+// Qianfan does not expose a `/models` endpoint on its OpenAI compatible API - newer on top
+pub(in crate::adapter) const MODELS: &[&str] = &[
+	"ernie-4.5-turbo-128k",
+	"ernie-4.5-turbo-32k",
+	"ernie-4.5-8k-preview",
+	"ernie-x1-turbo-32k", // Reasoning model
+	"ernie-4.0-turbo-128k",
+	"ernie-4.0-turbo-8k",
+	"ernie-4.0-8k",
+	"ernie-speed-128k",
+	"ernie-lite-8k",
+];
+
+impl QianfanAdapter {
+	pub const API_KEY_DEFAULT_ENV_NAME: &str = "QIANFAN_API_KEY";
+	pub const ACCESS_KEY_ENV_NAME: &str = "QIANFAN_ACCESS_KEY";
+	pub const SECRET_KEY_ENV_NAME: &str = "QIANFAN_SECRET_KEY";
+
+	/// `AuthData::MultiKeys` key names for the AK/SK pair.
+	pub const ACCESS_KEY_NAME: &str = "access_key";
+	pub const SECRET_KEY_NAME: &str = "secret_key";
+}
+
+impl Adapter for QianfanAdapter {
+	const DEFAULT_API_KEY_ENV_NAME: Option<&'static str> = Some(Self::API_KEY_DEFAULT_ENV_NAME);
+
+	fn default_endpoint() -> Endpoint {
+		const BASE_URL: &str = "https://qianfan.baidubce.com/v2/";
+		Endpoint::from_static(BASE_URL)
+	}
+
+	/// The API key env takes precedence; otherwise the AK/SK envs are used when both are set.
+	fn default_auth() -> AuthData {
+		if std::env::var(Self::API_KEY_DEFAULT_ENV_NAME).is_err()
+			&& let (Ok(access_key), Ok(secret_key)) = (
+				std::env::var(Self::ACCESS_KEY_ENV_NAME),
+				std::env::var(Self::SECRET_KEY_ENV_NAME),
+			) {
+			return AuthData::from_multi(HashMap::from([
+				(Self::ACCESS_KEY_NAME.to_string(), access_key),
+				(Self::SECRET_KEY_NAME.to_string(), secret_key),
+			]));
+		}
+
+		match Self::DEFAULT_API_KEY_ENV_NAME {
+			Some(env_name) => AuthData::from_env(env_name),
+			None => AuthData::None,
+		}
+	}
+
+	async fn all_model_names(_kind: AdapterKind, _endpoint: Endpoint, _auth: AuthData) -> Result<Vec<String>> {
+		Ok(MODELS.iter().map(|s| s.to_string()).collect())
+	}
+
+	async fn all_models(kind: AdapterKind, _target: ServiceTarget, _web_client: &WebClient) -> Result<Vec<Model>> {
+		Ok(MODELS.iter().map(|model_id| Self::to_model(kind, model_id)).collect())
+	}
+
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		OpenAIAdapter::util_get_service_url(model, service_type, endpoint)
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		chat_options: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, None)
+	}
+
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		OpenAIAdapter::to_chat_response(model_iden, web_response, options_set)
+	}
+
+	fn to_chat_stream(
+		model_iden: ModelIden,
+		reqwest_builder: RequestBuilder,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		OpenAIAdapter::to_chat_stream(model_iden, reqwest_builder, options_set)
+	}
+
+	fn to_embed_request_data(
+		service_target: ServiceTarget,
+		embed_req: EmbedRequest,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		OpenAIAdapter::to_embed_request_data(service_target, embed_req, options_set)
+	}
+
+	fn to_embed_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		OpenAIAdapter::to_embed_response(model_iden, web_response, options_set)
+	}
+}
+
+// region:    --- Auth
+
+impl QianfanAdapter {
+	/// Exchanges an AK/SK `AuthData::MultiKeys` for a (cached) bearer token `AuthData::Key`.
+	/// Any other `AuthData` is returned as is.
+	pub(in crate::adapter) async fn resolve_bearer_auth(model_iden: &ModelIden, auth: AuthData) -> Result<AuthData> {
+		let AuthData::MultiKeys(keys) = auth else {
+			return Ok(auth);
+		};
+
+		let get_key = |name: &str| {
+			keys.get(name).ok_or_else(|| Error::Resolver {
+				model_iden: model_iden.clone(),
+				resolver_error: resolver::Error::Custom(format!("Qianfan AuthData::MultiKeys is missing '{name}'")),
+			})
+		};
+		let access_key = get_key(Self::ACCESS_KEY_NAME)?;
+		let secret_key = get_key(Self::SECRET_KEY_NAME)?;
+
+		let token = get_bearer_token(access_key, secret_key).await?;
+
+		Ok(AuthData::from_single(token))
+	}
+}
+
+// endregion: --- Auth
+
+// region:    --- Support Functions
+
+impl QianfanAdapter {
+	fn to_model(kind: AdapterKind, model_id: &str) -> Model {
+		let model_name: ModelName = model_id.to_string().into();
+		let (max_input_tokens, max_output_tokens) = ModelCapabilities::infer_token_limits(kind, model_id);
+
+		Model::new(model_name, model_id)
+			.with_max_input_tokens(max_input_tokens)
+			.with_max_output_tokens(max_output_tokens)
+			.with_streaming(ModelCapabilities::supports_streaming(kind, model_id))
+			.with_tool_calls(ModelCapabilities::supports_tool_calls(kind, model_id))
+			.with_json_mode(ModelCapabilities::supports_json_mode(kind, model_id))
+			.with_reasoning(ModelCapabilities::supports_reasoning(kind, model_id))
+			.with_input_modalities(ModelCapabilities::infer_input_modalities(kind, model_id))
+			.with_output_modalities(ModelCapabilities::infer_output_modalities(kind, model_id))
+	}
+}
+
+// endregion: --- Support Functions
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_resolve_bearer_auth_passthrough_and_missing_keys() {
+		let model_iden = ModelIden::new(AdapterKind::Qianfan, "ernie-4.0-8k");
+
+		// API key auth is left untouched (no token exchange)
+		let auth = QianfanAdapter::resolve_bearer_auth(&model_iden, AuthData::from_single("bce-v3/key"))
+			.await
+			.expect("passthrough");
+		assert!(matches!(auth, AuthData::Key(ref key) if key == "bce-v3/key"));
+
+		// Incomplete AK/SK fails before any network call
+		let keys = HashMap::from([(QianfanAdapter::ACCESS_KEY_NAME.to_string(), "ak".to_string())]);
+		let res = QianfanAdapter::resolve_bearer_auth(&model_iden, AuthData::from_multi(keys)).await;
+		assert!(matches!(res, Err(Error::Resolver { .. })));
+	}
+}
+
+// endregion: --- Tests
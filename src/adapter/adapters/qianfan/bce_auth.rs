@@ -0,0 +1,173 @@
+//! Bearer token exchange for the Qianfan v2 API.
+//!
+//! Qianfan accepts either an API key (used as is) or a bearer token minted by the BCE IAM service
+//! from an Access Key / Secret Key pair. The IAM call is signed with `bce-auth-v1`, and minted tokens
+//! are cached in memory (per AK/SK) until shortly before they expire.
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::format_utc_timestamp;
+use crate::webc::WebClient;
+use crate::{Error, Headers, Result};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use value_ext::JsonValueExt;
+
+const IAM_HOST: &str = "iam.bj.baidubce.com";
+const IAM_TOKEN_PATH: &str = "/v1/BCE-BEARER/token";
+
+/// Lifetime requested for the minted bearer token.
+const TOKEN_EXPIRE_IN_SECS: u64 = 86_400;
+
+/// Validity of the signature of the IAM request itself.
+const SIGNATURE_EXPIRATION_SECS: u64 = 1_800;
+
+/// Cached tokens are refreshed this long before they expire.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(300);
+
+// region:    --- Token Cache
+
+struct CachedToken {
+	token: String,
+	expires_at: Instant,
+}
+
+/// Keyed by `(access_key, secret_key)`, so rotating the secret key mints a new token.
+static TOKEN_CACHE: LazyLock<Mutex<HashMap<(String, String), CachedToken>>> =
+	LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn get_cached_token(access_key: &str, secret_key: &str, now: Instant) -> Option<String> {
+	let cache = TOKEN_CACHE.lock().ok()?;
+	cache
+		.get(&(access_key.to_string(), secret_key.to_string()))
+		.filter(|cached| cached.expires_at > now + TOKEN_REFRESH_MARGIN)
+		.map(|cached| cached.token.clone())
+}
+
+fn set_cached_token(access_key: &str, secret_key: &str, token: String, expires_at: Instant) {
+	if let Ok(mut cache) = TOKEN_CACHE.lock() {
+		cache.insert(
+			(access_key.to_string(), secret_key.to_string()),
+			CachedToken { token, expires_at },
+		);
+	}
+}
+
+// endregion: --- Token Cache
+
+// region:    --- Token Exchange
+
+/// Returns a bearer token for the given AK/SK, from the cache or freshly minted by the BCE IAM service.
+pub(super) async fn get_bearer_token(access_key: &str, secret_key: &str) -> Result<String> {
+	if let Some(token) = get_cached_token(access_key, secret_key, Instant::now()) {
+		return Ok(token);
+	}
+
+	let requested_at = Instant::now();
+	let unix_secs = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or_default();
+	let authorization = sign_token_request(access_key, secret_key, &format_utc_timestamp(unix_secs));
+
+	let url = format!("https://{IAM_HOST}{IAM_TOKEN_PATH}?expireInSeconds={TOKEN_EXPIRE_IN_SECS}");
+	let headers = Headers::from(vec![
+		("Host".to_string(), IAM_HOST.to_string()),
+		("Authorization".to_string(), authorization),
+	]);
+
+	let mut web_response =
+		WebClient::default()
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: AdapterKind::Qianfan,
+				webc_error,
+			})?;
+	let token: String = web_response.body.x_take("token")?;
+
+	let expires_at = requested_at + Duration::from_secs(TOKEN_EXPIRE_IN_SECS);
+	set_cached_token(access_key, secret_key, token.clone(), expires_at);
+
+	Ok(token)
+}
+
+// endregion: --- Token Exchange
+
+// region:    --- Signing
+
+/// Builds the `bce-auth-v1` Authorization header value for the IAM bearer token request.
+///
+/// - `authStringPrefix` = `bce-auth-v1/{ak}/{timestamp}/{expiration}`
+/// - `signingKey` = hex(HMAC-SHA256(sk, authStringPrefix))
+/// - `signature` = hex(HMAC-SHA256(signingKey, canonicalRequest)), signing only the `host` header
+fn sign_token_request(access_key: &str, secret_key: &str, timestamp: &str) -> String {
+	let auth_string_prefix = format!("bce-auth-v1/{access_key}/{timestamp}/{SIGNATURE_EXPIRATION_SECS}");
+	let signing_key = hmac_sha256_hex(secret_key.as_bytes(), &auth_string_prefix);
+
+	let canonical_request = format!("GET\n{IAM_TOKEN_PATH}\nexpireInSeconds={TOKEN_EXPIRE_IN_SECS}\nhost:{IAM_HOST}");
+	let signature = hmac_sha256_hex(signing_key.as_bytes(), &canonical_request);
+
+	format!("{auth_string_prefix}/host/{signature}")
+}
+
+fn hmac_sha256_hex(key: &[u8], data: &str) -> String {
+	let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+	mac.update(data.as_bytes());
+	mac.finalize().into_bytes().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// endregion: --- Signing
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_hmac_sha256_hex_rfc4231() {
+		// RFC 4231 - Test Case 2
+		let hex = hmac_sha256_hex(b"Jefe", "what do ya want for nothing?");
+		assert_eq!(hex, "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+	}
+
+	#[test]
+	fn test_sign_token_request() {
+		let authorization = sign_token_request("test-ak", "test-sk", "2024-01-01T00:00:00Z");
+		assert_eq!(
+			authorization,
+			"bce-auth-v1/test-ak/2024-01-01T00:00:00Z/1800/host/242d42637d152ff0f9c30d39b2f9b9fa97af562e03f8f598b3875d952f5ad389"
+		);
+	}
+
+	#[test]
+	fn test_token_cache_refresh_margin() {
+		let now = Instant::now();
+		set_cached_token(
+			"cache-ak",
+			"cache-sk",
+			"fresh-token".to_string(),
+			now + Duration::from_secs(3_600),
+		);
+		assert_eq!(
+			get_cached_token("cache-ak", "cache-sk", now).as_deref(),
+			Some("fresh-token")
+		);
+		// another secret key does not share the token
+		assert_eq!(get_cached_token("cache-ak", "other-sk", now), None);
+
+		// within the refresh margin, the token is considered expired
+		set_cached_token(
+			"cache-ak",
+			"cache-sk",
+			"stale-token".to_string(),
+			now + Duration::from_secs(60),
+		);
+		assert_eq!(get_cached_token("cache-ak", "cache-sk", now), None);
+	}
+}
+
+// endregion: --- Tests
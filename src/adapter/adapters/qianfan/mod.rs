@@ -0,0 +1,13 @@
+//! API Documentation:     <https://cloud.baidu.com/doc/qianfan-api/s/3m7of64lb>
+//! Model Names:           <https://cloud.baidu.com/doc/qianfan-docs/s/7m95lyy43>
+//! Pricing:               <https://cloud.baidu.com/doc/qianfan-docs/s/Jm8r1826a>
+//! Auth (AK/SK):          <https://cloud.baidu.com/doc/IAM/s/Mm2x80phi>
+
+// region:    --- Modules
+
+mod adapter_impl;
+mod bce_auth;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
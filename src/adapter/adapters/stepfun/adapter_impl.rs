@@ -0,0 +1,158 @@
+use crate::adapter::ModelCapabilities;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::resolver::{AuthData, Endpoint};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Error, Headers, Result, ServiceTarget};
+use crate::{Model, ModelIden, ModelName};
+use reqwest::RequestBuilder;
+use serde_json::Value;
+use value_ext::JsonValueExt;
+
+pub struct StepFunAdapter;
+
+// Fallback list when the `/models` API is not reachable - newer on top
+pub(in crate::adapter) const MODELS: &[&str] = &[
+	"step-2-16k",         // Step 2 - 16K context
+	"step-2-mini",        // Step 2 Mini - 32K context
+	"step-1-8k",          // Step 1 - 8K context
+	"step-1-32k",         // Step 1 - 32K context
+	"step-1-256k",        // Step 1 - 256K context
+	"step-1o-vision-32k", // Step 1o Vision - 32K context
+	"step-1v-8k",         // Step 1V (vision) - 8K context
+	"step-1v-32k",        // Step 1V (vision) - 32K context
+];
+
+impl StepFunAdapter {
+	pub const API_KEY_DEFAULT_ENV_NAME: &str = "STEPFUN_API_KEY";
+}
+
+// The StepFun chat completions API is OpenAI compatible, so chat and stream are delegated to the OpenAI adapter.
+impl Adapter for StepFunAdapter {
+	const DEFAULT_API_KEY_ENV_NAME: Option<&'static str> = Some(Self::API_KEY_DEFAULT_ENV_NAME);
+
+	fn default_endpoint() -> Endpoint {
+		const BASE_URL: &str = "https://api.stepfun.com/v1/";
+		Endpoint::from_static(BASE_URL)
+	}
+
+	fn default_auth() -> AuthData {
+		match Self::DEFAULT_API_KEY_ENV_NAME {
+			Some(env_name) => AuthData::from_env(env_name),
+			None => AuthData::None,
+		}
+	}
+
+	async fn all_model_names(kind: AdapterKind, endpoint: Endpoint, auth: AuthData) -> Result<Vec<String>> {
+		match OpenAIAdapter::list_model_names_for_end_target(kind, endpoint, auth).await {
+			Ok(model_names) if !model_names.is_empty() => Ok(model_names),
+			_ => Ok(MODELS.iter().map(|s| s.to_string()).collect()),
+		}
+	}
+
+	async fn all_models(kind: AdapterKind, target: ServiceTarget, web_client: &WebClient) -> Result<Vec<Model>> {
+		let model_iden = ModelIden::new(kind, "temp");
+		let url = OpenAIAdapter::util_get_service_url(&model_iden, ServiceType::Models, target.endpoint)?;
+		let api_key = get_api_key(target.auth, &model_iden)?;
+		let headers = Headers::from(vec![("Authorization".to_string(), format!("Bearer {api_key}"))]);
+
+		// Use the live `/models` list when available, otherwise fall back to the static list
+		let model_ids = match web_client.do_get(&url, &headers).await {
+			Ok(web_response) => Self::parse_models_response(web_response).ok(),
+			Err(_) => None,
+		}
+		.unwrap_or_else(|| MODELS.iter().map(|s| s.to_string()).collect());
+
+		Ok(model_ids.into_iter().map(Self::to_model).collect())
+	}
+
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		OpenAIAdapter::util_get_service_url(model, service_type, endpoint)
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		chat_options: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, None)
+	}
+
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		OpenAIAdapter::to_chat_response(model_iden, web_response, options_set)
+	}
+
+	fn to_chat_stream(
+		model_iden: ModelIden,
+		reqwest_builder: RequestBuilder,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		OpenAIAdapter::to_chat_stream(model_iden, reqwest_builder, options_set)
+	}
+
+	fn to_embed_request_data(
+		_service_target: ServiceTarget,
+		_embed_req: EmbedRequest,
+		_options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		Err(Error::service_not_supported(AdapterKind::StepFun, ServiceType::Embed))
+	}
+
+	fn to_embed_response(
+		_model_iden: ModelIden,
+		_web_response: WebResponse,
+		_options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		Err(Error::service_not_supported(AdapterKind::StepFun, ServiceType::Embed))
+	}
+}
+
+// region:    --- Support Functions
+
+impl StepFunAdapter {
+	/// Parse the OpenAI-compatible `{"data": [{"id": "..."}, ...]}` models response, keeping only the Step chat models.
+	fn parse_models_response(mut web_response: WebResponse) -> Result<Vec<String>> {
+		let models_array: Vec<Value> = web_response.body.x_take("data")?;
+
+		let model_ids: Vec<String> = models_array
+			.into_iter()
+			.filter_map(|mut model_data| model_data.x_take::<String>("id").ok())
+			.filter(|model_id| model_id.starts_with("step-") && !model_id.contains("tts") && !model_id.contains("asr"))
+			.collect();
+
+		// Empty list triggers the fallback to the static list
+		if model_ids.is_empty() {
+			return Err(Error::InvalidJsonResponseElement {
+				info: "No valid StepFun models found in API response",
+			});
+		}
+
+		Ok(model_ids)
+	}
+
+	fn to_model(model_id: String) -> Model {
+		let kind = AdapterKind::StepFun;
+		let model_name: ModelName = model_id.clone().into();
+		let (max_input_tokens, max_output_tokens) = ModelCapabilities::infer_token_limits(kind, &model_id);
+
+		Model::new(model_name, model_id.clone())
+			.with_max_input_tokens(max_input_tokens)
+			.with_max_output_tokens(max_output_tokens)
+			.with_streaming(ModelCapabilities::supports_streaming(kind, &model_id))
+			.with_tool_calls(ModelCapabilities::supports_tool_calls(kind, &model_id))
+			.with_json_mode(ModelCapabilities::supports_json_mode(kind, &model_id))
+			.with_reasoning(ModelCapabilities::supports_reasoning(kind, &model_id))
+			.with_input_modalities(ModelCapabilities::infer_input_modalities(kind, &model_id))
+			.with_output_modalities(ModelCapabilities::infer_output_modalities(kind, &model_id))
+	}
+}
+
+// endregion: --- Support Functions
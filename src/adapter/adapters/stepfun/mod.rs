@@ -0,0 +1,11 @@
+//! API Documentation:     <https://platform.stepfun.com/docs/api-reference/chat/chat-completion-create>
+//! Model Names:           <https://platform.stepfun.com/docs/llm/text>
+//! Pricing:               <https://platform.stepfun.com/docs/pricing/details>
+
+// region:    --- Modules
+
+mod adapter_impl;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
@@ -1,10 +1,16 @@
 //! This support module is for common constructs and utilities for all the adapter implementations.
 //! It should be private to the `crate::adapter::adapters` module.
 
-use crate::ModelIden;
-use crate::chat::{ChatOptionsSet, Usage};
+use crate::adapter::AdapterKind;
+use crate::adapter::inter_stream::InterStreamEnd;
+use crate::chat::{ChatMessage, ChatOptionsSet, IgnoredOption, StopReason, Tool, ToolCall, ToolName, Usage};
 use crate::resolver::AuthData;
+use crate::webc::Message;
 use crate::{Error, Result};
+use crate::{Model, ModelIden};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 pub fn get_api_key(auth: AuthData, model: &ModelIden) -> Result<String> {
 	auth.single_key_value().map_err(|resolver_error| Error::Resolver {
@@ -13,14 +19,232 @@ pub fn get_api_key(auth: AuthData, model: &ModelIden) -> Result<String> {
 	})
 }
 
+// region:    --- Service Url
+
+/// Joins a service `suffix` (e.g., `chat/completions`) to a `base_url`, keeping all the base url path segments.
+///
+/// - The base url path is normalized to end with `/` (so `https://proxy.corp/llm/openai/v1` does not lose `v1`).
+/// - The suffix is always joined as relative (leading `/` are ignored).
+/// - The eventual base url query params are preserved on the final url.
+pub fn join_service_url(base_url: &str, suffix: &str) -> Result<String> {
+	let mut url = reqwest::Url::parse(base_url).map_err(|err| Error::InvalidEndpoint {
+		url: base_url.to_string(),
+		cause: err.to_string(),
+	})?;
+	let original_query_params = url.query().map(|query| query.to_string());
+	url.set_query(None);
+	url.set_fragment(None);
+
+	if !url.path().ends_with('/') {
+		let path = format!("{}/", url.path());
+		url.set_path(&path);
+	}
+
+	let suffix = suffix.trim_start_matches('/');
+	let mut full_url = url.join(suffix).map_err(|err| Error::InvalidEndpoint {
+		url: base_url.to_string(),
+		cause: format!("Cannot join suffix '{suffix}'. Cause: {err}"),
+	})?;
+	full_url.set_query(original_query_params.as_deref());
+
+	Ok(full_url.to_string())
+}
+
+// endregion: --- Service Url
+
+// region:    --- Model Records
+
+/// Deprecation fields found in the provider model records, in order of precedence.
+/// Values can be a bool, a date string, or unix seconds.
+const DEPRECATION_KEYS: &[&str] = &[
+	"deprecation_date",
+	"deprecated_at",
+	"deprecated",
+	"is_deprecated",
+	"sunset_date",
+	"shutdown_date",
+	"expiration_date",
+];
+
+/// Sets the `Model` deprecation fields from a provider model record (e.g., one entry of a `/models` listing).
+///
+/// A `true` bool flags the model as deprecated, and a date (string or unix seconds) also sets the `deprecation_date`.
+pub fn with_model_deprecation(mut model: Model, model_data: &Value) -> Model {
+	for key in DEPRECATION_KEYS {
+		match model_data.get(*key) {
+			Some(Value::Bool(true)) => model = model.with_deprecated(true),
+			Some(Value::String(date)) if !date.is_empty() && model.deprecation_date.is_none() => {
+				model = model.with_deprecation_date(date.as_str());
+			}
+			Some(Value::Number(num)) if model.deprecation_date.is_none() => {
+				if let Some(secs) = num.as_u64() {
+					model = model.with_deprecation_date(format_utc_timestamp(secs));
+				}
+			}
+			_ => (),
+		}
+	}
+	model
+}
+
+/// Formats unix seconds as an UTC `yyyy-MM-ddTHH:mm:ssZ` timestamp.
+pub fn format_utc_timestamp(unix_secs: u64) -> String {
+	// Civil date from days since epoch (Howard Hinnant's `civil_from_days`)
+	let days = (unix_secs / 86_400) as i64;
+	let secs_of_day = unix_secs % 86_400;
+
+	let z = days + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+	format!(
+		"{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+		secs_of_day / 3_600,
+		secs_of_day % 3_600 / 60,
+		secs_of_day % 60
+	)
+}
+
+// endregion: --- Model Records
+
+// region:    --- Tool Names
+
+/// Validates the custom tool names against the OpenAI constraint `^[a-zA-Z0-9_-]{1,64}$`,
+/// and checks there are no duplicate names.
+///
+/// Built-in tools (e.g., `ToolName::WebSearch`) are not checked.
+pub fn validate_openai_tool_names(model_iden: &ModelIden, tools: Option<&[Tool]>) -> Result<()> {
+	let Some(tools) = tools else {
+		return Ok(());
+	};
+
+	let invalid = |tool_name: &str, cause: &'static str| Error::InvalidToolName {
+		model_iden: model_iden.clone(),
+		tool_name: tool_name.to_string(),
+		cause,
+	};
+
+	for (idx, tool) in tools.iter().enumerate() {
+		let ToolName::Custom(name) = &tool.name else {
+			continue;
+		};
+		if name.is_empty() || name.len() > 64 {
+			return Err(invalid(name, "tool name must be 1 to 64 characters long"));
+		}
+		if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+			return Err(invalid(
+				name,
+				"tool name must only contain ASCII letters, digits, '_' or '-'",
+			));
+		}
+		if tools[..idx].iter().any(|prev| prev.name == tool.name) {
+			return Err(invalid(name, "duplicate tool name"));
+		}
+	}
+
+	Ok(())
+}
+
+// endregion: --- Tool Names
+
+// region:    --- Tool Arguments
+
+/// Normalizes the provider tool call `arguments` to a JSON object value, the same way for stream and non-stream paths.
+///
+/// - Object: returned as is.
+/// - String: parsed as JSON (an empty string is `{}`).
+/// - Null: `{}` (tool without arguments).
+/// - Otherwise (or malformed JSON), `Error::InvalidToolArguments`, unless `lenient`,
+///   in which case the original value is returned (e.g., the raw string).
+pub fn parse_tool_arguments(fn_name: &str, arguments: Value, lenient: bool) -> Result<Value> {
+	let invalid = |arguments: Value, cause: String| {
+		if lenient {
+			Ok(arguments)
+		} else {
+			Err(Error::InvalidToolArguments {
+				fn_name: fn_name.to_string(),
+				arguments: match arguments {
+					Value::String(txt) => txt,
+					other => other.to_string(),
+				},
+				cause,
+			})
+		}
+	};
+
+	match arguments {
+		Value::Object(_) => Ok(arguments),
+		Value::Null => Ok(Value::Object(Map::new())),
+		Value::String(txt) if txt.trim().is_empty() => Ok(Value::Object(Map::new())),
+		Value::String(txt) => match serde_json::from_str::<Value>(&txt) {
+			Ok(value @ Value::Object(_)) => Ok(value),
+			Ok(Value::Null) => Ok(Value::Object(Map::new())),
+			Ok(_) => invalid(Value::String(txt), "arguments is not a JSON object".to_string()),
+			Err(err) => invalid(Value::String(txt), err.to_string()),
+		},
+		other => invalid(other, "arguments is not a JSON object".to_string()),
+	}
+}
+
+// endregion: --- Tool Arguments
+
+// region:    --- Tool Call Replay
+
+/// Returns the call id for a provider tool call without id (e.g., Gemini, Cohere v1), as `call#{fn_name}#{idx}`,
+/// with `idx` the index of the tool call in the response.
+///
+/// Deterministic, so that a replayed transcript maps the tool responses back to their function name
+/// (see `tool_response_fn_name`).
+pub fn synthesize_call_id(fn_name: &str, idx: usize) -> String {
+	format!("call#{fn_name}#{idx}")
+}
+
+/// Indexes the tool calls of the chat messages by call id, for the providers referencing the tool calls
+/// by function name in the tool responses (e.g., Gemini, Cohere v1).
+pub fn tool_calls_by_call_id(messages: &[ChatMessage]) -> HashMap<String, ToolCall> {
+	messages
+		.iter()
+		.flat_map(|msg| msg.content.tool_calls())
+		.map(|tool_call| (tool_call.call_id.clone(), tool_call.clone()))
+		.collect()
+}
+
+/// Returns the function name of the tool call answered by a tool response.
+///
+/// - The tool call with this `call_id` in the transcript (e.g., an OpenAI `call_abc123` id).
+/// - Otherwise, the name in a synthesized `call#{fn_name}#{idx}` id.
+/// - Otherwise, the `call_id` itself (user provided name).
+pub fn tool_response_fn_name<'a>(call_id: &'a str, tool_calls: &'a HashMap<String, ToolCall>) -> &'a str {
+	if let Some(tool_call) = tool_calls.get(call_id) {
+		return &tool_call.fn_name;
+	}
+	call_id
+		.strip_prefix("call#")
+		.and_then(|rest| rest.rsplit_once('#'))
+		.map(|(fn_name, _idx)| fn_name)
+		.filter(|fn_name| !fn_name.is_empty())
+		.unwrap_or(call_id)
+}
+
+// endregion: --- Tool Call Replay
+
 // region:    --- StreamerChatOptions
 
+/// The options of the streamers, owned (copied from the `ChatOptionsSet`),
+/// so the streams do not borrow the request options and `ChatStream` stays `Send + 'static`.
 #[derive(Debug)]
 pub struct StreamerOptions {
 	pub capture_usage: bool,
 	pub capture_reasoning_content: bool,
 	pub capture_content: bool,
 	pub capture_tool_calls: bool,
+	pub lenient_tool_arguments: bool,
 	pub model_iden: ModelIden,
 }
 
@@ -31,6 +255,7 @@ impl StreamerOptions {
 			capture_content: options_set.capture_content().unwrap_or(false),
 			capture_reasoning_content: options_set.capture_reasoning_content().unwrap_or(false),
 			capture_tool_calls: options_set.capture_tool_calls().unwrap_or(false),
+			lenient_tool_arguments: options_set.lenient_tool_arguments().unwrap_or(false),
 			model_iden,
 		}
 	}
@@ -50,4 +275,451 @@ pub struct StreamerCapturedData {
 	pub thought_signatures: Option<Vec<String>>,
 }
 
+impl StreamerCapturedData {
+	/// Takes the data captured so far into the `InterStreamEnd` of a stream interrupted by `error`,
+	/// so the partial content is not lost (see `StreamEnd::incomplete`).
+	///
+	/// Returns the `error` itself when no content, reasoning, or tool calls were captured
+	/// (nothing to end with, so the consumers stopping at the end event still see the error).
+	///
+	/// Tool call arguments are parsed leniently, since the last ones may be truncated.
+	pub fn take_interrupted_end(&mut self, error: Error) -> Result<InterStreamEnd> {
+		if self.content.is_none() && self.reasoning_content.is_none() && self.tool_calls.is_none() {
+			return Err(error);
+		}
+
+		let captured_tool_calls = self.tool_calls.take().map(|tool_calls| {
+			tool_calls
+				.into_iter()
+				.map(|mut tool_call| {
+					let fn_arguments = std::mem::take(&mut tool_call.fn_arguments);
+					tool_call.fn_arguments =
+						parse_tool_arguments(&tool_call.fn_name, fn_arguments, true).unwrap_or_default();
+					tool_call
+				})
+				.collect()
+		});
+
+		Ok(InterStreamEnd {
+			captured_usage: self.usage.take(),
+			captured_stop_reason: self.stop_reason.take().map(StopReason::from),
+			captured_text_content: self.content.take(),
+			captured_content_parts: None,
+			captured_reasoning_content: self.reasoning_content.take(),
+			captured_tool_calls,
+			captured_thought_signatures: self.thought_signatures.take(),
+			captured_response_id: None,
+			interrupted_by: Some(Box::new(error)),
+		})
+	}
+}
+
 // endregion: --- Streamer Captured Data
+
+// region:    --- Ignored Options
+
+/// The `ChatOptions` sent in the provider payload (the other options are applied client-side),
+/// checked by `ignored_options`.
+pub const PAYLOAD_OPTIONS: &[&str] = &[
+	"temperature",
+	"max_tokens",
+	"min_tokens",
+	"top_p",
+	"top_k",
+	"stop_sequences",
+	"seed",
+	"n",
+	"response_format",
+	"reasoning_effort",
+	"include_thoughts",
+	"verbosity",
+	"service_tier",
+	"sampling_extras",
+	"tool_choice",
+	"parallel_tool_calls",
+	"cache_control",
+	"prompt_cache_key",
+	"cached_content",
+	"anthropic_version",
+	"anthropic_betas",
+];
+
+/// Returns the payload options set in `options_set` but not in the adapter `supported` ones,
+/// as ignored (traced at debug level by the client).
+pub fn ignored_options(
+	options_set: &ChatOptionsSet,
+	supported: &[&str],
+	adapter_kind: AdapterKind,
+) -> Vec<IgnoredOption> {
+	PAYLOAD_OPTIONS
+		.iter()
+		.filter(|option| !supported.contains(option) && is_option_set(options_set, option))
+		.map(|option| IgnoredOption::new(*option, format!("not supported by the {adapter_kind} adapter")))
+		.collect()
+}
+
+/// Returns true when the payload option (a `PAYLOAD_OPTIONS` name) is set in the chat or client options.
+pub fn is_option_set(options_set: &ChatOptionsSet, option: &str) -> bool {
+	match option {
+		"temperature" => options_set.temperature().is_some(),
+		"max_tokens" => options_set.max_tokens().is_some(),
+		"min_tokens" => options_set.min_tokens().is_some(),
+		"top_p" => options_set.top_p().is_some(),
+		"top_k" => options_set.top_k().is_some(),
+		"stop_sequences" => !options_set.stop_sequences().is_empty(),
+		"seed" => options_set.seed().is_some(),
+		"n" => options_set.n().is_some(),
+		"response_format" => options_set.response_format().is_some(),
+		"reasoning_effort" => options_set.reasoning_effort().is_some(),
+		"include_thoughts" => options_set.include_thoughts().is_some(),
+		"verbosity" => options_set.verbosity().is_some(),
+		"service_tier" => options_set.service_tier().is_some(),
+		"sampling_extras" => options_set.sampling_extras().is_some(),
+		"tool_choice" => options_set.tool_choice().is_some(),
+		"parallel_tool_calls" => options_set.parallel_tool_calls().is_some(),
+		"cache_control" => options_set.cache_control().is_some(),
+		"prompt_cache_key" => options_set.prompt_cache_key().is_some(),
+		"cached_content" => options_set.cached_content().is_some(),
+		"anthropic_version" => options_set.anthropic_version().is_some(),
+		"anthropic_betas" => options_set.anthropic_betas().is_some(),
+		_ => false,
+	}
+}
+
+// endregion: --- Ignored Options
+
+// region:    --- SSE Event Type
+
+/// The SSE event name when none is set (`event:` line), per the EventSource spec.
+const SSE_DEFAULT_EVENT: &str = "message";
+
+/// Returns the type of an SSE message, for the streamers dispatching on it (e.g., Anthropic `content_block_delta`).
+///
+/// - The SSE `event:` name when set.
+/// - Otherwise, the `type` property of the JSON data (some providers and proxies only send `data:` lines).
+/// - Otherwise, `"message"` (e.g., OpenAI `data:` only frames, or `[DONE]`).
+pub fn sse_event_type(message: &Message) -> Cow<'_, str> {
+	if message.event != SSE_DEFAULT_EVENT {
+		return Cow::Borrowed(message.event.as_str());
+	}
+
+	match serde_json::from_str::<Value>(&message.data) {
+		Ok(Value::Object(mut data)) => match data.remove("type") {
+			Some(Value::String(data_type)) => Cow::Owned(data_type),
+			_ => Cow::Borrowed(SSE_DEFAULT_EVENT),
+		},
+		_ => Cow::Borrowed(SSE_DEFAULT_EVENT),
+	}
+}
+
+// endregion: --- SSE Event Type
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_join_service_url_bases() -> Result<()> {
+		let cases = [
+			(
+				"https://api.openai.com/v1/",
+				"https://api.openai.com/v1/chat/completions",
+			),
+			(
+				"https://api.openai.com/v1",
+				"https://api.openai.com/v1/chat/completions",
+			),
+			(
+				"https://api.githubcopilot.com",
+				"https://api.githubcopilot.com/chat/completions",
+			),
+			(
+				"https://api.githubcopilot.com/",
+				"https://api.githubcopilot.com/chat/completions",
+			),
+			(
+				"https://proxy.corp/llm/openai/v1",
+				"https://proxy.corp/llm/openai/v1/chat/completions",
+			),
+			(
+				"https://proxy.corp/llm/openai/v1/",
+				"https://proxy.corp/llm/openai/v1/chat/completions",
+			),
+			(
+				"https://proxy.corp/llm/openai/v1?tenant=acme",
+				"https://proxy.corp/llm/openai/v1/chat/completions?tenant=acme",
+			),
+			(
+				"https://proxy.corp/llm/openai/v1/?tenant=acme&x=1",
+				"https://proxy.corp/llm/openai/v1/chat/completions?tenant=acme&x=1",
+			),
+		];
+
+		for (base_url, expected) in cases {
+			assert_eq!(
+				join_service_url(base_url, "chat/completions")?,
+				expected,
+				"base_url: {base_url}"
+			);
+			assert_eq!(
+				join_service_url(base_url, "/chat/completions")?,
+				expected,
+				"base_url: {base_url}"
+			);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_ignored_options_per_adapter() -> Result<()> {
+		use crate::ServiceTarget;
+		use crate::adapter::{AdapterDispatcher, ApiFamily, ServiceType};
+		use crate::chat::{
+			CacheControl, ChatOptions, ChatRequest, ChatResponseFormat, ReasoningEffort, SamplingExtras, ServiceTier,
+			Tool, ToolChoice, Verbosity,
+		};
+
+		// -- Every payload option set
+		let options = ChatOptions::default()
+			.with_temperature(0.2)
+			.with_max_tokens(256)
+			.with_min_tokens(8)
+			.with_top_p(0.9)
+			.with_top_k(40)
+			.with_stop_sequences(vec!["END".to_string()])
+			.with_seed(42)
+			.with_n(1)
+			.with_response_format(ChatResponseFormat::JsonMode)
+			.with_reasoning_effort(ReasoningEffort::Low)
+			.with_include_thoughts(true)
+			.with_verbosity(Verbosity::Low)
+			.with_service_tier(ServiceTier::Flex)
+			.with_sampling_extras(SamplingExtras::default().with_min_p(0.05))
+			.with_tool_choice(ToolChoice::Auto)
+			.with_parallel_tool_calls(false)
+			.with_cache_control(CacheControl::Ephemeral)
+			.with_prompt_cache_key("key-1")
+			.with_cached_content("cachedContents/abc")
+			.with_anthropic_version("2023-06-01")
+			.with_anthropic_betas(vec!["token-counting-2024-11-01".to_string()]);
+		let options_set = ChatOptionsSet::default().with_chat_options(Some(&options));
+		for option in PAYLOAD_OPTIONS {
+			assert!(is_option_set(&options_set, option), "option not set: {option}");
+		}
+
+		// -- The exact ignored options of some adapters
+		let cases: &[(AdapterKind, &[&str])] = &[
+			(
+				AdapterKind::Anthropic,
+				&[
+					"min_tokens",
+					"seed",
+					"n",
+					"include_thoughts",
+					"verbosity",
+					"service_tier",
+					"sampling_extras",
+					"prompt_cache_key",
+					"cached_content",
+					"cache_control",
+					"response_format",
+				],
+			),
+			(
+				AdapterKind::OpenAI,
+				&[
+					"min_tokens",
+					"top_k",
+					"include_thoughts",
+					"sampling_extras",
+					"cached_content",
+					"anthropic_version",
+					"anthropic_betas",
+				],
+			),
+			(
+				AdapterKind::Cohere,
+				&[
+					"min_tokens",
+					"seed",
+					"n",
+					"response_format",
+					"reasoning_effort",
+					"include_thoughts",
+					"verbosity",
+					"service_tier",
+					"sampling_extras",
+					"tool_choice",
+					"parallel_tool_calls",
+					"cache_control",
+					"prompt_cache_key",
+					"cached_content",
+					"anthropic_version",
+					"anthropic_betas",
+				],
+			),
+		];
+
+		for adapter_kind in AdapterKind::ALL.iter().copied() {
+			#[cfg(feature = "mock")]
+			if matches!(adapter_kind, AdapterKind::Mock) {
+				continue;
+			}
+			let target = ServiceTarget {
+				endpoint: AdapterDispatcher::default_endpoint(adapter_kind),
+				auth: AuthData::from_single("test-key"),
+				model: ModelIden::new(adapter_kind, "gemini-2.5-flash"),
+			};
+			let chat_req = ChatRequest::from_user("Hello").with_tools(vec![Tool::new("get_weather")]);
+			let data =
+				AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set.clone())?;
+			let ignored: Vec<&str> = data.ignored_options.iter().map(|ignored| ignored.option.as_str()).collect();
+
+			// each one a payload option, reported once
+			for (idx, option) in ignored.iter().enumerate() {
+				assert!(
+					PAYLOAD_OPTIONS.contains(option),
+					"{adapter_kind} unknown option: {option}"
+				);
+				assert!(
+					!ignored[..idx].contains(option),
+					"{adapter_kind} duplicate option: {option}"
+				);
+			}
+			// the provider specific options are ignored by the other providers
+			if adapter_kind.api_family() != ApiFamily::Anthropic {
+				assert!(ignored.contains(&"anthropic_version"), "{adapter_kind}: {ignored:?}");
+				assert!(ignored.contains(&"anthropic_betas"), "{adapter_kind}: {ignored:?}");
+			}
+			if !matches!(adapter_kind.api_family(), ApiFamily::Gemini | ApiFamily::Vertex) {
+				assert!(ignored.contains(&"cached_content"), "{adapter_kind}: {ignored:?}");
+			}
+
+			if let Some((_, expected)) = cases.iter().find(|(kind, _)| *kind == adapter_kind) {
+				assert_eq!(&ignored, expected, "adapter: {adapter_kind}");
+			}
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_response_fn_name() {
+		let tool_calls = tool_calls_by_call_id(&[ChatMessage::assistant(vec![ToolCall {
+			call_id: "call_abc123".to_string(),
+			fn_name: "get_weather".to_string(),
+			fn_arguments: serde_json::json!({"city": "Paris"}),
+			thought_signatures: None,
+		}])]);
+
+		assert_eq!(tool_response_fn_name("call_abc123", &tool_calls), "get_weather");
+		assert_eq!(
+			tool_response_fn_name(&synthesize_call_id("read_file", 1), &tool_calls),
+			"read_file"
+		);
+		assert_eq!(tool_response_fn_name("get_time", &tool_calls), "get_time");
+	}
+
+	#[test]
+	fn test_sse_event_type() {
+		let message = |event: &str, data: &str| Message {
+			event: event.to_string(),
+			data: data.to_string(),
+		};
+
+		// -- named events win over the data type
+		let named = message("content_block_delta", r#"{"type":"other","delta":{"text":"Hi"}}"#);
+		assert_eq!(sse_event_type(&named), "content_block_delta");
+
+		// -- data only frames fall back on the data type
+		let data_only = message("message", r#"{"type":"message_stop"}"#);
+		assert_eq!(sse_event_type(&data_only), "message_stop");
+
+		// -- otherwise, the default event name
+		assert_eq!(sse_event_type(&message("message", r#"{"choices":[]}"#)), "message");
+		assert_eq!(sse_event_type(&message("message", "[DONE]")), "message");
+		assert_eq!(sse_event_type(&message("message", r#"{"type":42}"#)), "message");
+	}
+
+	#[test]
+	fn test_join_service_url_invalid_base() {
+		let res = join_service_url("not a url", "models");
+		assert!(matches!(res, Err(Error::InvalidEndpoint { url, .. }) if url == "not a url"));
+	}
+
+	#[test]
+	fn test_with_model_deprecation_fields() {
+		let model = |data: Value| with_model_deprecation(Model::new("m", "m"), &data);
+
+		let res = model(serde_json::json!({"id": "m", "deprecation_date": "2025-06-30"}));
+		assert!(res.is_deprecated());
+		assert_eq!(res.deprecation_date.as_deref(), Some("2025-06-30"));
+
+		let res = model(serde_json::json!({"id": "m", "is_deprecated": true}));
+		assert!(res.deprecated);
+		assert_eq!(res.deprecation_date, None);
+
+		let res = model(serde_json::json!({"id": "m", "deprecated": true, "sunset_date": 1_700_000_000}));
+		assert!(res.is_deprecated());
+		assert_eq!(res.deprecation_date.as_deref(), Some("2023-11-14T22:13:20Z"));
+
+		let res = model(serde_json::json!({"id": "m", "deprecated": false, "deprecation_date": null}));
+		assert!(!res.is_deprecated());
+	}
+
+	#[test]
+	fn test_format_utc_timestamp() {
+		assert_eq!(format_utc_timestamp(0), "1970-01-01T00:00:00Z");
+		assert_eq!(format_utc_timestamp(1_700_000_000), "2023-11-14T22:13:20Z");
+		assert_eq!(format_utc_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+		assert_eq!(format_utc_timestamp(1_709_251_199), "2024-02-29T23:59:59Z");
+	}
+
+	#[test]
+	fn test_parse_tool_arguments_object() -> Result<()> {
+		let args = serde_json::json!({"city": "Paris"});
+		assert_eq!(parse_tool_arguments("get_weather", args.clone(), false)?, args);
+		assert_eq!(
+			parse_tool_arguments("get_weather", Value::Null, false)?,
+			serde_json::json!({})
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_parse_tool_arguments_stringified_json() -> Result<()> {
+		let args = Value::String(r#"{"city": "Paris"}"#.to_string());
+		assert_eq!(
+			parse_tool_arguments("get_weather", args, false)?,
+			serde_json::json!({"city": "Paris"})
+		);
+		assert_eq!(
+			parse_tool_arguments("get_weather", Value::String(String::new()), false)?,
+			serde_json::json!({})
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_parse_tool_arguments_malformed() -> Result<()> {
+		let args = Value::String(r#"{"city": "Par"#.to_string());
+
+		let err = parse_tool_arguments("get_weather", args.clone(), false).expect_err("should fail");
+		let Error::InvalidToolArguments { fn_name, arguments, .. } = err else {
+			panic!("expected Error::InvalidToolArguments, got: {err}");
+		};
+		assert_eq!(fn_name, "get_weather");
+		assert_eq!(arguments, r#"{"city": "Par"#);
+
+		assert!(parse_tool_arguments("get_weather", serde_json::json!([1, 2]), false).is_err());
+
+		// -- lenient keeps the raw value
+		assert_eq!(parse_tool_arguments("get_weather", args.clone(), true)?, args);
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -90,3 +90,63 @@ impl Adapter for TogetherAdapter {
 		OpenAIAdapter::to_embed_response(model_iden, web_response, options_set)
 	}
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::{AdapterDispatcher, ModelCapabilities};
+	use crate::common::Modality;
+
+	const MODEL_NAME: &str = "meta-llama/Llama-3.3-70B-Instruct-Turbo";
+
+	/// Together is activated by the namespace only (the bare model names fall back to Ollama).
+	#[test]
+	fn test_together_namespaced_routing() {
+		assert_eq!(
+			AdapterKind::from_model(&format!("together::{MODEL_NAME}")).ok(),
+			Some(AdapterKind::Together)
+		);
+		assert_eq!(AdapterKind::from_model(MODEL_NAME).ok(), Some(AdapterKind::Ollama));
+		assert_eq!(
+			AdapterKind::Together.default_key_env_name(),
+			Some(TogetherAdapter::API_KEY_DEFAULT_ENV_NAME)
+		);
+	}
+
+	#[test]
+	fn test_together_request_data() -> Result<()> {
+		let target = ServiceTarget {
+			endpoint: TogetherAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Together, format!("together::{MODEL_NAME}")),
+		};
+
+		let data = AdapterDispatcher::to_web_request_data(
+			target,
+			ServiceType::Chat,
+			ChatRequest::from_user("hello"),
+			ChatOptionsSet::default(),
+		)?;
+
+		assert_eq!(data.url, "https://api.together.xyz/v1/chat/completions");
+		assert_eq!(data.payload["model"], MODEL_NAME);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_together_capabilities() {
+		let kind = AdapterKind::Together;
+		assert!(ModelCapabilities::supports_streaming(kind, MODEL_NAME));
+		assert!(ModelCapabilities::supports_tool_calls(kind, MODEL_NAME));
+		assert!(ModelCapabilities::supports_json_mode(kind, MODEL_NAME));
+		assert!(ModelCapabilities::infer_input_modalities(kind, MODEL_NAME).contains(&Modality::Text));
+		// no known output limit for the hosted open models (no max_tokens capping)
+		assert_eq!(ModelCapabilities::known_max_output_tokens(kind, MODEL_NAME), None);
+		assert_eq!(ModelCapabilities::max_request_bytes(kind, MODEL_NAME), None);
+	}
+}
+
+// endregion: --- Tests
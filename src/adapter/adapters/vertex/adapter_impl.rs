@@ -1,5 +1,5 @@
 use crate::adapter::ModelCapabilities;
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, ignored_options, join_service_url};
 use crate::adapter::anthropic::{AnthropicAdapter, AnthropicRequestParts};
 use crate::adapter::gemini::GeminiAdapter;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
@@ -16,6 +16,9 @@ pub struct VertexAdapter;
 
 const VERTEX_ANTHROPIC_VERSION: &str = "vertex-2023-10-16";
 
+/// The `ChatOptions` payload options sent for the Anthropic publisher models (the others are reported as ignored).
+const ANTHROPIC_SUPPORTED_OPTIONS: &[&str] = &["temperature", "max_tokens", "top_p", "top_k", "stop_sequences"];
+
 impl VertexAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "VERTEX_API_KEY";
 }
@@ -38,6 +41,7 @@ impl VertexPublisher {
 			Err(Error::AdapterNotSupported {
 				adapter_kind: AdapterKind::Vertex,
 				feature: format!("model '{model_name}' (unknown Vertex AI publisher)"),
+				alternatives: Vec::new(),
 			})
 		}
 	}
@@ -127,13 +131,9 @@ impl Adapter for VertexAdapter {
 	/// an error before this fallback is ever reached.
 	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		if matches!(service_type, ServiceType::Models) {
-			return Err(Error::AdapterNotSupported {
-				adapter_kind: AdapterKind::Vertex,
-				feature: "models".to_string(),
-			});
+			return Err(Error::service_not_supported(AdapterKind::Vertex, ServiceType::Models));
 		}
 
-		let base_url = endpoint.base_url();
 		let (_, model_name) = model.model_name.namespace_and_name();
 		let publisher = VertexPublisher::from_model_name(model_name).unwrap_or_else(|_| {
 			warn!("Unknown Vertex AI publisher for model '{model_name}'; falling back to Google publisher");
@@ -141,25 +141,25 @@ impl Adapter for VertexAdapter {
 		});
 		let publisher_path = publisher.publisher_path();
 
-		let url = match publisher {
+		let suffix = match publisher {
 			VertexPublisher::Google => match service_type {
-				ServiceType::Chat => format!("{base_url}{publisher_path}/models/{model_name}:generateContent"),
+				ServiceType::Chat => format!("{publisher_path}/models/{model_name}:generateContent"),
 				ServiceType::ChatStream => {
-					format!("{base_url}{publisher_path}/models/{model_name}:streamGenerateContent")
+					format!("{publisher_path}/models/{model_name}:streamGenerateContent")
 				}
-				ServiceType::Embed => format!("{base_url}{publisher_path}/models/{model_name}:predict"),
+				ServiceType::Embed => format!("{publisher_path}/models/{model_name}:predict"),
 				ServiceType::Models => unreachable!("handled before publisher dispatch"),
 			},
 			VertexPublisher::Anthropic => match service_type {
 				ServiceType::Chat | ServiceType::ChatStream => {
-					format!("{base_url}{publisher_path}/models/{model_name}:rawPredict")
+					format!("{publisher_path}/models/{model_name}:rawPredict")
 				}
-				ServiceType::Embed => format!("{base_url}{publisher_path}/models/{model_name}:predict"),
+				ServiceType::Embed => format!("{publisher_path}/models/{model_name}:predict"),
 				ServiceType::Models => unreachable!("handled before publisher dispatch"),
 			},
 		};
 
-		Ok(url)
+		join_service_url(endpoint.base_url(), &suffix)
 	}
 
 	fn to_web_request_data(
@@ -232,10 +232,7 @@ impl Adapter for VertexAdapter {
 		_embed_req: crate::embed::EmbedRequest,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
-		Err(Error::AdapterNotSupported {
-			adapter_kind: AdapterKind::Vertex,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Vertex, ServiceType::Embed))
 	}
 
 	fn to_embed_response(
@@ -243,10 +240,7 @@ impl Adapter for VertexAdapter {
 		_web_response: WebResponse,
 		_options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::embed::EmbedResponse> {
-		Err(Error::AdapterNotSupported {
-			adapter_kind: AdapterKind::Vertex,
-			feature: "embeddings".to_string(),
-		})
+		Err(Error::service_not_supported(AdapterKind::Vertex, ServiceType::Embed))
 	}
 }
 
@@ -262,13 +256,19 @@ impl VertexAdapter {
 		chat_req: ChatRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
+		let ignored_options = GeminiAdapter::gemini_ignored_options(&options_set, model.adapter_kind);
 		let (payload, provider_model_name) =
 			GeminiAdapter::build_gemini_request_payload(&model, model_name, chat_req, options_set)?;
 
 		let provider_model = model.from_name(&provider_model_name);
 		let url = Self::get_service_url(&provider_model, service_type, endpoint)?;
 
-		Ok(WebRequestData { url, headers, payload })
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 }
 
@@ -323,9 +323,19 @@ impl VertexAdapter {
 			payload.x_insert("top_p", top_p)?;
 		}
 
+		if let Some(top_k) = options_set.top_k() {
+			payload.x_insert("top_k", top_k)?;
+		}
+
+		let ignored_options = ignored_options(&options_set, ANTHROPIC_SUPPORTED_OPTIONS, model.adapter_kind);
 		let url = Self::get_service_url(&model, service_type, endpoint)?;
 
-		Ok(WebRequestData { url, headers, payload })
+		Ok(WebRequestData {
+			url,
+			headers,
+			payload,
+			ignored_options,
+		})
 	}
 }
 
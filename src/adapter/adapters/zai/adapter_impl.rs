@@ -1,42 +1,28 @@
 use crate::adapter::ModelCapabilities;
+use crate::adapter::adapters::support::join_service_url;
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
+use crate::{Error, Result, ServiceTarget};
 use crate::{Model, ModelIden, ModelName};
-use crate::{Result, ServiceTarget};
 use reqwest::RequestBuilder;
 
+/// The model name namespace routing to the ZAI coding plan endpoint (e.g., `zai-coding::glm-4.6`).
+///
+/// NOTE: The `zai::` namespace is the adapter kind namespace, so `zai::glm-4.6` uses the regular API endpoint.
 pub const ZAI_CODING_NAMESPACE: &str = "zai-coding";
 
-/// Helper structure to hold ZAI model parsing information
-struct ZaiModelEndpoint {
-	endpoint: Endpoint,
-}
-
-impl ZaiModelEndpoint {
-	/// Parse ModelIden to determine if it's a coding model and return endpoint
-	fn from_model(model: &ModelIden) -> Self {
-		let (namespace, _) = model.model_name.namespace_and_name();
-
-		// Check if namespace is "zai" to route to coding endpoint
-		let endpoint = match namespace {
-			Some(ZAI_CODING_NAMESPACE) => Endpoint::from_static("https://api.z.ai/api/coding/paas/v4/"),
-			_ => ZaiAdapter::default_endpoint(),
-		};
-
-		Self { endpoint }
-	}
-}
+const ZAI_CODING_BASE_URL: &str = "https://api.z.ai/api/coding/paas/v4/";
 
 /// The ZAI API is mostly compatible with the OpenAI API.
 ///
 /// NOTE: This adapter will automatically route to the coding endpoint
-///       when the model name starts with "zai::".
+///       when the model name starts with `zai-coding::` (see `ZAI_CODING_NAMESPACE`).
 ///
-/// For example, `glm-4.6` uses the regular API endpoint,
-/// while `zai::glm-4.6` uses the coding plan endpoint.
+/// For example, `glm-4.6` (or `zai::glm-4.6`) uses the regular API endpoint,
+/// while `zai-coding::glm-4.6` uses the coding plan endpoint.
 ///
 pub struct ZaiAdapter;
 
@@ -75,6 +61,23 @@ pub(in crate::adapter) const MODELS: &[&str] = &[
 
 impl ZaiAdapter {
 	pub const API_KEY_DEFAULT_ENV_NAME: &str = "ZAI_API_KEY";
+
+	/// Returns true when the model name is in the coding plan namespace (`zai-coding::`).
+	pub fn is_coding_namespace(model: &ModelIden) -> bool {
+		let (namespace, _) = model.model_name.namespace_and_name();
+		namespace.is_some_and(|namespace| namespace.eq_ignore_ascii_case(ZAI_CODING_NAMESPACE))
+	}
+
+	/// Returns the endpoint for the model: the coding plan one for the `zai-coding::` namespace, `endpoint` otherwise.
+	///
+	/// NOTE: This is the single place of the endpoint selection (chat, stream, and embed URLs).
+	fn service_endpoint(model: &ModelIden, endpoint: Endpoint) -> Endpoint {
+		if Self::is_coding_namespace(model) {
+			Endpoint::from_static(ZAI_CODING_BASE_URL)
+		} else {
+			endpoint
+		}
+	}
 }
 
 // The ZAI API is mostly compatible with the OpenAI API.
@@ -93,8 +96,10 @@ impl Adapter for ZaiAdapter {
 		}
 	}
 
-	async fn all_model_names(kind: AdapterKind, endpoint: Endpoint, auth: AuthData) -> Result<Vec<String>> {
-		OpenAIAdapter::list_model_names_for_end_target(kind, endpoint, auth).await
+	async fn all_model_names(_kind: AdapterKind, _endpoint: Endpoint, _auth: AuthData) -> Result<Vec<String>> {
+		// ZAI doesn't have a models endpoint; same names as `all_models`
+		let coding_names = MODELS.iter().map(|name| format!("{ZAI_CODING_NAMESPACE}::{name}"));
+		Ok(MODELS.iter().map(|name| name.to_string()).chain(coding_names).collect())
 	}
 
 	async fn all_models(
@@ -102,35 +107,36 @@ impl Adapter for ZaiAdapter {
 		_target: ServiceTarget,
 		_web_client: &crate::webc::WebClient,
 	) -> Result<Vec<Model>> {
-		// ZAI doesn't have a models endpoint; build from hardcoded list
+		// ZAI doesn't have a models endpoint; build from hardcoded list,
+		// with the coding plan names (`zai-coding::`) after the regular ones
 		let mut models: Vec<Model> = Vec::new();
-		for model_id in MODELS {
-			let model = Self::parse_zai_model_to_model(model_id.to_string())?;
-			models.push(model);
+		for namespace in [None, Some(ZAI_CODING_NAMESPACE)] {
+			for model_id in MODELS {
+				let model = Self::parse_zai_model_to_model(model_id.to_string(), namespace)?;
+				models.push(model);
+			}
 		}
 		Ok(models)
 	}
 
-	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
-		let base_url = endpoint.base_url();
-		let url = match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}chat/completions"),
-			ServiceType::Embed => format!("{base_url}embeddings"),
-			ServiceType::Models => format!("{base_url}models"), // Might not be supported; placeholder
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		let endpoint = Self::service_endpoint(model, endpoint);
+		let suffix = match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => "chat/completions",
+			ServiceType::Embed => "embeddings",
+			// no models endpoint (`all_models` uses the hardcoded list)
+			ServiceType::Models => return Err(Error::service_not_supported(AdapterKind::Zai, ServiceType::Models)),
 		};
-		Ok(url)
+		join_service_url(endpoint.base_url(), suffix)
 	}
 
 	fn to_web_request_data(
-		mut target: ServiceTarget,
+		target: ServiceTarget,
 		service_type: ServiceType,
 		chat_req: ChatRequest,
 		chat_options: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
-		// Parse model name and determine appropriate endpoint
-		let zai_info = ZaiModelEndpoint::from_model(&target.model);
-		target.endpoint = zai_info.endpoint;
-
+		// NOTE: The coding plan endpoint is selected by `get_service_url` (called by the OpenAI util)
 		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options, None)
 	}
 
@@ -155,8 +161,8 @@ impl Adapter for ZaiAdapter {
 		embed_req: crate::embed::EmbedRequest,
 		options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::adapter::WebRequestData> {
-		let zai_info = ZaiModelEndpoint::from_model(&service_target.model);
-		service_target.endpoint = zai_info.endpoint;
+		// NOTE: The OpenAI embed builds the URL with the OpenAI `get_service_url`, so the endpoint is selected here
+		service_target.endpoint = Self::service_endpoint(&service_target.model, service_target.endpoint);
 
 		OpenAIAdapter::to_embed_request_data(service_target, embed_req, options_set)
 	}
@@ -175,8 +181,12 @@ impl Adapter for ZaiAdapter {
 /// Support functions for ZaiAdapter
 impl ZaiAdapter {
 	/// Convert a Zai (GLM) model ID to a complete Model object with capabilities
-	fn parse_zai_model_to_model(model_id: String) -> Result<Model> {
-		let model_name: ModelName = model_id.clone().into();
+	/// (named `{namespace}::{model_id}` when a namespace is given).
+	fn parse_zai_model_to_model(model_id: String, namespace: Option<&str>) -> Result<Model> {
+		let model_name: ModelName = match namespace {
+			Some(namespace) => format!("{namespace}::{model_id}").into(),
+			None => model_id.clone().into(),
+		};
 		let mut model = Model::new(model_name, model_id.clone());
 
 		// Set Zai model capabilities using the ModelCapabilities system
@@ -210,3 +220,86 @@ impl ZaiAdapter {
 }
 
 // endregion: --- Support Functions
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterDispatcher;
+	use crate::embed::{EmbedOptionsSet, EmbedRequest};
+
+	fn target(model_name: &str) -> ServiceTarget {
+		ServiceTarget {
+			endpoint: ZaiAdapter::default_endpoint(),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Zai, model_name),
+		}
+	}
+
+	#[test]
+	fn test_zai_is_coding_namespace() {
+		assert!(ZaiAdapter::is_coding_namespace(&ModelIden::new(
+			AdapterKind::Zai,
+			"zai-coding::glm-4.6"
+		)));
+		assert!(!ZaiAdapter::is_coding_namespace(&ModelIden::new(
+			AdapterKind::Zai,
+			"zai::glm-4.6"
+		)));
+		assert!(!ZaiAdapter::is_coding_namespace(&ModelIden::new(
+			AdapterKind::Zai,
+			"glm-4.6"
+		)));
+	}
+
+	#[test]
+	fn test_zai_coding_namespace_urls() -> Result<()> {
+		let coding = target("zai-coding::glm-4.6");
+
+		// -- Streamed chat request (URL and model name without the namespace)
+		let data = AdapterDispatcher::to_web_request_data(
+			coding.clone(),
+			ServiceType::ChatStream,
+			ChatRequest::from_user("hello"),
+			ChatOptionsSet::default(),
+		)?;
+		assert_eq!(data.url, "https://api.z.ai/api/coding/paas/v4/chat/completions");
+		assert_eq!(data.payload["model"], "glm-4.6");
+
+		// -- Embed URL
+		let data =
+			ZaiAdapter::to_embed_request_data(coding.clone(), EmbedRequest::new("hello"), EmbedOptionsSet::default())?;
+		assert_eq!(data.url, "https://api.z.ai/api/coding/paas/v4/embeddings");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_zai_regular_namespace_keeps_endpoint() -> Result<()> {
+		for model_name in ["glm-4.6", "zai::glm-4.6"] {
+			let data = AdapterDispatcher::to_web_request_data(
+				target(model_name),
+				ServiceType::ChatStream,
+				ChatRequest::from_user("hello"),
+				ChatOptionsSet::default(),
+			)?;
+			assert_eq!(
+				data.url, "https://api.z.ai/api/paas/v4/chat/completions",
+				"for {model_name}"
+			);
+		}
+
+		// -- A custom endpoint (e.g., from a service target resolver) is kept
+		let url = AdapterDispatcher::get_service_url(
+			&ModelIden::new(AdapterKind::Zai, "glm-4.6"),
+			ServiceType::Chat,
+			Endpoint::from_static("https://proxy.corp/zai/"),
+		)?;
+		assert_eq!(url, "https://proxy.corp/zai/chat/completions");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -12,7 +12,7 @@
 //! - Models: `glm-4.6`, `glm-4.5`, etc.
 //! - Usage: Standard API calls billed per token
 //!
-//! ### Coding Plan (Subscription-based only with the `zai-coding::` namepace)  
+//! ### Coding Plan (Subscription-based only with the `zai-coding::` namespace, see `ZAI_CODING_NAMESPACE`)
 //! - Endpoint: `<https://api.z.ai/api/coding/paas/v4/>`
 //! - Models: `zai-coding::glm-4.6`, `zai-coding::glm-4.5`, etc.
 //! - Usage: Fixed monthly subscription for coding tasks
@@ -34,7 +34,7 @@
 //! let response = client.exec_chat("zai-coding::glm-4.6", chat_request, None).await?;
 //! ```
 //!
-//! See `examples/c07-zai-dual-endpoints.rs` for a complete working example.
+//! See `examples/c99-zai.rs` for a complete working example.
 
 // region:    --- Modules
 
@@ -1,9 +1,17 @@
 use super::groq::GroqAdapter;
+use crate::adapter::adapters::ai21::AI21Adapter;
 use crate::adapter::adapters::copilot::CopilotAdapter;
 use crate::adapter::adapters::copilot_resp::CopilotRespAdapter;
+use crate::adapter::adapters::deepinfra::DeepInfraAdapter;
 use crate::adapter::adapters::github_copilot::GithubCopilotAdapter;
+use crate::adapter::adapters::hunyuan::HunyuanAdapter;
+use crate::adapter::adapters::lingyiwanwu::LingyiwanwuAdapter;
 use crate::adapter::adapters::mimo::MimoAdapter;
+#[cfg(feature = "mock")]
+use crate::adapter::adapters::mock::MockAdapter;
 use crate::adapter::adapters::ollama_cloud::OllamaCloudAdapter;
+use crate::adapter::adapters::qianfan::QianfanAdapter;
+use crate::adapter::adapters::stepfun::StepFunAdapter;
 use crate::adapter::adapters::together::TogetherAdapter;
 use crate::adapter::adapters::zai::ZaiAdapter;
 use crate::adapter::aliyun::AliyunAdapter;
@@ -44,6 +52,12 @@ impl AdapterDispatcher {
 			AdapterKind::Anthropic => AnthropicAdapter::default_endpoint(),
 			AdapterKind::Fireworks => FireworksAdapter::default_endpoint(),
 			AdapterKind::Together => TogetherAdapter::default_endpoint(),
+			AdapterKind::AI21 => AI21Adapter::default_endpoint(),
+			AdapterKind::DeepInfra => DeepInfraAdapter::default_endpoint(),
+			AdapterKind::Hunyuan => HunyuanAdapter::default_endpoint(),
+			AdapterKind::Qianfan => QianfanAdapter::default_endpoint(),
+			AdapterKind::StepFun => StepFunAdapter::default_endpoint(),
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::default_endpoint(),
 			AdapterKind::Groq => GroqAdapter::default_endpoint(),
 			AdapterKind::Mimo => MimoAdapter::default_endpoint(),
 			AdapterKind::Nebius => NebiusAdapter::default_endpoint(),
@@ -59,6 +73,8 @@ impl AdapterDispatcher {
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::default_endpoint(),
 			AdapterKind::Vertex => VertexAdapter::default_endpoint(),
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::default_endpoint(),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::default_endpoint(),
 		}
 	}
 
@@ -70,6 +86,12 @@ impl AdapterDispatcher {
 			AdapterKind::Anthropic => AnthropicAdapter::default_auth(),
 			AdapterKind::Fireworks => FireworksAdapter::default_auth(),
 			AdapterKind::Together => TogetherAdapter::default_auth(),
+			AdapterKind::AI21 => AI21Adapter::default_auth(),
+			AdapterKind::DeepInfra => DeepInfraAdapter::default_auth(),
+			AdapterKind::Hunyuan => HunyuanAdapter::default_auth(),
+			AdapterKind::Qianfan => QianfanAdapter::default_auth(),
+			AdapterKind::StepFun => StepFunAdapter::default_auth(),
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::default_auth(),
 			AdapterKind::Groq => GroqAdapter::default_auth(),
 			AdapterKind::Mimo => MimoAdapter::default_auth(),
 			AdapterKind::Nebius => NebiusAdapter::default_auth(),
@@ -85,6 +107,17 @@ impl AdapterDispatcher {
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::default_auth(),
 			AdapterKind::Vertex => VertexAdapter::default_auth(),
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::default_auth(),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::default_auth(),
+		}
+	}
+
+	/// Adapter specific auth step run after the auth resolution (e.g., Qianfan AK/SK to bearer token exchange).
+	/// Returns the `AuthData` unchanged for the other adapters.
+	pub async fn resolve_auth(model_iden: &ModelIden, auth: AuthData) -> Result<AuthData> {
+		match model_iden.adapter_kind {
+			AdapterKind::Qianfan => QianfanAdapter::resolve_bearer_auth(model_iden, auth).await,
+			_ => Ok(auth),
 		}
 	}
 
@@ -96,6 +129,12 @@ impl AdapterDispatcher {
 			AdapterKind::Anthropic => AnthropicAdapter::all_model_names(kind, endpoint, auth).await,
 			AdapterKind::Fireworks => FireworksAdapter::all_model_names(kind, endpoint, auth).await,
 			AdapterKind::Together => TogetherAdapter::all_model_names(kind, endpoint, auth).await,
+			AdapterKind::AI21 => AI21Adapter::all_model_names(kind, endpoint, auth).await,
+			AdapterKind::DeepInfra => DeepInfraAdapter::all_model_names(kind, endpoint, auth).await,
+			AdapterKind::Hunyuan => HunyuanAdapter::all_model_names(kind, endpoint, auth).await,
+			AdapterKind::Qianfan => QianfanAdapter::all_model_names(kind, endpoint, auth).await,
+			AdapterKind::StepFun => StepFunAdapter::all_model_names(kind, endpoint, auth).await,
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::all_model_names(kind, endpoint, auth).await,
 			AdapterKind::Groq => GroqAdapter::all_model_names(kind, endpoint, auth).await,
 			AdapterKind::Mimo => MimoAdapter::all_model_names(kind, endpoint, auth).await,
 			AdapterKind::Nebius => NebiusAdapter::all_model_names(kind, endpoint, auth).await,
@@ -111,6 +150,8 @@ impl AdapterDispatcher {
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::all_model_names(kind, endpoint, auth).await,
 			AdapterKind::Vertex => VertexAdapter::all_model_names(kind, endpoint, auth).await,
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::all_model_names(kind, endpoint, auth).await,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::all_model_names(kind, endpoint, auth).await,
 		}
 	}
 
@@ -134,9 +175,17 @@ impl AdapterDispatcher {
 			AdapterKind::CopilotResp => CopilotRespAdapter::all_models(kind, target, web_client).await,
 			AdapterKind::Fireworks => FireworksAdapter::all_models(kind, target, web_client).await,
 			AdapterKind::Together => TogetherAdapter::all_models(kind, target, web_client).await,
+			AdapterKind::AI21 => AI21Adapter::all_models(kind, target, web_client).await,
+			AdapterKind::DeepInfra => DeepInfraAdapter::all_models(kind, target, web_client).await,
+			AdapterKind::Hunyuan => HunyuanAdapter::all_models(kind, target, web_client).await,
+			AdapterKind::Qianfan => QianfanAdapter::all_models(kind, target, web_client).await,
+			AdapterKind::StepFun => StepFunAdapter::all_models(kind, target, web_client).await,
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::all_models(kind, target, web_client).await,
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::all_models(kind, target, web_client).await,
 			AdapterKind::Vertex => VertexAdapter::all_models(kind, target, web_client).await,
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::all_models(kind, target, web_client).await,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::all_models(kind, target, web_client).await,
 		}
 	}
 
@@ -148,6 +197,12 @@ impl AdapterDispatcher {
 			AdapterKind::Anthropic => AnthropicAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Fireworks => FireworksAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Together => TogetherAdapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::AI21 => AI21Adapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::DeepInfra => DeepInfraAdapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::Hunyuan => HunyuanAdapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::Qianfan => QianfanAdapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::StepFun => StepFunAdapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Groq => GroqAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Mimo => MimoAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Nebius => NebiusAdapter::get_service_url(model, service_type, endpoint),
@@ -163,15 +218,31 @@ impl AdapterDispatcher {
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Vertex => VertexAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::get_service_url(model, service_type, endpoint),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::get_service_url(model, service_type, endpoint),
 		}
 	}
 
 	pub fn to_web_request_data(
 		target: ServiceTarget,
 		service_type: ServiceType,
-		chat_req: ChatRequest,
+		mut chat_req: ChatRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
+		if chat_req.raw_prompt.is_some() {
+			validate_raw_prompt(&target.model, &chat_req)?;
+		}
+
+		if options_set.merge_adjacent_texts().unwrap_or(false) {
+			for msg in chat_req.messages.iter_mut() {
+				msg.content.merge_adjacent_texts();
+			}
+		}
+
+		if let Some(system_handling) = options_set.system_handling() {
+			chat_req.apply_system_handling(*system_handling);
+		}
+
 		let adapter_kind = &target.model.adapter_kind;
 		match adapter_kind {
 			AdapterKind::OpenAI => OpenAIAdapter::to_web_request_data(target, service_type, chat_req, options_set),
@@ -186,6 +257,16 @@ impl AdapterDispatcher {
 				FireworksAdapter::to_web_request_data(target, service_type, chat_req, options_set)
 			}
 			AdapterKind::Together => TogetherAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+			AdapterKind::AI21 => AI21Adapter::to_web_request_data(target, service_type, chat_req, options_set),
+			AdapterKind::DeepInfra => {
+				DeepInfraAdapter::to_web_request_data(target, service_type, chat_req, options_set)
+			}
+			AdapterKind::Hunyuan => HunyuanAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+			AdapterKind::Qianfan => QianfanAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+			AdapterKind::StepFun => StepFunAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+			AdapterKind::Lingyiwanwu => {
+				LingyiwanwuAdapter::to_web_request_data(target, service_type, chat_req, options_set)
+			}
 			AdapterKind::Groq => GroqAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			AdapterKind::Mimo => MimoAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			AdapterKind::Nebius => NebiusAdapter::to_web_request_data(target, service_type, chat_req, options_set),
@@ -207,6 +288,8 @@ impl AdapterDispatcher {
 			AdapterKind::GithubCopilot => {
 				GithubCopilotAdapter::to_web_request_data(target, service_type, chat_req, options_set)
 			}
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 		}
 	}
 
@@ -222,6 +305,12 @@ impl AdapterDispatcher {
 			AdapterKind::Anthropic => AnthropicAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Fireworks => FireworksAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Together => TogetherAdapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::AI21 => AI21Adapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::DeepInfra => DeepInfraAdapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::Hunyuan => HunyuanAdapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::Qianfan => QianfanAdapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::StepFun => StepFunAdapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Groq => GroqAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Mimo => MimoAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Nebius => NebiusAdapter::to_chat_response(model_iden, web_response, options_set),
@@ -237,6 +326,8 @@ impl AdapterDispatcher {
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Vertex => VertexAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::to_chat_response(model_iden, web_response, options_set),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_chat_response(model_iden, web_response, options_set),
 		}
 	}
 
@@ -252,6 +343,12 @@ impl AdapterDispatcher {
 			AdapterKind::Anthropic => AnthropicAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
 			AdapterKind::Fireworks => FireworksAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
 			AdapterKind::Together => TogetherAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::AI21 => AI21Adapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::DeepInfra => DeepInfraAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::Hunyuan => HunyuanAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::Qianfan => QianfanAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::StepFun => StepFunAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
 			AdapterKind::Groq => GroqAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
 			AdapterKind::Mimo => MimoAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
 			AdapterKind::Nebius => NebiusAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
@@ -269,6 +366,8 @@ impl AdapterDispatcher {
 			AdapterKind::GithubCopilot => {
 				GithubCopilotAdapter::to_chat_stream(model_iden, reqwest_builder, options_set)
 			}
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
 		}
 	}
 
@@ -280,14 +379,20 @@ impl AdapterDispatcher {
 		let adapter_kind = &target.model.adapter_kind;
 		match adapter_kind {
 			AdapterKind::OpenAI => OpenAIAdapter::to_embed_request_data(target, embed_req, options_set),
-			AdapterKind::OpenAIResp => Err(Error::AdapterNotSupported {
-				adapter_kind: target.model.adapter_kind,
-				feature: "embed".to_string(),
-			}),
+			AdapterKind::OpenAIResp => Err(Error::service_not_supported(
+				target.model.adapter_kind,
+				ServiceType::Embed,
+			)),
 			AdapterKind::Gemini => GeminiAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Anthropic => AnthropicAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Fireworks => FireworksAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Together => TogetherAdapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::AI21 => AI21Adapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::DeepInfra => DeepInfraAdapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::Hunyuan => HunyuanAdapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::Qianfan => QianfanAdapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::StepFun => StepFunAdapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Groq => GroqAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Mimo => MimoAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Nebius => NebiusAdapter::to_embed_request_data(target, embed_req, options_set),
@@ -303,6 +408,8 @@ impl AdapterDispatcher {
 			AdapterKind::OllamaCloud => OllamaCloudAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Vertex => VertexAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::GithubCopilot => GithubCopilotAdapter::to_embed_request_data(target, embed_req, options_set),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_embed_request_data(target, embed_req, options_set),
 		}
 	}
 
@@ -313,14 +420,20 @@ impl AdapterDispatcher {
 	) -> Result<EmbedResponse> {
 		match model_iden.adapter_kind {
 			AdapterKind::OpenAI => OpenAIAdapter::to_embed_response(model_iden, web_response, options_set),
-			AdapterKind::OpenAIResp => Err(Error::AdapterNotSupported {
-				adapter_kind: model_iden.adapter_kind,
-				feature: "embed".to_string(),
-			}),
+			AdapterKind::OpenAIResp => Err(Error::service_not_supported(
+				model_iden.adapter_kind,
+				ServiceType::Embed,
+			)),
 			AdapterKind::Gemini => GeminiAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Anthropic => AnthropicAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Fireworks => FireworksAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Together => TogetherAdapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::AI21 => AI21Adapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::DeepInfra => DeepInfraAdapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::Hunyuan => HunyuanAdapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::Qianfan => QianfanAdapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::StepFun => StepFunAdapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::Lingyiwanwu => LingyiwanwuAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Groq => GroqAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Mimo => MimoAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Nebius => NebiusAdapter::to_embed_response(model_iden, web_response, options_set),
@@ -338,6 +451,395 @@ impl AdapterDispatcher {
 			AdapterKind::GithubCopilot => {
 				GithubCopilotAdapter::to_embed_response(model_iden, web_response, options_set)
 			}
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_embed_response(model_iden, web_response, options_set),
+		}
+	}
+}
+
+/// A raw prompt is sent as is, so it is only accepted by the completion-style adapters, and without
+/// the system, messages, or tools (which would be silently dropped).
+fn validate_raw_prompt(model_iden: &ModelIden, chat_req: &ChatRequest) -> Result<()> {
+	if !model_iden.adapter_kind.supports_raw_prompt() {
+		return Err(Error::UnsupportedByModel {
+			model_iden: model_iden.clone(),
+			feature: "raw_prompt",
+		});
+	}
+
+	let conflict = if chat_req.system.is_some() {
+		Some("system")
+	} else if !chat_req.messages.is_empty() {
+		Some("messages")
+	} else if chat_req.tools.as_ref().is_some_and(|tools| !tools.is_empty()) {
+		Some("tools")
+	} else {
+		None
+	};
+	match conflict {
+		Some(conflict) => Err(Error::RawPromptConflict {
+			model_iden: model_iden.clone(),
+			conflict,
+		}),
+		None => Ok(()),
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// As for the chat requests, the embed requests send the model name without its namespace
+	/// (e.g., `zai::embedding-3` as `embedding-3`), for all the adapters supporting embeddings.
+	#[test]
+	fn test_embed_request_data_strips_namespace() -> Result<()> {
+		for kind in AdapterKind::ALL.iter().filter(|kind| kind.supports_service(ServiceType::Embed)) {
+			let target = ServiceTarget {
+				endpoint: AdapterDispatcher::default_endpoint(*kind),
+				auth: AuthData::from_single("test-key"),
+				model: ModelIden::new(*kind, format!("{}::embed-test-model", kind.as_lower_str())),
+			};
+
+			for embed_req in [
+				EmbedRequest::new("hello"),
+				EmbedRequest::new_batch(vec!["hello".to_string(), "world".to_string()]),
+			] {
+				let data =
+					AdapterDispatcher::to_embed_request_data(target.clone(), embed_req, EmbedOptionsSet::default())?;
+				let payload = data.payload.to_string();
+				assert!(payload.contains("embed-test-model"), "{kind} payload: {payload}");
+				assert!(!payload.contains("::"), "{kind} payload: {payload}");
+				assert!(!data.url.contains("::"), "{kind} url: {}", data.url);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// The models URL (`None` when not supported) of each adapter, with its default endpoint.
+	#[test]
+	fn test_models_service_url_per_adapter() -> Result<()> {
+		let cases: &[(AdapterKind, Option<&str>)] = &[
+			(AdapterKind::OpenAI, Some("https://api.openai.com/v1/models")),
+			(AdapterKind::OpenAIResp, Some("https://api.openai.com/v1/models")),
+			(
+				AdapterKind::Gemini,
+				Some("https://generativelanguage.googleapis.com/v1beta/models"),
+			),
+			(AdapterKind::Anthropic, Some("https://api.anthropic.com/v1/models")),
+			(
+				AdapterKind::Fireworks,
+				Some("https://api.fireworks.ai/inference/v1/models"),
+			),
+			(AdapterKind::Together, Some("https://api.together.xyz/v1/models")),
+			(AdapterKind::AI21, Some("https://api.ai21.com/studio/v1/models")),
+			(
+				AdapterKind::DeepInfra,
+				Some("https://api.deepinfra.com/v1/openai/models"),
+			),
+			(
+				AdapterKind::Hunyuan,
+				Some("https://api.hunyuan.cloud.tencent.com/v1/models"),
+			),
+			(AdapterKind::Qianfan, Some("https://qianfan.baidubce.com/v2/models")),
+			(AdapterKind::StepFun, Some("https://api.stepfun.com/v1/models")),
+			(AdapterKind::Lingyiwanwu, Some("https://api.lingyiwanwu.com/v1/models")),
+			(AdapterKind::Groq, Some("https://api.groq.com/openai/v1/models")),
+			(AdapterKind::Mimo, Some("https://api.xiaomimimo.com/v1/models")),
+			(AdapterKind::Nebius, Some("https://api.studio.nebius.ai/v1/models")),
+			(AdapterKind::Xai, Some("https://api.x.ai/v1/models")),
+			(AdapterKind::DeepSeek, Some("https://api.deepseek.com/v1/models")),
+			(AdapterKind::Zai, None),
+			(
+				AdapterKind::BigModel,
+				Some("https://open.bigmodel.cn/api/paas/v4/models"),
+			),
+			(
+				AdapterKind::Aliyun,
+				Some("https://dashscope.aliyuncs.com/compatible-mode/v1/models"),
+			),
+			(AdapterKind::Cohere, Some("https://api.cohere.com/v1/models")),
+			(AdapterKind::Copilot, Some("https://api.githubcopilot.com/models")),
+			(AdapterKind::CopilotResp, Some("https://api.githubcopilot.com/models")),
+			(AdapterKind::Ollama, Some("http://localhost:11434/api/tags")),
+			(AdapterKind::OllamaCloud, Some("https://ollama.com/api/tags")),
+			(AdapterKind::Vertex, None),
+			(
+				AdapterKind::GithubCopilot,
+				Some("https://models.github.ai/inference/models"),
+			),
+			#[cfg(feature = "mock")]
+			(AdapterKind::Mock, None),
+		];
+		assert_eq!(cases.len(), AdapterKind::ALL.len(), "one case per adapter kind");
+
+		for (kind, expected_url) in cases {
+			let model = ModelIden::new(*kind, "");
+			// The OpenAI and Anthropic default endpoints can be overridden by `OPENAI_BASE_URL` / `ANTHROPIC_BASE_URL`,
+			// so their static base URLs are used (test independent of the environment)
+			let endpoint = match kind {
+				AdapterKind::OpenAI => Endpoint::from_static("https://api.openai.com/v1/"),
+				AdapterKind::Anthropic => Endpoint::from_static("https://api.anthropic.com/v1/"),
+				_ => AdapterDispatcher::default_endpoint(*kind),
+			};
+			let res = AdapterDispatcher::get_service_url(&model, ServiceType::Models, endpoint);
+			assert_eq!(
+				kind.supports_service(ServiceType::Models),
+				expected_url.is_some(),
+				"{kind}"
+			);
+			match (res, expected_url) {
+				(Ok(url), Some(expected_url)) => assert_eq!(url, *expected_url, "{kind}"),
+				(Err(Error::AdapterNotSupported { adapter_kind, .. }), None) => assert_eq!(adapter_kind, *kind),
+				(res, _) => panic!("{kind} unexpected models url result: {res:?}"),
+			}
+		}
+
+		Ok(())
+	}
+
+	/// The chat URL of each adapter keeps the query params of an `Endpoint::builder()` endpoint
+	/// (e.g., the `api-version` of a proxy).
+	#[test]
+	fn test_chat_service_url_per_adapter_keeps_query_params() -> core::result::Result<(), Box<dyn std::error::Error>> {
+		let endpoint = Endpoint::builder()
+			.base("https://proxy.corp/")
+			.path_prefix("llm/v1")
+			.query_param("api-version", "2024-06-01")
+			.build()?;
+
+		for kind in AdapterKind::ALL {
+			#[cfg(feature = "mock")]
+			if matches!(kind, AdapterKind::Mock) {
+				continue;
+			}
+			let model = ModelIden::new(*kind, "gemini-2.0-flash");
+			let url = AdapterDispatcher::get_service_url(&model, ServiceType::Chat, endpoint.clone())?;
+			assert!(url.starts_with("https://proxy.corp/llm/v1/"), "{kind} url: {url}");
+			assert!(url.ends_with("?api-version=2024-06-01"), "{kind} url: {url}");
+			assert_eq!(url.matches('?').count(), 1, "{kind} url: {url}");
+		}
+
+		// -- Cohere embed (v2 instead of the v1 of the base)
+		let model = ModelIden::new(AdapterKind::Cohere, "embed-v4.0");
+		let url = AdapterDispatcher::get_service_url(&model, ServiceType::Embed, endpoint)?;
+		assert_eq!(url, "https://proxy.corp/llm/v2/embed?api-version=2024-06-01");
+
+		Ok(())
+	}
+
+	/// A namespaced model of the OpenAI compatible adapters resolves (no network) to its adapter,
+	/// default endpoint, and default auth env, and the request sends the model name without its namespace.
+	///
+	/// NOTE: Zhipu is served by the `bigmodel::` (open.bigmodel.cn) and `zai::` (z.ai) namespaces.
+	#[tokio::test]
+	async fn test_namespaced_model_routing_openai_compatible() -> Result<()> {
+		// (model, adapter kind, chat url, auth env name, payload model name)
+		let cases: &[(&str, AdapterKind, &str, &str, &str)] = &[
+			(
+				"together::Qwen/Qwen3-235B-A22B-Instruct-2507-tput",
+				AdapterKind::Together,
+				"https://api.together.xyz/v1/chat/completions",
+				"TOGETHER_API_KEY",
+				"Qwen/Qwen3-235B-A22B-Instruct-2507-tput",
+			),
+			(
+				"nebius::meta-llama/Llama-3.3-70B-Instruct",
+				AdapterKind::Nebius,
+				"https://api.studio.nebius.ai/v1/chat/completions",
+				"NEBIUS_API_KEY",
+				"meta-llama/Llama-3.3-70B-Instruct",
+			),
+			(
+				"zai::glm-4.6",
+				AdapterKind::Zai,
+				"https://api.z.ai/api/paas/v4/chat/completions",
+				"ZAI_API_KEY",
+				"glm-4.6",
+			),
+			(
+				"zai-coding::glm-4.6",
+				AdapterKind::Zai,
+				"https://api.z.ai/api/coding/paas/v4/chat/completions",
+				"ZAI_API_KEY",
+				"glm-4.6",
+			),
+			(
+				"bigmodel::glm-4-plus",
+				AdapterKind::BigModel,
+				"https://open.bigmodel.cn/api/paas/v4/chat/completions",
+				"BIGMODEL_API_KEY",
+				"glm-4-plus",
+			),
+			(
+				"fireworks::gpt-oss-20b",
+				AdapterKind::Fireworks,
+				"https://api.fireworks.ai/inference/v1/chat/completions",
+				"FIREWORKS_API_KEY",
+				"accounts/fireworks/models/gpt-oss-20b",
+			),
+		];
+		let client = crate::Client::default();
+
+		for (model, kind, expected_url, expected_env_name, expected_model_name) in cases {
+			// -- Resolve
+			let target = client.resolve_service_target(*model).await?;
+			assert_eq!(target.model.adapter_kind, *kind, "{model}");
+			assert_eq!(
+				target.endpoint.base_url(),
+				AdapterDispatcher::default_endpoint(*kind).base_url(),
+				"{model}"
+			);
+			assert!(
+				matches!(&target.auth, AuthData::FromEnv(env_name) if env_name == expected_env_name),
+				"{model} auth"
+			);
+
+			// -- Request (with an inline key, so no API key env is needed)
+			let target = ServiceTarget {
+				auth: AuthData::from_single("test-key"),
+				..target
+			};
+			let data = AdapterDispatcher::to_web_request_data(
+				target,
+				ServiceType::Chat,
+				ChatRequest::from_user("hello"),
+				ChatOptionsSet::default(),
+			)?;
+			assert_eq!(data.url, *expected_url, "{model}");
+			assert_eq!(data.payload["model"], *expected_model_name, "{model}");
+		}
+
+		Ok(())
+	}
+
+	fn raw_prompt_target(kind: AdapterKind) -> ServiceTarget {
+		ServiceTarget {
+			endpoint: AdapterDispatcher::default_endpoint(kind),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(kind, "acme/custom-finetune"),
+		}
+	}
+
+	/// The raw prompt is sent unmodified, to the completion-style endpoint of each supporting adapter.
+	#[test]
+	fn test_raw_prompt_request_data() -> Result<()> {
+		let raw_prompt = "<|system|>\nBe brief.<|end|>\n<|user|>\n  Hello  <|end|>\n<|assistant|>\n";
+		let cases: &[(AdapterKind, &str)] = &[
+			(AdapterKind::Ollama, "http://localhost:11434/api/generate"),
+			(AdapterKind::OllamaCloud, "https://ollama.com/api/generate"),
+			(AdapterKind::Together, "https://api.together.xyz/v1/completions"),
+			(
+				AdapterKind::Fireworks,
+				"https://api.fireworks.ai/inference/v1/completions",
+			),
+		];
+
+		for (kind, expected_url) in cases {
+			let data = AdapterDispatcher::to_web_request_data(
+				raw_prompt_target(*kind),
+				ServiceType::Chat,
+				ChatRequest::default().with_raw_prompt(raw_prompt),
+				ChatOptionsSet::default(),
+			)?;
+			assert_eq!(data.url, *expected_url, "{kind}");
+			assert_eq!(data.payload["prompt"], raw_prompt, "{kind}");
+			assert!(
+				data.payload.get("messages").is_none(),
+				"{kind} payload: {}",
+				data.payload
+			);
+			assert!(
+				data.payload.get("raw_prompt").is_none(),
+				"{kind} payload: {}",
+				data.payload
+			);
+		}
+
+		// Ollama does not apply the model template to a raw prompt
+		let data = AdapterDispatcher::to_web_request_data(
+			raw_prompt_target(AdapterKind::Ollama),
+			ServiceType::ChatStream,
+			ChatRequest::default().with_raw_prompt(raw_prompt),
+			ChatOptionsSet::default(),
+		)?;
+		assert_eq!(data.payload["raw"], true);
+		assert_eq!(data.payload["stream"], true);
+
+		// Ollama behind a proxy, base url without a trailing slash, and a chat path override
+		for kind in [AdapterKind::Ollama, AdapterKind::OllamaCloud] {
+			let endpoint =
+				Endpoint::from_static("https://proxy.corp/ollama").with_service_path(ServiceType::Chat, "v2/chat");
+			let target = ServiceTarget {
+				endpoint,
+				..raw_prompt_target(kind)
+			};
+			let data = AdapterDispatcher::to_web_request_data(
+				target.clone(),
+				ServiceType::Chat,
+				ChatRequest::default().with_raw_prompt(raw_prompt),
+				ChatOptionsSet::default(),
+			)?;
+			assert_eq!(data.url, "https://proxy.corp/ollama/api/generate", "{kind}");
+			let data = AdapterDispatcher::to_web_request_data(
+				target,
+				ServiceType::Chat,
+				ChatRequest::from_user("Hello"),
+				ChatOptionsSet::default(),
+			)?;
+			assert_eq!(data.url, "https://proxy.corp/ollama/v2/chat", "{kind}");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_raw_prompt_rejected() {
+		let to_web_request_data = |kind: AdapterKind, chat_req: ChatRequest| {
+			AdapterDispatcher::to_web_request_data(
+				raw_prompt_target(kind),
+				ServiceType::Chat,
+				chat_req,
+				ChatOptionsSet::default(),
+			)
+		};
+
+		// -- Combined with the messages, system, or tools
+		let cases = [
+			(ChatRequest::from_user("Hello").with_raw_prompt("Hi"), "messages"),
+			(ChatRequest::from_system("Be brief.").with_raw_prompt("Hi"), "system"),
+			(
+				ChatRequest::default()
+					.with_raw_prompt("Hi")
+					.append_tool(crate::chat::Tool::new("get_weather")),
+				"tools",
+			),
+		];
+		for (chat_req, expected_conflict) in cases {
+			let err = to_web_request_data(AdapterKind::Together, chat_req).expect_err("should conflict");
+			assert!(
+				matches!(err, Error::RawPromptConflict { conflict, .. } if conflict == expected_conflict),
+				"{err}"
+			);
+		}
+
+		// -- Chat-only adapters
+		for kind in [AdapterKind::OpenAI, AdapterKind::Anthropic, AdapterKind::Gemini] {
+			let err = to_web_request_data(kind, ChatRequest::default().with_raw_prompt("Hi")).expect_err("unsupported");
+			assert!(
+				matches!(
+					err,
+					Error::UnsupportedByModel {
+						feature: "raw_prompt",
+						..
+					}
+				),
+				"{kind}: {err}"
+			);
 		}
 	}
 }
+
+// endregion: --- Tests
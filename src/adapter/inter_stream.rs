@@ -32,6 +32,10 @@ pub struct InterStreamEnd {
 
 	// Response ID for stateful sessions (OpenAI Responses API).
 	pub captured_response_id: Option<String>,
+
+	// When the stream was interrupted by a terminal error (e.g., network or provider error mid-stream).
+	// The captured fields then hold the partial data received before the error.
+	pub interrupted_by: Option<Box<crate::Error>>,
 }
 
 /// Intermediary StreamEvent
@@ -42,5 +46,6 @@ pub enum InterStreamEvent {
 	ReasoningChunk(String),
 	ThoughtSignatureChunk(String),
 	ToolCallChunk(crate::chat::ToolCall),
+	ToolCallArgsChunk(crate::chat::ToolArgsChunk),
 	End(InterStreamEnd),
 }
@@ -14,18 +14,32 @@ mod adapter_types;
 mod adapters;
 mod dispatcher;
 mod model_capabilities;
+mod payload_schema;
 
 // -- Flatten (private, crate, public)
 use adapters::*;
 
 pub(crate) use adapter_types::*;
+#[cfg(feature = "mock")]
+pub(crate) use adapters::mock::MockAdapter;
 pub(crate) use dispatcher::*;
 
 pub use adapter_kind::*;
+pub use adapter_types::ServiceType;
+pub use adapters::anthropic::{
+	ANTHROPIC_BETAS, ANTHROPIC_VERSION, AnthropicExt, MessageBatch, MessageBatchPage, MessageBatchRequestCounts,
+	UsageBucket, UsageReport, UsageReportRequest, UsageResult,
+};
+pub use adapters::gemini::{CacheHandle, CacheUsageMetadata, GeminiExt};
+#[cfg(feature = "mock")]
+pub use adapters::mock::{MockChatHandler, mock_chat_response};
+pub use adapters::openai::{FileDeleted, FileList, FileObject, FilePurpose, FileUpload, OpenAIExt};
+pub use adapters::zai::{ZAI_CODING_NAMESPACE, ZaiAdapter};
 
 // -- Crate modules
 pub(crate) mod inter_stream;
 
 pub(crate) use model_capabilities::*;
+pub(crate) use payload_schema::*;
 
 // endregion: --- Modules
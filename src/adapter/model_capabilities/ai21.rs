@@ -0,0 +1,19 @@
+//! AI21 capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{ModelCaps, ProviderCaps};
+
+pub(super) static AI21: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// --- Jamba 1.5 Series (256K context, 4K default max output) ---
+		(Prefix("jamba-1.5-large"), ModelCaps::limits(256_000, 4_096)),
+		(Prefix("jamba-1.5-mini"), ModelCaps::limits(256_000, 4_096)),
+		// --- Jamba aliases / newer versions ---
+		(Prefix("jamba"), ModelCaps::limits(256_000, 4_096)),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT),
+	],
+	base: None,
+	ignore_case: false,
+};
@@ -0,0 +1,45 @@
+//! Anthropic capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_ALL, EFFORTS_NONE, ModelCaps, ModelPattern, ProviderCaps, TEXT, TEXT_IMAGE};
+
+const CLAUDE_4: ModelPattern = AnyOf(&[Contains("claude-4"), Contains("claude-opus-4"), Contains("claude-sonnet-4")]);
+
+pub(super) static ANTHROPIC: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// Claude 4 series (May 2025)
+		(Contains("claude-opus-4"), ModelCaps::limits(200_000, 32_000)),
+		(Contains("claude-sonnet-4"), ModelCaps::limits(200_000, 64_000)),
+		// Claude 3.7 series
+		(Contains("claude-3-7-sonnet"), ModelCaps::limits(200_000, 8_192)),
+		// Claude 3.5 series
+		(Contains("claude-3-5-sonnet"), ModelCaps::limits(200_000, 8_192)),
+		(Contains("claude-3-5-haiku"), ModelCaps::limits(200_000, 8_192)),
+		// Claude 3 series
+		(Contains("claude-3-opus"), ModelCaps::limits(200_000, 4_096)),
+		(Contains("claude-3-sonnet"), ModelCaps::limits(200_000, 4_096)),
+		(Contains("claude-3-haiku"), ModelCaps::limits(200_000, 4_096)),
+		// Claude 2 series
+		(Contains("claude-2.1"), ModelCaps::limits(200_000, 4_096)),
+		(Contains("claude-2.0"), ModelCaps::limits(100_000, 4_096)),
+		(Contains("claude-instant"), ModelCaps::limits(100_000, 4_096)),
+		// -- Features
+		(CLAUDE_4, ModelCaps::efforts(EFFORTS_ALL)),
+		// -- Modalities (image input from Claude 2.1)
+		(
+			AnyOf(&[CLAUDE_4, Contains("claude-3"), Contains("claude-2.1")]),
+			ModelCaps::input(TEXT_IMAGE),
+		),
+		// -- Defaults
+		(
+			Any,
+			ModelCaps::COMPAT
+				.with_json_mode(false)
+				.with_efforts(EFFORTS_NONE)
+				.with_input(TEXT),
+		),
+	],
+	base: None,
+	ignore_case: false,
+};
@@ -0,0 +1,46 @@
+//! Cohere capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{ModelCaps, ProviderCaps, TEXT, TEXT_IMAGE};
+
+pub(super) static COHERE: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// Aya series - High-performance multilingual models
+		(Contains("aya-vision-32b"), ModelCaps::limits(128_000, 8_192)),
+		(Contains("aya-vision-8b"), ModelCaps::limits(128_000, 4_096)),
+		(Contains("aya-expanse-32b"), ModelCaps::limits(128_000, 8_192)),
+		(Contains("aya-expanse-8b"), ModelCaps::limits(128_000, 4_096)),
+		// Command A series
+		(Contains("command-a-vision"), ModelCaps::limits(128_000, 4_096)),
+		(Contains("command-a"), ModelCaps::limits(128_000, 4_096)),
+		// Command R series (latest versions with improved performance)
+		(Contains("command-r-plus"), ModelCaps::limits(128_000, 4_096)),
+		(Contains("command-r7b"), ModelCaps::limits(128_000, 4_096)),
+		(Contains("command-r"), ModelCaps::limits(128_000, 4_096)),
+		// Legacy Command series
+		(Contains("command-light"), ModelCaps::limits(4_096, 4_096)),
+		(Contains("command-nightly"), ModelCaps::limits(4_096, 4_096)),
+		(Contains("command"), ModelCaps::limits(4_096, 4_096)),
+		// -- Features
+		// Command R, Command A, and Aya series support tool calls
+		(
+			AnyOf(&[
+				Contains("command-r"),
+				Contains("command-a"),
+				Contains("command-nightly"),
+				Contains("aya-"),
+			]),
+			ModelCaps::NONE.with_tool_calls(true),
+		),
+		// Most modern Cohere models support JSON mode, except the very basic/light ones
+		(Contains("aya-"), ModelCaps::NONE.with_json_mode(true)),
+		(Contains("command-light"), ModelCaps::NONE.with_json_mode(false)),
+		// -- Modalities
+		(Contains("vision"), ModelCaps::input(TEXT_IMAGE)),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_tool_calls(false).with_input(TEXT)),
+	],
+	base: None,
+	ignore_case: false,
+};
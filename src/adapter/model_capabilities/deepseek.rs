@@ -0,0 +1,31 @@
+//! DeepSeek capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_ALL, EFFORTS_NONE, ModelCaps, ProviderCaps};
+
+pub(super) static DEEPSEEK: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// DeepSeek-R1-0528 (reasoning model) - 64K input, 8K output (reasoning tokens not counted)
+		(Exact("deepseek-reasoner"), ModelCaps::limits(64_000, 8_192)),
+		// DeepSeek-V3-0324 (general chat) - 64K input, 8K output
+		(Exact("deepseek-chat"), ModelCaps::limits(64_000, 8_192)),
+		// -- Features
+		// Both DeepSeek models support function calling (up to 128 functions) and structured JSON output
+		(
+			AnyOf(&[Exact("deepseek-chat"), Exact("deepseek-reasoner")]),
+			ModelCaps::NONE.with_tool_calls(true).with_json_mode(true),
+		),
+		(Contains("reasoner"), ModelCaps::efforts(EFFORTS_ALL)),
+		// -- Defaults
+		(
+			Any,
+			ModelCaps::COMPAT
+				.with_tool_calls(false)
+				.with_json_mode(false)
+				.with_efforts(EFFORTS_NONE),
+		),
+	],
+	base: None,
+	ignore_case: false,
+};
@@ -0,0 +1,46 @@
+//! Gemini capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_ALL, EFFORTS_NONE, ModelCaps, ProviderCaps, TEXT, TEXT_IMAGE, TEXT_IMAGE_AUDIO};
+
+pub(super) static GEMINI: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// Gemini 2.5 series - Most advanced with thinking capabilities
+		(Contains("gemini-2.5-pro"), ModelCaps::limits(2_000_000, 32_768)), // 2M context (soon), 32K output
+		(Contains("gemini-2.5-flash"), ModelCaps::limits(1_000_000, 16_384)), // 1M context, 16K output
+		(Contains("gemini-2.5-flash-lite"), ModelCaps::limits(1_000_000, 8_192)), // 1M context, 8K output
+		// Gemini 2.0 series - Next generation with native tool use
+		(Contains("gemini-2.0-flash"), ModelCaps::limits(1_000_000, 32_768)), // 1M context, 32K output
+		(Contains("gemini-2.0-flash-lite"), ModelCaps::limits(1_000_000, 16_384)), // 1M context, 16K output
+		(Contains("gemini-2.0-flash-live"), ModelCaps::limits(1_000_000, 8_192)), // 1M context, 8K output (live)
+		// Legacy Gemini 1.5 series
+		(Contains("gemini-1.5-pro"), ModelCaps::limits(2_000_000, 8_192)), // 2M context, 8K output
+		(Contains("gemini-1.5-flash"), ModelCaps::limits(1_000_000, 8_192)), // 1M context, 8K output
+		// Gemini 1.0 series
+		(Contains("gemini-1.0-pro"), ModelCaps::limits(30_720, 2_048)), // 30K context, 2K output
+		// Experimental models
+		(Contains("gemini-exp"), ModelCaps::limits(2_000_000, 8_192)), // Experimental high context
+		// Embedding models
+		(Contains("embedding"), ModelCaps::limits(2_048, 768)), // Embedding input/output dimensions
+		// -- Features
+		(
+			AnyOf(&[Contains("thinking"), Contains("2.5")]),
+			ModelCaps::efforts(EFFORTS_ALL),
+		),
+		// -- Modalities (most Gemini models support vision, except the embedding models)
+		(Contains("embedding"), ModelCaps::input(TEXT)),
+		// Gemini 2.0 Flash Live supports audio input
+		(Contains("2.0-flash-live"), ModelCaps::input(TEXT_IMAGE_AUDIO)),
+		// -- Defaults
+		(
+			Any,
+			ModelCaps::COMPAT
+				.with_json_mode(false)
+				.with_efforts(EFFORTS_NONE)
+				.with_input(TEXT_IMAGE),
+		),
+	],
+	base: None,
+	ignore_case: false,
+};
@@ -0,0 +1,77 @@
+//! Groq capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_ALL, EFFORTS_NONE, ModelCaps, ProviderCaps, TEXT, TEXT_IMAGE};
+
+pub(super) static GROQ: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// --- Production Models (verified with official Groq documentation 2025) ---
+		// Moonshot AI Kimi K2 - 131K context, 16K output (verified)
+		(
+			Contains("moonshotai/kimi-k2-instruct"),
+			ModelCaps::limits(131_072, 16_384),
+		),
+		// Qwen 3 32B - 128K context, 32K output (updated from search results)
+		(Contains("qwen/qwen3-32b"), ModelCaps::limits(128_000, 32_768)),
+		// Llama 3.3 70B - 128K context, 32K output (verified)
+		(Contains("llama-3.3-70b-versatile"), ModelCaps::limits(128_000, 32_768)),
+		// Llama 3.1 8B instant - 131K context, 131K output (verified)
+		(Contains("llama-3.1-8b-instant"), ModelCaps::limits(131_072, 131_072)),
+		// Gemma2 9B - 8K context, 8K output (verified)
+		(Contains("gemma2-9b-it"), ModelCaps::limits(8_192, 8_192)),
+		// Llama Guard 4 12B - 131K context, 1K output (safety model, verified)
+		(
+			Contains("meta-llama/llama-guard-4-12b"),
+			ModelCaps::limits(131_072, 1_024),
+		),
+		// --- Preview Models (verified capabilities) ---
+		// DeepSeek R1 distilled - 128K context, 32K max output (updated - default 1K but can go up to 32K)
+		(
+			Contains("deepseek-r1-distill-llama-70b"),
+			ModelCaps::limits(128_000, 32_768),
+		),
+		// Llama 4 Maverick 17B - 131K context, 8K output (verified)
+		(
+			Contains("meta-llama/llama-4-maverick-17b-128e-instruct"),
+			ModelCaps::limits(131_072, 8_192),
+		),
+		// Llama 4 Scout 17B - 131K context, 8K output (verified)
+		(
+			Contains("meta-llama/llama-4-scout-17b-16e-instruct"),
+			ModelCaps::limits(131_072, 8_192),
+		),
+		// Prompt Guard models - 512 context, 512 output (verified)
+		(Contains("meta-llama/llama-prompt-guard-2"), ModelCaps::limits(512, 512)),
+		// --- Legacy Models (maintaining existing specs) ---
+		// Llama 3.1 405B reasoning - 131K context, 32K output
+		(Contains("llama-3.1-405b-reasoning"), ModelCaps::limits(131_072, 32_768)),
+		// Llama 3.1 70B versatile - 131K context, 32K output
+		(Contains("llama-3.1-70b-versatile"), ModelCaps::limits(131_072, 32_768)),
+		// Llama 3.2 vision models - verified multimodal capabilities with image support
+		(Contains("llama-3.2-90b-vision"), ModelCaps::limits(131_072, 32_768)),
+		(Contains("llama-3.2-11b-vision"), ModelCaps::limits(131_072, 16_384)),
+		// Llama 3.2 smaller models
+		(Contains("llama-3.2-3b-preview"), ModelCaps::limits(131_072, 32_768)),
+		(Contains("llama-3.2-1b-preview"), ModelCaps::limits(131_072, 32_768)),
+		// Mixtral 8x7B - 32K context window
+		(Contains("mixtral-8x7b-32768"), ModelCaps::limits(32_768, 32_768)),
+		// Legacy Llama models
+		(Contains("llama3-70b-8192"), ModelCaps::limits(8_192, 8_192)),
+		(Contains("llama-guard-3-8b"), ModelCaps::limits(8_192, 8_192)),
+		// Legacy Gemma 7B
+		(Contains("gemma-7b-it"), ModelCaps::limits(8_192, 8_192)),
+		// -- Features
+		// Qwen 3 supports the reasoning_effort parameter
+		(Contains("qwen3-32b"), ModelCaps::efforts(EFFORTS_ALL)),
+		// -- Modalities (vision models, verified 2025)
+		(
+			AnyOf(&[Contains("vision"), Contains("llama-3.2-90b"), Contains("llama-3.2-11b")]),
+			ModelCaps::input(TEXT_IMAGE),
+		),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_efforts(EFFORTS_NONE).with_input(TEXT)),
+	],
+	base: None,
+	ignore_case: false,
+};
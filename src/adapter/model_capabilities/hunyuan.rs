@@ -0,0 +1,25 @@
+//! Hunyuan capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_HIGH, EFFORTS_NONE, ModelCaps, ProviderCaps};
+
+pub(super) static HUNYUAN: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// --- Long context variants ---
+		(Prefix("hunyuan-lite"), ModelCaps::limits(256_000, 6_000)),
+		(Prefix("hunyuan-standard-256"), ModelCaps::limits(256_000, 6_000)),
+		// --- Turbo / TurboS / T1 ---
+		(Prefix("hunyuan-turbos"), ModelCaps::limits(28_000, 16_000)),
+		// T1 - 92K context window (28K input + 64K output)
+		(Prefix("hunyuan-t1"), ModelCaps::limits(92_000, 64_000)),
+		(Prefix("hunyuan-turbo"), ModelCaps::limits(28_000, 4_000)),
+		(Prefix("hunyuan"), ModelCaps::limits(28_000, 4_000)),
+		// -- Features (T1 is an always-thinking reasoning model)
+		(Prefix("hunyuan-t1"), ModelCaps::efforts(EFFORTS_HIGH)),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_efforts(EFFORTS_NONE)),
+	],
+	base: None,
+	ignore_case: false,
+};
@@ -0,0 +1,19 @@
+//! Lingyiwanwu (Yi) capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{ModelCaps, ProviderCaps, TEXT, TEXT_IMAGE};
+
+pub(super) static LINGYIWANWU: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		(Prefix("yi-lightning"), ModelCaps::limits(16_000, 4_096)),
+		(Prefix("yi-vision"), ModelCaps::limits(16_000, 4_096)),
+		(Prefix("yi-large"), ModelCaps::limits(32_000, 4_096)),
+		// -- Modalities
+		(Contains("vision"), ModelCaps::input(TEXT_IMAGE)),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_input(TEXT)),
+	],
+	base: None,
+	ignore_case: false,
+};
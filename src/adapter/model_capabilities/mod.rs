@@ -0,0 +1,677 @@
+use crate::ModelName;
+use crate::adapter::{AdapterKind, ApiFamily};
+use crate::common::{Modality, ReasoningEffortType};
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, RwLock};
+
+// region:    --- Modules
+
+mod ai21;
+mod anthropic;
+mod cohere;
+mod deepseek;
+mod gemini;
+mod groq;
+mod hunyuan;
+mod lingyiwanwu;
+mod nebius;
+mod ollama;
+mod open_weights;
+mod openai;
+mod provider_caps;
+mod qianfan;
+mod stepfun;
+mod xai;
+mod zai;
+
+use provider_caps::{ModelCaps, ProviderCaps};
+
+// endregion: --- Modules
+
+/// Utilities to derive model capabilities from the model id/name.
+///
+/// Each provider has its capability table (`ProviderCaps`, model pattern -> capabilities) in its own module.
+/// Many providers choose names that are compatible with or inspired by OpenAI. For that reason
+/// we keep the OpenAI table as a generic fall-back (and as the base table of the providers serving OpenAI models).
+#[allow(dead_code)]
+pub struct ModelCapabilities;
+
+// Provider优先级顺序（可按需调整）
+const PROVIDER_PRIORITY: [AdapterKind; 9] = [
+	AdapterKind::OpenAI,
+	AdapterKind::Anthropic,
+	AdapterKind::Cohere,
+	AdapterKind::DeepSeek,
+	AdapterKind::Gemini,
+	AdapterKind::Groq,
+	AdapterKind::Xai,
+	AdapterKind::Nebius,
+	AdapterKind::Ollama,
+];
+
+// Helper macro: 按 Provider 回退顺序查找能力 (the `ModelCaps` field accessor, e.g., `|caps| caps.streaming`)
+macro_rules! provider_fallback {
+	($adapter_kind: expr, $model_id: expr, $field: expr) => {{
+		let (adapter_kind, model_id) = ($adapter_kind, $model_id);
+		ModelCapabilities::provider_lookup(adapter_kind, model_id, $field).or_else(|| {
+			PROVIDER_PRIORITY
+				.iter()
+				.filter(|kind| **kind != adapter_kind)
+				.find_map(|kind| ModelCapabilities::provider_lookup(*kind, model_id, $field))
+		})
+	}};
+}
+
+// region:    --- Capability Overrides
+
+/// Capabilities measured at runtime (e.g., by `Client::probe_capabilities`).
+/// When set, they win over the model name heuristics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CapabilityOverride {
+	pub streaming: Option<bool>,
+	pub tool_calls: Option<bool>,
+	pub json_mode: Option<bool>,
+	/// Image input support (adds or removes `Modality::Image` from the inferred input modalities).
+	pub image_input: Option<bool>,
+}
+
+/// Process-wide, keyed by (adapter kind, model id).
+static CAPABILITY_OVERRIDES: LazyLock<RwLock<HashMap<(AdapterKind, String), CapabilityOverride>>> =
+	LazyLock::new(|| RwLock::new(HashMap::new()));
+
+impl ModelCapabilities {
+	/// Sets (replaces) the capability override for this model.
+	pub(crate) fn set_override(adapter_kind: AdapterKind, model_id: &str, cap_override: CapabilityOverride) {
+		let model_id = strip_namespace(model_id);
+		if let Ok(mut overrides) = CAPABILITY_OVERRIDES.write() {
+			overrides.insert((adapter_kind, model_id.to_string()), cap_override);
+		}
+	}
+
+	pub(crate) fn get_override(adapter_kind: AdapterKind, model_id: &str) -> Option<CapabilityOverride> {
+		let model_id = strip_namespace(model_id);
+		let overrides = CAPABILITY_OVERRIDES.read().ok()?;
+		overrides.get(&(adapter_kind, model_id.to_string())).copied()
+	}
+
+	/// Sets the image input override of this model (`None` to go back to the model id heuristics),
+	/// keeping its other overrides (e.g., from `Client::probe_capabilities`).
+	///
+	/// Process-wide. `Some(true)` adds, and `Some(false)` removes, `Modality::Image` from `infer_input_modalities`.
+	/// Public through `Client::set_image_input_override`.
+	pub(crate) fn set_image_input_override(adapter_kind: AdapterKind, model_id: &str, image_input: Option<bool>) {
+		let model_id = strip_namespace(model_id);
+		if let Ok(mut overrides) = CAPABILITY_OVERRIDES.write() {
+			overrides.entry((adapter_kind, model_id.to_string())).or_default().image_input = image_input;
+		}
+	}
+}
+
+// endregion: --- Capability Overrides
+
+impl ModelCapabilities {
+	/// The capability table of the adapter kind (`None` for the kinds delegating to another provider table).
+	fn provider_caps(kind: AdapterKind) -> Option<&'static ProviderCaps> {
+		let caps = match kind {
+			AdapterKind::OpenAI => &openai::OPENAI,
+			AdapterKind::OpenAIResp => &openai::OPENAI_RESP,
+			AdapterKind::Copilot
+			| AdapterKind::CopilotResp
+			| AdapterKind::DeepInfra
+			| AdapterKind::Mimo
+			| AdapterKind::Aliyun => &openai::OPENAI_COMPAT,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => &openai::OPENAI_COMPAT,
+			AdapterKind::Fireworks | AdapterKind::Together => &open_weights::OPEN_WEIGHTS,
+			AdapterKind::Nebius => &nebius::NEBIUS,
+			AdapterKind::Anthropic => &anthropic::ANTHROPIC,
+			AdapterKind::Cohere => &cohere::COHERE,
+			AdapterKind::DeepSeek => &deepseek::DEEPSEEK,
+			AdapterKind::Gemini => &gemini::GEMINI,
+			AdapterKind::Groq => &groq::GROQ,
+			AdapterKind::Xai => &xai::XAI,
+			AdapterKind::Zai | AdapterKind::BigModel => &zai::ZAI,
+			AdapterKind::AI21 => &ai21::AI21,
+			AdapterKind::Hunyuan => &hunyuan::HUNYUAN,
+			AdapterKind::Qianfan => &qianfan::QIANFAN,
+			AdapterKind::StepFun => &stepfun::STEPFUN,
+			AdapterKind::Lingyiwanwu => &lingyiwanwu::LINGYIWANWU,
+			AdapterKind::Ollama => &ollama::OLLAMA,
+			AdapterKind::OllamaCloud | AdapterKind::Vertex | AdapterKind::GithubCopilot => return None,
+		};
+		Some(caps)
+	}
+
+	fn delegated_capability_target(kind: AdapterKind, model_id: &str) -> Option<(AdapterKind, &str)> {
+		match kind {
+			AdapterKind::OllamaCloud => Some((AdapterKind::Ollama, model_id)),
+			AdapterKind::Vertex => AdapterKind::from_model(model_id).ok().map(|kind| (kind, model_id)),
+			AdapterKind::GithubCopilot => {
+				let (_, provider_model_id) = model_id.split_once('/').unwrap_or(("", model_id));
+				AdapterKind::from_model(provider_model_id)
+					.ok()
+					.map(|kind| (kind, provider_model_id))
+			}
+			_ => None,
+		}
+	}
+
+	/// The capability from the provider table (or the delegated provider one), without the other providers fallback.
+	fn provider_lookup<T>(kind: AdapterKind, model_id: &str, field: fn(&ModelCaps) -> Option<T>) -> Option<T> {
+		if let Some((delegated_kind, delegated_model_id)) = Self::delegated_capability_target(kind, model_id) {
+			return Self::provider_lookup(delegated_kind, delegated_model_id, field);
+		}
+		Self::provider_caps(kind)?.lookup(model_id, field)
+	}
+
+	// ---------- PUBLIC API ----------
+
+	/// Infer the model token limits (max input, max output)
+	pub fn infer_token_limits(adapter_kind: AdapterKind, model_id: &str) -> (Option<u32>, Option<u32>) {
+		let model_id = strip_namespace(model_id);
+		provider_fallback!(adapter_kind, model_id, |caps| caps.token_limits).unwrap_or((Some(4_096), Some(4_096)))
+	}
+
+	/// Returns the model max output tokens when known for its own provider
+	/// (unlike `infer_token_limits`, no cross-provider or generic default).
+	pub fn known_max_output_tokens(adapter_kind: AdapterKind, model_id: &str) -> Option<u32> {
+		let model_id = strip_namespace(model_id);
+		Self::provider_lookup(adapter_kind, model_id, |caps| caps.token_limits).and_then(|(_, max_output)| max_output)
+	}
+
+	/// Whether the model supports server-sent streaming responses.
+	pub fn supports_streaming(adapter_kind: AdapterKind, model_id: &str) -> bool {
+		let model_id = strip_namespace(model_id);
+		if let Some(streaming) = Self::get_override(adapter_kind, model_id).and_then(|o| o.streaming) {
+			return streaming;
+		}
+		provider_fallback!(adapter_kind, model_id, |caps| caps.streaming)
+			.unwrap_or_else(|| family_supports_streaming(adapter_kind.api_family()))
+	}
+
+	/// Whether the model supports "function/tool" calls.
+	pub fn supports_tool_calls(kind: AdapterKind, model_id: &str) -> bool {
+		let model_id = strip_namespace(model_id);
+		if let Some(tool_calls) = Self::get_override(kind, model_id).and_then(|o| o.tool_calls) {
+			return tool_calls;
+		}
+		// NOTE: No delegation nor other providers fallback, the unknown models are assumed to support tool calls.
+		Self::provider_caps(kind)
+			.and_then(|caps| caps.lookup(model_id, |caps| caps.tool_calls))
+			.unwrap_or(true)
+	}
+
+	/// Whether the model supports JSON mode (structured output).
+	pub fn supports_json_mode(adapter_kind: AdapterKind, model_id: &str) -> bool {
+		let model_id = strip_namespace(model_id);
+		if let Some(json_mode) = Self::get_override(adapter_kind, model_id).and_then(|o| o.json_mode) {
+			return json_mode;
+		}
+		provider_fallback!(adapter_kind, model_id, |caps| caps.json_mode).unwrap_or(false)
+	}
+
+	/// Whether the model supports reasoning effort control (i.e., has reasoning efforts).
+	pub fn supports_reasoning(adapter_kind: AdapterKind, model_id: &str) -> bool {
+		let model_id = strip_namespace(model_id);
+		provider_fallback!(adapter_kind, model_id, |caps| caps
+			.reasoning_efforts
+			.map(|efforts| !efforts.is_empty()))
+		.unwrap_or(false)
+	}
+
+	/// Input modalities supported by the model.
+	pub fn infer_input_modalities(adapter_kind: AdapterKind, model_id: &str) -> HashSet<Modality> {
+		let model_id = strip_namespace(model_id);
+		let mut modalities = Self::heuristic_input_modalities(adapter_kind, model_id);
+		match Self::get_override(adapter_kind, model_id).and_then(|o| o.image_input) {
+			Some(true) => {
+				modalities.insert(Modality::Image);
+			}
+			Some(false) => {
+				modalities.remove(&Modality::Image);
+			}
+			None => (),
+		}
+		modalities
+	}
+
+	/// Input modalities from the model id heuristics (without the capability override).
+	fn heuristic_input_modalities(adapter_kind: AdapterKind, model_id: &str) -> HashSet<Modality> {
+		provider_fallback!(adapter_kind, model_id, |caps| caps.input_modalities)
+			.map(|modalities| modalities.iter().cloned().collect())
+			.unwrap_or_else(|| HashSet::from([Modality::Text]))
+	}
+
+	/// Output modalities supported by the model.
+	pub fn infer_output_modalities(adapter_kind: AdapterKind, model_id: &str) -> HashSet<Modality> {
+		let model_id = strip_namespace(model_id);
+		provider_fallback!(adapter_kind, model_id, |caps| caps.output_modalities)
+			.map(|modalities| modalities.iter().cloned().collect())
+			.unwrap_or_else(|| HashSet::from([Modality::Text]))
+	}
+
+	/// Reasoning effort types supported by the model (if any).
+	pub fn infer_reasoning_efforts(adapter_kind: AdapterKind, model_id: &str) -> Vec<ReasoningEffortType> {
+		let model_id = strip_namespace(model_id);
+		provider_fallback!(adapter_kind, model_id, |caps| caps.reasoning_efforts)
+			.map(<[ReasoningEffortType]>::to_vec)
+			.unwrap_or_default()
+	}
+
+	/// Max images per request, when known for the provider (e.g., for pre-flight validation).
+	pub fn max_images_per_request(adapter_kind: AdapterKind, model_id: &str) -> Option<usize> {
+		let model_id = strip_namespace(model_id);
+		match adapter_kind {
+			AdapterKind::OpenAI | AdapterKind::OpenAIResp => Some(500),
+			AdapterKind::Anthropic => Some(100),
+			AdapterKind::Gemini => Some(3_000),
+			AdapterKind::Groq => Some(5),
+			AdapterKind::OllamaCloud | AdapterKind::Vertex | AdapterKind::GithubCopilot => {
+				let (delegated_kind, delegated_model_id) = Self::delegated_capability_target(adapter_kind, model_id)?;
+				Self::max_images_per_request(delegated_kind, delegated_model_id)
+			}
+			_ => None,
+		}
+	}
+
+	/// Max request body size in bytes, when documented for the provider
+	/// (e.g., for `Client::estimate_request` and the `validate_request_bytes` pre-flight validation).
+	pub fn max_request_bytes(adapter_kind: AdapterKind, model_id: &str) -> Option<usize> {
+		let model_id = strip_namespace(model_id);
+		const MB: usize = 1024 * 1024;
+		match adapter_kind {
+			AdapterKind::OpenAI | AdapterKind::OpenAIResp => Some(50 * MB),
+			AdapterKind::Anthropic => Some(32 * MB),
+			// Gemini inline data limit (larger content goes through the File API)
+			AdapterKind::Gemini => Some(20 * MB),
+			AdapterKind::OllamaCloud | AdapterKind::Vertex | AdapterKind::GithubCopilot => {
+				let (delegated_kind, delegated_model_id) = Self::delegated_capability_target(adapter_kind, model_id)?;
+				Self::max_request_bytes(delegated_kind, delegated_model_id)
+			}
+			_ => None,
+		}
+	}
+
+	/// Max input tokens of an embedding model, when known
+	/// (e.g., for the client-side `EmbedTruncate` of the providers without a truncate option).
+	pub fn max_embed_input_tokens(adapter_kind: AdapterKind, model_id: &str) -> Option<u32> {
+		let model_id = strip_namespace(model_id);
+		match adapter_kind {
+			AdapterKind::OpenAI | AdapterKind::OpenAIResp
+				if model_id.starts_with("text-embedding-3") || model_id == "text-embedding-ada-002" =>
+			{
+				Some(8_191)
+			}
+			AdapterKind::Gemini if model_id.contains("embedding") => Some(2_048),
+			AdapterKind::Cohere if model_id.starts_with("embed-") => Some(512),
+			_ => None,
+		}
+	}
+}
+
+// region:    --- Support
+
+/// The streaming default of the API family, for the models without a known streaming capability.
+///
+/// NOTE: Exhaustive (no wildcard), so a new family has to decide its default.
+fn family_supports_streaming(api_family: ApiFamily) -> bool {
+	match api_family {
+		ApiFamily::OpenAICompat
+		| ApiFamily::OpenAIResponses
+		| ApiFamily::Anthropic
+		| ApiFamily::Gemini
+		| ApiFamily::CohereV2
+		| ApiFamily::Ollama
+		| ApiFamily::Vertex => true,
+		#[cfg(feature = "mock")]
+		ApiFamily::Mock => true,
+	}
+}
+
+/// Returns the model id without its adapter namespace, when a known one (e.g., `groq::llama-3.1-8b-instant`
+/// -> `llama-3.1-8b-instant`), so the capabilities are the same for the namespaced and the bare model ids.
+fn strip_namespace(model_id: &str) -> &str {
+	match ModelName::split_as_namespace_and_name(model_id) {
+		(Some(namespace), name) if AdapterKind::from_namespace(namespace).is_some() => name,
+		_ => model_id,
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_model_capabilities_override_wins_over_heuristics() {
+		// gpt-4o supports tool calls by the name heuristics
+		assert!(ModelCapabilities::supports_tool_calls(
+			AdapterKind::OpenAI,
+			"gpt-4o-override-test"
+		));
+
+		ModelCapabilities::set_override(
+			AdapterKind::OpenAI,
+			"gpt-4o-override-test",
+			CapabilityOverride {
+				tool_calls: Some(false),
+				..Default::default()
+			},
+		);
+
+		assert!(!ModelCapabilities::supports_tool_calls(
+			AdapterKind::OpenAI,
+			"gpt-4o-override-test"
+		));
+		// not overridden, still from the heuristics
+		assert!(ModelCapabilities::supports_streaming(
+			AdapterKind::OpenAI,
+			"gpt-4o-override-test"
+		));
+		// other adapter kind, not overridden
+		assert!(ModelCapabilities::supports_tool_calls(
+			AdapterKind::Ollama,
+			"gpt-4o-override-test"
+		));
+	}
+
+	#[test]
+	fn test_model_capabilities_unknown_model_streaming_family_default() {
+		for kind in AdapterKind::ALL.iter().copied() {
+			assert!(
+				ModelCapabilities::supports_streaming(kind, "acme-unknown-model"),
+				"{kind}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_model_capabilities_open_weights_vision_input() {
+		let cases = [
+			// -- Fireworks
+			(
+				AdapterKind::Fireworks,
+				"accounts/fireworks/models/llama-v3p2-11b-vision-instruct",
+				true,
+			),
+			(
+				AdapterKind::Fireworks,
+				"accounts/fireworks/models/qwen2p5-vl-32b-instruct",
+				true,
+			),
+			(
+				AdapterKind::Fireworks,
+				"accounts/fireworks/models/phi-3-vision-128k-instruct",
+				true,
+			),
+			(
+				AdapterKind::Fireworks,
+				"accounts/fireworks/models/llama-v3p1-8b-instruct",
+				false,
+			),
+			(
+				AdapterKind::Fireworks,
+				"accounts/fireworks/models/qwen3-235b-a22b",
+				false,
+			),
+			// -- Together
+			(
+				AdapterKind::Together,
+				"meta-llama/Llama-3.2-11B-Vision-Instruct-Turbo",
+				true,
+			),
+			(AdapterKind::Together, "Qwen/Qwen2.5-VL-72B-Instruct", true),
+			(AdapterKind::Together, "meta-llama/Llama-3.3-70B-Instruct-Turbo", false),
+			(AdapterKind::Together, "deepseek-ai/DeepSeek-V3", false),
+			// -- Nebius
+			(AdapterKind::Nebius, "Qwen/Qwen2-VL-72B-Instruct", true),
+			(AdapterKind::Nebius, "llava-hf/llava-1.5-13b-hf", true),
+			(AdapterKind::Nebius, "mistralai/Pixtral-12B-2409", true),
+			(AdapterKind::Nebius, "Qwen/Qwen2.5-Coder-32B-Instruct", false),
+			(AdapterKind::Nebius, "meta-llama/Meta-Llama-3.1-70B-Instruct", false),
+		];
+
+		for (kind, model_id, image) in cases {
+			let modalities = ModelCapabilities::infer_input_modalities(kind, model_id);
+			assert!(modalities.contains(&Modality::Text), "{model_id}");
+			assert_eq!(modalities.contains(&Modality::Image), image, "{model_id}");
+		}
+	}
+
+	#[test]
+	fn test_model_capabilities_image_input_override() {
+		let model_id = "acme/custom-multimodal-override-test";
+		assert!(!ModelCapabilities::infer_input_modalities(AdapterKind::Together, model_id).contains(&Modality::Image));
+
+		ModelCapabilities::set_image_input_override(AdapterKind::Together, model_id, Some(true));
+		let modalities = ModelCapabilities::infer_input_modalities(AdapterKind::Together, model_id);
+		assert!(modalities.contains(&Modality::Image));
+		assert!(modalities.contains(&Modality::Text));
+
+		// a probe override keeps the image input override
+		ModelCapabilities::set_override(
+			AdapterKind::Together,
+			model_id,
+			CapabilityOverride {
+				streaming: Some(true),
+				image_input: ModelCapabilities::get_override(AdapterKind::Together, model_id)
+					.and_then(|o| o.image_input),
+				..Default::default()
+			},
+		);
+		assert!(ModelCapabilities::infer_input_modalities(AdapterKind::Together, model_id).contains(&Modality::Image));
+
+		// back to the heuristics
+		ModelCapabilities::set_image_input_override(AdapterKind::Together, model_id, None);
+		assert!(!ModelCapabilities::infer_input_modalities(AdapterKind::Together, model_id).contains(&Modality::Image));
+		assert_eq!(
+			ModelCapabilities::get_override(AdapterKind::Together, model_id).and_then(|o| o.streaming),
+			Some(true)
+		);
+
+		ModelCapabilities::set_image_input_override(AdapterKind::Nebius, "acme/custom-vl-override-test", Some(false));
+		assert!(
+			!ModelCapabilities::infer_input_modalities(AdapterKind::Nebius, "acme/custom-vl-override-test")
+				.contains(&Modality::Image)
+		);
+	}
+
+	/// Every public capability function gives the same result for the namespaced and the bare model id.
+	#[test]
+	fn test_model_capabilities_namespaced_model_id_same_as_bare() {
+		let cases = [
+			(AdapterKind::Zai, "glm-4.6", "zai::glm-4.6"),
+			(AdapterKind::Zai, "glm-4.5", "zai-coding::glm-4.5"),
+			(AdapterKind::Groq, "llama-3.1-8b-instant", "groq::llama-3.1-8b-instant"),
+			(AdapterKind::OpenAI, "gpt-4o", "openai::gpt-4o"),
+		];
+
+		for (kind, bare, namespaced) in cases {
+			assert_eq!(
+				ModelCapabilities::infer_token_limits(kind, bare),
+				ModelCapabilities::infer_token_limits(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::known_max_output_tokens(kind, bare),
+				ModelCapabilities::known_max_output_tokens(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::supports_streaming(kind, bare),
+				ModelCapabilities::supports_streaming(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::supports_tool_calls(kind, bare),
+				ModelCapabilities::supports_tool_calls(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::supports_json_mode(kind, bare),
+				ModelCapabilities::supports_json_mode(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::supports_reasoning(kind, bare),
+				ModelCapabilities::supports_reasoning(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::infer_input_modalities(kind, bare),
+				ModelCapabilities::infer_input_modalities(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::infer_output_modalities(kind, bare),
+				ModelCapabilities::infer_output_modalities(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::infer_reasoning_efforts(kind, bare),
+				ModelCapabilities::infer_reasoning_efforts(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::max_images_per_request(kind, bare),
+				ModelCapabilities::max_images_per_request(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::max_request_bytes(kind, bare),
+				ModelCapabilities::max_request_bytes(kind, namespaced),
+				"{namespaced}"
+			);
+			assert_eq!(
+				ModelCapabilities::max_embed_input_tokens(kind, bare),
+				ModelCapabilities::max_embed_input_tokens(kind, namespaced),
+				"{namespaced}"
+			);
+		}
+
+		// -- A namespaced model id reaches the provider heuristics (not only the generic fallback)
+		assert!(ModelCapabilities::supports_reasoning(AdapterKind::Zai, "zai::glm-4.5"));
+		assert_eq!(
+			ModelCapabilities::known_max_output_tokens(AdapterKind::OpenAI, "openai::gpt-4o"),
+			Some(16_384)
+		);
+		assert_eq!(
+			ModelCapabilities::max_embed_input_tokens(AdapterKind::OpenAI, "openai::text-embedding-3-small"),
+			Some(8_191)
+		);
+	}
+
+	#[test]
+	fn test_model_capabilities_reasoning_efforts() {
+		// -- Only the reasoning models of a provider
+		assert!(ModelCapabilities::supports_reasoning(
+			AdapterKind::Gemini,
+			"gemini-2.5-pro"
+		));
+		assert!(!ModelCapabilities::supports_reasoning(
+			AdapterKind::Gemini,
+			"gemini-1.5-flash"
+		));
+		assert!(ModelCapabilities::infer_reasoning_efforts(AdapterKind::Gemini, "gemini-1.5-flash").is_empty());
+		assert!(ModelCapabilities::infer_reasoning_efforts(AdapterKind::DeepSeek, "deepseek-chat").is_empty());
+		assert_eq!(
+			ModelCapabilities::infer_reasoning_efforts(AdapterKind::DeepSeek, "deepseek-reasoner").len(),
+			4
+		);
+
+		// -- The always-thinking models
+		assert_eq!(
+			ModelCapabilities::infer_reasoning_efforts(AdapterKind::Hunyuan, "hunyuan-t1-latest"),
+			vec![ReasoningEffortType::High]
+		);
+		assert_eq!(
+			ModelCapabilities::infer_reasoning_efforts(AdapterKind::Qianfan, "ernie-x1-turbo-32k"),
+			vec![ReasoningEffortType::High]
+		);
+
+		// -- BigModel serves the Zai (GLM) models
+		assert_eq!(
+			ModelCapabilities::infer_reasoning_efforts(AdapterKind::BigModel, "glm-4.5"),
+			ModelCapabilities::infer_reasoning_efforts(AdapterKind::Zai, "glm-4.5")
+		);
+	}
+
+	/// Every known model id (the adapters `MODELS`) has consistent capabilities.
+	#[test]
+	fn test_model_capabilities_known_models_consistent() {
+		use crate::adapter::adapters::{
+			ai21, aliyun, anthropic, cohere, copilot, deepseek, gemini, groq, hunyuan, lingyiwanwu, nebius, openai,
+			qianfan, stepfun, xai, zai,
+		};
+
+		const NO_STREAMING_PATTERNS: &[&str] = &["tts", "whisper", "dall-e"];
+		let known_models: &[(AdapterKind, &[&str])] = &[
+			(AdapterKind::AI21, ai21::MODELS),
+			(AdapterKind::Aliyun, aliyun::MODELS),
+			(AdapterKind::Anthropic, anthropic::MODELS),
+			(AdapterKind::Cohere, cohere::MODELS),
+			(AdapterKind::Copilot, copilot::MODELS),
+			(AdapterKind::DeepSeek, deepseek::MODELS),
+			(AdapterKind::Gemini, gemini::MODELS),
+			(AdapterKind::Groq, groq::MODELS),
+			(AdapterKind::Hunyuan, hunyuan::MODELS),
+			(AdapterKind::Lingyiwanwu, lingyiwanwu::MODELS),
+			(AdapterKind::Nebius, nebius::MODELS),
+			(AdapterKind::OpenAI, openai::MODELS),
+			(AdapterKind::Qianfan, qianfan::MODELS),
+			(AdapterKind::StepFun, stepfun::MODELS),
+			(AdapterKind::Xai, xai::MODELS),
+			(AdapterKind::Zai, zai::MODELS),
+		];
+
+		for &(kind, models) in known_models {
+			for &model_id in models {
+				// -- Reasoning flag <=> reasoning efforts
+				let efforts = ModelCapabilities::infer_reasoning_efforts(kind, model_id);
+				assert_eq!(
+					ModelCapabilities::supports_reasoning(kind, model_id),
+					!efforts.is_empty(),
+					"{kind}::{model_id} reasoning flag and efforts {efforts:?}"
+				);
+
+				// -- Output limit within the input limit
+				if let (Some(max_input), Some(max_output)) = ModelCapabilities::infer_token_limits(kind, model_id) {
+					assert!(
+						max_output <= max_input,
+						"{kind}::{model_id} max output {max_output} > max input {max_input}"
+					);
+				}
+
+				// -- Streaming, not an audio or image generation model
+				if ModelCapabilities::supports_streaming(kind, model_id) {
+					assert!(
+						!NO_STREAMING_PATTERNS.iter().any(|pattern| model_id.contains(pattern)),
+						"{kind}::{model_id} should not stream"
+					);
+				}
+			}
+		}
+
+		for model_id in ["tts-1", "whisper-1", "dall-e-3"] {
+			assert!(
+				!ModelCapabilities::supports_streaming(AdapterKind::OpenAI, model_id),
+				"{model_id}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_strip_namespace() {
+		assert_eq!(strip_namespace("groq::llama-3.1-8b-instant"), "llama-3.1-8b-instant");
+		assert_eq!(strip_namespace("ZAI::glm-4.6"), "glm-4.6");
+		assert_eq!(strip_namespace("glm-4.6"), "glm-4.6");
+		assert_eq!(strip_namespace("meta-llama/Llama-3.3-70B"), "meta-llama/Llama-3.3-70B");
+		// not an adapter namespace, kept as is
+		assert_eq!(strip_namespace("custom::my-model"), "custom::my-model");
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,12 @@
+//! Nebius capabilities (open-weights models).
+
+use super::open_weights::OPEN_WEIGHTS;
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{ModelCaps, ProviderCaps};
+
+pub(super) static NEBIUS: ProviderCaps = ProviderCaps {
+	// Nebius does not expose specific per-model limits publicly; use broad defaults.
+	rules: &[(Any, ModelCaps::limits(128_000, 8_192))],
+	base: Some(&OPEN_WEIGHTS),
+	ignore_case: false,
+};
@@ -0,0 +1,14 @@
+//! Ollama capabilities (local models, also for Ollama Cloud).
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{ModelCaps, ProviderCaps};
+
+pub(super) static OLLAMA: ProviderCaps = ProviderCaps {
+	rules: &[
+		// Local Ollama models – very rough defaults.
+		(Any, ModelCaps::limits(32_768, 8_192)),
+		(Any, ModelCaps::COMPAT),
+	],
+	base: None,
+	ignore_case: false,
+};
@@ -0,0 +1,27 @@
+//! The open-weights models, as served by Fireworks and Together (and Nebius, see `nebius`).
+
+use super::openai::OPENAI;
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{ModelCaps, ModelPattern, ProviderCaps, TEXT, TEXT_IMAGE};
+
+/// Open-weights vision models (e.g., `Llama-3.2-11B-Vision-Instruct`, `qwen2p5-vl-32b-instruct`, `llava-1.5-13b-hf`,
+/// `Pixtral-12B-2409`).
+const VISION: ModelPattern = AnyOf(&[
+	Contains("vision"),
+	Contains("-vl-"),
+	Suffix("-vl"),
+	Contains("llava"),
+	Contains("pixtral"),
+]);
+
+/// The model ids are case-insensitive (`Qwen/Qwen2.5-VL-72B-Instruct` on Together, `qwen2p5-vl-..` on Fireworks).
+///
+/// The token limits are the OpenAI ones (for the OpenAI model names only).
+pub(super) static OPEN_WEIGHTS: ProviderCaps = ProviderCaps {
+	rules: &[
+		(VISION, ModelCaps::input(TEXT_IMAGE)),
+		(Any, ModelCaps::COMPAT.with_input(TEXT)),
+	],
+	base: Some(&OPENAI),
+	ignore_case: true,
+};
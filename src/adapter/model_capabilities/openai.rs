@@ -0,0 +1,91 @@
+//! OpenAI capabilities, also the base table of the providers serving the OpenAI models
+//! (e.g., Copilot, DeepInfra, and the OpenAI Responses API).
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{
+	EFFORTS_ALL, EFFORTS_NONE, ModelCaps, ModelPattern, ProviderCaps, TEXT, TEXT_AUDIO, TEXT_IMAGE, TEXT_IMAGE_AUDIO,
+};
+
+/// The o-series reasoning models.
+const REASONING: ModelPattern = AnyOf(&[Prefix("o1"), Prefix("o3"), Prefix("o4")]);
+
+/// The chat models (tool calls and JSON mode).
+const CHAT: ModelPattern = AnyOf(&[
+	Prefix("gpt-4"),
+	Prefix("gpt-3.5"),
+	Prefix("o1"),
+	Prefix("o3"),
+	Prefix("o4"),
+	Prefix("chatgpt"),
+]);
+
+const VISION: ModelPattern = AnyOf(&[
+	Contains("vision"),
+	Prefix("gpt-4o"),
+	Prefix("gpt-4.1"),
+	Prefix("o1"),
+	Prefix("o3"),
+	Prefix("o4"),
+]);
+
+/// The audio transcription, speech, and image generation models (no streaming).
+const NO_STREAMING: ModelPattern = AnyOf(&[Contains("whisper"), Contains("tts"), Contains("dall-e")]);
+
+pub(super) static OPENAI: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		(Prefix("gpt-4.1"), ModelCaps::limits(128_000, 32_768)),
+		(Prefix("gpt-4o"), ModelCaps::limits(128_000, 16_384)),
+		(Prefix("o3"), ModelCaps::limits(200_000, 100_000)),
+		(Prefix("o4"), ModelCaps::limits(200_000, 100_000)),
+		(Prefix("o1"), ModelCaps::limits(200_000, 100_000)),
+		(
+			AllOf(&[Prefix("gpt-4"), Contains("32k")]),
+			ModelCaps::limits(32_768, 32_768),
+		),
+		(Prefix("gpt-4"), ModelCaps::limits(8_192, 4_096)),
+		(
+			AllOf(&[Prefix("gpt-3.5"), Contains("16k")]),
+			ModelCaps::limits(16_384, 16_384),
+		),
+		(Prefix("gpt-3.5"), ModelCaps::limits(4_096, 4_096)),
+		(Prefix("chatgpt"), ModelCaps::limits(16_384, 16_384)),
+		// -- Features
+		(NO_STREAMING, ModelCaps::NONE.with_streaming(false)),
+		(CHAT, ModelCaps::NONE.with_tool_calls(true).with_json_mode(true)),
+		(REASONING, ModelCaps::efforts(EFFORTS_ALL)),
+		// -- Modalities
+		(AllOf(&[VISION, Contains("audio")]), ModelCaps::input(TEXT_IMAGE_AUDIO)),
+		(VISION, ModelCaps::input(TEXT_IMAGE)),
+		(Contains("audio"), ModelCaps::input(TEXT_AUDIO)),
+		(Contains("tts"), ModelCaps::output(TEXT_AUDIO)),
+		(Contains("dall-e"), ModelCaps::output(TEXT_IMAGE)),
+		// -- Defaults (no token limits, unknown model)
+		(
+			Any,
+			ModelCaps::NONE
+				.with_streaming(true)
+				.with_tool_calls(false)
+				.with_json_mode(false)
+				.with_efforts(EFFORTS_NONE)
+				.with_input(TEXT)
+				.with_output(TEXT),
+		),
+	],
+	base: None,
+	ignore_case: false,
+};
+
+/// The OpenAI Responses API, tool calls for every model.
+pub(super) static OPENAI_RESP: ProviderCaps = ProviderCaps {
+	rules: &[(Any, ModelCaps::NONE.with_tool_calls(true))],
+	base: Some(&OPENAI),
+	ignore_case: false,
+};
+
+/// The OpenAI compatible providers serving the OpenAI models by name (e.g., Copilot, DeepInfra, Aliyun).
+pub(super) static OPENAI_COMPAT: ProviderCaps = ProviderCaps {
+	rules: &[(Any, ModelCaps::COMPAT)],
+	base: Some(&OPENAI),
+	ignore_case: false,
+};
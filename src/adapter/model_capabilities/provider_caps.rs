@@ -0,0 +1,223 @@
+//! The data-driven capability tables, one `ProviderCaps` per provider (see the sibling provider modules).
+
+use crate::common::{Modality, ReasoningEffortType};
+use std::borrow::Cow;
+
+// region:    --- Common Values
+
+pub(super) const EFFORTS_ALL: &[ReasoningEffortType] = &[
+	ReasoningEffortType::Low,
+	ReasoningEffortType::Medium,
+	ReasoningEffortType::High,
+	ReasoningEffortType::Budget,
+];
+/// For the always-thinking models (no effort control besides on/off).
+pub(super) const EFFORTS_HIGH: &[ReasoningEffortType] = &[ReasoningEffortType::High];
+/// Not a reasoning model.
+pub(super) const EFFORTS_NONE: &[ReasoningEffortType] = &[];
+
+pub(super) const TEXT: &[Modality] = &[Modality::Text];
+pub(super) const TEXT_IMAGE: &[Modality] = &[Modality::Text, Modality::Image];
+pub(super) const TEXT_AUDIO: &[Modality] = &[Modality::Text, Modality::Audio];
+pub(super) const TEXT_IMAGE_AUDIO: &[Modality] = &[Modality::Text, Modality::Image, Modality::Audio];
+
+// endregion: --- Common Values
+
+// region:    --- ModelPattern
+
+/// How a table rule matches the model id (without namespace).
+#[derive(Debug, Clone, Copy)]
+pub(super) enum ModelPattern {
+	Exact(&'static str),
+	Prefix(&'static str),
+	Contains(&'static str),
+	Suffix(&'static str),
+	AnyOf(&'static [ModelPattern]),
+	AllOf(&'static [ModelPattern]),
+	Not(&'static ModelPattern),
+	/// Every model id (e.g., the last rule of a table, as the provider default).
+	Any,
+}
+
+impl ModelPattern {
+	pub(super) fn matches(&self, model_id: &str) -> bool {
+		match self {
+			Self::Exact(id) => model_id == *id,
+			Self::Prefix(prefix) => model_id.starts_with(prefix),
+			Self::Contains(part) => model_id.contains(part),
+			Self::Suffix(suffix) => model_id.ends_with(suffix),
+			Self::AnyOf(patterns) => patterns.iter().any(|pattern| pattern.matches(model_id)),
+			Self::AllOf(patterns) => patterns.iter().all(|pattern| pattern.matches(model_id)),
+			Self::Not(pattern) => !pattern.matches(model_id),
+			Self::Any => true,
+		}
+	}
+}
+
+// endregion: --- ModelPattern
+
+// region:    --- ModelCaps
+
+/// The capabilities set by a table rule (`None` when the rule does not set it).
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ModelCaps {
+	/// (max input tokens, max output tokens)
+	pub token_limits: Option<(Option<u32>, Option<u32>)>,
+	pub streaming: Option<bool>,
+	pub tool_calls: Option<bool>,
+	pub json_mode: Option<bool>,
+	/// The reasoning effort types, empty when not a reasoning model
+	/// (the model supports reasoning if and only if not empty).
+	pub reasoning_efforts: Option<&'static [ReasoningEffortType]>,
+	pub input_modalities: Option<&'static [Modality]>,
+	pub output_modalities: Option<&'static [Modality]>,
+}
+
+/// Constructors (const, for the static tables)
+impl ModelCaps {
+	pub(super) const NONE: Self = Self {
+		token_limits: None,
+		streaming: None,
+		tool_calls: None,
+		json_mode: None,
+		reasoning_efforts: None,
+		input_modalities: None,
+		output_modalities: None,
+	};
+
+	/// Streaming, tool calls, and JSON mode (e.g., the OpenAI compatible providers default).
+	pub(super) const COMPAT: Self = Self::NONE.with_streaming(true).with_tool_calls(true).with_json_mode(true);
+
+	pub(super) const fn limits(max_input: u32, max_output: u32) -> Self {
+		let mut caps = Self::NONE;
+		caps.token_limits = Some((Some(max_input), Some(max_output)));
+		caps
+	}
+
+	pub(super) const fn efforts(efforts: &'static [ReasoningEffortType]) -> Self {
+		Self::NONE.with_efforts(efforts)
+	}
+
+	pub(super) const fn input(modalities: &'static [Modality]) -> Self {
+		Self::NONE.with_input(modalities)
+	}
+
+	pub(super) const fn output(modalities: &'static [Modality]) -> Self {
+		Self::NONE.with_output(modalities)
+	}
+
+	pub(super) const fn with_streaming(mut self, streaming: bool) -> Self {
+		self.streaming = Some(streaming);
+		self
+	}
+
+	pub(super) const fn with_tool_calls(mut self, tool_calls: bool) -> Self {
+		self.tool_calls = Some(tool_calls);
+		self
+	}
+
+	pub(super) const fn with_json_mode(mut self, json_mode: bool) -> Self {
+		self.json_mode = Some(json_mode);
+		self
+	}
+
+	pub(super) const fn with_efforts(mut self, efforts: &'static [ReasoningEffortType]) -> Self {
+		self.reasoning_efforts = Some(efforts);
+		self
+	}
+
+	pub(super) const fn with_input(mut self, modalities: &'static [Modality]) -> Self {
+		self.input_modalities = Some(modalities);
+		self
+	}
+
+	pub(super) const fn with_output(mut self, modalities: &'static [Modality]) -> Self {
+		self.output_modalities = Some(modalities);
+		self
+	}
+}
+
+// endregion: --- ModelCaps
+
+// region:    --- ProviderCaps
+
+/// The capability table of a provider: the rules, in order, and an eventual base table.
+///
+/// A capability is the one of the first matching rule setting it, then the one of the `base` table
+/// (e.g., the OpenAI table for the providers serving the OpenAI models), otherwise `None`
+/// (and `ModelCapabilities` falls back to the other providers).
+pub(super) struct ProviderCaps {
+	pub rules: &'static [(ModelPattern, ModelCaps)],
+	pub base: Option<&'static ProviderCaps>,
+	/// Match the rules on the lower-cased model id (e.g., `Qwen/Qwen2.5-VL-72B-Instruct`).
+	pub ignore_case: bool,
+}
+
+impl ProviderCaps {
+	pub(super) fn lookup<T>(&self, model_id: &str, field: fn(&ModelCaps) -> Option<T>) -> Option<T> {
+		let rule_model_id = if self.ignore_case {
+			Cow::Owned(model_id.to_lowercase())
+		} else {
+			Cow::Borrowed(model_id)
+		};
+
+		self.rules
+			.iter()
+			.filter(|(pattern, _)| pattern.matches(&rule_model_id))
+			.find_map(|(_, caps)| field(caps))
+			.or_else(|| self.base.and_then(|base| base.lookup(model_id, field)))
+	}
+}
+
+// endregion: --- ProviderCaps
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ModelPattern::*;
+
+	static TEST_CAPS: ProviderCaps = ProviderCaps {
+		rules: &[
+			(Prefix("model-a"), ModelCaps::limits(1_000, 100)),
+			(
+				AllOf(&[Prefix("model"), Not(&Contains("mini"))]),
+				ModelCaps::efforts(EFFORTS_ALL),
+			),
+			(Any, ModelCaps::limits(500, 50)),
+		],
+		base: Some(&BASE_CAPS),
+		ignore_case: true,
+	};
+
+	static BASE_CAPS: ProviderCaps = ProviderCaps {
+		rules: &[(Any, ModelCaps::COMPAT)],
+		base: None,
+		ignore_case: false,
+	};
+
+	#[test]
+	fn test_provider_caps_lookup_first_rule_then_base() {
+		// -- First matching rule setting the capability
+		assert_eq!(
+			TEST_CAPS.lookup("Model-A-1", |caps| caps.token_limits),
+			Some((Some(1_000), Some(100)))
+		);
+		assert_eq!(
+			TEST_CAPS.lookup("model-b", |caps| caps.token_limits),
+			Some((Some(500), Some(50)))
+		);
+		assert_eq!(
+			TEST_CAPS.lookup("model-a", |caps| caps.reasoning_efforts.map(<[_]>::len)),
+			Some(4)
+		);
+
+		// -- Not set by the table rules, from the base
+		assert_eq!(TEST_CAPS.lookup("model-mini", |caps| caps.reasoning_efforts), None);
+		assert_eq!(TEST_CAPS.lookup("model-mini", |caps| caps.json_mode), Some(true));
+		assert_eq!(BASE_CAPS.lookup("model-a", |caps| caps.token_limits), None);
+	}
+}
+
+// endregion: --- Tests
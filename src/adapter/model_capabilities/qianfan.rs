@@ -0,0 +1,32 @@
+//! Qianfan (ERNIE) capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_HIGH, EFFORTS_NONE, ModelCaps, ProviderCaps};
+
+pub(super) static QIANFAN: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// --- Context size is in the model name (e.g., ernie-4.5-turbo-128k) ---
+		(
+			AllOf(&[Prefix("ernie"), Suffix("-128k")]),
+			ModelCaps::limits(128_000, 12_288),
+		),
+		(
+			AllOf(&[Prefix("ernie"), Suffix("-32k")]),
+			ModelCaps::limits(32_000, 12_288),
+		),
+		(
+			AllOf(&[Prefix("ernie"), Suffix("-8k")]),
+			ModelCaps::limits(8_000, 2_048),
+		),
+		// --- ERNIE 4.5 / 4.0 without size suffix ---
+		(Prefix("ernie-4.5"), ModelCaps::limits(128_000, 12_288)),
+		(Prefix("ernie-4.0"), ModelCaps::limits(8_000, 2_048)),
+		// -- Features (X1 is an always-thinking reasoning model)
+		(Prefix("ernie-x1"), ModelCaps::efforts(EFFORTS_HIGH)),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_efforts(EFFORTS_NONE)),
+	],
+	base: None,
+	ignore_case: false,
+};
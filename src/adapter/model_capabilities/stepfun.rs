@@ -0,0 +1,27 @@
+//! StepFun capabilities.
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{ModelCaps, ProviderCaps, TEXT, TEXT_IMAGE};
+
+pub(super) static STEPFUN: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// --- Context size is in the model name (e.g., step-2-16k, step-1v-32k) ---
+		(Suffix("-8k"), ModelCaps::limits(8_000, 4_096)),
+		(Suffix("-16k"), ModelCaps::limits(16_000, 8_192)),
+		(Suffix("-32k"), ModelCaps::limits(32_000, 8_192)),
+		(Suffix("-128k"), ModelCaps::limits(128_000, 8_192)),
+		(Suffix("-256k"), ModelCaps::limits(256_000, 8_192)),
+		// --- Mini variants ---
+		(Prefix("step-2-mini"), ModelCaps::limits(32_000, 8_192)),
+		// -- Modalities (vision models, e.g., step-1v-8k, step-1o-vision-32k, step-1.5v-mini)
+		(
+			AnyOf(&[Contains("1v"), Contains("1.5v"), Contains("vision")]),
+			ModelCaps::input(TEXT_IMAGE),
+		),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_input(TEXT)),
+	],
+	base: None,
+	ignore_case: false,
+};
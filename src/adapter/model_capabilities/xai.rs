@@ -0,0 +1,37 @@
+//! xAI capabilities (verified from the official xAI docs 2025).
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_ALL, EFFORTS_NONE, ModelCaps, ProviderCaps, TEXT, TEXT_IMAGE};
+
+pub(super) static XAI: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// --- Grok 4 Series ---
+		(Exact("grok-4-0709"), ModelCaps::limits(256_000, 32_768)), // 256K context, estimate 32K output
+		// --- Grok 3 Series ---
+		(Exact("grok-3"), ModelCaps::limits(131_072, 32_768)), // 131K context, estimate 32K output
+		(Exact("grok-3-mini"), ModelCaps::limits(131_072, 16_384)), // 131K context, estimate 16K output
+		(Exact("grok-3-fast"), ModelCaps::limits(131_072, 32_768)), // 131K context, estimate 32K output
+		(Exact("grok-3-mini-fast"), ModelCaps::limits(131_072, 8_192)), // 131K context, estimate 8K output
+		// --- Grok 2 Vision Series ---
+		(Exact("grok-2-vision-1212"), ModelCaps::limits(32_768, 8_192)), // 32K context, estimate 8K output
+		// --- Legacy/Generic Grok models ---
+		(Contains("grok-4"), ModelCaps::limits(256_000, 32_768)), // Fallback for grok-4 variants
+		(Contains("grok-3"), ModelCaps::limits(131_072, 32_768)), // Fallback for grok-3 variants
+		(Contains("grok"), ModelCaps::limits(131_072, 32_768)),   // Generic grok fallback
+		// -- Features (only these models support general reasoning)
+		(
+			AnyOf(&[Exact("grok-4-0709"), Exact("grok-3-mini"), Exact("grok-3-mini-fast")]),
+			ModelCaps::efforts(EFFORTS_ALL),
+		),
+		// -- Modalities (only grok-4-0709 and grok-2-vision-1212 support image input)
+		(
+			AnyOf(&[Exact("grok-4-0709"), Contains("grok-2-vision-1212")]),
+			ModelCaps::input(TEXT_IMAGE),
+		),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_efforts(EFFORTS_NONE).with_input(TEXT)),
+	],
+	base: None,
+	ignore_case: false,
+};
@@ -0,0 +1,45 @@
+//! Zai (GLM) capabilities, also for BigModel (same models).
+
+use super::provider_caps::ModelPattern::*;
+use super::provider_caps::{EFFORTS_HIGH, EFFORTS_NONE, ModelCaps, ProviderCaps, TEXT, TEXT_IMAGE};
+
+pub(super) static ZAI: ProviderCaps = ProviderCaps {
+	rules: &[
+		// -- Token limits
+		// --- GLM-4.5 Series (verified from official docs 2025) ---
+		(Exact("glm-4.5"), ModelCaps::limits(128_000, 32_768)), // 128K context, estimate 32K output
+		(Exact("glm-4.5-x"), ModelCaps::limits(128_000, 32_768)), // 128K context, estimate 32K output
+		(Exact("glm-4.5-air"), ModelCaps::limits(128_000, 16_384)), // 128K context, estimate 16K output (lightweight)
+		(Exact("glm-4.5-airx"), ModelCaps::limits(128_000, 16_384)), // 128K context, estimate 16K output (lightweight)
+		(Exact("glm-4.5-flash"), ModelCaps::limits(128_000, 8_192)), // 128K context, estimate 8K output (free tier)
+		// --- GLM-4-32B Series ---
+		(Exact("glm-4-32b-0414-128k"), ModelCaps::limits(128_000, 32_768)), // 128K context, estimate 32K output
+		// --- Legacy GLM-4 Series (maintaining existing configs) ---
+		(Prefix("glm-4-plus"), ModelCaps::limits(128_000, 32_768)),
+		(Prefix("glm-4-air"), ModelCaps::limits(128_000, 16_384)),
+		(Prefix("glm-4-flash"), ModelCaps::limits(128_000, 8_192)),
+		(Prefix("glm-4-long"), ModelCaps::limits(1_000_000, 32_768)), // Long context model
+		// --- Vision Models ---
+		(Contains("4v"), ModelCaps::limits(128_000, 16_384)),
+		// --- Other Models ---
+		(Prefix("glm-z1"), ModelCaps::limits(128_000, 16_384)),
+		(Contains("thinking"), ModelCaps::limits(128_000, 32_768)), // Thinking models
+		// --- Generic fallback ---
+		(Prefix("glm-4"), ModelCaps::limits(128_000, 16_384)),
+		(Prefix("glm"), ModelCaps::limits(128_000, 8_192)),
+		// -- Features (the GLM-4.5 thinking models, verified from official docs 2025)
+		(
+			AllOf(&[Contains("glm-4.5"), Not(&Contains("air"))]),
+			ModelCaps::efforts(EFFORTS_HIGH),
+		),
+		// -- Modalities (vision models, verified from official docs 2025)
+		(
+			AnyOf(&[Contains("4v"), Contains("vision")]),
+			ModelCaps::input(TEXT_IMAGE),
+		),
+		// -- Defaults
+		(Any, ModelCaps::COMPAT.with_efforts(EFFORTS_NONE).with_input(TEXT)),
+	],
+	base: None,
+	ignore_case: false,
+};
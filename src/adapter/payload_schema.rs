@@ -0,0 +1,355 @@
+//! Lightweight JSON shape checks for the adapter request payloads.
+//!
+//! The checks are expressed as data (a small list of `FieldRule` per adapter/service type) so that they are cheap to
+//! maintain. They are run by the client before any network call when `ClientConfig::validate_payloads` is on
+//! (by default in debug builds only, see `with_validate_payloads`).
+//!
+//! NOTE: These are not full provider schemas; they only catch the structural mistakes
+//!       (e.g., wrong `response_format` nesting, `tools` not an array) that would otherwise surface as provider 400s.
+
+use crate::adapter::{AdapterKind, ApiFamily, ServiceType};
+use crate::{Error, ModelIden, Result};
+use serde_json::Value;
+
+// region:    --- Types
+
+/// The expected JSON type of a payload field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JsonType {
+	Object,
+	Array,
+	String,
+	Number,
+	Bool,
+}
+
+impl JsonType {
+	fn matches(self, value: &Value) -> bool {
+		match self {
+			JsonType::Object => value.is_object(),
+			JsonType::Array => value.is_array(),
+			JsonType::String => value.is_string(),
+			JsonType::Number => value.is_number(),
+			JsonType::Bool => value.is_boolean(),
+		}
+	}
+
+	fn as_str(self) -> &'static str {
+		match self {
+			JsonType::Object => "object",
+			JsonType::Array => "array",
+			JsonType::String => "string",
+			JsonType::Number => "number",
+			JsonType::Bool => "boolean",
+		}
+	}
+}
+
+/// A single shape rule on a payload field, addressed by a JSON pointer (e.g., `/response_format/type`).
+///
+/// - When `required` is true, the field must be present whenever its parent is present
+///   (so `/response_format/type` is only required if `/response_format` is set).
+/// - When present, the field must be of the `json_type`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FieldRule {
+	pub pointer: &'static str,
+	pub json_type: JsonType,
+	pub required: bool,
+}
+
+const fn required(pointer: &'static str, json_type: JsonType) -> FieldRule {
+	FieldRule {
+		pointer,
+		json_type,
+		required: true,
+	}
+}
+
+const fn optional(pointer: &'static str, json_type: JsonType) -> FieldRule {
+	FieldRule {
+		pointer,
+		json_type,
+		required: false,
+	}
+}
+
+// endregion: --- Types
+
+// region:    --- Schemas
+
+const OPENAI_CHAT: &[FieldRule] = &[
+	required("/model", JsonType::String),
+	required("/messages", JsonType::Array),
+	optional("/stream", JsonType::Bool),
+	optional("/stream_options", JsonType::Object),
+	optional("/tools", JsonType::Array),
+	optional("/stop", JsonType::Array),
+	optional("/response_format", JsonType::Object),
+	required("/response_format/type", JsonType::String),
+	required("/response_format/json_schema/schema", JsonType::Object),
+];
+
+const OPENAI_RESP_CHAT: &[FieldRule] = &[
+	required("/model", JsonType::String),
+	required("/input", JsonType::Array),
+	optional("/stream", JsonType::Bool),
+	optional("/tools", JsonType::Array),
+	optional("/reasoning", JsonType::Object),
+	optional("/text", JsonType::Object),
+	optional("/text/format", JsonType::Object),
+	required("/text/format/type", JsonType::String),
+];
+
+const ANTHROPIC_CHAT: &[FieldRule] = &[
+	required("/model", JsonType::String),
+	required("/messages", JsonType::Array),
+	required("/max_tokens", JsonType::Number),
+	optional("/stream", JsonType::Bool),
+	optional("/tools", JsonType::Array),
+	optional("/stop_sequences", JsonType::Array),
+	optional("/thinking", JsonType::Object),
+	optional("/output_config", JsonType::Object),
+];
+
+const GEMINI_CHAT: &[FieldRule] = &[
+	required("/contents", JsonType::Array),
+	optional("/tools", JsonType::Array),
+	optional("/systemInstruction", JsonType::Object),
+	optional("/generationConfig", JsonType::Object),
+	optional("/generationConfig/stopSequences", JsonType::Array),
+];
+
+const COHERE_CHAT: &[FieldRule] = &[
+	required("/model", JsonType::String),
+	optional("/stream", JsonType::Bool),
+	optional("/chat_history", JsonType::Array),
+	optional("/stop_sequences", JsonType::Array),
+];
+
+const OLLAMA_CHAT: &[FieldRule] = &[
+	required("/model", JsonType::String),
+	required("/messages", JsonType::Array),
+	optional("/stream", JsonType::Bool),
+	optional("/tools", JsonType::Array),
+	optional("/options", JsonType::Object),
+];
+
+const COPILOT_CHAT: &[FieldRule] = &[
+	required("/model", JsonType::String),
+	required("/messages", JsonType::Array),
+	optional("/stream", JsonType::Bool),
+	optional("/tools", JsonType::Array),
+];
+
+/// Vertex serves both Gemini and Anthropic models, so only the fields common to both shapes are checked.
+const VERTEX_CHAT: &[FieldRule] = &[
+	optional("/contents", JsonType::Array),
+	optional("/messages", JsonType::Array),
+	optional("/tools", JsonType::Array),
+];
+
+/// Returns the payload rules for the given adapter kind and service type, if any.
+pub(crate) fn payload_schema(adapter_kind: AdapterKind, service_type: ServiceType) -> Option<&'static [FieldRule]> {
+	if !matches!(service_type, ServiceType::Chat | ServiceType::ChatStream) {
+		return None;
+	}
+
+	// Copilot serves `chat/completions`, with its own (smaller) payload.
+	if adapter_kind == AdapterKind::Copilot {
+		return Some(COPILOT_CHAT);
+	}
+
+	let rules = match adapter_kind.api_family() {
+		ApiFamily::OpenAICompat => OPENAI_CHAT,
+		ApiFamily::OpenAIResponses => OPENAI_RESP_CHAT,
+		ApiFamily::Anthropic => ANTHROPIC_CHAT,
+		ApiFamily::Gemini => GEMINI_CHAT,
+		ApiFamily::CohereV2 => COHERE_CHAT,
+		ApiFamily::Ollama => OLLAMA_CHAT,
+		ApiFamily::Vertex => VERTEX_CHAT,
+		#[cfg(feature = "mock")]
+		ApiFamily::Mock => return None,
+	};
+
+	Some(rules)
+}
+
+// endregion: --- Schemas
+
+// region:    --- Validation
+
+/// Validates the request `payload` against the schema of the model adapter kind and service type.
+///
+/// Returns `Error::RequestPayloadInvalid` listing all the violations found.
+pub(crate) fn validate_payload(model_iden: &ModelIden, service_type: ServiceType, payload: &Value) -> Result<()> {
+	let Some(rules) = payload_schema(model_iden.adapter_kind, service_type) else {
+		return Ok(());
+	};
+
+	if !payload.is_object() {
+		return Err(Error::RequestPayloadInvalid {
+			model_iden: model_iden.clone(),
+			cause: "payload must be a JSON object".to_string(),
+		});
+	}
+
+	let violations: Vec<String> = rules.iter().filter_map(|rule| check_rule(rule, payload)).collect();
+
+	if violations.is_empty() {
+		Ok(())
+	} else {
+		Err(Error::RequestPayloadInvalid {
+			model_iden: model_iden.clone(),
+			cause: violations.join("\n"),
+		})
+	}
+}
+
+fn check_rule(rule: &FieldRule, payload: &Value) -> Option<String> {
+	match payload.pointer(rule.pointer) {
+		Some(value) if !rule.json_type.matches(value) => Some(format!(
+			"'{}' must be of type {} (actual: {})",
+			rule.pointer,
+			rule.json_type.as_str(),
+			json_type_name(value)
+		)),
+		Some(_) => None,
+		None => {
+			if !rule.required {
+				return None;
+			}
+			// Only required if the parent is present (root is always present)
+			let parent_pointer = rule.pointer.rsplit_once('/').map(|(parent, _)| parent).unwrap_or_default();
+			payload
+				.pointer(parent_pointer)
+				.is_some()
+				.then(|| format!("'{}' is required ({})", rule.pointer, rule.json_type.as_str()))
+		}
+	}
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+	match value {
+		Value::Null => "null",
+		Value::Bool(_) => "boolean",
+		Value::Number(_) => "number",
+		Value::String(_) => "string",
+		Value::Array(_) => "array",
+		Value::Object(_) => "object",
+	}
+}
+
+// endregion: --- Validation
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ServiceTarget;
+	use crate::adapter::{AdapterDispatcher, WebRequestData};
+	use crate::chat::{ChatMessage, ChatOptions, ChatOptionsSet, ChatRequest, ChatResponseFormat};
+	use crate::resolver::AuthData;
+	use serde_json::json;
+
+	fn build_payload(adapter_kind: AdapterKind, model_name: &str, options: Option<&ChatOptions>) -> Result<Value> {
+		let target = ServiceTarget {
+			endpoint: AdapterDispatcher::default_endpoint(adapter_kind),
+			auth: AuthData::from_single("test-api-key"),
+			model: ModelIden::new(adapter_kind, model_name),
+		};
+		let chat_req = ChatRequest::from_user("Hello")
+			.append_message(ChatMessage::assistant("Hi"))
+			.append_message(ChatMessage::user("Weather?"));
+		let options_set = ChatOptionsSet::default().with_chat_options(options);
+
+		let WebRequestData { payload, .. } =
+			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set)?;
+		Ok(payload)
+	}
+
+	/// Shallow merge, as an `extra_body`-style passthrough would do.
+	fn merge_extra_body(payload: &mut Value, extra_body: Value) {
+		if let (Some(payload), Value::Object(extra_body)) = (payload.as_object_mut(), extra_body) {
+			payload.extend(extra_body);
+		}
+	}
+
+	#[test]
+	fn test_validate_payload_valid_adapter_payloads() -> Result<()> {
+		let cases = [
+			(AdapterKind::OpenAI, "gpt-4o-mini"),
+			(AdapterKind::OpenAIResp, "gpt-5-mini"),
+			(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+			(AdapterKind::Gemini, "gemini-2.5-flash"),
+			(AdapterKind::Groq, "llama-3.1-8b-instant"),
+			(AdapterKind::Ollama, "gemma3:4b"),
+		];
+		let options = ChatOptions::default().with_response_format(ChatResponseFormat::JsonMode);
+
+		for (adapter_kind, model_name) in cases {
+			let payload = build_payload(adapter_kind, model_name, Some(&options))?;
+			let model_iden = ModelIden::new(adapter_kind, model_name);
+			validate_payload(&model_iden, ServiceType::Chat, &payload)?;
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_payload_tools_not_array() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let mut payload = build_payload(AdapterKind::OpenAI, "gpt-4o-mini", None)?;
+		merge_extra_body(&mut payload, json!({"tools": {"name": "get_weather"}}));
+
+		let err = validate_payload(&model_iden, ServiceType::Chat, &payload).expect_err("should fail");
+
+		let Error::RequestPayloadInvalid { cause, .. } = err else {
+			panic!("expected Error::RequestPayloadInvalid, got: {err}");
+		};
+		assert!(
+			cause.contains("'/tools' must be of type array (actual: object)"),
+			"cause: {cause}"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_payload_response_format_wrong_nesting() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let mut payload = build_payload(AdapterKind::OpenAI, "gpt-4o-mini", None)?;
+		// `schema` at the wrong level, and no `type`
+		merge_extra_body(&mut payload, json!({"response_format": {"schema": {"type": "object"}}}));
+
+		let err = validate_payload(&model_iden, ServiceType::Chat, &payload).expect_err("should fail");
+
+		let Error::RequestPayloadInvalid { cause, .. } = err else {
+			panic!("expected Error::RequestPayloadInvalid, got: {err}");
+		};
+		assert!(cause.contains("'/response_format/type' is required"), "cause: {cause}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_payload_anthropic_missing_max_tokens() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5");
+		let mut payload = build_payload(AdapterKind::Anthropic, "claude-sonnet-4-5", None)?;
+		if let Some(obj) = payload.as_object_mut() {
+			obj.remove("max_tokens");
+		}
+
+		let err = validate_payload(&model_iden, ServiceType::ChatStream, &payload).expect_err("should fail");
+
+		assert!(err.to_string().contains("'/max_tokens' is required"), "err: {err}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_payload_no_schema_for_embed() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "text-embedding-3-small");
+		validate_payload(&model_iden, ServiceType::Embed, &json!("not an object"))?;
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
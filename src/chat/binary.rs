@@ -139,6 +139,15 @@ impl Binary {
 		};
 		size
 	}
+
+	/// Returns the estimated decoded byte length of a base64 binary (`None` for a URL binary,
+	/// as its content is not fetched).
+	pub fn decoded_size(&self) -> Option<usize> {
+		match &self.source {
+			BinarySource::Url(_) => None,
+			BinarySource::Base64(data) => Some(data.trim_end_matches('=').len() * 3 / 4),
+		}
+	}
 }
 
 // region:    --- BinarySource
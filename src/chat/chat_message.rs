@@ -19,6 +19,11 @@ pub struct ChatMessage {
 
 	/// Optional per-message options (e.g., cache control).
 	pub options: Option<MessageOptions>,
+
+	/// Optional client-side metadata (e.g., id, timestamp) for application bookkeeping.
+	/// Never sent to the provider, but kept by the serialization of the `ChatRequest` (for persistence).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<MessageMetadata>,
 }
 
 // region:    --- Constructors
@@ -29,6 +34,7 @@ impl ChatMessage {
 			role,
 			content: content.into(),
 			options: None,
+			metadata: None,
 		}
 	}
 
@@ -38,6 +44,17 @@ impl ChatMessage {
 			role: ChatRole::System,
 			content: content.into(),
 			options: None,
+			metadata: None,
+		}
+	}
+
+	/// Constructs a developer message (OpenAI `developer` role, `system` for the other providers).
+	pub fn developer(content: impl Into<MessageContent>) -> Self {
+		Self {
+			role: ChatRole::Developer,
+			content: content.into(),
+			options: None,
+			metadata: None,
 		}
 	}
 
@@ -47,6 +64,7 @@ impl ChatMessage {
 			role: ChatRole::Assistant,
 			content: content.into(),
 			options: None,
+			metadata: None,
 		}
 	}
 
@@ -56,6 +74,7 @@ impl ChatMessage {
 			role: ChatRole::User,
 			content: content.into(),
 			options: None,
+			metadata: None,
 		}
 	}
 
@@ -65,6 +84,7 @@ impl ChatMessage {
 			role: ChatRole::Tool,
 			content: content.into(),
 			options: None,
+			metadata: None,
 		}
 	}
 }
@@ -89,6 +109,12 @@ impl ChatMessage {
 		self
 	}
 
+	/// Attaches client-side metadata to this message (not sent to the provider).
+	pub fn with_metadata(mut self, metadata: MessageMetadata) -> Self {
+		self.metadata = Some(metadata);
+		self
+	}
+
 	/// Attach reasoning content to this message as a `ContentPart::ReasoningContent` part.
 	/// This is used for round-tripping assistant reasoning (e.g., DeepSeek, Kimi).
 	pub fn with_reasoning_content(mut self, reasoning: Option<String>) -> Self {
@@ -110,6 +136,46 @@ impl ChatMessage {
 }
 // endregion: --- Builders
 
+// region:    --- MessageMetadata
+
+/// Client-side message metadata, for application bookkeeping (e.g., persistence, UI).
+///
+/// NOTE: Never sent to the provider, the adapters only read the role, content, and options of the messages.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MessageMetadata {
+	/// Application message id.
+	pub id: Option<String>,
+
+	/// Creation time, in milliseconds since the Unix epoch.
+	pub timestamp_ms: Option<i64>,
+}
+
+impl MessageMetadata {
+	/// Returns the metadata with the current time as `timestamp_ms`.
+	pub fn now() -> Self {
+		Self::default().with_timestamp_ms(now_unix_ms())
+	}
+
+	pub fn with_id(mut self, id: impl Into<String>) -> Self {
+		self.id = Some(id.into());
+		self
+	}
+
+	pub fn with_timestamp_ms(mut self, timestamp_ms: i64) -> Self {
+		self.timestamp_ms = Some(timestamp_ms);
+		self
+	}
+}
+
+fn now_unix_ms() -> i64 {
+	std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|duration| i64::try_from(duration.as_millis()).unwrap_or(i64::MAX))
+		.unwrap_or(0)
+}
+
+// endregion: --- MessageMetadata
+
 // region:    --- MessageOptions
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, From)]
@@ -182,6 +248,9 @@ impl From<CacheControl> for MessageOptions {
 #[allow(missing_docs)]
 pub enum ChatRole {
 	System,
+	/// OpenAI `developer` role (replaces `system` for the o-series and gpt-5 models).
+	/// Sent as `system` for the models and providers that do not support it.
+	Developer,
 	User,
 	Assistant,
 	Tool,
@@ -205,6 +274,7 @@ impl From<Vec<ToolCall>> for ChatMessage {
 			role: ChatRole::Assistant,
 			content: MessageContent::from(tool_calls),
 			options: None,
+			metadata: None,
 		}
 	}
 }
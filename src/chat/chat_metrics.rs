@@ -0,0 +1,90 @@
+//! Client-side timing metrics for chat responses (latency, time to first token, tokens per second).
+
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+/// Timing metrics measured by the client for a chat request.
+///
+/// - For `exec_chat`, set on `ChatResponse::metrics`. The latency covers the HTTP request and full response body.
+/// - For `exec_chat_stream`, set on `StreamEnd::metrics`. The latency starts when the stream is first polled
+///   (which is when the request is sent) and ends at the `End` event.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ChatMetrics {
+	/// Total request latency, in milliseconds.
+	pub latency_ms: u64,
+
+	/// Time to the first content, reasoning, or tool call chunk, in milliseconds (streaming only).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub time_to_first_token_ms: Option<u64>,
+
+	/// Output tokens per second, when the usage reports `completion_tokens`.
+	/// For streams, this is measured from the first token to the end (generation rate).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub tokens_per_second: Option<f64>,
+}
+
+impl ChatMetrics {
+	/// Computes the metrics from the request timing.
+	///
+	/// `first_token_at` is `None` for non-streaming requests, in which case the tokens per second
+	/// are computed over the whole latency.
+	pub(crate) fn from_timing(
+		started_at: Instant,
+		first_token_at: Option<Instant>,
+		ended_at: Instant,
+		completion_tokens: Option<i32>,
+	) -> Self {
+		let latency = ended_at.saturating_duration_since(started_at);
+		let time_to_first_token = first_token_at.map(|first| first.saturating_duration_since(started_at));
+
+		let generation = match first_token_at {
+			Some(first) => ended_at.saturating_duration_since(first),
+			None => latency,
+		};
+		let tokens_per_second = completion_tokens
+			.filter(|tokens| *tokens > 0)
+			.filter(|_| !generation.is_zero())
+			.map(|tokens| tokens as f64 / generation.as_secs_f64());
+
+		Self {
+			latency_ms: latency.as_millis() as u64,
+			time_to_first_token_ms: time_to_first_token.map(|ttft| ttft.as_millis() as u64),
+			tokens_per_second,
+		}
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	#[test]
+	fn test_chat_metrics_from_timing_non_stream() {
+		let started_at = Instant::now();
+		let ended_at = started_at + Duration::from_millis(500);
+
+		let metrics = ChatMetrics::from_timing(started_at, None, ended_at, Some(50));
+
+		assert_eq!(metrics.latency_ms, 500);
+		assert_eq!(metrics.time_to_first_token_ms, None);
+		assert_eq!(metrics.tokens_per_second, Some(100.0));
+	}
+
+	#[test]
+	fn test_chat_metrics_from_timing_stream_without_usage() {
+		let started_at = Instant::now();
+		let first_token_at = started_at + Duration::from_millis(120);
+		let ended_at = started_at + Duration::from_millis(320);
+
+		let metrics = ChatMetrics::from_timing(started_at, Some(first_token_at), ended_at, None);
+
+		assert_eq!(metrics.latency_ms, 320);
+		assert_eq!(metrics.time_to_first_token_ms, Some(120));
+		assert_eq!(metrics.tokens_per_second, None);
+	}
+}
+
+// endregion: --- Tests
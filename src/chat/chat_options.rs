@@ -6,12 +6,22 @@
 //! Note 2: Kept separate from `ChatRequest` for easier reuse and composition.
 
 use crate::Headers;
-use crate::chat::CacheControl;
+use crate::adapter::AdapterKind;
 use crate::chat::chat_req_response_format::ChatResponseFormat;
+use crate::chat::{CacheControl, ToolChoice};
+use crate::resolver::StreamTransport;
+use crate::support;
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::ops::Deref;
 
+/// Seed of the `ChatOptions::deterministic()` preset.
+const DETERMINISTIC_SEED: u64 = 42;
+
+/// Default maximum size of a response, when `ChatOptions::max_response_bytes` is not set (32 MB).
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 32 * 1024 * 1024;
+
 /// Options considered by all `Client::exec_*` chat calls.
 ///
 /// A default can be set on the `Client` during builder configuration.
@@ -24,9 +34,31 @@ pub struct ChatOptions {
 	/// Maximum tokens to generate (if supported).
 	pub max_tokens: Option<u32>,
 
+	/// Clamp `max_tokens` to the model known output limit (from the built-in model name heuristics),
+	/// with a trace warning (default: false). Left as is when no limit is known for the model.
+	pub cap_max_tokens: Option<bool>,
+
+	/// Maximum number of continuation requests when a response is truncated by the output token limit
+	/// (`StopReason::MaxTokens`), for `exec_chat` (default: 0, no continuation).
+	pub auto_continue: Option<u32>,
+
+	/// What `exec_chat` does with an empty response (`ChatResponse::is_empty()`) (default: `OnEmpty::Allow`).
+	pub on_empty: Option<OnEmpty>,
+
+	/// What `exec_chat` and `exec_chat_stream` do with a request without messages (besides the system ones),
+	/// before sending it (default: `OnEmptyConversation::Allow`).
+	pub on_empty_conversation: Option<OnEmptyConversation>,
+
+	/// Minimum tokens to generate before a stop can end the output, if supported
+	/// (the vLLM-based OpenAI-compatible providers such as Nebius). Omitted elsewhere.
+	pub min_tokens: Option<u32>,
+
 	/// Nucleus sampling (top-p), if supported.
 	pub top_p: Option<f64>,
 
+	/// Top-k sampling, if supported (e.g., Anthropic, Gemini, Cohere, Ollama). Omitted for OpenAI.
+	pub top_k: Option<u32>,
+
 	/// Sequences that halt generation when encountered.
 	pub stop_sequences: Vec<String>,
 
@@ -43,6 +75,25 @@ pub struct ChatOptions {
 	/// (streaming) Collect tool calls; available in `StreamEnd.captured_tool_calls`.
 	pub capture_tool_calls: Option<bool>,
 
+	/// Keep malformed tool call arguments as the raw JSON string value instead of failing with
+	/// `Error::InvalidToolArguments` (default: false).
+	pub lenient_tool_arguments: Option<bool>,
+
+	/// (Streaming) Decodes the invalid UTF-8 bytes of the stream as the replacement character (`U+FFFD`)
+	/// instead of failing the stream. The multi-byte characters split across chunks are reassembled either way.
+	/// Default: true.
+	pub lossy_utf8: Option<bool>,
+
+	/// The maximum size in bytes of the response body (non-streaming), or of the streamed text, reasoning,
+	/// and tool argument chunks (streaming). Past it, the call fails with `Error::ResponseTooLarge`
+	/// (for a stream, returned after the chunks that fit).
+	/// Default: `DEFAULT_MAX_RESPONSE_BYTES` (32 MB).
+	pub max_response_bytes: Option<usize>,
+
+	/// Fails the request with `Error::RequestTooLarge` before sending it, when the payload is over the
+	/// provider documented request size limit (see `ModelCapabilities::max_request_bytes`). Default: false.
+	pub validate_request_bytes: Option<bool>,
+
 	/// Capture the raw HTTP body (primarily for debugging/inspection).
 	pub capture_raw_body: Option<bool>,
 
@@ -51,31 +102,91 @@ pub struct ChatOptions {
 	/// Note: Additional formats may be added in the future.
 	pub response_format: Option<ChatResponseFormat>,
 
+	/// Strip the markdown code fences (e.g., ```` ```json ````) and surrounding whitespace of the response text
+	/// (`ChatResponse.content`, and the stream `StreamEnd.captured_content`).
+	/// Default: true when a `response_format` is set, false otherwise.
+	pub normalize_json_output: Option<bool>,
+
 	// -- Reasoning options
 	/// Extract -style reasoning blocks into `ChatResponse.reasoning_content` when present.
 	pub normalize_reasoning_content: Option<bool>,
 
+	/// Send `system` messages with the `developer` role for the OpenAI o-series models (o1, o3, o4),
+	/// which expect it in place of `system` (default: true).
+	pub normalize_system_role: Option<bool>,
+
+	/// Merge the adjacent text parts of each message into one before serialization
+	/// (joined with a blank line, as `MessageContent::joined_texts`) (default: false).
+	pub merge_adjacent_texts: Option<bool>,
+
+	/// How the system content is sent, for the models ignoring or degrading on the system role
+	/// (default: `SystemHandling::Native`).
+	pub system_handling: Option<SystemHandling>,
+
 	/// Preferred reasoning effort, when supported by the provider.
 	pub reasoning_effort: Option<ReasoningEffort>,
 
+	/// Request the thought summaries (Gemini `thinkingConfig.includeThoughts`), returned as `reasoning_content`
+	/// (streaming and non-streaming). When not set, follows `capture_reasoning_content`.
+	pub include_thoughts: Option<bool>,
+
 	/// Verbosity (for OpenAI gpt-5),
 	pub verbosity: Option<Verbosity>,
 
 	/// Seed for repeatability, if supported.
 	pub seed: Option<u64>,
 
+	/// Number of choices to generate (OpenAI and compatibles `n`), returned in `ChatResponse::choices`.
+	/// NOTE: The usage covers all the choices.
+	pub n: Option<u32>,
+
 	/// Service tier preference (OpenAI-specific, for flex processing).
 	pub service_tier: Option<ServiceTier>,
 
+	/// Extra sampling parameters (min_p, repetition penalty, mirostat) for the providers accepting them
+	/// (Ollama, Ollama Cloud, and the vLLM-based OpenAI-compatible providers such as Nebius).
+	/// Omitted for the strict providers (e.g., OpenAI).
+	pub sampling_extras: Option<SamplingExtras>,
+
 	/// Additional HTTP headers to include with the request.
 	pub extra_headers: Option<Headers>,
 
+	/// Additional URL query parameters appended to the final request URL (e.g., Azure `api-version`).
+	/// Values are URL-encoded, and override existing query parameters with the same name.
+	pub extra_query_params: Option<Vec<(String, String)>>,
+
+	/// Extra JSON deep-merged into the final request payload, for any adapter (escape hatch for the provider
+	/// parameters not covered by these options). Objects are merged, other values (arrays included) are replaced.
+	/// The payload values it replaces are reported in `ChatResponse.extra_body_overrides`.
+	pub extra_body: Option<Value>,
+
+	/// Per-adapter extra JSON, deep-merged over `extra_body` for the matching adapter kind.
+	pub extra_body_for: Option<Vec<(AdapterKind, Value)>>,
+
+	// -- Tool options
+	/// How the model should use the request tools (provider default when `None`).
+	pub tool_choice: Option<ToolChoice>,
+
+	/// Whether the model may return several tool calls in one response (provider default when `None`).
+	/// `false` is sent as `disable_parallel_tool_use` for Anthropic.
+	pub parallel_tool_calls: Option<bool>,
+
 	// -- Prompt cache options
 	/// Request-level cache control preference.
 	pub cache_control: Option<CacheControl>,
 
 	/// OpenAI prompt cache key.
 	pub prompt_cache_key: Option<String>,
+
+	/// Gemini cached content name (e.g., `cachedContents/abc123`), as created with `Client::create_gemini_cache`.
+	pub cached_content: Option<String>,
+
+	// -- Provider headers
+	/// Anthropic `anthropic-version` header value (default `ANTHROPIC_VERSION`).
+	pub anthropic_version: Option<String>,
+
+	/// Anthropic `anthropic-beta` values, merged after the `ANTHROPIC_BETAS` defaults and the client ones (deduped).
+	pub anthropic_betas: Option<Vec<String>>,
 }
 
 /// Chainable Setters
@@ -92,12 +203,54 @@ impl ChatOptions {
 		self
 	}
 
+	/// Enables or disables clamping `max_tokens` to the model known output limit.
+	pub fn with_cap_max_tokens(mut self, value: bool) -> Self {
+		self.cap_max_tokens = Some(value);
+		self
+	}
+
+	/// Sets the maximum number of continuation requests for the responses truncated by the output token limit.
+	///
+	/// The partial output is sent back as the last assistant message, and the results are stitched
+	/// into one `ChatResponse` (non-streaming `exec_chat` only).
+	///
+	/// NOTE: The continuation requests clamp `max_tokens` to the model known output limit
+	///       (as with `with_cap_max_tokens(true)`), whatever the `cap_max_tokens` option.
+	pub fn with_auto_continue(mut self, max_continuations: u32) -> Self {
+		self.auto_continue = Some(max_continuations);
+		self
+	}
+
+	/// Sets what `exec_chat` does with an empty response (no text, no tool calls).
+	pub fn with_on_empty(mut self, value: OnEmpty) -> Self {
+		self.on_empty = Some(value);
+		self
+	}
+
+	/// Sets what the chat calls do with a request without messages (e.g., only a system prompt).
+	pub fn with_on_empty_conversation(mut self, value: OnEmptyConversation) -> Self {
+		self.on_empty_conversation = Some(value);
+		self
+	}
+
+	/// Sets the minimum number of output tokens.
+	pub fn with_min_tokens(mut self, value: u32) -> Self {
+		self.min_tokens = Some(value);
+		self
+	}
+
 	/// Sets nucleus sampling (top-p).
 	pub fn with_top_p(mut self, value: f64) -> Self {
 		self.top_p = Some(value);
 		self
 	}
 
+	/// Sets top-k sampling.
+	pub fn with_top_k(mut self, value: u32) -> Self {
+		self.top_k = Some(value);
+		self
+	}
+
 	/// Enables or disables capturing usage in streaming mode.
 	pub fn with_capture_usage(mut self, value: bool) -> Self {
 		self.capture_usage = Some(value);
@@ -122,6 +275,30 @@ impl ChatOptions {
 		self
 	}
 
+	/// Enables or disables keeping malformed tool call arguments as raw strings (instead of an error).
+	pub fn with_lenient_tool_arguments(mut self, value: bool) -> Self {
+		self.lenient_tool_arguments = Some(value);
+		self
+	}
+
+	/// Enables or disables decoding the invalid UTF-8 of the stream as `U+FFFD` (instead of an error).
+	pub fn with_lossy_utf8(mut self, value: bool) -> Self {
+		self.lossy_utf8 = Some(value);
+		self
+	}
+
+	/// Sets the maximum size in bytes of the response (see `max_response_bytes`).
+	pub fn with_max_response_bytes(mut self, value: usize) -> Self {
+		self.max_response_bytes = Some(value);
+		self
+	}
+
+	/// Enables or disables the request size validation before sending (see `validate_request_bytes`).
+	pub fn with_validate_request_bytes(mut self, value: bool) -> Self {
+		self.validate_request_bytes = Some(value);
+		self
+	}
+
 	/// Enables or disables capturing the raw HTTP body.
 	pub fn with_capture_raw_body(mut self, value: bool) -> Self {
 		self.capture_raw_body = Some(value);
@@ -146,18 +323,48 @@ impl ChatOptions {
 		self
 	}
 
+	/// Enables or disables the `system` to `developer` role conversion for the OpenAI o-series models.
+	pub fn with_normalize_system_role(mut self, value: bool) -> Self {
+		self.normalize_system_role = Some(value);
+		self
+	}
+
+	/// Enables or disables the merging of the adjacent text parts of the messages (see `merge_adjacent_texts`).
+	pub fn with_merge_adjacent_texts(mut self, value: bool) -> Self {
+		self.merge_adjacent_texts = Some(value);
+		self
+	}
+
+	/// Sets how the system content is sent (see `SystemHandling`).
+	pub fn with_system_handling(mut self, value: SystemHandling) -> Self {
+		self.system_handling = Some(value);
+		self
+	}
+
 	/// Sets the response format.
 	pub fn with_response_format(mut self, res_format: impl Into<ChatResponseFormat>) -> Self {
 		self.response_format = Some(res_format.into());
 		self
 	}
 
+	/// Enables or disables the stripping of the code fences of JSON responses (see `normalize_json_output`).
+	pub fn with_normalize_json_output(mut self, value: bool) -> Self {
+		self.normalize_json_output = Some(value);
+		self
+	}
+
 	/// Sets the reasoning effort hint.
 	pub fn with_reasoning_effort(mut self, value: ReasoningEffort) -> Self {
 		self.reasoning_effort = Some(value);
 		self
 	}
 
+	/// Requests the thought summaries as reasoning content (Gemini).
+	pub fn with_include_thoughts(mut self, value: bool) -> Self {
+		self.include_thoughts = Some(value);
+		self
+	}
+
 	/// Sets the verbosity hint.
 	pub fn with_verbosity(mut self, value: Verbosity) -> Self {
 		self.verbosity = Some(value);
@@ -170,18 +377,63 @@ impl ChatOptions {
 		self
 	}
 
+	/// Sets the number of choices to generate (see `ChatResponse::choices`).
+	pub fn with_n(mut self, value: u32) -> Self {
+		self.n = Some(value);
+		self
+	}
+
 	/// Sets the service tier preference (OpenAI-specific).
 	pub fn with_service_tier(mut self, value: ServiceTier) -> Self {
 		self.service_tier = Some(value);
 		self
 	}
 
+	/// Sets the extra sampling parameters (see `SamplingExtras`).
+	pub fn with_sampling_extras(mut self, value: SamplingExtras) -> Self {
+		self.sampling_extras = Some(value);
+		self
+	}
+
 	/// Adds extra HTTP headers.
 	pub fn with_extra_headers(mut self, headers: impl Into<Headers>) -> Self {
 		self.extra_headers = Some(headers.into());
 		self
 	}
 
+	/// Sets extra URL query parameters for the request.
+	pub fn with_extra_query_params(mut self, params: Vec<(String, String)>) -> Self {
+		self.extra_query_params = Some(params);
+		self
+	}
+
+	/// Sets the extra JSON deep-merged into the final request payload (any adapter).
+	pub fn with_extra_body(mut self, value: Value) -> Self {
+		self.extra_body = Some(value);
+		self
+	}
+
+	/// Adds extra JSON deep-merged into the final request payload for the `adapter_kind` only
+	/// (over `extra_body`, replacing a previous one for the same adapter kind).
+	pub fn with_extra_body_for(mut self, adapter_kind: AdapterKind, value: Value) -> Self {
+		let extra_body_for = self.extra_body_for.get_or_insert_with(Vec::new);
+		extra_body_for.retain(|(kind, _)| *kind != adapter_kind);
+		extra_body_for.push((adapter_kind, value));
+		self
+	}
+
+	/// Sets how the model should use the request tools.
+	pub fn with_tool_choice(mut self, value: ToolChoice) -> Self {
+		self.tool_choice = Some(value);
+		self
+	}
+
+	/// Allows or prevents several tool calls in one response.
+	pub fn with_parallel_tool_calls(mut self, value: bool) -> Self {
+		self.parallel_tool_calls = Some(value);
+		self
+	}
+
 	/// Sets the request-level cache control preference.
 	pub fn with_cache_control(mut self, value: CacheControl) -> Self {
 		self.cache_control = Some(value);
@@ -194,6 +446,24 @@ impl ChatOptions {
 		self
 	}
 
+	/// Sets the Gemini cached content to reference (a `CacheHandle`, or its name).
+	pub fn with_cached_content(mut self, cached_content: impl Into<String>) -> Self {
+		self.cached_content = Some(cached_content.into());
+		self
+	}
+
+	/// Overrides the Anthropic `anthropic-version` header.
+	pub fn with_anthropic_version(mut self, version: impl Into<String>) -> Self {
+		self.anthropic_version = Some(version.into());
+		self
+	}
+
+	/// Sets the Anthropic `anthropic-beta` values (e.g., `token-counting-2024-11-01`), added to the client ones.
+	pub fn with_anthropic_betas(mut self, betas: Vec<String>) -> Self {
+		self.anthropic_betas = Some(betas);
+		self
+	}
+
 	// -- Deprecated
 
 	/// Deprecated: use `with_response_format(ChatResponseFormat::JsonMode)`.
@@ -209,6 +479,183 @@ impl ChatOptions {
 	}
 }
 
+/// Presets
+///
+/// Starting points for common use cases, which can be further customized with the `with_*` setters.
+impl ChatOptions {
+	/// Higher temperature and nucleus sampling for brainstorming and creative writing.
+	pub fn creative() -> Self {
+		Self::default().with_temperature(1.0).with_top_p(0.95)
+	}
+
+	/// Zero temperature, full nucleus (`top_p` 1.0), and a fixed seed, for repeatable outputs
+	/// (as far as the provider supports seeds).
+	///
+	/// ```
+	/// use genai::chat::ChatOptions;
+	///
+	/// let options = ChatOptions::deterministic();
+	/// assert_eq!(options.temperature, Some(0.0));
+	/// assert_eq!(options.top_p, Some(1.0));
+	/// assert!(options.seed.is_some());
+	/// ```
+	pub fn deterministic() -> Self {
+		Self::default()
+			.with_temperature(0.0)
+			.with_top_p(1.0)
+			.with_seed(DETERMINISTIC_SEED)
+	}
+
+	/// Low temperature and narrow sampling for factual answers, extraction, and code.
+	pub fn precise() -> Self {
+		Self::default().with_temperature(0.0).with_top_p(0.1)
+	}
+
+	/// JSON mode (`ChatResponseFormat::JsonMode`) with a zero temperature.
+	///
+	/// NOTE: The prompt should still ask for JSON for broad provider compatibility.
+	///
+	/// ```
+	/// use genai::chat::{ChatOptions, ChatResponseFormat};
+	///
+	/// let options = ChatOptions::json().with_max_tokens(512);
+	/// assert!(matches!(options.response_format, Some(ChatResponseFormat::JsonMode)));
+	/// assert_eq!(options.temperature, Some(0.0));
+	/// ```
+	pub fn json() -> Self {
+		Self::default()
+			.with_response_format(ChatResponseFormat::JsonMode)
+			.with_temperature(0.0)
+	}
+
+	/// Reasoning effort with reasoning content capture (for streaming) and normalization (e.g., `<think>` blocks).
+	///
+	/// NOTE: No temperature/top_p are set, as many reasoning models reject them.
+	pub fn reasoning(effort: ReasoningEffort) -> Self {
+		Self::default()
+			.with_reasoning_effort(effort)
+			.with_capture_reasoning_content(true)
+			.with_normalize_reasoning_content(true)
+	}
+}
+
+/// Merge
+impl ChatOptions {
+	/// Returns these options layered with `other`: the fields set in `other` take precedence,
+	/// and the unset ones fall back to `self` (the same precedence as per-call options over client defaults).
+	///
+	/// `stop_sequences` are taken from `other` when not empty.
+	///
+	/// ```
+	/// use genai::chat::ChatOptions;
+	///
+	/// let app_preset = ChatOptions::deterministic().with_max_tokens(1024);
+	/// let options = app_preset.merge(&ChatOptions::default().with_temperature(0.3));
+	///
+	/// assert_eq!(options.temperature, Some(0.3));
+	/// assert_eq!(options.max_tokens, Some(1024));
+	/// assert_eq!(options.top_p, Some(1.0));
+	/// ```
+	pub fn merge(self, other: &ChatOptions) -> ChatOptions {
+		// Destructured so that a new field cannot be forgotten here.
+		let ChatOptions {
+			temperature,
+			max_tokens,
+			cap_max_tokens,
+			auto_continue,
+			on_empty,
+			on_empty_conversation,
+			min_tokens,
+			top_p,
+			top_k,
+			stop_sequences,
+			capture_usage,
+			capture_content,
+			capture_reasoning_content,
+			capture_tool_calls,
+			lenient_tool_arguments,
+			lossy_utf8,
+			max_response_bytes,
+			validate_request_bytes,
+			capture_raw_body,
+			response_format,
+			normalize_json_output,
+			normalize_reasoning_content,
+			normalize_system_role,
+			merge_adjacent_texts,
+			system_handling,
+			reasoning_effort,
+			include_thoughts,
+			verbosity,
+			seed,
+			n,
+			service_tier,
+			sampling_extras,
+			extra_headers,
+			extra_query_params,
+			extra_body,
+			extra_body_for,
+			tool_choice,
+			parallel_tool_calls,
+			cache_control,
+			prompt_cache_key,
+			cached_content,
+			anthropic_version,
+			anthropic_betas,
+		} = other.clone();
+
+		ChatOptions {
+			temperature: temperature.or(self.temperature),
+			max_tokens: max_tokens.or(self.max_tokens),
+			cap_max_tokens: cap_max_tokens.or(self.cap_max_tokens),
+			auto_continue: auto_continue.or(self.auto_continue),
+			on_empty: on_empty.or(self.on_empty),
+			on_empty_conversation: on_empty_conversation.or(self.on_empty_conversation),
+			min_tokens: min_tokens.or(self.min_tokens),
+			top_p: top_p.or(self.top_p),
+			top_k: top_k.or(self.top_k),
+			stop_sequences: if stop_sequences.is_empty() {
+				self.stop_sequences
+			} else {
+				stop_sequences
+			},
+			capture_usage: capture_usage.or(self.capture_usage),
+			capture_content: capture_content.or(self.capture_content),
+			capture_reasoning_content: capture_reasoning_content.or(self.capture_reasoning_content),
+			capture_tool_calls: capture_tool_calls.or(self.capture_tool_calls),
+			lenient_tool_arguments: lenient_tool_arguments.or(self.lenient_tool_arguments),
+			lossy_utf8: lossy_utf8.or(self.lossy_utf8),
+			max_response_bytes: max_response_bytes.or(self.max_response_bytes),
+			validate_request_bytes: validate_request_bytes.or(self.validate_request_bytes),
+			capture_raw_body: capture_raw_body.or(self.capture_raw_body),
+			response_format: response_format.or(self.response_format),
+			normalize_json_output: normalize_json_output.or(self.normalize_json_output),
+			normalize_reasoning_content: normalize_reasoning_content.or(self.normalize_reasoning_content),
+			normalize_system_role: normalize_system_role.or(self.normalize_system_role),
+			merge_adjacent_texts: merge_adjacent_texts.or(self.merge_adjacent_texts),
+			system_handling: system_handling.or(self.system_handling),
+			reasoning_effort: reasoning_effort.or(self.reasoning_effort),
+			include_thoughts: include_thoughts.or(self.include_thoughts),
+			verbosity: verbosity.or(self.verbosity),
+			seed: seed.or(self.seed),
+			n: n.or(self.n),
+			service_tier: service_tier.or(self.service_tier),
+			sampling_extras: sampling_extras.or(self.sampling_extras),
+			extra_headers: extra_headers.or(self.extra_headers),
+			extra_query_params: extra_query_params.or(self.extra_query_params),
+			extra_body: extra_body.or(self.extra_body),
+			extra_body_for: extra_body_for.or(self.extra_body_for),
+			tool_choice: tool_choice.or(self.tool_choice),
+			parallel_tool_calls: parallel_tool_calls.or(self.parallel_tool_calls),
+			cache_control: cache_control.or(self.cache_control),
+			prompt_cache_key: prompt_cache_key.or(self.prompt_cache_key),
+			cached_content: cached_content.or(self.cached_content),
+			anthropic_version: anthropic_version.or(self.anthropic_version),
+			anthropic_betas: anthropic_betas.or(self.anthropic_betas),
+		}
+	}
+}
+
 // region:    --- ReasoningEffort
 
 /// Provider-specific hint for reasoning intensity/budget.
@@ -452,15 +899,136 @@ impl std::str::FromStr for ServiceTier {
 
 // endregion: --- ServiceTier
 
+// region:    --- OnEmpty
+
+/// What `exec_chat` does with an empty response (`ChatResponse::is_empty()`), see `ChatOptions::with_on_empty(..)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnEmpty {
+	/// Return an `Error::NoChatResponse`.
+	Error,
+	/// Send the request again (up to 2 times), then return an `Error::NoChatResponse`.
+	Retry,
+	/// Return the empty response as is.
+	#[default]
+	Allow,
+}
+
+// endregion: --- OnEmpty
+
+// region:    --- OnEmptyConversation
+
+/// What the chat calls do with a request without messages besides the system ones (e.g., `ChatRequest::from_system(..)`),
+/// see `ChatOptions::with_on_empty_conversation(..)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnEmptyConversation {
+	/// Return an `Error::ChatReqHasNoMessages` (without sending the request).
+	Error,
+	/// Append a user message with this text.
+	InsertUser(String),
+	/// Send the request as is (some providers then fail).
+	#[default]
+	Allow,
+}
+
+impl OnEmptyConversation {
+	/// Appends a user message with `text` to the empty conversations.
+	pub fn insert_user(text: impl Into<String>) -> Self {
+		Self::InsertUser(text.into())
+	}
+}
+
+// endregion: --- OnEmptyConversation
+
+// region:    --- SystemHandling
+
+/// How the system content (the request `system`, and the system or developer role messages) is sent,
+/// see `ChatOptions::with_system_handling(..)`.
+///
+/// Some models (e.g., certain Gemma and small local models) ignore or degrade on the system prompts.
+/// Applied to the request before the adapter serialization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SystemHandling {
+	/// Sent with the provider system role (or field).
+	#[default]
+	Native,
+	/// The request `system` and the leading system messages joined (with a blank line) and prepended
+	/// to the text of the first user message (or sent as a first user message when there is none).
+	/// The later system messages are sent as user messages in place (as `AsUserMessage`).
+	PrependToFirstUser,
+	/// Sent as user messages, in place (the request `system` as the first message).
+	AsUserMessage,
+}
+
+// endregion: --- SystemHandling
+
+// region:    --- SamplingExtras
+
+/// Sampling parameters outside of the standard set, accepted by self-hosted engines (Ollama, vLLM).
+///
+/// - Ollama (native API): sent in `options` (`repetition_penalty` is sent as `repeat_penalty`).
+/// - vLLM-based OpenAI-compatible providers (e.g., Nebius): sent at the payload root (mirostat is not sent).
+///
+/// NOTE: `top_k` is the `ChatOptions::top_k`, also sent to these providers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SamplingExtras {
+	/// Minimum token probability, relative to the most likely token.
+	pub min_p: Option<f64>,
+
+	/// Penalty for repeated tokens (1.0 means no penalty).
+	pub repetition_penalty: Option<f64>,
+
+	/// Mirostat sampling mode (0 = disabled, 1 = Mirostat, 2 = Mirostat 2.0). Ollama only.
+	pub mirostat: Option<u8>,
+
+	/// Mirostat target entropy (tau). Ollama only.
+	pub mirostat_tau: Option<f64>,
+
+	/// Mirostat learning rate (eta). Ollama only.
+	pub mirostat_eta: Option<f64>,
+}
+
+/// Chainable Setters
+impl SamplingExtras {
+	pub fn with_min_p(mut self, value: f64) -> Self {
+		self.min_p = Some(value);
+		self
+	}
+
+	pub fn with_repetition_penalty(mut self, value: f64) -> Self {
+		self.repetition_penalty = Some(value);
+		self
+	}
+
+	pub fn with_mirostat(mut self, value: u8) -> Self {
+		self.mirostat = Some(value);
+		self
+	}
+
+	pub fn with_mirostat_tau(mut self, value: f64) -> Self {
+		self.mirostat_tau = Some(value);
+		self
+	}
+
+	pub fn with_mirostat_eta(mut self, value: f64) -> Self {
+		self.mirostat_eta = Some(value);
+		self
+	}
+}
+
+// endregion: --- SamplingExtras
+
 // region:    --- ChatOptionsSet
 
 /// This is an internal crate struct to resolve the ChatOptions value in a cascading manner.
 /// First, it attempts to get the value at the chat level (ChatOptions from the exec_chat...(...) argument).
 /// If a value for the property is not found, it looks at the client default one.
+///
+/// It also carries the stream transport of the resolved endpoint (not a `ChatOptions` property), for the streamers.
 #[derive(Default, Clone)]
 pub(crate) struct ChatOptionsSet<'a, 'b> {
 	client: Option<&'a ChatOptions>,
 	chat: Option<&'b ChatOptions>,
+	stream_transport: StreamTransport,
 }
 
 impl<'a, 'b> ChatOptionsSet<'a, 'b> {
@@ -472,6 +1040,10 @@ impl<'a, 'b> ChatOptionsSet<'a, 'b> {
 		self.chat = options;
 		self
 	}
+	pub fn with_stream_transport(mut self, stream_transport: StreamTransport) -> Self {
+		self.stream_transport = stream_transport;
+		self
+	}
 }
 
 impl ChatOptionsSet<'_, '_> {
@@ -487,12 +1059,48 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.max_tokens))
 	}
 
+	pub fn cap_max_tokens(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.cap_max_tokens)
+			.or_else(|| self.client.and_then(|client| client.cap_max_tokens))
+	}
+
+	pub fn auto_continue(&self) -> Option<u32> {
+		self.chat
+			.and_then(|chat| chat.auto_continue)
+			.or_else(|| self.client.and_then(|client| client.auto_continue))
+	}
+
+	pub fn on_empty(&self) -> Option<&OnEmpty> {
+		self.chat
+			.and_then(|chat| chat.on_empty.as_ref())
+			.or_else(|| self.client.and_then(|client| client.on_empty.as_ref()))
+	}
+
+	pub fn on_empty_conversation(&self) -> Option<&OnEmptyConversation> {
+		self.chat
+			.and_then(|chat| chat.on_empty_conversation.as_ref())
+			.or_else(|| self.client.and_then(|client| client.on_empty_conversation.as_ref()))
+	}
+
+	pub fn min_tokens(&self) -> Option<u32> {
+		self.chat
+			.and_then(|chat| chat.min_tokens)
+			.or_else(|| self.client.and_then(|client| client.min_tokens))
+	}
+
 	pub fn top_p(&self) -> Option<f64> {
 		self.chat
 			.and_then(|chat| chat.top_p)
 			.or_else(|| self.client.and_then(|client| client.top_p))
 	}
 
+	pub fn top_k(&self) -> Option<u32> {
+		self.chat
+			.and_then(|chat| chat.top_k)
+			.or_else(|| self.client.and_then(|client| client.top_k))
+	}
+
 	pub fn stop_sequences(&self) -> &[String] {
 		self.chat
 			.map(|chat| chat.stop_sequences.deref())
@@ -524,6 +1132,35 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.capture_tool_calls))
 	}
 
+	pub fn lenient_tool_arguments(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.lenient_tool_arguments)
+			.or_else(|| self.client.and_then(|client| client.lenient_tool_arguments))
+	}
+
+	/// The stream transport of the resolved endpoint (see `Endpoint::with_stream_transport`).
+	pub fn stream_transport(&self) -> StreamTransport {
+		self.stream_transport
+	}
+
+	pub fn lossy_utf8(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.lossy_utf8)
+			.or_else(|| self.client.and_then(|client| client.lossy_utf8))
+	}
+
+	pub fn max_response_bytes(&self) -> Option<usize> {
+		self.chat
+			.and_then(|chat| chat.max_response_bytes)
+			.or_else(|| self.client.and_then(|client| client.max_response_bytes))
+	}
+
+	pub fn validate_request_bytes(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.validate_request_bytes)
+			.or_else(|| self.client.and_then(|client| client.validate_request_bytes))
+	}
+
 	pub fn capture_raw_body(&self) -> Option<bool> {
 		self.chat
 			.and_then(|chat| chat.capture_raw_body)
@@ -536,18 +1173,50 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.response_format.as_ref()))
 	}
 
+	/// Returns whether the JSON output is normalized (defaults to true when a `response_format` is set).
+	pub fn normalize_json_output(&self) -> bool {
+		self.chat
+			.and_then(|chat| chat.normalize_json_output)
+			.or_else(|| self.client.and_then(|client| client.normalize_json_output))
+			.unwrap_or_else(|| self.response_format().is_some())
+	}
+
 	pub fn normalize_reasoning_content(&self) -> Option<bool> {
 		self.chat
 			.and_then(|chat| chat.normalize_reasoning_content)
 			.or_else(|| self.client.and_then(|client| client.normalize_reasoning_content))
 	}
 
+	pub fn normalize_system_role(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.normalize_system_role)
+			.or_else(|| self.client.and_then(|client| client.normalize_system_role))
+	}
+
+	pub fn merge_adjacent_texts(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.merge_adjacent_texts)
+			.or_else(|| self.client.and_then(|client| client.merge_adjacent_texts))
+	}
+
+	pub fn system_handling(&self) -> Option<&SystemHandling> {
+		self.chat
+			.and_then(|chat| chat.system_handling.as_ref())
+			.or_else(|| self.client.and_then(|client| client.system_handling.as_ref()))
+	}
+
 	pub fn reasoning_effort(&self) -> Option<&ReasoningEffort> {
 		self.chat
 			.and_then(|chat| chat.reasoning_effort.as_ref())
 			.or_else(|| self.client.and_then(|client| client.reasoning_effort.as_ref()))
 	}
 
+	pub fn include_thoughts(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.include_thoughts)
+			.or_else(|| self.client.and_then(|client| client.include_thoughts))
+	}
+
 	pub fn verbosity(&self) -> Option<&Verbosity> {
 		self.chat
 			.and_then(|chat| chat.verbosity.as_ref())
@@ -560,12 +1229,24 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.seed))
 	}
 
+	pub fn n(&self) -> Option<u32> {
+		self.chat
+			.and_then(|chat| chat.n)
+			.or_else(|| self.client.and_then(|client| client.n))
+	}
+
 	pub fn service_tier(&self) -> Option<&ServiceTier> {
 		self.chat
 			.and_then(|chat| chat.service_tier.as_ref())
 			.or_else(|| self.client.and_then(|client| client.service_tier.as_ref()))
 	}
 
+	pub fn sampling_extras(&self) -> Option<&SamplingExtras> {
+		self.chat
+			.and_then(|chat| chat.sampling_extras.as_ref())
+			.or_else(|| self.client.and_then(|client| client.sampling_extras.as_ref()))
+	}
+
 	#[allow(unused)]
 	pub fn extra_headers(&self) -> Option<&Headers> {
 		self.chat
@@ -573,12 +1254,81 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.extra_headers.as_ref()))
 	}
 
+	pub fn extra_query_params(&self) -> Option<&[(String, String)]> {
+		self.chat
+			.and_then(|chat| chat.extra_query_params.as_deref())
+			.or_else(|| self.client.and_then(|client| client.extra_query_params.as_deref()))
+	}
+
+	/// Returns the extra body for the `adapter_kind`, the per-adapter one deep-merged over the generic one.
+	pub fn extra_body(&self, adapter_kind: AdapterKind) -> Option<Value> {
+		let generic = self
+			.chat
+			.and_then(|chat| chat.extra_body.as_ref())
+			.or_else(|| self.client.and_then(|client| client.extra_body.as_ref()));
+		let for_adapter = self
+			.chat
+			.and_then(|chat| chat.extra_body_for.as_deref())
+			.or_else(|| self.client.and_then(|client| client.extra_body_for.as_deref()))
+			.and_then(|extra_body_for| extra_body_for.iter().find(|(kind, _)| *kind == adapter_kind))
+			.map(|(_, value)| value);
+
+		match (generic, for_adapter) {
+			(Some(generic), Some(for_adapter)) => {
+				let mut extra_body = generic.clone();
+				support::deep_merge_json(&mut extra_body, for_adapter);
+				Some(extra_body)
+			}
+			(generic, for_adapter) => generic.or(for_adapter).cloned(),
+		}
+	}
+
 	pub fn prompt_cache_key(&self) -> Option<&str> {
 		self.chat
 			.and_then(|chat| chat.prompt_cache_key.as_deref())
 			.or_else(|| self.client.and_then(|client| client.prompt_cache_key.as_deref()))
 	}
 
+	pub fn cached_content(&self) -> Option<&str> {
+		self.chat
+			.and_then(|chat| chat.cached_content.as_deref())
+			.or_else(|| self.client.and_then(|client| client.cached_content.as_deref()))
+	}
+
+	pub fn anthropic_version(&self) -> Option<&str> {
+		self.chat
+			.and_then(|chat| chat.anthropic_version.as_deref())
+			.or_else(|| self.client.and_then(|client| client.anthropic_version.as_deref()))
+	}
+
+	/// The client `anthropic_betas` followed by the chat ones (merged, not overridden), `None` when neither is set.
+	pub fn anthropic_betas(&self) -> Option<Vec<&str>> {
+		let client_betas = self.client.and_then(|client| client.anthropic_betas.as_deref());
+		let chat_betas = self.chat.and_then(|chat| chat.anthropic_betas.as_deref());
+		if client_betas.is_none() && chat_betas.is_none() {
+			return None;
+		}
+		let betas = client_betas
+			.into_iter()
+			.chain(chat_betas)
+			.flatten()
+			.map(String::as_str)
+			.collect();
+		Some(betas)
+	}
+
+	pub fn tool_choice(&self) -> Option<&ToolChoice> {
+		self.chat
+			.and_then(|chat| chat.tool_choice.as_ref())
+			.or_else(|| self.client.and_then(|client| client.tool_choice.as_ref()))
+	}
+
+	pub fn parallel_tool_calls(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.parallel_tool_calls)
+			.or_else(|| self.client.and_then(|client| client.parallel_tool_calls))
+	}
+
 	pub fn cache_control(&self) -> Option<&CacheControl> {
 		self.chat
 			.and_then(|chat| chat.cache_control.as_ref())
@@ -598,3 +1348,147 @@ impl ChatOptionsSet<'_, '_> {
 }
 
 // endregion: --- ChatOptionsSet
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chat::JsonSpec;
+	use serde_json::json;
+
+	#[test]
+	fn test_chat_options_presets_values() {
+		let creative = ChatOptions::creative();
+		assert_eq!(creative.temperature, Some(1.0));
+		assert_eq!(creative.top_p, Some(0.95));
+
+		let precise = ChatOptions::precise();
+		assert_eq!(precise.temperature, Some(0.0));
+		assert_eq!(precise.top_p, Some(0.1));
+
+		let json = ChatOptions::json();
+		assert!(matches!(json.response_format, Some(ChatResponseFormat::JsonMode)));
+		assert_eq!(json.temperature, Some(0.0));
+		assert_eq!(json.top_p, None);
+
+		let reasoning = ChatOptions::reasoning(ReasoningEffort::High);
+		assert!(matches!(reasoning.reasoning_effort, Some(ReasoningEffort::High)));
+		assert_eq!(reasoning.capture_reasoning_content, Some(true));
+		assert_eq!(reasoning.normalize_reasoning_content, Some(true));
+		assert_eq!(reasoning.temperature, None);
+		assert_eq!(reasoning.top_p, None);
+	}
+
+	#[test]
+	fn test_chat_options_presets_compose() {
+		let options = ChatOptions::creative().with_temperature(0.7).with_max_tokens(256);
+		assert_eq!(options.temperature, Some(0.7));
+		assert_eq!(options.top_p, Some(0.95));
+		assert_eq!(options.max_tokens, Some(256));
+
+		let options = ChatOptions::json().with_seed(42).with_capture_usage(true);
+		assert!(matches!(options.response_format, Some(ChatResponseFormat::JsonMode)));
+		assert_eq!(options.seed, Some(42));
+		assert_eq!(options.capture_usage, Some(true));
+
+		let options = ChatOptions::reasoning(ReasoningEffort::Low).with_reasoning_effort(ReasoningEffort::Medium);
+		assert!(matches!(options.reasoning_effort, Some(ReasoningEffort::Medium)));
+	}
+
+	#[test]
+	fn test_chat_options_deterministic_preset() {
+		let options = ChatOptions::deterministic();
+		assert_eq!(options.temperature, Some(0.0));
+		assert_eq!(options.top_p, Some(1.0));
+		assert_eq!(options.seed, Some(DETERMINISTIC_SEED));
+		assert!(options.response_format.is_none());
+	}
+
+	#[test]
+	fn test_chat_options_merge_precedence() {
+		let base = ChatOptions::creative()
+			.with_max_tokens(1024)
+			.with_stop_sequences(vec!["END".to_string()])
+			.with_capture_usage(true);
+		let other = ChatOptions::json().with_seed(7);
+
+		let merged = base.merge(&other);
+
+		// -- Set in `other`, so it wins
+		assert_eq!(merged.temperature, Some(0.0));
+		assert!(matches!(merged.response_format, Some(ChatResponseFormat::JsonMode)));
+		assert_eq!(merged.seed, Some(7));
+		// -- Unset in `other`, so it falls back to `self`
+		assert_eq!(merged.top_p, Some(0.95));
+		assert_eq!(merged.max_tokens, Some(1024));
+		assert_eq!(merged.capture_usage, Some(true));
+		assert_eq!(merged.stop_sequences, vec!["END".to_string()]);
+
+		// -- Non-empty stop sequences of `other` replace the ones of `self`
+		let merged = merged.merge(&ChatOptions::default().with_stop_sequences(vec!["STOP".to_string()]));
+		assert_eq!(merged.stop_sequences, vec!["STOP".to_string()]);
+		assert_eq!(merged.seed, Some(7));
+	}
+
+	#[test]
+	fn test_chat_options_merge_matches_options_set() {
+		let client = ChatOptions::deterministic().with_max_tokens(256).with_top_k(40);
+		let chat = ChatOptions::default().with_temperature(0.5).with_include_thoughts(true);
+
+		let options_set = ChatOptionsSet::default()
+			.with_client_options(Some(&client))
+			.with_chat_options(Some(&chat));
+		let merged = client.clone().merge(&chat);
+
+		assert_eq!(merged.temperature, options_set.temperature());
+		assert_eq!(merged.top_p, options_set.top_p());
+		assert_eq!(merged.top_k, options_set.top_k());
+		assert_eq!(merged.max_tokens, options_set.max_tokens());
+		assert_eq!(merged.seed, options_set.seed());
+		assert_eq!(merged.include_thoughts, options_set.include_thoughts());
+	}
+
+	#[test]
+	fn test_chat_options_normalize_json_output_default() {
+		let enabled = |options: &ChatOptions| {
+			ChatOptionsSet::default()
+				.with_chat_options(Some(options))
+				.normalize_json_output()
+		};
+
+		assert!(enabled(&ChatOptions::json()));
+		assert!(enabled(
+			&ChatOptions::default().with_response_format(JsonSpec::new("person", json!({"type": "object"})))
+		));
+		assert!(!enabled(&ChatOptions::default()));
+		// -- Explicit values win
+		assert!(!enabled(&ChatOptions::json().with_normalize_json_output(false)));
+		assert!(enabled(&ChatOptions::default().with_normalize_json_output(true)));
+	}
+
+	#[test]
+	fn test_chat_options_extra_body_per_adapter() {
+		let client = ChatOptions::default().with_extra_body(json!({"user": "client", "metadata": {"a": 1}}));
+		let chat = ChatOptions::default()
+			.with_extra_body_for(AdapterKind::Gemini, json!({"metadata": {"b": 2}}))
+			.with_extra_body_for(AdapterKind::Gemini, json!({"metadata": {"c": 3}}));
+
+		let options_set = ChatOptionsSet::default()
+			.with_client_options(Some(&client))
+			.with_chat_options(Some(&chat));
+
+		// -- The per-adapter one is merged over the generic one (the last set for an adapter kind wins)
+		assert_eq!(
+			options_set.extra_body(AdapterKind::Gemini),
+			Some(json!({"user": "client", "metadata": {"a": 1, "c": 3}}))
+		);
+		assert_eq!(
+			options_set.extra_body(AdapterKind::OpenAI),
+			Some(json!({"user": "client", "metadata": {"a": 1}}))
+		);
+		assert_eq!(ChatOptionsSet::default().extra_body(AdapterKind::OpenAI), None);
+	}
+}
+
+// endregion: --- Tests
@@ -1,13 +1,19 @@
 //! This module contains all the types related to a Chat Request (except ChatOptions, which has its own file).
 
-use crate::chat::{ChatMessage, ChatResponse, ChatRole, StreamEnd, Tool, ToolCall, ToolResponse};
+use crate::chat::{
+	Binary, ChatMessage, ChatResponse, ChatRole, ContentPart, StreamEnd, SystemHandling, Tool, ToolCall, ToolResponse,
+};
+use crate::redaction::{self, RedactedDebug, RedactionMode, redact_text, redaction_mode};
 use crate::support;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 // region:    --- ChatRequest
 
 /// Chat request for client chat calls.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+///
+/// Note: The `Debug` output follows the `genai::redaction` mode (see also `redacted_debug()`).
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct ChatRequest {
 	/// The initial system content of the request.
 	pub system: Option<String>,
@@ -30,6 +36,12 @@ pub struct ChatRequest {
 	/// Some(true) when using stateful sessions with previous_response_id.
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub store: Option<bool>,
+
+	/// Raw prompt sent as is, instead of the messages (no chat template applied by the provider).
+	/// Only for the completion-style adapters (see `AdapterKind::supports_raw_prompt`),
+	/// and cannot be combined with the system, messages, or tools.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw_prompt: Option<String>,
 }
 
 /// Constructors
@@ -42,6 +54,7 @@ impl ChatRequest {
 			tools: None,
 			previous_response_id: None,
 			store: None,
+			raw_prompt: None,
 		}
 	}
 
@@ -53,6 +66,7 @@ impl ChatRequest {
 			tools: None,
 			previous_response_id: None,
 			store: None,
+			raw_prompt: None,
 		}
 	}
 
@@ -64,6 +78,7 @@ impl ChatRequest {
 			tools: None,
 			previous_response_id: None,
 			store: None,
+			raw_prompt: None,
 		}
 	}
 
@@ -75,6 +90,7 @@ impl ChatRequest {
 			tools: None,
 			previous_response_id: None,
 			store: None,
+			raw_prompt: None,
 		}
 	}
 }
@@ -125,9 +141,41 @@ impl ChatRequest {
 		self
 	}
 
+	/// Set the raw prompt, already formatted with the model template, sent instead of the messages.
+	///
+	/// e.g., `ChatRequest::default().with_raw_prompt("<|user|>\nHello<|end|>\n<|assistant|>\n")`
+	///
+	/// NOTE: Only for Ollama (`api/generate` with `raw: true`), and the Together and Fireworks `completions` endpoints.
+	///       The other adapters fail with `Error::UnsupportedByModel`, and a request with a system, messages,
+	///       or tools fails with `Error::RawPromptConflict`.
+	pub fn with_raw_prompt(mut self, raw_prompt: impl Into<String>) -> Self {
+		self.raw_prompt = Some(raw_prompt.into());
+		self
+	}
+
 	/// Append one tool.
+	///
+	/// NOTE: Last wins - if a tool with the same name is already present, it is replaced in place
+	///       (keeping its position), so that tools composed from multiple sources do not end up duplicated.
 	pub fn append_tool(mut self, tool: impl Into<Tool>) -> Self {
-		self.tools.get_or_insert_with(Vec::new).push(tool.into());
+		let tool = tool.into();
+		let tools = self.tools.get_or_insert_with(Vec::new);
+		match tools.iter_mut().find(|existing| existing.name == tool.name) {
+			Some(existing) => *existing = tool,
+			None => tools.push(tool),
+		}
+		self
+	}
+
+	/// Append multiple tools from any iterable (same last-wins semantic as `append_tool`).
+	pub fn append_tools<I>(mut self, tools: I) -> Self
+	where
+		I: IntoIterator,
+		I::Item: Into<Tool>,
+	{
+		for tool in tools {
+			self = self.append_tool(tool);
+		}
 		self
 	}
 
@@ -167,13 +215,13 @@ impl ChatRequest {
 
 /// Getters
 impl ChatRequest {
-	/// Iterate over all system content: the top-level system prompt, then any system-role messages.
+	/// Iterate over all system content: the top-level system prompt, then any system-role (or developer-role) messages.
 	pub fn iter_systems(&self) -> impl Iterator<Item = &str> {
 		self.system
 			.iter()
 			.map(|s| s.as_str())
 			.chain(self.messages.iter().filter_map(|message| match message.role {
-				ChatRole::System => message.content.first_text(),
+				ChatRole::System | ChatRole::Developer => message.content.first_text(),
 				_ => None,
 			}))
 	}
@@ -192,10 +240,113 @@ impl ChatRequest {
 		systems
 	}
 
+	/// Returns the `Debug` output with the system and message content redacted,
+	/// with the global redaction mode, or `RedactionMode::Hash` when it is off.
+	pub fn redacted_debug(&self) -> String {
+		let mode = match redaction_mode() {
+			RedactionMode::Off => RedactionMode::Hash,
+			mode => mode,
+		};
+		redaction::with_forced_mode(mode, || format!("{self:?}"))
+	}
+
 	#[deprecated(note = "use join_systems()")]
 	pub fn combine_systems(&self) -> Option<String> {
 		self.join_systems()
 	}
+
+	/// Returns the tool with the given name, if any.
+	pub fn tool(&self, name: &str) -> Option<&Tool> {
+		self.tools.as_ref()?.iter().find(|tool| tool.name.as_str() == name)
+	}
+
+	/// Returns the names present more than once in `tools` (e.g., when set directly or via `with_tools`).
+	pub fn duplicate_tool_names(&self) -> Vec<&str> {
+		let mut duplicates: Vec<&str> = Vec::new();
+		let Some(tools) = self.tools.as_ref() else {
+			return duplicates;
+		};
+		for (idx, tool) in tools.iter().enumerate() {
+			let name = tool.name.as_str();
+			if tools[..idx].iter().any(|prev| prev.name.as_str() == name) && !duplicates.contains(&name) {
+				duplicates.push(name);
+			}
+		}
+		duplicates
+	}
+
+	/// Returns the number of images (binary parts with an `image/` content type) across the messages.
+	pub fn total_image_count(&self) -> usize {
+		self.iter_images().count()
+	}
+
+	/// Returns the estimated decoded byte size of the images across the messages
+	/// (only the base64 images, the URL images are not fetched).
+	pub fn total_image_bytes(&self) -> usize {
+		self.iter_images().filter_map(Binary::decoded_size).sum()
+	}
+
+	fn iter_images(&self) -> impl Iterator<Item = &Binary> {
+		self.messages
+			.iter()
+			.flat_map(|msg| msg.content.binaries())
+			.filter(|binary| binary.is_image())
+	}
+}
+
+/// Transformers (applied before the adapter serialization)
+impl ChatRequest {
+	/// Moves the system content into the user messages, per the `SystemHandling` (no change for `Native`).
+	pub(crate) fn apply_system_handling(&mut self, handling: SystemHandling) {
+		match handling {
+			SystemHandling::Native => (),
+			SystemHandling::PrependToFirstUser => {
+				// Only the leading system messages are folded, the later ones are sent as user messages in place
+				let leading_count = self
+					.messages
+					.iter()
+					.take_while(|msg| matches!(msg.role, ChatRole::System | ChatRole::Developer))
+					.count();
+				let leading_systems: Vec<ChatMessage> = self.messages.drain(..leading_count).collect();
+				for msg in self.messages.iter_mut() {
+					if matches!(msg.role, ChatRole::System | ChatRole::Developer) {
+						msg.role = ChatRole::User;
+					}
+				}
+
+				let mut systems: Option<String> = None;
+				let leading_texts = leading_systems.iter().filter_map(|msg| msg.content.first_text());
+				for system in self.system.take().as_deref().into_iter().chain(leading_texts) {
+					support::combine_text_with_empty_line(systems.get_or_insert_with(String::new), system);
+				}
+				let Some(systems) = systems else {
+					return;
+				};
+
+				match self.messages.iter_mut().find(|msg| matches!(msg.role, ChatRole::User)) {
+					Some(first_user) => match first_user.content.iter_mut().next() {
+						Some(ContentPart::Text(text)) => {
+							let mut combined = systems;
+							support::combine_text_with_empty_line(&mut combined, text);
+							*text = combined;
+						}
+						_ => first_user.content.prepend(ContentPart::Text(systems)),
+					},
+					None => self.messages.insert(0, ChatMessage::user(systems)),
+				}
+			}
+			SystemHandling::AsUserMessage => {
+				for msg in self.messages.iter_mut() {
+					if matches!(msg.role, ChatRole::System | ChatRole::Developer) {
+						msg.role = ChatRole::User;
+					}
+				}
+				if let Some(system) = self.system.take() {
+					self.messages.insert(0, ChatMessage::user(system));
+				}
+			}
+		}
+	}
 }
 
 impl From<Vec<ChatMessage>> for ChatRequest {
@@ -206,10 +357,42 @@ impl From<Vec<ChatMessage>> for ChatRequest {
 			tools: None,
 			previous_response_id: None,
 			store: None,
+			raw_prompt: None,
 		}
 	}
 }
 
+impl fmt::Debug for ChatRequest {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mode = redaction_mode();
+		let mut debug = f.debug_struct("ChatRequest");
+		if mode.is_off() {
+			debug.field("system", &self.system);
+		} else {
+			let system = self
+				.system
+				.as_deref()
+				.map(|system| RedactedDebug(redact_text("text", system, mode)));
+			debug.field("system", &system);
+		}
+		debug
+			.field("messages", &self.messages)
+			.field("tools", &self.tools)
+			.field("previous_response_id", &self.previous_response_id)
+			.field("store", &self.store);
+		if mode.is_off() {
+			debug.field("raw_prompt", &self.raw_prompt);
+		} else {
+			let raw_prompt = self
+				.raw_prompt
+				.as_deref()
+				.map(|raw_prompt| RedactedDebug(redact_text("text", raw_prompt, mode)));
+			debug.field("raw_prompt", &raw_prompt);
+		}
+		debug.finish()
+	}
+}
+
 // endregion: --- ChatRequest
 
 #[cfg(test)]
@@ -232,6 +415,52 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_append_tool_last_wins_and_lookup() {
+		let chat_req = ChatRequest::from_user("Hello")
+			.append_tool(Tool::new("get_weather").with_description("v1"))
+			.append_tools(vec![
+				Tool::new("get_time"),
+				Tool::new("get_weather").with_description("v2"),
+			]);
+
+		let tools = chat_req.tools.as_ref().expect("should have tools");
+		assert_eq!(tools.len(), 2);
+		assert_eq!(tools[0].name.as_str(), "get_weather");
+		assert_eq!(
+			chat_req.tool("get_weather").and_then(|t| t.description.as_deref()),
+			Some("v2")
+		);
+		assert!(chat_req.tool("get_time").is_some());
+		assert!(chat_req.tool("unknown").is_none());
+		assert!(chat_req.duplicate_tool_names().is_empty());
+	}
+
+	#[test]
+	fn test_chat_request_redacted_debug_hides_prompts() {
+		let chat_req = ChatRequest::from_system("secret system instructions")
+			.append_message(ChatMessage::user("my secret question"))
+			.append_message(ChatMessage::assistant("a secret answer"));
+
+		let redacted = chat_req.redacted_debug();
+
+		assert!(!redacted.contains("secret"), "{redacted}");
+		assert!(redacted.contains("[text: 26B sha256:"), "{redacted}");
+		assert!(redacted.contains("[text: 18B sha256:"), "{redacted}");
+	}
+
+	#[test]
+	fn test_duplicate_tool_names_with_tools() {
+		let chat_req = ChatRequest::from_user("Hello").with_tools(vec![
+			Tool::new("a"),
+			Tool::new("b"),
+			Tool::new("a"),
+			Tool::new("a"),
+		]);
+
+		assert_eq!(chat_req.duplicate_tool_names(), vec!["a"]);
+	}
+
 	#[test]
 	fn test_append_tool_use_from_chat_response_preserves_reasoning() {
 		let chat_res = ChatResponse {
@@ -244,8 +473,15 @@ mod tests {
 			provider_model_iden: test_model_iden(),
 			stop_reason: None,
 			usage: Usage::default(),
+			usage_reported: false,
 			captured_raw_body: None,
 			response_id: None,
+			metrics: None,
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		};
 		let tool_response = ToolResponse::new("call_1", r#"{"weather":"Sunny"}"#);
 
@@ -272,6 +508,8 @@ mod tests {
 			])),
 			captured_reasoning_content: Some("I should inspect the tool call first.".to_string()),
 			captured_response_id: None,
+			metrics: None,
+			incomplete: false,
 		};
 		let tool_response = ToolResponse::new("call_1", r#"{"weather":"Sunny"}"#);
 
@@ -286,4 +524,25 @@ mod tests {
 		);
 		assert_eq!(assistant_msg.content.tool_calls().len(), 1);
 	}
+
+	#[test]
+	fn test_total_image_count_and_bytes() {
+		let image_b64 = || ContentPart::from_binary_base64("image/png", "aGVsbG8=", None);
+		let chat_req = ChatRequest::new(vec![
+			ChatMessage::user(MessageContent::from_parts(vec![
+				ContentPart::Text("Compare these.".to_string()),
+				image_b64(),
+				ContentPart::from_binary_url("image/jpeg", "https://example.com/cat.jpg", None),
+			])),
+			ChatMessage::assistant("They differ."),
+			ChatMessage::user(MessageContent::from_parts(vec![
+				image_b64(),
+				ContentPart::from_binary_base64("application/pdf", "aGVsbG8=", None),
+			])),
+		]);
+
+		// the pdf is not an image, the url image counts but has no known size
+		assert_eq!(chat_req.total_image_count(), 3);
+		assert_eq!(chat_req.total_image_bytes(), 2 * "hello".len());
+	}
 }
@@ -1,9 +1,12 @@
 //! Types for chat responses. `ChatStream` is defined separately.
 
+use futures::{Stream, StreamExt, future};
 use serde::{Deserialize, Serialize};
 
-use crate::ModelIden;
-use crate::chat::{ChatMessage, ChatStream, MessageContent, ToolCall, Usage};
+use crate::chat::{
+	ChatMessage, ChatMetrics, ChatStream, ChatStreamEvent, ContentPart, MessageContent, ToolCall, Usage,
+};
+use crate::{ModelIden, Result};
 
 // region:    --- StopReason
 
@@ -77,6 +80,18 @@ impl StopReason {
 	pub fn is_max_tokens(&self) -> bool {
 		matches!(self, Self::MaxTokens(_))
 	}
+
+	/// Returns the OpenAI `finish_reason` name (`stop`, `length`, `tool_calls`, `content_filter`),
+	/// or the raw provider string for `Other`.
+	pub fn openai_finish_reason(&self) -> &str {
+		match self {
+			Self::Completed(_) | Self::StopSequence(_) => "stop",
+			Self::MaxTokens(_) => "length",
+			Self::ToolCall(_) => "tool_calls",
+			Self::ContentFilter(_) => "content_filter",
+			Self::Other(raw) => raw,
+		}
+	}
 }
 
 impl PartialEq for StopReason {
@@ -96,6 +111,51 @@ impl std::fmt::Display for StopReason {
 
 // endregion: --- StopReason
 
+// region:    --- IgnoredOption
+
+/// A requested `ChatOptions` option the adapter could not honor for the request (e.g., `seed` for Anthropic).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IgnoredOption {
+	/// The `ChatOptions` field name (e.g., `seed`, `top_k`).
+	pub option: String,
+	/// Why it was not sent (e.g., `not supported by the Anthropic adapter`).
+	pub reason: String,
+}
+
+impl IgnoredOption {
+	pub fn new(option: impl Into<String>, reason: impl Into<String>) -> Self {
+		Self {
+			option: option.into(),
+			reason: reason.into(),
+		}
+	}
+}
+
+// endregion: --- IgnoredOption
+
+// region:    --- ChatChoice
+
+/// A choice of a multi-choice response (e.g., with `ChatOptions::n`).
+///
+/// The provider usage is not per choice (see `ChatResponse::usage`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatChoice {
+	/// The provider choice index.
+	pub index: u32,
+
+	pub content: MessageContent,
+
+	pub reasoning_content: Option<String>,
+
+	pub stop_reason: Option<StopReason>,
+
+	/// The provider log probabilities of the choice, as returned (when requested, e.g., with `extra_body`).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub logprobs: Option<serde_json::Value>,
+}
+
+// endregion: --- ChatChoice
+
 // region:    --- ChatResponse
 
 /// Response returned by a non-streaming chat request.
@@ -121,16 +181,58 @@ pub struct ChatResponse {
 	pub stop_reason: Option<StopReason>,
 
 	/// Token usage reported by the provider.
+	/// NOTE: For a multi-choice response, the usage covers all the choices.
 	pub usage: Usage,
 
+	/// True when the provider reported the usage (set by the adapters).
+	/// When false, `usage` is empty (see `Usage::is_empty`), and should not be read as zero tokens.
+	#[serde(default)]
+	pub usage_reported: bool,
+
 	/// IMPORTANT: (since 0.5.3) This is populated at the client.exec_chat when the options capture_raw_body is set to true
 	/// Raw response body (only if asked via options.capture_raw_body)
 	pub captured_raw_body: Option<serde_json::Value>,
 
-	/// Response ID for stateful sessions (OpenAI Responses API).
-	/// Use as `previous_response_id` in the next request to continue the conversation server-side.
+	/// The provider response ID (e.g., `resp_...` for the OpenAI Responses API, `chatcmpl-...` for the Chat Completions).
+	/// For the OpenAI Responses API, use as `previous_response_id` in the next request to continue the conversation server-side.
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub response_id: Option<String>,
+
+	/// Client-side timing metrics (latency, tokens per second).
+	/// Set by the client exec_chat.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub metrics: Option<ChatMetrics>,
+
+	/// JSON pointers of the adapter payload values replaced by `ChatOptions` `extra_body` (e.g., `/temperature`).
+	/// Set by the client exec_chat.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub extra_body_overrides: Vec<String>,
+
+	/// The requested `ChatOptions` the adapter could not honor (not sent to the provider).
+	/// Set by the client exec_chat.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub ignored_options: Vec<IgnoredOption>,
+
+	/// All the choices, in provider order, when the provider returned more than one (e.g., with `ChatOptions::n`).
+	/// The first choice is also the response `content`, `reasoning_content`, and `stop_reason`.
+	/// Empty for a single-choice response.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub choices: Vec<ChatChoice>,
+
+	/// The service tier the provider used for the request, as reported
+	/// (e.g., OpenAI `flex` or `default`, Groq `on_demand`, Anthropic `standard` or `priority`).
+	/// To check that the requested `ChatOptions::service_tier` took effect.
+	///
+	/// NOTE: Only for `exec_chat` (not captured from the `exec_chat_stream` events).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub service_tier: Option<String>,
+
+	/// The provider queue, latency, and load hints, as reported (e.g., the Groq `queue_time` and `total_time`,
+	/// the Anthropic `anthropic-ratelimit-*` and `retry-after` headers).
+	///
+	/// NOTE: Only for `exec_chat` (not captured from the `exec_chat_stream` events).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub provider_timing: Option<serde_json::Value>,
 }
 
 impl ChatResponse {
@@ -173,6 +275,37 @@ impl ChatResponse {
 		self.content.into_tool_calls()
 	}
 
+	/// Returns true when the response has no text (or only whitespace) and no tool calls,
+	/// nor other content (e.g., images). The reasoning content is not considered.
+	pub fn is_empty(&self) -> bool {
+		self.content.iter().all(|part| match part {
+			ContentPart::Text(text) => text.trim().is_empty(),
+			ContentPart::ReasoningContent(_) | ContentPart::ThoughtSignature(_) => true,
+			_ => false,
+		})
+	}
+
+	/// Returns the number of choices (1 for a single-choice response).
+	pub fn choice_count(&self) -> usize {
+		self.choices.len().max(1)
+	}
+
+	/// Returns the average completion tokens per choice (the provider usage covers all the choices).
+	pub fn completion_tokens_per_choice(&self) -> Option<i32> {
+		let choice_count = i32::try_from(self.choice_count()).unwrap_or(i32::MAX);
+		self.usage.completion_tokens.map(|tokens| tokens / choice_count)
+	}
+
+	/// Returns true when the provider-reported model differs from the resolved `model_iden`
+	/// (e.g., `gpt-4o` answered by the dated snapshot `gpt-4o-2024-08-06`).
+	///
+	/// The names are compared without their namespace (`openai::gpt-4o` is not changed by `gpt-4o`).
+	pub fn model_changed(&self) -> bool {
+		let (_, model_name) = self.model_iden.model_name.namespace_and_name();
+		let (_, provider_model_name) = self.provider_model_iden.model_name.namespace_and_name();
+		self.model_iden.adapter_kind != self.provider_model_iden.adapter_kind || model_name != provider_model_name
+	}
+
 	/// Builds an assistant history message for a tool-use continuation, preserving
 	/// the full assistant content plus any extracted reasoning content.
 	pub fn assistant_message_for_tool_use(&self) -> Option<ChatMessage> {
@@ -195,6 +328,112 @@ impl ChatResponse {
 	}
 }
 
+/// OpenAI format export
+impl ChatResponse {
+	/// Returns the response in the OpenAI chat completions format (`chat.completion` object),
+	/// e.g., to answer as an OpenAI compatible server.
+	///
+	/// - `choices[].message`: the text parts (joined), the tool calls (stringified arguments),
+	///   and the eventual `reasoning_content`.
+	/// - `choices[].finish_reason`: the OpenAI name of the stop reason (the raw one for `Other`).
+	/// - `usage`, `service_tier`: as captured (omitted when not reported).
+	///
+	/// NOTE: The `id` is the `response_id` when present, and `created` is the export time.
+	pub fn to_openai_json(&self) -> serde_json::Value {
+		let choices: Vec<serde_json::Value> = if self.choices.is_empty() {
+			vec![openai_choice_json(
+				0,
+				&self.content,
+				self.reasoning_content.as_deref(),
+				self.stop_reason.as_ref(),
+				None,
+			)]
+		} else {
+			self.choices
+				.iter()
+				.map(|choice| {
+					openai_choice_json(
+						choice.index,
+						&choice.content,
+						choice.reasoning_content.as_deref(),
+						choice.stop_reason.as_ref(),
+						choice.logprobs.as_ref(),
+					)
+				})
+				.collect()
+		};
+
+		let created = std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.map(|duration| duration.as_secs())
+			.unwrap_or_default();
+
+		let mut res = serde_json::json!({
+			"id": self.response_id.as_deref().unwrap_or("chatcmpl-genai"),
+			"object": "chat.completion",
+			"created": created,
+			"model": self.provider_model_iden.model_name.namespace_and_name().1,
+			"choices": choices,
+		});
+		if self.usage_reported || !self.usage.is_empty() {
+			res["usage"] = serde_json::to_value(&self.usage).unwrap_or_default();
+		}
+		if let Some(service_tier) = self.service_tier.as_deref() {
+			res["service_tier"] = service_tier.into();
+		}
+
+		res
+	}
+}
+
+/// An OpenAI `choices` item (see `ChatResponse::to_openai_json`).
+fn openai_choice_json(
+	index: u32,
+	content: &MessageContent,
+	reasoning_content: Option<&str>,
+	stop_reason: Option<&StopReason>,
+	logprobs: Option<&serde_json::Value>,
+) -> serde_json::Value {
+	let mut message = serde_json::json!({
+		"role": "assistant",
+		"content": content.joined_texts(),
+	});
+	if let Some(reasoning_content) = reasoning_content {
+		message["reasoning_content"] = reasoning_content.into();
+	}
+	let tool_calls: Vec<serde_json::Value> = content
+		.tool_calls()
+		.into_iter()
+		.map(|tool_call| {
+			// The arguments are a JSON string (kept as is when already a string, e.g., lenient parsing)
+			let arguments = match &tool_call.fn_arguments {
+				serde_json::Value::String(arguments) => arguments.clone(),
+				fn_arguments => fn_arguments.to_string(),
+			};
+			serde_json::json!({
+				"id": tool_call.call_id,
+				"type": "function",
+				"function": {
+					"name": tool_call.fn_name,
+					"arguments": arguments,
+				}
+			})
+		})
+		.collect();
+	if !tool_calls.is_empty() {
+		message["tool_calls"] = tool_calls.into();
+	}
+
+	let finish_reason = stop_reason.map(StopReason::openai_finish_reason);
+
+	serde_json::json!({
+		"index": index,
+		"message": message,
+		"finish_reason": finish_reason,
+		"logprobs": logprobs,
+	})
+}
+
 /// Deprecated Getters
 impl ChatResponse {
 	/// Deprecated: use `first_text` or `texts`.
@@ -245,8 +484,15 @@ mod tests {
 			provider_model_iden: test_model_iden(),
 			stop_reason: None,
 			usage: Usage::default(),
+			usage_reported: false,
 			captured_raw_body: None,
 			response_id: None,
+			metrics: None,
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		};
 
 		let assistant_msg = chat_res
@@ -271,23 +517,132 @@ mod tests {
 			provider_model_iden: test_model_iden(),
 			stop_reason: None,
 			usage: Usage::default(),
+			usage_reported: false,
 			captured_raw_body: None,
 			response_id: None,
+			metrics: None,
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
 		};
 
 		assert!(chat_res.assistant_message_for_tool_use().is_none());
 	}
+
+	fn test_chat_response(provider_model_name: Option<String>) -> ChatResponse {
+		let model_iden = test_model_iden();
+		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
+		ChatResponse {
+			content: MessageContent::from_text("Hello"),
+			reasoning_content: None,
+			model_iden,
+			provider_model_iden,
+			stop_reason: None,
+			usage: Usage::default(),
+			usage_reported: false,
+			captured_raw_body: None,
+			response_id: None,
+			metrics: None,
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
+		}
+	}
+
+	#[test]
+	fn test_model_changed_same_name() {
+		assert!(!test_chat_response(Some("test-model".to_string())).model_changed());
+		assert!(!test_chat_response(None).model_changed());
+	}
+
+	#[test]
+	fn test_model_changed_namespaced_name() {
+		let mut chat_res = test_chat_response(Some("gpt-4o".to_string()));
+		chat_res.model_iden = ModelIden::new(AdapterKind::OpenAI, "openai::gpt-4o");
+		assert!(!chat_res.model_changed());
+
+		chat_res.provider_model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-2024-08-06");
+		assert!(chat_res.model_changed());
+	}
+
+	#[test]
+	fn test_model_changed_different_name() {
+		let chat_res = test_chat_response(Some("test-model-2024-08-06".to_string()));
+
+		assert!(chat_res.model_changed());
+		assert_eq!(&*chat_res.model_iden.model_name, "test-model");
+		assert_eq!(&*chat_res.provider_model_iden.model_name, "test-model-2024-08-06");
+		assert_eq!(chat_res.provider_model_iden.adapter_kind, AdapterKind::OpenAI);
+	}
+
+	#[test]
+	fn test_chat_response_is_empty() {
+		let chat_res = |parts: Vec<ContentPart>| ChatResponse {
+			content: MessageContent::from_parts(parts),
+			reasoning_content: None,
+			model_iden: test_model_iden(),
+			provider_model_iden: test_model_iden(),
+			stop_reason: None,
+			usage: Usage::default(),
+			usage_reported: false,
+			captured_raw_body: None,
+			response_id: None,
+			metrics: None,
+			extra_body_overrides: Vec::new(),
+			ignored_options: Vec::new(),
+			choices: Vec::new(),
+			service_tier: None,
+			provider_timing: None,
+		};
+
+		assert!(chat_res(vec![]).is_empty());
+		assert!(chat_res(vec![ContentPart::Text(" \n".to_string())]).is_empty());
+		assert!(chat_res(vec![ContentPart::ReasoningContent("Thinking...".to_string())]).is_empty());
+		assert!(!chat_res(vec![ContentPart::Text("Hello".to_string())]).is_empty());
+		assert!(!chat_res(vec![ContentPart::ToolCall(test_tool_call())]).is_empty());
+	}
 }
 
 // region:    --- ChatStreamResponse
 
 /// Result of a streaming chat request.
+///
+/// `Send + 'static`, as its `ChatStream`, so it can be moved into a spawned task.
 pub struct ChatStreamResponse {
 	/// Stream to iterate through response events.
 	pub stream: ChatStream,
 
 	/// Model identifier (AdapterKind/ModelName) used for this request.
 	pub model_iden: ModelIden,
+
+	/// JSON pointers of the adapter payload values replaced by `ChatOptions` `extra_body`.
+	/// Set by the client exec_chat_stream.
+	pub extra_body_overrides: Vec<String>,
+
+	/// The requested `ChatOptions` the adapter could not honor (not sent to the provider).
+	/// Set by the client exec_chat_stream.
+	pub ignored_options: Vec<IgnoredOption>,
+}
+
+impl ChatStreamResponse {
+	/// Consumes the response and returns a stream of the content text chunks only.
+	///
+	/// All the other events (start, first token, reasoning, tool calls, end) are dropped.
+	/// Stream errors are passed through.
+	pub fn text_stream(self) -> impl Stream<Item = Result<String>> {
+		self.stream.filter_map(|event| {
+			let text = match event {
+				Ok(ChatStreamEvent::Chunk(chunk)) => Some(Ok(chunk.content)),
+				Ok(_) => None,
+				Err(err) => Some(Err(err)),
+			};
+			future::ready(text)
+		})
+	}
 }
 
 // endregion: --- ChatStreamResponse
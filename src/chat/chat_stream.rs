@@ -1,20 +1,90 @@
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{ChatMessage, ContentPart, MessageContent, StopReason, ToolCall, Usage};
+use crate::chat::{ChatMessage, ChatMetrics, ContentPart, MessageContent, StopReason, ToolCall, Usage};
+use crate::support::{parse_partial_json, strip_code_fences_in_texts};
+use crate::{ModelIden, TokenBudget};
 use futures::Stream;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::Instant;
 
 type InterStreamType = Pin<Box<dyn Stream<Item = crate::Result<InterStreamEvent>> + Send>>;
 
 /// A stream of chat events produced by a streaming chat request.
+///
+/// `ChatStream` is `Send + 'static` (it does not borrow the request options),
+/// so it can be moved into a spawned task (e.g., `tokio::spawn`).
 pub struct ChatStream {
 	inter_stream: InterStreamType,
+
+	/// Set on the first poll (when the request is sent).
+	started_at: Option<Instant>,
+	/// Set on the first content, reasoning, or tool call chunk.
+	first_token_at: Option<Instant>,
+	/// The first token chunk, held back while the `FirstToken` event is returned.
+	pending_event: Option<ChatStreamEvent>,
+	/// The error of an interrupted stream, returned right after its incomplete `End` event.
+	pending_error: Option<crate::Error>,
+	/// Events already read from the stream, returned first (see `prepend_events`).
+	replay_events: VecDeque<ChatStreamEvent>,
+	/// The client token budget, recorded with the `End` captured usage.
+	token_budget: Option<TokenBudget>,
+	/// Strip the code fences of the `End` captured text content (see `ChatOptions::normalize_json_output`).
+	normalize_json_output: bool,
+	/// The maximum size of the streamed data (see `ChatOptions::max_response_bytes`).
+	size_limit: Option<StreamSizeLimit>,
+}
+
+/// The streamed data size limit, with the running count of the bytes received so far.
+struct StreamSizeLimit {
+	model_iden: ModelIden,
+	max_bytes: usize,
+	received_bytes: usize,
+}
+
+impl StreamSizeLimit {
+	/// Adds the size of the event data, and returns false (without counting it) when it exceeds the limit.
+	fn push(&mut self, event: &InterStreamEvent) -> bool {
+		let size = match event {
+			InterStreamEvent::Chunk(content)
+			| InterStreamEvent::ReasoningChunk(content)
+			| InterStreamEvent::ThoughtSignatureChunk(content) => content.len(),
+			InterStreamEvent::ToolCallArgsChunk(args_chunk) => args_chunk.fragment.len(),
+			_ => 0,
+		};
+		if self.received_bytes + size > self.max_bytes {
+			return false;
+		}
+		self.received_bytes += size;
+		true
+	}
+
+	/// Returns the `ResponseTooLarge` error (the data received so far was already returned as chunks).
+	fn overflow_error(&self) -> crate::Error {
+		crate::Error::ResponseTooLarge {
+			model_iden: self.model_iden.clone(),
+			max_bytes: self.max_bytes,
+			partial: String::new(),
+		}
+	}
 }
 
 impl ChatStream {
 	pub(crate) fn new(inter_stream: InterStreamType) -> Self {
-		ChatStream { inter_stream }
+		ChatStream {
+			inter_stream,
+			started_at: None,
+			first_token_at: None,
+			pending_event: None,
+			pending_error: None,
+			replay_events: VecDeque::new(),
+			token_budget: None,
+			normalize_json_output: false,
+			size_limit: None,
+		}
 	}
 
 	pub(crate) fn from_inter_stream<T>(inter_stream: T) -> Self
@@ -24,6 +94,30 @@ impl ChatStream {
 		let boxed_stream: InterStreamType = Box::pin(inter_stream);
 		ChatStream::new(boxed_stream)
 	}
+
+	/// Puts back events already read from this stream, so they are returned first, in order
+	/// (e.g., for hedged streams read up to their first token).
+	pub(crate) fn prepend_events(&mut self, events: Vec<ChatStreamEvent>) {
+		for event in events.into_iter().rev() {
+			self.replay_events.push_front(event);
+		}
+	}
+
+	pub(crate) fn set_token_budget(&mut self, token_budget: TokenBudget) {
+		self.token_budget = Some(token_budget);
+	}
+
+	pub(crate) fn set_normalize_json_output(&mut self, normalize_json_output: bool) {
+		self.normalize_json_output = normalize_json_output;
+	}
+
+	pub(crate) fn set_max_response_bytes(&mut self, model_iden: ModelIden, max_bytes: usize) {
+		self.size_limit = Some(StreamSizeLimit {
+			model_iden,
+			max_bytes,
+			received_bytes: 0,
+		});
+	}
 }
 
 // region:    --- Stream Impl
@@ -34,8 +128,36 @@ impl Stream for ChatStream {
 	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
 		let this = self.get_mut();
 
+		if let Some(replay_event) = this.replay_events.pop_front() {
+			return Poll::Ready(Some(Ok(replay_event)));
+		}
+		if let Some(pending_event) = this.pending_event.take() {
+			return Poll::Ready(Some(Ok(pending_event)));
+		}
+		if let Some(pending_error) = this.pending_error.take() {
+			return Poll::Ready(Some(Err(pending_error)));
+		}
+
+		let started_at = *this.started_at.get_or_insert_with(Instant::now);
+
 		match Pin::new(&mut this.inter_stream).poll_next(cx) {
 			Poll::Ready(Some(Ok(event))) => {
+				// -- Past the size limit, return the error, and stop reading
+				if let Some(size_limit) = this.size_limit.as_mut()
+					&& !size_limit.push(&event)
+				{
+					this.inter_stream = Box::pin(futures::stream::empty());
+					return Poll::Ready(Some(Err(size_limit.overflow_error())));
+				}
+
+				let is_first_token = this.first_token_at.is_none()
+					&& matches!(
+						event,
+						InterStreamEvent::Chunk(_)
+							| InterStreamEvent::ReasoningChunk(_)
+							| InterStreamEvent::ToolCallChunk(_)
+					);
+
 				let chat_event = match event {
 					InterStreamEvent::Start => ChatStreamEvent::Start,
 					InterStreamEvent::Chunk(content) => ChatStreamEvent::Chunk(StreamChunk { content }),
@@ -48,8 +170,40 @@ impl Stream for ChatStream {
 					InterStreamEvent::ToolCallChunk(tool_call) => {
 						ChatStreamEvent::ToolCallChunk(ToolChunk { tool_call })
 					}
-					InterStreamEvent::End(inter_end) => ChatStreamEvent::End(inter_end.into()),
+					InterStreamEvent::ToolCallArgsChunk(args_chunk) => ChatStreamEvent::ToolCallArgsChunk(args_chunk),
+					InterStreamEvent::End(mut inter_end) => {
+						// -- An interrupted stream ends with its partial data, then returns the error on the next poll
+						this.pending_error = inter_end.interrupted_by.take().map(|error| *error);
+						let mut stream_end = StreamEnd::from(inter_end);
+						stream_end.incomplete = this.pending_error.is_some();
+						let completion_tokens = stream_end.captured_usage.as_ref().and_then(|u| u.completion_tokens);
+						stream_end.metrics = Some(ChatMetrics::from_timing(
+							started_at,
+							this.first_token_at,
+							Instant::now(),
+							completion_tokens,
+						));
+						if let (Some(token_budget), Some(usage)) = (&this.token_budget, &stream_end.captured_usage) {
+							token_budget.record(usage);
+						}
+						if this.normalize_json_output
+							&& let Some(captured_content) = stream_end.captured_content.as_mut()
+						{
+							strip_code_fences_in_texts(captured_content);
+						}
+						ChatStreamEvent::End(stream_end)
+					}
 				};
+
+				// -- On the first token, emit `FirstToken` first, and the chunk on the next poll
+				if is_first_token {
+					let first_token_at = Instant::now();
+					this.first_token_at = Some(first_token_at);
+					this.pending_event = Some(chat_event);
+					let elapsed = first_token_at.saturating_duration_since(started_at);
+					return Poll::Ready(Some(Ok(ChatStreamEvent::FirstToken(StreamFirstToken { elapsed }))));
+				}
+
 				Poll::Ready(Some(Ok(chat_event)))
 			}
 			Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
@@ -69,6 +223,10 @@ pub enum ChatStreamEvent {
 	/// Emitted once at the start of the stream.
 	Start,
 
+	/// Emitted once, right before the first content, reasoning, or tool call chunk,
+	/// with the time elapsed since the request was sent (time to first token).
+	FirstToken(StreamFirstToken),
+
 	/// Assistant content chunk (text).
 	Chunk(StreamChunk),
 
@@ -81,8 +239,17 @@ pub enum ChatStreamEvent {
 	/// Tool-call chunk.
 	ToolCallChunk(ToolChunk),
 
+	/// Tool-call arguments fragment (raw partial JSON), as streamed by the provider.
+	/// Emitted right before the matching `ToolCallChunk` (currently by the Anthropic adapter only).
+	/// See `ToolArgsAccumulator` to get the partial arguments as JSON.
+	ToolCallArgsChunk(ToolArgsChunk),
+
 	/// End of stream.
 	/// May include captured usage and/or content when enabled via `ChatOptions`.
+	///
+	/// When the stream is interrupted by an error (e.g., network or provider error mid-stream) after some
+	/// content, reasoning, or tool calls were captured, an `End` with `incomplete: true` and the partial
+	/// captured data is emitted, followed by the error. Without captured data, the stream yields just the error.
 	End(StreamEnd),
 }
 
@@ -94,6 +261,13 @@ pub struct StreamChunk {
 	pub content: String,
 }
 
+/// Content of `ChatStreamEvent::FirstToken`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StreamFirstToken {
+	/// Time from the request send (first poll of the stream) to the first token chunk.
+	pub elapsed: Duration,
+}
+
 /// Content of `ChatStreamEvent::ToolCallChunk`.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ToolChunk {
@@ -101,10 +275,22 @@ pub struct ToolChunk {
 	pub tool_call: ToolCall,
 }
 
+/// Content of `ChatStreamEvent::ToolCallArgsChunk`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolArgsChunk {
+	/// Index of the tool call in the response (0 for the first tool call).
+	pub tool_index: usize,
+	/// The tool call id.
+	pub call_id: String,
+	/// The new partial JSON fragment of the arguments (not the accumulated arguments).
+	pub fragment: String,
+}
+
 /// Terminal event data with optionally captured usage and content.
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StreamEnd {
 	/// Captured usage if `ChatOptions.capture_usage` is enabled.
+	/// None when the provider did not report the usage in the stream (never an empty `Usage`).
 	pub captured_usage: Option<Usage>,
 
 	/// Normalised stop reason captured at stream end (see [`StopReason`]).
@@ -121,6 +307,15 @@ pub struct StreamEnd {
 
 	/// Response ID for stateful sessions (OpenAI Responses API).
 	pub captured_response_id: Option<String>,
+
+	/// Client-side timing metrics (latency, time to first token, tokens per second).
+	/// Set by the `ChatStream` when the `End` event is emitted.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub metrics: Option<ChatMetrics>,
+	/// True when the stream was interrupted by an error before its natural end.
+	/// The captured fields then hold the partial data received so far, and the error is the next stream item.
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub incomplete: bool,
 }
 
 impl From<InterStreamEnd> for StreamEnd {
@@ -174,11 +369,13 @@ impl From<InterStreamEnd> for StreamEnd {
 
 		// -- Return result
 		StreamEnd {
-			captured_usage: inter_end.captured_usage,
+			captured_usage: inter_end.captured_usage.filter(|usage| !usage.is_empty()),
 			captured_stop_reason: inter_end.captured_stop_reason,
 			captured_content,
 			captured_reasoning_content: inter_end.captured_reasoning_content,
 			captured_response_id: inter_end.captured_response_id,
+			metrics: None,
+			incomplete: inter_end.interrupted_by.is_some(),
 		}
 	}
 }
@@ -284,6 +481,47 @@ impl StreamEnd {
 
 // endregion: --- ChatStreamEvent
 
+// region:    --- ToolArgsAccumulator
+
+/// Accumulates the `ChatStreamEvent::ToolCallArgsChunk` fragments of a stream,
+/// to render the tool call arguments while they are streamed (e.g., a file path being typed out).
+#[derive(Debug, Clone, Default)]
+pub struct ToolArgsAccumulator {
+	/// Accumulated raw arguments, by tool index.
+	tool_args: Vec<String>,
+}
+
+impl ToolArgsAccumulator {
+	/// Adds the event fragment if it is a `ToolCallArgsChunk` (other events are ignored),
+	/// and returns the updated tool index.
+	pub fn push(&mut self, event: &ChatStreamEvent) -> Option<usize> {
+		let ChatStreamEvent::ToolCallArgsChunk(args_chunk) = event else {
+			return None;
+		};
+		let tool_index = args_chunk.tool_index;
+		if self.tool_args.len() <= tool_index {
+			self.tool_args.resize(tool_index + 1, String::new());
+		}
+		self.tool_args[tool_index].push_str(&args_chunk.fragment);
+		Some(tool_index)
+	}
+
+	/// Returns the raw arguments accumulated so far for this tool index.
+	pub fn tool_args_raw(&self, tool_index: usize) -> Option<&str> {
+		self.tool_args.get(tool_index).map(String::as_str)
+	}
+
+	/// Returns the arguments accumulated so far for this tool index, leniently parsed
+	/// (open strings, arrays, and objects are closed, and a trailing incomplete member is dropped).
+	///
+	/// Returns `None` when nothing can be parsed yet.
+	pub fn partial_tool_args(&self, tool_index: usize) -> Option<Value> {
+		parse_partial_json(self.tool_args_raw(tool_index)?)
+	}
+}
+
+// endregion: --- ToolArgsAccumulator
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -310,6 +548,8 @@ mod tests {
 			])),
 			captured_reasoning_content: Some("I should call the weather tool.".to_string()),
 			captured_response_id: None,
+			metrics: None,
+			incomplete: false,
 		};
 
 		let assistant_msg = stream_end
@@ -338,4 +578,158 @@ mod tests {
 			Some(StopReason::Completed("stop".to_string()))
 		);
 	}
+
+	#[test]
+	fn test_stream_end_empty_usage_is_none() {
+		// -- Not reported by the provider (empty usage)
+		let inter_end = InterStreamEnd {
+			captured_usage: Some(Usage::default()),
+			..Default::default()
+		};
+		let stream_end = StreamEnd::from(inter_end);
+		assert!(stream_end.captured_usage.is_none());
+
+		// -- Reported
+		let inter_end = InterStreamEnd {
+			captured_usage: Some(Usage {
+				prompt_tokens: Some(3),
+				..Default::default()
+			}),
+			..Default::default()
+		};
+		let stream_end = StreamEnd::from(inter_end);
+		assert_eq!(stream_end.captured_usage.and_then(|u| u.prompt_tokens), Some(3));
+	}
+
+	fn assert_send<T: Send + 'static>() {}
+
+	#[test]
+	fn test_chat_stream_is_send_static() {
+		assert_send::<ChatStream>();
+		assert_send::<ChatStreamEvent>();
+		assert_send::<crate::chat::ChatStreamResponse>();
+	}
+
+	#[tokio::test]
+	async fn test_chat_stream_consumed_in_spawned_task() -> crate::Result<()> {
+		use futures::StreamExt;
+
+		// -- Setup & Fixtures
+		let events = vec![
+			Ok(InterStreamEvent::Start),
+			Ok(InterStreamEvent::Chunk("Hello".to_string())),
+			Ok(InterStreamEvent::End(InterStreamEnd::default())),
+		];
+		let stream = ChatStream::from_inter_stream(futures::stream::iter(events));
+
+		// -- Exec
+		let handle = tokio::spawn(async move {
+			let mut stream = stream;
+			let mut texts = Vec::new();
+			while let Some(event) = stream.next().await {
+				if let ChatStreamEvent::Chunk(chunk) = event? {
+					texts.push(chunk.content);
+				}
+			}
+			crate::Result::Ok(texts)
+		});
+		let texts = handle.await.map_err(|err| crate::Error::Internal(err.to_string()))??;
+
+		// -- Check
+		assert_eq!(texts, vec!["Hello".to_string()]);
+
+		Ok(())
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn test_chat_stream_metrics_with_controlled_timing() -> crate::Result<()> {
+		use futures::StreamExt;
+
+		// -- Setup & Fixtures
+		// (delay before the event in ms, event) - with paused time, `sleep` advances the clock exactly.
+		let end = InterStreamEnd {
+			captured_usage: Some(Usage {
+				completion_tokens: Some(20),
+				..Default::default()
+			}),
+			..Default::default()
+		};
+		let events = vec![
+			(0, InterStreamEvent::Start),
+			(200, InterStreamEvent::Chunk("Hello".to_string())),
+			(100, InterStreamEvent::Chunk(" world".to_string())),
+			(100, InterStreamEvent::End(end)),
+		];
+		let inter_stream = futures::stream::iter(events).then(|(delay_ms, event)| async move {
+			tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+			Ok(event)
+		});
+
+		// -- Exec
+		let mut stream = ChatStream::from_inter_stream(inter_stream);
+		let mut stream_end = None;
+		while let Some(event) = stream.next().await {
+			if let ChatStreamEvent::End(end) = event? {
+				stream_end = Some(end);
+			}
+		}
+
+		// -- Check
+		let metrics = stream_end.and_then(|end| end.metrics).expect("stream end should have metrics");
+		assert_eq!(metrics.latency_ms, 400);
+		assert_eq!(metrics.time_to_first_token_ms, Some(200));
+		// 20 tokens over the 200ms between the first token and the end
+		assert_eq!(metrics.tokens_per_second, Some(100.0));
+
+		Ok(())
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn test_chat_stream_first_token_event_before_chunks() -> crate::Result<()> {
+		use futures::StreamExt;
+
+		// -- Setup & Fixtures
+		let events = vec![
+			(0, InterStreamEvent::Start),
+			(50, InterStreamEvent::ThoughtSignatureChunk("sig".to_string())),
+			(100, InterStreamEvent::ReasoningChunk("Thinking".to_string())),
+			(100, InterStreamEvent::Chunk("Hello".to_string())),
+			(0, InterStreamEvent::End(InterStreamEnd::default())),
+		];
+		let inter_stream = futures::stream::iter(events).then(|(delay_ms, event)| async move {
+			tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+			Ok(event)
+		});
+
+		// -- Exec
+		let mut stream = ChatStream::from_inter_stream(inter_stream);
+		let mut names: Vec<&str> = Vec::new();
+		let mut first_token_elapsed = None;
+		while let Some(event) = stream.next().await {
+			let name = match event? {
+				ChatStreamEvent::Start => "Start",
+				ChatStreamEvent::FirstToken(first_token) => {
+					first_token_elapsed = Some(first_token.elapsed);
+					"FirstToken"
+				}
+				ChatStreamEvent::Chunk(_) => "Chunk",
+				ChatStreamEvent::ReasoningChunk(_) => "ReasoningChunk",
+				ChatStreamEvent::ThoughtSignatureChunk(_) => "ThoughtSignatureChunk",
+				ChatStreamEvent::ToolCallChunk(_) => "ToolCallChunk",
+				ChatStreamEvent::ToolCallArgsChunk(_) => "ToolCallArgsChunk",
+				ChatStreamEvent::End(_) => "End",
+			};
+			names.push(name);
+		}
+
+		// -- Check
+		// thought signatures are not tokens, so the first token is the reasoning chunk
+		assert_eq!(
+			names,
+			vec!["Start", "ThoughtSignatureChunk", "FirstToken", "ReasoningChunk", "Chunk", "End"]
+		);
+		assert_eq!(first_token_elapsed, Some(Duration::from_millis(150)));
+
+		Ok(())
+	}
 }
@@ -1,11 +1,15 @@
 /// Note: MessageContent is used for ChatRequest and ChatResponse.
 use crate::chat::{Binary, ContentPart, CustomPart, ToolCall, ToolResponse};
+use crate::redaction::{RedactedDebug, RedactionMode, redact_text, redaction_mode};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Message content container used in ChatRequest and ChatResponse.
 ///
 /// Transparent wrapper around a list of ContentPart (Text, Binary, ToolCall, or ToolResponse).
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+///
+/// Note: The `Debug` output follows the `genai::redaction` mode (parts rendered as size and hash summaries).
+#[derive(Clone, Default, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct MessageContent {
 	/// The parts that compose this message.
@@ -87,6 +91,24 @@ impl MessageContent {
 	}
 }
 
+/// Normalization
+impl MessageContent {
+	/// Merges each run of adjacent text parts into one text part,
+	/// joined with a blank line (as `joined_texts`). The other parts are kept in place.
+	pub fn merge_adjacent_texts(&mut self) {
+		let mut merged: Vec<ContentPart> = Vec::with_capacity(self.parts.len());
+		for part in std::mem::take(&mut self.parts) {
+			match (merged.last_mut(), part) {
+				(Some(ContentPart::Text(combined)), ContentPart::Text(text)) => {
+					support::combine_text_with_empty_line(combined, &text);
+				}
+				(_, part) => merged.push(part),
+			}
+		}
+		self.parts = merged;
+	}
+}
+
 impl Extend<ContentPart> for MessageContent {
 	fn extend<T: IntoIterator<Item = ContentPart>>(&mut self, iter: T) {
 		self.parts.extend(iter);
@@ -504,9 +526,80 @@ impl From<Vec<ContentPart>> for MessageContent {
 
 // endregion: --- Froms
 
+// region:    --- Debug
+
+impl fmt::Debug for MessageContent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mode = redaction_mode();
+		if mode.is_off() {
+			return f.debug_struct("MessageContent").field("parts", &self.parts).finish();
+		}
+
+		let parts: Vec<RedactedDebug> = self.parts.iter().map(|part| RedactedDebug(redact_part(part, mode))).collect();
+		f.debug_struct("MessageContent").field("parts", &parts).finish()
+	}
+}
+
+/// Summary of one part, e.g., `[text: 1.2kB sha256:ab12cd34…]` (non-text parts are summarized from their JSON).
+fn redact_part(part: &ContentPart, mode: RedactionMode) -> String {
+	let (label, content) = match part {
+		ContentPart::Text(text) => ("text", text.clone()),
+		ContentPart::ReasoningContent(reasoning) => ("reasoning", reasoning.clone()),
+		ContentPart::ThoughtSignature(thought) => ("thought_signature", thought.clone()),
+		ContentPart::Binary(binary) => ("binary", serde_json::to_string(binary).unwrap_or_default()),
+		ContentPart::ToolCall(tool_call) => ("tool_call", serde_json::to_string(tool_call).unwrap_or_default()),
+		ContentPart::ToolResponse(tool_response) => (
+			"tool_response",
+			serde_json::to_string(tool_response).unwrap_or_default(),
+		),
+		ContentPart::Custom(custom) => ("custom", serde_json::to_string(custom).unwrap_or_default()),
+	};
+	redact_text(label, &content, mode)
+}
+
+// endregion: --- Debug
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::redaction::with_forced_mode;
+
+	#[test]
+	fn test_message_content_debug_redacted() {
+		let content = MessageContent::from_text("my secret prompt").append(ToolCall {
+			call_id: "call_1".to_string(),
+			fn_name: "lookup".to_string(),
+			fn_arguments: serde_json::json!({"query": "secret arguments"}),
+			thought_signatures: None,
+		});
+
+		let hashed = with_forced_mode(RedactionMode::Hash, || format!("{content:?}"));
+		let length_only = with_forced_mode(RedactionMode::LengthOnly, || format!("{content:?}"));
+		let off = with_forced_mode(RedactionMode::Off, || format!("{content:?}"));
+
+		assert!(!hashed.contains("secret"), "{hashed}");
+		assert!(hashed.contains("[text: 16B sha256:"), "{hashed}");
+		assert!(!length_only.contains("secret"), "{length_only}");
+		assert!(length_only.contains("[text: 16B]"), "{length_only}");
+		assert!(off.contains("my secret prompt"), "{off}");
+	}
+
+	#[test]
+	fn test_message_content_merge_adjacent_texts() {
+		let mut content = MessageContent::from_parts(vec![
+			ContentPart::Text("One".to_string()),
+			ContentPart::Text("Two".to_string()),
+			ContentPart::Text("Three".to_string()),
+			ContentPart::ReasoningContent("thinking".to_string()),
+			ContentPart::Text("Four".to_string()),
+		]);
+
+		content.merge_adjacent_texts();
+
+		assert_eq!(content.len(), 3);
+		assert_eq!(content.texts(), vec!["One\n\nTwo\n\nThree", "Four"]);
+		assert!(content.parts()[1].is_reasoning_content());
+	}
 
 	#[test]
 	fn test_message_content_joined_texts_empty() {
@@ -5,6 +5,7 @@
 
 mod binary;
 mod chat_message;
+mod chat_metrics;
 mod chat_options;
 mod chat_req_response_format;
 mod chat_request;
@@ -14,10 +15,12 @@ mod content_part;
 mod message_content;
 mod tool;
 mod usage;
+mod usage_report;
 
 // -- Flatten
 pub use binary::*;
 pub use chat_message::*;
+pub use chat_metrics::*;
 pub use chat_options::*;
 pub use chat_req_response_format::*;
 pub use chat_request::*;
@@ -27,6 +30,7 @@ pub use content_part::*;
 pub use message_content::*;
 pub use tool::*;
 pub use usage::*;
+pub use usage_report::*;
 
 #[doc = "Printing helpers for chat requests and streaming output."]
 pub mod printer;
@@ -84,6 +84,18 @@ async fn print_chat_stream_inner(
 						}
 					}
 
+					ChatStreamEvent::FirstToken(first_token) => {
+						if print_events {
+							(
+								Some(format!("\n-- ChatStreamEvent::FirstToken {:?}\n", first_token.elapsed)),
+								None,
+								false,
+							)
+						} else {
+							(None, None, false)
+						}
+					}
+
 					ChatStreamEvent::Chunk(StreamChunk { content }) => {
 						if print_events && first_chunk {
 							first_chunk = false;
@@ -139,6 +151,9 @@ async fn print_chat_stream_inner(
 						}
 					}
 
+					// The fragments are already in the ToolCallChunk arguments
+					ChatStreamEvent::ToolCallArgsChunk(_) => (None, None, false),
+
 					ChatStreamEvent::End(end_event) => {
 						if print_events {
 							// TODO: Might implement pretty JSON formatting
@@ -153,6 +168,13 @@ async fn print_chat_stream_inner(
 					}
 				}
 			}
+			// Keep the content printed so far, so it can be recovered from the error
+			Err(e) if !content_capture.is_empty() => {
+				return Err(Error::StreamInterrupted {
+					partial_content: content_capture,
+					cause: e,
+				});
+			}
 			Err(e) => return Err(e.into()),
 		};
 
@@ -195,6 +217,13 @@ pub enum Error {
 	/// The stream returned an error from the main crate.
 	#[from]
 	Stream(crate::Error),
+
+	/// The stream returned an error after some content was printed (e.g., connection dropped mid-stream).
+	StreamInterrupted {
+		/// The content printed (and captured) before the error.
+		partial_content: String,
+		cause: crate::Error,
+	},
 }
 
 // region:    --- Error Boilerplate
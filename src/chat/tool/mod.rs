@@ -9,12 +9,14 @@
 
 mod tool_base;
 mod tool_call;
+mod tool_choice;
 mod tool_response;
 mod tool_types;
 mod web_search_config;
 
 pub use tool_base::*;
 pub use tool_call::*;
+pub use tool_choice::*;
 pub use tool_response::*;
 pub use tool_types::*;
 pub use web_search_config::*;
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// How the model should use the request tools (`ChatOptions::with_tool_choice(..)`).
+///
+/// Provider mapping (sent with the request tools only):
+/// - Anthropic: `Auto` → `auto`, `Required` → `any`, `None` → `none`, `Tool` → `tool` with its `name`.
+/// - OpenAI (and compatible): `auto`, `required`, `none`, `Tool` → `{"type": "function", ..}` with its name.
+/// - Gemini: `AUTO`, `ANY`, `NONE`, `Tool` → `ANY` with its name as the only allowed function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ToolChoice {
+	/// The model decides whether to call a tool (provider default).
+	Auto,
+	/// The model must call at least one of the tools.
+	Required,
+	/// The model must not call any tool.
+	None,
+	/// The model must call this tool.
+	Tool(String),
+}
+
+impl ToolChoice {
+	/// Forces the call of the tool named `name`.
+	pub fn tool(name: impl Into<String>) -> Self {
+		Self::Tool(name.into())
+	}
+}
@@ -8,6 +8,13 @@ pub struct ToolResponse {
 	/// Tool output payload as a string. Providers may use JSON-serialized content.
 	// For now, just a string (would probably be serialized JSON)
 	pub content: String,
+	/// True when the tool execution failed, and `content` is the error message (see `ToolResponse::error`).
+	///
+	/// - Anthropic: sent as `is_error: true` on the `tool_result` block.
+	/// - Gemini: sent as the `error` (instead of `content`) of the `functionResponse.response`.
+	/// - OpenAI and compatibles: no dedicated field, sent as a plain tool message with the error as content.
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub is_error: bool,
 }
 
 /// Constructor
@@ -17,6 +24,16 @@ impl ToolResponse {
 		Self {
 			call_id: tool_call_id.into(),
 			content: content.into(),
+			is_error: false,
+		}
+	}
+
+	/// Creates a ToolResponse for a failed tool execution, with the error message as content.
+	pub fn error(tool_call_id: impl Into<String>, message: impl Into<String>) -> Self {
+		Self {
+			call_id: tool_call_id.into(),
+			content: message.into(),
+			is_error: true,
 		}
 	}
 }
@@ -42,4 +59,8 @@ impl ToolResponse {
 	fn content(&self) -> &str {
 		&self.content
 	}
+
+	fn is_error(&self) -> bool {
+		self.is_error
+	}
 }
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none};
+use std::ops::{Add, AddAssign};
 
 /// Normalized token usage across providers (OpenAI-compatible).
 ///
@@ -28,6 +29,17 @@ pub struct Usage {
 }
 
 impl Usage {
+	/// True if no counter was reported (all the token counts and details are `None` or empty).
+	///
+	/// NOTE: A provider reporting `0` tokens is not empty (the counter is `Some(0)`).
+	pub fn is_empty(&self) -> bool {
+		self.prompt_tokens.is_none()
+			&& self.prompt_tokens_details.as_ref().map(|d| d.is_empty()).unwrap_or(true)
+			&& self.completion_tokens.is_none()
+			&& self.completion_tokens_details.as_ref().map(|d| d.is_empty()).unwrap_or(true)
+			&& self.total_tokens.is_none()
+	}
+
 	/// Remove detail objects that contain only `None` fields.
 	pub fn compact_details(&mut self) {
 		if self.prompt_tokens_details.as_ref().is_some_and(|d| d.is_empty()) {
@@ -111,3 +123,67 @@ impl CompletionTokensDetails {
 			&& self.audio_tokens.is_none()
 	}
 }
+
+// region:    --- Usage Arithmetic
+
+/// Sums the counters and the details (a counter stays `None` when `None` on both sides).
+impl AddAssign<&Usage> for Usage {
+	fn add_assign(&mut self, other: &Usage) {
+		add_count(&mut self.prompt_tokens, other.prompt_tokens);
+		add_details(&mut self.prompt_tokens_details, &other.prompt_tokens_details);
+		add_count(&mut self.completion_tokens, other.completion_tokens);
+		add_details(&mut self.completion_tokens_details, &other.completion_tokens_details);
+		add_count(&mut self.total_tokens, other.total_tokens);
+	}
+}
+
+impl Add for Usage {
+	type Output = Usage;
+
+	fn add(mut self, other: Usage) -> Usage {
+		self += &other;
+		self
+	}
+}
+
+impl AddAssign<&PromptTokensDetails> for PromptTokensDetails {
+	fn add_assign(&mut self, other: &PromptTokensDetails) {
+		add_count(&mut self.cache_creation_tokens, other.cache_creation_tokens);
+		add_details(&mut self.cache_creation_details, &other.cache_creation_details);
+		add_count(&mut self.cached_tokens, other.cached_tokens);
+		add_count(&mut self.audio_tokens, other.audio_tokens);
+	}
+}
+
+impl AddAssign<&CacheCreationDetails> for CacheCreationDetails {
+	fn add_assign(&mut self, other: &CacheCreationDetails) {
+		add_count(&mut self.ephemeral_5m_tokens, other.ephemeral_5m_tokens);
+		add_count(&mut self.ephemeral_1h_tokens, other.ephemeral_1h_tokens);
+	}
+}
+
+impl AddAssign<&CompletionTokensDetails> for CompletionTokensDetails {
+	fn add_assign(&mut self, other: &CompletionTokensDetails) {
+		add_count(&mut self.accepted_prediction_tokens, other.accepted_prediction_tokens);
+		add_count(&mut self.rejected_prediction_tokens, other.rejected_prediction_tokens);
+		add_count(&mut self.reasoning_tokens, other.reasoning_tokens);
+		add_count(&mut self.audio_tokens, other.audio_tokens);
+	}
+}
+
+fn add_count(total: &mut Option<i32>, value: Option<i32>) {
+	if let Some(value) = value {
+		*total = Some(total.unwrap_or(0) + value);
+	}
+}
+
+fn add_details<T>(total: &mut Option<T>, value: &Option<T>)
+where
+	T: Default + for<'a> AddAssign<&'a T>,
+{
+	if let Some(value) = value {
+		*total.get_or_insert_with(T::default) += value;
+	}
+}
+
+// endregion: --- Usage Arithmetic
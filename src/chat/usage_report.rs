@@ -0,0 +1,399 @@
+//! Usage aggregation across many calls and providers - `UsageReport`.
+//!
+//! For the multi-call workflows (agent runs, batches), the `(ModelIden, Usage)` of each call are added to a report,
+//! which gives the per-model, per-adapter, and overall totals, with the reasoning and cached token breakdowns,
+//! and a cost rollup from the caller pricing.
+//!
+//! The report is fed by the caller: no `Client` feature adds to it on its own.
+//!
+//! NOTE: A `ChatResponse` from `exec_chat` with auto-continue, or an `EmbedResponse` from the batch embedding,
+//!       already carries the summed usage of its calls, and is added as one entry (`add_chat_response`, `add_embed_response`).
+//!
+//! NOTE: For `exec_chat_hedged`, the response `model_iden` is the winning target, so `add_chat_response` attributes
+//!       the usage to it. The dropped hedge requests report no usage, so they are not in the report
+//!       (even though they may cost tokens).
+
+use crate::ModelIden;
+use crate::adapter::AdapterKind;
+use crate::chat::{ChatResponse, Usage};
+use crate::embed::EmbedResponse;
+use serde::{Deserialize, Serialize};
+
+// region:    --- ModelUsage
+
+/// The usage totals of one model in a `UsageReport`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelUsage {
+	pub model_iden: ModelIden,
+
+	/// The number of entries added for this model.
+	pub calls: u32,
+
+	/// The summed usage (counters and details) of this model.
+	pub usage: Usage,
+}
+
+// endregion: --- ModelUsage
+
+// region:    --- TokenPricing
+
+/// The price of a model, per million tokens (in any currency, as long as the same one is used for all models).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenPricing {
+	pub input_per_million: f64,
+	/// The price of the cached input tokens (`prompt_tokens_details.cached_tokens`). `input_per_million` when not set.
+	pub cached_input_per_million: Option<f64>,
+	pub output_per_million: f64,
+}
+
+impl TokenPricing {
+	pub fn new(input_per_million: f64, output_per_million: f64) -> Self {
+		Self {
+			input_per_million,
+			cached_input_per_million: None,
+			output_per_million,
+		}
+	}
+
+	pub fn with_cached_input_per_million(mut self, cached_input_per_million: f64) -> Self {
+		self.cached_input_per_million = Some(cached_input_per_million);
+		self
+	}
+
+	/// Returns the cost of the usage.
+	pub fn cost(&self, usage: &Usage) -> f64 {
+		let prompt_tokens = usage.prompt_tokens.unwrap_or(0).max(0) as f64;
+		let cached_tokens = cached_tokens(usage) as f64;
+		let completion_tokens = usage.completion_tokens.unwrap_or(0).max(0) as f64;
+		let cached_price = self.cached_input_per_million.unwrap_or(self.input_per_million);
+
+		((prompt_tokens - cached_tokens).max(0.) * self.input_per_million
+			+ cached_tokens * cached_price
+			+ completion_tokens * self.output_per_million)
+			/ 1_000_000.
+	}
+}
+
+/// The cost rollup of a `UsageReport` (see `UsageReport::cost`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CostRollup {
+	/// The total cost of the priced models.
+	pub total: f64,
+
+	/// The cost of each priced model, in the report order.
+	pub by_model: Vec<(ModelIden, f64)>,
+
+	/// The models without pricing (not in `total`).
+	pub unpriced: Vec<ModelIden>,
+}
+
+// endregion: --- TokenPricing
+
+// region:    --- UsageReport
+
+/// The aggregated usage of many calls, possibly across providers (serializable for logging).
+///
+/// ```ignore
+/// let mut report = UsageReport::default();
+/// report.add_chat_response(&chat_res);
+/// report.add(embed_res.model_iden.clone(), &embed_res.usage);
+/// println!("{}", serde_json::to_string(&report)?);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageReport {
+	/// The per-model totals, in the order the models were first added.
+	models: Vec<ModelUsage>,
+}
+
+/// Add
+impl UsageReport {
+	/// Adds the usage of one call of the model.
+	pub fn add(&mut self, model_iden: ModelIden, usage: &Usage) {
+		match self.models.iter_mut().find(|model| model.model_iden == model_iden) {
+			Some(model) => {
+				model.calls += 1;
+				model.usage += usage;
+			}
+			None => self.models.push(ModelUsage {
+				model_iden,
+				calls: 1,
+				usage: usage.clone(),
+			}),
+		}
+	}
+
+	/// Adds the usage of a chat response (for its `model_iden`).
+	pub fn add_chat_response(&mut self, chat_res: &ChatResponse) {
+		self.add(chat_res.model_iden.clone(), &chat_res.usage);
+	}
+
+	/// Adds the usage of an embed response (for its `model_iden`).
+	pub fn add_embed_response(&mut self, embed_res: &EmbedResponse) {
+		self.add(embed_res.model_iden.clone(), &embed_res.usage);
+	}
+
+	/// Adds all the entries of another report.
+	pub fn merge(&mut self, other: &UsageReport) {
+		for other_model in other.models.iter() {
+			match self.models.iter_mut().find(|model| model.model_iden == other_model.model_iden) {
+				Some(model) => {
+					model.calls += other_model.calls;
+					model.usage += &other_model.usage;
+				}
+				None => self.models.push(other_model.clone()),
+			}
+		}
+	}
+}
+
+/// Totals
+impl UsageReport {
+	pub fn is_empty(&self) -> bool {
+		self.models.is_empty()
+	}
+
+	/// The per-model totals, in the order the models were first added.
+	pub fn by_model(&self) -> &[ModelUsage] {
+		&self.models
+	}
+
+	/// The totals of a model, if added.
+	pub fn model(&self, model_iden: &ModelIden) -> Option<&ModelUsage> {
+		self.models.iter().find(|model| &model.model_iden == model_iden)
+	}
+
+	/// The per-adapter totals (calls and usage), in the order the adapters were first added.
+	pub fn by_adapter(&self) -> Vec<(AdapterKind, u32, Usage)> {
+		let mut by_adapter: Vec<(AdapterKind, u32, Usage)> = Vec::new();
+		for model in self.models.iter() {
+			let adapter_kind = model.model_iden.adapter_kind;
+			match by_adapter.iter_mut().find(|(kind, ..)| *kind == adapter_kind) {
+				Some((_, calls, usage)) => {
+					*calls += model.calls;
+					*usage += &model.usage;
+				}
+				None => by_adapter.push((adapter_kind, model.calls, model.usage.clone())),
+			}
+		}
+		by_adapter
+	}
+
+	/// The number of calls added.
+	pub fn calls(&self) -> u32 {
+		self.models.iter().map(|model| model.calls).sum()
+	}
+
+	/// The summed usage of all the models.
+	pub fn total(&self) -> Usage {
+		let mut total = Usage::default();
+		for model in self.models.iter() {
+			total += &model.usage;
+		}
+		total
+	}
+
+	/// The total reasoning tokens (`completion_tokens_details.reasoning_tokens`).
+	pub fn reasoning_tokens(&self) -> i32 {
+		self.models.iter().map(|model| reasoning_tokens(&model.usage)).sum()
+	}
+
+	/// The total cached input tokens (`prompt_tokens_details.cached_tokens`).
+	pub fn cached_tokens(&self) -> i32 {
+		self.models.iter().map(|model| cached_tokens(&model.usage)).sum()
+	}
+
+	/// The total cache creation tokens (`prompt_tokens_details.cache_creation_tokens`).
+	pub fn cache_creation_tokens(&self) -> i32 {
+		self.models
+			.iter()
+			.filter_map(|model| model.usage.prompt_tokens_details.as_ref()?.cache_creation_tokens)
+			.sum()
+	}
+
+	/// The cost of each model with a pricing (from `pricing_fn`), and their total.
+	pub fn cost(&self, pricing_fn: impl Fn(&ModelIden) -> Option<TokenPricing>) -> CostRollup {
+		let mut rollup = CostRollup::default();
+		for model in self.models.iter() {
+			match pricing_fn(&model.model_iden) {
+				Some(pricing) => {
+					let cost = pricing.cost(&model.usage);
+					rollup.total += cost;
+					rollup.by_model.push((model.model_iden.clone(), cost));
+				}
+				None => rollup.unpriced.push(model.model_iden.clone()),
+			}
+		}
+		rollup
+	}
+}
+
+// endregion: --- UsageReport
+
+// region:    --- Support
+
+fn cached_tokens(usage: &Usage) -> i32 {
+	usage
+		.prompt_tokens_details
+		.as_ref()
+		.and_then(|details| details.cached_tokens)
+		.unwrap_or(0)
+}
+
+fn reasoning_tokens(usage: &Usage) -> i32 {
+	usage
+		.completion_tokens_details
+		.as_ref()
+		.and_then(|details| details.reasoning_tokens)
+		.unwrap_or(0)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chat::{CompletionTokensDetails, PromptTokensDetails};
+
+	fn usage(prompt: i32, completion: i32, cached: Option<i32>, reasoning: Option<i32>) -> Usage {
+		Usage {
+			prompt_tokens: Some(prompt),
+			prompt_tokens_details: cached.map(|cached_tokens| PromptTokensDetails {
+				cached_tokens: Some(cached_tokens),
+				..Default::default()
+			}),
+			completion_tokens: Some(completion),
+			completion_tokens_details: reasoning.map(|reasoning_tokens| CompletionTokensDetails {
+				reasoning_tokens: Some(reasoning_tokens),
+				..Default::default()
+			}),
+			total_tokens: Some(prompt + completion),
+		}
+	}
+
+	/// A synthetic agent run: two OpenAI models, one Anthropic model, one Gemini model.
+	fn agent_run_report() -> UsageReport {
+		let gpt = ModelIden::new(AdapterKind::OpenAI, "gpt-4o");
+		let gpt_mini = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let claude = ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5");
+		let gemini = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+
+		let mut report = UsageReport::default();
+		report.add(gpt.clone(), &usage(1_000, 200, Some(400), None));
+		report.add(claude.clone(), &usage(2_000, 500, Some(1_500), Some(100)));
+		report.add(gpt.clone(), &usage(1_200, 300, None, None));
+		report.add(gpt_mini, &usage(500, 50, None, None));
+		report.add(gemini, &usage(800, 400, None, Some(250)));
+		report.add(claude, &usage(2_500, 600, Some(2_000), Some(150)));
+		report
+	}
+
+	#[test]
+	fn test_usage_report_totals_and_grouping() {
+		let report = agent_run_report();
+
+		// -- Per model (first added order)
+		let by_model = report.by_model();
+		assert_eq!(by_model.len(), 4);
+		assert_eq!(&*by_model[0].model_iden.model_name, "gpt-4o");
+		assert_eq!(by_model[0].calls, 2);
+		assert_eq!(by_model[0].usage.prompt_tokens, Some(2_200));
+		assert_eq!(by_model[0].usage.completion_tokens, Some(500));
+		assert_eq!(&*by_model[1].model_iden.model_name, "claude-sonnet-4-5");
+		assert_eq!(by_model[1].usage.total_tokens, Some(5_600));
+
+		// -- Per adapter
+		let by_adapter = report.by_adapter();
+		let kinds: Vec<AdapterKind> = by_adapter.iter().map(|(kind, ..)| *kind).collect();
+		assert_eq!(
+			kinds,
+			vec![AdapterKind::OpenAI, AdapterKind::Anthropic, AdapterKind::Gemini]
+		);
+		let (_, openai_calls, openai_usage) = &by_adapter[0];
+		assert_eq!(*openai_calls, 3);
+		assert_eq!(openai_usage.prompt_tokens, Some(2_700));
+		assert_eq!(openai_usage.total_tokens, Some(3_250));
+
+		// -- Overall
+		assert_eq!(report.calls(), 6);
+		let total = report.total();
+		assert_eq!(total.prompt_tokens, Some(8_000));
+		assert_eq!(total.completion_tokens, Some(2_050));
+		assert_eq!(total.total_tokens, Some(10_050));
+
+		// -- Breakdowns
+		assert_eq!(report.cached_tokens(), 3_900);
+		assert_eq!(report.reasoning_tokens(), 500);
+		assert_eq!(report.cache_creation_tokens(), 0);
+	}
+
+	#[test]
+	fn test_usage_report_cost_rollup() {
+		let report = agent_run_report();
+
+		let rollup = report.cost(|model_iden| match &*model_iden.model_name {
+			"gpt-4o" => Some(TokenPricing::new(2.5, 10.).with_cached_input_per_million(1.25)),
+			"claude-sonnet-4-5" => Some(TokenPricing::new(3., 15.).with_cached_input_per_million(0.3)),
+			_ => None,
+		});
+
+		// gpt-4o: (2_200 - 400) * 2.5 + 400 * 1.25 + 500 * 10 = 10_000 (per million)
+		// claude: (4_500 - 3_500) * 3 + 3_500 * 0.3 + 1_100 * 15 = 20_550 (per million)
+		assert_eq!(rollup.by_model.len(), 2);
+		assert!((rollup.by_model[0].1 - 0.01).abs() < 1e-9);
+		assert!((rollup.by_model[1].1 - 0.02055).abs() < 1e-9);
+		assert!((rollup.total - 0.03055).abs() < 1e-9);
+		let unpriced: Vec<&str> = rollup.unpriced.iter().map(|m| &*m.model_name).collect();
+		assert_eq!(unpriced, vec!["gpt-4o-mini", "gemini-2.5-flash"]);
+	}
+
+	#[test]
+	fn test_usage_report_merge_and_serde() -> crate::Result<()> {
+		let mut report = agent_run_report();
+		let mut other = UsageReport::default();
+		other.add(
+			ModelIden::new(AdapterKind::OpenAI, "gpt-4o"),
+			&usage(100, 10, None, None),
+		);
+		other.add(
+			ModelIden::new(AdapterKind::DeepSeek, "deepseek-chat"),
+			&usage(50, 5, None, None),
+		);
+
+		report.merge(&other);
+
+		assert_eq!(report.calls(), 8);
+		assert_eq!(report.by_model().len(), 5);
+		let gpt = report
+			.model(&ModelIden::new(AdapterKind::OpenAI, "gpt-4o"))
+			.expect("should have gpt-4o");
+		assert_eq!(gpt.calls, 3);
+		assert_eq!(gpt.usage.prompt_tokens, Some(2_300));
+
+		// -- Serde round trip
+		let json = serde_json::to_string(&report)?;
+		let report_back: UsageReport = serde_json::from_str(&json)?;
+		assert_eq!(report_back.calls(), 8);
+		assert_eq!(report_back.total().total_tokens, report.total().total_tokens);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_usage_add_sums_details() {
+		let sum = usage(100, 20, Some(40), None) + usage(200, 30, Some(60), Some(10));
+
+		assert_eq!(sum.prompt_tokens, Some(300));
+		assert_eq!(sum.completion_tokens, Some(50));
+		assert_eq!(sum.total_tokens, Some(350));
+		assert_eq!(sum.prompt_tokens_details.and_then(|d| d.cached_tokens), Some(100));
+		assert_eq!(sum.completion_tokens_details.and_then(|d| d.reasoning_tokens), Some(10));
+
+		// -- None on both sides stays None
+		let sum = Usage::default() + Usage::default();
+		assert!(sum.is_empty());
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,71 @@
+//! Automatic continuation of the truncated responses - `ChatOptions::with_auto_continue(..)`.
+//!
+//! When a response stops on the output token limit (`StopReason::MaxTokens`), `exec_chat` sends the request again
+//! with the partial output appended as the last assistant message, and stitches the texts into one `ChatResponse`,
+//! up to `max_continuations` times.
+//!
+//! - The continuation requests cap `max_tokens` to the model known output limit (as `cap_max_tokens`).
+//! - The usage counters (and details) are summed, the other response fields are the ones of the last response.
+//!
+//! NOTE: Only the text is continued; a truncated response without text (e.g., a tool call) is returned as is.
+
+use crate::chat::{
+	ChatMessage, ChatOptions, ChatRequest, ChatResponse, ContentPart, MessageContent, StopReason, Usage,
+};
+use crate::client::ModelSpec;
+use crate::{Client, Result};
+
+// region:    --- Client Auto Continue
+
+impl Client {
+	pub(super) async fn exec_chat_auto_continue(
+		&self,
+		model: ModelSpec,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		max_continuations: u32,
+	) -> Result<ChatResponse> {
+		let continue_options = options.cloned().unwrap_or_default().with_cap_max_tokens(true);
+
+		let mut chat_res = self.exec_chat_once(model.clone(), chat_req.clone(), options).await?;
+
+		for _ in 0..max_continuations {
+			if !chat_res.stop_reason.as_ref().is_some_and(StopReason::is_max_tokens) {
+				break;
+			}
+			let partial = chat_res.content.texts().concat();
+			if partial.is_empty() {
+				break;
+			}
+
+			let continue_req = chat_req.clone().append_message(ChatMessage::assistant(partial.clone()));
+			let next_res = self
+				.exec_chat_once(model.clone(), continue_req, Some(&continue_options))
+				.await?;
+			chat_res = stitch_responses(partial, chat_res.usage, next_res);
+		}
+
+		Ok(chat_res)
+	}
+}
+
+// endregion: --- Client Auto Continue
+
+// region:    --- Support
+
+/// Returns the `next_res` with its text prefixed by the `partial` text, and the usage summed with `prev_usage`.
+/// The usage is reported when any of the stitched responses reported it.
+fn stitch_responses(partial: String, prev_usage: Usage, mut next_res: ChatResponse) -> ChatResponse {
+	let next_text = next_res.content.texts().concat();
+	let other_parts = next_res
+		.content
+		.into_parts()
+		.into_iter()
+		.filter(|part| !matches!(part, ContentPart::Text(_)));
+	next_res.content = MessageContent::from_text(partial + &next_text).extended(other_parts);
+	next_res.usage_reported |= !prev_usage.is_empty();
+	next_res.usage = prev_usage + next_res.usage;
+	next_res
+}
+
+// endregion: --- Support
@@ -1,4 +1,7 @@
+use crate::adapter::ServiceType;
 use crate::chat::ChatOptions;
+use crate::client::ChatMiddleware;
+use crate::redaction::RedactionMode;
 use crate::resolver::{
 	AuthResolver, IntoAuthResolverFn, IntoModelMapperFn, IntoServiceTargetResolverFn, ModelMapper,
 	ServiceTargetResolver,
@@ -50,6 +53,62 @@ impl ClientBuilder {
 		self
 	}
 
+	/// Enable (or disable) request payload validation on `ClientConfig` (creates it if absent).
+	/// Default: on in debug builds only.
+	pub fn with_validate_payloads(mut self, validate_payloads: bool) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.validate_payloads = Some(validate_payloads);
+		self
+	}
+
+	/// Set the captured raw body redaction mode on `ClientConfig` (creates it if absent).
+	pub fn with_redaction_mode(mut self, mode: RedactionMode) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.redaction_mode = Some(mode);
+		self
+	}
+
+	/// Enable waiting for the rate limit resets on `ClientConfig` (creates it if absent).
+	pub fn with_rate_limit_wait(mut self, rate_limit_wait: bool) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.rate_limit_wait = rate_limit_wait;
+		self
+	}
+
+	/// Add a ChatMiddleware around the `exec_chat` and `exec_chat_stream` requests on `ClientConfig` (creates it if absent).
+	///
+	/// The middlewares run in the order they are added.
+	/// NOTE: The `exec_chat_stream` requests pass through unchanged unless the middleware implements `around_stream`.
+	pub fn with_chat_middleware(mut self, middleware: impl ChatMiddleware + 'static) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.chat_middlewares.push(Arc::new(middleware));
+		self
+	}
+
+	/// Set the MockChatHandler answering the `AdapterKind::Mock` chat requests on `ClientConfig` (creates it if absent).
+	///
+	/// Only with the `mock` feature, to test the code using a `Client` without network.
+	#[cfg(feature = "mock")]
+	pub fn with_mock_chat_handler(mut self, handler: impl crate::adapter::MockChatHandler + 'static) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.mock_chat_handler = Some(Arc::new(handler));
+		self
+	}
+
+	/// Set a service url path override on `ClientConfig` (creates it if absent).
+	pub fn with_service_path(mut self, service_type: ServiceType, path: impl Into<String>) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.service_paths.insert(service_type, path.into());
+		self
+	}
+
+	/// Set the env var name for the `url::<base_url>::<model_name>` API key on `ClientConfig` (creates it if absent).
+	pub fn with_url_model_key_env(mut self, env_name: impl Into<String>) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.url_model_key_env = Some(env_name.into());
+		self
+	}
+
 	/// Set `AuthResolver` on `ClientConfig` (creates it if absent).
 	pub fn with_auth_resolver(mut self, auth_resolver: AuthResolver) -> Self {
 		let client_config = self.config.get_or_insert_with(ClientConfig::default);
@@ -120,7 +179,14 @@ impl ClientBuilder {
 			WebClient::from_reqwest_client(reqwest_client)
 		};
 
-		let inner = super::ClientInner { web_client, config };
-		Client { inner: Arc::new(inner) }
+		let inner = super::ClientInner {
+			web_client,
+			config,
+			rate_limits: Default::default(),
+		};
+		Client {
+			inner: Arc::new(inner),
+			token_budget: None,
+		}
 	}
 }
@@ -0,0 +1,242 @@
+//! Runtime capability probing - `Client::probe_capabilities(..)`.
+//!
+//! IMPORTANT: Probing sends real (tiny) requests to the provider, so it costs tokens. It is only run when called.
+
+use crate::adapter::{CapabilityOverride, ModelCapabilities};
+use crate::chat::{ChatOptions, ChatRequest, ChatResponseFormat, Tool};
+use crate::client::ModelSpec;
+use crate::{Client, ModelIden, Result};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// region:    --- ProbeOptions
+
+/// Options for `Client::probe_capabilities`. All probes run by default.
+#[derive(Debug, Clone, Default)]
+pub struct ProbeOptions {
+	pub skip_chat: bool,
+	pub skip_streaming: bool,
+	pub skip_json_mode: bool,
+	pub skip_tool_calls: bool,
+
+	/// When true, writes the probed streaming, json mode, and tool calls results as capability overrides
+	/// (which then win over the model name heuristics, e.g., for `Model` capabilities of `all_models`).
+	/// Only written when the chat probe succeeded, so auth or network failures are not recorded as unsupported.
+	pub write_override: bool,
+}
+
+impl ProbeOptions {
+	pub fn with_skip_chat(mut self, value: bool) -> Self {
+		self.skip_chat = value;
+		self
+	}
+
+	pub fn with_skip_streaming(mut self, value: bool) -> Self {
+		self.skip_streaming = value;
+		self
+	}
+
+	pub fn with_skip_json_mode(mut self, value: bool) -> Self {
+		self.skip_json_mode = value;
+		self
+	}
+
+	pub fn with_skip_tool_calls(mut self, value: bool) -> Self {
+		self.skip_tool_calls = value;
+		self
+	}
+
+	pub fn with_write_override(mut self, value: bool) -> Self {
+		self.write_override = value;
+		self
+	}
+}
+
+// endregion: --- ProbeOptions
+
+// region:    --- ProbedCaps
+
+/// The result of one probe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProbeResult {
+	/// The probe request succeeded.
+	Supported,
+	/// The probe request failed, with the error message.
+	Failed(String),
+	/// The probe was skipped (see `ProbeOptions`).
+	Skipped,
+}
+
+impl ProbeResult {
+	pub fn is_supported(&self) -> bool {
+		matches!(self, ProbeResult::Supported)
+	}
+
+	/// Returns `Some(bool)` for a probe that ran, `None` if skipped.
+	pub fn as_bool(&self) -> Option<bool> {
+		match self {
+			ProbeResult::Supported => Some(true),
+			ProbeResult::Failed(_) => Some(false),
+			ProbeResult::Skipped => None,
+		}
+	}
+}
+
+/// Capabilities probed with real requests by `Client::probe_capabilities`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProbedCaps {
+	pub model_iden: ModelIden,
+	/// 1 token chat request.
+	pub chat: ProbeResult,
+	/// Stream opened and read to its end.
+	pub streaming: ProbeResult,
+	/// Chat request with `ChatResponseFormat::JsonMode`.
+	pub json_mode: ProbeResult,
+	/// Chat request with a no-op tool declared.
+	pub tool_calls: ProbeResult,
+}
+
+// endregion: --- ProbedCaps
+
+// region:    --- Client Probe
+
+const PROBE_PROMPT: &str = "Reply with OK.";
+const PROBE_JSON_PROMPT: &str = r#"Reply with the JSON object {"ok": true}."#;
+
+impl Client {
+	/// Probes the model capabilities by issuing a few tiny, real requests (chat, stream, JSON mode, tool declaration),
+	/// and reports which ones succeeded.
+	///
+	/// IMPORTANT: This costs tokens (one small request per probe). Probes can be skipped with `ProbeOptions`.
+	pub async fn probe_capabilities(
+		&self,
+		model: impl Into<ModelSpec>,
+		options: Option<&ProbeOptions>,
+	) -> Result<ProbedCaps> {
+		let default_options = ProbeOptions::default();
+		let options = options.unwrap_or(&default_options);
+
+		// Resolved once, so all the probes use the same target (mapped model, auth, endpoint).
+		let target = self.config().resolve_model_spec(model.into()).await?;
+		let model_iden = target.model.clone();
+		let model_spec = || ModelSpec::Target(target.clone());
+
+		// -- Chat (1 token)
+		let chat = if options.skip_chat {
+			ProbeResult::Skipped
+		} else {
+			let chat_options = ChatOptions::default().with_max_tokens(1);
+			let res = self
+				.exec_chat(model_spec(), ChatRequest::from_user(PROBE_PROMPT), Some(&chat_options))
+				.await;
+			into_probe_result(res.map(|_| ()))
+		};
+
+		// -- Streaming (open, then read to the end)
+		let streaming = if options.skip_streaming {
+			ProbeResult::Skipped
+		} else {
+			let chat_options = ChatOptions::default().with_max_tokens(1);
+			let res = self
+				.exec_chat_stream(model_spec(), ChatRequest::from_user(PROBE_PROMPT), Some(&chat_options))
+				.await;
+			let res = match res {
+				Ok(stream_res) => drain_chat_stream(stream_res.stream).await,
+				Err(err) => Err(err),
+			};
+			into_probe_result(res)
+		};
+
+		// -- JSON mode
+		let json_mode = if options.skip_json_mode {
+			ProbeResult::Skipped
+		} else {
+			let chat_options = ChatOptions::default()
+				.with_max_tokens(16)
+				.with_response_format(ChatResponseFormat::JsonMode);
+			let res = self
+				.exec_chat(
+					model_spec(),
+					ChatRequest::from_user(PROBE_JSON_PROMPT),
+					Some(&chat_options),
+				)
+				.await;
+			into_probe_result(res.map(|_| ()))
+		};
+
+		// -- Tool calls (no-op tool declaration)
+		let tool_calls = if options.skip_tool_calls {
+			ProbeResult::Skipped
+		} else {
+			let chat_options = ChatOptions::default().with_max_tokens(16);
+			let noop_tool = Tool::new("noop")
+				.with_description("Does nothing. Do not call it.")
+				.with_schema(json!({"type": "object", "properties": {}}));
+			let chat_req = ChatRequest::from_user(PROBE_PROMPT).append_tool(noop_tool);
+			let res = self.exec_chat(model_spec(), chat_req, Some(&chat_options)).await;
+			into_probe_result(res.map(|_| ()))
+		};
+
+		let probed_caps = ProbedCaps {
+			model_iden,
+			chat,
+			streaming,
+			json_mode,
+			tool_calls,
+		};
+
+		// -- Write the capability override
+		if options.write_override && probed_caps.chat.is_supported() {
+			let adapter_kind = probed_caps.model_iden.adapter_kind;
+			let model_name = probed_caps.model_iden.model_name.as_str();
+			// The image input is not probed, so the one set by `set_image_input_override` is kept
+			let image_input = ModelCapabilities::get_override(adapter_kind, model_name).and_then(|o| o.image_input);
+			ModelCapabilities::set_override(
+				adapter_kind,
+				model_name,
+				CapabilityOverride {
+					streaming: probed_caps.streaming.as_bool(),
+					tool_calls: probed_caps.tool_calls.as_bool(),
+					json_mode: probed_caps.json_mode.as_bool(),
+					image_input,
+				},
+			);
+		}
+
+		Ok(probed_caps)
+	}
+
+	/// Sets the image input override of the model (`None` to go back to the model id heuristics),
+	/// e.g., for an open-weights vision model the heuristics miss (`Model::supported_input_modalities` of `all_models`).
+	///
+	/// Process-wide (as the `probe_capabilities` overrides, which keep it).
+	pub fn set_image_input_override(&self, model_iden: &ModelIden, image_input: Option<bool>) {
+		ModelCapabilities::set_image_input_override(
+			model_iden.adapter_kind,
+			model_iden.model_name.as_str(),
+			image_input,
+		);
+	}
+}
+
+// endregion: --- Client Probe
+
+// region:    --- Support
+
+fn into_probe_result(res: Result<()>) -> ProbeResult {
+	match res {
+		Ok(()) => ProbeResult::Supported,
+		Err(err) => ProbeResult::Failed(err.to_string()),
+	}
+}
+
+/// Reads the stream to its end (an interrupted stream returns its error after the incomplete `End` event).
+async fn drain_chat_stream(mut stream: crate::chat::ChatStream) -> Result<()> {
+	while let Some(event) = stream.next().await {
+		event?;
+	}
+	Ok(())
+}
+
+// endregion: --- Support
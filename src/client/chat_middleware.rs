@@ -0,0 +1,163 @@
+//! Chat middlewares - `ClientBuilder::with_chat_middleware(..)`.
+//!
+//! A `ChatMiddleware` wraps each chat request sent by `Client::exec_chat` (`around_request`) and
+//! `Client::exec_chat_stream` (`around_stream`). It receives the request and the next of the chain
+//! (`ChatNext` / `ChatStreamNext`), so it can change the request, inspect or change the response,
+//! or answer without calling the provider.
+//!
+//! The middlewares are composed in the order they were added (the first one added is the outermost), and the
+//! last `next` sends the request to the provider.
+//!
+//! NOTE: Each provider call goes through the chain (auto-continuation and `OnEmpty::Retry` requests included).
+
+use crate::chat::{ChatOptions, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::{Client, Result, ServiceTarget};
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+// region:    --- ChatMiddleware
+
+/// A layer around the chat requests of a `Client` (logging, request mutation, system prompt injection, redaction, ...).
+///
+/// Implementations call `next.run(chat_req)` to continue the chain (usually once), e.g.:
+///
+/// ```ignore
+/// impl ChatMiddleware for SystemInjector {
+///     fn around_request<'a>(&'a self, chat_req: ChatRequest, next: ChatNext<'a>) -> BoxFuture<'a, Result<ChatResponse>> {
+///         Box::pin(async move { next.run(chat_req.with_system("Answer in French.")).await })
+///     }
+/// }
+/// ```
+///
+/// IMPORTANT: `around_stream` passes the `exec_chat_stream` requests through unchanged by default,
+///            so a middleware changing the requests (e.g., redaction) must also implement it.
+pub trait ChatMiddleware: Send + Sync {
+	/// Handles the chat request, calling `next.run(..)` to send it to the rest of the chain.
+	fn around_request<'a>(&'a self, chat_req: ChatRequest, next: ChatNext<'a>) -> BoxFuture<'a, Result<ChatResponse>>;
+
+	/// Handles the chat stream request, calling `next.run(..)` to send it to the rest of the chain.
+	///
+	/// Default: the request is passed to the next unchanged.
+	fn around_stream<'a>(
+		&'a self,
+		chat_req: ChatRequest,
+		next: ChatStreamNext<'a>,
+	) -> BoxFuture<'a, Result<ChatStreamResponse>> {
+		next.run(chat_req)
+	}
+}
+
+impl std::fmt::Debug for dyn ChatMiddleware {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "ChatMiddleware")
+	}
+}
+
+// endregion: --- ChatMiddleware
+
+// region:    --- ChatNext
+
+/// The rest of the middleware chain, given to `ChatMiddleware::around_request`.
+pub struct ChatNext<'a> {
+	client: &'a Client,
+	target: &'a ServiceTarget,
+	options: Option<&'a ChatOptions>,
+	middlewares: &'a [Arc<dyn ChatMiddleware>],
+}
+
+impl<'a> ChatNext<'a> {
+	pub(super) fn new(
+		client: &'a Client,
+		target: &'a ServiceTarget,
+		options: Option<&'a ChatOptions>,
+		middlewares: &'a [Arc<dyn ChatMiddleware>],
+	) -> Self {
+		Self {
+			client,
+			target,
+			options,
+			middlewares,
+		}
+	}
+
+	/// The resolved service target of the request (model, endpoint, and auth).
+	pub fn target(&self) -> &ServiceTarget {
+		self.target
+	}
+
+	/// The chat options of the request (without the client default ones).
+	pub fn options(&self) -> Option<&ChatOptions> {
+		self.options
+	}
+
+	/// Runs the next middleware, or sends the request to the provider when this is the end of the chain.
+	pub fn run(self, chat_req: ChatRequest) -> BoxFuture<'a, Result<ChatResponse>> {
+		match self.middlewares.split_first() {
+			Some((middleware, rest)) => {
+				let next = ChatNext {
+					middlewares: rest,
+					..self
+				};
+				middleware.around_request(chat_req, next)
+			}
+			None => Box::pin(self.client.exec_chat_send(self.target.clone().into(), chat_req, self.options)),
+		}
+	}
+}
+
+// endregion: --- ChatNext
+
+// region:    --- ChatStreamNext
+
+/// The rest of the middleware chain, given to `ChatMiddleware::around_stream`.
+pub struct ChatStreamNext<'a> {
+	client: &'a Client,
+	target: &'a ServiceTarget,
+	options: Option<&'a ChatOptions>,
+	middlewares: &'a [Arc<dyn ChatMiddleware>],
+}
+
+impl<'a> ChatStreamNext<'a> {
+	pub(super) fn new(
+		client: &'a Client,
+		target: &'a ServiceTarget,
+		options: Option<&'a ChatOptions>,
+		middlewares: &'a [Arc<dyn ChatMiddleware>],
+	) -> Self {
+		Self {
+			client,
+			target,
+			options,
+			middlewares,
+		}
+	}
+
+	/// The resolved service target of the request (model, endpoint, and auth).
+	pub fn target(&self) -> &ServiceTarget {
+		self.target
+	}
+
+	/// The chat options of the request (without the client default ones).
+	pub fn options(&self) -> Option<&ChatOptions> {
+		self.options
+	}
+
+	/// Runs the next middleware, or sends the stream request to the provider when this is the end of the chain.
+	pub fn run(self, chat_req: ChatRequest) -> BoxFuture<'a, Result<ChatStreamResponse>> {
+		match self.middlewares.split_first() {
+			Some((middleware, rest)) => {
+				let next = ChatStreamNext {
+					middlewares: rest,
+					..self
+				};
+				middleware.around_stream(chat_req, next)
+			}
+			None => Box::pin(
+				self.client
+					.exec_chat_stream_send(self.target.clone().into(), chat_req, self.options),
+			),
+		}
+	}
+}
+
+// endregion: --- ChatStreamNext
@@ -1,9 +1,24 @@
-use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
-use crate::chat::{ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
-use crate::client::ModelSpec;
-use crate::embed::{EmbedOptions, EmbedOptionsSet, EmbedRequest, EmbedResponse};
+#[cfg(feature = "mock")]
+use crate::adapter::MockAdapter;
+use crate::adapter::{
+	AdapterDispatcher, AdapterKind, ModelCapabilities, ServiceType, WebRequestData, validate_payload,
+};
+use crate::chat::{
+	ChatMessage, ChatMetrics, ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStreamResponse,
+	DEFAULT_MAX_RESPONSE_BYTES, IgnoredOption, OnEmpty, OnEmptyConversation,
+};
+use crate::client::{ChatNext, ChatStreamNext, ModelSpec};
+use crate::client::{rate_limit, request_estimate};
+use crate::embed::{
+	BatchControl, BatchProgress, DEFAULT_EMBED_BATCH_CHUNK_SIZE, EmbedInput, EmbedOptions, EmbedOptionsSet,
+	EmbedRequest, EmbedResponse, EmbedTruncate, run_embed_batch,
+};
+use crate::redaction::redact_json;
 use crate::resolver::AuthData;
+use crate::support;
+use crate::webc;
 use crate::{Client, Error, Model, ModelIden, Result, ServiceTarget};
+use tokio::time::Instant;
 
 /// High-level client APIs.
 impl Client {
@@ -78,16 +93,97 @@ impl Client {
 		let options_set = ChatOptionsSet::default()
 			.with_chat_options(options)
 			.with_client_options(self.config().chat_options());
+		let max_continuations = options_set.auto_continue().unwrap_or_default();
 
-		let target = self.config().resolve_model_spec(model.into()).await?;
+		match options_set.on_empty() {
+			None | Some(OnEmpty::Allow) => {
+				self.exec_chat_continued(model.into(), chat_req, options, max_continuations)
+					.await
+			}
+			Some(on_empty) => {
+				self.exec_chat_on_empty(model.into(), chat_req, options, on_empty.clone(), max_continuations)
+					.await
+			}
+		}
+	}
+
+	/// Sends a chat request, with the continuation requests when `max_continuations > 0`.
+	pub(super) async fn exec_chat_continued(
+		&self,
+		model: ModelSpec,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		max_continuations: u32,
+	) -> Result<ChatResponse> {
+		if max_continuations > 0 {
+			self.exec_chat_auto_continue(model, chat_req, options, max_continuations).await
+		} else {
+			self.exec_chat_once(model, chat_req, options).await
+		}
+	}
+
+	/// Sends a chat request and returns the full response (a single call, no continuation),
+	/// through the chat middlewares if any.
+	pub(super) async fn exec_chat_once(
+		&self,
+		model: ModelSpec,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let middlewares = self.config().chat_middlewares();
+		if middlewares.is_empty() {
+			return self.exec_chat_send(model, chat_req, options).await;
+		}
+
+		let target = self.config().resolve_model_spec(model).await?;
+		ChatNext::new(self, &target, options, middlewares).run(chat_req).await
+	}
+
+	/// Sends a chat request to the provider (the end of the chat middleware chain).
+	pub(super) async fn exec_chat_send(
+		&self,
+		model: ModelSpec,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options());
+
+		let target = self.config().resolve_model_spec(model).await?;
 		let model = target.model.clone();
 		let auth_data = target.auth.clone();
+		let rate_limit_key = rate_limit::auth_key_id(&auth_data);
+
+		let capped_options = capped_max_tokens_options(&model, &options_set, options);
+		let options_set = match capped_options.as_ref() {
+			Some(capped_options) => options_set.with_chat_options(Some(capped_options)),
+			None => options_set,
+		};
+
+		if self.config().validate_payloads() {
+			validate_image_count(&model, &chat_req)?;
+		}
+		let chat_req = normalize_empty_conversation(&model, &options_set, chat_req)?;
+
+		// The mock requests go through the same validation and rate limiting, without the web call
+		#[cfg(feature = "mock")]
+		let mock_chat_req = (model.adapter_kind == AdapterKind::Mock).then(|| chat_req.clone());
 
 		let WebRequestData {
 			mut url,
 			mut headers,
-			payload,
+			mut payload,
+			ignored_options,
 		} = AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set.clone())?;
+		trace_ignored_options(&model, &ignored_options);
+
+		let extra_body_overrides = merge_extra_body(&model, &options_set, &mut payload);
+
+		if self.config().validate_payloads() {
+			validate_payload(&model, ServiceType::Chat, &payload)?;
+		}
+		request_estimate::validate_request_bytes(&model, &options_set, &payload)?;
 
 		if let Some(extra_headers) = options.and_then(|o| o.extra_headers.as_ref()) {
 			headers.merge_with(extra_headers);
@@ -102,21 +198,69 @@ impl Client {
 			headers = override_headers;
 		};
 
+		if let Some(extra_query_params) = options_set.extra_query_params() {
+			url = support::url_with_extra_query_params(&url, extra_query_params)?;
+		}
+
+		if let Some(token_budget) = self.token_budget() {
+			token_budget.check(&model)?;
+		}
+
+		self.wait_for_rate_limit(&model, rate_limit_key.as_deref()).await;
+
+		#[cfg(feature = "mock")]
+		if let Some(chat_req) = mock_chat_req {
+			return exec_mock_chat(self, &model, chat_req);
+		}
+
+		let max_response_bytes = options_set.max_response_bytes().unwrap_or(DEFAULT_MAX_RESPONSE_BYTES);
+		let started_at = Instant::now();
 		let web_res = self
 			.web_client()
-			.do_post(&url, &headers, &payload)
-			.await
-			.map_err(|webc_error| Error::WebModelCall {
+			.do_post_limited(&url, &headers, &payload, max_response_bytes)
+			.await;
+		let ended_at = Instant::now();
+		self.record_rate_limit(&model, rate_limit_key, &web_res);
+		let web_res = web_res.map_err(|webc_error| match webc_error {
+			webc::Error::ResponseTooLarge {
+				max_bytes,
+				partial_body,
+			} => Error::ResponseTooLarge {
+				model_iden: model.clone(),
+				max_bytes,
+				partial: partial_body,
+			},
+			webc_error => Error::WebModelCall {
 				model_iden: model.clone(),
 				webc_error,
-			})?;
+			},
+		})?;
 
-		// Note: here we capture/clone the raw body if set in the options_set
-		let captured_raw_body = options_set.capture_raw_body().unwrap_or_default().then(|| web_res.body.clone());
+		// Note: here we capture/clone the raw body if set in the options_set (redacted per the client redaction mode)
+		let captured_raw_body = options_set
+			.capture_raw_body()
+			.unwrap_or_default()
+			.then(|| redact_json(&web_res.body, self.config().redaction_mode()));
 
+		let normalize_json_output = options_set.normalize_json_output();
 		match AdapterDispatcher::to_chat_response(model.clone(), web_res, options_set) {
 			Ok(mut chat_res) => {
+				if normalize_json_output {
+					support::strip_code_fences_in_texts(&mut chat_res.content);
+				}
 				chat_res.captured_raw_body = captured_raw_body;
+				chat_res.extra_body_overrides = extra_body_overrides;
+				chat_res.ignored_options = ignored_options;
+				if let Some(token_budget) = self.token_budget() {
+					token_budget.record(&chat_res.usage);
+				}
+				chat_res.metrics = Some(ChatMetrics::from_timing(
+					started_at,
+					None,
+					ended_at,
+					chat_res.usage.completion_tokens,
+				));
+				trace_provider_metadata(&model, &chat_res);
 				Ok(chat_res)
 			}
 			Err(err) => {
@@ -134,31 +278,128 @@ impl Client {
 		}
 	}
 
+	/// Sends a chat request and returns the full response with the raw provider JSON body
+	/// (e.g., for debugging a new provider), whatever the `capture_raw_body` option.
+	///
+	/// NOTE: As for `captured_raw_body`, the raw body is redacted per the client redaction mode.
+	pub async fn exec_chat_with_raw(
+		&self,
+		model: impl Into<ModelSpec>,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<(ChatResponse, serde_json::Value)> {
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options());
+		let keep_captured_raw_body = options_set.capture_raw_body().unwrap_or_default();
+
+		let raw_options = options.cloned().unwrap_or_default().with_capture_raw_body(true);
+		let mut chat_res = self.exec_chat(model, chat_req, Some(&raw_options)).await?;
+
+		let raw_body = if keep_captured_raw_body {
+			chat_res.captured_raw_body.clone()
+		} else {
+			chat_res.captured_raw_body.take()
+		};
+		let raw_body = raw_body.ok_or_else(|| Error::Internal("Raw response body was not captured".to_string()))?;
+
+		Ok((chat_res, raw_body))
+	}
+
+	/// Builds the provider request payload of a chat request, without sending it
+	/// (e.g., for inspection or benchmarks).
+	///
+	/// The `target` is used as is (no model mapping or auth resolution, see `resolve_service_target`).
+	/// The client chat options apply as for `exec_chat`.
+	pub fn build_chat_payload(
+		&self,
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<serde_json::Value> {
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options());
+
+		let model = target.model.clone();
+		let WebRequestData { mut payload, .. } =
+			AdapterDispatcher::to_web_request_data(target, service_type, chat_req, options_set.clone())?;
+		merge_extra_body(&model, &options_set, &mut payload);
+
+		Ok(payload)
+	}
+
 	/// Streams a chat response.
 	///
 	/// Accepts any type that implements `Into<ModelSpec>`:
 	/// - `&str` or `String`: Model name with full inference
 	/// - `ModelIden`: Explicit adapter, resolves auth/endpoint
 	/// - `ServiceTarget`: Uses directly, bypasses model mapping and auth resolution
+	///
+	/// The request goes through the chat middlewares if any (see `ChatMiddleware::around_stream`).
 	pub async fn exec_chat_stream(
 		&self,
 		model: impl Into<ModelSpec>,
 		chat_req: ChatRequest,
 		options: Option<&ChatOptions>,
+	) -> Result<ChatStreamResponse> {
+		let model = model.into();
+		let middlewares = self.config().chat_middlewares();
+		if middlewares.is_empty() {
+			return self.exec_chat_stream_send(model, chat_req, options).await;
+		}
+
+		let target = self.config().resolve_model_spec(model).await?;
+		ChatStreamNext::new(self, &target, options, middlewares).run(chat_req).await
+	}
+
+	/// Sends a chat stream request to the provider (the end of the chat middleware chain).
+	pub(super) async fn exec_chat_stream_send(
+		&self,
+		model: ModelSpec,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
 	) -> Result<ChatStreamResponse> {
 		let options_set = ChatOptionsSet::default()
 			.with_chat_options(options)
 			.with_client_options(self.config().chat_options());
 
-		let target = self.config().resolve_model_spec(model.into()).await?;
+		let target = self.config().resolve_model_spec(model).await?;
 		let model = target.model.clone();
 		let auth_data = target.auth.clone();
+		let rate_limit_key = rate_limit::auth_key_id(&auth_data);
+		let options_set = options_set.with_stream_transport(target.endpoint.stream_transport());
+
+		let capped_options = capped_max_tokens_options(&model, &options_set, options);
+		let options_set = match capped_options.as_ref() {
+			Some(capped_options) => options_set.with_chat_options(Some(capped_options)),
+			None => options_set,
+		};
+
+		if self.config().validate_payloads() {
+			validate_image_count(&model, &chat_req)?;
+		}
+		let chat_req = normalize_empty_conversation(&model, &options_set, chat_req)?;
+
+		// The mock requests go through the same validation and rate limiting, without the web call
+		#[cfg(feature = "mock")]
+		let mock_chat_req = (model.adapter_kind == AdapterKind::Mock).then(|| chat_req.clone());
 
 		let WebRequestData {
 			mut url,
 			mut headers,
-			payload,
+			mut payload,
+			ignored_options,
 		} = AdapterDispatcher::to_web_request_data(target, ServiceType::ChatStream, chat_req, options_set.clone())?;
+		trace_ignored_options(&model, &ignored_options);
+
+		let extra_body_overrides = merge_extra_body(&model, &options_set, &mut payload);
+
+		if self.config().validate_payloads() {
+			validate_payload(&model, ServiceType::ChatStream, &payload)?;
+		}
+		request_estimate::validate_request_bytes(&model, &options_set, &payload)?;
 
 		if let Some(extra_headers) = options.and_then(|o| o.extra_headers.as_ref()) {
 			headers.merge_with(extra_headers);
@@ -176,6 +417,22 @@ impl Client {
 			headers = override_headers;
 		};
 
+		if let Some(extra_query_params) = options_set.extra_query_params() {
+			url = support::url_with_extra_query_params(&url, extra_query_params)?;
+		}
+
+		if let Some(token_budget) = self.token_budget() {
+			token_budget.check(&model)?;
+		}
+
+		self.wait_for_rate_limit(&model, rate_limit_key.as_deref()).await;
+
+		#[cfg(feature = "mock")]
+		if let Some(chat_req) = mock_chat_req {
+			let chat_res = exec_mock_chat(self, &model, chat_req)?;
+			return Ok(MockAdapter::into_chat_stream_response(chat_res, options_set));
+		}
+
 		let reqwest_builder = self
 			.web_client()
 			.new_req_builder(&url, &headers, &payload)
@@ -184,7 +441,16 @@ impl Client {
 				webc_error,
 			})?;
 
-		let res = AdapterDispatcher::to_chat_stream(model, reqwest_builder, options_set)?;
+		let normalize_json_output = options_set.normalize_json_output();
+		let max_response_bytes = options_set.max_response_bytes().unwrap_or(DEFAULT_MAX_RESPONSE_BYTES);
+		let mut res = AdapterDispatcher::to_chat_stream(model.clone(), reqwest_builder, options_set)?;
+		res.stream.set_normalize_json_output(normalize_json_output);
+		res.stream.set_max_response_bytes(model, max_response_bytes);
+		res.extra_body_overrides = extra_body_overrides;
+		res.ignored_options = ignored_options;
+		if let Some(token_budget) = self.token_budget() {
+			res.stream.set_token_budget(token_budget.clone());
+		}
 
 		Ok(res)
 	}
@@ -215,6 +481,37 @@ impl Client {
 		self.exec_embed(model, embed_req, options).await
 	}
 
+	/// Creates embeddings for many inputs, in chunks, reporting progress after each chunk.
+	///
+	/// - Chunks (see `EmbedOptions::with_batch_chunk_size`) are sent one after the other,
+	///   so progress events are emitted in input order, each input index being reported exactly once.
+	/// - `on_progress` can return `BatchControl::Cancel` to stop before the next chunk
+	///   (the embeddings of the completed chunks are still returned).
+	pub async fn exec_embed_batch_with_progress<C>(
+		&self,
+		model: impl Into<ModelSpec>,
+		inputs: Vec<String>,
+		options: Option<&EmbedOptions>,
+		on_progress: impl FnMut(BatchProgress) -> C,
+	) -> Result<EmbedResponse>
+	where
+		C: Into<BatchControl>,
+	{
+		let chunk_size = EmbedOptionsSet::new()
+			.with_request_options(options)
+			.with_client_options(self.config().embed_options())
+			.batch_chunk_size()
+			.unwrap_or(DEFAULT_EMBED_BATCH_CHUNK_SIZE);
+
+		// Resolve once, so that each chunk targets the same service
+		let target = self.config().resolve_model_spec(model.into()).await?;
+		let model = target.model.clone();
+
+		let exec_chunk = |chunk: Vec<String>| self.exec_embed(target.clone(), EmbedRequest::new_batch(chunk), options);
+
+		run_embed_batch(model, inputs, chunk_size, exec_chunk, on_progress).await
+	}
+
 	/// Sends an embedding request and returns the response.
 	///
 	/// Accepts any type that implements `Into<ModelSpec>`:
@@ -233,9 +530,18 @@ impl Client {
 
 		let target = self.config().resolve_model_spec(model.into()).await?;
 		let model = target.model.clone();
+		let embed_req = truncate_embed_inputs(&model, embed_req, &options_set);
 
-		let WebRequestData { headers, payload, url } =
-			AdapterDispatcher::to_embed_request_data(target, embed_req, options_set.clone())?;
+		let WebRequestData {
+			headers,
+			payload,
+			mut url,
+			..
+		} = AdapterDispatcher::to_embed_request_data(target, embed_req, options_set.clone())?;
+
+		if let Some(extra_query_params) = options_set.extra_query_params() {
+			url = support::url_with_extra_query_params(&url, extra_query_params)?;
+		}
 
 		let web_res = self
 			.web_client()
@@ -246,8 +552,300 @@ impl Client {
 				webc_error,
 			})?;
 
-		let res = AdapterDispatcher::to_embed_response(model, web_res, options_set)?;
+		let mut res = AdapterDispatcher::to_embed_response(model, web_res, options_set)?;
+		if let Some(raw_body) = res.captured_raw_body.as_mut() {
+			*raw_body = redact_json(raw_body, self.config().redaction_mode());
+		}
 
 		Ok(res)
 	}
 }
+
+// region:    --- Support
+
+/// Returns the chat options with `max_tokens` clamped to the model known output limit,
+/// when `cap_max_tokens` is set and the requested value is over it (`None` when nothing changes).
+fn capped_max_tokens_options(
+	model: &ModelIden,
+	options_set: &ChatOptionsSet,
+	options: Option<&ChatOptions>,
+) -> Option<ChatOptions> {
+	if !options_set.cap_max_tokens().unwrap_or_default() {
+		return None;
+	}
+	let max_tokens = options_set.max_tokens()?;
+	let limit = ModelCapabilities::known_max_output_tokens(model.adapter_kind, &model.model_name)?;
+	if max_tokens <= limit {
+		return None;
+	}
+
+	tracing::warn!("max_tokens {max_tokens} is over the {model} output limit, capped to {limit}");
+	let capped_options = options.cloned().unwrap_or_default().with_max_tokens(limit);
+	Some(capped_options)
+}
+
+/// Returns `Error::TooManyImages` when the request has more images than the model known per-request limit.
+fn validate_image_count(model: &ModelIden, chat_req: &ChatRequest) -> Result<()> {
+	let Some(max_images) = ModelCapabilities::max_images_per_request(model.adapter_kind, &model.model_name) else {
+		return Ok(());
+	};
+	let image_count = chat_req.total_image_count();
+	if image_count > max_images {
+		return Err(Error::TooManyImages {
+			model_iden: model.clone(),
+			image_count,
+			max_images,
+		});
+	}
+	Ok(())
+}
+
+/// Applies the `on_empty_conversation` policy to a request without messages (besides the system and developer ones).
+///
+/// NOTE: A raw prompt request has no messages by design, so it is not an empty conversation.
+fn normalize_empty_conversation(
+	model: &ModelIden,
+	options_set: &ChatOptionsSet,
+	chat_req: ChatRequest,
+) -> Result<ChatRequest> {
+	if chat_req.raw_prompt.is_some()
+		|| chat_req
+			.messages
+			.iter()
+			.any(|msg| !matches!(msg.role, ChatRole::System | ChatRole::Developer))
+	{
+		return Ok(chat_req);
+	}
+	match options_set.on_empty_conversation() {
+		None | Some(OnEmptyConversation::Allow) => Ok(chat_req),
+		Some(OnEmptyConversation::Error) => Err(Error::ChatReqHasNoMessages {
+			model_iden: model.clone(),
+		}),
+		Some(OnEmptyConversation::InsertUser(text)) => Ok(chat_req.append_message(ChatMessage::user(text.clone()))),
+	}
+}
+
+/// Answers an `AdapterKind::Mock` chat request with the client `MockChatHandler` (no web call).
+#[cfg(feature = "mock")]
+fn exec_mock_chat(client: &Client, model: &ModelIden, chat_req: ChatRequest) -> Result<ChatResponse> {
+	let handler = client
+		.config()
+		.mock_chat_handler()
+		.ok_or_else(|| Error::MockChatHandlerMissing {
+			model_iden: model.clone(),
+		})?;
+	let mut chat_res = handler.handle(chat_req);
+	chat_res.model_iden = model.clone();
+	Ok(chat_res)
+}
+
+/// Truncates the embed inputs client-side for the `EmbedTruncate` modes the provider does not support
+/// (Cohere supports all, Ollama `None` and `End`), to the model max input tokens (`EmbedOptions::max_input_tokens`,
+/// or the model known limit). Left as is when no limit is known.
+fn truncate_embed_inputs(model: &ModelIden, embed_req: EmbedRequest, options_set: &EmbedOptionsSet) -> EmbedRequest {
+	let Some(truncate) = options_set.truncate() else {
+		return embed_req;
+	};
+	let native = match model.adapter_kind {
+		AdapterKind::Cohere => true,
+		AdapterKind::Ollama | AdapterKind::OllamaCloud => !matches!(truncate, EmbedTruncate::Start),
+		_ => matches!(truncate, EmbedTruncate::None),
+	};
+	if native {
+		return embed_req;
+	}
+
+	let max_input_tokens = options_set
+		.max_input_tokens()
+		.or_else(|| ModelCapabilities::max_embed_input_tokens(model.adapter_kind, &model.model_name));
+	let Some(max_input_tokens) = max_input_tokens else {
+		tracing::debug!(
+			"{model} embed inputs not truncated, unknown max input tokens (see EmbedOptions::max_input_tokens)"
+		);
+		return embed_req;
+	};
+
+	let input = match embed_req.input {
+		EmbedInput::Single(text) => EmbedInput::Single(truncate.truncate_text(&text, max_input_tokens).into_owned()),
+		EmbedInput::Batch(texts) => EmbedInput::Batch(
+			texts
+				.iter()
+				.map(|text| truncate.truncate_text(text, max_input_tokens).into_owned())
+				.collect(),
+		),
+	};
+	EmbedRequest { input }
+}
+
+/// Traces the options the adapter could not honor (at debug level, as they are also in the response).
+fn trace_ignored_options(model: &ModelIden, ignored_options: &[IgnoredOption]) {
+	for ignored in ignored_options {
+		tracing::debug!("{model} ignored option '{}': {}", ignored.option, ignored.reason);
+	}
+}
+
+/// Traces the provider service tier and timing of the response at debug level
+/// (as the `service_tier` and `provider_timing` event fields).
+fn trace_provider_metadata(model: &ModelIden, chat_res: &ChatResponse) {
+	if chat_res.service_tier.is_none() && chat_res.provider_timing.is_none() {
+		return;
+	}
+	let service_tier = chat_res.service_tier.as_deref();
+	let provider_timing = chat_res.provider_timing.as_ref().map(|timing| timing.to_string());
+
+	tracing::debug!(
+		service_tier,
+		provider_timing = provider_timing.as_deref(),
+		"{model} response metadata"
+	);
+}
+
+/// Deep merges the `extra_body` of the options (for the model adapter kind) into the payload,
+/// and returns the JSON pointers of the payload values it replaced (traced as a warning).
+fn merge_extra_body(model: &ModelIden, options_set: &ChatOptionsSet, payload: &mut serde_json::Value) -> Vec<String> {
+	let Some(extra_body) = options_set.extra_body(model.adapter_kind) else {
+		return Vec::new();
+	};
+	let overridden = support::deep_merge_json(payload, &extra_body);
+	if !overridden.is_empty() {
+		tracing::warn!("extra_body overrides {model} payload values: {}", overridden.join(", "));
+	}
+	overridden
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chat::{ContentPart, MessageContent};
+
+	fn capped_max_tokens(model: &ModelIden, options: &ChatOptions) -> Option<u32> {
+		let options_set = ChatOptionsSet::default().with_chat_options(Some(options));
+		let capped_options = capped_max_tokens_options(model, &options_set, Some(options));
+		let options_set = match capped_options.as_ref() {
+			Some(capped_options) => options_set.with_chat_options(Some(capped_options)),
+			None => options_set,
+		};
+		options_set.max_tokens()
+	}
+
+	#[test]
+	fn test_capped_max_tokens_over_limit_clamped() {
+		let model = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let options = ChatOptions::default().with_max_tokens(100_000).with_cap_max_tokens(true);
+
+		assert_eq!(capped_max_tokens(&model, &options), Some(16_384));
+		// -- Not opted in, left as is
+		let options = ChatOptions::default().with_max_tokens(100_000);
+		assert_eq!(capped_max_tokens(&model, &options), Some(100_000));
+	}
+
+	#[test]
+	fn test_capped_max_tokens_under_limit_or_unknown_left_as_is() {
+		let options = ChatOptions::default().with_max_tokens(100_000).with_cap_max_tokens(true);
+
+		// -- No known limit for this model
+		let model = ModelIden::new(AdapterKind::OpenAI, "my-fine-tuned-model");
+		assert_eq!(capped_max_tokens(&model, &options), Some(100_000));
+
+		// -- Under the limit
+		let model = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let options = options.with_max_tokens(1_000);
+		assert_eq!(capped_max_tokens(&model, &options), Some(1_000));
+	}
+
+	#[test]
+	fn test_validate_image_count_over_limit() {
+		let images = |count: usize| {
+			let parts: Vec<ContentPart> = (0..count)
+				.map(|_| ContentPart::from_binary_url("image/png", "https://example.com/img.png", None))
+				.collect();
+			ChatMessage::user(MessageContent::from_parts(parts))
+		};
+		// 3 + 3 images across the conversation, over the Groq limit of 5
+		let chat_req = ChatRequest::new(vec![images(3), ChatMessage::assistant("Noted."), images(3)]);
+
+		let model = ModelIden::new(AdapterKind::Groq, "meta-llama/llama-4-scout-17b-16e-instruct");
+		let err = validate_image_count(&model, &chat_req).expect_err("should be over the image limit");
+		assert!(matches!(
+			err,
+			Error::TooManyImages {
+				image_count: 6,
+				max_images: 5,
+				..
+			}
+		));
+
+		// -- Under the limit, or no known limit
+		let model = ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5");
+		assert!(validate_image_count(&model, &chat_req).is_ok());
+		let model = ModelIden::new(AdapterKind::Together, "some-vision-model");
+		assert!(validate_image_count(&model, &chat_req).is_ok());
+	}
+
+	#[test]
+	fn test_normalize_empty_conversation_policies() -> Result<()> {
+		let model = ModelIden::new(AdapterKind::Anthropic, "claude-haiku-4-5");
+		let normalize = |policy: Option<OnEmptyConversation>, chat_req: ChatRequest| {
+			let options = ChatOptions {
+				on_empty_conversation: policy,
+				..Default::default()
+			};
+			let options_set = ChatOptionsSet::default().with_chat_options(Some(&options));
+			normalize_empty_conversation(&model, &options_set, chat_req)
+		};
+		let system_only = || ChatRequest::from_system("You are a haiku poet.");
+
+		// -- Allow (default) sends as is
+		assert!(normalize(None, system_only())?.messages.is_empty());
+
+		// -- Error, also with only system messages
+		let err = normalize(Some(OnEmptyConversation::Error), system_only()).expect_err("should be an error");
+		assert!(matches!(err, Error::ChatReqHasNoMessages { .. }));
+		let system_messages = ChatRequest::new(vec![ChatMessage::system("You are a haiku poet.")]);
+		assert!(normalize(Some(OnEmptyConversation::Error), system_messages).is_err());
+
+		// -- InsertUser appends the user turn
+		let chat_req = normalize(Some(OnEmptyConversation::insert_user("Begin.")), system_only())?;
+		assert_eq!(chat_req.messages.len(), 1);
+		assert_eq!(chat_req.messages[0].role, ChatRole::User);
+		assert_eq!(chat_req.messages[0].content.first_text(), Some("Begin."));
+
+		// -- A conversation is left as is
+		let chat_req = ChatRequest::from_user("Write a haiku.");
+		let chat_req = normalize(Some(OnEmptyConversation::Error), chat_req)?;
+		assert_eq!(chat_req.messages.len(), 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_truncate_embed_inputs_client_side() {
+		let long_text = "a".repeat(40);
+		let embed_req = || EmbedRequest::new_batch(vec![long_text.clone(), "short".to_string()]);
+		let options = EmbedOptions::new().with_truncate(EmbedTruncate::End).with_max_input_tokens(4);
+		let options_set = EmbedOptionsSet::new().with_request_options(Some(&options));
+
+		// -- Not supported by OpenAI, truncated client-side (4 tokens, 16 bytes)
+		let model = ModelIden::new(AdapterKind::OpenAI, "text-embedding-3-small");
+		let truncated = truncate_embed_inputs(&model, embed_req(), &options_set);
+		assert_eq!(truncated.inputs(), vec!["a".repeat(16).as_str(), "short"]);
+
+		// -- Supported by Cohere, sent as is
+		let model = ModelIden::new(AdapterKind::Cohere, "embed-english-v3.0");
+		let sent = truncate_embed_inputs(&model, embed_req(), &options_set);
+		assert_eq!(sent.inputs()[0].len(), 40);
+
+		// -- Known model limit (8191 tokens), the input fits
+		let options = EmbedOptions::new().with_truncate(EmbedTruncate::Start);
+		let options_set = EmbedOptionsSet::new().with_request_options(Some(&options));
+		let model = ModelIden::new(AdapterKind::OpenAI, "text-embedding-3-small");
+		let sent = truncate_embed_inputs(&model, embed_req(), &options_set);
+		assert_eq!(sent.inputs()[0].len(), 40);
+	}
+}
+
+// endregion: --- Tests
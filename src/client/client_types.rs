@@ -1,3 +1,5 @@
+use crate::client::TokenBudget;
+use crate::client::rate_limit::RateLimits;
 use crate::webc::WebClient;
 use crate::{ClientBuilder, ClientConfig};
 use std::sync::Arc;
@@ -11,6 +13,9 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub struct Client {
 	pub(super) inner: Arc<ClientInner>,
+
+	/// Set with `with_token_budget` (not part of the inner, so a budget can be scoped to some clones).
+	pub(super) token_budget: Option<TokenBudget>,
 }
 
 // region:    --- Client Constructors
@@ -31,6 +36,14 @@ impl Client {
 	pub fn builder() -> ClientBuilder {
 		ClientBuilder::default()
 	}
+
+	/// Returns this client with a cumulative token budget for its chat calls (see `TokenBudget`).
+	///
+	/// The client internals (config, connection pool) are shared, and the budget usage is shared by the clones.
+	pub fn with_token_budget(mut self, budget: TokenBudget) -> Self {
+		self.token_budget = Some(budget);
+		self
+	}
 }
 
 // endregion: --- Client Constructors
@@ -45,6 +58,14 @@ impl Client {
 	pub(crate) fn config(&self) -> &ClientConfig {
 		&self.inner.config
 	}
+
+	pub fn token_budget(&self) -> Option<&TokenBudget> {
+		self.token_budget.as_ref()
+	}
+
+	pub(crate) fn rate_limits(&self) -> &RateLimits {
+		&self.inner.rate_limits
+	}
 }
 
 // endregion: --- Client Getters
@@ -56,6 +77,8 @@ pub(super) struct ClientInner {
 	pub(super) web_client: WebClient,
 
 	pub(super) config: ClientConfig,
+
+	pub(super) rate_limits: RateLimits,
 }
 
 // endregion: --- ClientInner
@@ -1,9 +1,14 @@
-use crate::adapter::{AdapterDispatcher, AdapterKind};
+#[cfg(feature = "mock")]
+use crate::adapter::MockChatHandler;
+use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType};
 use crate::chat::ChatOptions;
-use crate::client::{ModelSpec, ServiceTarget};
+use crate::client::{ChatMiddleware, ModelSpec, ServiceTarget};
 use crate::embed::EmbedOptions;
+use crate::redaction::{self, RedactionMode};
 use crate::resolver::{AuthData, AuthResolver, Endpoint, ModelMapper, ServiceTargetResolver};
 use crate::{Error, ModelIden, Result, WebConfig};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Configuration for building and customizing a `Client`.
 #[derive(Debug, Default, Clone)]
@@ -14,6 +19,14 @@ pub struct ClientConfig {
 	pub(super) web_config: Option<WebConfig>,
 	pub(super) chat_options: Option<ChatOptions>,
 	pub(super) embed_options: Option<EmbedOptions>,
+	pub(super) validate_payloads: Option<bool>,
+	pub(super) service_paths: HashMap<ServiceType, String>,
+	pub(super) url_model_key_env: Option<String>,
+	pub(super) redaction_mode: Option<RedactionMode>,
+	pub(super) rate_limit_wait: bool,
+	pub(super) chat_middlewares: Vec<Arc<dyn ChatMiddleware>>,
+	#[cfg(feature = "mock")]
+	pub(super) mock_chat_handler: Option<Arc<dyn MockChatHandler>>,
 }
 
 /// Chainable setters related to the ClientConfig.
@@ -60,6 +73,62 @@ impl ClientConfig {
 		self
 	}
 
+	/// Enables (or disables) the request payload shape and image count validation before any network call.
+	///
+	/// Default: on in debug builds, off in release builds.
+	pub fn with_validate_payloads(mut self, validate_payloads: bool) -> Self {
+		self.validate_payloads = Some(validate_payloads);
+		self
+	}
+
+	/// Overrides the url path (relative to the endpoint base url) used for a service type.
+	///
+	/// e.g., `.with_service_path(ServiceType::Chat, "v2/chat")` for a proxy not serving `chat/completions`.
+	///
+	/// Applied to the resolved endpoint unless this one already defines a path for this service type.
+	pub fn with_service_path(mut self, service_type: ServiceType, path: impl Into<String>) -> Self {
+		self.service_paths.insert(service_type, path.into());
+		self
+	}
+
+	/// Sets the env var name holding the API key for the `url::<base_url>::<model_name>` model names.
+	///
+	/// Only used when the AuthResolver (if any) does not return an AuthData for this model.
+	pub fn with_url_model_key_env(mut self, env_name: impl Into<String>) -> Self {
+		self.url_model_key_env = Some(env_name.into());
+		self
+	}
+
+	/// Sets the redaction mode of the `captured_raw_body` of the responses
+	/// (overrides the global `genai::redaction` mode for this client, e.g., `RedactionMode::Off` to keep them as is).
+	pub fn with_redaction_mode(mut self, mode: RedactionMode) -> Self {
+		self.redaction_mode = Some(mode);
+		self
+	}
+
+	/// Delays the chat requests while the last rate limit headers of the provider API key say the requests
+	/// or tokens are exhausted (until their reset), instead of sending them to get a 429 (default: false).
+	///
+	/// See `Client::rate_limit_state`.
+	pub fn with_rate_limit_wait(mut self, rate_limit_wait: bool) -> Self {
+		self.rate_limit_wait = rate_limit_wait;
+		self
+	}
+
+	/// Adds a ChatMiddleware around the `exec_chat` requests, after the ones already added
+	/// (the first one added is the outermost).
+	pub fn with_chat_middleware(mut self, middleware: impl ChatMiddleware + 'static) -> Self {
+		self.chat_middlewares.push(Arc::new(middleware));
+		self
+	}
+
+	/// Sets the MockChatHandler answering the `AdapterKind::Mock` chat requests (`mock` feature).
+	#[cfg(feature = "mock")]
+	pub fn with_mock_chat_handler(mut self, handler: impl MockChatHandler + 'static) -> Self {
+		self.mock_chat_handler = Some(Arc::new(handler));
+		self
+	}
+
 	/// Returns the WebConfig, if set.
 	pub fn web_config(&self) -> Option<&WebConfig> {
 		self.web_config.as_ref()
@@ -92,6 +161,42 @@ impl ClientConfig {
 	pub fn embed_options(&self) -> Option<&EmbedOptions> {
 		self.embed_options.as_ref()
 	}
+
+	/// Returns true if the request payloads should be validated (when not set, true in debug builds only).
+	pub fn validate_payloads(&self) -> bool {
+		self.validate_payloads.unwrap_or(cfg!(debug_assertions))
+	}
+
+	/// Returns the env var name for the `url::` model names API key, if set.
+	pub fn url_model_key_env(&self) -> Option<&str> {
+		self.url_model_key_env.as_deref()
+	}
+
+	/// Returns true if the chat requests wait for the rate limit resets.
+	pub fn rate_limit_wait(&self) -> bool {
+		self.rate_limit_wait
+	}
+
+	/// Returns the ChatMiddlewares, in the order they run.
+	pub fn chat_middlewares(&self) -> &[Arc<dyn ChatMiddleware>] {
+		&self.chat_middlewares
+	}
+
+	/// Returns the MockChatHandler, if set (`mock` feature).
+	#[cfg(feature = "mock")]
+	pub fn mock_chat_handler(&self) -> Option<&Arc<dyn MockChatHandler>> {
+		self.mock_chat_handler.as_ref()
+	}
+
+	/// Returns the redaction mode for the captured raw bodies (the client one, or the global one if not set).
+	pub fn redaction_mode(&self) -> RedactionMode {
+		self.redaction_mode.unwrap_or_else(redaction::redaction_mode)
+	}
+
+	/// Returns the url path override for the service type, if set.
+	pub fn service_path(&self, service_type: ServiceType) -> Option<&str> {
+		self.service_paths.get(&service_type).map(|path| path.as_str())
+	}
 }
 
 /// Resolvers
@@ -99,10 +204,16 @@ impl ClientConfig {
 	/// Resolves auth and endpoint for the given adapter kind.
 	///
 	/// Used by `Client::all_model_names()` where no specific model name is available.
+	/// The ServiceTargetResolver (if any) is applied with an empty model name, so custom endpoints are honored.
 	pub(crate) async fn resolve_adapter_config(&self, adapter_kind: AdapterKind) -> Result<(AuthData, Endpoint)> {
 		let model = ModelIden::new(adapter_kind, "");
-		let auth = self.run_auth_resolver(model).await?;
+		let auth = self.run_auth_resolver(model.clone()).await?;
 		let endpoint = AdapterDispatcher::default_endpoint(adapter_kind);
+
+		let ServiceTarget { auth, endpoint, .. } = self
+			.run_service_target_resolver(ServiceTarget { model, auth, endpoint })
+			.await?;
+
 		Ok((auth, endpoint))
 	}
 
@@ -148,31 +259,30 @@ impl ClientConfig {
 		})
 	}
 
-	/// Resolves a [`ModelIden`] to an [`AuthData`] via the [`AuthResolver`] (if any).
+	/// Resolves a [`ModelIden`] to an [`AuthData`] via the [`AuthResolver`] (if any),
+	/// then applies the adapter specific auth step (e.g., token exchange).
 	async fn run_auth_resolver(&self, model: ModelIden) -> Result<AuthData> {
-		match self.auth_resolver() {
-			Some(auth_resolver) => {
-				let auth_data = auth_resolver
-					.resolve(model.clone())
-					.await
-					.map_err(|err| Error::Resolver {
-						model_iden: model.clone(),
-						resolver_error: err,
-					})?
-					// default the resolver resolves to nothing
-					.unwrap_or_else(|| AdapterDispatcher::default_auth(model.adapter_kind));
+		let auth_data = match self.auth_resolver() {
+			Some(auth_resolver) => auth_resolver
+				.resolve(model.clone())
+				.await
+				.map_err(|err| Error::Resolver {
+					model_iden: model.clone(),
+					resolver_error: err,
+				})?
+				// default the resolver resolves to nothing
+				.unwrap_or_else(|| AdapterDispatcher::default_auth(model.adapter_kind)),
+			None => AdapterDispatcher::default_auth(model.adapter_kind),
+		};
 
-				Ok(auth_data)
-			}
-			None => Ok(AdapterDispatcher::default_auth(model.adapter_kind)),
-		}
+		AdapterDispatcher::resolve_auth(&model, auth_data).await
 	}
 
 	/// Resolves a [`ServiceTarget`] via the [`ServiceTargetResolver`] (if any).
 	async fn run_service_target_resolver(&self, service_target: ServiceTarget) -> Result<ServiceTarget> {
 		let model = service_target.model.clone();
 
-		match self.service_target_resolver() {
+		let mut service_target = match self.service_target_resolver() {
 			Some(service_target_resolver) => {
 				service_target_resolver
 					.resolve(service_target)
@@ -180,10 +290,23 @@ impl ClientConfig {
 					.map_err(|resolver_error| Error::Resolver {
 						model_iden: model,
 						resolver_error,
-					})
+					})?
+			}
+			None => service_target,
+		};
+		service_target.endpoint = self.apply_service_paths(service_target.endpoint);
+
+		Ok(service_target)
+	}
+
+	/// Adds the client service path overrides to the endpoint (the ones already set on the endpoint win).
+	fn apply_service_paths(&self, mut endpoint: Endpoint) -> Endpoint {
+		for (service_type, path) in self.service_paths.iter() {
+			if endpoint.service_path(*service_type).is_none() {
+				endpoint = endpoint.with_service_path(*service_type, path.clone());
 			}
-			None => Ok(service_target),
 		}
+		endpoint
 	}
 
 	/// Resolves a [`ModelSpec`] to a [`ServiceTarget`].
@@ -198,6 +321,9 @@ impl ClientConfig {
 	/// - [`ModelSpec::Target`]: Returns the target directly, running only the service target resolver.
 	pub async fn resolve_model_spec(&self, spec: ModelSpec) -> Result<ServiceTarget> {
 		match spec {
+			ModelSpec::Name(name) if ModelSpec::parse_url_model_name(&name).is_some() => {
+				self.resolve_url_model_name(&name).await
+			}
 			ModelSpec::Name(name) => {
 				let adapter_kind = AdapterKind::from_model(&name)?;
 				let model = ModelIden::new(adapter_kind, name);
@@ -208,6 +334,44 @@ impl ClientConfig {
 		}
 	}
 
+	/// Resolves a `url::<base_url>::<model_name>` model name to a [`ServiceTarget`] (OpenAI-compatible mapping).
+	///
+	/// Skips the adapter inference and the ModelMapper; the auth comes from the AuthResolver (if any)
+	/// or from the `url_model_key_env` env var, and the ServiceTargetResolver is still applied.
+	async fn resolve_url_model_name(&self, name: &str) -> Result<ServiceTarget> {
+		let (base_url, model_name) = ModelSpec::parse_url_model_name(name).ok_or_else(|| {
+			Error::Internal(format!(
+				"Model name '{name}' is not a valid 'url::<base_url>::<model_name>'"
+			))
+		})?;
+		let model = ModelIden::new(AdapterKind::OpenAI, model_name);
+
+		let resolved_auth = match self.auth_resolver() {
+			Some(auth_resolver) => {
+				auth_resolver
+					.resolve(model.clone())
+					.await
+					.map_err(|resolver_error| Error::Resolver {
+						model_iden: model.clone(),
+						resolver_error,
+					})?
+			}
+			None => None,
+		};
+		let auth = resolved_auth.unwrap_or_else(|| match self.url_model_key_env() {
+			Some(env_name) => AuthData::from_env(env_name),
+			None => AuthData::None,
+		});
+
+		let service_target = ServiceTarget {
+			model,
+			auth,
+			endpoint: Endpoint::from_owned(base_url),
+		};
+
+		self.run_service_target_resolver(service_target).await
+	}
+
 	pub async fn resolve_service_target_without_model(&self, adapter_kind: AdapterKind) -> Result<ServiceTarget> {
 		let service_target = ServiceTarget {
 			model: ModelIden::new(adapter_kind, ""),
@@ -227,7 +391,86 @@ impl ClientConfig {
 			}
 			None => service_target,
 		};
+		let service_target = ServiceTarget {
+			endpoint: self.apply_service_paths(service_target.endpoint),
+			..service_target
+		};
 
 		Ok(service_target)
 	}
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::resolver::ServiceTargetResolver;
+
+	type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+	#[test]
+	fn test_validate_payloads_explicit_over_build_default() {
+		assert_eq!(ClientConfig::default().validate_payloads(), cfg!(debug_assertions));
+		assert!(ClientConfig::default().with_validate_payloads(true).validate_payloads());
+		assert!(!ClientConfig::default().with_validate_payloads(false).validate_payloads());
+	}
+
+	#[tokio::test]
+	async fn test_resolve_model_spec_url_model_name() -> TestResult<()> {
+		let config = ClientConfig::default().with_url_model_key_env("BASETEN_API_KEY");
+
+		let target = config
+			.resolve_model_spec(ModelSpec::from_name("url::http://[::1]:8000/v1::my-model"))
+			.await?;
+
+		assert_eq!(target.model, ModelIden::new(AdapterKind::OpenAI, "my-model"));
+		assert_eq!(target.endpoint.base_url(), "http://[::1]:8000/v1");
+		assert!(matches!(target.auth, AuthData::FromEnv(ref env_name) if env_name == "BASETEN_API_KEY"));
+
+		let chat_url = AdapterDispatcher::get_service_url(&target.model, ServiceType::Chat, target.endpoint)?;
+		assert_eq!(chat_url, "http://[::1]:8000/v1/chat/completions");
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_resolve_service_target_custom_chat_path() -> TestResult<()> {
+		let config = ClientConfig::default().with_service_path(ServiceType::Chat, "v2/chat");
+		let model = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+
+		let target = config.resolve_service_target(model.clone()).await?;
+		let chat_url = AdapterDispatcher::get_service_url(&model, ServiceType::Chat, target.endpoint.clone())?;
+		let models_url = AdapterDispatcher::get_service_url(&model, ServiceType::Models, target.endpoint)?;
+
+		assert_eq!(chat_url, "https://api.openai.com/v1/v2/chat");
+		assert_eq!(models_url, "https://api.openai.com/v1/models");
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_resolve_service_target_custom_chat_path_with_resolver_endpoint() -> TestResult<()> {
+		let resolver = ServiceTargetResolver::from_resolver_fn(
+			|service_target: ServiceTarget| -> core::result::Result<ServiceTarget, crate::resolver::Error> {
+				Ok(ServiceTarget {
+					endpoint: Endpoint::from_static("https://proxy.corp/llm/"),
+					..service_target
+				})
+			},
+		);
+		let config = ClientConfig::default()
+			.with_service_target_resolver(resolver)
+			.with_service_path(ServiceType::Chat, "openai/chat");
+		let model = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+
+		let target = config.resolve_service_target(model.clone()).await?;
+		let stream_url = AdapterDispatcher::get_service_url(&model, ServiceType::ChatStream, target.endpoint)?;
+
+		assert_eq!(stream_url, "https://proxy.corp/llm/openai/chat");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
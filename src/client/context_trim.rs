@@ -0,0 +1,272 @@
+//! Context trimming - `Client::exec_chat_trimmed(..)` and `Client::trim_chat_request(..)`.
+//!
+//! When the estimated input tokens of a request are over `TrimPolicy::max_input_tokens`, the older turns
+//! (all the non-system messages but the `keep_last` ones) are either dropped, or summarized by a model call
+//! and replaced by the summary (as a system message, after the system ones).
+//!
+//! NOTE: The estimate is approximate (4 bytes per token of the JSON-serialized content), so keep some headroom.
+//!
+//! IMPORTANT: `TrimStrategy::Summarize` is a real request, so it costs tokens.
+
+use crate::chat::{ChatMessage, ChatOptions, ChatRequest, ChatResponse, ChatRole, MessageContent};
+use crate::client::ModelSpec;
+use crate::support::approx_tokens;
+use crate::{Client, Error, Result};
+
+// region:    --- TrimPolicy
+
+const DEFAULT_KEEP_LAST: usize = 4;
+
+const SUMMARY_SYSTEM_PROMPT: &str = "Summarize the following conversation for its continuation by an assistant. \
+Keep the facts, decisions, open questions, and tool results needed to continue. Reply with the summary only.";
+
+/// How the older turns are compacted when the context is too long.
+#[derive(Debug, Clone)]
+pub enum TrimStrategy {
+	/// Drop the oldest turns until the estimate fits (or only the `keep_last` messages remain).
+	DropOldest,
+	/// Summarize the older turns with `summary_model` (the request model when `None`),
+	/// and replace them with the summary, as a system message after the system ones.
+	Summarize { summary_model: Option<ModelSpec> },
+}
+
+/// Context trimming policy for `Client::exec_chat_trimmed` and `Client::trim_chat_request`.
+#[derive(Debug, Clone)]
+pub struct TrimPolicy {
+	/// The estimated input tokens above which the request is trimmed.
+	pub max_input_tokens: u32,
+
+	/// Number of most recent non-system messages always kept as is (default: 4).
+	pub keep_last: usize,
+
+	pub strategy: TrimStrategy,
+}
+
+impl TrimPolicy {
+	pub fn new(max_input_tokens: u32, strategy: TrimStrategy) -> Self {
+		Self {
+			max_input_tokens,
+			keep_last: DEFAULT_KEEP_LAST,
+			strategy,
+		}
+	}
+
+	pub fn with_keep_last(mut self, keep_last: usize) -> Self {
+		self.keep_last = keep_last;
+		self
+	}
+}
+
+// endregion: --- TrimPolicy
+
+// region:    --- Client Trim
+
+impl Client {
+	/// Executes a chat request, trimmed first with the `policy` when its context is too long.
+	pub async fn exec_chat_trimmed(
+		&self,
+		model: impl Into<ModelSpec>,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		policy: &TrimPolicy,
+	) -> Result<ChatResponse> {
+		let model = model.into();
+		let chat_req = self.trim_chat_request(model.clone(), chat_req, policy).await?;
+		self.exec_chat(model, chat_req, options).await
+	}
+
+	/// Returns the request trimmed with the `policy` (as is when its estimated input tokens fit).
+	///
+	/// `model` is the summarization model of `TrimStrategy::Summarize` when it has no `summary_model`.
+	pub async fn trim_chat_request(
+		&self,
+		model: impl Into<ModelSpec>,
+		mut chat_req: ChatRequest,
+		policy: &TrimPolicy,
+	) -> Result<ChatRequest> {
+		if estimate_input_tokens(&chat_req) <= policy.max_input_tokens {
+			return Ok(chat_req);
+		}
+
+		let split_idx = older_turns_end(&chat_req.messages, policy.keep_last);
+		if split_idx == 0 {
+			return Ok(chat_req);
+		}
+		let tail = chat_req.messages.split_off(split_idx);
+		let (system_msgs, older): (Vec<ChatMessage>, Vec<ChatMessage>) =
+			std::mem::take(&mut chat_req.messages).into_iter().partition(is_system);
+		if older.is_empty() {
+			chat_req.messages = system_msgs.into_iter().chain(tail).collect();
+			return Ok(chat_req);
+		}
+
+		match &policy.strategy {
+			TrimStrategy::DropOldest => {
+				let mut older = older.into_iter().peekable();
+				loop {
+					chat_req.messages = system_msgs.iter().cloned().chain(older.clone()).chain(tail.clone()).collect();
+					if estimate_input_tokens(&chat_req) <= policy.max_input_tokens || older.peek().is_none() {
+						break;
+					}
+					older.next();
+					// do not start on the tool responses of a dropped tool call
+					while older.next_if(|msg| matches!(msg.role, ChatRole::Tool)).is_some() {}
+				}
+			}
+			TrimStrategy::Summarize { summary_model } => {
+				let summary_model = summary_model.clone().unwrap_or_else(|| model.into());
+				let transcript = older.iter().map(render_message).collect::<Vec<_>>().join("\n\n");
+				let summary_req = ChatRequest::from_user(transcript).with_system(SUMMARY_SYSTEM_PROMPT);
+
+				let summary_res = self.exec_chat(summary_model, summary_req, None).await?;
+				let model_iden = summary_res.model_iden.clone();
+				let summary = summary_res
+					.into_first_text()
+					.filter(|summary| !summary.trim().is_empty())
+					.ok_or(Error::NoChatResponse { model_iden })?;
+
+				let summary_msg = ChatMessage::system(format!("Summary of the earlier conversation:\n{summary}"));
+				chat_req.messages = system_msgs.into_iter().chain([summary_msg]).chain(tail).collect();
+			}
+		}
+
+		Ok(chat_req)
+	}
+}
+
+// endregion: --- Client Trim
+
+// region:    --- Support
+
+/// Approximate input tokens of the request (4 bytes per token of the JSON-serialized content).
+fn estimate_input_tokens(chat_req: &ChatRequest) -> u32 {
+	let system_len = chat_req.system.as_deref().map(str::len).unwrap_or(0);
+	let messages_len: usize = chat_req
+		.messages
+		.iter()
+		.map(|msg| serde_json::to_string(&msg.content).map(|txt| txt.len()).unwrap_or(0))
+		.sum();
+	approx_tokens(system_len + messages_len)
+}
+
+/// Returns the index where the kept messages start (the last `keep_last` non-system messages),
+/// moved back so the kept messages do not start with tool responses (their tool call is kept too).
+fn older_turns_end(messages: &[ChatMessage], keep_last: usize) -> usize {
+	let non_system_idxs: Vec<usize> = (0..messages.len()).filter(|idx| !is_system(&messages[*idx])).collect();
+	if non_system_idxs.len() <= keep_last {
+		return 0;
+	}
+	let mut split_idx = match keep_last {
+		0 => messages.len(),
+		keep_last => non_system_idxs[non_system_idxs.len() - keep_last],
+	};
+	while split_idx > 0 && split_idx < messages.len() && matches!(messages[split_idx].role, ChatRole::Tool) {
+		split_idx -= 1;
+	}
+	split_idx
+}
+
+fn is_system(msg: &ChatMessage) -> bool {
+	matches!(msg.role, ChatRole::System | ChatRole::Developer)
+}
+
+/// Renders a message as text for the summarization transcript.
+fn render_message(msg: &ChatMessage) -> String {
+	let content: &MessageContent = &msg.content;
+	let mut lines: Vec<String> = content.texts().into_iter().map(str::to_string).collect();
+	for tool_call in content.tool_calls() {
+		lines.push(format!("[tool call {}({})]", tool_call.fn_name, tool_call.fn_arguments));
+	}
+	for tool_response in content.tool_responses() {
+		lines.push(format!(
+			"[tool response {}: {}]",
+			tool_response.call_id, tool_response.content
+		));
+	}
+	format!("{}: {}", msg.role, lines.join("\n"))
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chat::{ToolCall, ToolResponse};
+	use serde_json::json;
+
+	fn conversation() -> Vec<ChatMessage> {
+		vec![
+			ChatMessage::system("Be brief."),
+			ChatMessage::user("What is the weather in Paris?"),
+			ChatMessage::from(vec![ToolCall {
+				call_id: "call_1".to_string(),
+				fn_name: "get_weather".to_string(),
+				fn_arguments: json!({"city": "Paris"}),
+				thought_signatures: None,
+			}]),
+			ChatMessage::from(ToolResponse::new("call_1", "sunny")),
+			ChatMessage::assistant("It is sunny."),
+			ChatMessage::user("And tomorrow?"),
+		]
+	}
+
+	#[test]
+	fn test_context_trim_older_turns_end_keeps_tool_pairs() {
+		let messages = conversation();
+
+		// keep the last 2 (assistant, user)
+		assert_eq!(older_turns_end(&messages, 2), 4);
+		// keep the last 3 would start on the tool response, so the tool call is kept too
+		assert_eq!(older_turns_end(&messages, 3), 2);
+		// all kept
+		assert_eq!(older_turns_end(&messages, 5), 0);
+		assert_eq!(older_turns_end(&messages, 0), 6);
+	}
+
+	#[tokio::test]
+	async fn test_context_trim_drop_oldest() -> Result<()> {
+		let client = Client::default();
+		let mut messages = conversation();
+		messages.insert(1, ChatMessage::user("x".repeat(4_000)));
+		messages.insert(2, ChatMessage::assistant("Noted."));
+		let chat_req = ChatRequest::new(messages);
+
+		let policy = TrimPolicy::new(100, TrimStrategy::DropOldest).with_keep_last(2);
+		let trimmed = client.trim_chat_request("gpt-4o-mini", chat_req, &policy).await?;
+
+		// the oldest turns are dropped until it fits, the system message and the last ones are kept
+		assert!(estimate_input_tokens(&trimmed) <= 100);
+		assert!(matches!(trimmed.messages[0].role, ChatRole::System));
+		assert!(!matches!(trimmed.messages[1].role, ChatRole::Tool));
+		assert!(
+			trimmed
+				.messages
+				.iter()
+				.all(|msg| msg.content.first_text().is_none_or(|txt| txt.len() < 4_000))
+		);
+		assert_eq!(
+			trimmed.messages.last().and_then(|msg| msg.content.first_text()),
+			Some("And tomorrow?")
+		);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_context_trim_fits_unchanged() -> Result<()> {
+		let client = Client::default();
+		let chat_req = ChatRequest::new(conversation());
+
+		let policy = TrimPolicy::new(10_000, TrimStrategy::Summarize { summary_model: None });
+		let trimmed = client.trim_chat_request("gpt-4o-mini", chat_req, &policy).await?;
+
+		assert_eq!(trimmed.messages.len(), 6);
+		assert!(trimmed.system.is_none());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,48 @@
+//! Empty response policy - `ChatOptions::with_on_empty(..)`.
+//!
+//! A valid-but-empty response (`ChatResponse::is_empty()`, no text nor tool calls) is returned as is (`OnEmpty::Allow`,
+//! default), returned as an `Error::NoChatResponse` (`OnEmpty::Error`), or sent again up to `MAX_EMPTY_RETRIES` times
+//! before the error (`OnEmpty::Retry`).
+//!
+//! NOTE: Only for `exec_chat` (the streams are not checked).
+
+use crate::chat::{ChatOptions, ChatRequest, ChatResponse, OnEmpty};
+use crate::client::ModelSpec;
+use crate::{Client, Error, Result};
+
+const MAX_EMPTY_RETRIES: u32 = 2;
+
+// region:    --- Client Empty Response
+
+impl Client {
+	pub(super) async fn exec_chat_on_empty(
+		&self,
+		model: ModelSpec,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		on_empty: OnEmpty,
+		max_continuations: u32,
+	) -> Result<ChatResponse> {
+		let mut retries = 0;
+		loop {
+			let chat_res = self
+				.exec_chat_continued(model.clone(), chat_req.clone(), options, max_continuations)
+				.await?;
+			if !chat_res.is_empty() {
+				return Ok(chat_res);
+			}
+
+			match on_empty {
+				OnEmpty::Allow => return Ok(chat_res),
+				OnEmpty::Retry if retries < MAX_EMPTY_RETRIES => retries += 1,
+				OnEmpty::Error | OnEmpty::Retry => {
+					return Err(Error::NoChatResponse {
+						model_iden: chat_res.model_iden,
+					});
+				}
+			}
+		}
+	}
+}
+
+// endregion: --- Client Empty Response
@@ -0,0 +1,177 @@
+//! Hedged requests - `Client::exec_chat_hedged(..)` and `Client::exec_chat_stream_hedged(..)`.
+//!
+//! The primary model is sent first. If it has not answered after `HedgePolicy::delay` (full response for
+//! `exec_chat`, first token for streams), the same request is sent to the next secondary target, and so on.
+//! The first successful answer wins, and the other requests are dropped (which closes their connections).
+//!
+//! IMPORTANT: A fired hedge is a real request, so it costs tokens even when it loses.
+
+use crate::chat::{ChatOptions, ChatRequest, ChatResponse, ChatStreamEvent, ChatStreamResponse};
+use crate::client::ModelSpec;
+use crate::{Client, Error, Result};
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{Instant, sleep_until};
+
+// region:    --- HedgePolicy
+
+/// Hedging policy for `Client::exec_chat_hedged` and `Client::exec_chat_stream_hedged`.
+#[derive(Debug, Clone)]
+pub struct HedgePolicy {
+	/// Wait time before sending the request to the next target (since the previous one was sent).
+	pub delay: Duration,
+
+	/// The fallback targets, sent in order.
+	pub secondary_targets: Vec<ModelSpec>,
+}
+
+impl HedgePolicy {
+	pub fn new(delay: Duration) -> Self {
+		Self {
+			delay,
+			secondary_targets: Vec::new(),
+		}
+	}
+
+	pub fn append_secondary_target(mut self, target: impl Into<ModelSpec>) -> Self {
+		self.secondary_targets.push(target.into());
+		self
+	}
+}
+
+// endregion: --- HedgePolicy
+
+// region:    --- Client Hedge
+
+impl Client {
+	/// Executes a chat request, hedged with the `policy` secondary targets.
+	///
+	/// The winning target is reported by `ChatResponse::model_iden`.
+	pub async fn exec_chat_hedged(
+		&self,
+		model: impl Into<ModelSpec>,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		policy: &HedgePolicy,
+	) -> Result<ChatResponse> {
+		run_hedged(model.into(), policy, |target| {
+			self.exec_chat(target, chat_req.clone(), options)
+		})
+		.await
+	}
+
+	/// Executes a streaming chat request, hedged with the `policy` secondary targets.
+	///
+	/// The trigger is the first token latency: each stream is read up to its first token (or its end),
+	/// and the returned stream replays the events read so far.
+	/// The winning target is reported by `ChatStreamResponse::model_iden`.
+	pub async fn exec_chat_stream_hedged(
+		&self,
+		model: impl Into<ModelSpec>,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		policy: &HedgePolicy,
+	) -> Result<ChatStreamResponse> {
+		run_hedged(model.into(), policy, |target| {
+			self.open_stream_to_first_token(target, chat_req.clone(), options)
+		})
+		.await
+	}
+
+	async fn open_stream_to_first_token(
+		&self,
+		target: ModelSpec,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatStreamResponse> {
+		let mut stream_res = self.exec_chat_stream(target, chat_req, options).await?;
+
+		let mut events = Vec::new();
+		while let Some(event) = stream_res.stream.next().await {
+			let event = event?;
+			// An incomplete `End` before the first token has nothing to return, so it is a failure
+			// (skipped, the stream error follows and moves on to the next target)
+			if let ChatStreamEvent::End(stream_end) = &event
+				&& stream_end.incomplete
+			{
+				continue;
+			}
+			let is_first_token = matches!(event, ChatStreamEvent::FirstToken(_) | ChatStreamEvent::End(_));
+			events.push(event);
+			if is_first_token {
+				break;
+			}
+		}
+		stream_res.stream.prepend_events(events);
+
+		Ok(stream_res)
+	}
+}
+
+// endregion: --- Client Hedge
+
+// region:    --- Support
+
+/// Runs `exec` on the primary target, then on each secondary target after the policy delay
+/// (or right away when all the running ones failed), and returns the first success.
+///
+/// When all fail, returns the error of the first target that was sent.
+async fn run_hedged<T, F, Fut>(primary: ModelSpec, policy: &HedgePolicy, mut exec: F) -> Result<T>
+where
+	F: FnMut(ModelSpec) -> Fut,
+	Fut: Future<Output = Result<T>>,
+{
+	let mut targets = std::iter::once(primary)
+		.chain(policy.secondary_targets.iter().cloned())
+		.enumerate()
+		.peekable();
+	let mut running = FuturesUnordered::new();
+	let mut first_error: Option<(usize, Error)> = None;
+
+	let mut launch = |(idx, target): (usize, ModelSpec)| {
+		let fut = exec(target);
+		async move { (idx, fut.await) }
+	};
+
+	if let Some(primary) = targets.next() {
+		running.push(launch(primary));
+	}
+	let mut next_at = Instant::now() + policy.delay;
+
+	loop {
+		let has_next = targets.peek().is_some();
+
+		tokio::select! {
+			Some((idx, res)) = running.next() => match res {
+				Ok(value) => return Ok(value),
+				Err(err) => {
+					if first_error.as_ref().is_none_or(|(first_idx, _)| idx < *first_idx) {
+						first_error = Some((idx, err));
+					}
+					// -- All the running requests failed, so send the next one right away
+					if running.is_empty() {
+						let Some(next) = targets.next() else { break };
+						running.push(launch(next));
+						next_at = Instant::now() + policy.delay;
+					}
+				}
+			},
+			_ = sleep_until(next_at), if has_next => {
+				if let Some(next) = targets.next() {
+					running.push(launch(next));
+				}
+				next_at = Instant::now() + policy.delay;
+			}
+			else => break,
+		}
+	}
+
+	match first_error {
+		Some((_, err)) => Err(err),
+		None => Err(Error::Internal("Hedged request had no target to send".to_string())),
+	}
+}
+
+// endregion: --- Support
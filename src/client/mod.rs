@@ -5,21 +5,41 @@
 
 // region:    --- Modules
 
+mod auto_continue;
 mod builder;
+mod capability_probe;
+mod chat_middleware;
 mod client_impl;
 mod client_types;
 mod config;
+mod context_trim;
+mod empty_response;
 mod headers;
+mod hedge;
 mod model_spec;
+mod provider_ext;
+mod rate_limit;
+mod redaction_middleware;
+mod request_estimate;
 mod service_target;
+mod token_budget;
 mod web_config;
 
 pub use builder::*;
+pub use capability_probe::*;
+pub use chat_middleware::*;
 pub use client_types::*;
 pub use config::*;
+pub use context_trim::*;
 pub use headers::*;
+pub use hedge::*;
 pub use model_spec::*;
+pub use provider_ext::*;
+pub use rate_limit::RateLimitState;
+pub use redaction_middleware::*;
+pub use request_estimate::{PartSize, RequestEstimate};
 pub use service_target::*;
+pub use token_budget::*;
 pub use web_config::*;
 
 // endregion: --- Modules
@@ -33,7 +33,19 @@ use crate::{ModelIden, ModelName, ServiceTarget};
 ///     model: ModelIden::new(AdapterKind::OpenAI, "custom-model"),
 /// };
 /// let spec: ModelSpec = target.into();
+///
+/// // Using a URL-bearing model name (OpenAI-compatible deployment, no adapter inference)
+/// let spec: ModelSpec = "url::https://model-abc.api.baseten.co/v1::my-model".into();
 /// ```
+///
+/// # URL model names
+///
+/// A `ModelSpec::Name` of the form `url::<base_url>::<model_name>` resolves to a `ServiceTarget` with:
+/// - `endpoint`: the `<base_url>` (must start with `http://` or `https://`),
+/// - `model`: `<model_name>` with the `AdapterKind::OpenAI` (OpenAI-compatible) mapping,
+/// - `auth`: from the `AuthResolver` (if any), otherwise from the `ClientConfig::with_url_model_key_env(..)` env var.
+///
+/// The model name is taken after the last `::`, so the base url can contain `::` (e.g., `http://[::1]:8000/v1`).
 #[derive(Debug, Clone)]
 pub enum ModelSpec {
 	/// Model name - without or without model namespace
@@ -74,6 +86,32 @@ impl ModelSpec {
 
 // endregion: --- Constructors
 
+// region:    --- URL Model Name
+
+/// The namespace for URL-bearing model names, e.g., `url::https://model-abc.api.baseten.co/v1::my-model`
+pub const URL_MODEL_NAMESPACE: &str = "url";
+
+impl ModelSpec {
+	/// Parses a `url::<base_url>::<model_name>` model name into `(base_url, model_name)`.
+	///
+	/// Returns `None` if the model name is not in the `url::` namespace, if the base url is not `http(s)://`,
+	/// or if the model name is empty.
+	pub fn parse_url_model_name(model: &str) -> Option<(&str, &str)> {
+		let rest = model.strip_prefix(URL_MODEL_NAMESPACE)?.strip_prefix("::")?;
+		let (base_url, model_name) = rest.rsplit_once("::")?;
+
+		let has_http_scheme = base_url.starts_with("http://") || base_url.starts_with("https://");
+		let has_host = base_url.split_once("://").is_some_and(|(_, host)| !host.is_empty());
+		if !has_http_scheme || !has_host || model_name.is_empty() {
+			return None;
+		}
+
+		Some((base_url, model_name))
+	}
+}
+
+// endregion: --- URL Model Name
+
 // region:    --- From Implementations
 
 impl From<&str> for ModelSpec {
@@ -131,3 +169,40 @@ impl From<ServiceTarget> for ModelSpec {
 }
 
 // endregion: --- From Implementations
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_url_model_name_simple() {
+		let res = ModelSpec::parse_url_model_name("url::https://model-abc.api.baseten.co/v1::my-model");
+		assert_eq!(res, Some(("https://model-abc.api.baseten.co/v1", "my-model")));
+	}
+
+	#[test]
+	fn test_parse_url_model_name_with_double_colon_in_url() {
+		let res = ModelSpec::parse_url_model_name("url::http://[::1]:8000/v1::my-model");
+		assert_eq!(res, Some(("http://[::1]:8000/v1", "my-model")));
+
+		let res = ModelSpec::parse_url_model_name("url::https://proxy.corp/ns::v1/::meta-llama/Llama-3.1-8B");
+		assert_eq!(res, Some(("https://proxy.corp/ns::v1/", "meta-llama/Llama-3.1-8B")));
+	}
+
+	#[test]
+	fn test_parse_url_model_name_invalid() {
+		// not in the url namespace
+		assert_eq!(ModelSpec::parse_url_model_name("openai::gpt-4o"), None);
+		assert_eq!(ModelSpec::parse_url_model_name("urls::https://a.b/v1::m"), None);
+		// no model name
+		assert_eq!(ModelSpec::parse_url_model_name("url::https://a.b/v1"), None);
+		assert_eq!(ModelSpec::parse_url_model_name("url::https://a.b/v1::"), None);
+		// not an http(s) url
+		assert_eq!(ModelSpec::parse_url_model_name("url::ftp://a.b/v1::m"), None);
+		assert_eq!(ModelSpec::parse_url_model_name("url::https://::m"), None);
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,31 @@
+//! Provider extensions - typed handles for provider-specific APIs that do not fit the `Adapter` trait
+//! (e.g., Anthropic Message Batches, Anthropic Admin usage report).
+//!
+//! Usage: `client.provider_ext::<AnthropicExt>()?.list_batches(None, None).await?`
+//!
+//! Adding an extension for another provider:
+//! - Implement the handle in the provider adapter module (e.g., `adapters/anthropic/ext.rs`), holding a `&Client`.
+//! - Resolve auth and endpoint per call with the client config, so the `AuthResolver` and
+//!   `ServiceTargetResolver` apply as they do for chat requests, and send with the client `WebClient`.
+//! - Implement `ProviderExt` for the handle, and re-export it (and its types) from `genai::adapter`.
+
+use crate::adapter::AdapterKind;
+use crate::{Client, Result};
+
+/// A typed handle to provider-specific APIs, created with `Client::provider_ext::<E>()`.
+pub trait ProviderExt<'a>: Sized {
+	/// The adapter kind this extension targets.
+	const ADAPTER_KIND: AdapterKind;
+
+	/// Creates the extension handle bound to `client`.
+	fn from_client(client: &'a Client) -> Result<Self>;
+}
+
+impl Client {
+	/// Returns the provider extension `E` bound to this client.
+	///
+	/// Provider extensions share the client configuration (auth resolver, service target resolver, web config).
+	pub fn provider_ext<'a, E: ProviderExt<'a>>(&'a self) -> Result<E> {
+		E::from_client(self)
+	}
+}
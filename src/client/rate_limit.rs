@@ -0,0 +1,453 @@
+//! Provider rate limit tracking - the latest `remaining` / `reset` response headers, per adapter kind and API key.
+//!
+//! - Recorded from the non-streaming responses (success or error status) of `exec_chat`
+//!   (the streaming responses headers are consumed by the streamers, so they are not recorded).
+//! - When `ClientConfig::with_rate_limit_wait(true)` is set, `exec_chat` and `exec_chat_stream` wait
+//!   until the reset time when the last view says the requests or tokens are exhausted
+//!   (instead of sending a request that would get a guaranteed 429).
+//! - Current view: `Client::rate_limit_state(adapter_kind)`.
+//!
+//! Header schemas:
+//! - OpenAI (and the OpenAI-compatible providers using it): `x-ratelimit-{limit,remaining,reset}-{requests,tokens}`,
+//!   with the reset as a duration (e.g., `6m0s`, `20ms`).
+//! - Anthropic: `anthropic-ratelimit-{requests,tokens}-{limit,remaining,reset}`, with the reset as an RFC 3339 time.
+//! - Both: `retry-after` (seconds).
+
+use crate::adapter::AdapterKind;
+use crate::resolver::AuthData;
+use crate::webc::{self, WebResponse};
+use crate::{Client, ModelIden};
+use reqwest::header::HeaderMap;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// region:    --- RateLimitState
+
+/// The latest rate limit view of a provider API key, from the response headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitState {
+	pub requests_limit: Option<u64>,
+	pub requests_remaining: Option<u64>,
+	pub requests_reset_at: Option<SystemTime>,
+
+	pub tokens_limit: Option<u64>,
+	pub tokens_remaining: Option<u64>,
+	pub tokens_reset_at: Option<SystemTime>,
+
+	/// From the `retry-after` header (e.g., on a 429).
+	pub retry_after_at: Option<SystemTime>,
+
+	/// Short sha256 prefix of the API key (the key itself is not kept), `None` if the key could not be read.
+	pub key_id: Option<String>,
+
+	/// When the headers were received.
+	pub updated_at: SystemTime,
+}
+
+impl RateLimitState {
+	/// Parses the rate limit headers of the `adapter_kind` schema, received at `now`.
+	///
+	/// Returns `None` when the response has no rate limit headers.
+	pub fn from_headers(adapter_kind: AdapterKind, headers: &HeaderMap, now: SystemTime) -> Option<Self> {
+		let mut state = match adapter_kind {
+			AdapterKind::Anthropic => Self::from_anthropic_headers(headers, now),
+			_ => Self::from_openai_headers(headers, now),
+		};
+
+		state.retry_after_at = header_str(headers, "retry-after")
+			.and_then(|value| value.trim().parse::<f64>().ok())
+			.filter(|secs| secs.is_finite() && *secs >= 0.)
+			.map(|secs| now + Duration::from_secs_f64(secs));
+
+		state.has_values().then_some(state)
+	}
+
+	/// Returns how long to wait before sending a request, if the view says the requests or tokens are exhausted
+	/// (or a `retry-after` is pending) at `now`.
+	pub fn wait_time(&self, now: SystemTime) -> Option<Duration> {
+		let until = |at: Option<SystemTime>| at.and_then(|at| at.duration_since(now).ok());
+
+		let mut wait = until(self.retry_after_at);
+		if self.requests_remaining == Some(0) {
+			wait = wait.max(until(self.requests_reset_at));
+		}
+		if self.tokens_remaining == Some(0) {
+			wait = wait.max(until(self.tokens_reset_at));
+		}
+
+		wait.filter(|wait| !wait.is_zero())
+	}
+}
+
+/// Parsers
+impl RateLimitState {
+	fn empty(now: SystemTime) -> Self {
+		Self {
+			requests_limit: None,
+			requests_remaining: None,
+			requests_reset_at: None,
+			tokens_limit: None,
+			tokens_remaining: None,
+			tokens_reset_at: None,
+			retry_after_at: None,
+			key_id: None,
+			updated_at: now,
+		}
+	}
+
+	fn from_openai_headers(headers: &HeaderMap, now: SystemTime) -> Self {
+		let reset_at = |name: &str| header_str(headers, name).and_then(parse_go_duration).map(|d| now + d);
+		Self {
+			requests_limit: header_u64(headers, "x-ratelimit-limit-requests"),
+			requests_remaining: header_u64(headers, "x-ratelimit-remaining-requests"),
+			requests_reset_at: reset_at("x-ratelimit-reset-requests"),
+			tokens_limit: header_u64(headers, "x-ratelimit-limit-tokens"),
+			tokens_remaining: header_u64(headers, "x-ratelimit-remaining-tokens"),
+			tokens_reset_at: reset_at("x-ratelimit-reset-tokens"),
+			..Self::empty(now)
+		}
+	}
+
+	fn from_anthropic_headers(headers: &HeaderMap, now: SystemTime) -> Self {
+		let reset_at = |name: &str| header_str(headers, name).and_then(parse_rfc3339);
+		Self {
+			requests_limit: header_u64(headers, "anthropic-ratelimit-requests-limit"),
+			requests_remaining: header_u64(headers, "anthropic-ratelimit-requests-remaining"),
+			requests_reset_at: reset_at("anthropic-ratelimit-requests-reset"),
+			tokens_limit: header_u64(headers, "anthropic-ratelimit-tokens-limit"),
+			tokens_remaining: header_u64(headers, "anthropic-ratelimit-tokens-remaining"),
+			tokens_reset_at: reset_at("anthropic-ratelimit-tokens-reset"),
+			..Self::empty(now)
+		}
+	}
+
+	fn has_values(&self) -> bool {
+		self.requests_remaining.is_some() || self.tokens_remaining.is_some() || self.retry_after_at.is_some()
+	}
+}
+
+// endregion: --- RateLimitState
+
+// region:    --- RateLimits
+
+/// The latest `RateLimitState` per adapter kind and API key (held by the `Client`).
+#[derive(Debug, Default)]
+pub(crate) struct RateLimits {
+	states: Mutex<HashMap<(AdapterKind, Option<String>), RateLimitState>>,
+}
+
+impl RateLimits {
+	pub(crate) fn record(&self, model: &ModelIden, key_id: Option<String>, headers: &HeaderMap, now: SystemTime) {
+		let Some(mut state) = RateLimitState::from_headers(model.adapter_kind, headers, now) else {
+			return;
+		};
+		state.key_id = key_id.clone();
+		if let Ok(mut states) = self.states.lock() {
+			states.insert((model.adapter_kind, key_id), state);
+		}
+	}
+
+	pub(crate) fn get(&self, adapter_kind: AdapterKind, key_id: Option<&str>) -> Option<RateLimitState> {
+		let states = self.states.lock().ok()?;
+		states.get(&(adapter_kind, key_id.map(|id| id.to_string()))).cloned()
+	}
+
+	/// Returns the most recently updated state of the adapter kind (any key).
+	pub(crate) fn latest(&self, adapter_kind: AdapterKind) -> Option<RateLimitState> {
+		let states = self.states.lock().ok()?;
+		states
+			.iter()
+			.filter(|((kind, _), _)| *kind == adapter_kind)
+			.map(|(_, state)| state)
+			.max_by_key(|state| state.updated_at)
+			.cloned()
+	}
+}
+
+/// Short sha256 prefix of an API key, to track the rate limits per key without keeping the key.
+pub(crate) fn key_id(api_key: &str) -> String {
+	let digest = Sha256::digest(api_key.as_bytes());
+	digest.iter().take(6).map(|b| format!("{b:02x}")).collect()
+}
+
+// endregion: --- RateLimits
+
+// region:    --- Client Rate Limit
+
+impl Client {
+	/// Returns the latest rate limit view for the adapter kind
+	/// (from the most recent response with rate limit headers, any API key), if any.
+	pub fn rate_limit_state(&self, adapter_kind: AdapterKind) -> Option<RateLimitState> {
+		self.rate_limits().latest(adapter_kind)
+	}
+
+	/// Waits until the rate limit reset of the API key, when `ClientConfig::rate_limit_wait` is set
+	/// and its last view says the requests or tokens are exhausted.
+	pub(crate) async fn wait_for_rate_limit(&self, model: &ModelIden, key_id: Option<&str>) {
+		if !self.config().rate_limit_wait() {
+			return;
+		}
+		let wait = self
+			.rate_limits()
+			.get(model.adapter_kind, key_id)
+			.and_then(|state| state.wait_time(SystemTime::now()));
+		if let Some(wait) = wait {
+			tracing::debug!("Waiting {wait:?} for the {} rate limit reset", model.adapter_kind);
+			tokio::time::sleep(wait).await;
+		}
+	}
+
+	/// Records the rate limit headers of a response (success or error status).
+	pub(crate) fn record_rate_limit(
+		&self,
+		model: &ModelIden,
+		key_id: Option<String>,
+		web_res: &webc::Result<WebResponse>,
+	) {
+		let headers = match web_res {
+			Ok(web_res) => &web_res.headers,
+			Err(webc::Error::ResponseFailedStatus { headers, .. }) => headers.as_ref(),
+			Err(_) => return,
+		};
+		self.rate_limits().record(model, key_id, headers, SystemTime::now());
+	}
+}
+
+/// Returns the rate limit key id of the auth API key, if it can be read.
+pub(crate) fn auth_key_id(auth: &AuthData) -> Option<String> {
+	auth.single_key_value().ok().map(|api_key| key_id(&api_key))
+}
+
+// endregion: --- Client Rate Limit
+
+// region:    --- Support
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+	headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+	header_str(headers, name).and_then(|value| value.trim().parse().ok())
+}
+
+/// Parses an OpenAI reset duration (Go duration format), e.g., `1s`, `6m0s`, `20ms`, `1h2m3.5s`.
+fn parse_go_duration(value: &str) -> Option<Duration> {
+	let mut rest = value.trim();
+	if rest.is_empty() {
+		return None;
+	}
+
+	let mut total = 0.;
+	while !rest.is_empty() {
+		let num_len = rest.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+		let num: f64 = rest[..num_len].parse().ok()?;
+		rest = &rest[num_len..];
+
+		let unit_len = rest.find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len());
+		let secs_per_unit = match &rest[..unit_len] {
+			"h" => 3600.,
+			"m" => 60.,
+			"s" => 1.,
+			"ms" => 0.001,
+			"us" | "µs" => 0.000_001,
+			"ns" => 0.000_000_001,
+			_ => return None,
+		};
+		total += num * secs_per_unit;
+		rest = &rest[unit_len..];
+	}
+
+	Some(Duration::from_secs_f64(total))
+}
+
+/// Parses an RFC 3339 time (e.g., `2025-01-01T00:00:30Z`, `2025-01-01T01:00:30.5+01:00`).
+fn parse_rfc3339(value: &str) -> Option<SystemTime> {
+	let value = value.trim();
+	let (date, time) = value.split_once(['T', 't', ' '])?;
+
+	let mut date_parts = date.splitn(3, '-');
+	let year: i64 = date_parts.next()?.parse().ok()?;
+	let month: u32 = date_parts.next()?.parse().ok()?;
+	let day: u32 = date_parts.next()?.parse().ok()?;
+
+	// -- Split the offset (Z or +hh:mm / -hh:mm)
+	let (time, offset_secs) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+		(time, 0)
+	} else {
+		let idx = time.rfind(['+', '-'])?;
+		let (time, offset) = time.split_at(idx);
+		let sign = if offset.starts_with('-') { -1 } else { 1 };
+		let (hours, minutes) = offset[1..].split_once(':')?;
+		let offset_secs = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+		(time, sign * offset_secs)
+	};
+
+	let mut time_parts = time.splitn(3, ':');
+	let hour: i64 = time_parts.next()?.parse().ok()?;
+	let minute: i64 = time_parts.next()?.parse().ok()?;
+	let second: f64 = time_parts.next()?.parse().ok()?;
+
+	let days = days_from_civil(year, month, day);
+	let secs = days as f64 * 86_400. + (hour * 3600 + minute * 60 - offset_secs) as f64 + second;
+	if secs < 0. {
+		return None;
+	}
+
+	Some(UNIX_EPOCH + Duration::from_secs_f64(secs))
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let month = month as i64;
+	let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146_097 + day_of_era - 719_468
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::{HeaderName, HeaderValue};
+
+	fn headers(entries: &[(&'static str, &str)]) -> HeaderMap {
+		entries
+			.iter()
+			.map(|(name, value)| {
+				let value = HeaderValue::from_str(value).expect("valid header value");
+				(HeaderName::from_static(name), value)
+			})
+			.collect()
+	}
+
+	fn at(secs: u64) -> SystemTime {
+		UNIX_EPOCH + Duration::from_secs(secs)
+	}
+
+	#[test]
+	fn test_rate_limit_openai_header_sequence_wait_times() {
+		let start = at(1_750_000_000);
+
+		// -- Requests left: no wait
+		let state = RateLimitState::from_headers(
+			AdapterKind::OpenAI,
+			&headers(&[
+				("x-ratelimit-limit-requests", "60"),
+				("x-ratelimit-remaining-requests", "1"),
+				("x-ratelimit-reset-requests", "1s"),
+				("x-ratelimit-remaining-tokens", "1200"),
+				("x-ratelimit-reset-tokens", "6m0s"),
+			]),
+			start,
+		)
+		.expect("should have a state");
+		assert_eq!(state.requests_limit, Some(60));
+		assert_eq!(state.wait_time(start), None);
+
+		// -- Requests exhausted: wait until the requests reset
+		let state = RateLimitState::from_headers(
+			AdapterKind::OpenAI,
+			&headers(&[
+				("x-ratelimit-remaining-requests", "0"),
+				("x-ratelimit-reset-requests", "1.5s"),
+				("x-ratelimit-remaining-tokens", "1000"),
+				("x-ratelimit-reset-tokens", "20ms"),
+			]),
+			start,
+		)
+		.expect("should have a state");
+		assert_eq!(state.wait_time(start), Some(Duration::from_millis(1_500)));
+		assert_eq!(
+			state.wait_time(start + Duration::from_secs(1)),
+			Some(Duration::from_millis(500))
+		);
+		assert_eq!(state.wait_time(start + Duration::from_secs(2)), None);
+
+		// -- Tokens exhausted too: the longest reset wins
+		let state = RateLimitState::from_headers(
+			AdapterKind::OpenAI,
+			&headers(&[
+				("x-ratelimit-remaining-requests", "0"),
+				("x-ratelimit-reset-requests", "2s"),
+				("x-ratelimit-remaining-tokens", "0"),
+				("x-ratelimit-reset-tokens", "1m30s"),
+			]),
+			start,
+		)
+		.expect("should have a state");
+		assert_eq!(state.wait_time(start), Some(Duration::from_secs(90)));
+	}
+
+	#[test]
+	fn test_rate_limit_anthropic_header_sequence_wait_times() {
+		// 2025-01-01T00:00:00Z
+		let start = at(1_735_689_600);
+
+		let state = RateLimitState::from_headers(
+			AdapterKind::Anthropic,
+			&headers(&[
+				("anthropic-ratelimit-requests-limit", "50"),
+				("anthropic-ratelimit-requests-remaining", "49"),
+				("anthropic-ratelimit-requests-reset", "2025-01-01T00:00:01Z"),
+				("anthropic-ratelimit-tokens-remaining", "0"),
+				("anthropic-ratelimit-tokens-reset", "2025-01-01T00:00:30Z"),
+			]),
+			start,
+		)
+		.expect("should have a state");
+		assert_eq!(state.requests_remaining, Some(49));
+		assert_eq!(state.wait_time(start), Some(Duration::from_secs(30)));
+
+		// -- 429 with retry-after (and a time zone offset reset)
+		let state = RateLimitState::from_headers(
+			AdapterKind::Anthropic,
+			&headers(&[
+				("retry-after", "45"),
+				("anthropic-ratelimit-requests-remaining", "0"),
+				("anthropic-ratelimit-requests-reset", "2025-01-01T01:00:10+01:00"),
+			]),
+			start,
+		)
+		.expect("should have a state");
+		assert_eq!(state.requests_reset_at, Some(start + Duration::from_secs(10)));
+		assert_eq!(state.wait_time(start), Some(Duration::from_secs(45)));
+	}
+
+	#[test]
+	fn test_rate_limit_no_headers_and_latest_per_key() {
+		assert!(RateLimitState::from_headers(AdapterKind::OpenAI, &HeaderMap::new(), at(0)).is_none());
+
+		let rate_limits = RateLimits::default();
+		let model = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let key_a = Some(key_id("key-a"));
+		let key_b = Some(key_id("key-b"));
+		rate_limits.record(
+			&model,
+			key_a.clone(),
+			&headers(&[("x-ratelimit-remaining-requests", "0")]),
+			at(1),
+		);
+		rate_limits.record(
+			&model,
+			key_b.clone(),
+			&headers(&[("x-ratelimit-remaining-requests", "7")]),
+			at(2),
+		);
+
+		let state_a = rate_limits.get(AdapterKind::OpenAI, key_a.as_deref()).expect("key a state");
+		assert_eq!(state_a.requests_remaining, Some(0));
+		let latest = rate_limits.latest(AdapterKind::OpenAI).expect("latest state");
+		assert_eq!(latest.key_id, key_b);
+		assert!(rate_limits.latest(AdapterKind::Anthropic).is_none());
+	}
+}
+
+// endregion: --- Tests
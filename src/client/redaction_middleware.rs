@@ -0,0 +1,281 @@
+//! PII redaction of the outgoing chat requests - `RedactionMiddleware`.
+//!
+//! A `ChatMiddleware` masking the personal data (emails, phone numbers, credit card numbers, or any configured
+//! pattern) in the message text before the request is sent to the provider.
+//!
+//! NOTE: Unlike `genai::redaction` (which only changes the `Debug` and trace output), this changes what is sent.
+//!       The caller `ChatRequest` is not changed (the middleware sends a redacted copy).
+
+use crate::chat::{ChatRequest, ChatResponse, ChatStreamResponse, ContentPart};
+use crate::client::{ChatMiddleware, ChatNext, ChatStreamNext};
+use crate::{Error, Result};
+use futures::future::BoxFuture;
+use regex::{NoExpand, Regex};
+use serde_json::Value;
+use std::borrow::Cow;
+
+// region:    --- RedactionRule
+
+/// A named pattern, and the text replacing its matches (e.g., `email`, `[EMAIL]`).
+#[derive(Debug, Clone)]
+pub struct RedactionRule {
+	name: String,
+	regex: Regex,
+	replacement: String,
+}
+
+impl RedactionRule {
+	/// Creates a rule from a regex pattern (`Error::InvalidRedactionPattern` if the pattern does not compile).
+	///
+	/// The replacement is inserted as is (no `$name` group expansion).
+	pub fn new(name: impl Into<String>, pattern: &str, replacement: impl Into<String>) -> Result<Self> {
+		let regex = Regex::new(pattern).map_err(|err| Error::InvalidRedactionPattern {
+			pattern: pattern.to_string(),
+			cause: err.to_string(),
+		})?;
+		Ok(Self {
+			name: name.into(),
+			regex,
+			replacement: replacement.into(),
+		})
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn pattern(&self) -> &str {
+		self.regex.as_str()
+	}
+
+	pub fn replacement(&self) -> &str {
+		&self.replacement
+	}
+}
+
+// endregion: --- RedactionRule
+
+// region:    --- RedactionMiddleware
+
+/// Default rules, in order (the credit cards before the phone numbers, so a card number is not seen as a phone).
+const DEFAULT_RULES: &[(&str, &str, &str)] = &[
+	("email", r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}", "[EMAIL]"),
+	("credit_card", r"\b(?:\d[ -]?){12,18}\d\b", "[CARD]"),
+	(
+		"phone",
+		r"(?:\+\d{1,3}[ .-]?)?\(?\d{3}\)?[ .-]?\d{3}[ .-]\d{4}\b",
+		"[PHONE]",
+	),
+];
+
+/// A `ChatMiddleware` masking the configured patterns in the outgoing message text
+/// (the system prompt, the text parts, the tool call arguments, and the tool responses),
+/// for both the `exec_chat` and `exec_chat_stream` requests.
+///
+/// `RedactionMiddleware::default()` masks the emails, the credit-card-like numbers, and the phone numbers.
+/// Use `RedactionMiddleware::empty()` and `with_rule(..)` for a custom set.
+///
+/// ```ignore
+/// let client = Client::builder()
+///     .with_chat_middleware(RedactionMiddleware::default().with_rule(RedactionRule::new("ssn", r"\b\d{3}-\d{2}-\d{4}\b", "[SSN]")?))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RedactionMiddleware {
+	rules: Vec<RedactionRule>,
+}
+
+impl Default for RedactionMiddleware {
+	fn default() -> Self {
+		let rules = DEFAULT_RULES
+			.iter()
+			.map(|(name, pattern, replacement)| {
+				RedactionRule::new(*name, pattern, *replacement).expect("default redaction patterns are valid")
+			})
+			.collect();
+		Self { rules }
+	}
+}
+
+impl RedactionMiddleware {
+	/// A middleware without rules (see `with_rule`).
+	pub fn empty() -> Self {
+		Self { rules: Vec::new() }
+	}
+
+	/// Adds a rule, applied after the existing ones.
+	pub fn with_rule(mut self, rule: RedactionRule) -> Self {
+		self.rules.push(rule);
+		self
+	}
+
+	pub fn rules(&self) -> &[RedactionRule] {
+		&self.rules
+	}
+
+	/// Returns the text with the matches of every rule replaced (borrowed when nothing matched).
+	pub fn redact_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+		let mut text = Cow::Borrowed(text);
+		for rule in self.rules.iter() {
+			if let Cow::Owned(redacted) = rule.regex.replace_all(&text, NoExpand(&rule.replacement)) {
+				text = Cow::Owned(redacted);
+			}
+		}
+		text
+	}
+
+	/// Returns a copy of the request with the system prompt, the text parts, the tool call arguments
+	/// (their string values), and the tool responses redacted.
+	pub fn redact_request(&self, chat_req: &ChatRequest) -> ChatRequest {
+		let mut chat_req = chat_req.clone();
+
+		if let Some(system) = chat_req.system.as_mut() {
+			self.redact_in_place(system);
+		}
+		for message in chat_req.messages.iter_mut() {
+			for part in message.content.iter_mut() {
+				match part {
+					ContentPart::Text(text) => self.redact_in_place(text),
+					ContentPart::ToolCall(tool_call) => self.redact_value(&mut tool_call.fn_arguments),
+					ContentPart::ToolResponse(tool_response) => self.redact_in_place(&mut tool_response.content),
+					_ => (),
+				}
+			}
+		}
+
+		chat_req
+	}
+
+	fn redact_in_place(&self, text: &mut String) {
+		if let Cow::Owned(redacted) = self.redact_text(text) {
+			*text = redacted;
+		}
+	}
+
+	/// Redacts the string leaves of a JSON value (the keys are kept).
+	fn redact_value(&self, value: &mut Value) {
+		match value {
+			Value::String(text) => self.redact_in_place(text),
+			Value::Array(items) => items.iter_mut().for_each(|item| self.redact_value(item)),
+			Value::Object(map) => map.values_mut().for_each(|item| self.redact_value(item)),
+			_ => (),
+		}
+	}
+}
+
+impl ChatMiddleware for RedactionMiddleware {
+	fn around_request<'a>(&'a self, chat_req: ChatRequest, next: ChatNext<'a>) -> BoxFuture<'a, Result<ChatResponse>> {
+		next.run(self.redact_request(&chat_req))
+	}
+
+	fn around_stream<'a>(
+		&'a self,
+		chat_req: ChatRequest,
+		next: ChatStreamNext<'a>,
+	) -> BoxFuture<'a, Result<ChatStreamResponse>> {
+		next.run(self.redact_request(&chat_req))
+	}
+}
+
+// endregion: --- RedactionMiddleware
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chat::{ChatMessage, ToolCall, ToolResponse};
+	use serde_json::json;
+
+	#[test]
+	fn test_redaction_default_rules() {
+		let redaction = RedactionMiddleware::default();
+
+		let text = "Contact jane.doe@example.com or +1 555-123-4567, card 4111 1111 1111 1111.";
+		assert_eq!(redaction.redact_text(text), "Contact [EMAIL] or [PHONE], card [CARD].");
+		assert_eq!(redaction.redact_text("(555) 123-4567"), "[PHONE]");
+		assert_eq!(redaction.redact_text("4111-1111-1111-1111"), "[CARD]");
+
+		// -- Nothing to redact, borrowed
+		let text = "The order 1234 ships in 3 days.";
+		assert!(matches!(redaction.redact_text(text), Cow::Borrowed(t) if t == text));
+	}
+
+	#[test]
+	fn test_redaction_custom_rule() -> Result<()> {
+		let redaction =
+			RedactionMiddleware::empty().with_rule(RedactionRule::new("ssn", r"\b\d{3}-\d{2}-\d{4}\b", "[SSN $1]")?);
+
+		assert_eq!(
+			redaction.redact_text("SSN 123-45-6789, jane@example.com"),
+			"SSN [SSN $1], jane@example.com"
+		);
+
+		let err = RedactionRule::new("bad", "(unclosed", "x").expect_err("should not compile");
+		assert!(matches!(err, Error::InvalidRedactionPattern { pattern, .. } if pattern == "(unclosed"));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_redaction_request_serialized_masked_original_unchanged() -> Result<()> {
+		let chat_req = ChatRequest::from_system("The user email is jane.doe@example.com.")
+			.append_message(ChatMessage::user("Call me at 555-123-4567."))
+			.append_message(ChatMessage::from(ToolResponse::new(
+				"call_1",
+				r#"{"card": "4111 1111 1111 1111"}"#,
+			)));
+		let original = serde_json::to_string(&chat_req)?;
+
+		let redacted = RedactionMiddleware::default().redact_request(&chat_req);
+		let redacted = serde_json::to_string(&redacted)?;
+
+		assert!(!redacted.contains("jane.doe@example.com"));
+		assert!(!redacted.contains("555-123-4567"));
+		assert!(!redacted.contains("4111 1111 1111 1111"));
+		assert!(redacted.contains("[EMAIL]"));
+		assert!(redacted.contains("[PHONE]"));
+		assert!(redacted.contains("[CARD]"));
+
+		// the original request is unchanged
+		assert_eq!(serde_json::to_string(&chat_req)?, original);
+		assert!(original.contains("jane.doe@example.com"));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_redaction_request_tool_call_arguments() -> Result<()> {
+		let tool_call = ToolCall {
+			call_id: "call_1".to_string(),
+			fn_name: "send_email".to_string(),
+			fn_arguments: json!({
+				"to": "jane.doe@example.com",
+				"cc": ["john@example.com", 42],
+				"details": {"phone": "555-123-4567", "urgent": true}
+			}),
+			thought_signatures: None,
+		};
+		let chat_req = ChatRequest::from_user("Email Jane.").append_message(ChatMessage::from(vec![tool_call]));
+
+		let redacted = RedactionMiddleware::default().redact_request(&chat_req);
+
+		let fn_arguments = redacted.messages[1]
+			.content
+			.tool_calls()
+			.first()
+			.map(|tool_call| tool_call.fn_arguments.clone());
+		assert_eq!(
+			fn_arguments,
+			Some(json!({
+				"to": "[EMAIL]",
+				"cc": ["[EMAIL]", 42],
+				"details": {"phone": "[PHONE]", "urgent": true}
+			}))
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,195 @@
+//! Request size estimation - `Client::estimate_request(..)`.
+//!
+//! The request is serialized through the adapter request builder (as `Client::build_chat_payload`),
+//! and measured, without being sent. Useful to plan quotas, or to switch the large binary parts
+//! to a file-upload flow before hitting the provider request size limit.
+//!
+//! NOTE: `approx_tokens` is approximate (4 bytes per token of the serialized payload), and the base64
+//!       binary parts are counted as bytes, so it overestimates the requests with images or documents.
+
+use crate::adapter::{ModelCapabilities, ServiceType};
+use crate::chat::{ChatOptions, ChatOptionsSet, ChatRequest};
+use crate::client::ModelSpec;
+use crate::support::approx_tokens;
+use crate::{Client, Error, ModelIden, Result};
+use serde::{Deserialize, Serialize};
+
+// region:    --- RequestEstimate
+
+/// The serialized size of a content part of the request (as serialized by genai, before the adapter mapping).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartSize {
+	/// The index of the message in `ChatRequest::messages`.
+	pub message_idx: usize,
+	/// The index of the part in the message content.
+	pub part_idx: usize,
+	pub bytes: usize,
+}
+
+/// The estimated size of a chat request for a given provider, as returned by `Client::estimate_request`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestEstimate {
+	pub model_iden: ModelIden,
+
+	/// The size of the provider JSON payload (what would be sent).
+	pub payload_bytes: usize,
+
+	/// Approximate input tokens (4 bytes per token of the payload).
+	pub approx_tokens: u32,
+
+	/// The size of the largest content part (0 when there are no parts).
+	pub largest_part_bytes: usize,
+
+	/// The size of each content part of the messages, in request order.
+	pub part_bytes: Vec<PartSize>,
+
+	/// The provider documented request size limit, when known (see `ModelCapabilities::max_request_bytes`).
+	pub max_request_bytes: Option<usize>,
+}
+
+impl RequestEstimate {
+	/// Returns true when the payload is over the provider known request size limit.
+	pub fn exceeds_limit(&self) -> bool {
+		self.max_request_bytes.is_some_and(|max_bytes| self.payload_bytes > max_bytes)
+	}
+}
+
+// endregion: --- RequestEstimate
+
+// region:    --- Client Estimate
+
+impl Client {
+	/// Estimates the size of a chat request for the model provider, without sending it.
+	///
+	/// The model is resolved as for `exec_chat` (model mapping and auth resolution), and the request
+	/// is serialized with the chat options (client ones included), `extra_body` included.
+	pub async fn estimate_request(
+		&self,
+		model: impl Into<ModelSpec>,
+		chat_req: &ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<RequestEstimate> {
+		let target = self.config().resolve_model_spec(model.into()).await?;
+		let model_iden = target.model.clone();
+
+		let payload = self.build_chat_payload(target, ServiceType::Chat, chat_req.clone(), options)?;
+		let payload_bytes = serde_json::to_vec(&payload)?.len();
+
+		let mut part_bytes = Vec::new();
+		for (message_idx, message) in chat_req.messages.iter().enumerate() {
+			for (part_idx, part) in message.content.iter().enumerate() {
+				let bytes = serde_json::to_vec(part)?.len();
+				part_bytes.push(PartSize {
+					message_idx,
+					part_idx,
+					bytes,
+				});
+			}
+		}
+		let largest_part_bytes = part_bytes.iter().map(|part| part.bytes).max().unwrap_or(0);
+
+		let max_request_bytes = ModelCapabilities::max_request_bytes(model_iden.adapter_kind, &model_iden.model_name);
+
+		Ok(RequestEstimate {
+			model_iden,
+			payload_bytes,
+			approx_tokens: approx_tokens(payload_bytes),
+			largest_part_bytes,
+			part_bytes,
+			max_request_bytes,
+		})
+	}
+}
+
+// endregion: --- Client Estimate
+
+// region:    --- Support
+
+/// Returns `Error::RequestTooLarge` when `validate_request_bytes` is set and the payload is over
+/// the model provider known request size limit.
+pub(super) fn validate_request_bytes(
+	model: &ModelIden,
+	options_set: &ChatOptionsSet,
+	payload: &serde_json::Value,
+) -> Result<()> {
+	if !options_set.validate_request_bytes().unwrap_or(false) {
+		return Ok(());
+	}
+	let Some(max_bytes) = ModelCapabilities::max_request_bytes(model.adapter_kind, &model.model_name) else {
+		return Ok(());
+	};
+	let payload_bytes = serde_json::to_vec(payload)?.len();
+	if payload_bytes > max_bytes {
+		return Err(Error::RequestTooLarge {
+			model_iden: model.clone(),
+			payload_bytes,
+			max_bytes,
+		});
+	}
+	Ok(())
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ServiceTarget;
+	use crate::adapter::AdapterKind;
+	use crate::chat::{ChatMessage, ContentPart};
+	use crate::resolver::{AuthData, Endpoint};
+	use serde_json::json;
+
+	#[tokio::test]
+	async fn test_estimate_request_part_sizes() -> Result<()> {
+		let client = Client::default();
+		let big_image = "A".repeat(4_000);
+		let chat_req = ChatRequest::from_system("Be brief.").append_message(ChatMessage::user(vec![
+			ContentPart::from_text("What is in this image?"),
+			ContentPart::from_binary_base64("image/png", big_image.as_str(), None),
+		]));
+
+		let target = ServiceTarget {
+			endpoint: Endpoint::from_static("https://api.anthropic.com/v1/"),
+			auth: AuthData::from_single("test-key"),
+			model: ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+		};
+		let estimate = client.estimate_request(target, &chat_req, None).await?;
+
+		assert_eq!(estimate.part_bytes.len(), 2);
+		assert_eq!(estimate.part_bytes[1].message_idx, 0);
+		assert_eq!(estimate.part_bytes[1].part_idx, 1);
+		assert_eq!(estimate.largest_part_bytes, estimate.part_bytes[1].bytes);
+		assert!(estimate.largest_part_bytes > 4_000);
+		assert!(estimate.payload_bytes > estimate.largest_part_bytes);
+		assert_eq!(estimate.approx_tokens as usize, estimate.payload_bytes.div_ceil(4));
+		assert_eq!(estimate.max_request_bytes, Some(32 * 1024 * 1024));
+		assert!(!estimate.exceeds_limit());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_request_bytes() -> Result<()> {
+		let model = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		let payload = json!({"contents": "A".repeat(21 * 1024 * 1024)});
+
+		// -- Not enabled
+		assert!(validate_request_bytes(&model, &ChatOptionsSet::default(), &payload).is_ok());
+
+		// -- Enabled
+		let options = ChatOptions::default().with_validate_request_bytes(true);
+		let options_set = ChatOptionsSet::default().with_chat_options(Some(&options));
+		let err = validate_request_bytes(&model, &options_set, &payload).expect_err("should be over the limit");
+		assert!(matches!(err, Error::RequestTooLarge { max_bytes, .. } if max_bytes == 20 * 1024 * 1024));
+
+		// -- Under the limit
+		assert!(validate_request_bytes(&model, &options_set, &json!({"contents": "hello"})).is_ok());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,230 @@
+//! Conversation token budget - `Client::with_token_budget(..)`.
+//!
+//! - The budget tracks the cumulative usage of the chat calls of the client (and its clones).
+//! - Once the budget is exhausted, `exec_chat` and `exec_chat_stream` refuse to send with
+//!   `Error::TokenBudgetExceeded` (the call that crosses the limit still completes).
+//! - The `on_approaching` callback is called once, when the usage first reaches the approaching ratio.
+//!
+//! NOTE: Streams are recorded from their `End` captured usage, so `ChatOptions::with_capture_usage(true)` is needed.
+
+use crate::chat::Usage;
+use crate::{Error, ModelIden, Result};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+type OnApproachingFn = Arc<dyn Fn(TokenBudgetStatus) + Send + Sync>;
+
+// region:    --- TokenBudget
+
+/// A cumulative token budget shared by the clones of a budget (and of the client holding it).
+#[derive(Clone)]
+pub struct TokenBudget {
+	max_tokens: u64,
+	/// Ratio of `max_tokens` at which `on_approaching` is called (0.8 by default).
+	approaching_ratio: f64,
+	on_approaching: Option<OnApproachingFn>,
+
+	used: Arc<AtomicU64>,
+	approaching_notified: Arc<AtomicBool>,
+}
+
+/// A snapshot of the token budget usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBudgetStatus {
+	pub used: u64,
+	pub max_tokens: u64,
+}
+
+impl TokenBudgetStatus {
+	pub fn remaining(&self) -> u64 {
+		self.max_tokens.saturating_sub(self.used)
+	}
+
+	pub fn is_exceeded(&self) -> bool {
+		self.used >= self.max_tokens
+	}
+}
+
+/// Constructors & Setters
+impl TokenBudget {
+	pub fn new(max_tokens: u64) -> Self {
+		Self {
+			max_tokens,
+			approaching_ratio: 0.8,
+			on_approaching: None,
+			used: Arc::new(AtomicU64::new(0)),
+			approaching_notified: Arc::new(AtomicBool::new(false)),
+		}
+	}
+
+	/// Sets the ratio of `max_tokens` (clamped to 0..=1) at which `on_approaching` is called.
+	pub fn with_approaching_ratio(mut self, ratio: f64) -> Self {
+		self.approaching_ratio = ratio.clamp(0.0, 1.0);
+		self
+	}
+
+	/// Sets the callback called once, when the usage first reaches the approaching ratio
+	/// (e.g., to summarize the conversation before the budget is exhausted).
+	pub fn with_on_approaching(mut self, on_approaching: impl Fn(TokenBudgetStatus) + Send + Sync + 'static) -> Self {
+		self.on_approaching = Some(Arc::new(on_approaching));
+		self
+	}
+}
+
+/// Getters & Reset
+impl TokenBudget {
+	pub fn status(&self) -> TokenBudgetStatus {
+		TokenBudgetStatus {
+			used: self.used.load(Ordering::Relaxed),
+			max_tokens: self.max_tokens,
+		}
+	}
+
+	pub fn used(&self) -> u64 {
+		self.status().used
+	}
+
+	pub fn remaining(&self) -> u64 {
+		self.status().remaining()
+	}
+
+	pub fn is_exceeded(&self) -> bool {
+		self.status().is_exceeded()
+	}
+
+	/// Resets the usage to 0 (e.g., for a new conversation), and re-arms the `on_approaching` callback.
+	pub fn reset(&self) {
+		self.used.store(0, Ordering::Relaxed);
+		self.approaching_notified.store(false, Ordering::Relaxed);
+	}
+}
+
+/// Crate Support
+impl TokenBudget {
+	/// Returns `Error::TokenBudgetExceeded` when the budget is exhausted.
+	pub(crate) fn check(&self, model_iden: &ModelIden) -> Result<()> {
+		let status = self.status();
+		if status.is_exceeded() {
+			return Err(Error::TokenBudgetExceeded {
+				model_iden: model_iden.clone(),
+				used: status.used,
+				max_tokens: status.max_tokens,
+			});
+		}
+		Ok(())
+	}
+
+	/// Adds the usage total tokens (or prompt + completion tokens when no total).
+	pub(crate) fn record(&self, usage: &Usage) {
+		let tokens = usage
+			.total_tokens
+			.unwrap_or_else(|| usage.prompt_tokens.unwrap_or(0) + usage.completion_tokens.unwrap_or(0));
+		let tokens = u64::try_from(tokens).unwrap_or(0);
+
+		let used = self.used.fetch_add(tokens, Ordering::Relaxed) + tokens;
+
+		let approaching_at = (self.max_tokens as f64 * self.approaching_ratio).ceil() as u64;
+		if used >= approaching_at
+			&& !self.approaching_notified.swap(true, Ordering::Relaxed)
+			&& let Some(on_approaching) = &self.on_approaching
+		{
+			on_approaching(TokenBudgetStatus {
+				used,
+				max_tokens: self.max_tokens,
+			});
+		}
+	}
+}
+
+impl std::fmt::Debug for TokenBudget {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("TokenBudget")
+			.field("max_tokens", &self.max_tokens)
+			.field("approaching_ratio", &self.approaching_ratio)
+			.field("used", &self.used())
+			.field("on_approaching", &self.on_approaching.is_some())
+			.finish()
+	}
+}
+
+// endregion: --- TokenBudget
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterKind;
+	use std::sync::Mutex;
+
+	fn usage(total_tokens: i32) -> Usage {
+		Usage {
+			total_tokens: Some(total_tokens),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_token_budget_accumulates_then_refuses() {
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let budget = TokenBudget::new(100);
+
+		for _ in 0..3 {
+			assert!(budget.check(&model_iden).is_ok());
+			budget.record(&usage(30));
+		}
+		assert_eq!(budget.used(), 90);
+		assert_eq!(budget.remaining(), 10);
+		assert!(budget.check(&model_iden).is_ok());
+
+		// -- The call crossing the limit completes, the next one is refused
+		budget.record(&usage(30));
+		assert_eq!(budget.remaining(), 0);
+		let err = budget.check(&model_iden).expect_err("budget should be exceeded");
+		assert!(matches!(
+			err,
+			Error::TokenBudgetExceeded {
+				used: 120,
+				max_tokens: 100,
+				..
+			}
+		));
+	}
+
+	#[test]
+	fn test_token_budget_on_approaching_called_once() {
+		let notified: Arc<Mutex<Vec<TokenBudgetStatus>>> = Default::default();
+		let budget = TokenBudget::new(100).with_approaching_ratio(0.5).with_on_approaching({
+			let notified = notified.clone();
+			move |status| notified.lock().unwrap().push(status)
+		});
+
+		budget.record(&usage(40));
+		assert!(notified.lock().unwrap().is_empty());
+
+		// -- Shared by the clones (e.g., the clones of the client)
+		let budget_clone = budget.clone();
+		budget_clone.record(&usage(20));
+		budget.record(&usage(20));
+
+		assert_eq!(
+			*notified.lock().unwrap(),
+			vec![TokenBudgetStatus {
+				used: 60,
+				max_tokens: 100
+			}]
+		);
+
+		// -- Reset re-arms the callback
+		budget.reset();
+		budget.record(&Usage {
+			prompt_tokens: Some(45),
+			completion_tokens: Some(5),
+			..Default::default()
+		});
+		assert_eq!(notified.lock().unwrap().len(), 2);
+		assert_eq!(budget.used(), 50);
+	}
+}
+
+// endregion: --- Tests
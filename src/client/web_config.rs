@@ -8,6 +8,12 @@ use std::time::Duration;
 /// - HTTP/2 keep-alive (prevents idle connection drops)
 /// - HTTP/2 adaptive flow-control window
 /// - Connection pool: 4 idle connections per host
+///
+/// For streaming-heavy workloads (hundreds of concurrent SSE streams to the same host), the connection churn
+/// can be reduced by keeping more idle connections (`with_pool_max_idle_per_host(32)`), for longer
+/// (`with_pool_idle_timeout(Duration::from_secs(300))`), with TCP keep-alive (`with_tcp_keepalive(Duration::from_secs(60))`).
+/// `with_http2_prior_knowledge(true)` multiplexes the streams on a few connections, but only for the endpoints
+/// known to speak HTTP/2 without negotiation (e.g., an `h2c` proxy), as the HTTP/1-only endpoints will then fail.
 #[derive(Debug, Clone)]
 pub struct WebConfig {
 	pub timeout: Option<Duration>,
@@ -21,6 +27,16 @@ pub struct WebConfig {
 	pub gzip: bool,
 	/// Enable TCP_NODELAY (disable Nagle's algorithm). Default: true.
 	pub tcp_nodelay: bool,
+	/// Use HTTP/2 without negotiation (the endpoints must support it). Default: false.
+	pub http2_prior_knowledge: bool,
+	/// Maximum idle connections kept per host. Default: 4.
+	pub pool_max_idle_per_host: usize,
+	/// How long the idle connections are kept. Default: None (reqwest default, 90 seconds).
+	pub pool_idle_timeout: Option<Duration>,
+	/// TCP keep-alive interval. Default: None (disabled).
+	pub tcp_keepalive: Option<Duration>,
+	/// HTTP/2 keep-alive ping interval. Default: 20 seconds.
+	pub http2_keep_alive_interval: Option<Duration>,
 }
 
 impl Default for WebConfig {
@@ -34,6 +50,11 @@ impl Default for WebConfig {
 			danger_accept_invalid_certs: false,
 			gzip: true,
 			tcp_nodelay: true,
+			http2_prior_knowledge: false,
+			pool_max_idle_per_host: 4,
+			pool_idle_timeout: None,
+			tcp_keepalive: None,
+			http2_keep_alive_interval: Some(Duration::from_secs(20)),
 		}
 	}
 }
@@ -100,6 +121,36 @@ impl WebConfig {
 		self
 	}
 
+	/// Sets whether HTTP/2 is used without negotiation (only for the endpoints known to support it).
+	pub fn with_http2_prior_knowledge(mut self, value: bool) -> Self {
+		self.http2_prior_knowledge = value;
+		self
+	}
+
+	/// Sets the maximum idle connections kept per host.
+	pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+		self.pool_max_idle_per_host = max_idle;
+		self
+	}
+
+	/// Sets how long the idle connections are kept (`None` to keep them).
+	pub fn with_pool_idle_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+		self.pool_idle_timeout = timeout.into();
+		self
+	}
+
+	/// Sets the TCP keep-alive interval (`None` to disable it).
+	pub fn with_tcp_keepalive(mut self, interval: impl Into<Option<Duration>>) -> Self {
+		self.tcp_keepalive = interval.into();
+		self
+	}
+
+	/// Sets the HTTP/2 keep-alive ping interval (`None` to disable it).
+	pub fn with_http2_keep_alive_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+		self.http2_keep_alive_interval = interval.into();
+		self
+	}
+
 	/// Applies this config to a reqwest::ClientBuilder.
 	pub fn apply_to_builder(&self, mut builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
 		if let Some(timeout) = self.timeout {
@@ -127,10 +178,20 @@ impl WebConfig {
 		if self.tcp_nodelay {
 			builder = builder.tcp_nodelay(true);
 		}
+		// Connection pool tuning
+		builder = builder.pool_max_idle_per_host(self.pool_max_idle_per_host);
+		if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+			builder = builder.pool_idle_timeout(pool_idle_timeout);
+		}
+		if let Some(tcp_keepalive) = self.tcp_keepalive {
+			builder = builder.tcp_keepalive(tcp_keepalive);
+		}
 		// HTTP/2 connection tuning
+		if self.http2_prior_knowledge {
+			builder = builder.http2_prior_knowledge();
+		}
 		builder = builder
-			.pool_max_idle_per_host(4)
-			.http2_keep_alive_interval(Some(Duration::from_secs(20)))
+			.http2_keep_alive_interval(self.http2_keep_alive_interval)
 			.http2_keep_alive_timeout(Duration::from_secs(10))
 			.http2_keep_alive_while_idle(true)
 			.http2_adaptive_window(true);
@@ -150,4 +211,24 @@ mod tests {
 
 		assert_eq!(config.read_timeout, Some(timeout));
 	}
+
+	#[test]
+	fn connection_tuning_defaults_and_setters() {
+		let config = WebConfig::default();
+		assert!(!config.http2_prior_knowledge);
+		assert_eq!(config.pool_max_idle_per_host, 4);
+		assert_eq!(config.http2_keep_alive_interval, Some(Duration::from_secs(20)));
+
+		let config = config
+			.with_http2_prior_knowledge(true)
+			.with_pool_max_idle_per_host(32)
+			.with_pool_idle_timeout(Duration::from_secs(300))
+			.with_tcp_keepalive(Duration::from_secs(60))
+			.with_http2_keep_alive_interval(None);
+		assert!(config.http2_prior_knowledge);
+		assert_eq!(config.pool_max_idle_per_host, 32);
+		assert_eq!(config.pool_idle_timeout, Some(Duration::from_secs(300)));
+		assert_eq!(config.tcp_keepalive, Some(Duration::from_secs(60)));
+		assert_eq!(config.http2_keep_alive_interval, None);
+	}
 }
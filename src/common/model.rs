@@ -46,6 +46,14 @@ pub struct Model {
 	/// Whether the model supports JSON mode.
 	pub supports_json_mode: bool,
 
+	/// Whether the provider flags the model as deprecated (or scheduled for removal).
+	#[serde(default)]
+	pub deprecated: bool,
+
+	/// The deprecation (or shutdown) date as reported by the provider, if any (e.g., `2025-06-30`).
+	#[serde(default)]
+	pub deprecation_date: Option<String>,
+
 	/// Additional model-specific properties.
 	pub additional_properties: Option<serde_json::Value>,
 }
@@ -94,6 +102,8 @@ impl Model {
 			supports_tool_calls: false,
 			supports_streaming: false,
 			supports_json_mode: false,
+			deprecated: false,
+			deprecation_date: None,
 			additional_properties: None,
 		}
 	}
@@ -195,6 +205,19 @@ impl Model {
 		self
 	}
 
+	/// Set the deprecated flag.
+	pub fn with_deprecated(mut self, deprecated: bool) -> Self {
+		self.deprecated = deprecated;
+		self
+	}
+
+	/// Set the deprecation date (also flags the model as deprecated).
+	pub fn with_deprecation_date(mut self, date: impl Into<String>) -> Self {
+		self.deprecation_date = Some(date.into());
+		self.deprecated = true;
+		self
+	}
+
 	/// Set additional properties.
 	pub fn with_additional_properties(mut self, properties: serde_json::Value) -> Self {
 		self.additional_properties = Some(properties);
@@ -242,6 +265,11 @@ impl Model {
 		self.max_output_tokens.map(|limit| tokens <= limit).unwrap_or(true)
 	}
 
+	/// Check if the model is deprecated (flagged, or with a deprecation date), e.g., to hide sunset models.
+	pub fn is_deprecated(&self) -> bool {
+		self.deprecated || self.deprecation_date.is_some()
+	}
+
 	/// Check if this is a multimodal model.
 	pub fn is_multimodal(&self) -> bool {
 		self.supported_input_modalities.len() > 1
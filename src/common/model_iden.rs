@@ -1,15 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-use crate::ModelName;
 use crate::adapter::AdapterKind;
-use derive_more::Display;
+use crate::{Error, ModelName, Result};
 
 /// Holds the adapter kind and model name in an efficient, clonable way.
 ///
 /// This struct represents the association between an adapter kind
 /// and a model name, allowing for easy conversion and instantiation.
-#[derive(Clone, Debug, Serialize, Deserialize, Display, Eq, PartialEq, Hash)]
-#[display("{model_name} (adapter: {adapter_kind})")]
+///
+/// Displays as `adapter::model_name` (e.g., `openai::gpt-4o`, `zai::zai-coding::glm-4.6`),
+/// which parses back to the same `ModelIden` with `FromStr`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub struct ModelIden {
 	/// The adapter kind.
 	pub adapter_kind: AdapterKind,
@@ -56,7 +57,10 @@ impl ModelIden {
 		}
 	}
 
-	/// Creates a new `ModelIden` with the specified name, or clones the existing one if the name is the same.
+	/// Creates a new `ModelIden` with the specified name, or clones the existing one if the name is the same or `None`.
+	///
+	/// Typically used to build `ChatResponse::provider_model_iden` from the provider-reported model name,
+	/// see [`crate::chat::ChatResponse::model_changed`] to detect a mismatch.
 	/// NOTE: Might be deprecated in favor of [`ModelIden::from_name`]
 	pub fn from_optional_name(&self, new_name: Option<String>) -> ModelIden {
 		if let Some(new_name) = new_name {
@@ -83,3 +87,75 @@ where
 		}
 	}
 }
+
+impl std::fmt::Display for ModelIden {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}::{}", self.adapter_kind.as_lower_str(), self.model_name)
+	}
+}
+
+impl std::str::FromStr for ModelIden {
+	type Err = Error;
+
+	/// Parses `adapter::model_name` (the `Display` format), where `adapter` is an `AdapterKind::as_lower_str()`.
+	/// Otherwise, the whole string is the model name, and the adapter kind is inferred with `AdapterKind::from_model`.
+	fn from_str(s: &str) -> Result<Self> {
+		if let Some((adapter, model_name)) = s.split_once("::")
+			&& let Some(adapter_kind) = AdapterKind::from_lower_str(adapter)
+			&& !model_name.is_empty()
+		{
+			return Ok(ModelIden::new(adapter_kind, model_name));
+		}
+
+		if s.is_empty() {
+			return Err(Error::ModelIdenParsing { actual: s.to_string() });
+		}
+
+		let adapter_kind = AdapterKind::from_model(s)?;
+		Ok(ModelIden::new(adapter_kind, s))
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_model_iden_display_default_adapter() -> Result<()> {
+		let model_iden: ModelIden = "gpt-4o-mini".parse()?;
+
+		assert_eq!(model_iden.adapter_kind, AdapterKind::OpenAI);
+		assert_eq!(model_iden.to_string(), "openai::gpt-4o-mini");
+		assert_eq!(model_iden.to_string().parse::<ModelIden>()?, model_iden);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_model_iden_display_namespaced() -> Result<()> {
+		let model_iden = ModelIden::new(AdapterKind::Zai, "zai-coding::glm-4.6");
+		assert_eq!(model_iden.to_string(), "zai::zai-coding::glm-4.6");
+		assert_eq!(model_iden.to_string().parse::<ModelIden>()?, model_iden);
+
+		let model_iden = ModelIden::new(AdapterKind::Groq, "groq::llama-3.1-8b-instant");
+		assert_eq!(model_iden.to_string(), "groq::groq::llama-3.1-8b-instant");
+		assert_eq!(model_iden.to_string().parse::<ModelIden>()?, model_iden);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_model_iden_from_str_namespaced_name() -> Result<()> {
+		// Same as the client model name resolution (namespace kept in the model name)
+		let model_iden: ModelIden = "zai-coding::glm-4.6".parse()?;
+		assert_eq!(model_iden, ModelIden::new(AdapterKind::Zai, "zai-coding::glm-4.6"));
+
+		assert!("".parse::<ModelIden>().is_err());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
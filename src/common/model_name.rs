@@ -1,25 +1,36 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Store a model name with or without namespace
 /// e.g. `gemini-3-flash-preview` or `gemini::gemini-3-flash-preview`
-#[derive(Clone, Debug, Serialize, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct ModelName(Inner);
 
-#[derive(Clone, Debug, Serialize, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 enum Inner {
 	Static(&'static str),
 	Shared(Arc<str>),
 }
 
+/// Serialized as the plain model name string (e.g., `"gemini::gemini-3-flash-preview"`).
+impl Serialize for ModelName {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_str(self.as_str())
+	}
+}
+
 impl<'de> Deserialize<'de> for ModelName {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
-		let s: &str = <&str>::deserialize(deserializer)?;
+		// Owned, so it also deserializes from a reader (no borrowed string)
+		let s = String::deserialize(deserializer)?;
 		Ok(ModelName(Inner::Shared(Arc::<str>::from(s))))
 	}
 }
@@ -158,3 +169,25 @@ impl PartialEq<ModelName> for String {
 }
 
 // endregion: --- EQ
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_model_name_serde_plain_string() -> serde_json::Result<()> {
+		let model_name = ModelName::from_static("gemini::gemini-3-flash-preview");
+
+		let json = serde_json::to_string(&model_name)?;
+		assert_eq!(json, r#""gemini::gemini-3-flash-preview""#);
+
+		let model_name_back: ModelName = serde_json::from_reader(json.as_bytes())?;
+		assert_eq!(model_name_back.as_str(), model_name.as_str());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
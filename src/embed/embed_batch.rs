@@ -0,0 +1,234 @@
+//! Chunked batch embedding with per-chunk progress reporting.
+//!
+//! Used by `Client::exec_embed_batch_with_progress(..)`. Chunks are executed one after the other,
+//! so progress events are always emitted in input order, and cancellation is checked between chunks.
+
+use crate::chat::Usage;
+use crate::embed::{EmbedResponse, Embedding};
+use crate::{ModelIden, Result};
+use std::future::Future;
+use std::ops::Range;
+
+/// Default number of inputs sent per embed request when `EmbedOptions::batch_chunk_size` is not set.
+pub const DEFAULT_EMBED_BATCH_CHUNK_SIZE: usize = 64;
+
+// region:    --- BatchProgress
+
+/// Progress event emitted after each chunk of a batch embedding completes.
+#[derive(Debug, Clone)]
+pub struct BatchProgress {
+	/// Zero-based index of the completed chunk.
+	pub chunk_index: usize,
+
+	/// Total number of chunks for this batch.
+	pub chunk_count: usize,
+
+	/// Indices (in the original inputs) covered by the completed chunk.
+	pub input_indices: Range<usize>,
+
+	/// Number of inputs completed so far (including this chunk).
+	pub completed_inputs: usize,
+
+	/// Total number of inputs for this batch.
+	pub total_inputs: usize,
+
+	/// Usage reported for the completed chunk.
+	pub usage: Usage,
+}
+
+impl BatchProgress {
+	/// Returns true if this is the last chunk of the batch.
+	pub fn is_last(&self) -> bool {
+		self.completed_inputs == self.total_inputs
+	}
+}
+
+/// Returned by the progress callback to continue or cancel the remaining chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchControl {
+	/// Continue with the next chunk (also the value for a callback returning `()`).
+	#[default]
+	Continue,
+	/// Stop before the next chunk. The embeddings of the completed chunks are still returned.
+	Cancel,
+}
+
+impl From<()> for BatchControl {
+	fn from(_: ()) -> Self {
+		BatchControl::Continue
+	}
+}
+
+// endregion: --- BatchProgress
+
+// region:    --- Batch Runner
+
+/// Runs `inputs` through `exec_chunk` in chunks of `chunk_size`, reporting each completed chunk to `on_progress`.
+///
+/// The returned `EmbedResponse` contains the embeddings of all the completed chunks,
+/// with their `index` relative to the original `inputs`, and the summed usage.
+pub(crate) async fn run_embed_batch<F, Fut, P, C>(
+	model_iden: ModelIden,
+	inputs: Vec<String>,
+	chunk_size: usize,
+	mut exec_chunk: F,
+	mut on_progress: P,
+) -> Result<EmbedResponse>
+where
+	F: FnMut(Vec<String>) -> Fut,
+	Fut: Future<Output = Result<EmbedResponse>>,
+	P: FnMut(BatchProgress) -> C,
+	C: Into<BatchControl>,
+{
+	let chunk_size = chunk_size.max(1);
+	let total_inputs = inputs.len();
+	let chunk_count = total_inputs.div_ceil(chunk_size);
+
+	let mut embeddings: Vec<Embedding> = Vec::with_capacity(total_inputs);
+	let mut usage = Usage::default();
+	let mut provider_model_iden: Option<ModelIden> = None;
+
+	let mut inputs = inputs.into_iter();
+	let mut start = 0;
+
+	for chunk_index in 0..chunk_count {
+		let chunk: Vec<String> = inputs.by_ref().take(chunk_size).collect();
+		let end = start + chunk.len();
+
+		let res = exec_chunk(chunk).await?;
+
+		provider_model_iden.get_or_insert(res.provider_model_iden);
+		usage += &res.usage;
+		embeddings.extend(res.embeddings.into_iter().map(|mut embedding| {
+			embedding.index += start;
+			embedding
+		}));
+
+		let progress = BatchProgress {
+			chunk_index,
+			chunk_count,
+			input_indices: start..end,
+			completed_inputs: end,
+			total_inputs,
+			usage: res.usage,
+		};
+		start = end;
+
+		if on_progress(progress).into() == BatchControl::Cancel {
+			break;
+		}
+	}
+
+	let provider_model_iden = provider_model_iden.unwrap_or_else(|| model_iden.clone());
+	Ok(EmbedResponse::new(embeddings, model_iden, provider_model_iden, usage))
+}
+
+// endregion: --- Batch Runner
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterKind;
+
+	fn test_model_iden() -> ModelIden {
+		ModelIden::new(AdapterKind::OpenAI, "text-embedding-3-small")
+	}
+
+	/// Mock chunk executor: one embedding per input (vector = input length), 1 prompt token per input.
+	async fn mock_exec_chunk(chunk: Vec<String>) -> Result<EmbedResponse> {
+		let usage = Usage {
+			prompt_tokens: Some(chunk.len() as i32),
+			total_tokens: Some(chunk.len() as i32),
+			..Default::default()
+		};
+		let embeddings = chunk
+			.iter()
+			.enumerate()
+			.map(|(idx, input)| Embedding::new(vec![input.len() as f32], idx))
+			.collect();
+		Ok(EmbedResponse::new(
+			embeddings,
+			test_model_iden(),
+			test_model_iden(),
+			usage,
+		))
+	}
+
+	fn test_inputs(count: usize) -> Vec<String> {
+		(0..count).map(|idx| "x".repeat(idx + 1)).collect()
+	}
+
+	#[tokio::test]
+	async fn test_run_embed_batch_reports_every_index_once() -> Result<()> {
+		let mut reported: Vec<usize> = Vec::new();
+		let mut events: Vec<BatchProgress> = Vec::new();
+
+		let res = run_embed_batch(test_model_iden(), test_inputs(10), 3, mock_exec_chunk, |progress| {
+			reported.extend(progress.input_indices.clone());
+			events.push(progress);
+		})
+		.await?;
+
+		assert_eq!(reported, (0..10).collect::<Vec<_>>());
+		assert_eq!(events.len(), 4);
+		assert_eq!(
+			events.iter().map(|e| e.chunk_index).collect::<Vec<_>>(),
+			vec![0, 1, 2, 3]
+		);
+		assert!(events.iter().all(|e| e.chunk_count == 4 && e.total_inputs == 10));
+		assert_eq!(events[3].input_indices, 9..10);
+		assert!(events[3].is_last());
+		assert_eq!(events[1].usage.prompt_tokens, Some(3));
+
+		// embeddings are re-indexed relative to the original inputs
+		assert_eq!(res.embeddings.len(), 10);
+		for (idx, embedding) in res.embeddings.iter().enumerate() {
+			assert_eq!(embedding.index, idx);
+			assert_eq!(embedding.vector, vec![(idx + 1) as f32]);
+		}
+		assert_eq!(res.usage.prompt_tokens, Some(10));
+		assert_eq!(res.usage.total_tokens, Some(10));
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_run_embed_batch_cancel_between_chunks() -> Result<()> {
+		let mut exec_count = 0;
+		let exec_chunk = |chunk: Vec<String>| {
+			exec_count += 1;
+			mock_exec_chunk(chunk)
+		};
+
+		let res = run_embed_batch(test_model_iden(), test_inputs(10), 4, exec_chunk, |progress| {
+			if progress.chunk_index == 1 {
+				BatchControl::Cancel
+			} else {
+				BatchControl::Continue
+			}
+		})
+		.await?;
+
+		assert_eq!(exec_count, 2);
+		assert_eq!(res.embeddings.len(), 8);
+		assert_eq!(res.embeddings.last().map(|e| e.index), Some(7));
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_run_embed_batch_empty_inputs() -> Result<()> {
+		let mut event_count = 0;
+
+		let res = run_embed_batch(test_model_iden(), Vec::new(), 3, mock_exec_chunk, |_| event_count += 1).await?;
+
+		assert_eq!(event_count, 0);
+		assert!(res.embeddings.is_empty());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
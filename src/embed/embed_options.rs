@@ -2,8 +2,10 @@
 //! - It can be provided at the `client::embed(..)` level as an argument,
 //! - or set in the client config `client_config.with_embed_options(..)` to be used as the default for all requests
 
-use crate::Headers;
+use crate::support::approx_bytes;
+use crate::{Error, Headers, Result};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 // region:    --- EmbedOptions
 
@@ -13,6 +15,10 @@ pub struct EmbedOptions {
 	/// Custom headers to include in the request.
 	pub headers: Option<Headers>,
 
+	/// Additional URL query parameters appended to the final request URL (e.g., Azure `api-version`).
+	/// Values are URL-encoded, and override existing query parameters with the same name.
+	pub extra_query_params: Option<Vec<(String, String)>>,
+
 	/// Whether to capture the raw response body for provider-specific features.
 	pub capture_raw_body: Option<bool>,
 
@@ -37,10 +43,19 @@ pub struct EmbedOptions {
 	/// Default: "search_document" (Cohere), "SEMANTIC_SIMILARITY" (Gemini)
 	pub embedding_type: Option<String>,
 
-	/// How to handle inputs longer than the maximum token length (supported by Cohere).
-	/// Common values: "NONE", "START", "END"
-	/// Default: "END"
-	pub truncate: Option<String>,
+	/// How to handle the inputs longer than the model maximum input tokens (see `EmbedTruncate`).
+	/// Sent to the providers supporting it (Cohere `truncate`, Ollama `truncate`),
+	/// otherwise applied client-side when the model limit is known (or `max_input_tokens` is set).
+	/// Default: the provider behavior (e.g., `END` for Cohere, an error for OpenAI).
+	pub truncate: Option<EmbedTruncate>,
+
+	/// Maximum input tokens of the model, for the client-side truncation
+	/// (default: the known limit of the model, e.g., 8191 for the OpenAI `text-embedding-3-*`).
+	pub max_input_tokens: Option<u32>,
+
+	/// Number of inputs sent per request by `Client::exec_embed_batch_with_progress(..)`.
+	/// Default: `DEFAULT_EMBED_BATCH_CHUNK_SIZE` (64)
+	pub batch_chunk_size: Option<usize>,
 }
 
 /// Constructors
@@ -59,6 +74,12 @@ impl EmbedOptions {
 		self
 	}
 
+	/// Set extra URL query parameters for the request.
+	pub fn with_extra_query_params(mut self, params: Vec<(String, String)>) -> Self {
+		self.extra_query_params = Some(params);
+		self
+	}
+
 	/// Enable or disable capturing the raw response body.
 	pub fn with_capture_raw_body(mut self, capture: bool) -> Self {
 		self.capture_raw_body = Some(capture);
@@ -96,8 +117,20 @@ impl EmbedOptions {
 	}
 
 	/// Set the truncation method for inputs longer than the maximum token length.
-	pub fn with_truncate(mut self, truncate: impl Into<String>) -> Self {
-		self.truncate = Some(truncate.into());
+	pub fn with_truncate(mut self, truncate: EmbedTruncate) -> Self {
+		self.truncate = Some(truncate);
+		self
+	}
+
+	/// Set the maximum input tokens of the model, for the client-side truncation.
+	pub fn with_max_input_tokens(mut self, max_input_tokens: u32) -> Self {
+		self.max_input_tokens = Some(max_input_tokens);
+		self
+	}
+
+	/// Set the number of inputs sent per request for progress batch embedding.
+	pub fn with_batch_chunk_size(mut self, batch_chunk_size: usize) -> Self {
+		self.batch_chunk_size = Some(batch_chunk_size);
 		self
 	}
 }
@@ -109,6 +142,11 @@ impl EmbedOptions {
 		self.headers.as_ref()
 	}
 
+	/// Get the extra URL query parameters.
+	pub fn extra_query_params(&self) -> Option<&[(String, String)]> {
+		self.extra_query_params.as_deref()
+	}
+
 	/// Get whether to capture raw body.
 	pub fn capture_raw_body(&self) -> bool {
 		self.capture_raw_body.unwrap_or(false)
@@ -140,8 +178,18 @@ impl EmbedOptions {
 	}
 
 	/// Get the truncation method.
-	pub fn truncate(&self) -> Option<&str> {
-		self.truncate.as_deref()
+	pub fn truncate(&self) -> Option<EmbedTruncate> {
+		self.truncate
+	}
+
+	/// Get the maximum input tokens.
+	pub fn max_input_tokens(&self) -> Option<u32> {
+		self.max_input_tokens
+	}
+
+	/// Get the batch chunk size.
+	pub fn batch_chunk_size(&self) -> Option<usize> {
+		self.batch_chunk_size
 	}
 }
 
@@ -181,6 +229,13 @@ impl<'client, 'request> EmbedOptionsSet<'client, 'request> {
 			.or_else(|| self.client_options.and_then(|o| o.headers()))
 	}
 
+	/// Get the effective extra URL query parameters (request overrides client).
+	pub fn extra_query_params(&self) -> Option<&[(String, String)]> {
+		self.request_options
+			.and_then(|o| o.extra_query_params())
+			.or_else(|| self.client_options.and_then(|o| o.extra_query_params()))
+	}
+
 	/// Get the effective capture_raw_body setting.
 	pub fn capture_raw_body(&self) -> bool {
 		self.request_options
@@ -226,11 +281,160 @@ impl<'client, 'request> EmbedOptionsSet<'client, 'request> {
 	}
 
 	/// Get the effective truncate setting.
-	pub fn truncate(&self) -> Option<&str> {
+	pub fn truncate(&self) -> Option<EmbedTruncate> {
 		self.request_options
 			.and_then(|o| o.truncate())
 			.or_else(|| self.client_options.and_then(|o| o.truncate()))
 	}
+
+	/// Get the effective max_input_tokens setting.
+	pub fn max_input_tokens(&self) -> Option<u32> {
+		self.request_options
+			.and_then(|o| o.max_input_tokens())
+			.or_else(|| self.client_options.and_then(|o| o.max_input_tokens()))
+	}
+
+	/// Get the effective batch chunk size setting.
+	pub fn batch_chunk_size(&self) -> Option<usize> {
+		self.request_options
+			.and_then(|o| o.batch_chunk_size())
+			.or_else(|| self.client_options.and_then(|o| o.batch_chunk_size()))
+	}
 }
 
 // endregion: --- EmbedOptionsSet
+
+// region:    --- EmbedTruncate
+
+/// How an embedding input longer than the model maximum input tokens is handled.
+///
+/// Serialized as the provider value (`"NONE"`, `"START"`, `"END"`), and parsed from it (case-insensitive),
+/// e.g., `"START".parse::<EmbedTruncate>()?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EmbedTruncate {
+	/// No truncation (the provider returns an error for a too long input).
+	None,
+	/// Drop the start of the input (keep the end).
+	Start,
+	/// Drop the end of the input (keep the start).
+	End,
+}
+
+impl EmbedTruncate {
+	/// The provider value (Cohere `truncate`).
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			EmbedTruncate::None => "NONE",
+			EmbedTruncate::Start => "START",
+			EmbedTruncate::End => "END",
+		}
+	}
+
+	/// Returns the truncate for a provider value keyword (case-insensitive), e.g., `START`.
+	pub fn from_keyword(name: &str) -> Option<Self> {
+		match name.to_uppercase().as_str() {
+			"NONE" => Some(EmbedTruncate::None),
+			"START" => Some(EmbedTruncate::Start),
+			"END" => Some(EmbedTruncate::End),
+			_ => None,
+		}
+	}
+
+	/// Returns the text truncated to about `max_tokens` (see `support::approx_bytes`, on a char boundary),
+	/// borrowed when it fits (or for `EmbedTruncate::None`).
+	pub(crate) fn truncate_text<'a>(&self, text: &'a str, max_tokens: u32) -> Cow<'a, str> {
+		let max_bytes = approx_bytes(max_tokens);
+		if text.len() <= max_bytes {
+			return Cow::Borrowed(text);
+		}
+
+		match self {
+			EmbedTruncate::None => Cow::Borrowed(text),
+			EmbedTruncate::End => {
+				let mut end = max_bytes;
+				while !text.is_char_boundary(end) {
+					end -= 1;
+				}
+				Cow::Owned(text[..end].to_string())
+			}
+			EmbedTruncate::Start => {
+				let mut start = text.len() - max_bytes;
+				while !text.is_char_boundary(start) {
+					start += 1;
+				}
+				Cow::Owned(text[start..].to_string())
+			}
+		}
+	}
+}
+
+impl std::str::FromStr for EmbedTruncate {
+	type Err = Error;
+
+	/// Parses a provider value keyword (`NONE`, `START`, `END`).
+	fn from_str(s: &str) -> Result<Self> {
+		Self::from_keyword(s).ok_or(Error::EmbedTruncateParsing { actual: s.to_string() })
+	}
+}
+
+// endregion: --- EmbedTruncate
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_embed_truncate_text_start_end_none() {
+		let text = "abcdefghij";
+
+		assert_eq!(EmbedTruncate::End.truncate_text(text, 2), "abcdefgh");
+		assert_eq!(EmbedTruncate::Start.truncate_text(text, 2), "cdefghij");
+		assert_eq!(EmbedTruncate::None.truncate_text(text, 2), text);
+
+		// -- Fits, borrowed
+		assert!(matches!(EmbedTruncate::End.truncate_text(text, 3), Cow::Borrowed(_)));
+
+		// -- On a char boundary (é is 2 bytes, 4 bytes max)
+		assert_eq!(EmbedTruncate::End.truncate_text("aééé", 1), "aé");
+		assert_eq!(EmbedTruncate::Start.truncate_text("éééa", 1), "éa");
+	}
+
+	#[test]
+	fn test_embed_options_set_truncate_request_over_client() {
+		let client = EmbedOptions::new().with_truncate(EmbedTruncate::End);
+		let request = EmbedOptions::new().with_truncate(EmbedTruncate::Start);
+
+		let options_set = EmbedOptionsSet::new()
+			.with_client_options(Some(&client))
+			.with_request_options(Some(&request));
+		assert_eq!(options_set.truncate(), Some(EmbedTruncate::Start));
+		assert_eq!(EmbedTruncate::Start.as_str(), "START");
+
+		let options_set = EmbedOptionsSet::new().with_client_options(Some(&client));
+		assert_eq!(options_set.truncate(), Some(EmbedTruncate::End));
+	}
+
+	#[test]
+	fn test_embed_truncate_serde_and_parse_provider_value() -> Result<()> {
+		// -- Serde, the provider value
+		let json = serde_json::to_string(&EmbedTruncate::Start).expect("should serialize");
+		assert_eq!(json, r#""START""#);
+		let truncate: EmbedTruncate = serde_json::from_str(r#""END""#).expect("should deserialize");
+		assert_eq!(truncate, EmbedTruncate::End);
+
+		// -- FromStr, case-insensitive
+		assert_eq!("NONE".parse::<EmbedTruncate>()?, EmbedTruncate::None);
+		assert_eq!("start".parse::<EmbedTruncate>()?, EmbedTruncate::Start);
+		assert!(matches!(
+			"middle".parse::<EmbedTruncate>(),
+			Err(Error::EmbedTruncateParsing { .. })
+		));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -76,7 +76,12 @@ impl EmbedRequest {
 // region:    --- EmbedInput
 
 /// The input for an embedding request, supporting both single and batch operations.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Serialized as the provider `input` is: a string for `Single`, an array for `Batch`
+/// (the adapters of the providers only accepting an array send a single input as a one item array).
+/// The response embeddings are in the input order, with their input `index`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum EmbedInput {
 	/// A single text input.
 	Single(String),
@@ -109,3 +114,30 @@ impl From<Vec<&str>> for EmbedInput {
 }
 
 // endregion: --- EmbedInput
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embed_input_serde_single_string_batch_array() -> serde_json::Result<()> {
+		let single = EmbedRequest::new("hello");
+		let batch = EmbedRequest::new_batch(vec!["hello".to_string(), "world".to_string()]);
+
+		assert_eq!(serde_json::to_value(&single)?, json!({"input": "hello"}));
+		assert_eq!(serde_json::to_value(&batch)?, json!({"input": ["hello", "world"]}));
+
+		// -- Round trip
+		let single: EmbedRequest = serde_json::from_value(json!({"input": "hello"}))?;
+		assert_eq!(single.input, EmbedInput::Single("hello".to_string()));
+		let batch: EmbedRequest = serde_json::from_value(json!({"input": ["hello", "world"]}))?;
+		assert_eq!(batch.inputs(), vec!["hello", "world"]);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
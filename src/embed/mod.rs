@@ -3,11 +3,13 @@
 
 // region:    --- Modules
 
+mod embed_batch;
 mod embed_options;
 mod embed_request;
 mod embed_response;
 
 // -- Flatten
+pub use embed_batch::*;
 pub use embed_options::*;
 pub use embed_request::*;
 pub use embed_response::*;
@@ -1,6 +1,6 @@
-use crate::adapter::AdapterKind;
+use crate::adapter::{AdapterKind, ServiceType};
 use crate::chat::ChatRole;
-use crate::{ModelIden, resolver, webc};
+use crate::{ModelIden, resolver, support, webc};
 use derive_more::{Display, From};
 use reqwest::StatusCode;
 use value_ext::JsonValueExtError;
@@ -31,9 +31,42 @@ pub enum Error {
 	#[display("Content type not supported for model '{model_iden}'.\nCause: {cause}")]
 	MessageContentTypeNotSupported { model_iden: ModelIden, cause: &'static str },
 
+	#[display("Feature '{feature}' not supported by model '{model_iden}'")]
+	UnsupportedByModel {
+		model_iden: ModelIden,
+		feature: &'static str,
+	},
+
+	#[display("Raw prompt cannot be combined with the {conflict} of the chat request (model '{model_iden}')")]
+	RawPromptConflict {
+		model_iden: ModelIden,
+		conflict: &'static str,
+	},
+
 	#[display("JSON mode requested but no instruction/prompt provided.")]
 	JsonModeWithoutInstruction,
 
+	#[display("Invalid tool name '{tool_name}' for model '{model_iden}'.\nCause: {cause}")]
+	InvalidToolName {
+		model_iden: ModelIden,
+		tool_name: String,
+		cause: &'static str,
+	},
+
+	#[display("Too many images for model '{model_iden}': {image_count} (max {max_images} per request)")]
+	TooManyImages {
+		model_iden: ModelIden,
+		image_count: usize,
+		max_images: usize,
+	},
+
+	#[display("Request for model '{model_iden}' is too large: {payload_bytes} bytes (max {max_bytes} bytes)")]
+	RequestTooLarge {
+		model_iden: ModelIden,
+		payload_bytes: usize,
+		max_bytes: usize,
+	},
+
 	#[display("Failed to parse verbosity. Actual: '{actual}'")]
 	VerbosityParsing { actual: String },
 
@@ -46,13 +79,39 @@ pub enum Error {
 	#[display("Failed to parse prompt cache retention. Actual: '{actual}'")]
 	PromptCacheRetentionParsing { actual: String },
 
+	#[display("Failed to parse embed truncate (NONE, START, or END). Actual: '{actual}'")]
+	EmbedTruncateParsing { actual: String },
+
+	#[display("Failed to parse model iden. Actual: '{actual}'")]
+	ModelIdenParsing { actual: String },
+
 	// -- Chat Output
 	#[display("No chat response from model '{model_iden}'")]
 	NoChatResponse { model_iden: ModelIden },
 
+	#[display("Response of model '{model_iden}' exceeded the maximum size of {max_bytes} bytes")]
+	ResponseTooLarge {
+		model_iden: ModelIden,
+		max_bytes: usize,
+		/// The raw body received up to the limit (empty for a stream, its chunks were already returned).
+		partial: String,
+	},
+
 	#[display("Invalid JSON response element: {info}")]
 	InvalidJsonResponseElement { info: &'static str },
 
+	#[display("Invalid tool call arguments for function '{fn_name}'. Arguments: {arguments}\nCause: {cause}")]
+	InvalidToolArguments {
+		fn_name: String,
+		arguments: String,
+		cause: String,
+	},
+
+	// -- Mock
+	#[cfg(feature = "mock")]
+	#[display("No mock chat handler for model '{model_iden}' (see ClientBuilder::with_mock_chat_handler)")]
+	MockChatHandlerMissing { model_iden: ModelIden },
+
 	// -- Auth
 	#[display("Model '{model_iden}' requires an API key.")]
 	RequiresApiKey { model_iden: ModelIden },
@@ -63,6 +122,14 @@ pub enum Error {
 	#[display("No authentication data available for model '{model_iden}'.")]
 	NoAuthData { model_iden: ModelIden },
 
+	// -- Endpoint
+	#[display("Invalid endpoint url '{url}'.\nCause: {cause}")]
+	InvalidEndpoint { url: String, cause: String },
+
+	// -- Redaction
+	#[display("Invalid redaction pattern '{pattern}'.\nCause: {cause}")]
+	InvalidRedactionPattern { pattern: String, cause: String },
+
 	// -- ModelMapper
 	#[display("Model mapping failed for '{model_iden}'.\nCause: {cause}")]
 	ModelMapperFailed {
@@ -118,7 +185,10 @@ Cause:\n{cause}
 		error: BoxError,
 	},
 
-	#[display("HTTP error.\nStatus: {status} {canonical_reason}\nBody: {body}")]
+	#[display(
+		"HTTP error.\nStatus: {status} {canonical_reason}\nBody: {}",
+		support::truncate_for_log(body)
+	)]
 	HttpError {
 		status: StatusCode,
 		canonical_reason: String,
@@ -132,9 +202,28 @@ Cause:\n{cause}
 		resolver_error: resolver::Error,
 	},
 
+	// -- Client
+	#[display("Token budget exceeded for model '{model_iden}' ({used} of {max_tokens} tokens used)")]
+	TokenBudgetExceeded {
+		model_iden: ModelIden,
+		used: u64,
+		max_tokens: u64,
+	},
+
 	// -- Adapter Support
-	#[display("Adapter '{adapter_kind}' does not support feature '{feature}'")]
-	AdapterNotSupported { adapter_kind: AdapterKind, feature: String },
+	#[display(
+		"Adapter '{adapter_kind}' does not support feature '{feature}'{}",
+		alternatives_hint(alternatives)
+	)]
+	AdapterNotSupported {
+		adapter_kind: AdapterKind,
+		feature: String,
+		/// The adapters supporting the feature (empty when not a service, or unknown)
+		alternatives: Vec<AdapterKind>,
+	},
+
+	#[display("Invalid request payload for model '{model_iden}'.\nCause:\n{cause}")]
+	RequestPayloadInvalid { model_iden: ModelIden, cause: String },
 
 	#[display("Internal error: {_0}")]
 	Internal(String),
@@ -149,6 +238,149 @@ Cause:\n{cause}
 	SerdeJson(serde_json::Error),
 }
 
+// region:    --- Constructors
+
+impl Error {
+	/// Returns `Error::AdapterNotSupported` for a service the adapter does not implement,
+	/// with the adapters supporting it as alternatives (from `AdapterKind::supports_service`).
+	pub fn service_not_supported(adapter_kind: AdapterKind, service_type: ServiceType) -> Self {
+		let feature = match service_type {
+			ServiceType::Chat => "chat",
+			ServiceType::ChatStream => "chat stream",
+			ServiceType::Embed => "embeddings",
+			ServiceType::Models => "models",
+		};
+		Error::AdapterNotSupported {
+			adapter_kind,
+			feature: feature.to_string(),
+			alternatives: adapter_kind.alternatives_for(service_type),
+		}
+	}
+}
+
+fn alternatives_hint(alternatives: &[AdapterKind]) -> String {
+	if alternatives.is_empty() {
+		return String::new();
+	}
+	let names: Vec<&str> = alternatives.iter().map(AdapterKind::as_str).collect();
+	format!(". Adapters supporting it: {}", names.join(", "))
+}
+
+// endregion: --- Constructors
+
+// region:    --- ProviderErrorKind
+
+/// The classification of a failed provider call (from the HTTP status and the provider error body),
+/// see `Error::provider_error_kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProviderErrorKind {
+	/// The provider is overloaded (Anthropic 529 `overloaded_error`, 503, Gemini `UNAVAILABLE`).
+	Overloaded,
+	/// Rate limited (429, Anthropic `rate_limit_error`, Gemini `RESOURCE_EXHAUSTED`).
+	RateLimited,
+	/// The account quota or billing limit is reached (OpenAI `insufficient_quota`, also sent as a 429).
+	QuotaExceeded,
+	/// A conflicting concurrent request (409, retried by the OpenAI SDKs).
+	Conflict,
+	/// The provider timed out (408, Gemini `DEADLINE_EXCEEDED`).
+	Timeout,
+	/// Another provider server error (5xx, Anthropic `api_error`).
+	ServerError,
+	/// Invalid or missing credentials, or not allowed (401, 403).
+	Authentication,
+	/// Another client error (4xx).
+	InvalidRequest,
+}
+
+impl ProviderErrorKind {
+	/// Returns true when the same request may succeed later (e.g., after a backoff, or on another provider).
+	pub fn is_retryable(&self) -> bool {
+		matches!(
+			self,
+			Self::Overloaded | Self::RateLimited | Self::Conflict | Self::Timeout | Self::ServerError
+		)
+	}
+
+	/// Classifies a provider error from its HTTP status (when known) and its body.
+	///
+	/// The error type of the body wins over the status (e.g., an OpenAI 429 `insufficient_quota`
+	/// is not a rate limit). The body can be the full error response (`{"error": {..}}`), or the error object.
+	pub fn classify(status: Option<StatusCode>, body: &serde_json::Value) -> Option<Self> {
+		Self::from_error_body(body).or_else(|| status.and_then(Self::from_status))
+	}
+
+	fn from_status(status: StatusCode) -> Option<Self> {
+		let kind = match status.as_u16() {
+			529 | 503 => Self::Overloaded,
+			429 => Self::RateLimited,
+			409 => Self::Conflict,
+			408 | 504 => Self::Timeout,
+			401 | 403 => Self::Authentication,
+			500..=599 => Self::ServerError,
+			400..=499 => Self::InvalidRequest,
+			_ => return None,
+		};
+		Some(kind)
+	}
+
+	/// From the Anthropic/OpenAI `type` and `code`, and the Gemini `status`, of the error object.
+	fn from_error_body(body: &serde_json::Value) -> Option<Self> {
+		let error = body.get("error").filter(|error| error.is_object()).unwrap_or(body);
+		let str_prop = |name: &str| error.get(name).and_then(|value| value.as_str());
+
+		[str_prop("type"), str_prop("code"), str_prop("status")]
+			.into_iter()
+			.flatten()
+			.find_map(|error_type| {
+				let kind = match error_type {
+					"overloaded_error" | "UNAVAILABLE" => Self::Overloaded,
+					"rate_limit_error" | "rate_limit_exceeded" | "RESOURCE_EXHAUSTED" => Self::RateLimited,
+					"insufficient_quota" => Self::QuotaExceeded,
+					"timeout_error" | "DEADLINE_EXCEEDED" => Self::Timeout,
+					"api_error" | "server_error" | "INTERNAL" => Self::ServerError,
+					"authentication_error" | "permission_error" | "UNAUTHENTICATED" | "PERMISSION_DENIED" => {
+						Self::Authentication
+					}
+					_ => return None,
+				};
+				Some(kind)
+			})
+	}
+}
+
+impl Error {
+	/// Returns the provider error classification of a failed call (the HTTP error responses,
+	/// and the stream error events), or `None` for the other errors.
+	pub fn provider_error_kind(&self) -> Option<ProviderErrorKind> {
+		match self {
+			Error::WebModelCall { webc_error, .. } | Error::WebAdapterCall { webc_error, .. } => match webc_error {
+				webc::Error::ResponseFailedStatus { status, body, .. } => {
+					ProviderErrorKind::classify(Some(*status), &parse_error_body(body))
+				}
+				_ => None,
+			},
+			Error::HttpError { status, body, .. } => {
+				ProviderErrorKind::classify(Some(*status), &parse_error_body(body))
+			}
+			Error::ChatResponse { body, .. } => ProviderErrorKind::classify(None, body),
+			Error::WebStream { error, .. } => error.downcast_ref::<Error>().and_then(Error::provider_error_kind),
+			_ => None,
+		}
+	}
+
+	/// Returns true when the provider error is retryable (see `ProviderErrorKind::is_retryable`).
+	pub fn is_retryable(&self) -> bool {
+		self.provider_error_kind().is_some_and(|kind| kind.is_retryable())
+	}
+}
+
+/// The JSON error body, or the raw text as a JSON string.
+fn parse_error_body(body: &str) -> serde_json::Value {
+	serde_json::from_str(body).unwrap_or_else(|_| serde_json::Value::String(body.to_string()))
+}
+
+// endregion: --- ProviderErrorKind
+
 // region:    --- Error Boilerplate
 
 // The Display trait is now derived via derive_more::Display
@@ -158,6 +390,165 @@ Cause:\n{cause}
 // 	}
 // }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::ModelMapperFailed { cause, .. } => Some(cause),
+			Error::WebAdapterCall { webc_error, .. } => Some(webc_error),
+			Error::WebModelCall { webc_error, .. } => Some(webc_error),
+			Error::StreamParse { serde_error, .. } => Some(serde_error),
+			Error::WebStream { error, .. } => Some(error.as_ref()),
+			Error::Resolver { resolver_error, .. } => Some(resolver_error),
+			Error::JsonValueExt(err) => Some(err),
+			Error::SerdeJson(err) => Some(err),
+			_ => None,
+		}
+	}
+}
 
 // endregion: --- Error Boilerplate
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::error::Error as _;
+
+	#[test]
+	fn test_error_source_web_adapter_call() {
+		let err = Error::WebAdapterCall {
+			adapter_kind: AdapterKind::OpenAI,
+			webc_error: webc::Error::ResponseFailedNotJson {
+				content_type: "text/html".to_string(),
+				body: "<html></html>".to_string(),
+			},
+		};
+
+		let source = err.source().expect("should have a source");
+		let webc_error = source.downcast_ref::<webc::Error>().expect("source should be a webc::Error");
+		assert!(matches!(webc_error, webc::Error::ResponseFailedNotJson { .. }));
+	}
+
+	#[test]
+	fn test_error_source_serde_json() {
+		let serde_error = serde_json::from_str::<serde_json::Value>("{").expect_err("should be invalid json");
+		let err = Error::from(serde_error);
+
+		let source = err.source().expect("should have a source");
+		assert!(source.downcast_ref::<serde_json::Error>().is_some());
+	}
+
+	#[test]
+	fn test_error_service_not_supported_suggests_alternatives() {
+		let err = Error::service_not_supported(AdapterKind::Groq, ServiceType::Embed);
+
+		let Error::AdapterNotSupported { alternatives, .. } = &err else {
+			panic!("should be AdapterNotSupported, was: {err:?}");
+		};
+		assert!(alternatives.contains(&AdapterKind::OpenAI));
+		assert!(!alternatives.contains(&AdapterKind::Groq));
+		assert!(!alternatives.contains(&AdapterKind::Anthropic));
+		assert!(err.to_string().starts_with(
+			"Adapter 'Groq' does not support feature 'embeddings'. Adapters supporting it: OpenAI, Gemini, "
+		));
+	}
+
+	#[test]
+	fn test_error_provider_error_kind_classification() {
+		use serde_json::json;
+
+		// (status, body, expected kind, retryable)
+		let cases = [
+			// Anthropic overloaded
+			(
+				Some(529),
+				json!({"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}),
+				Some(ProviderErrorKind::Overloaded),
+				true,
+			),
+			(
+				Some(529),
+				json!("<html>overloaded</html>"),
+				Some(ProviderErrorKind::Overloaded),
+				true,
+			),
+			// OpenAI 409 conflict, and the 429 quota (not a rate limit)
+			(
+				Some(409),
+				json!({"error": {"message": "conflict", "type": "invalid_request_error"}}),
+				Some(ProviderErrorKind::Conflict),
+				true,
+			),
+			(
+				Some(429),
+				json!({"error": {"type": "insufficient_quota", "code": "insufficient_quota"}}),
+				Some(ProviderErrorKind::QuotaExceeded),
+				false,
+			),
+			(
+				Some(429),
+				json!({"error": {"type": "requests", "code": "rate_limit_exceeded"}}),
+				Some(ProviderErrorKind::RateLimited),
+				true,
+			),
+			// Gemini
+			(
+				Some(429),
+				json!({"error": {"code": 429, "message": "Quota exceeded", "status": "RESOURCE_EXHAUSTED"}}),
+				Some(ProviderErrorKind::RateLimited),
+				true,
+			),
+			(
+				Some(503),
+				json!({"error": {"code": 503, "status": "UNAVAILABLE"}}),
+				Some(ProviderErrorKind::Overloaded),
+				true,
+			),
+			// Status only
+			(Some(500), json!(null), Some(ProviderErrorKind::ServerError), true),
+			(Some(401), json!(null), Some(ProviderErrorKind::Authentication), false),
+			(Some(400), json!(null), Some(ProviderErrorKind::InvalidRequest), false),
+			// Stream error events (no status)
+			(
+				None,
+				json!({"type": "overloaded_error", "message": "Overloaded"}),
+				Some(ProviderErrorKind::Overloaded),
+				true,
+			),
+			(None, json!({"message": "unknown"}), None, false),
+		];
+
+		for (status, body, expected, retryable) in cases {
+			let status = status.map(|status| StatusCode::from_u16(status).expect("valid status"));
+			let kind = ProviderErrorKind::classify(status, &body);
+			assert_eq!(kind, expected, "status {status:?}, body {body}");
+			assert_eq!(kind.is_some_and(|kind| kind.is_retryable()), retryable, "{kind:?}");
+		}
+	}
+
+	#[test]
+	fn test_error_provider_error_kind_web_model_call() {
+		let err = Error::WebModelCall {
+			model_iden: ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5"),
+			webc_error: webc::Error::ResponseFailedStatus {
+				status: StatusCode::from_u16(529).expect("valid status"),
+				body: r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#.to_string(),
+				headers: Box::default(),
+			},
+		};
+
+		assert_eq!(err.provider_error_kind(), Some(ProviderErrorKind::Overloaded));
+		assert!(err.is_retryable());
+		assert!(!Error::Internal("leaf".to_string()).is_retryable());
+	}
+
+	#[test]
+	fn test_error_source_none_for_leaf() {
+		let err = Error::Internal("leaf".to_string());
+
+		assert!(err.source().is_none());
+	}
+}
+
+// endregion: --- Tests
@@ -12,12 +12,13 @@ mod error;
 // -- Flatten
 pub use client::*;
 pub use common::*;
-pub use error::{BoxError, Error, Result};
+pub use error::{BoxError, Error, ProviderErrorKind, Result};
 
 // -- Public Modules
 pub mod adapter;
 pub mod chat;
 pub mod embed;
+pub mod redaction;
 pub mod resolver;
 pub mod webc;
 
@@ -0,0 +1,194 @@
+//! Redaction of message content in `Debug` and trace output (e.g., to keep prompts out of application logs).
+//!
+//! - The global mode (`set_redaction_mode`) applies to the `Debug` output of `MessageContent` and `ChatRequest`,
+//!   and to the internal tracing of the response bodies.
+//! - The client mode (`ClientConfig::with_redaction_mode`) applies to the `captured_raw_body` of the responses,
+//!   and falls back to the global mode when not set (so `RedactionMode::Off` on a client keeps the raw bodies as is).
+//! - `ChatRequest::redacted_debug()` always redacts, whatever the global mode.
+//!
+//! A redacted value is formatted as `[text: 1.2kB sha256:ab12cd34…]` (`Hash`) or `[text: 1.2kB]` (`LengthOnly`).
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// region:    --- RedactionMode
+
+/// How message content is rendered in `Debug` and trace output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RedactionMode {
+	/// Content is rendered as is.
+	#[default]
+	Off,
+	/// Content is replaced by its size and a short sha256 prefix (same content, same hash).
+	Hash,
+	/// Content is replaced by its size only.
+	LengthOnly,
+}
+
+impl RedactionMode {
+	pub fn is_off(&self) -> bool {
+		matches!(self, RedactionMode::Off)
+	}
+
+	fn to_u8(self) -> u8 {
+		match self {
+			RedactionMode::Off => 0,
+			RedactionMode::Hash => 1,
+			RedactionMode::LengthOnly => 2,
+		}
+	}
+
+	fn from_u8(value: u8) -> Self {
+		match value {
+			1 => RedactionMode::Hash,
+			2 => RedactionMode::LengthOnly,
+			_ => RedactionMode::Off,
+		}
+	}
+}
+
+// endregion: --- RedactionMode
+
+// region:    --- Global Mode
+
+static GLOBAL_MODE: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+	/// Set while formatting with a forced mode (see `with_forced_mode`).
+	static FORCED_MODE: Cell<Option<RedactionMode>> = const { Cell::new(None) };
+}
+
+/// Sets the process-wide redaction mode for `Debug` and trace output.
+pub fn set_redaction_mode(mode: RedactionMode) {
+	GLOBAL_MODE.store(mode.to_u8(), Ordering::Relaxed);
+}
+
+/// Returns the active redaction mode (the global one, unless forced for the current formatting).
+pub fn redaction_mode() -> RedactionMode {
+	FORCED_MODE
+		.with(|forced| forced.get())
+		.unwrap_or_else(|| RedactionMode::from_u8(GLOBAL_MODE.load(Ordering::Relaxed)))
+}
+
+/// Runs `f` with `mode` as the active redaction mode of the current thread.
+pub(crate) fn with_forced_mode<R>(mode: RedactionMode, f: impl FnOnce() -> R) -> R {
+	let previous = FORCED_MODE.with(|forced| forced.replace(Some(mode)));
+	let res = f();
+	FORCED_MODE.with(|forced| forced.set(previous));
+	res
+}
+
+// endregion: --- Global Mode
+
+// region:    --- Redact
+
+/// Returns the redacted summary of `content`, e.g., `[text: 1.2kB sha256:ab12cd34…]`,
+/// or the content as is when `mode` is `Off`.
+pub fn redact_text(label: &str, content: &str, mode: RedactionMode) -> String {
+	let size = format_size(content.len());
+	match mode {
+		RedactionMode::Off => content.to_string(),
+		RedactionMode::Hash => format!("[{label}: {size} sha256:{}…]", sha256_prefix(content)),
+		RedactionMode::LengthOnly => format!("[{label}: {size}]"),
+	}
+}
+
+/// Returns a copy of `value` with every string value redacted (keys and non-string values are kept).
+pub fn redact_json(value: &Value, mode: RedactionMode) -> Value {
+	if mode.is_off() {
+		return value.clone();
+	}
+	match value {
+		Value::String(content) => Value::String(redact_text("text", content, mode)),
+		Value::Array(items) => Value::Array(items.iter().map(|item| redact_json(item, mode)).collect()),
+		Value::Object(map) => {
+			Value::Object(map.iter().map(|(key, value)| (key.clone(), redact_json(value, mode))).collect())
+		}
+		other => other.clone(),
+	}
+}
+
+/// Debug-formats as the string itself (no quotes), for redacted summaries in `Debug` impls.
+pub(crate) struct RedactedDebug(pub String);
+
+impl std::fmt::Debug for RedactedDebug {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+/// Redacts a raw body for tracing with the active mode.
+pub(crate) fn redact_body(body: &str) -> String {
+	redact_text("body", body, redaction_mode())
+}
+
+// endregion: --- Redact
+
+// region:    --- Support
+
+fn sha256_prefix(content: &str) -> String {
+	let digest = Sha256::digest(content.as_bytes());
+	digest.iter().take(4).map(|b| format!("{b:02x}")).collect()
+}
+
+fn format_size(len: usize) -> String {
+	match len {
+		0..1_000 => format!("{len}B"),
+		1_000..1_000_000 => format!("{:.1}kB", len as f64 / 1_000.0),
+		_ => format!("{:.1}MB", len as f64 / 1_000_000.0),
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_redaction_redact_text_modes() {
+		let content = "a".repeat(1_234);
+
+		assert_eq!(redact_text("text", &content, RedactionMode::Off), content);
+		assert_eq!(
+			redact_text("text", &content, RedactionMode::LengthOnly),
+			"[text: 1.2kB]"
+		);
+
+		let hashed = redact_text("text", &content, RedactionMode::Hash);
+		assert!(hashed.starts_with("[text: 1.2kB sha256:"), "{hashed}");
+		assert_eq!(hashed, redact_text("text", &content, RedactionMode::Hash));
+		assert_ne!(hashed, redact_text("text", "other content", RedactionMode::Hash));
+	}
+
+	#[test]
+	fn test_redaction_redact_json_strings_only() {
+		let value = json!({"message": {"content": "my secret prompt"}, "tokens": [1, 2], "done": true});
+
+		let redacted = redact_json(&value, RedactionMode::LengthOnly);
+
+		assert_eq!(
+			redacted,
+			json!({"message": {"content": "[text: 16B]"}, "tokens": [1, 2], "done": true})
+		);
+		assert!(!redacted.to_string().contains("secret"));
+	}
+
+	#[test]
+	fn test_redaction_forced_mode_restores_previous() {
+		let inner = with_forced_mode(RedactionMode::Hash, || {
+			with_forced_mode(RedactionMode::LengthOnly, redaction_mode);
+			redaction_mode()
+		});
+
+		assert_eq!(inner, RedactionMode::Hash);
+		assert_eq!(FORCED_MODE.with(|forced| forced.get()), None);
+	}
+}
+
+// endregion: --- Tests
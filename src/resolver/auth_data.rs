@@ -18,8 +18,7 @@ pub enum AuthData {
 	},
 
 	/// The key names/values when a credential has multiple pieces of credential information.
-	/// This will be adapter-specific.
-	/// NOTE: Not used yet.
+	/// This will be adapter-specific (e.g., Qianfan `access_key` / `secret_key`).
 	MultiKeys(HashMap<String, String>),
 
 	None,
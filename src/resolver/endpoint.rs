@@ -1,11 +1,27 @@
+use crate::adapter::ServiceType;
+use crate::resolver::{Error, Result};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// A construct to store the endpoint of a service.
 /// It is designed to be efficiently clonable.
-/// For now, it supports only `base_url`, but it may later have other URLs per "service name".
+/// It holds the `base_url`, and optional per `ServiceType` path overrides (e.g., a proxy serving chat at a custom path).
 #[derive(Debug, Clone)]
 pub struct Endpoint {
 	inner: EndpointInner,
+	service_paths: Option<Arc<HashMap<ServiceType, String>>>,
+	stream_transport: StreamTransport,
+}
+
+/// The wire format of the streamed chat responses of an endpoint.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StreamTransport {
+	/// Server-sent events (`text/event-stream`, `data: {..}` events). The provider default.
+	#[default]
+	Sse,
+	/// Newline-delimited JSON chunks (one chunk per line, no `data:` prefix), e.g., gateways streaming
+	/// over chunked JSON lines because a proxy buffers the SSE responses.
+	JsonLines,
 }
 
 #[derive(Debug, Clone)]
@@ -19,14 +35,54 @@ impl Endpoint {
 	pub fn from_static(url: &'static str) -> Self {
 		Endpoint {
 			inner: EndpointInner::Static(url),
+			service_paths: None,
+			stream_transport: StreamTransport::Sse,
 		}
 	}
 
 	pub fn from_owned(url: impl Into<Arc<str>>) -> Self {
 		Endpoint {
 			inner: EndpointInner::Owned(url.into()),
+			service_paths: None,
+			stream_transport: StreamTransport::Sse,
 		}
 	}
+
+	/// Same as `from_owned`, but returns `Error::InvalidEndpoint` when the url is not a valid http(s) url
+	/// (e.g., a custom endpoint read from a config file).
+	pub fn try_from_owned(url: impl Into<Arc<str>>) -> Result<Self> {
+		let endpoint = Self::from_owned(url);
+		endpoint.validate()?;
+		Ok(endpoint)
+	}
+
+	/// Returns an `EndpointBuilder`, to compose the base url from a base, a path prefix, and query params
+	/// (e.g., for proxies).
+	pub fn builder() -> EndpointBuilder {
+		EndpointBuilder::default()
+	}
+}
+
+/// Setters
+impl Endpoint {
+	/// Overrides the path (relative to the `base_url`) used for the given service type.
+	///
+	/// e.g., `.with_service_path(ServiceType::Chat, "v2/chat")` instead of the adapter default `chat/completions`.
+	///
+	/// Note: Honored by the OpenAI-compatible adapters.
+	pub fn with_service_path(mut self, service_type: ServiceType, path: impl Into<String>) -> Self {
+		let service_paths = self.service_paths.get_or_insert_with(Default::default);
+		Arc::make_mut(service_paths).insert(service_type, path.into());
+		self
+	}
+
+	/// Sets the wire format of the streamed chat responses (default `StreamTransport::Sse`).
+	///
+	/// Note: Honored by the OpenAI-compatible adapters.
+	pub fn with_stream_transport(mut self, stream_transport: StreamTransport) -> Self {
+		self.stream_transport = stream_transport;
+		self
+	}
 }
 
 /// Getters
@@ -37,4 +93,115 @@ impl Endpoint {
 			EndpointInner::Owned(url) => url,
 		}
 	}
+
+	/// Returns `Error::InvalidEndpoint` when the base url cannot be parsed, is not http(s), or has no host.
+	pub fn validate(&self) -> Result<()> {
+		validate_base_url(self.base_url())
+	}
+
+	/// Returns the path override for the given service type, if any.
+	///
+	/// Note: `ServiceType::ChatStream` falls back to the `ServiceType::Chat` override.
+	pub fn service_path(&self, service_type: ServiceType) -> Option<&str> {
+		let service_paths = self.service_paths.as_ref()?;
+		service_paths
+			.get(&service_type)
+			.or_else(|| match service_type {
+				ServiceType::ChatStream => service_paths.get(&ServiceType::Chat),
+				_ => None,
+			})
+			.map(|path| path.as_str())
+	}
+
+	pub fn stream_transport(&self) -> StreamTransport {
+		self.stream_transport
+	}
+}
+
+// region:    --- Support
+
+fn validate_base_url(base_url: &str) -> Result<()> {
+	let invalid = |cause: String| Error::InvalidEndpoint {
+		url: base_url.to_string(),
+		cause,
+	};
+	let url = reqwest::Url::parse(base_url).map_err(|err| invalid(err.to_string()))?;
+	if !matches!(url.scheme(), "http" | "https") {
+		return Err(invalid(format!(
+			"Unsupported scheme '{}' (expected http or https)",
+			url.scheme()
+		)));
+	}
+	if url.host_str().is_none_or(str::is_empty) {
+		return Err(invalid("No host".to_string()));
+	}
+	Ok(())
+}
+
+// endregion: --- Support
+
+// region:    --- EndpointBuilder
+
+/// Builds a normalized `Endpoint` base url from its parts.
+///
+/// e.g., `Endpoint::builder().base("https://proxy.corp").path_prefix("llm/openai/v1").query_param("api-version", "2024-06-01")`
+/// builds `https://proxy.corp/llm/openai/v1/?api-version=2024-06-01`, and the adapters then join their service
+/// path (e.g., `https://proxy.corp/llm/openai/v1/chat/completions?api-version=2024-06-01`).
+#[derive(Debug, Clone, Default)]
+pub struct EndpointBuilder {
+	base: Option<String>,
+	path_prefix: Option<String>,
+	query_params: Vec<(String, String)>,
+}
+
+impl EndpointBuilder {
+	/// Sets the base url (scheme, host, and eventual path and query params).
+	pub fn base(mut self, base: impl Into<String>) -> Self {
+		self.base = Some(base.into());
+		self
+	}
+
+	/// Sets the path appended to the base url path (leading and trailing `/` are ignored).
+	pub fn path_prefix(mut self, path_prefix: impl Into<String>) -> Self {
+		self.path_prefix = Some(path_prefix.into());
+		self
+	}
+
+	/// Appends a query param (url-encoded), sent with every service url.
+	pub fn query_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.query_params.push((name.into(), value.into()));
+		self
+	}
+
+	/// Builds the `Endpoint`, with a base url path ending with `/`.
+	pub fn build(self) -> Result<Endpoint> {
+		let base = self.base.ok_or_else(|| Error::InvalidEndpoint {
+			url: String::new(),
+			cause: "No base url (use `.base(..)`)".to_string(),
+		})?;
+		validate_base_url(&base)?;
+		let mut url = reqwest::Url::parse(&base).map_err(|err| Error::InvalidEndpoint {
+			url: base.clone(),
+			cause: err.to_string(),
+		})?;
+		url.set_fragment(None);
+
+		let mut path = url.path().trim_end_matches('/').to_string();
+		if let Some(path_prefix) = self.path_prefix.as_deref().map(|prefix| prefix.trim_matches('/'))
+			&& !path_prefix.is_empty()
+		{
+			path.push('/');
+			path.push_str(path_prefix);
+		}
+		path.push('/');
+		url.set_path(&path);
+
+		if !self.query_params.is_empty() {
+			url.query_pairs_mut().extend_pairs(&self.query_params);
+		}
+
+		Ok(Endpoint::from_owned(url.to_string()))
+	}
 }
+
+// endregion: --- EndpointBuilder
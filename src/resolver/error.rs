@@ -15,6 +15,9 @@ pub enum Error {
 	/// The `AuthData` is not a single value.
 	ResolverAuthDataNotSingleValue,
 
+	/// The endpoint url cannot be built (see `EndpointBuilder::build`).
+	InvalidEndpoint { url: String, cause: String },
+
 	/// Custom error message.
 	#[from]
 	Custom(String),
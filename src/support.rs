@@ -1,8 +1,10 @@
+use crate::chat::{ContentPart, MessageContent};
+use crate::{Error, Result};
 use serde::{Deserialize, Deserializer};
 
 // region:    --- Serde Support
 
-pub fn zero_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+pub fn zero_as_none<'de, D, T>(deserializer: D) -> core::result::Result<Option<T>, D::Error>
 where
 	D: Deserializer<'de>,
 	T: Deserialize<'de> + PartialEq + Default,
@@ -34,4 +36,437 @@ pub fn combine_text_with_empty_line(combined: &mut String, text: &str) {
 	combined.push_str(text);
 }
 
+/// Strips the markdown code fence wrapping `txt` (e.g., ```` ```json ```` … ```` ``` ````), and the surrounding whitespace.
+///
+/// Only the opening fence (with its optional language tag) and the closing one are removed,
+/// so the backticks inside the content (e.g., in string values) are kept.
+pub fn strip_code_fence(txt: &str) -> &str {
+	let trimmed = txt.trim();
+	let Some(rest) = trimmed.strip_prefix("```") else {
+		return trimmed;
+	};
+	let rest = rest.trim_start_matches(|c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+	let rest = rest.strip_suffix("```").unwrap_or(rest);
+	rest.trim()
+}
+
+/// Strips the code fences of the text parts of `content` (see `strip_code_fence`).
+pub fn strip_code_fences_in_texts(content: &mut MessageContent) {
+	for part in content.iter_mut() {
+		if let ContentPart::Text(text) = part {
+			let stripped = strip_code_fence(text);
+			if stripped.len() != text.len() {
+				*text = stripped.to_string();
+			}
+		}
+	}
+}
+
+/// Max bytes of a body embedded in a log line or an error message (see `truncate_for_log`).
+pub const MAX_LOGGED_BODY_BYTES: usize = 4 * 1024;
+
+/// Returns the longest prefix of `txt` of at most `max_bytes`, cut on a char boundary
+/// (so a multi-byte UTF-8 char is never split).
+pub fn truncate_utf8(txt: &str, max_bytes: usize) -> &str {
+	if txt.len() <= max_bytes {
+		return txt;
+	}
+	let mut end = max_bytes;
+	while !txt.is_char_boundary(end) {
+		end -= 1;
+	}
+	&txt[..end]
+}
+
+/// Returns `txt` truncated to `MAX_LOGGED_BODY_BYTES` (UTF-8 safe), with a truncation marker when cut,
+/// for the bodies embedded in the logs and error messages.
+pub fn truncate_for_log(txt: &str) -> String {
+	let truncated = truncate_utf8(txt, MAX_LOGGED_BODY_BYTES);
+	if truncated.len() == txt.len() {
+		txt.to_string()
+	} else {
+		format!("{truncated}… [truncated, {} bytes total]", txt.len())
+	}
+}
+
 // endregion: --- Text Support
+
+// region:    --- Token Estimate Support
+
+/// Approximate bytes per token of the client-side token estimates (no tokenizer).
+pub const APPROX_BYTES_PER_TOKEN: usize = 4;
+
+/// Approximate tokens of a content of `bytes` length (rounded up).
+pub fn approx_tokens(bytes: usize) -> u32 {
+	u32::try_from(bytes.div_ceil(APPROX_BYTES_PER_TOKEN)).unwrap_or(u32::MAX)
+}
+
+/// Approximate bytes of `tokens` tokens (the inverse of `approx_tokens`).
+pub fn approx_bytes(tokens: u32) -> usize {
+	(tokens as usize).saturating_mul(APPROX_BYTES_PER_TOKEN)
+}
+
+// endregion: --- Token Estimate Support
+
+// region:    --- Url Support
+
+/// Appends the `params` to the query of `url`.
+///
+/// - Values are URL-encoded (form encoding, e.g., space as `+` and `+` as `%2B`).
+/// - Existing query parameters are preserved, except the ones with the same name as one of `params`,
+///   which get overridden.
+pub fn url_with_extra_query_params(url: &str, params: &[(String, String)]) -> Result<String> {
+	if params.is_empty() {
+		return Ok(url.to_string());
+	}
+
+	let mut full_url =
+		reqwest::Url::parse(url).map_err(|err| Error::Internal(format!("Cannot parse url: {url}. Cause:\n{err}")))?;
+
+	let existing_params: Vec<(String, String)> = full_url
+		.query_pairs()
+		.filter(|(name, _)| !params.iter().any(|(param_name, _)| param_name == name))
+		.map(|(name, value)| (name.into_owned(), value.into_owned()))
+		.collect();
+
+	full_url
+		.query_pairs_mut()
+		.clear()
+		.extend_pairs(existing_params.iter())
+		.extend_pairs(params.iter());
+
+	Ok(full_url.to_string())
+}
+
+// endregion: --- Url Support
+
+// region:    --- Json Support
+
+/// Leniently parses a JSON prefix (e.g., tool call arguments still streaming), by closing its open strings,
+/// arrays, and objects. A trailing incomplete member (dangling key, `:`, or partial literal) is dropped.
+///
+/// Returns `None` when no valid JSON value can be recovered from the prefix (e.g., empty).
+pub fn parse_partial_json(txt: &str) -> Option<serde_json::Value> {
+	let mut in_string = false;
+	let mut escaped = false;
+	// Positions where the prefix can be cut, keeping a valid value (after `{`, `[`, and before `,`)
+	let mut cut_points: Vec<usize> = Vec::new();
+
+	for (idx, c) in txt.char_indices() {
+		if in_string {
+			match c {
+				_ if escaped => escaped = false,
+				'\\' => escaped = true,
+				'"' => in_string = false,
+				_ => (),
+			}
+			continue;
+		}
+		match c {
+			'"' => in_string = true,
+			'{' | '[' => cut_points.push(idx + 1),
+			',' => cut_points.push(idx),
+			_ => (),
+		}
+	}
+
+	if let Some(value) = close_partial_json(txt) {
+		return Some(value);
+	}
+	cut_points.into_iter().rev().find_map(|idx| close_partial_json(&txt[..idx]))
+}
+
+/// Closes the open string, arrays, and objects of `txt` and parses it.
+fn close_partial_json(txt: &str) -> Option<serde_json::Value> {
+	let mut stack: Vec<char> = Vec::new();
+	let mut in_string = false;
+	let mut escaped = false;
+
+	for c in txt.chars() {
+		if in_string {
+			match c {
+				_ if escaped => escaped = false,
+				'\\' => escaped = true,
+				'"' => in_string = false,
+				_ => (),
+			}
+			continue;
+		}
+		match c {
+			'"' => in_string = true,
+			'{' => stack.push('}'),
+			'[' => stack.push(']'),
+			'}' | ']' => {
+				stack.pop();
+			}
+			_ => (),
+		}
+	}
+
+	let mut closed = txt.to_string();
+	if in_string {
+		// a dangling escape cannot be closed, so it is dropped
+		if escaped {
+			closed.pop();
+		}
+		closed.push('"');
+	}
+	closed.extend(stack.iter().rev());
+
+	serde_json::from_str(&closed).ok()
+}
+
+/// Deep merges `patch` into `target`: objects are merged key by key, and any other value (arrays included)
+/// replaces the target one.
+///
+/// Returns the JSON pointers (e.g., `/generationConfig/temperature`) of the target values replaced by a different value.
+pub fn deep_merge_json(target: &mut serde_json::Value, patch: &serde_json::Value) -> Vec<String> {
+	let mut overridden = Vec::new();
+	deep_merge_json_at(target, patch, String::new(), &mut overridden);
+	overridden
+}
+
+fn deep_merge_json_at(
+	target: &mut serde_json::Value,
+	patch: &serde_json::Value,
+	path: String,
+	overridden: &mut Vec<String>,
+) {
+	match (target, patch) {
+		(serde_json::Value::Object(target_map), serde_json::Value::Object(patch_map)) => {
+			for (key, patch_value) in patch_map {
+				match target_map.get_mut(key) {
+					Some(target_value) => {
+						// JSON pointer escaping (RFC 6901)
+						let key_path = format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"));
+						deep_merge_json_at(target_value, patch_value, key_path, overridden);
+					}
+					None => {
+						target_map.insert(key.clone(), patch_value.clone());
+					}
+				}
+			}
+		}
+		(target, patch) => {
+			if target != patch {
+				overridden.push(path);
+				*target = patch.clone();
+			}
+		}
+	}
+}
+
+// endregion: --- Json Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ModelIden;
+	use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType};
+	use crate::resolver::Endpoint;
+	use serde_json::json;
+
+	fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+		pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+	}
+
+	#[test]
+	fn test_url_extra_query_params_openai_endpoint() -> Result<()> {
+		let model = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let endpoint = Endpoint::from_static("https://my-azure.openai.azure.com/openai/v1/");
+		let url = AdapterDispatcher::get_service_url(&model, ServiceType::Chat, endpoint)?;
+
+		let url = url_with_extra_query_params(&url, &params(&[("api-version", "2024-10-21")]))?;
+
+		assert_eq!(
+			url,
+			"https://my-azure.openai.azure.com/openai/v1/chat/completions?api-version=2024-10-21"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_url_extra_query_params_merges_existing_query() -> Result<()> {
+		let model = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let endpoint = Endpoint::from_static("https://proxy.corp/v1/?tenant=acme&api-version=old");
+		let url = AdapterDispatcher::get_service_url(&model, ServiceType::ChatStream, endpoint)?;
+
+		let url = url_with_extra_query_params(&url, &params(&[("api-version", "2024-10-21")]))?;
+
+		assert_eq!(
+			url,
+			"https://proxy.corp/v1/chat/completions?tenant=acme&api-version=2024-10-21"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_url_extra_query_params_gemini_endpoint_encoding() -> Result<()> {
+		let model = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		let endpoint = Endpoint::from_static("https://generativelanguage.googleapis.com/v1beta/");
+		let url = AdapterDispatcher::get_service_url(&model, ServiceType::Chat, endpoint)?;
+
+		let url = url_with_extra_query_params(&url, &params(&[("key", "a b+c"), ("label", "x?y&z")]))?;
+
+		assert_eq!(
+			url,
+			"https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:generateContent?key=a+b%2Bc&label=x%3Fy%26z"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_url_extra_query_params_empty_is_noop() -> Result<()> {
+		let url = "https://api.openai.com/v1/chat/completions?x=1";
+
+		assert_eq!(url_with_extra_query_params(url, &[])?, url);
+		Ok(())
+	}
+
+	#[test]
+	fn test_truncate_utf8_multi_byte_boundaries() {
+		// "é" is 2 bytes, "€" is 3 bytes, "🦀" is 4 bytes
+		let txt = "aé€🦀";
+		let cases = [
+			(0, ""),
+			(1, "a"),
+			(2, "a"), // inside "é"
+			(3, "aé"),
+			(4, "aé"), // inside "€"
+			(5, "aé"), // inside "€"
+			(6, "aé€"),
+			(9, "aé€"), // inside "🦀"
+			(10, "aé€🦀"),
+			(100, "aé€🦀"),
+		];
+
+		for (max_bytes, expected) in cases {
+			assert_eq!(truncate_utf8(txt, max_bytes), expected, "for max_bytes {max_bytes}");
+		}
+	}
+
+	#[test]
+	fn test_truncate_for_log_marker() {
+		let short = "short body";
+		assert_eq!(truncate_for_log(short), short);
+
+		// a 3-byte char straddling the limit is dropped, not split
+		let long = format!("{}€tail", "x".repeat(MAX_LOGGED_BODY_BYTES - 1));
+		let truncated = truncate_for_log(&long);
+		assert!(truncated.starts_with(&"x".repeat(MAX_LOGGED_BODY_BYTES - 1)));
+		assert!(!truncated.contains('€'));
+		assert!(truncated.ends_with(&format!("… [truncated, {} bytes total]", long.len())));
+	}
+
+	#[test]
+	fn test_strip_code_fence_cases() {
+		let cases = [
+			("```json\n{\"a\": 1}\n```", r#"{"a": 1}"#),
+			("```\n{\"a\": 1}\n```", r#"{"a": 1}"#),
+			("  \n```JSON\n[1, 2]\n```  \n", "[1, 2]"),
+			// -- Missing closing fence
+			("```json\n{\"a\": 1}", r#"{"a": 1}"#),
+			// -- Nested backticks in string values are kept
+			(
+				"```json\n{\"code\": \"```rust\\nfn main() {}\\n```\", \"inline\": \"`x`\"}\n```",
+				r#"{"code": "```rust\nfn main() {}\n```", "inline": "`x`"}"#,
+			),
+			// -- Not fenced, only trimmed
+			("  {\"a\": \"```\"}\n", r#"{"a": "```"}"#),
+		];
+
+		for (txt, expected) in cases {
+			assert_eq!(strip_code_fence(txt), expected, "for {txt}");
+		}
+	}
+
+	#[test]
+	fn test_parse_partial_json_closes_open_values() {
+		let cases = [
+			(r#"{"city": "Pa"#, json!({"city": "Pa"})),
+			(r#"{"city": "Paris", "coun"#, json!({"city": "Paris"})),
+			(r#"{"city": "Paris", "country":"#, json!({"city": "Paris"})),
+			(r#"{"city": "Paris","#, json!({"city": "Paris"})),
+			(r#"{"tags": ["a", "b"#, json!({"tags": ["a", "b"]})),
+			(r#"{"n": 12"#, json!({"n": 12})),
+			(r#"{"ok": tr"#, json!({})),
+			(r#"{"path": "C:\"#, json!({"path": "C:"})),
+			(
+				r#"{"a": {"b": "c"}, "d": [1, {"e"#,
+				json!({"a": {"b": "c"}, "d": [1, {}]}),
+			),
+			(r#"{"city": "Paris"}"#, json!({"city": "Paris"})),
+		];
+
+		for (txt, expected) in cases {
+			assert_eq!(parse_partial_json(txt), Some(expected), "for {txt}");
+		}
+		assert_eq!(parse_partial_json(""), None);
+	}
+
+	#[test]
+	fn test_deep_merge_json_cases() {
+		// (target, patch, expected, expected overridden pointers)
+		let cases = [
+			// -- New keys are added
+			(
+				json!({"model": "m"}),
+				json!({"user": "u1"}),
+				json!({"model": "m", "user": "u1"}),
+				vec![],
+			),
+			// -- Nested objects are merged
+			(
+				json!({"generationConfig": {"temperature": 0.2, "topP": 0.9}}),
+				json!({"generationConfig": {"temperature": 0.7, "seed": 1}}),
+				json!({"generationConfig": {"temperature": 0.7, "topP": 0.9, "seed": 1}}),
+				vec!["/generationConfig/temperature"],
+			),
+			// -- Arrays are replaced, not concatenated
+			(
+				json!({"stop": ["a", "b"]}),
+				json!({"stop": ["c"]}),
+				json!({"stop": ["c"]}),
+				vec!["/stop"],
+			),
+			// -- Type changes replace the whole value
+			(
+				json!({"response_format": {"type": "json_object"}}),
+				json!({"response_format": "text"}),
+				json!({"response_format": "text"}),
+				vec!["/response_format"],
+			),
+			// -- Same values are not reported
+			(
+				json!({"stream": true}),
+				json!({"stream": true}),
+				json!({"stream": true}),
+				vec![],
+			),
+			// -- Null replaces (does not remove)
+			(
+				json!({"seed": 1}),
+				json!({"seed": null}),
+				json!({"seed": null}),
+				vec!["/seed"],
+			),
+			// -- Keys are escaped as JSON pointers
+			(
+				json!({"a/b": {"c~d": 1}}),
+				json!({"a/b": {"c~d": 2}}),
+				json!({"a/b": {"c~d": 2}}),
+				vec!["/a~1b/c~0d"],
+			),
+		];
+
+		for (mut target, patch, expected, expected_overridden) in cases {
+			let overridden = deep_merge_json(&mut target, &patch);
+			assert_eq!(target, expected, "for patch {patch}");
+			assert_eq!(overridden, expected_overridden, "for patch {patch}");
+		}
+	}
+}
+
+// endregion: --- Tests
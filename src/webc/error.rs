@@ -1,3 +1,4 @@
+use crate::support::truncate_for_log;
 use derive_more::{Display, From};
 use reqwest::{StatusCode, header::HeaderMap};
 use value_ext::JsonValueExtError;
@@ -8,19 +9,32 @@ pub type Result<T> = core::result::Result<T, Error>;
 #[allow(missing_docs)]
 #[derive(Debug, From, Display)]
 pub enum Error {
-	#[display("Response content type '{content_type}' is not JSON as expected. Response body:\n{body}")]
+	#[display(
+		"Response content type '{content_type}' is not JSON as expected. Response body:\n{}",
+		truncate_for_log(body)
+	)]
 	ResponseFailedNotJson { content_type: String, body: String },
 
-	#[display("Response was invalid json. Cause:\n'{cause}'\nBody:\n{body}")]
+	#[display("Response was invalid json. Cause:\n'{cause}'\nBody:\n{}", truncate_for_log(body))]
 	ResponseFailedInvalidJson { body: String, cause: String },
 
-	#[display("Request failed with status code '{status}'. Response body:\n{body}")]
+	#[display(
+		"Request failed with status code '{status}'. Response body:\n{}",
+		truncate_for_log(body)
+	)]
 	ResponseFailedStatus {
 		status: StatusCode,
 		body: String,
 		headers: Box<HeaderMap>,
 	},
 
+	#[display("Response body exceeded the maximum size of {max_bytes} bytes")]
+	ResponseTooLarge {
+		max_bytes: usize,
+		/// The body received up to the limit.
+		partial_body: String,
+	},
+
 	// -- Utils
 	#[display("JSON value extension error: {_0}")]
 	#[from]
@@ -41,6 +55,14 @@ pub enum Error {
 // 	}
 // }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::JsonValueExt(err) => Some(err),
+			Error::Reqwest(err) => Some(err),
+			_ => None,
+		}
+	}
+}
 
 // endregion: --- Error Boilerplate
@@ -6,9 +6,13 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 /// Simple EventSource stream implementation that uses WebStream as a foundation.
+///
+/// NOTE: With `new_json_lines`, the body is read as newline-delimited JSON chunks (no `data:` prefix),
+///       each line being a `message` event, so the streamers parse the same events as with SSE.
 pub struct EventSourceStream {
 	inner: WebStream,
 	opened: bool,
+	json_lines: bool,
 }
 
 #[derive(Debug)]
@@ -29,6 +33,42 @@ impl EventSourceStream {
 		Self {
 			inner: WebStream::new_with_delimiter(reqwest_builder, "\n\n"),
 			opened: false,
+			json_lines: false,
+		}
+	}
+
+	/// Returns an event stream over a newline-delimited JSON body (one event data per line).
+	pub fn new_json_lines(reqwest_builder: RequestBuilder) -> Self {
+		Self {
+			inner: WebStream::new_with_delimiter(reqwest_builder, "\n"),
+			opened: false,
+			json_lines: true,
+		}
+	}
+
+	/// Sets whether the invalid UTF-8 bytes are decoded as `U+FFFD` (default) rather than failing the stream.
+	pub fn with_lossy_utf8(mut self, lossy_utf8: bool) -> Self {
+		self.inner = self.inner.with_lossy_utf8(lossy_utf8);
+		self
+	}
+
+	/// Returns an event source over the given response body chunks (no request), for the streamer tests.
+	#[cfg(test)]
+	pub(crate) fn from_body_chunks(chunks: Vec<Vec<u8>>) -> Self {
+		Self {
+			inner: WebStream::from_body_chunks(crate::webc::StreamMode::Delimiter("\n\n"), chunks),
+			opened: false,
+			json_lines: false,
+		}
+	}
+
+	/// Same as `from_body_chunks`, for a newline-delimited JSON body.
+	#[cfg(test)]
+	pub(crate) fn from_json_lines_body_chunks(chunks: Vec<Vec<u8>>) -> Self {
+		Self {
+			inner: WebStream::from_body_chunks(crate::webc::StreamMode::Delimiter("\n"), chunks),
+			opened: false,
+			json_lines: true,
 		}
 	}
 }
@@ -50,6 +90,18 @@ impl Stream for EventSourceStream {
 			let nx = Pin::new(&mut this.inner).poll_next(cx);
 
 			match nx {
+				Poll::Ready(Some(Ok(raw_event))) if this.json_lines => {
+					// One event per line (a `data:` prefix is tolerated)
+					let line = raw_event.trim();
+					let data = line.strip_prefix("data:").map(str::trim).unwrap_or(line);
+					if data.is_empty() {
+						continue;
+					}
+					return Poll::Ready(Some(Ok(Event::Message(Message {
+						event: "message".to_string(),
+						data: data.to_string(),
+					}))));
+				}
 				Poll::Ready(Some(Ok(raw_event))) => {
 					let mut event = "message".to_string();
 					let mut data = String::new();
@@ -86,3 +138,31 @@ impl Stream for EventSourceStream {
 		}
 	}
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::StreamExt;
+
+	#[tokio::test]
+	async fn test_event_source_json_lines_one_message_per_line() {
+		let body = "{\"n\":1}\n\ndata: {\"n\":2}\n{\"n\":3}";
+		let stream = EventSourceStream::from_json_lines_body_chunks(vec![body.as_bytes().to_vec()]);
+
+		let datas: Vec<String> = stream
+			.filter_map(|event| async move {
+				match event.expect("event should parse") {
+					Event::Message(message) => Some(message.data),
+					Event::Open => None,
+				}
+			})
+			.collect()
+			.await;
+
+		assert_eq!(datas, vec![r#"{"n":1}"#, r#"{"n":2}"#, r#"{"n":3}"#]);
+	}
+}
+
+// endregion: --- Tests
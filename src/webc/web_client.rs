@@ -1,6 +1,8 @@
 use crate::Headers;
+use crate::redaction::redact_body;
 use crate::webc::{Error, Result};
 use reqwest::header::HeaderMap;
+use reqwest::multipart::Form;
 use reqwest::{Method, RequestBuilder, StatusCode};
 use serde_json::Value;
 
@@ -64,6 +66,63 @@ impl WebClient {
 		Ok(response)
 	}
 
+	/// POST like `do_post`, but fails with `Error::ResponseTooLarge` when the response body exceeds `max_body_bytes`
+	/// (the body is read by chunks, so an oversized body is not held in memory).
+	pub async fn do_post_limited(
+		&self,
+		url: &str,
+		headers: &Headers,
+		content: &Value,
+		max_body_bytes: usize,
+	) -> Result<WebResponse> {
+		let reqwest_builder = self.new_req_builder(url, headers, content)?;
+
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let response = WebResponse::from_reqwest_response_limited(reqwest_res, Some(max_body_bytes)).await?;
+
+		Ok(response)
+	}
+
+	/// POST a `multipart/form-data` body (e.g., file uploads). The response is expected to be JSON.
+	pub async fn do_post_multipart(&self, url: &str, headers: &Headers, form: Form) -> Result<WebResponse> {
+		let mut reqwest_builder = self.reqwest_client.request(Method::POST, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+		let reqwest_res = reqwest_builder.multipart(form).send().await?;
+
+		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
+
+		Ok(response)
+	}
+
+	pub async fn do_delete(&self, url: &str, headers: &Headers) -> Result<WebResponse> {
+		let mut reqwest_builder = self.reqwest_client.request(Method::DELETE, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
+
+		Ok(response)
+	}
+
+	/// GET a raw (non-JSON) body, e.g., file content.
+	pub async fn do_get_bytes(&self, url: &str, headers: &Headers) -> Result<Vec<u8>> {
+		let mut reqwest_builder = self.reqwest_client.request(Method::GET, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let reqwest_res = WebResponse::ensure_success(reqwest_res).await?;
+		let bytes = reqwest_res.bytes().await?;
+
+		Ok(bytes.to_vec())
+	}
+
 	pub fn new_req_builder(&self, url: &str, headers: &Headers, content: &Value) -> Result<RequestBuilder> {
 		let method = Method::POST;
 
@@ -87,6 +146,7 @@ impl WebClient {
 pub struct WebResponse {
 	#[allow(unused)]
 	pub status: StatusCode,
+	pub headers: HeaderMap,
 	pub body: Value,
 }
 
@@ -95,13 +155,46 @@ impl WebResponse {
 	/// Note 2: Currently, the WebResponse holds a Value (parsed from the entire body), and then the caller
 	///         can cherry-pick/deserialize further. In the future, we might consider returning `body: String`
 	///         to enable more optimized parsing, allowing for selective parsing constrained by the structure.
-	pub(crate) async fn from_reqwest_response(mut res: reqwest::Response) -> Result<WebResponse> {
+	pub(crate) async fn from_reqwest_response(res: reqwest::Response) -> Result<WebResponse> {
+		Self::from_reqwest_response_limited(res, None).await
+	}
+
+	/// Same as `from_reqwest_response`, with an optional maximum body size (see `read_body_limited`).
+	pub(crate) async fn from_reqwest_response_limited(
+		res: reqwest::Response,
+		max_body_bytes: Option<usize>,
+	) -> Result<WebResponse> {
+		let mut res = Self::ensure_success(res).await?;
+		let status = res.status();
+
+		// Move the headers into a new HeaderMap
+		let headers = res.headers_mut().drain().filter_map(|(n, v)| n.map(|n| (n, v)));
+		let header_map = HeaderMap::from_iter(headers);
+
+		// Capture the body
+		let ct = header_map.get("content-type").and_then(|v| v.to_str().ok()).unwrap_or_default();
+		let body = match max_body_bytes {
+			Some(max_bytes) => Self::read_body_limited(res, max_bytes).await?,
+			None => res.text().await?,
+		};
+
+		let body = Self::parse_json_body(ct, body)?;
+
+		Ok(WebResponse {
+			status,
+			headers: header_map,
+			body,
+		})
+	}
+
+	/// Returns the response as is if the status is a success, otherwise `Error::ResponseFailedStatus` with the body.
+	async fn ensure_success(res: reqwest::Response) -> Result<reqwest::Response> {
 		let status = res.status();
 
 		if !status.is_success() {
 			let headers = res.headers().clone();
 			let body = res.text().await?;
-			tracing::trace!("AI Response failed. Body:\n{body}");
+			tracing::trace!("AI Response failed. Body:\n{}", redact_body(&body));
 			return Err(Error::ResponseFailedStatus {
 				status,
 				body,
@@ -109,30 +202,153 @@ impl WebResponse {
 			});
 		}
 
-		// Move the headers into a new HeaderMap
-		let headers = res.headers_mut().drain().filter_map(|(n, v)| n.map(|n| (n, v)));
-		let header_map = HeaderMap::from_iter(headers);
+		Ok(res)
+	}
 
-		// Capture the body
-		let ct = header_map.get("content-type").and_then(|v| v.to_str().ok()).unwrap_or_default();
-		let body = res.text().await?;
+	/// Reads the body by chunks, and fails with `Error::ResponseTooLarge` (with the body up to the limit)
+	/// as soon as it exceeds `max_bytes`.
+	async fn read_body_limited(mut res: reqwest::Response, max_bytes: usize) -> Result<String> {
+		let mut body: Vec<u8> = Vec::new();
+		while let Some(chunk) = res.chunk().await? {
+			if body.len() + chunk.len() > max_bytes {
+				body.extend_from_slice(&chunk[..max_bytes - body.len()]);
+				return Err(Error::ResponseTooLarge {
+					max_bytes,
+					partial_body: String::from_utf8_lossy(&body).into_owned(),
+				});
+			}
+			body.extend_from_slice(&chunk);
+		}
 
-		let body = if ct.starts_with("application/json") {
-			tracing::trace!("AI Response body:\n{body}");
-			let value: Value = serde_json::from_str(&body).map_err(|err| Error::ResponseFailedInvalidJson {
-				body,
-				cause: err.to_string(),
-			})?;
-			value
+		Ok(String::from_utf8_lossy(&body).into_owned())
+	}
+
+	/// Parses a non-streaming response body into a single JSON value.
+	///
+	/// Some proxies return `application/x-ndjson` (or chunked, concatenated JSON objects under
+	/// `application/json`) even for non-stream calls. In this case, the objects are the stream chunks:
+	/// the last one is selected (e.g., Ollama `"done": true` object, with the stats), with the
+	/// `message.content` deltas of all the chunks concatenated (the last one usually has an empty content).
+	fn parse_json_body(content_type: &str, body: String) -> Result<Value> {
+		if content_type.starts_with("application/json") {
+			tracing::trace!("AI Response body:\n{}", redact_body(&body));
+			match serde_json::from_str::<Value>(&body) {
+				Ok(value) => Ok(value),
+				// Might be multiple concatenated JSON objects (chunked JSON)
+				Err(err) => match parse_json_chunks(&body) {
+					Some(value) => Ok(value),
+					None => Err(Error::ResponseFailedInvalidJson {
+						body,
+						cause: err.to_string(),
+					}),
+				},
+			}
+		} else if is_ndjson_content_type(content_type) {
+			tracing::trace!("AI Response body (ndjson):\n{}", redact_body(&body));
+			match parse_json_chunks(&body) {
+				Some(value) => Ok(value),
+				None => Err(Error::ResponseFailedInvalidJson {
+					body,
+					cause: "No valid JSON object found in ndjson body".to_string(),
+				}),
+			}
 		} else {
-			return Err(Error::ResponseFailedNotJson {
-				content_type: ct.to_string(),
+			Err(Error::ResponseFailedNotJson {
+				content_type: content_type.to_string(),
 				body,
-			});
-		};
+			})
+		}
+	}
+}
+
+// region:    --- Support
+
+fn is_ndjson_content_type(content_type: &str) -> bool {
+	content_type.starts_with("application/x-ndjson")
+		|| content_type.starts_with("application/ndjson")
+		|| content_type.starts_with("application/jsonl")
+		|| content_type.starts_with("application/x-jsonlines")
+}
 
-		Ok(WebResponse { status, body })
+/// Returns the last JSON value of a whitespace/newline separated sequence of JSON values (the chunks),
+/// with its `message.content` set to the concatenated `message.content` deltas of all the chunks.
+/// Returns `None` if the body is empty or if any of the values is malformed.
+fn parse_json_chunks(body: &str) -> Option<Value> {
+	let mut chunks = serde_json::Deserializer::from_str(body)
+		.into_iter::<Value>()
+		.collect::<core::result::Result<Vec<_>, _>>()
+		.ok()?;
+
+	let content: Option<String> = chunks
+		.iter()
+		.filter_map(|chunk| chunk.pointer("/message/content").and_then(Value::as_str))
+		.fold(None, |acc, delta| Some(acc.unwrap_or_default() + delta));
+
+	let mut last = chunks.pop()?;
+	if let Some(content) = content
+		&& let Some(last_content) = last.pointer_mut("/message/content")
+	{
+		*last_content = Value::String(content);
 	}
+	Some(last)
 }
 
+// endregion: --- Support
+
 // endregion: --- WebResponse
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_json_body_ndjson_selects_last_object() {
+		let body = r#"{"model":"llama3","message":{"role":"assistant","content":"Hel"},"done":false}
+{"model":"llama3","message":{"role":"assistant","content":"lo"},"done":true}
+"#;
+
+		let value = WebResponse::parse_json_body("application/x-ndjson", body.to_string()).expect("should parse");
+
+		assert_eq!(value["done"], true);
+		assert_eq!(value["message"]["content"], "Hello");
+	}
+
+	#[test]
+	fn test_parse_json_body_ndjson_concatenates_content_deltas() {
+		// Ollama style chunks: the content deltas, then the final `done` object with an empty content and the stats
+		let body = r#"{"model":"llama3","message":{"role":"assistant","content":"The sky"},"done":false}
+{"model":"llama3","message":{"role":"assistant","content":" is"},"done":false}
+{"model":"llama3","message":{"role":"assistant","content":" blue."},"done":false}
+{"model":"llama3","message":{"role":"assistant","content":""},"done":true,"eval_count":4}
+"#;
+
+		let value = WebResponse::parse_json_body("application/x-ndjson", body.to_string()).expect("should parse");
+
+		assert_eq!(value["done"], true);
+		assert_eq!(value["eval_count"], 4);
+		assert_eq!(value["message"]["content"], "The sky is blue.");
+	}
+
+	#[test]
+	fn test_parse_json_body_chunked_json_selects_last_object() {
+		let body = r#"{"id":"1","partial":true}{"id":"1","choices":[{"message":{"content":"hi"}}]}"#;
+
+		let value =
+			WebResponse::parse_json_body("application/json; charset=utf-8", body.to_string()).expect("should parse");
+
+		assert_eq!(value["choices"][0]["message"]["content"], "hi");
+	}
+
+	#[test]
+	fn test_parse_json_body_ndjson_malformed_is_clear_error() {
+		let body = "{\"done\":false}\n{\"done\":";
+
+		let err = WebResponse::parse_json_body("application/x-ndjson", body.to_string()).expect_err("should fail");
+
+		assert!(matches!(err, Error::ResponseFailedInvalidJson { .. }));
+	}
+}
+
+// endregion: --- Tests
@@ -21,6 +21,8 @@ pub struct WebStream {
 	response_future: Option<Pin<Box<dyn Future<Output = Result<Response, BoxError>> + Send>>>,
 	bytes_stream: Option<Pin<Box<dyn Stream<Item = Result<Bytes, BoxError>> + Send>>>,
 	pending_utf8_bytes: Vec<u8>,
+	// Invalid UTF-8 decoded with the replacement character (true), or failing the stream (false)
+	lossy_utf8: bool,
 	// If a poll was a partial message, then we keep the previous part
 	partial_message: Option<String>,
 	// If a poll retrieved multiple messages, we keep them to be sent in the next poll
@@ -42,6 +44,7 @@ impl WebStream {
 			response_future: None,
 			bytes_stream: None,
 			pending_utf8_bytes: Vec::new(),
+			lossy_utf8: true,
 			partial_message: None,
 			remaining_messages: None,
 		}
@@ -54,6 +57,32 @@ impl WebStream {
 			response_future: None,
 			bytes_stream: None,
 			pending_utf8_bytes: Vec::new(),
+			lossy_utf8: true,
+			partial_message: None,
+			remaining_messages: None,
+		}
+	}
+
+	/// Sets whether the invalid UTF-8 bytes are decoded as the replacement character (`U+FFFD`, default)
+	/// rather than failing the stream.
+	///
+	/// NOTE: A multi-byte character split across chunks is always reassembled (this is for the invalid bytes only).
+	pub fn with_lossy_utf8(mut self, lossy_utf8: bool) -> Self {
+		self.lossy_utf8 = lossy_utf8;
+		self
+	}
+
+	/// Returns a stream over the given response body chunks (no request), for the stream parsing tests.
+	#[cfg(test)]
+	pub(crate) fn from_body_chunks(stream_mode: StreamMode, chunks: Vec<Vec<u8>>) -> Self {
+		let bytes_stream = futures::stream::iter(chunks.into_iter().map(|chunk| Ok(Bytes::from(chunk))));
+		Self {
+			stream_mode,
+			reqwest_builder: None,
+			response_future: None,
+			bytes_stream: Some(Box::pin(bytes_stream)),
+			pending_utf8_bytes: Vec::new(),
+			lossy_utf8: true,
 			partial_message: None,
 			remaining_messages: None,
 		}
@@ -113,7 +142,9 @@ impl Stream for WebStream {
 			if let Some(ref mut stream) = this.bytes_stream {
 				match stream.as_mut().poll_next(cx) {
 					Poll::Ready(Some(Ok(bytes))) => {
-						let Some(buff_string) = decode_utf8_chunk(&bytes, &mut this.pending_utf8_bytes)? else {
+						let Some(buff_string) =
+							decode_utf8_chunk(&bytes, &mut this.pending_utf8_bytes, this.lossy_utf8)?
+						else {
 							continue;
 						};
 
@@ -131,8 +162,7 @@ impl Stream for WebStream {
 					Poll::Ready(None) => {
 						if !this.pending_utf8_bytes.is_empty() {
 							let pending = std::mem::take(&mut this.pending_utf8_bytes);
-							let buff_string =
-								String::from_utf8(pending).map_err(|e| -> BoxError { Box::new(e) as BoxError })?;
+							let buff_string = decode_utf8_tail(pending, this.lossy_utf8)?;
 
 							if let Some(first_message) = process_decoded_text(
 								&this.stream_mode,
@@ -172,34 +202,53 @@ struct BuffResponse {
 	candidate_message: Option<String>,
 }
 
-fn decode_utf8_chunk(bytes: &[u8], pending_utf8_bytes: &mut Vec<u8>) -> Result<Option<String>, BoxError> {
+/// Decodes the `bytes` after the `pending_utf8_bytes` of the previous chunk.
+/// - An incomplete multi-byte character at the end is kept in `pending_utf8_bytes` for the next chunk.
+/// - An invalid sequence is decoded as `U+FFFD` when `lossy`, and is an error otherwise.
+fn decode_utf8_chunk(bytes: &[u8], pending_utf8_bytes: &mut Vec<u8>, lossy: bool) -> Result<Option<String>, BoxError> {
 	pending_utf8_bytes.extend_from_slice(bytes);
 
-	match std::str::from_utf8(pending_utf8_bytes) {
-		Ok(valid_str) => {
-			let decoded = valid_str.to_string();
-			pending_utf8_bytes.clear();
-			Ok((!decoded.is_empty()).then_some(decoded))
-		}
-		Err(utf8_error) => {
-			// Incomplete multibyte codepoint at the end: keep bytes for next chunk.
-			if utf8_error.error_len().is_none() {
-				let valid_up_to = utf8_error.valid_up_to();
-				if valid_up_to == 0 {
-					return Ok(None);
+	let mut decoded = String::new();
+	let mut start = 0;
+	while start < pending_utf8_bytes.len() {
+		match std::str::from_utf8(&pending_utf8_bytes[start..]) {
+			Ok(valid_str) => {
+				decoded.push_str(valid_str);
+				start = pending_utf8_bytes.len();
+			}
+			Err(utf8_error) => {
+				let valid_end = start + utf8_error.valid_up_to();
+				let valid_str = std::str::from_utf8(&pending_utf8_bytes[start..valid_end])
+					.map_err(|e| -> BoxError { Box::new(e) as BoxError })?;
+				decoded.push_str(valid_str);
+
+				match utf8_error.error_len() {
+					// Incomplete multibyte codepoint at the end: keep bytes for next chunk.
+					None => {
+						start = valid_end;
+						break;
+					}
+					Some(invalid_len) if lossy => {
+						decoded.push(char::REPLACEMENT_CHARACTER);
+						start = valid_end + invalid_len;
+					}
+					Some(_) => return Err(Box::new(utf8_error) as BoxError),
 				}
-
-				let decoded = std::str::from_utf8(&pending_utf8_bytes[..valid_up_to])
-					.map_err(|e| -> BoxError { Box::new(e) as BoxError })?
-					.to_string();
-				let remaining = pending_utf8_bytes.split_off(valid_up_to);
-				*pending_utf8_bytes = remaining;
-				Ok((!decoded.is_empty()).then_some(decoded))
-			} else {
-				Err(Box::new(utf8_error) as BoxError)
 			}
 		}
 	}
+	pending_utf8_bytes.drain(..start);
+
+	Ok((!decoded.is_empty()).then_some(decoded))
+}
+
+/// Decodes the bytes still pending at the end of the stream (an incomplete character).
+fn decode_utf8_tail(pending_utf8_bytes: Vec<u8>, lossy: bool) -> Result<String, BoxError> {
+	if lossy {
+		Ok(String::from_utf8_lossy(&pending_utf8_bytes).into_owned())
+	} else {
+		String::from_utf8(pending_utf8_bytes).map_err(|e| -> BoxError { Box::new(e) as BoxError })
+	}
 }
 
 fn process_decoded_text(
@@ -302,7 +351,10 @@ fn new_with_pretty_json_array(
 							messages.push(json_str.to_string());
 						} else {
 							// Should not happen if logic is correct
-							tracing::warn!("WebStream: Extracted block failed JSON validation: {}", json_str);
+							tracing::warn!(
+								"WebStream: Extracted block failed JSON validation: {}",
+								crate::redaction::redact_body(json_str)
+							);
 						}
 						// Update last_idx to point after this object
 						last_idx = idx + 1;
@@ -396,17 +448,25 @@ fn process_buff_string_delimited(
 
 #[cfg(test)]
 mod tests {
-	use super::{StreamMode, decode_utf8_chunk, process_decoded_text};
+	use super::{StreamMode, WebStream, decode_utf8_chunk, process_decoded_text};
+	use crate::error::BoxError;
+	use bytes::Bytes;
+	use futures::StreamExt;
+
+	/// Splits the bytes in chunks of `size` (splitting the multi-byte characters).
+	fn split_bytes(bytes: &[u8], size: usize) -> Vec<Vec<u8>> {
+		bytes.chunks(size).map(<[u8]>::to_vec).collect()
+	}
 
 	#[test]
 	fn multibyte_utf8_split_across_chunks_should_not_error() {
 		let ch = "你".as_bytes();
 		let mut pending = Vec::new();
 
-		let first = decode_utf8_chunk(&ch[..2], &mut pending).expect("first chunk should not error");
+		let first = decode_utf8_chunk(&ch[..2], &mut pending, true).expect("first chunk should not error");
 		assert!(first.is_none());
 
-		let second = decode_utf8_chunk(&ch[2..], &mut pending).expect("second chunk should complete utf8");
+		let second = decode_utf8_chunk(&ch[2..], &mut pending, true).expect("second chunk should complete utf8");
 		assert_eq!(second.as_deref(), Some("你"));
 		assert!(pending.is_empty());
 	}
@@ -418,7 +478,7 @@ mod tests {
 		let mut remaining_messages = None;
 		let stream_mode = StreamMode::Delimiter("\n\n");
 
-		let c1 = decode_utf8_chunk("data: hel".as_bytes(), &mut pending_utf8).expect("decode c1");
+		let c1 = decode_utf8_chunk("data: hel".as_bytes(), &mut pending_utf8, true).expect("decode c1");
 		let msg1 = process_decoded_text(
 			&stream_mode,
 			c1.expect("decoded text for c1"),
@@ -428,7 +488,7 @@ mod tests {
 		.expect("process c1");
 		assert!(msg1.is_none());
 
-		let c2 = decode_utf8_chunk("lo\n\n".as_bytes(), &mut pending_utf8).expect("decode c2");
+		let c2 = decode_utf8_chunk("lo\n\n".as_bytes(), &mut pending_utf8, true).expect("decode c2");
 		let msg2 = process_decoded_text(
 			&stream_mode,
 			c2.expect("decoded text for c2"),
@@ -460,16 +520,73 @@ mod tests {
 	fn incomplete_utf8_with_error_len_none_should_wait_for_more_bytes() {
 		let ch = "中".as_bytes();
 		let mut pending = Vec::new();
-		let result = decode_utf8_chunk(&ch[..1], &mut pending).expect("incomplete utf8 should be buffered");
+		let result = decode_utf8_chunk(&ch[..1], &mut pending, true).expect("incomplete utf8 should be buffered");
 
 		assert!(result.is_none());
 		assert_eq!(pending, vec![ch[0]]);
 	}
 
 	#[test]
-	fn true_invalid_utf8_should_return_clear_parse_error() {
+	fn invalid_utf8_should_be_replaced_when_lossy() {
+		let mut pending = Vec::new();
+		let decoded = decode_utf8_chunk(b"ab\xFFcd", &mut pending, true).expect("lossy must not fail");
+
+		assert_eq!(decoded.as_deref(), Some("ab\u{FFFD}cd"));
+		assert!(pending.is_empty());
+	}
+
+	#[tokio::test]
+	async fn emoji_and_cjk_split_across_chunks_should_be_reassembled() {
+		let body = "data: 你好 😀\n\ndata: 世界 🎉!\n\n";
+
+		for size in 1..=5 {
+			let stream = WebStream::from_body_chunks(StreamMode::Delimiter("\n\n"), split_bytes(body.as_bytes(), size));
+			let messages: Vec<String> = stream.map(|msg| msg.expect("message should decode")).collect().await;
+
+			assert_eq!(
+				messages,
+				vec!["data: 你好 😀", "data: 世界 🎉!"],
+				"for chunk size {size}"
+			);
+		}
+	}
+
+	#[tokio::test]
+	async fn truncated_utf8_at_stream_end_should_be_replaced_when_lossy() {
+		let mut body = "data: ok 😀".as_bytes().to_vec();
+		body.truncate(body.len() - 1);
+
+		let stream = WebStream::from_body_chunks(StreamMode::Delimiter("\n\n"), vec![body.clone()]);
+		let messages: Vec<_> = stream.collect().await;
+		assert_eq!(messages.len(), 1);
+		assert_eq!(messages[0].as_deref().ok(), Some("data: ok \u{FFFD}"));
+
+		let stream = WebStream::from_body_chunks(StreamMode::Delimiter("\n\n"), vec![body]).with_lossy_utf8(false);
+		let messages: Vec<_> = stream.collect().await;
+		assert!(messages.iter().any(|msg| msg.is_err()), "strict must fail");
+	}
+
+	#[tokio::test]
+	async fn body_error_should_come_after_the_received_events() {
+		// Two events in one chunk (one queued), then the connection drop
+		let chunks: Vec<Result<Bytes, BoxError>> = vec![
+			Ok(Bytes::from("data: Red\n\ndata: , green\n\n")),
+			Err("connection reset".into()),
+		];
+		let mut stream = WebStream::from_body_chunks(StreamMode::Delimiter("\n\n"), Vec::new());
+		stream.bytes_stream = Some(Box::pin(futures::stream::iter(chunks)));
+
+		let messages: Vec<_> = stream.collect().await;
+		assert_eq!(messages.len(), 3);
+		assert_eq!(messages[0].as_deref().ok(), Some("data: Red"));
+		assert_eq!(messages[1].as_deref().ok(), Some("data: , green"));
+		assert!(messages[2].is_err(), "the body error should be last");
+	}
+
+	#[test]
+	fn true_invalid_utf8_should_return_clear_parse_error_when_strict() {
 		let mut pending = Vec::new();
-		let err = decode_utf8_chunk(&[0xF0, 0x28, 0x8C, 0x28], &mut pending).expect_err("must fail");
+		let err = decode_utf8_chunk(&[0xF0, 0x28, 0x8C, 0x28], &mut pending, false).expect_err("must fail");
 		assert!(err.to_string().contains("invalid utf-8"));
 	}
 
@@ -480,7 +597,7 @@ mod tests {
 		let mut remaining_messages = None;
 		let stream_mode = StreamMode::Delimiter("\n\n");
 
-		let c1 = decode_utf8_chunk("data: [DO".as_bytes(), &mut pending_utf8).expect("decode c1");
+		let c1 = decode_utf8_chunk("data: [DO".as_bytes(), &mut pending_utf8, true).expect("decode c1");
 		let msg1 = process_decoded_text(
 			&stream_mode,
 			c1.expect("decoded text for c1"),
@@ -490,7 +607,7 @@ mod tests {
 		.expect("process c1");
 		assert!(msg1.is_none());
 
-		let c2 = decode_utf8_chunk("NE]\n\n".as_bytes(), &mut pending_utf8).expect("decode c2");
+		let c2 = decode_utf8_chunk("NE]\n\n".as_bytes(), &mut pending_utf8, true).expect("decode c2");
 		let msg2 = process_decoded_text(
 			&stream_mode,
 			c2.expect("decoded text for c2"),
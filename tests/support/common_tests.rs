@@ -13,7 +13,7 @@ use genai::chat::{
 	BinarySource, CacheControl, ChatMessage, ChatOptions, ChatRequest, ChatResponseFormat, ContentPart, JsonSpec,
 	ReasoningEffort, Tool, ToolResponse, Verbosity,
 };
-use genai::embed::EmbedOptions;
+use genai::embed::{EmbedOptions, EmbedTruncate};
 use genai::resolver::{AuthData, AuthResolver, AuthResolverFn, IntoAuthResolverFn, ServiceTargetResolver};
 use genai::{Client, ClientConfig, ModelIden, ServiceTarget};
 use serde_json::{Value, json};
@@ -792,6 +792,42 @@ pub async fn common_test_chat_stream_tool_capture_ok(model: &str) -> TestResult<
 	Ok(())
 }
 
+/// The env var with the max time to first token (in ms) for `common_test_chat_stream_ttft_under_bound_ok`.
+pub const ENV_MAX_TTFT_MS: &str = "GENAI_TEST_MAX_TTFT_MS";
+
+/// Checks the stream time to first token (from `StreamEnd::metrics`) is under the `GENAI_TEST_MAX_TTFT_MS` bound,
+/// to catch a buffering regression in the streamers (e.g., a response buffered before being streamed).
+///
+/// Skipped when `GENAI_TEST_MAX_TTFT_MS` is not set (as the bound depends on the provider and the network).
+pub async fn common_test_chat_stream_ttft_under_bound_ok(model: &str) -> TestResult<()> {
+	let Ok(max_ttft_ms) = std::env::var(ENV_MAX_TTFT_MS) else {
+		println!("Skipping ttft check for '{model}': {ENV_MAX_TTFT_MS} not set");
+		return Ok(());
+	};
+	let max_ttft_ms: u64 = max_ttft_ms
+		.parse()
+		.map_err(|_| format!("{ENV_MAX_TTFT_MS} should be a number of ms, was '{max_ttft_ms}'"))?;
+
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream(model, chat_req, None).await?;
+	let StreamExtract { stream_end, .. } = extract_stream_end(chat_res.stream).await?;
+
+	// -- Check
+	let metrics = stream_end.metrics.ok_or("StreamEnd should have metrics")?;
+	let ttft_ms = metrics.time_to_first_token_ms.ok_or("Should have a time to first token")?;
+	assert!(
+		ttft_ms <= max_ttft_ms,
+		"time to first token {ttft_ms} ms should be <= {max_ttft_ms} ms (latency {} ms)",
+		metrics.latency_ms
+	);
+
+	Ok(())
+}
+
 // endregion: --- Chat Stream Tests
 
 // region:    --- Binaries
@@ -996,6 +1032,35 @@ pub async fn common_test_tool_full_flow_ok(model: &str) -> TestResult<()> {
 	Ok(())
 }
 
+/// Builds the tool use transcript with `transcript_model` (e.g., an OpenAI model, with its call ids),
+/// and replays it against `replay_model` for the final answer.
+pub async fn common_test_tool_replay_ok(transcript_model: &str, replay_model: &str) -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_tool_simple();
+
+	// -- Exec the transcript model for the tool calls
+	let chat_res = client.exec_chat(transcript_model, chat_req.clone(), None).await?;
+	let tool_call = chat_res
+		.tool_calls()
+		.into_iter()
+		.next()
+		.ok_or("Should have a tool call")?
+		.clone();
+	let tool_response = ToolResponse::new(tool_call.call_id, r#"{"weather": "Sunny", "temperature": "32C"}"#);
+	let chat_req = chat_req.append_tool_use_from_chat_response(&chat_res, tool_response);
+
+	// -- Replay against the other provider
+	let chat_res = client.exec_chat(replay_model, chat_req, None).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Last response should be message")?.to_lowercase();
+	assert!(content.contains("32"), "Should contain '32'");
+	assert!(content.contains("sunny"), "Should contain 'sunny'");
+
+	Ok(())
+}
+
 // endregion: --- Tools
 
 // region:    --- With Resolvers
@@ -1241,7 +1306,7 @@ pub async fn common_test_embed_batch_simple_ok_with_usage_check(model: &str, exp
 pub async fn common_test_embed_provider_specific_options_ok(
 	model: &str,
 	embedding_type: &str,
-	truncate: Option<&str>,
+	truncate: Option<EmbedTruncate>,
 ) -> TestResult<()> {
 	common_test_embed_provider_specific_options_ok_with_usage_check(model, embedding_type, truncate, true).await
 }
@@ -1249,7 +1314,7 @@ pub async fn common_test_embed_provider_specific_options_ok(
 pub async fn common_test_embed_provider_specific_options_ok_with_usage_check(
 	model: &str,
 	embedding_type: &str,
-	truncate: Option<&str>,
+	truncate: Option<EmbedTruncate>,
 	expect_usage: bool,
 ) -> TestResult<()> {
 	// -- Setup & Fixtures
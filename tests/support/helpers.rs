@@ -92,11 +92,13 @@ pub async fn extract_stream_end(mut chat_stream: ChatStream) -> TestResult<Strea
 
 	while let Some(Ok(stream_event)) = chat_stream.next().await {
 		match stream_event {
-			ChatStreamEvent::Start => (), // nothing to do
+			ChatStreamEvent::Start => (),         // nothing to do
+			ChatStreamEvent::FirstToken(_) => (), // nothing to do
 			ChatStreamEvent::Chunk(s_chunk) => content.push(s_chunk.content),
 			ChatStreamEvent::ReasoningChunk(s_chunk) => reasoning_content.push(s_chunk.content),
 			ChatStreamEvent::ThoughtSignatureChunk(_) => (), // ignore thought signature chunks for now
 			ChatStreamEvent::ToolCallChunk(tc) => tool_call_chunks.push(tc.tool_call),
+			ChatStreamEvent::ToolCallArgsChunk(_) => (), // the fragments are in the ToolCallChunk arguments
 			ChatStreamEvent::End(s_end) => {
 				stream_end = Some(s_end);
 				break;
@@ -10,12 +10,14 @@ mod data;
 mod helpers;
 mod seeders;
 mod test_error;
+mod test_server;
 pub mod yakbak;
 
 pub use asserts::*;
 pub use helpers::*;
 pub use seeders::*;
 pub use test_error::*;
+pub use test_server::*;
 
 pub mod common_tests;
 
@@ -0,0 +1,120 @@
+//! A local HTTP server for the integration tests, answering each request with a handler function
+//! (e.g., a mocked provider API).
+
+use super::TestResult;
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper::server::conn::{http1, http2};
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use serde_json::Value;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::net::TcpListener;
+
+/// A request received by the test server.
+#[derive(Debug, Clone)]
+pub struct TestRequest {
+	pub path: String,
+	/// The JSON body (`Value::Null` if empty or not JSON).
+	pub body: Value,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Protocol {
+	Http1,
+	/// HTTP/2 only, without TLS (prior knowledge).
+	H2c,
+}
+
+pub struct TestServer {
+	addr: SocketAddr,
+	connections: Arc<AtomicUsize>,
+}
+
+impl TestServer {
+	/// Starts an HTTP/1.1 server answering each request with `handler`.
+	pub async fn start<F>(handler: F) -> TestResult<Self>
+	where
+		F: Fn(TestRequest) -> Response<Full<Bytes>> + Send + Sync + 'static,
+	{
+		Self::start_with(Protocol::Http1, handler).await
+	}
+
+	/// Starts an `h2c` server (HTTP/2 only, no TLS) answering each request with `handler`.
+	pub async fn start_h2c<F>(handler: F) -> TestResult<Self>
+	where
+		F: Fn(TestRequest) -> Response<Full<Bytes>> + Send + Sync + 'static,
+	{
+		Self::start_with(Protocol::H2c, handler).await
+	}
+
+	/// Base URL, e.g. `http://127.0.0.1:12345/`
+	pub fn base_url(&self) -> String {
+		format!("http://127.0.0.1:{}/", self.addr.port())
+	}
+
+	/// Count of the connections accepted so far.
+	pub fn connections(&self) -> usize {
+		self.connections.load(Ordering::SeqCst)
+	}
+}
+
+impl TestServer {
+	async fn start_with<F>(protocol: Protocol, handler: F) -> TestResult<Self>
+	where
+		F: Fn(TestRequest) -> Response<Full<Bytes>> + Send + Sync + 'static,
+	{
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let addr = listener.local_addr()?;
+		let connections: Arc<AtomicUsize> = Arc::default();
+		let handler = Arc::new(handler);
+
+		let accepted = connections.clone();
+		tokio::spawn(async move {
+			while let Ok((stream, _)) = listener.accept().await {
+				accepted.fetch_add(1, Ordering::SeqCst);
+				let handler = handler.clone();
+				tokio::spawn(async move {
+					let svc = service_fn(move |req: Request<Incoming>| {
+						let handler = handler.clone();
+						async move {
+							let path = req.uri().path().to_string();
+							let body = req.into_body().collect().await.map(|b| b.to_bytes()).unwrap_or_default();
+							let body = serde_json::from_slice(&body).unwrap_or_default();
+							Ok::<_, Infallible>(handler(TestRequest { path, body }))
+						}
+					});
+					let io = TokioIo::new(stream);
+					let _ = match protocol {
+						Protocol::Http1 => http1::Builder::new().serve_connection(io, svc).await,
+						Protocol::H2c => http2::Builder::new(TokioExecutor::new()).serve_connection(io, svc).await,
+					};
+				});
+			}
+		});
+
+		Ok(TestServer { addr, connections })
+	}
+}
+
+// region:    --- Responses
+
+/// Returns a `200` response with the JSON `body`.
+pub fn json_response(body: &Value) -> Response<Full<Bytes>> {
+	body_response("application/json", body.to_string())
+}
+
+/// Returns a `200` response with the `body` of the given content type.
+pub fn body_response(content_type: &str, body: impl Into<Bytes>) -> Response<Full<Bytes>> {
+	Response::builder()
+		.header("content-type", content_type)
+		.body(Full::new(body.into()))
+		.expect("valid response")
+}
+
+// endregion: --- Responses
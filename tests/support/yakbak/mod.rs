@@ -1,7 +1,11 @@
 //! Yakbak — lightweight HTTP record/replay for integration testing.
 //!
 //! - **Record mode**: proxies requests to a real backend, saves response bodies as `.txt` files.
-//! - **Replay mode**: serves `.txt` files from a cassette directory in lexicographic order.
+//! - **Replay mode**: serves `.txt` files from a cassette directory in lexicographic order,
+//!   and keeps the received requests (see `YakbakServer::received_requests`).
+//!   A cassette can script an error status with a first line like `yakbak-status: 400`,
+//!   and a mid-body connection drop with a `yakbak-disconnect` line (the body before it is sent).
+//!   A `yakbak-delay-ms: 500` first line (before the status line, if any) delays the response.
 //!
 //! No manifest files needed — content-type is inferred from the response body.
 
@@ -8,23 +8,52 @@ use hyper_util::rt::TokioIo;
 use std::convert::Infallible;
 use std::net::SocketAddr;
 use std::path::PathBuf;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 
-type BoxBody = http_body_util::combinators::BoxBody<Bytes, Infallible>;
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+type BoxBody = http_body_util::combinators::BoxBody<Bytes, BoxError>;
 
 /// Chunk size for replay responses (matches typical TCP/HTTP chunk boundaries).
 const REPLAY_CHUNK_SIZE: usize = 8192;
 
+/// Delay before the scripted `yakbak-disconnect` connection drop (the body before it is flushed).
+const DISCONNECT_DELAY: Duration = Duration::from_millis(50);
+
 pub enum Mode {
 	Record { backend_url: String, cassette_dir: PathBuf },
 	Replay { cassette_dir: PathBuf },
 }
 
+/// A request received by the replay server, to assert on what the client sent.
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+	pub method: String,
+	pub path_and_query: String,
+	pub headers: Vec<(String, String)>,
+	pub body: Bytes,
+}
+
+impl ReceivedRequest {
+	/// Returns the first header value for `name` (case-insensitive).
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(k, _)| k.eq_ignore_ascii_case(name))
+			.map(|(_, v)| v.as_str())
+	}
+
+	pub fn body_text(&self) -> String {
+		String::from_utf8_lossy(&self.body).to_string()
+	}
+}
+
 pub struct YakbakServer {
 	addr: SocketAddr,
+	received: Arc<Mutex<Vec<ReceivedRequest>>>,
 	shutdown_tx: Option<oneshot::Sender<()>>,
 	join_handle: Option<tokio::task::JoinHandle<()>>,
 }
@@ -39,6 +68,11 @@ impl YakbakServer {
 		format!("http://127.0.0.1:{}/", self.addr.port())
 	}
 
+	/// Requests received so far (replay mode only), in order.
+	pub fn received_requests(&self) -> Vec<ReceivedRequest> {
+		self.received.lock().map(|received| received.clone()).unwrap_or_default()
+	}
+
 	pub async fn shutdown(&mut self) {
 		if let Some(tx) = self.shutdown_tx.take() {
 			let _ = tx.send(());
@@ -65,6 +99,7 @@ impl YakbakServer {
 		let addr = listener.local_addr().map_err(|e| format!("yakbak addr: {e}"))?;
 
 		let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+		let received: Arc<Mutex<Vec<ReceivedRequest>>> = Arc::default();
 
 		let join_handle = match mode {
 			Mode::Record {
@@ -86,6 +121,7 @@ impl YakbakServer {
 				let state = Arc::new(ReplayState {
 					cassette_dir,
 					counter: AtomicUsize::new(0),
+					received: received.clone(),
 				});
 				tokio::spawn(run_server(listener, shutdown_rx, move |req| {
 					let state = state.clone();
@@ -96,6 +132,7 @@ impl YakbakServer {
 
 		Ok(YakbakServer {
 			addr,
+			received,
 			shutdown_tx: Some(shutdown_tx),
 			join_handle: Some(join_handle),
 		})
@@ -232,9 +269,37 @@ async fn handle_record(req: Request<Incoming>, state: &RecordState) -> Result<Re
 struct ReplayState {
 	cassette_dir: PathBuf,
 	counter: AtomicUsize,
+	received: Arc<Mutex<Vec<ReceivedRequest>>>,
 }
 
-async fn handle_replay(_req: Request<Incoming>, state: &ReplayState) -> Result<Response<BoxBody>, String> {
+async fn handle_replay(req: Request<Incoming>, state: &ReplayState) -> Result<Response<BoxBody>, String> {
+	// -- Keep the received request
+	let method = req.method().to_string();
+	let path_and_query = req
+		.uri()
+		.path_and_query()
+		.map(|pq| pq.as_str().to_string())
+		.unwrap_or_else(|| "/".to_string());
+	let headers: Vec<(String, String)> = req
+		.headers()
+		.iter()
+		.map(|(name, value)| (name.to_string(), value.to_str().unwrap_or("").to_string()))
+		.collect();
+	let body = req
+		.into_body()
+		.collect()
+		.await
+		.map_err(|e| format!("read body: {e}"))?
+		.to_bytes();
+	if let Ok(mut received) = state.received.lock() {
+		received.push(ReceivedRequest {
+			method,
+			path_and_query,
+			headers,
+			body,
+		});
+	}
+
 	// -- List .txt files in sorted order
 	let mut files: Vec<PathBuf> = Vec::new();
 	let mut dir = tokio::fs::read_dir(&state.cassette_dir)
@@ -259,6 +324,12 @@ async fn handle_replay(_req: Request<Incoming>, state: &ReplayState) -> Result<R
 	})?;
 
 	let body = tokio::fs::read(file).await.map_err(|e| format!("read: {e}"))?;
+	let (delay, body) = split_delay_line(body)?;
+	if let Some(delay) = delay {
+		tokio::time::sleep(delay).await;
+	}
+	let (status, body) = split_status_line(body)?;
+	let (body, disconnect) = split_disconnect_line(body);
 	let body_str = String::from_utf8_lossy(&body);
 
 	// -- Infer content-type from body content
@@ -275,20 +346,71 @@ async fn handle_replay(_req: Request<Incoming>, state: &ReplayState) -> Result<R
 	// This uses HTTP/1.1 chunked transfer encoding, which is how real API
 	// servers deliver SSE responses. This is important because it means
 	// multi-byte UTF-8 characters can be split across chunk boundaries.
-	let chunks: Vec<Result<Frame<Bytes>, Infallible>> = body
+	let chunks: Vec<Result<Frame<Bytes>, BoxError>> = body
 		.chunks(REPLAY_CHUNK_SIZE)
 		.map(|chunk| Ok(Frame::data(Bytes::copy_from_slice(chunk))))
 		.collect();
-	let stream = futures::stream::iter(chunks);
+	// A body error makes hyper abort the connection mid-body (like a network drop).
+	// NOTE: The error is delayed, so that hyper flushes the headers and the body before it
+	//       (an immediate error aborts the connection with the buffered bytes unsent).
+	let disconnect_error = futures::StreamExt::then(futures::stream::iter(disconnect.then_some(())), |_| async {
+		tokio::time::sleep(DISCONNECT_DELAY).await;
+		Err::<Frame<Bytes>, BoxError>("yakbak scripted disconnect".into())
+	});
+	let stream = futures::StreamExt::chain(futures::stream::iter(chunks), disconnect_error);
 	let stream_body = StreamBody::new(stream);
 
 	Response::builder()
-		.status(200)
+		.status(status)
 		.header("content-type", content_type)
 		.body(stream_body.boxed())
 		.map_err(|e| format!("build response: {e}"))
 }
 
+/// Splits the optional scripted delay first line (e.g., `yakbak-delay-ms: 500`) from the cassette body.
+/// The response is sent after this delay (e.g., to simulate a slow model).
+fn split_delay_line(body: Vec<u8>) -> Result<(Option<Duration>, Vec<u8>), String> {
+	const DELAY_PREFIX: &[u8] = b"yakbak-delay-ms: ";
+	if !body.starts_with(DELAY_PREFIX) {
+		return Ok((None, body));
+	}
+	let line_end = body.iter().position(|b| *b == b'\n').unwrap_or(body.len());
+	let delay_ms = String::from_utf8_lossy(&body[DELAY_PREFIX.len()..line_end]);
+	let delay_ms = delay_ms
+		.trim()
+		.parse::<u64>()
+		.map_err(|e| format!("invalid yakbak-delay-ms '{delay_ms}': {e}"))?;
+	let rest = body.get(line_end + 1..).unwrap_or_default().to_vec();
+	Ok((Some(Duration::from_millis(delay_ms)), rest))
+}
+
+/// Splits the optional scripted status first line (e.g., `yakbak-status: 400`) from the cassette body.
+/// Without this line, the status is 200.
+fn split_status_line(body: Vec<u8>) -> Result<(u16, Vec<u8>), String> {
+	const STATUS_PREFIX: &[u8] = b"yakbak-status: ";
+	if !body.starts_with(STATUS_PREFIX) {
+		return Ok((200, body));
+	}
+	let line_end = body.iter().position(|b| *b == b'\n').unwrap_or(body.len());
+	let status = String::from_utf8_lossy(&body[STATUS_PREFIX.len()..line_end]);
+	let status = status
+		.trim()
+		.parse::<u16>()
+		.map_err(|e| format!("invalid yakbak-status '{status}': {e}"))?;
+	let rest = body.get(line_end + 1..).unwrap_or_default().to_vec();
+	Ok((status, rest))
+}
+
+/// Cuts the cassette body at the optional scripted `yakbak-disconnect` line.
+/// The body before it is sent, then the connection is dropped.
+fn split_disconnect_line(body: Vec<u8>) -> (Vec<u8>, bool) {
+	const DISCONNECT_LINE: &[u8] = b"yakbak-disconnect\n";
+	match body.windows(DISCONNECT_LINE.len()).position(|window| window == DISCONNECT_LINE) {
+		Some(idx) => (body[..idx].to_vec(), true),
+		None => (body, false),
+	}
+}
+
 /// Infer content-type from body text.
 fn infer_content_type(body: &str) -> &'static str {
 	let trimmed = body.trim_start();
@@ -0,0 +1,246 @@
+//! Integration tests for the `ChatMiddleware` chain, against a local OpenAI compatible server
+//! answering with the system message it received.
+
+mod support;
+
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatRequest, ChatResponse, ChatStreamEvent, ChatStreamResponse};
+use genai::resolver::{AuthData, Endpoint};
+use genai::{ChatMiddleware, ChatNext, ChatStreamNext, Client, ModelIden, RedactionMiddleware, ServiceTarget};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use support::{TestRequest, TestResult, TestServer, body_response, json_response};
+
+/// Starts the local server, answering with the content of the first `system` message of the request
+/// (or "no system"), as SSE chunks when the request is a stream one.
+async fn start_server() -> TestResult<TestServer> {
+	TestServer::start(|req: TestRequest| {
+		let system = req.body["messages"]
+			.as_array()
+			.and_then(|messages| messages.iter().find(|message| message["role"] == "system"))
+			.and_then(|message| message["content"].as_str())
+			.unwrap_or("no system")
+			.to_string();
+
+		if req.body["stream"] == true {
+			let chunk = json!({"choices": [{"index": 0, "delta": {"role": "assistant", "content": system}, "finish_reason": null}]});
+			let stop = json!({"choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]});
+			return body_response(
+				"text/event-stream",
+				format!("data: {chunk}\n\ndata: {stop}\n\ndata: [DONE]\n\n"),
+			);
+		}
+
+		json_response(&json!({
+			"choices": [{"index": 0, "message": {"role": "assistant", "content": system}, "finish_reason": "stop"}],
+			"usage": {"prompt_tokens": 10, "completion_tokens": 3, "total_tokens": 13}
+		}))
+	})
+	.await
+}
+
+// region:    --- Middlewares
+
+/// Injects a system message into the request.
+struct SystemInjector(&'static str);
+
+impl ChatMiddleware for SystemInjector {
+	fn around_request<'a>(
+		&'a self,
+		chat_req: ChatRequest,
+		next: ChatNext<'a>,
+	) -> BoxFuture<'a, genai::Result<ChatResponse>> {
+		Box::pin(async move { next.run(chat_req.with_system(self.0)).await })
+	}
+
+	fn around_stream<'a>(
+		&'a self,
+		chat_req: ChatRequest,
+		next: ChatStreamNext<'a>,
+	) -> BoxFuture<'a, genai::Result<ChatStreamResponse>> {
+		next.run(chat_req.with_system(self.0))
+	}
+}
+
+/// Records the requests it receives (and the model of the target).
+#[derive(Default, Clone)]
+struct Recorder(Arc<Mutex<Vec<(String, ChatRequest)>>>);
+
+impl ChatMiddleware for Recorder {
+	fn around_request<'a>(
+		&'a self,
+		chat_req: ChatRequest,
+		next: ChatNext<'a>,
+	) -> BoxFuture<'a, genai::Result<ChatResponse>> {
+		let model_name = next.target().model.model_name.to_string();
+		self.0.lock().expect("recorder lock").push((model_name, chat_req.clone()));
+		next.run(chat_req)
+	}
+}
+
+// endregion: --- Middlewares
+
+#[tokio::test]
+async fn test_chat_middleware_inject_system_and_record() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let server = start_server().await?;
+	let recorder = Recorder::default();
+	let client = Client::builder()
+		.with_chat_middleware(SystemInjector("Answer in French."))
+		.with_chat_middleware(recorder.clone())
+		.build();
+	let target = ServiceTarget {
+		endpoint: Endpoint::from_owned(server.base_url()),
+		auth: AuthData::from_single("test-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	};
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat(target, ChatRequest::from_user("What is the capital of France?"), None)
+		.await?;
+
+	// -- Check
+	// the provider received the injected system message
+	assert_eq!(chat_res.first_text(), Some("Answer in French."));
+
+	// the recorder (second) saw the final request, with the system of the injector (first)
+	let recorded = recorder.0.lock().expect("recorder lock");
+	assert_eq!(recorded.len(), 1);
+	let (model_name, recorded_req) = &recorded[0];
+	assert_eq!(model_name, "gpt-4o-mini");
+	assert_eq!(recorded_req.system.as_deref(), Some("Answer in French."));
+	assert_eq!(recorded_req.messages.len(), 1);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_middleware_redaction_masks_sent_request() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let server = start_server().await?;
+	let recorder = Recorder::default();
+	let client = Client::builder()
+		.with_chat_middleware(RedactionMiddleware::default())
+		.with_chat_middleware(recorder.clone())
+		.build();
+	let target = ServiceTarget {
+		endpoint: Endpoint::from_owned(server.base_url()),
+		auth: AuthData::from_single("test-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	};
+	let chat_req = ChatRequest::from_system("The user is jane.doe@example.com, phone 555-123-4567.")
+		.append_message(genai::chat::ChatMessage::user("My card is 4111 1111 1111 1111."));
+
+	// -- Exec
+	let chat_res = client.exec_chat(target, chat_req.clone(), None).await?;
+
+	// -- Check
+	// the provider received the redacted system
+	assert_eq!(chat_res.first_text(), Some("The user is [EMAIL], phone [PHONE]."));
+
+	let recorded = recorder.0.lock().expect("recorder lock");
+	let (_, recorded_req) = &recorded[0];
+	assert_eq!(
+		recorded_req.messages[0].content.first_text(),
+		Some("My card is [CARD].")
+	);
+
+	// the request of the caller is unchanged
+	assert_eq!(
+		chat_req.system.as_deref(),
+		Some("The user is jane.doe@example.com, phone 555-123-4567.")
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_middleware_stream_inject_system_and_record() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let server = start_server().await?;
+	let recorder = Recorder::default();
+	let client = Client::builder()
+		.with_chat_middleware(SystemInjector("Answer in French."))
+		.with_chat_middleware(recorder.clone())
+		.build();
+	let target = ServiceTarget {
+		endpoint: Endpoint::from_owned(server.base_url()),
+		auth: AuthData::from_single("test-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	};
+
+	// -- Exec
+	let stream_res = client
+		.exec_chat_stream(target, ChatRequest::from_user("What is the capital of France?"), None)
+		.await?;
+	let mut content = String::new();
+	let mut stream = stream_res.stream;
+	while let Some(event) = stream.next().await {
+		if let ChatStreamEvent::Chunk(chunk) = event? {
+			content.push_str(&chunk.content);
+		}
+	}
+
+	// -- Check
+	// the provider received the injected system message (`around_stream`)
+	assert_eq!(content, "Answer in French.");
+	// the recorder only implements `around_request`, so the stream request passed through it unrecorded
+	assert!(recorder.0.lock().expect("recorder lock").is_empty());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_middleware_stream_redaction_masks_sent_request() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let server = start_server().await?;
+	let client = Client::builder().with_chat_middleware(RedactionMiddleware::default()).build();
+	let target = ServiceTarget {
+		endpoint: Endpoint::from_owned(server.base_url()),
+		auth: AuthData::from_single("test-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	};
+	let chat_req = ChatRequest::from_system("The user is jane.doe@example.com.")
+		.append_message(genai::chat::ChatMessage::user("Hello."));
+
+	// -- Exec
+	let stream_res = client.exec_chat_stream(target, chat_req, None).await?;
+	let mut content = String::new();
+	let mut stream = stream_res.stream;
+	while let Some(event) = stream.next().await {
+		if let ChatStreamEvent::Chunk(chunk) = event? {
+			content.push_str(&chunk.content);
+		}
+	}
+
+	// -- Check
+	// the provider received the redacted system
+	assert_eq!(content, "The user is [EMAIL].");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_middleware_none_same_request() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let server = start_server().await?;
+	let client = Client::default();
+	let target = ServiceTarget {
+		endpoint: Endpoint::from_owned(server.base_url()),
+		auth: AuthData::from_single("test-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	};
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat(target, ChatRequest::from_user("What is the capital of France?"), None)
+		.await?;
+
+	// -- Check
+	assert_eq!(chat_res.first_text(), Some("no system"));
+
+	Ok(())
+}
@@ -0,0 +1,167 @@
+//! Tests for the test-only `AdapterKind::Mock` (run with `--features mock`), as a downstream user
+//! stubbing the chat and stream responses of the code under test.
+
+mod support;
+
+use genai::adapter::{AdapterKind, mock_chat_response};
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest, ToolCall, Usage};
+use genai::{Client, Error, TokenBudget};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use support::{TestResult, extract_stream_end};
+
+const MODEL: &str = "mock::test-model";
+
+/// The user code under test: asks the model for a one line summary.
+async fn summarize(client: &Client, text: &str) -> genai::Result<String> {
+	let chat_req = ChatRequest::from_system("Summarize in one line.").append_message(ChatMessage::user(text));
+	let chat_res = client.exec_chat(MODEL, chat_req, None).await?;
+	Ok(chat_res.into_first_text().unwrap_or_default())
+}
+
+/// Answers with the last user message reversed, and records the requests.
+fn echo_client(requests: Arc<Mutex<Vec<ChatRequest>>>) -> Client {
+	Client::builder()
+		.with_mock_chat_handler(move |chat_req: ChatRequest| {
+			let question = chat_req
+				.messages
+				.last()
+				.and_then(|msg| msg.content.first_text())
+				.unwrap_or_default();
+			let answer: String = question.chars().rev().collect();
+			requests.lock().expect("requests lock").push(chat_req);
+
+			let mut chat_res = mock_chat_response(answer);
+			chat_res.usage = Usage {
+				prompt_tokens: Some(10),
+				completion_tokens: Some(3),
+				total_tokens: Some(13),
+				..Default::default()
+			};
+			chat_res.usage_reported = true;
+			chat_res
+		})
+		.build()
+}
+
+#[tokio::test]
+async fn test_mock_adapter_chat_deterministic() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let requests = Arc::new(Mutex::new(Vec::new()));
+	let client = echo_client(requests.clone());
+
+	// -- Exec
+	let summary = summarize(&client, "abc").await?;
+	let summary_again = summarize(&client, "abc").await?;
+
+	// -- Check
+	assert_eq!(summary, "cba");
+	assert_eq!(summary_again, summary);
+
+	let requests = requests.lock().expect("requests lock");
+	assert_eq!(requests.len(), 2);
+	assert_eq!(requests[0].system.as_deref(), Some("Summarize in one line."));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_adapter_chat_response_model_and_usage() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = echo_client(Arc::default());
+
+	// -- Exec
+	let chat_res = client.exec_chat(MODEL, ChatRequest::from_user("hello"), None).await?;
+
+	// -- Check
+	assert_eq!(chat_res.model_iden.adapter_kind, AdapterKind::Mock);
+	assert_eq!(chat_res.model_iden.model_name.namespace_and_name().1, "test-model");
+	assert_eq!(chat_res.first_text(), Some("olleh"));
+	assert_eq!(chat_res.usage.total_tokens, Some(13));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_adapter_stream_deterministic() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = echo_client(Arc::default());
+	let options = ChatOptions::default().with_capture_content(true).with_capture_usage(true);
+
+	// -- Exec
+	let chat_stream = client
+		.exec_chat_stream(MODEL, ChatRequest::from_user("stream me"), Some(&options))
+		.await?;
+	let extract = extract_stream_end(chat_stream.stream).await?;
+
+	// -- Check
+	assert_eq!(extract.content.as_deref(), Some("em maerts"));
+	assert_eq!(extract.stream_end.captured_first_text(), Some("em maerts"));
+	let usage = extract.stream_end.captured_usage.as_ref().ok_or("should have usage")?;
+	assert_eq!(usage.total_tokens, Some(13));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_adapter_stream_tool_call() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = Client::builder()
+		.with_mock_chat_handler(|_chat_req: ChatRequest| {
+			mock_chat_response(ToolCall {
+				call_id: "call_1".to_string(),
+				fn_name: "get_weather".to_string(),
+				fn_arguments: json!({"city": "Paris"}),
+				thought_signatures: None,
+			})
+		})
+		.build();
+	let options = ChatOptions::default().with_capture_tool_calls(true);
+
+	// -- Exec
+	let chat_stream = client
+		.exec_chat_stream(MODEL, ChatRequest::from_user("Weather in Paris?"), Some(&options))
+		.await?;
+	let extract = extract_stream_end(chat_stream.stream).await?;
+
+	// -- Check
+	assert_eq!(extract.tool_call_chunks.len(), 1);
+	assert_eq!(extract.tool_call_chunks[0].fn_name, "get_weather");
+	let tool_calls = extract.stream_end.captured_tool_calls().ok_or("should have tool calls")?;
+	assert_eq!(tool_calls[0].fn_arguments, json!({"city": "Paris"}));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_adapter_without_handler_err() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+
+	// -- Exec
+	let res = client.exec_chat(MODEL, ChatRequest::from_user("hello"), None).await;
+
+	// -- Check
+	assert!(matches!(res, Err(Error::MockChatHandlerMissing { .. })));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_adapter_exhausted_token_budget_err() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let requests = Arc::new(Mutex::new(Vec::new()));
+	let client = echo_client(requests.clone()).with_token_budget(TokenBudget::new(0));
+
+	// -- Exec
+	let res = client.exec_chat(MODEL, ChatRequest::from_user("hello"), None).await;
+	let stream_res = client.exec_chat_stream(MODEL, ChatRequest::from_user("hello"), None).await;
+
+	// -- Check
+	// the mock requests go through the same checks as the provider ones, so the handler is not called
+	assert!(matches!(res, Err(Error::TokenBudgetExceeded { .. })));
+	assert!(matches!(stream_res, Err(Error::TokenBudgetExceeded { .. })));
+	assert!(requests.lock().expect("requests lock").is_empty());
+
+	Ok(())
+}
@@ -0,0 +1,84 @@
+//! Integration tests for the Ollama `all_models` capabilities (from `/api/show`), against a local mocked Ollama server.
+
+mod support;
+
+use genai::adapter::AdapterKind;
+use genai::resolver::{Endpoint, ServiceTargetResolver};
+use genai::{Client, Modality, ServiceTarget};
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use support::{TestRequest, TestResult, TestServer, json_response};
+
+/// Answers `/api/tags` with three models, and `/api/show` with their capabilities
+/// (`legacy:7b` has no `capabilities`, as the older Ollama versions).
+/// Returns the server and the count of the `/api/show` calls.
+async fn start_ollama_server() -> TestResult<(TestServer, Arc<AtomicUsize>)> {
+	let show_calls: Arc<AtomicUsize> = Arc::default();
+
+	let calls = show_calls.clone();
+	let server = TestServer::start(move |req: TestRequest| {
+		let res_body = match req.path.as_str() {
+			"/api/tags" => json!({"models": [
+				{"name": "llama3.1:8b", "digest": "d-llama"},
+				{"name": "llava:7b", "digest": "d-llava"},
+				{"name": "legacy:7b", "digest": "d-legacy"},
+			]}),
+			"/api/show" => {
+				calls.fetch_add(1, Ordering::SeqCst);
+				match req.body["model"].as_str() {
+					Some("llama3.1:8b") => json!({"capabilities": ["completion", "tools"]}),
+					Some("llava:7b") => json!({"capabilities": ["completion", "vision"]}),
+					_ => json!({"details": {"family": "legacy"}}),
+				}
+			}
+			_ => json!({}),
+		};
+		json_response(&res_body)
+	})
+	.await?;
+
+	Ok((server, show_calls))
+}
+
+#[tokio::test]
+async fn test_ollama_all_models_show_capabilities() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let (server, show_calls) = start_ollama_server().await?;
+	let base_url = server.base_url();
+	let client = Client::builder()
+		.with_service_target_resolver(ServiceTargetResolver::from_resolver_fn(
+			move |st: ServiceTarget| -> Result<ServiceTarget, genai::resolver::Error> {
+				Ok(ServiceTarget {
+					endpoint: Endpoint::from_owned(base_url.clone()),
+					..st
+				})
+			},
+		))
+		.build();
+
+	// -- Exec
+	let models = client.all_models(AdapterKind::Ollama).await?;
+
+	// -- Check
+	let model = |id: &str| models.iter().find(|m| m.id == id).ok_or(format!("Should have model '{id}'"));
+
+	let llama = model("llama3.1:8b")?;
+	assert!(llama.supports_tool_calls);
+	assert!(!llama.supported_input_modalities.contains(&Modality::Image));
+
+	let llava = model("llava:7b")?;
+	assert!(!llava.supports_tool_calls);
+	assert!(llava.supported_input_modalities.contains(&Modality::Image));
+
+	// no capabilities, the name heuristics
+	let legacy = model("legacy:7b")?;
+	assert!(legacy.supports_tool_calls);
+	assert_eq!(show_calls.load(Ordering::SeqCst), 3);
+
+	// -- Check the cache (the model without capabilities is fetched again)
+	client.all_models(AdapterKind::Ollama).await?;
+	assert_eq!(show_calls.load(Ordering::SeqCst), 4);
+
+	Ok(())
+}
@@ -0,0 +1,113 @@
+mod support;
+
+use crate::support::{Check, TestResult, common_tests};
+use genai::adapter::AdapterKind;
+use genai::resolver::AuthData;
+use serial_test::serial;
+
+const MODEL: &str = "jamba-mini";
+const MODEL_NS: &str = "ai21::jamba-mini";
+
+// region:    --- Chat
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_namespaced_ok() -> TestResult<()> {
+	common_tests::common_test_chat_simple_ok(MODEL_NS, None).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_multi_system_ok() -> TestResult<()> {
+	common_tests::common_test_chat_multi_system_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_json_mode_ok() -> TestResult<()> {
+	common_tests::common_test_chat_json_mode_ok(MODEL, Some(Check::USAGE)).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_temperature_ok() -> TestResult<()> {
+	common_tests::common_test_chat_temperature_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_stop_sequences_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
+}
+
+// endregion: --- Chat
+
+// region:    --- Chat Stream Tests
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_stream_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_stream_capture_content_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_chat_stream_capture_all_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_all_ok(MODEL, None).await
+}
+
+// endregion: --- Chat Stream Tests
+
+// region:    --- Tool Tests
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_tool_simple_ok() -> TestResult<()> {
+	common_tests::common_test_tool_simple_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_tool_full_flow_ok() -> TestResult<()> {
+	common_tests::common_test_tool_full_flow_ok(MODEL).await
+}
+
+// endregion: --- Tool Tests
+
+// region:    --- Resolver Tests
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_resolver_auth_ok() -> TestResult<()> {
+	common_tests::common_test_resolver_auth_ok(MODEL, AuthData::from_env("AI21_API_KEY")).await
+}
+
+// endregion: --- Resolver Tests
+
+// region:    --- List
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_list_models() -> TestResult<()> {
+	common_tests::common_test_list_models(AdapterKind::AI21, "jamba-mini").await
+}
+
+#[tokio::test]
+#[serial(ai21)]
+async fn test_all_models() -> TestResult<()> {
+	common_tests::common_test_all_models(AdapterKind::AI21, "jamba-mini").await
+}
+
+// endregion: --- List
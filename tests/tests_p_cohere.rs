@@ -55,13 +55,13 @@ async fn test_chat_stream_simple_ok() -> TestResult<()> {
 	common_tests::common_test_chat_stream_simple_ok(MODEL, None).await
 }
 
-// NOTE 2024-06-23 - Occasionally, the last stream message sent by Cohere is malformed and cannot be parsed.
-//                   Will investigate further if requested.
-// #[tokio::test]
+// NOTE: Cohere occasionally sends a malformed last stream message, which the streamer ignores.
+#[tokio::test]
 #[serial(cohere)]
-// async fn test_chat_stream_capture_content_ok() -> TestResult<()> {
-// 	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
-// }
+async fn test_chat_stream_capture_content_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
+}
+
 #[tokio::test]
 #[serial(cohere)]
 async fn test_chat_stream_capture_all_ok() -> TestResult<()> {
@@ -102,6 +102,11 @@ async fn test_tool_full_flow_ok() -> TestResult<()> {
 	common_tests::common_test_tool_full_flow_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_tool_replay_from_openai_ok() -> TestResult<()> {
+	common_tests::common_test_tool_replay_ok("gpt-4.1-mini", MODEL).await
+}
+
 // endregion: --- Tool Tests
 
 // region:    --- Resolver Tests
@@ -1,6 +1,7 @@
 mod support;
 
 use crate::support::{TestResult, common_tests};
+use genai::embed::EmbedTruncate;
 
 const MODEL: &str = "embed-english-v3.0";
 const MODEL_V4: &str = "embed-v4.0";
@@ -43,7 +44,8 @@ async fn test_cohere_embed_batch_empty_should_fail() -> TestResult<()> {
 
 #[tokio::test]
 async fn test_cohere_embed_with_provider_specific_options_ok() -> TestResult<()> {
-	common_tests::common_test_embed_provider_specific_options_ok(MODEL_V4, "search_query", Some("START")).await
+	common_tests::common_test_embed_provider_specific_options_ok(MODEL_V4, "search_query", Some(EmbedTruncate::Start))
+		.await
 }
 
 // endregion: --- Provider-Specific Tests
@@ -4,7 +4,7 @@ use crate::support::{Check, TestResult, common_tests};
 use genai::adapter::AdapterKind;
 
 const MODEL: &str = "gpt-4o";
-const MODEL_NS: &str = "github_copilot::gpt-4o";
+const MODEL_NS: &str = "copilot::gpt-4o";
 
 // region:    --- Chat
 
@@ -0,0 +1,117 @@
+mod support;
+
+use crate::support::{Check, TestResult, common_tests};
+use genai::adapter::AdapterKind;
+use genai::resolver::AuthData;
+use serial_test::serial;
+
+const MODEL: &str = "deepinfra::meta-llama/Meta-Llama-3.1-8B-Instruct";
+const MODEL_EMBED: &str = "deepinfra::BAAI/bge-base-en-v1.5";
+
+// region:    --- Chat
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_multi_system_ok() -> TestResult<()> {
+	common_tests::common_test_chat_multi_system_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_json_mode_ok() -> TestResult<()> {
+	common_tests::common_test_chat_json_mode_ok(MODEL, Some(Check::USAGE)).await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_temperature_ok() -> TestResult<()> {
+	common_tests::common_test_chat_temperature_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_stop_sequences_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
+}
+
+// endregion: --- Chat
+
+// region:    --- Chat Stream Tests
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_stream_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_stream_capture_content_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_chat_stream_capture_all_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_all_ok(MODEL, None).await
+}
+
+// endregion: --- Chat Stream Tests
+
+// region:    --- Tool Tests
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_tool_simple_ok() -> TestResult<()> {
+	common_tests::common_test_tool_simple_ok(MODEL).await
+}
+
+// endregion: --- Tool Tests
+
+// region:    --- Embed Tests
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_embed_single_simple_ok() -> TestResult<()> {
+	common_tests::common_test_embed_single_simple_ok(MODEL_EMBED).await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_embed_batch_simple_ok() -> TestResult<()> {
+	common_tests::common_test_embed_batch_simple_ok(MODEL_EMBED).await
+}
+
+// endregion: --- Embed Tests
+
+// region:    --- Resolver Tests
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_resolver_auth_ok() -> TestResult<()> {
+	common_tests::common_test_resolver_auth_ok(MODEL, AuthData::from_env("DEEPINFRA_API_KEY")).await
+}
+
+// endregion: --- Resolver Tests
+
+// region:    --- List
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_list_models() -> TestResult<()> {
+	common_tests::common_test_list_models(AdapterKind::DeepInfra, "meta-llama").await
+}
+
+#[tokio::test]
+#[serial(deepinfra)]
+async fn test_all_models() -> TestResult<()> {
+	common_tests::common_test_all_models(AdapterKind::DeepInfra, "meta-llama/Meta-Llama-3.1-8B-Instruct").await
+}
+
+// endregion: --- List
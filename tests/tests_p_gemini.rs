@@ -136,6 +136,11 @@ async fn test_tool_full_flow_ok() -> TestResult<()> {
 	common_tests::common_test_tool_full_flow_ok(MODEL_FLASH_2_5).await
 }
 
+#[tokio::test]
+async fn test_tool_replay_from_openai_ok() -> TestResult<()> {
+	common_tests::common_test_tool_replay_ok("gpt-4.1-mini", MODEL_FLASH_2_5).await
+}
+
 #[tokio::test]
 async fn test_tool_deterministic_history_gemini_3_ok() -> TestResult<()> {
 	use genai::chat::{ChatMessage, ChatRequest, Tool, ToolCall, ToolResponse};
@@ -31,6 +31,11 @@ async fn test_gemini_embed_batch_simple_ok() -> TestResult<()> {
 	common_tests::common_test_embed_batch_simple_ok_with_usage_check(MODEL, false).await
 }
 
+#[tokio::test]
+async fn test_gemini_embed_batch_namespaced_ok() -> TestResult<()> {
+	common_tests::common_test_embed_batch_simple_ok_with_usage_check(MODEL_NS, false).await
+}
+
 #[tokio::test]
 async fn test_gemini_embed_batch_empty_should_fail() -> TestResult<()> {
 	common_tests::common_test_embed_empty_batch_should_fail(MODEL).await
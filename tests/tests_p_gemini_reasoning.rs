@@ -1,6 +1,11 @@
 mod support;
 
-use crate::support::{Check, TestResult, common_tests};
+use crate::support::{
+	Check, TestResult, assert_reasoning_content, assert_reasoning_usage, common_tests, extract_stream_end,
+	seed_chat_req_simple,
+};
+use genai::Client;
+use genai::chat::ChatOptions;
 
 // "gemini-2.5-flash", "gemini-2.5-pro-preview"
 const MODEL: &str = "gemini-2.5-flash"; // can add "-zero", "-low", "-medium", "-high" suffix
@@ -10,11 +15,67 @@ const REASONING_MODEL: &str = "gemini-2.5-pro"; // Most advanced reasoning model
 
 #[tokio::test]
 async fn test_chat_simple_ok() -> TestResult<()> {
-	// NOTE: At this point, gemini 2.5 does not seems to give back reasoning content.
+	// NOTE: Without `include_thoughts`, gemini 2.5 does not give back reasoning content (see below).
 	//       But it should have REASONING_USAGE
 	common_tests::common_test_chat_simple_ok(MODEL, Some(Check::REASONING_USAGE)).await
 }
 
+#[tokio::test]
+async fn test_chat_include_thoughts_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = Client::builder()
+		.with_chat_options(ChatOptions::default().with_include_thoughts(true))
+		.build();
+
+	// -- Exec
+	let chat_res = client.exec_chat(MODEL, seed_chat_req_simple(), None).await?;
+
+	// -- Check (thought summaries as reasoning content, kept out of the text)
+	assert_reasoning_usage(&chat_res.usage)?;
+	assert_reasoning_content(&chat_res)?;
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_include_thoughts_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let options = ChatOptions::default()
+		.with_include_thoughts(true)
+		.with_capture_content(true)
+		.with_capture_reasoning_content(true);
+
+	// -- Exec
+	let stream_res = client.exec_chat_stream(MODEL, seed_chat_req_simple(), Some(&options)).await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+
+	// -- Check
+	let reasoning_content = extract.reasoning_content.as_deref().ok_or("Should have reasoning chunks")?;
+	assert!(!reasoning_content.is_empty());
+	let captured_reasoning = extract
+		.stream_end
+		.captured_reasoning_content
+		.as_deref()
+		.ok_or("Should have captured reasoning content")?;
+	assert_eq!(captured_reasoning, reasoning_content);
+	let content = extract.content.as_deref().ok_or("Should have content")?;
+	assert!(
+		!content.contains(reasoning_content),
+		"thoughts should not be in the text"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_capture_all_ok() -> TestResult<()> {
+	// NOTE: `capture_reasoning_content` sends `includeThoughts` (when `include_thoughts` is not set),
+	//       so the thought summaries come back as reasoning content.
+	common_tests::common_test_chat_stream_capture_all_ok(MODEL, Some(Check::REASONING_CONTENT | Check::REASONING_USAGE))
+		.await
+}
+
 #[tokio::test]
 async fn test_chat_reasoning_pro_ok() -> TestResult<()> {
 	// Test with the most advanced reasoning model
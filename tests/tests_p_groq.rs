@@ -2,7 +2,8 @@ mod support;
 
 use crate::support::{Check, TestResult, common_tests};
 use genai::adapter::AdapterKind;
-use genai::resolver::AuthData;
+use genai::resolver::{AuthData, AuthResolver};
+use genai::{Client, Error, ModelIden};
 
 const MODEL: &str = "groq::openai/gpt-oss-20b";
 
@@ -62,6 +63,11 @@ async fn test_chat_stream_capture_all_ok() -> TestResult<()> {
 	common_tests::common_test_chat_stream_capture_all_ok(MODEL, None).await
 }
 
+#[tokio::test]
+async fn test_chat_stream_ttft_under_bound_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_ttft_under_bound_ok(MODEL).await
+}
+
 // endregion: --- Chat Stream Tests
 
 // region:    --- Image Tests
@@ -96,6 +102,41 @@ async fn test_tool_full_flow_ok() -> TestResult<()> {
 
 // endregion: --- Tool Tests
 
+// region:    --- Embed
+
+#[tokio::test]
+async fn test_embed_not_supported_suggests_alternatives() -> TestResult<()> {
+	// -- Setup & Fixtures
+	// The embed call fails before any request is sent, so no real key is needed.
+	let auth_resolver =
+		AuthResolver::from_resolver_fn(|_model_iden: ModelIden| Ok(Some(AuthData::from_single("not-used"))));
+	let client = Client::builder().with_auth_resolver(auth_resolver).build();
+
+	// -- Exec
+	let err = client
+		.embed(MODEL, "Hello", None)
+		.await
+		.expect_err("Groq embed should not be supported");
+
+	// -- Check
+	let Error::AdapterNotSupported { alternatives, .. } = &err else {
+		return Err(format!("should be AdapterNotSupported, was: {err:?}").into());
+	};
+	assert!(alternatives.contains(&AdapterKind::OpenAI));
+	assert!(!alternatives.contains(&AdapterKind::Groq));
+	let msg = err.to_string();
+	assert!(
+		msg.starts_with(
+			"Adapter 'Groq' does not support feature 'embeddings'. Adapters supporting it: OpenAI, Gemini,"
+		),
+		"unexpected message: {msg}"
+	);
+
+	Ok(())
+}
+
+// endregion: --- Embed
+
 // region:    --- Resolver Tests
 
 #[tokio::test]
@@ -0,0 +1,130 @@
+mod support;
+
+use crate::support::{Check, TestResult, common_tests};
+use genai::adapter::AdapterKind;
+use genai::resolver::AuthData;
+use serial_test::serial;
+
+const MODEL: &str = "hunyuan-turbo";
+const MODEL_NS: &str = "hunyuan::hunyuan-turbo";
+const MODEL_EMBED: &str = "hunyuan::hunyuan-embedding";
+
+// region:    --- Chat
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_namespaced_ok() -> TestResult<()> {
+	common_tests::common_test_chat_simple_ok(MODEL_NS, None).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_multi_system_ok() -> TestResult<()> {
+	common_tests::common_test_chat_multi_system_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_json_mode_ok() -> TestResult<()> {
+	common_tests::common_test_chat_json_mode_ok(MODEL, Some(Check::USAGE)).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_temperature_ok() -> TestResult<()> {
+	common_tests::common_test_chat_temperature_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_stop_sequences_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
+}
+
+// endregion: --- Chat
+
+// region:    --- Chat Stream Tests
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_stream_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_stream_capture_content_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_chat_stream_capture_all_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_all_ok(MODEL, None).await
+}
+
+// endregion: --- Chat Stream Tests
+
+// region:    --- Tool Tests
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_tool_simple_ok() -> TestResult<()> {
+	common_tests::common_test_tool_simple_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_tool_full_flow_ok() -> TestResult<()> {
+	common_tests::common_test_tool_full_flow_ok(MODEL).await
+}
+
+// endregion: --- Tool Tests
+
+// region:    --- Embed Tests
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_embed_single_simple_ok() -> TestResult<()> {
+	common_tests::common_test_embed_single_simple_ok(MODEL_EMBED).await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_embed_batch_simple_ok() -> TestResult<()> {
+	common_tests::common_test_embed_batch_simple_ok(MODEL_EMBED).await
+}
+
+// endregion: --- Embed Tests
+
+// region:    --- Resolver Tests
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_resolver_auth_ok() -> TestResult<()> {
+	common_tests::common_test_resolver_auth_ok(MODEL, AuthData::from_env("HUNYUAN_API_KEY")).await
+}
+
+// endregion: --- Resolver Tests
+
+// region:    --- List
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_list_models() -> TestResult<()> {
+	common_tests::common_test_list_models(AdapterKind::Hunyuan, "hunyuan-turbo").await
+}
+
+#[tokio::test]
+#[serial(hunyuan)]
+async fn test_all_models() -> TestResult<()> {
+	common_tests::common_test_all_models(AdapterKind::Hunyuan, "hunyuan-turbo").await
+}
+
+// endregion: --- List
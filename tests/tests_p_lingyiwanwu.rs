@@ -0,0 +1,130 @@
+mod support;
+
+use crate::support::{Check, TestResult, common_tests};
+use genai::adapter::AdapterKind;
+use genai::resolver::AuthData;
+use serial_test::serial;
+
+const MODEL: &str = "yi-lightning";
+const MODEL_NS: &str = "lingyiwanwu::yi-lightning";
+const MODEL_V: &str = "yi-vision-v2"; // Vision model
+
+// region:    --- Chat
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_namespaced_ok() -> TestResult<()> {
+	common_tests::common_test_chat_simple_ok(MODEL_NS, None).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_multi_system_ok() -> TestResult<()> {
+	common_tests::common_test_chat_multi_system_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_json_mode_ok() -> TestResult<()> {
+	common_tests::common_test_chat_json_mode_ok(MODEL, Some(Check::USAGE)).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_temperature_ok() -> TestResult<()> {
+	common_tests::common_test_chat_temperature_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_stop_sequences_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
+}
+
+// endregion: --- Chat
+
+// region:    --- Chat Stream Tests
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_stream_simple_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_simple_ok(MODEL, None).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_stream_capture_content_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_stream_capture_all_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_capture_all_ok(MODEL, None).await
+}
+
+// endregion: --- Chat Stream Tests
+
+// region:    --- Binary Tests
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_binary_image_url_ok() -> TestResult<()> {
+	common_tests::common_test_chat_image_url_ok(MODEL_V).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_chat_binary_image_b64_ok() -> TestResult<()> {
+	common_tests::common_test_chat_image_b64_ok(MODEL_V).await
+}
+
+// endregion: --- Binary Tests
+
+// region:    --- Tool Tests
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_tool_simple_ok() -> TestResult<()> {
+	common_tests::common_test_tool_simple_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_tool_full_flow_ok() -> TestResult<()> {
+	common_tests::common_test_tool_full_flow_ok(MODEL).await
+}
+
+// endregion: --- Tool Tests
+
+// region:    --- Resolver Tests
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_resolver_auth_ok() -> TestResult<()> {
+	common_tests::common_test_resolver_auth_ok(MODEL, AuthData::from_env("LINGYIWANWU_API_KEY")).await
+}
+
+// endregion: --- Resolver Tests
+
+// region:    --- List
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_list_models() -> TestResult<()> {
+	common_tests::common_test_list_models(AdapterKind::Lingyiwanwu, "yi-lightning").await
+}
+
+#[tokio::test]
+#[serial(lingyiwanwu)]
+async fn test_all_models() -> TestResult<()> {
+	common_tests::common_test_all_models(AdapterKind::Lingyiwanwu, "yi-lightning").await
+}
+
+// endregion: --- List
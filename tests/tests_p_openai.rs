@@ -114,6 +114,11 @@ async fn test_chat_stream_tool_capture_ok() -> TestResult<()> {
 	common_tests::common_test_chat_stream_tool_capture_ok(MODEL_LATEST).await
 }
 
+#[tokio::test]
+async fn test_chat_stream_ttft_under_bound_ok() -> TestResult<()> {
+	common_tests::common_test_chat_stream_ttft_under_bound_ok(MODEL_LATEST).await
+}
+
 // endregion: --- Chat Stream Tests
 
 // region:    --- Binary Tests
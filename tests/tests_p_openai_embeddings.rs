@@ -34,6 +34,11 @@ async fn test_embed_batch_simple_ok() -> TestResult<()> {
 	common_tests::common_test_embed_batch_simple_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_embed_batch_namespaced_ok() -> TestResult<()> {
+	common_tests::common_test_embed_batch_simple_ok(MODEL_NS).await
+}
+
 #[tokio::test]
 async fn test_embed_batch_empty_should_fail() -> TestResult<()> {
 	common_tests::common_test_embed_empty_batch_should_fail(MODEL).await
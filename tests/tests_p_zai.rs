@@ -7,6 +7,8 @@ use genai::resolver::AuthData;
 const MODEL: &str = "glm-4-plus"; // Base GLM model for testing
 const MODEL_NS: &str = "zai::glm-4-plus";
 const MODEL_V: &str = "glm-4v-flash"; // Visual language model does not support function calling
+const MODEL_EMBED: &str = "embedding-3";
+const MODEL_EMBED_NS: &str = "zai::embedding-3";
 
 // region:    --- Chat
 
@@ -102,6 +104,25 @@ async fn test_tool_full_flow_ok() -> TestResult<()> {
 }
 // endregion: --- Tool Tests
 
+// region:    --- Embed Tests
+
+#[tokio::test]
+async fn test_embed_single_simple_ok() -> TestResult<()> {
+	common_tests::common_test_embed_single_simple_ok(MODEL_EMBED).await
+}
+
+#[tokio::test]
+async fn test_embed_single_namespaced_ok() -> TestResult<()> {
+	common_tests::common_test_embed_single_simple_ok(MODEL_EMBED_NS).await
+}
+
+#[tokio::test]
+async fn test_embed_batch_namespaced_ok() -> TestResult<()> {
+	common_tests::common_test_embed_batch_simple_ok(MODEL_EMBED_NS).await
+}
+
+// endregion: --- Embed Tests
+
 // region:    --- Resolver Tests
 
 #[tokio::test]
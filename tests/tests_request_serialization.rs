@@ -0,0 +1,53 @@
+//! Baseline guard for the request payload building time (see `benches/request_serialization.rs` for the benchmarks).
+
+mod support;
+
+use genai::adapter::{AdapterKind, ServiceType};
+use genai::chat::{ChatMessage, ChatRequest};
+use genai::resolver::{AuthData, Endpoint};
+use genai::{Client, ModelIden, ServiceTarget};
+use std::time::{Duration, Instant};
+use support::TestResult;
+
+/// Generous (debug build, shared CI runners), meant to catch order of magnitude regressions only.
+const MAX_BUILD_DURATION: Duration = Duration::from_millis(250);
+
+#[test]
+fn test_request_serialization_100_messages_under_threshold() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let target = ServiceTarget {
+		endpoint: Endpoint::from_static("https://api.openai.com/v1/"),
+		auth: AuthData::from_single("test-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	};
+	let messages = (0..100)
+		.map(|idx| {
+			if idx % 2 == 0 {
+				ChatMessage::user(format!("Question {idx}: {}", "lorem ".repeat(50)))
+			} else {
+				ChatMessage::assistant(format!("Answer {idx}: {}", "ipsum ".repeat(50)))
+			}
+		})
+		.collect();
+	let chat_req = ChatRequest::from_messages(messages);
+
+	// -- Exec (best of 5, to limit the scheduling noise)
+	let mut best = Duration::MAX;
+	let mut payload = serde_json::Value::Null;
+	for _ in 0..5 {
+		let start = Instant::now();
+		payload = client.build_chat_payload(target.clone(), ServiceType::Chat, chat_req.clone(), None)?;
+		best = best.min(start.elapsed());
+	}
+
+	// -- Check
+	let message_count = payload["messages"].as_array().map(|m| m.len());
+	assert_eq!(message_count, Some(100));
+	assert!(
+		best < MAX_BUILD_DURATION,
+		"100 messages payload build took {best:?} (max {MAX_BUILD_DURATION:?})"
+	);
+
+	Ok(())
+}
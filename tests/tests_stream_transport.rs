@@ -0,0 +1,89 @@
+//! Integration tests for the `StreamTransport` of an endpoint, against a local OpenAI compatible server
+//! streaming the same chunks as SSE (`/sse/`) or as JSON lines (`/jsonl/`).
+
+mod support;
+
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatOptions, ChatRequest};
+use genai::resolver::{AuthData, Endpoint, StreamTransport};
+use genai::{Client, ModelIden, ServiceTarget};
+use serde_json::{Value, json};
+use support::{TestRequest, TestResult, TestServer, body_response, extract_stream_end};
+
+fn stream_chunks() -> Vec<Value> {
+	vec![
+		json!({"choices": [{"index": 0, "delta": {"role": "assistant", "content": "Red, "}}]}),
+		json!({"choices": [{"index": 0, "delta": {"content": "yellow, "}}]}),
+		json!({"choices": [{"index": 0, "delta": {"content": "and blue."}}]}),
+		json!({"choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]}),
+		json!({"choices": [], "usage": {"prompt_tokens": 12, "completion_tokens": 6, "total_tokens": 18}}),
+	]
+}
+
+/// Starts the local server, streaming the chunks as SSE events (ending with `[DONE]`),
+/// or as JSON lines (without `[DONE]`).
+async fn start_server() -> TestResult<TestServer> {
+	TestServer::start(|req: TestRequest| {
+		if req.path.starts_with("/jsonl/") {
+			let body: String = stream_chunks().iter().map(|chunk| format!("{chunk}\n")).collect();
+			body_response("application/x-ndjson", body)
+		} else {
+			let mut body: String = stream_chunks().iter().map(|chunk| format!("data: {chunk}\n\n")).collect();
+			body.push_str("data: [DONE]\n\n");
+			body_response("text/event-stream", body)
+		}
+	})
+	.await
+}
+
+fn service_target(endpoint: Endpoint) -> ServiceTarget {
+	ServiceTarget {
+		endpoint,
+		auth: AuthData::from_single("test-key"),
+		model: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+	}
+}
+
+#[tokio::test]
+async fn test_stream_transport_json_lines_same_as_sse() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let base_url = start_server().await?.base_url();
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("List the primary colors.");
+	let options = ChatOptions::default().with_capture_content(true).with_capture_usage(true);
+
+	// -- Exec
+	let sse_endpoint = Endpoint::from_owned(format!("{base_url}sse/"));
+	let sse_res = client
+		.exec_chat_stream(service_target(sse_endpoint), chat_req.clone(), Some(&options))
+		.await?;
+	let sse = extract_stream_end(sse_res.stream).await?;
+
+	let jsonl_endpoint =
+		Endpoint::from_owned(format!("{base_url}jsonl/")).with_stream_transport(StreamTransport::JsonLines);
+	let jsonl_res = client
+		.exec_chat_stream(service_target(jsonl_endpoint), chat_req, Some(&options))
+		.await?;
+	let jsonl = extract_stream_end(jsonl_res.stream).await?;
+
+	// -- Check
+	assert_eq!(sse.content.as_deref(), Some("Red, yellow, and blue."));
+	assert_eq!(jsonl.content, sse.content);
+	assert_eq!(
+		jsonl.stream_end.captured_first_text(),
+		sse.stream_end.captured_first_text()
+	);
+	assert_eq!(
+		jsonl
+			.stream_end
+			.captured_stop_reason
+			.as_ref()
+			.map(|reason| reason.raw().to_string()),
+		Some("stop".to_string())
+	);
+	let usage = |end: &genai::chat::StreamEnd| end.captured_usage.as_ref().and_then(|usage| usage.total_tokens);
+	assert_eq!(usage(&sse.stream_end), Some(18));
+	assert_eq!(usage(&jsonl.stream_end), usage(&sse.stream_end));
+
+	Ok(())
+}
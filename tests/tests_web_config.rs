@@ -0,0 +1,86 @@
+//! Integration tests for the `WebConfig` connection settings, against a local HTTP/2 (prior knowledge) server.
+
+mod support;
+
+use genai::chat::ChatRequest;
+use genai::resolver::{AuthData, AuthResolver, Endpoint, ServiceTargetResolver};
+use genai::{Client, ServiceTarget, WebConfig};
+use support::{TestResult, TestServer, body_response};
+
+const MODEL: &str = "gpt-4o-mini";
+
+const CHAT_RESPONSE: &str = r#"{"id":"chatcmpl-h2c","object":"chat.completion","created":1750000000,"model":"gpt-4o-mini","choices":[{"index":0,"message":{"role":"assistant","content":"Blue."},"finish_reason":"stop"}],"usage":{"prompt_tokens":8,"completion_tokens":2,"total_tokens":10}}"#;
+
+/// Starts a local `h2c` server (HTTP/2 only, no TLS) answering an OpenAI chat completion.
+async fn start_h2c_server() -> TestResult<TestServer> {
+	TestServer::start_h2c(|_req| body_response("application/json", CHAT_RESPONSE)).await
+}
+
+fn h2c_client(base_url: String, web_config: WebConfig) -> Client {
+	Client::builder()
+		.with_web_config(web_config)
+		.with_auth_resolver(AuthResolver::from_resolver_fn(
+			|_| -> Result<Option<AuthData>, genai::resolver::Error> { Ok(Some(AuthData::from_single("fake-key"))) },
+		))
+		.with_service_target_resolver(ServiceTargetResolver::from_resolver_fn(
+			move |st: ServiceTarget| -> Result<ServiceTarget, genai::resolver::Error> {
+				Ok(ServiceTarget {
+					endpoint: Endpoint::from_owned(base_url.clone()),
+					..st
+				})
+			},
+		))
+		.build()
+}
+
+/// With `http2_prior_knowledge`, the sequential calls reuse the same connection.
+#[tokio::test]
+async fn test_web_config_http2_prior_knowledge_sequential_reuse() -> TestResult<()> {
+	let server = start_h2c_server().await?;
+	let client = h2c_client(server.base_url(), WebConfig::default().with_http2_prior_knowledge(true));
+
+	for _ in 0..5 {
+		let chat_res = client.exec_chat(MODEL, ChatRequest::from_user("Sky color?"), None).await?;
+		assert_eq!(chat_res.first_text(), Some("Blue."));
+	}
+
+	assert_eq!(server.connections(), 1);
+
+	Ok(())
+}
+
+/// With `http2_prior_knowledge`, the concurrent calls are multiplexed on one connection.
+#[tokio::test]
+async fn test_web_config_http2_prior_knowledge_concurrent_multiplexed() -> TestResult<()> {
+	let server = start_h2c_server().await?;
+	let client = h2c_client(
+		server.base_url(),
+		WebConfig::default()
+			.with_http2_prior_knowledge(true)
+			.with_pool_max_idle_per_host(32),
+	);
+
+	// -- A first call opens the connection, the next ones share it
+	client.exec_chat(MODEL, ChatRequest::from_user("Sky color?"), None).await?;
+	let calls = (0..8).map(|_| client.exec_chat(MODEL, ChatRequest::from_user("Sky color?"), None));
+	let chat_responses = futures::future::try_join_all(calls).await?;
+
+	assert_eq!(chat_responses.len(), 8);
+	assert_eq!(server.connections(), 1);
+
+	Ok(())
+}
+
+/// Without `http2_prior_knowledge`, the client speaks HTTP/1.1, which the `h2c` server rejects
+/// (so the setting is what makes the calls above succeed).
+#[tokio::test]
+async fn test_web_config_without_http2_prior_knowledge_fails_on_h2c() -> TestResult<()> {
+	let server = start_h2c_server().await?;
+	let client = h2c_client(server.base_url(), WebConfig::default());
+
+	let res = client.exec_chat(MODEL, ChatRequest::from_user("Sky color?"), None).await;
+
+	assert!(res.is_err(), "should fail over HTTP/1.1, got: {res:?}");
+
+	Ok(())
+}
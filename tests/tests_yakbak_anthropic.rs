@@ -1,11 +1,15 @@
 //! Replay integration tests for the Anthropic adapter.
 //!
 //! These tests use pre-recorded cassettes from `tests/data/yakbak/anthropic/`
-//! and assert that tool call streaming events flow through correctly.
+//! and assert that tool call streaming events flow through correctly,
+//! and that the `AnthropicExt` provider extension calls parse correctly.
 
 mod support;
 
+use futures::StreamExt;
+use genai::adapter::{AnthropicExt, UsageReportRequest};
 use genai::chat::*;
+use genai::resolver::AuthData;
 use serde_json::{Value, json};
 use support::yakbak::replay_client;
 use support::{TestResult, extract_stream_end};
@@ -88,5 +92,200 @@ async fn test_yakbak_anthropic_tool_stream() -> TestResult<()> {
 	assert_eq!(usage.prompt_tokens, Some(85));
 	assert_eq!(usage.completion_tokens, Some(42));
 
+	// -- Verify client-side timing metrics
+	let metrics = extract.stream_end.metrics.as_ref().ok_or("Should have metrics")?;
+	let ttft_ms = metrics.time_to_first_token_ms.ok_or("Should have time to first token")?;
+	assert!(ttft_ms <= metrics.latency_ms);
+
+	Ok(())
+}
+
+/// Verify that the Anthropic `input_json_delta` fragments are emitted as `ToolCallArgsChunk` events,
+/// and that `ToolArgsAccumulator::partial_tool_args` exposes the arguments while they stream.
+#[tokio::test]
+async fn test_yakbak_anthropic_tool_stream_args_chunks() -> TestResult<()> {
+	let (client, _server) = replay_client("anthropic", "tool_stream").await?;
+
+	let chat_req = ChatRequest::from_user("What is the temperature in C and weather, in Paris, France")
+		.append_tool(Tool::new("get_weather"));
+	let options = ChatOptions::default().with_capture_tool_calls(true);
+
+	let mut stream = client
+		.exec_chat_stream("anthropic::claude-haiku-4-5", chat_req, Some(&options))
+		.await?
+		.stream;
+
+	let mut accumulator = ToolArgsAccumulator::default();
+	let mut fragments: Vec<String> = Vec::new();
+	let mut partials: Vec<Value> = Vec::new();
+	let mut stream_end = None;
+	while let Some(event) = stream.next().await {
+		let event = event?;
+		if let Some(tool_index) = accumulator.push(&event) {
+			assert_eq!(tool_index, 0);
+			partials.push(accumulator.partial_tool_args(tool_index).unwrap_or(Value::Null));
+		}
+		match event {
+			ChatStreamEvent::ToolCallArgsChunk(args_chunk) => {
+				assert_eq!(args_chunk.call_id, "toolu_01A2B3C4D5");
+				fragments.push(args_chunk.fragment);
+			}
+			ChatStreamEvent::End(end) => stream_end = Some(end),
+			_ => (),
+		}
+	}
+
+	// -- Check the fragments (the empty first delta is skipped)
+	assert_eq!(
+		fragments,
+		vec![
+			r#"{"ci"#,
+			r#"ty": "Pa"#,
+			r#"ris", "#,
+			r#""country"#,
+			r#"": "France","#,
+			r#" "unit":"#,
+			r#" "C"}"#
+		]
+	);
+
+	// -- Check the incremental partial arguments
+	assert_eq!(
+		partials,
+		vec![
+			json!({}),
+			json!({"city": "Pa"}),
+			json!({"city": "Paris"}),
+			json!({"city": "Paris"}),
+			json!({"city": "Paris", "country": "France"}),
+			json!({"city": "Paris", "country": "France"}),
+			json!({"city": "Paris", "country": "France", "unit": "C"}),
+		]
+	);
+
+	// -- Check the final assembled tool call
+	let stream_end = stream_end.ok_or("Should have a StreamEnd event")?;
+	let tool_calls = stream_end.captured_tool_calls().ok_or("Should have captured tool calls")?;
+	assert_eq!(tool_calls.len(), 1);
+	assert_eq!(Some(&tool_calls[0].fn_arguments), partials.last());
+
+	Ok(())
+}
+
+/// Verify that the `ToolCallArgsChunk` tool index follows the tool calls (not the content blocks),
+/// when the response has a text block and several tool calls.
+#[tokio::test]
+async fn test_yakbak_anthropic_tool_stream_args_chunks_multi() -> TestResult<()> {
+	let (client, _server) = replay_client("anthropic", "tool_stream_multi").await?;
+
+	let chat_req = ChatRequest::from_user("Read src/main.rs and the first 20 lines of Cargo.toml")
+		.append_tool(Tool::new("read_file"));
+	let options = ChatOptions::default().with_capture_tool_calls(true);
+
+	let mut stream = client
+		.exec_chat_stream("anthropic::claude-haiku-4-5", chat_req, Some(&options))
+		.await?
+		.stream;
+
+	let mut accumulator = ToolArgsAccumulator::default();
+	let mut tool_indexes: Vec<usize> = Vec::new();
+	let mut first_partial_path = None;
+	let mut stream_end = None;
+	while let Some(event) = stream.next().await {
+		let event = event?;
+		if let Some(tool_index) = accumulator.push(&event) {
+			tool_indexes.push(tool_index);
+			if first_partial_path.is_none() {
+				first_partial_path = accumulator.partial_tool_args(tool_index);
+			}
+		}
+		if let ChatStreamEvent::End(end) = event {
+			stream_end = Some(end);
+		}
+	}
+
+	assert_eq!(tool_indexes, vec![0, 0, 1, 1, 1]);
+	// the path is exposed while still being typed out
+	assert_eq!(first_partial_path, Some(json!({"path": "src/"})));
+	assert_eq!(accumulator.partial_tool_args(0), Some(json!({"path": "src/main.rs"})));
+	assert_eq!(
+		accumulator.tool_args_raw(1),
+		Some(r#"{"path": "Cargo.toml", "lines": [1, 20]}"#)
+	);
+
+	// -- Check the final assembled tool calls match the accumulated arguments
+	let stream_end = stream_end.ok_or("Should have a StreamEnd event")?;
+	let tool_calls = stream_end.captured_tool_calls().ok_or("Should have captured tool calls")?;
+	assert_eq!(tool_calls.len(), 2);
+	for (tool_index, tool_call) in tool_calls.iter().enumerate() {
+		assert_eq!(
+			Some(&tool_call.fn_arguments),
+			accumulator.partial_tool_args(tool_index).as_ref()
+		);
+	}
+	assert_eq!(
+		tool_calls[1].fn_arguments,
+		json!({"path": "Cargo.toml", "lines": [1, 20]})
+	);
+
+	Ok(())
+}
+
+/// Verify that `AnthropicExt::list_batches` parses the Message Batches page.
+#[tokio::test]
+async fn test_yakbak_anthropic_ext_list_batches() -> TestResult<()> {
+	let (client, _server) = replay_client("anthropic", "list_batches").await?;
+
+	let page = client.provider_ext::<AnthropicExt>()?.list_batches(Some(2), None).await?;
+
+	assert_eq!(page.data.len(), 2);
+	assert!(page.has_more);
+	assert_eq!(page.last_id.as_deref(), Some("msgbatch_01Yakbak2InProgress"));
+
+	let ended = &page.data[0];
+	assert_eq!(ended.id, "msgbatch_01HkcTjaV5uDC8jWR4ZsDV8d");
+	assert_eq!(ended.processing_status, "ended");
+	assert_eq!(ended.request_counts.succeeded, 98);
+	assert_eq!(ended.request_counts.errored, 2);
+	assert!(ended.results_url.is_some());
+
+	let in_progress = &page.data[1];
+	assert_eq!(in_progress.processing_status, "in_progress");
+	assert_eq!(in_progress.request_counts.processing, 10);
+	assert!(in_progress.ended_at.is_none());
+
+	Ok(())
+}
+
+/// Verify that `AnthropicExt::get_usage_report` parses the Admin API usage report buckets.
+#[tokio::test]
+async fn test_yakbak_anthropic_ext_usage_report() -> TestResult<()> {
+	let (client, _server) = replay_client("anthropic", "usage_report").await?;
+
+	let request = UsageReportRequest::new("2025-06-01T00:00:00Z")
+		.with_ending_at("2025-06-03T00:00:00Z")
+		.with_bucket_width("1d")
+		.append_group_by("model");
+	let report = client
+		.provider_ext::<AnthropicExt>()?
+		.with_admin_auth(AuthData::from_single("yakbak-fake-admin-key"))
+		.get_usage_report(&request)
+		.await?;
+
+	assert_eq!(report.data.len(), 2);
+	assert!(!report.has_more);
+	assert!(report.next_page.is_none());
+
+	let bucket = &report.data[0];
+	assert_eq!(bucket.starting_at, "2025-06-01T00:00:00Z");
+	assert_eq!(bucket.results.len(), 1);
+	let result = &bucket.results[0];
+	assert_eq!(result.model.as_deref(), Some("claude-sonnet-4-5"));
+	assert_eq!(result.uncached_input_tokens, 1500);
+	assert_eq!(result.cache_read_input_tokens, 800);
+	assert_eq!(result.output_tokens, 500);
+
+	assert!(report.data[1].results.is_empty());
+
 	Ok(())
 }
@@ -6,6 +6,7 @@
 mod support;
 
 use genai::chat::*;
+use std::time::Duration;
 use support::yakbak::replay_client;
 use support::{TestResult, extract_stream_end};
 
@@ -46,3 +47,49 @@ async fn test_yakbak_gemini_thinking_stream() -> TestResult<()> {
 
 	Ok(())
 }
+
+/// A created cached content is referenced by name in the next chat request, and its tokens are reported as cached.
+#[tokio::test]
+async fn test_yakbak_gemini_create_cache_then_chat() -> TestResult<()> {
+	let (client, server) = replay_client("gemini", "cached_content").await?;
+
+	let manual_req = ChatRequest::from_system("You answer questions about the attached manual.")
+		.append_message(ChatMessage::user("<the long manual>"));
+	let cache = client
+		.create_gemini_cache("gemini-2.5-flash", manual_req, Duration::from_secs(600))
+		.await?;
+	assert_eq!(cache.name, "cachedContents/yakbak-cache-01");
+	assert_eq!(
+		cache.usage_metadata.as_ref().and_then(|usage| usage.total_token_count),
+		Some(4096)
+	);
+
+	let options = ChatOptions::default().with_cached_content(&cache);
+	let chat_res = client
+		.exec_chat(
+			"gemini-2.5-flash",
+			ChatRequest::from_user("What does chapter 2 say?"),
+			Some(&options),
+		)
+		.await?;
+	assert_eq!(chat_res.first_text(), Some("Chapter 2 covers the installation steps."));
+	let cached_tokens = chat_res
+		.usage
+		.prompt_tokens_details
+		.as_ref()
+		.and_then(|details| details.cached_tokens);
+	assert_eq!(cached_tokens, Some(4096));
+
+	// -- Check the requests
+	let requests = server.received_requests();
+	assert_eq!(requests.len(), 2);
+	assert_eq!(requests[0].path_and_query, "/cachedContents");
+	let create_body: serde_json::Value = serde_json::from_slice(&requests[0].body).map_err(genai::Error::from)?;
+	assert_eq!(create_body["model"], "models/gemini-2.5-flash");
+	assert_eq!(create_body["ttl"], "600s");
+	assert_eq!(requests[1].path_and_query, "/models/gemini-2.5-flash:generateContent");
+	let chat_body: serde_json::Value = serde_json::from_slice(&requests[1].body).map_err(genai::Error::from)?;
+	assert_eq!(chat_body["cachedContent"], "cachedContents/yakbak-cache-01");
+
+	Ok(())
+}
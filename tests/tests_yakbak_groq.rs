@@ -0,0 +1,80 @@
+//! Replay integration tests for the Groq adapter.
+//!
+//! These tests use pre-recorded cassettes from `tests/data/yakbak/groq/`
+//! and assert that the provider errors sent inside the SSE stream end the stream.
+
+mod support;
+
+use futures::StreamExt;
+use genai::chat::*;
+use support::TestResult;
+use support::yakbak::replay_client;
+
+const MODEL: &str = "groq::llama-3.1-8b-instant";
+
+/// Collects the chunk texts, the `End`, and the error of the stream.
+async fn collect_stream(stream: ChatStream) -> (Vec<String>, Option<StreamEnd>, Option<genai::Error>) {
+	let mut stream = stream;
+	let mut chunks: Vec<String> = Vec::new();
+	let mut stream_end: Option<StreamEnd> = None;
+	let mut stream_error: Option<genai::Error> = None;
+	while let Some(event) = stream.next().await {
+		match event {
+			Ok(ChatStreamEvent::Chunk(chunk)) => chunks.push(chunk.content),
+			Ok(ChatStreamEvent::End(end)) => stream_end = Some(end),
+			Ok(_) => (),
+			Err(err) => stream_error = Some(err),
+		}
+	}
+	(chunks, stream_end, stream_error)
+}
+
+/// A top-level `error` payload mid-stream ends with an incomplete `End` carrying the partial content,
+/// then returns the provider error (with its message).
+#[tokio::test]
+async fn test_yakbak_groq_stream_error_payload() -> TestResult<()> {
+	let (client, _server) = replay_client("groq", "stream_error").await?;
+
+	let options = ChatOptions::default().with_capture_content(true);
+	let stream_res = client
+		.exec_chat_stream(MODEL, ChatRequest::from_user("List three colors."), Some(&options))
+		.await?;
+	let (chunks, stream_end, stream_error) = collect_stream(stream_res.stream).await;
+
+	assert_eq!(chunks, vec!["Red", ", green"]);
+	let stream_end = stream_end.ok_or("Should have the incomplete StreamEnd")?;
+	assert!(stream_end.incomplete);
+	assert_eq!(stream_end.captured_first_text(), Some("Red, green"));
+	let stream_error = stream_error.ok_or("Should have the provider error after the End")?;
+	let genai::Error::ChatResponse { body, .. } = &stream_error else {
+		return Err(format!("should be a chat response error, got: {stream_error}").into());
+	};
+	assert_eq!(body["code"], "tool_use_failed");
+	assert!(stream_error.to_string().contains("Failed to call a function"));
+
+	Ok(())
+}
+
+/// The `x_groq.error` envelope mid-stream ends the stream the same way.
+#[tokio::test]
+async fn test_yakbak_groq_stream_x_groq_error() -> TestResult<()> {
+	let (client, _server) = replay_client("groq", "stream_error_x_groq").await?;
+
+	let options = ChatOptions::default().with_capture_content(true);
+	let stream_res = client
+		.exec_chat_stream(MODEL, ChatRequest::from_user("List three colors."), Some(&options))
+		.await?;
+	let (chunks, stream_end, stream_error) = collect_stream(stream_res.stream).await;
+
+	assert_eq!(chunks, vec!["Red"]);
+	let stream_end = stream_end.ok_or("Should have the incomplete StreamEnd")?;
+	assert!(stream_end.incomplete);
+	assert_eq!(stream_end.captured_first_text(), Some("Red"));
+	let stream_error = stream_error.ok_or("Should have the provider error after the End")?;
+	let genai::Error::ChatResponse { body, .. } = &stream_error else {
+		return Err(format!("should be a chat response error, got: {stream_error}").into());
+	};
+	assert_eq!(body["type"], "internal_server_error");
+
+	Ok(())
+}
@@ -5,8 +5,13 @@
 
 mod support;
 
+use futures::{StreamExt, TryStreamExt};
+use genai::adapter::{AdapterKind, FilePurpose, FileUpload, OpenAIExt};
 use genai::chat::*;
+use genai::{Error, HedgePolicy, ProbeOptions, ProbeResult, TokenBudget, TokenBudgetStatus, TrimPolicy, TrimStrategy};
 use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use support::yakbak::replay_client;
 use support::{TestResult, extract_stream_end};
 
@@ -109,3 +114,649 @@ async fn test_yakbak_openai_stream_tool_call_with_finish_reason() -> TestResult<
 
 	Ok(())
 }
+
+/// `all_model_names` must return the live `/models` list (through the resolved endpoint),
+/// not the static fallback list.
+#[tokio::test]
+async fn test_yakbak_openai_all_model_names_live() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "list_models").await?;
+
+	let model_names = client.all_model_names(AdapterKind::OpenAI).await?;
+
+	assert_eq!(
+		model_names,
+		vec!["gpt-4o-mini", "gpt-4.1-nano", "ft:gpt-4o-mini:acme::yakbak"],
+		"Should be the models from the replayed /models response"
+	);
+
+	Ok(())
+}
+
+/// `all_model_names` must follow the `has_more` / `last_id` pagination and return the models of all the pages.
+#[tokio::test]
+async fn test_yakbak_openai_all_model_names_paginated() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "list_models_paginated").await?;
+
+	let model_names = client.all_model_names(AdapterKind::OpenAI).await?;
+
+	assert_eq!(
+		model_names,
+		vec!["gpt-4o-mini", "gpt-4.1-nano", "o4-mini"],
+		"Should have the models of the two replayed pages"
+	);
+
+	Ok(())
+}
+
+/// `OpenAIExt` Files API: upload (multipart), list, content, and delete, in this order.
+#[tokio::test]
+async fn test_yakbak_openai_ext_files() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "files").await?;
+	let openai = client.provider_ext::<OpenAIExt>()?;
+	let batch_line =
+		r#"{"custom_id": "req-1", "method": "POST", "url": "/v1/chat/completions", "body": {"model": "gpt-4o-mini"}}"#;
+
+	// -- Exec
+	let file = openai
+		.upload_file(FileUpload::new(
+			"batch_input.jsonl",
+			format!("{batch_line}\n"),
+			FilePurpose::Batch,
+		))
+		.await?;
+	let files = openai.list_files(Some(FilePurpose::Batch)).await?;
+	let content = openai.get_file_content(&file.id).await?;
+	let deleted = openai.delete_file(&file.id).await?;
+
+	// -- Check responses
+	assert_eq!(file.id, "file-yakbak123");
+	assert_eq!(file.purpose, "batch");
+	assert_eq!(file.filename, "batch_input.jsonl");
+	assert_eq!(files.data.len(), 1);
+	assert!(!files.has_more);
+	assert_eq!(String::from_utf8_lossy(&content).trim_end(), batch_line);
+	assert!(deleted.deleted);
+
+	// -- Check requests
+	let requests = server.received_requests();
+	assert_eq!(requests.len(), 4);
+
+	let upload = &requests[0];
+	assert_eq!(upload.method, "POST");
+	assert_eq!(upload.path_and_query, "/files");
+	let content_type = upload.header("content-type").ok_or("Should have content-type")?;
+	assert!(content_type.starts_with("multipart/form-data; boundary="));
+	let upload_body = upload.body_text();
+	assert!(upload_body.contains("Content-Disposition: form-data; name=\"purpose\"\r\n\r\nbatch\r\n"));
+	assert!(upload_body.contains("Content-Disposition: form-data; name=\"file\"; filename=\"batch_input.jsonl\""));
+	assert!(upload_body.contains(batch_line));
+
+	assert_eq!(requests[1].method, "GET");
+	assert_eq!(requests[1].path_and_query, "/files?purpose=batch");
+	assert_eq!(requests[2].path_and_query, "/files/file-yakbak123/content");
+	assert_eq!(requests[3].method, "DELETE");
+	assert_eq!(requests[3].path_and_query, "/files/file-yakbak123");
+	assert_eq!(requests[3].header("authorization"), Some("Bearer yakbak-fake-key"));
+
+	Ok(())
+}
+
+/// `probe_capabilities` runs the chat, stream, JSON mode, and tool probes in order, and reports a scripted
+/// tool declaration failure (HTTP 400) as `Failed` without failing the other probes.
+#[tokio::test]
+async fn test_yakbak_openai_probe_capabilities_tool_failure() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "probe_capabilities").await?;
+
+	let caps = client.probe_capabilities("gpt-4o-mini", None).await?;
+
+	assert_eq!(caps.model_iden.model_name, "gpt-4o-mini");
+	assert_eq!(caps.chat, ProbeResult::Supported);
+	assert_eq!(caps.streaming, ProbeResult::Supported);
+	assert_eq!(caps.json_mode, ProbeResult::Supported);
+	let ProbeResult::Failed(tool_error) = &caps.tool_calls else {
+		return Err(format!("tool_calls probe should have failed, got {:?}", caps.tool_calls).into());
+	};
+	assert!(tool_error.contains("400"), "should have the status, got: {tool_error}");
+
+	let requests = server.received_requests();
+	assert_eq!(requests.len(), 4);
+	assert!(requests[1].body_text().contains(r#""stream":true"#));
+	assert!(requests[2].body_text().contains("json_object"));
+	assert!(requests[3].body_text().contains(r#""name":"noop""#));
+
+	Ok(())
+}
+
+/// Skipped probes send no requests.
+#[tokio::test]
+async fn test_yakbak_openai_probe_capabilities_skip() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "probe_capabilities_skip").await?;
+
+	let options = ProbeOptions::default()
+		.with_skip_streaming(true)
+		.with_skip_json_mode(true)
+		.with_write_override(true);
+	let caps = client.probe_capabilities("gpt-4o-mini", Some(&options)).await?;
+
+	assert_eq!(caps.chat, ProbeResult::Supported);
+	assert_eq!(caps.streaming, ProbeResult::Skipped);
+	assert_eq!(caps.json_mode, ProbeResult::Skipped);
+	assert_eq!(caps.tool_calls, ProbeResult::Supported);
+	assert_eq!(server.received_requests().len(), 2);
+
+	Ok(())
+}
+
+/// `text_stream()` yields only the content chunks (the start, reasoning, and end events are dropped).
+#[tokio::test]
+async fn test_yakbak_openai_text_stream() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "text_stream").await?;
+
+	let stream_res = client
+		.exec_chat_stream("gpt-4o-mini", ChatRequest::from_user("Count to three."), None)
+		.await?;
+	let texts: Vec<String> = stream_res.text_stream().try_collect().await?;
+
+	assert_eq!(texts, vec!["One", ", two", ", three."]);
+
+	Ok(())
+}
+
+/// A stream dropped mid-way ends with an incomplete `End` carrying the partial captured content,
+/// then returns the stream error.
+#[tokio::test]
+async fn test_yakbak_openai_stream_disconnect_partial_content() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "stream_disconnect").await?;
+
+	let options = ChatOptions::default()
+		.with_capture_content(true)
+		.with_capture_reasoning_content(true);
+	let stream_res = client
+		.exec_chat_stream(
+			"gpt-4o-mini",
+			ChatRequest::from_user("List three colors."),
+			Some(&options),
+		)
+		.await?;
+
+	let mut stream = stream_res.stream;
+	let mut chunks: Vec<String> = Vec::new();
+	let mut stream_end: Option<StreamEnd> = None;
+	let mut stream_error: Option<genai::Error> = None;
+	while let Some(event) = stream.next().await {
+		match event {
+			Ok(ChatStreamEvent::Chunk(chunk)) => chunks.push(chunk.content),
+			Ok(ChatStreamEvent::End(end)) => stream_end = Some(end),
+			Ok(_) => (),
+			Err(err) => stream_error = Some(err),
+		}
+	}
+
+	assert_eq!(chunks, vec!["Red", ", green"]);
+	let stream_end = stream_end.ok_or("Should have the incomplete StreamEnd")?;
+	assert!(stream_end.incomplete);
+	assert_eq!(stream_end.captured_first_text(), Some("Red, green"));
+	assert_eq!(
+		stream_end.captured_reasoning_content.as_deref(),
+		Some("Listing colors.")
+	);
+	let stream_error = stream_error.ok_or("Should have the stream error after the End")?;
+	assert!(
+		matches!(stream_error, genai::Error::WebStream { .. }),
+		"should be a web stream error, got: {stream_error}"
+	);
+
+	Ok(())
+}
+
+/// A slow primary fires the hedge, and the secondary answer wins (reported by `model_iden`).
+#[tokio::test]
+async fn test_yakbak_openai_exec_chat_hedged_fires() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "hedge_fires").await?;
+
+	let policy = HedgePolicy::new(Duration::from_millis(100)).append_secondary_target("gpt-4.1-mini");
+	let chat_res = client
+		.exec_chat_hedged("gpt-4o-mini", ChatRequest::from_user("Say hi."), None, &policy)
+		.await?;
+
+	assert_eq!(chat_res.model_iden.model_name, "gpt-4.1-mini");
+	assert_eq!(chat_res.first_text(), Some("Secondary answer."));
+	assert_eq!(server.received_requests().len(), 2);
+
+	Ok(())
+}
+
+/// A primary answering before the delay does not fire the hedge.
+#[tokio::test]
+async fn test_yakbak_openai_exec_chat_hedged_not_needed() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "hedge_not_needed").await?;
+
+	let policy = HedgePolicy::new(Duration::from_secs(2)).append_secondary_target("gpt-4.1-mini");
+	let chat_res = client
+		.exec_chat_hedged("gpt-4o-mini", ChatRequest::from_user("Say hi."), None, &policy)
+		.await?;
+
+	assert_eq!(chat_res.model_iden.model_name, "gpt-4o-mini");
+	assert_eq!(chat_res.first_text(), Some("Primary answer."));
+	assert_eq!(server.received_requests().len(), 1);
+
+	Ok(())
+}
+
+/// For streams, the hedge fires on the first token latency, and the winning stream replays the events read so far.
+#[tokio::test]
+async fn test_yakbak_openai_exec_chat_stream_hedged_fires() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "hedge_stream_fires").await?;
+
+	let policy = HedgePolicy::new(Duration::from_millis(100)).append_secondary_target("gpt-4.1-mini");
+	let stream_res = client
+		.exec_chat_stream_hedged("gpt-4o-mini", ChatRequest::from_user("Say hi."), None, &policy)
+		.await?;
+
+	assert_eq!(stream_res.model_iden.model_name, "gpt-4.1-mini");
+	let texts: Vec<String> = stream_res.text_stream().try_collect().await?;
+	assert_eq!(texts, vec!["Secondary", " answer."]);
+	assert_eq!(server.received_requests().len(), 2);
+
+	Ok(())
+}
+
+/// A stream failing before its first token (here, past the size limit) is a failure,
+/// so the secondary is sent right away and wins.
+#[tokio::test]
+async fn test_yakbak_openai_exec_chat_stream_hedged_incomplete_primary() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "hedge_stream_incomplete").await?;
+
+	let policy = HedgePolicy::new(Duration::from_secs(2)).append_secondary_target("gpt-4.1-mini");
+	let options = ChatOptions::default().with_max_response_bytes(20);
+	let stream_res = client
+		.exec_chat_stream_hedged(
+			"gpt-4o-mini",
+			ChatRequest::from_user("Say hi."),
+			Some(&options),
+			&policy,
+		)
+		.await?;
+
+	assert_eq!(stream_res.model_iden.model_name, "gpt-4.1-mini");
+	let texts: Vec<String> = stream_res.text_stream().try_collect().await?;
+	assert_eq!(texts, vec!["Secondary", " answer."]);
+	assert_eq!(server.received_requests().len(), 2);
+
+	Ok(())
+}
+
+/// The token budget accumulates the usage of the calls, calls `on_approaching` once,
+/// and refuses the calls once exhausted (without sending them).
+#[tokio::test]
+async fn test_yakbak_openai_token_budget_refuses_when_exhausted() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "token_budget").await?;
+
+	let approaching: Arc<Mutex<Vec<TokenBudgetStatus>>> = Default::default();
+	let budget = TokenBudget::new(120).with_approaching_ratio(0.5).with_on_approaching({
+		let approaching = approaching.clone();
+		move |status| approaching.lock().unwrap().push(status)
+	});
+	let client = client.with_token_budget(budget.clone());
+
+	// -- First call (60 tokens), reaches the approaching ratio
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("First."), None).await?;
+	assert_eq!(budget.used(), 60);
+	assert_eq!(approaching.lock().unwrap().len(), 1);
+
+	// -- Second call (90 tokens), crosses the budget but completes
+	let chat_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Second."), None).await?;
+	assert_eq!(chat_res.first_text(), Some("Second answer."));
+	assert_eq!(budget.used(), 150);
+	assert_eq!(approaching.lock().unwrap().len(), 1);
+
+	// -- Third call is refused, and not sent
+	let res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Third."), None).await;
+	assert!(matches!(
+		res,
+		Err(Error::TokenBudgetExceeded {
+			used: 150,
+			max_tokens: 120,
+			..
+		})
+	));
+	assert_eq!(server.received_requests().len(), 2);
+
+	Ok(())
+}
+
+/// With `TrimStrategy::Summarize`, the older turns are summarized by a first call,
+/// and the final request has the summary (as a system message) and the last turns only.
+#[tokio::test]
+async fn test_yakbak_openai_exec_chat_trimmed_summarize() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "trim_summarize").await?;
+
+	let older_details = "I want to visit Lisbon in May. ".repeat(40);
+	let chat_req = ChatRequest::new(vec![
+		ChatMessage::system("You are a travel assistant."),
+		ChatMessage::user(older_details.clone()),
+		ChatMessage::assistant("Great, what is your budget?"),
+		ChatMessage::user("About 1500 EUR, and I prefer trains."),
+		ChatMessage::assistant("Noted."),
+		ChatMessage::user("How should I go to Porto?"),
+	]);
+	let policy = TrimPolicy::new(200, TrimStrategy::Summarize { summary_model: None }).with_keep_last(2);
+
+	let chat_res = client.exec_chat_trimmed("gpt-4o-mini", chat_req, None, &policy).await?;
+
+	assert_eq!(
+		chat_res.first_text(),
+		Some("Take the train from Lisbon to Porto, it fits your budget.")
+	);
+
+	// -- Check the summarization request (older turns only)
+	let requests = server.received_requests();
+	assert_eq!(requests.len(), 2);
+	let summary_body = requests[0].body_text();
+	assert!(summary_body.contains("1500 EUR"));
+	assert!(!summary_body.contains("How should I go to Porto?"));
+
+	// -- Check the final request (summary + last turns)
+	let final_body: serde_json::Value = serde_json::from_str(&requests[1].body_text()).map_err(Error::from)?;
+	let messages = final_body["messages"].as_array().ok_or("Should have messages")?;
+	assert_eq!(messages.len(), 4);
+	assert_eq!(messages[0]["content"], json!("You are a travel assistant."));
+	let summary = messages[1]["content"].as_str().ok_or("Should have the summary message")?;
+	assert_eq!(messages[1]["role"], json!("system"));
+	assert!(summary.starts_with("Summary of the earlier conversation:\nThe user is planning a trip to Lisbon"));
+	assert_eq!(messages[2]["content"], json!("Noted."));
+	assert_eq!(messages[3]["content"], json!("How should I go to Porto?"));
+	assert!(!requests[1].body_text().contains(&older_details));
+
+	Ok(())
+}
+
+/// The `extra_body` (generic, then per-adapter) is deep-merged into the sent payload,
+/// and the adapter values it replaced are reported in `ChatResponse.extra_body_overrides`.
+#[tokio::test]
+async fn test_yakbak_openai_extra_body_merged_and_reported() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "extra_body").await?;
+
+	let options = ChatOptions::default()
+		.with_temperature(0.2)
+		.with_stop_sequences(vec!["END".to_string()])
+		.with_extra_body(json!({"temperature": 0.9, "user": "user-42", "stop": ["STOP"]}))
+		.with_extra_body_for(AdapterKind::OpenAI, json!({"metadata": {"team": "search"}}))
+		.with_extra_body_for(AdapterKind::Anthropic, json!({"top_k": 5}));
+
+	let chat_res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("Say hello in French."),
+			Some(&options),
+		)
+		.await?;
+
+	assert_eq!(chat_res.first_text(), Some("Bonjour!"));
+	let mut overrides = chat_res.extra_body_overrides.clone();
+	overrides.sort();
+	assert_eq!(overrides, vec!["/stop".to_string(), "/temperature".to_string()]);
+
+	// -- Check the sent payload
+	let requests = server.received_requests();
+	let body: serde_json::Value = serde_json::from_str(&requests[0].body_text()).map_err(Error::from)?;
+	assert_eq!(body["temperature"], json!(0.9));
+	assert_eq!(body["stop"], json!(["STOP"]));
+	assert_eq!(body["user"], json!("user-42"));
+	assert_eq!(body["metadata"], json!({"team": "search"}));
+	assert!(body.get("top_k").is_none());
+	assert_eq!(body["model"], json!("gpt-4o-mini"));
+
+	Ok(())
+}
+
+/// `exec_chat_with_raw` returns the raw provider JSON alongside the parsed response,
+/// without `capture_raw_body` (so `captured_raw_body` stays empty).
+#[tokio::test]
+async fn test_yakbak_openai_exec_chat_with_raw() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "chat_with_raw").await?;
+
+	let (chat_res, raw_body) = client
+		.exec_chat_with_raw("gpt-4o-mini", ChatRequest::from_user("Why is the sky blue?"), None)
+		.await?;
+
+	let cassette = std::fs::read_to_string("tests/data/yakbak/openai/chat_with_raw/response_000.txt")?;
+	let expected: serde_json::Value = serde_json::from_str(&cassette).map_err(Error::from)?;
+	assert_eq!(raw_body, expected);
+	assert_eq!(raw_body["system_fingerprint"], json!("fp_yakbak"));
+
+	assert_eq!(
+		chat_res.first_text(),
+		Some("The sky is blue because of Rayleigh scattering.")
+	);
+	assert_eq!(chat_res.usage.total_tokens, Some(24));
+	assert!(chat_res.captured_raw_body.is_none());
+
+	Ok(())
+}
+
+/// With a JSON response format, the markdown code fences of the response text are stripped
+/// (non-streaming content and stream captured content), and left as is otherwise.
+#[tokio::test]
+async fn test_yakbak_openai_normalize_json_output() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "json_fenced").await?;
+	let json_options = ChatOptions::json().with_capture_content(true);
+
+	// -- Non-streaming, fenced with the `json` tag (the inner backticks are kept)
+	let chat_res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("Give a city as JSON."),
+			Some(&json_options),
+		)
+		.await?;
+	assert_eq!(chat_res.first_text(), Some(r#"{"city": "Paris", "snippet": "`x`"}"#));
+
+	// -- Streaming, the captured content is stripped (not the chunks)
+	let stream_res = client
+		.exec_chat_stream(
+			"gpt-4o-mini",
+			ChatRequest::from_user("Another one."),
+			Some(&json_options),
+		)
+		.await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+	assert_eq!(extract.stream_end.captured_first_text(), Some(r#"{"city": "Lyon"}"#));
+	assert_eq!(extract.content.as_deref(), Some("```json\n{\"city\": \"Lyon\"}\n```"));
+
+	// -- No response format, so content starting with backticks is left as is
+	let chat_res = client
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Show a shell command."), None)
+		.await?;
+	assert_eq!(chat_res.first_text(), Some("```bash\nls -la\n```"));
+
+	Ok(())
+}
+
+/// With `auto_continue`, a response truncated by the output token limit is continued
+/// (the partial output sent back as the last assistant message), and the texts are stitched.
+#[tokio::test]
+async fn test_yakbak_openai_auto_continue_stitches_truncated_response() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "auto_continue").await?;
+	let options = ChatOptions::default().with_max_tokens(10).with_auto_continue(2);
+
+	let chat_res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("List the primary colors."),
+			Some(&options),
+		)
+		.await?;
+
+	assert_eq!(
+		chat_res.first_text(),
+		Some("The three primary colors of paint are red, yellow, and blue.")
+	);
+	assert!(matches!(chat_res.stop_reason, Some(StopReason::Completed(_))));
+	assert_eq!(chat_res.usage.prompt_tokens, Some(41));
+	assert_eq!(chat_res.usage.completion_tokens, Some(15));
+
+	// -- Check the continuation request (partial output as the last assistant message)
+	let requests = server.received_requests();
+	assert_eq!(requests.len(), 2);
+	let body: serde_json::Value = serde_json::from_str(&requests[1].body_text()).map_err(Error::from)?;
+	let messages = body["messages"].as_array().ok_or("Should have messages")?;
+	assert_eq!(messages.len(), 2);
+	assert_eq!(messages[1]["role"], json!("assistant"));
+	assert_eq!(
+		messages[1]["content"],
+		json!("The three primary colors of paint are red, ")
+	);
+
+	Ok(())
+}
+
+/// With `OnEmpty::Allow` (default), an empty response is returned as is.
+#[tokio::test]
+async fn test_yakbak_openai_on_empty_allow() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "empty_response").await?;
+	let options = ChatOptions::default().with_on_empty(OnEmpty::Allow);
+
+	let chat_res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("List the primary colors."),
+			Some(&options),
+		)
+		.await?;
+
+	assert!(chat_res.is_empty());
+	assert_eq!(server.received_requests().len(), 1);
+
+	Ok(())
+}
+
+/// With `OnEmpty::Error`, an empty response is a `NoChatResponse` error (without retry).
+#[tokio::test]
+async fn test_yakbak_openai_on_empty_error() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "empty_response").await?;
+	let options = ChatOptions::default().with_on_empty(OnEmpty::Error);
+
+	let res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("List the primary colors."),
+			Some(&options),
+		)
+		.await;
+
+	assert!(matches!(res, Err(Error::NoChatResponse { .. })), "got: {res:?}");
+	assert_eq!(server.received_requests().len(), 1);
+
+	Ok(())
+}
+
+/// With `OnEmpty::Retry`, the request is sent again until a non-empty response (up to 2 retries).
+#[tokio::test]
+async fn test_yakbak_openai_on_empty_retry() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "empty_response").await?;
+	let options = ChatOptions::default().with_on_empty(OnEmpty::Retry);
+
+	let chat_res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("List the primary colors."),
+			Some(&options),
+		)
+		.await?;
+
+	assert_eq!(chat_res.first_text(), Some("Red, yellow, and blue."));
+	// the empty content `""`, then `null`, then the answer
+	assert_eq!(server.received_requests().len(), 3);
+
+	Ok(())
+}
+
+/// A response body over `max_response_bytes` fails with `Error::ResponseTooLarge` carrying the body up to the limit.
+#[tokio::test]
+async fn test_yakbak_openai_max_response_bytes_non_streaming() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "chat_with_raw").await?;
+	let options = ChatOptions::default().with_max_response_bytes(64);
+
+	let res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("Why is the sky blue?"),
+			Some(&options),
+		)
+		.await;
+
+	let Err(Error::ResponseTooLarge { max_bytes, partial, .. }) = res else {
+		return Err(format!("should be a response too large error, got: {res:?}").into());
+	};
+	assert_eq!(max_bytes, 64);
+	assert_eq!(partial.len(), 64);
+	assert!(partial.starts_with(r#"{"id":"chatcmpl-yakbak-raw""#));
+
+	Ok(())
+}
+
+/// A stream over `max_response_bytes` returns the chunks that fit, then `Error::ResponseTooLarge` (without `End`).
+#[tokio::test]
+async fn test_yakbak_openai_max_response_bytes_streaming() -> TestResult<()> {
+	let (client, _server) = replay_client("openai", "text_stream").await?;
+	// "Counting." (9) + "One" (3) fit, ", two" (5) does not
+	let options = ChatOptions::default().with_capture_content(true).with_max_response_bytes(16);
+
+	let mut stream = client
+		.exec_chat_stream("gpt-4o-mini", ChatRequest::from_user("Count to three."), Some(&options))
+		.await?
+		.stream;
+	let mut chunks: Vec<String> = Vec::new();
+	let mut stream_end: Option<StreamEnd> = None;
+	let mut stream_error: Option<Error> = None;
+	while let Some(event) = stream.next().await {
+		match event {
+			Ok(ChatStreamEvent::Chunk(chunk)) => chunks.push(chunk.content),
+			Ok(ChatStreamEvent::End(end)) => stream_end = Some(end),
+			Ok(_) => (),
+			Err(err) => stream_error = Some(err),
+		}
+	}
+
+	assert_eq!(chunks, vec!["One"]);
+	assert!(stream_end.is_none(), "should not have a StreamEnd");
+	let Some(Error::ResponseTooLarge { max_bytes, partial, .. }) = stream_error else {
+		return Err(format!("should be a response too large error, got: {stream_error:?}").into());
+	};
+	assert_eq!(max_bytes, 16);
+	assert!(partial.is_empty());
+
+	Ok(())
+}
+
+/// A system-only (or developer-only) request gets the `OnEmptyConversation::InsertUser` user turn, and is not sent with `Error`.
+#[tokio::test]
+async fn test_yakbak_openai_on_empty_conversation() -> TestResult<()> {
+	let (client, server) = replay_client("openai", "chat_with_raw").await?;
+	let chat_req = ChatRequest::from_system("Answer in one sentence.");
+
+	// -- Error, before sending
+	let options = ChatOptions::default().with_on_empty_conversation(OnEmptyConversation::Error);
+	let res = client.exec_chat("gpt-4o-mini", chat_req.clone(), Some(&options)).await;
+	assert!(matches!(res, Err(Error::ChatReqHasNoMessages { .. })), "got: {res:?}");
+	// a developer message is an instruction too, not a conversation turn
+	let developer_req = chat_req.clone().append_message(ChatMessage::developer("Be concise."));
+	let res = client.exec_chat("gpt-4o-mini", developer_req, Some(&options)).await;
+	assert!(matches!(res, Err(Error::ChatReqHasNoMessages { .. })), "got: {res:?}");
+	assert!(server.received_requests().is_empty());
+
+	// -- InsertUser
+	let options = ChatOptions::default().with_on_empty_conversation(OnEmptyConversation::insert_user("Begin."));
+	client.exec_chat("gpt-4o-mini", chat_req, Some(&options)).await?;
+
+	let requests = server.received_requests();
+	assert_eq!(requests.len(), 1);
+	let body: serde_json::Value = serde_json::from_slice(&requests[0].body).map_err(Error::from)?;
+	let messages = body["messages"].as_array().ok_or("Should have messages")?;
+	let last = messages.last().ok_or("Should have the user turn")?;
+	assert_eq!(last["role"], "user");
+	assert_eq!(last["content"], "Begin.");
+
+	Ok(())
+}